@@ -0,0 +1,35 @@
+use smithy4rs_core::{
+    schema::{Document, Schema},
+    serde::{Codec, CodecError, de::DeserializeWithSchema, se::SerializeWithSchema},
+};
+
+use crate::{CborDeserializer, CborSerializer};
+
+/// [`Codec`] that reads and writes CBOR documents.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CborCodec;
+
+impl Codec for CborCodec {
+    #[allow(clippy::borrowed_box)]
+    fn serialize_document(
+        &self,
+        schema: &Schema,
+        document: &Box<dyn Document>,
+    ) -> Result<Vec<u8>, CodecError> {
+        let mut buf = Vec::new();
+        document.serialize_with_schema(schema, CborSerializer::new(&mut buf))?;
+        Ok(buf)
+    }
+
+    fn deserialize_document(
+        &self,
+        schema: &Schema,
+        data: &[u8],
+    ) -> Result<Box<dyn Document>, CodecError> {
+        let mut deserializer = CborDeserializer::new(data);
+        Ok(Box::<dyn Document>::deserialize_with_schema(
+            schema,
+            &mut deserializer,
+        )?)
+    }
+}
@@ -0,0 +1,840 @@
+use std::borrow::Cow;
+
+use num_bigint::Sign;
+use smithy4rs_core::{
+    BigDecimal, BigInt, IndexMap, Instant,
+    schema::{Blob, Document, NULL as NULL_DOCUMENT, Schema},
+    serde::{
+        deserializers::{
+            DeserializeWithSchema, Deserializer, ListReader, MapReader, StructReader,
+            UnknownEnumPolicy,
+        },
+        pool::{BufferPool, PooledBuffer},
+        strict::UnknownMembers,
+    },
+};
+
+use crate::{
+    errors::CborSerdeError,
+    serialization::{
+        BREAK, FALSE, FLOAT16, FLOAT32, FLOAT64, MAJOR_ARRAY, MAJOR_BYTES, MAJOR_MAP,
+        MAJOR_NEGATIVE, MAJOR_TAG, MAJOR_TEXT, MAJOR_UNSIGNED, NULL, TAG_DECIMAL_FRACTION,
+        TAG_EPOCH_TIMESTAMP, TAG_NEGATIVE_BIGNUM, TAG_POSITIVE_BIGNUM, TRUE, UNDEFINED,
+    },
+};
+
+/// Additional info value indicating an indefinite-length item
+const INDEFINITE: u8 = 31;
+
+/// Default maximum nesting depth of skipped items and documents
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+/// A CBOR deserializer that reads from a byte slice.
+pub struct CborDeserializer<'de> {
+    data: &'de [u8],
+    pos: usize,
+    pool: Option<BufferPool>,
+    strict_members: bool,
+    unknown_enums: UnknownEnumPolicy,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl<'de> CborDeserializer<'de> {
+    /// Create a new CBOR deserializer from a byte slice.
+    pub fn new(data: &'de [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            pool: None,
+            strict_members: false,
+            unknown_enums: UnknownEnumPolicy::MapToUnknown,
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// Draw the buffers backing blobs and chunked strings from a [`BufferPool`].
+    #[must_use]
+    pub fn with_buffer_pool(mut self, pool: BufferPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Reject unknown members of all structures and unions.
+    ///
+    /// Deserialization fails with [`CborSerdeError::UnknownMembers`] listing every
+    /// unknown member of a struct, instead of skipping them. Individual shapes can
+    /// also opt in with the [`StrictMembersTrait`](smithy4rs_core::serde::strict::StrictMembersTrait).
+    #[must_use]
+    pub fn with_strict_members(mut self, strict: bool) -> Self {
+        self.strict_members = strict;
+        self
+    }
+
+    /// Set the [`UnknownEnumPolicy`] for enum values that do not match a known variant.
+    ///
+    /// Unknown values are deserialized into the `Unknown` variant of an enum by
+    /// default. Use [`UnknownEnumPolicy::Error`] to reject them instead.
+    #[must_use]
+    pub fn with_unknown_enum_policy(mut self, policy: UnknownEnumPolicy) -> Self {
+        self.unknown_enums = policy;
+        self
+    }
+
+    /// Set the maximum nesting depth of untyped data.
+    ///
+    /// Documents, and unknown members that are skipped, are read recursively, so
+    /// deeply nested input could otherwise overflow the stack. Each array, map, and
+    /// tag counts as one level of nesting. Deserialization fails with
+    /// [`CborSerdeError::DepthLimitExceeded`] past the limit.
+    ///
+    /// Defaults to [`DEFAULT_MAX_DEPTH`].
+    #[must_use]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Enter a nested item, failing if the maximum depth is exceeded.
+    #[inline]
+    fn enter(&mut self) -> Result<(), CborSerdeError> {
+        if self.depth >= self.max_depth {
+            return Err(CborSerdeError::DepthLimitExceeded(self.max_depth));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn take_buffer(&self, capacity: usize) -> PooledBuffer {
+        PooledBuffer::take(self.pool.as_ref(), capacity)
+    }
+
+    #[inline]
+    fn peek_byte(&self) -> Result<u8, CborSerdeError> {
+        self.data
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| CborSerdeError::DeserializationError("Unexpected end of input".into()))
+    }
+
+    #[inline]
+    fn next_byte(&mut self) -> Result<u8, CborSerdeError> {
+        let byte = self.peek_byte()?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    #[inline]
+    fn next_slice(&mut self, len: usize) -> Result<&'de [u8], CborSerdeError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| {
+                CborSerdeError::DeserializationError("Unexpected end of input".into())
+            })?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    /// Read the argument following an initial byte.
+    ///
+    /// Returns `None` for indefinite-length items.
+    fn read_argument(&mut self, initial: u8) -> Result<Option<u64>, CborSerdeError> {
+        let info = initial & 0x1f;
+        let value = match info {
+            0..=23 => u64::from(info),
+            24 => u64::from(self.next_byte()?),
+            25 => u64::from(u16::from_be_bytes(self.next_array()?)),
+            26 => u64::from(u32::from_be_bytes(self.next_array()?)),
+            27 => u64::from_be_bytes(self.next_array()?),
+            INDEFINITE => return Ok(None),
+            _ => {
+                return Err(CborSerdeError::DeserializationError(format!(
+                    "Invalid additional info: {info}"
+                )));
+            }
+        };
+        Ok(Some(value))
+    }
+
+    #[inline]
+    fn next_array<const N: usize>(&mut self) -> Result<[u8; N], CborSerdeError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.next_slice(N)?);
+        Ok(array)
+    }
+
+    /// Read the header of a data item, checking that it has the expected major type.
+    fn read_header(&mut self, major: u8, expected: &str) -> Result<Option<u64>, CborSerdeError> {
+        let initial = self.next_byte()?;
+        if initial & 0xe0 != major {
+            return Err(CborSerdeError::DeserializationError(format!(
+                "Expected {expected}, found initial byte {initial:#04x}"
+            )));
+        }
+        self.read_argument(initial)
+    }
+
+    /// Read a definite-length header, rejecting indefinite-length items.
+    fn read_definite_header(&mut self, major: u8, expected: &str) -> Result<u64, CborSerdeError> {
+        self.read_header(major, expected)?.ok_or_else(|| {
+            CborSerdeError::DeserializationError(format!("Expected definite-length {expected}"))
+        })
+    }
+
+    /// Read a tag number if the next item is tagged.
+    fn read_optional_tag(&mut self) -> Result<Option<u64>, CborSerdeError> {
+        if self.peek_byte()? & 0xe0 != MAJOR_TAG {
+            return Ok(None);
+        }
+        self.read_definite_header(MAJOR_TAG, "tag").map(Some)
+    }
+
+    /// Check for (and consume) a break marker ending an indefinite-length item.
+    #[inline]
+    fn consume_break(&mut self) -> Result<bool, CborSerdeError> {
+        if self.peek_byte()? == BREAK {
+            self.pos += 1;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Read any CBOR integer (major type 0 or 1) as an `i128`.
+    fn read_int(&mut self) -> Result<i128, CborSerdeError> {
+        let initial = self.next_byte()?;
+        let major = initial & 0xe0;
+        let value = self.read_argument(initial)?.ok_or_else(|| {
+            CborSerdeError::DeserializationError("Integers cannot be indefinite-length".into())
+        })?;
+        match major {
+            MAJOR_UNSIGNED => Ok(i128::from(value)),
+            MAJOR_NEGATIVE => Ok(-1 - i128::from(value)),
+            _ => Err(CborSerdeError::DeserializationError(format!(
+                "Expected integer, found initial byte {initial:#04x}"
+            ))),
+        }
+    }
+
+    fn read_int_as<T: TryFrom<i128>>(&mut self, type_name: &str) -> Result<T, CborSerdeError> {
+        let value = self.read_int()?;
+        T::try_from(value).map_err(|_| {
+            CborSerdeError::DeserializationError(format!(
+                "Value out of range for {type_name}: {value}"
+            ))
+        })
+    }
+
+    /// Read any CBOR number (integer or float) as an `f64`.
+    fn read_f64(&mut self) -> Result<f64, CborSerdeError> {
+        match self.peek_byte()? {
+            FLOAT16 => {
+                self.pos += 1;
+                Ok(f16_to_f64(u16::from_be_bytes(self.next_array()?)))
+            }
+            FLOAT32 => {
+                self.pos += 1;
+                Ok(f64::from(f32::from_be_bytes(self.next_array()?)))
+            }
+            FLOAT64 => {
+                self.pos += 1;
+                Ok(f64::from_be_bytes(self.next_array()?))
+            }
+            _ => self.read_int().map(|v| v as f64),
+        }
+    }
+
+    /// Read a byte or text string, concatenating indefinite-length chunks.
+    fn read_chunks(&mut self, major: u8, expected: &str) -> Result<Cow<'de, [u8]>, CborSerdeError> {
+        match self.read_header(major, expected)? {
+            Some(len) => Ok(Cow::Borrowed(self.next_slice(to_usize(len)?)?)),
+            None => {
+                let mut result = self.take_buffer(0);
+                while !self.consume_break()? {
+                    let len = self.read_definite_header(major, expected)?;
+                    result.extend_from_slice(self.next_slice(to_usize(len)?)?);
+                }
+                Ok(Cow::Owned(result.into_inner()))
+            }
+        }
+    }
+
+    fn read_text(&mut self) -> Result<Cow<'de, str>, CborSerdeError> {
+        let invalid =
+            |e| CborSerdeError::DeserializationError(format!("Invalid UTF-8 in text string: {e}"));
+        match self.read_chunks(MAJOR_TEXT, "text string")? {
+            Cow::Borrowed(bytes) => std::str::from_utf8(bytes)
+                .map(Cow::Borrowed)
+                .map_err(invalid),
+            Cow::Owned(bytes) => String::from_utf8(bytes)
+                .map(Cow::Owned)
+                .map_err(|e| invalid(e.utf8_error())),
+        }
+    }
+
+    /// Read an integer or tagged bignum.
+    fn read_bignum(&mut self) -> Result<BigInt, CborSerdeError> {
+        match self.read_optional_tag()? {
+            None => self.read_int().map(BigInt::from),
+            Some(TAG_POSITIVE_BIGNUM) => {
+                let bytes = self.read_chunks(MAJOR_BYTES, "bignum byte string")?;
+                Ok(BigInt::from_bytes_be(Sign::Plus, &bytes))
+            }
+            Some(TAG_NEGATIVE_BIGNUM) => {
+                let bytes = self.read_chunks(MAJOR_BYTES, "bignum byte string")?;
+                Ok(-BigInt::from_bytes_be(Sign::Plus, &bytes) - 1u8)
+            }
+            Some(tag) => Err(CborSerdeError::DeserializationError(format!(
+                "Unexpected tag for big integer: {tag}"
+            ))),
+        }
+    }
+
+    /// Skip over a complete data item, including any nested items.
+    fn skip_item(&mut self) -> Result<(), CborSerdeError> {
+        self.enter()?;
+        let result = self.skip_nested_item();
+        self.leave();
+        result
+    }
+
+    fn skip_nested_item(&mut self) -> Result<(), CborSerdeError> {
+        let initial = self.next_byte()?;
+        let major = initial & 0xe0;
+        let argument = self.read_argument(initial)?;
+        match (major, argument) {
+            (MAJOR_UNSIGNED | MAJOR_NEGATIVE, _) => {}
+            (MAJOR_BYTES | MAJOR_TEXT, Some(len)) => {
+                self.next_slice(to_usize(len)?)?;
+            }
+            (MAJOR_BYTES | MAJOR_TEXT, None) => {
+                while !self.consume_break()? {
+                    self.skip_item()?;
+                }
+            }
+            (MAJOR_ARRAY, Some(len)) => {
+                for _ in 0..len {
+                    self.skip_item()?;
+                }
+            }
+            (MAJOR_MAP, Some(len)) => {
+                for _ in 0..len {
+                    self.skip_item()?;
+                    self.skip_item()?;
+                }
+            }
+            (MAJOR_ARRAY | MAJOR_MAP, None) => {
+                while !self.consume_break()? {
+                    self.skip_item()?;
+                }
+            }
+            (MAJOR_TAG, _) => self.skip_item()?,
+            // Simple values and floats have their payloads consumed as the argument
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Read a complete data item as an untyped document.
+    ///
+    /// Integers that do not fit in a `long` are read as `bigInteger`. Single
+    /// precision floats are read as `float`, and all other floats as `double`. Tagged timestamps, bignums, and decimal
+    /// fractions are read as the corresponding Smithy types.
+    fn next_document(&mut self, schema: &Schema) -> Result<Box<dyn Document>, CborSerdeError> {
+        self.enter()?;
+        let result = self.next_nested_document(schema);
+        self.leave();
+        result
+    }
+
+    fn next_nested_document(
+        &mut self,
+        schema: &Schema,
+    ) -> Result<Box<dyn Document>, CborSerdeError> {
+        let initial = self.peek_byte()?;
+        match initial & 0xe0 {
+            MAJOR_UNSIGNED | MAJOR_NEGATIVE => {
+                let value = self.read_int()?;
+                Ok(i64::try_from(value).map_or_else(|_| BigInt::from(value).into(), Into::into))
+            }
+            MAJOR_BYTES => self.read_blob(schema).map(Into::into),
+            MAJOR_TEXT => self.read_string(schema).map(Into::into),
+            MAJOR_ARRAY => {
+                let mut remaining = self.read_header(MAJOR_ARRAY, "array")?;
+                let mut elements: Vec<Box<dyn Document>> = Vec::new();
+                while has_next(self, &mut remaining)? {
+                    elements.push(self.next_document(schema)?);
+                }
+                Ok(elements.into())
+            }
+            MAJOR_MAP => {
+                let mut remaining = self.read_header(MAJOR_MAP, "map")?;
+                let mut entries: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+                while has_next(self, &mut remaining)? {
+                    let key = self.read_text()?.into_owned();
+                    entries.insert(key, self.next_document(schema)?);
+                }
+                Ok(entries.into())
+            }
+            MAJOR_TAG => {
+                let start = self.pos;
+                let tag = self.read_definite_header(MAJOR_TAG, "tag")?;
+                self.pos = start;
+                match tag {
+                    TAG_EPOCH_TIMESTAMP => self.read_timestamp(schema).map(Into::into),
+                    TAG_POSITIVE_BIGNUM | TAG_NEGATIVE_BIGNUM => self.read_bignum().map(Into::into),
+                    TAG_DECIMAL_FRACTION => self.read_big_decimal(schema).map(Into::into),
+                    tag => Err(CborSerdeError::DeserializationError(format!(
+                        "Unexpected tag in document: {tag}"
+                    ))),
+                }
+            }
+            _ => match initial {
+                FALSE | TRUE => self.read_bool(schema).map(Into::into),
+                NULL | UNDEFINED => {
+                    self.pos += 1;
+                    Ok(NULL_DOCUMENT.clone())
+                }
+                FLOAT32 => {
+                    self.pos += 1;
+                    Ok(f32::from_be_bytes(self.next_array()?).into())
+                }
+                FLOAT16 | FLOAT64 => self.read_f64().map(Into::into),
+                other => Err(CborSerdeError::DeserializationError(format!(
+                    "Unexpected initial byte in document: {other:#04x}"
+                ))),
+            },
+        }
+    }
+}
+
+/// Reader for CBOR struct members.
+pub struct CborStructReader<'de, 'a> {
+    de: &'a mut CborDeserializer<'de>,
+    remaining: Option<u64>,
+    unknown: UnknownMembers,
+}
+
+/// Reader for CBOR list elements.
+pub struct CborListReader<'de, 'a> {
+    de: &'a mut CborDeserializer<'de>,
+    remaining: Option<u64>,
+}
+
+/// Reader for CBOR map entries.
+pub struct CborMapReader<'de, 'a> {
+    de: &'a mut CborDeserializer<'de>,
+    remaining: Option<u64>,
+}
+
+/// Advance a definite or indefinite-length container, returning `false` once
+/// all items have been consumed.
+#[inline]
+fn has_next(
+    de: &mut CborDeserializer<'_>,
+    remaining: &mut Option<u64>,
+) -> Result<bool, CborSerdeError> {
+    match remaining {
+        Some(0) => Ok(false),
+        Some(n) => {
+            *n -= 1;
+            Ok(true)
+        }
+        None => Ok(!de.consume_break()?),
+    }
+}
+
+#[inline]
+fn to_usize(len: u64) -> Result<usize, CborSerdeError> {
+    usize::try_from(len)
+        .map_err(|_| CborSerdeError::DeserializationError(format!("Length too large: {len}")))
+}
+
+/// Convert an IEEE 754 half-precision float to a double.
+fn f16_to_f64(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = i32::from((bits >> 10) & 0x1f);
+    let fraction = f64::from(bits & 0x3ff);
+    let magnitude = match exponent {
+        0 => fraction * 2f64.powi(-24),
+        0x1f if fraction == 0.0 => f64::INFINITY,
+        0x1f => f64::NAN,
+        _ => (1.0 + fraction / 1024.0) * 2f64.powi(exponent - 15),
+    };
+    sign * magnitude
+}
+
+// ============================================================================
+// Deserializer Implementation
+// ============================================================================
+
+impl<'de, 'a> Deserializer<'de> for &'a mut CborDeserializer<'de> {
+    type Error = CborSerdeError;
+    type StructReader = CborStructReader<'de, 'a>;
+    type ListReader = CborListReader<'de, 'a>;
+    type MapReader = CborMapReader<'de, 'a>;
+
+    #[inline]
+    fn unknown_enum_policy(&self) -> UnknownEnumPolicy {
+        self.unknown_enums
+    }
+
+    fn read_bool(self, _schema: &Schema) -> Result<bool, Self::Error> {
+        match self.next_byte()? {
+            TRUE => Ok(true),
+            FALSE => Ok(false),
+            other => Err(CborSerdeError::DeserializationError(format!(
+                "Expected bool, found initial byte {other:#04x}"
+            ))),
+        }
+    }
+
+    fn read_byte(self, _schema: &Schema) -> Result<i8, Self::Error> {
+        self.read_int_as("i8")
+    }
+
+    fn read_short(self, _schema: &Schema) -> Result<i16, Self::Error> {
+        self.read_int_as("i16")
+    }
+
+    fn read_integer(self, _schema: &Schema) -> Result<i32, Self::Error> {
+        self.read_int_as("i32")
+    }
+
+    fn read_long(self, _schema: &Schema) -> Result<i64, Self::Error> {
+        self.read_int_as("i64")
+    }
+
+    fn read_float(self, _schema: &Schema) -> Result<f32, Self::Error> {
+        self.read_f64().map(|v| v as f32)
+    }
+
+    fn read_double(self, _schema: &Schema) -> Result<f64, Self::Error> {
+        self.read_f64()
+    }
+
+    fn read_big_integer(self, _schema: &Schema) -> Result<BigInt, Self::Error> {
+        self.read_bignum()
+    }
+
+    fn read_big_decimal(self, _schema: &Schema) -> Result<BigDecimal, Self::Error> {
+        match self.read_optional_tag()? {
+            // Plain integers are also valid big decimals
+            None => self.read_int().map(|v| BigDecimal::from(BigInt::from(v))),
+            Some(TAG_DECIMAL_FRACTION) => {
+                if self.read_definite_header(MAJOR_ARRAY, "decimal fraction array")? != 2 {
+                    return Err(CborSerdeError::DeserializationError(
+                        "Decimal fraction must contain exactly two elements".into(),
+                    ));
+                }
+                let exponent: i64 = self.read_int_as("i64")?;
+                let mantissa = self.read_bignum()?;
+                Ok(BigDecimal::new(mantissa, -exponent))
+            }
+            Some(tag) => Err(CborSerdeError::DeserializationError(format!(
+                "Unexpected tag for big decimal: {tag}"
+            ))),
+        }
+    }
+
+    fn read_string(self, _schema: &Schema) -> Result<String, Self::Error> {
+        self.read_text().map(Cow::into_owned)
+    }
+
+    fn read_str(self, _schema: &Schema) -> Result<Cow<'de, str>, Self::Error> {
+        self.read_text()
+    }
+
+    fn read_blob(self, _schema: &Schema) -> Result<Blob, Self::Error> {
+        let bytes = match self.read_chunks(MAJOR_BYTES, "byte string")? {
+            Cow::Borrowed(bytes) => {
+                let mut buffer = self.take_buffer(bytes.len());
+                buffer.extend_from_slice(bytes);
+                buffer.into_inner()
+            }
+            Cow::Owned(bytes) => bytes,
+        };
+        Ok(Blob::from(bytes))
+    }
+
+    fn read_timestamp(self, _schema: &Schema) -> Result<Instant, Self::Error> {
+        match self.read_optional_tag()? {
+            None | Some(TAG_EPOCH_TIMESTAMP) => {}
+            Some(tag) => {
+                return Err(CborSerdeError::DeserializationError(format!(
+                    "Unexpected tag for timestamp: {tag}"
+                )));
+            }
+        }
+        let seconds = self.read_f64()?;
+        Instant::from_epoch_milliseconds((seconds * 1000.0).round() as i64).map_err(|e| {
+            CborSerdeError::DeserializationError(format!("Failed to parse timestamp: {e}"))
+        })
+    }
+
+    fn read_document(self, schema: &Schema) -> Result<Box<dyn Document>, Self::Error> {
+        self.next_document(schema)
+    }
+
+    fn read_null(self) -> Result<(), Self::Error> {
+        match self.next_byte()? {
+            NULL | UNDEFINED => Ok(()),
+            other => Err(CborSerdeError::DeserializationError(format!(
+                "Expected null, found initial byte {other:#04x}"
+            ))),
+        }
+    }
+
+    fn read_struct(self, schema: &Schema) -> Result<Self::StructReader, Self::Error> {
+        let remaining = self.read_header(MAJOR_MAP, "map")?;
+        let unknown = UnknownMembers::new(schema, self.strict_members);
+        Ok(CborStructReader {
+            de: self,
+            remaining,
+            unknown,
+        })
+    }
+
+    fn read_list(self, _schema: &Schema) -> Result<Self::ListReader, Self::Error> {
+        let remaining = self.read_header(MAJOR_ARRAY, "array")?;
+        Ok(CborListReader {
+            de: self,
+            remaining,
+        })
+    }
+
+    fn read_map(self, _schema: &Schema) -> Result<Self::MapReader, Self::Error> {
+        let remaining = self.read_header(MAJOR_MAP, "map")?;
+        Ok(CborMapReader {
+            de: self,
+            remaining,
+        })
+    }
+
+    fn is_null(&mut self) -> bool {
+        matches!(self.peek_byte(), Ok(NULL | UNDEFINED))
+    }
+}
+
+// ============================================================================
+// StructReader Implementation
+// ============================================================================
+
+impl<'de> StructReader<'de> for CborStructReader<'de, '_> {
+    type Error = CborSerdeError;
+
+    fn read_member<'a>(&mut self, schema: &'a Schema) -> Result<Option<&'a Schema>, Self::Error> {
+        while has_next(self.de, &mut self.remaining)? {
+            let key = self.de.read_text()?;
+            if let Some(member_schema) = schema.get_member_or_alias(&key) {
+                return Ok(Some(member_schema));
+            }
+            // Unknown key — skip the value
+            self.unknown.insert(&key);
+            self.de.skip_item()?;
+        }
+        self.unknown.finish::<Self::Error>(schema)?;
+        Ok(None)
+    }
+
+    fn read_value<T: DeserializeWithSchema<'de>>(
+        &mut self,
+        schema: &Schema,
+    ) -> Result<T, Self::Error> {
+        T::deserialize_with_schema(schema, &mut *self.de)
+    }
+
+    fn skip_value(&mut self) -> Result<(), Self::Error> {
+        self.de.skip_item()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining.and_then(|n| usize::try_from(n).ok())
+    }
+}
+
+// ============================================================================
+// ListReader Implementation
+// ============================================================================
+
+impl<'de> ListReader<'de> for CborListReader<'de, '_> {
+    type Error = CborSerdeError;
+
+    fn read_element<T: DeserializeWithSchema<'de>>(
+        &mut self,
+        schema: &Schema,
+    ) -> Result<Option<T>, Self::Error> {
+        if !has_next(self.de, &mut self.remaining)? {
+            return Ok(None);
+        }
+        T::deserialize_with_schema(schema, &mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining.and_then(|n| usize::try_from(n).ok())
+    }
+}
+
+// ============================================================================
+// MapReader Implementation
+// ============================================================================
+
+impl<'de> MapReader<'de> for CborMapReader<'de, '_> {
+    type Error = CborSerdeError;
+
+    fn read_key(&mut self) -> Result<Option<String>, Self::Error> {
+        if !has_next(self.de, &mut self.remaining)? {
+            return Ok(None);
+        }
+        self.de.read_text().map(|key| Some(key.into_owned()))
+    }
+
+    fn read_value<V: DeserializeWithSchema<'de>>(
+        &mut self,
+        schema: &Schema,
+    ) -> Result<V, Self::Error> {
+        V::deserialize_with_schema(schema, &mut *self.de)
+    }
+
+    fn skip_value(&mut self) -> Result<(), Self::Error> {
+        self.de.skip_item()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining.and_then(|n| usize::try_from(n).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smithy4rs_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_read_primitives() {
+        let mut de = CborDeserializer::new(&[0xf5]);
+        assert!((&mut de).read_bool(&BOOLEAN).unwrap());
+
+        let mut de = CborDeserializer::new(&[0x18, 0x2a]);
+        assert_eq!((&mut de).read_integer(&INTEGER).unwrap(), 42);
+
+        let mut de = CborDeserializer::new(&[0x38, 0x63]);
+        assert_eq!((&mut de).read_integer(&INTEGER).unwrap(), -100);
+
+        let mut de = CborDeserializer::new(&[0x65, b'h', b'e', b'l', b'l', b'o']);
+        assert_eq!((&mut de).read_string(&STRING).unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_read_floats_of_any_width() {
+        // 1.5 as a half-precision float
+        let mut de = CborDeserializer::new(&[0xf9, 0x3e, 0x00]);
+        assert_eq!((&mut de).read_double(&DOUBLE).unwrap(), 1.5);
+
+        // 100000.0 as a single-precision float
+        let mut de = CborDeserializer::new(&[0xfa, 0x47, 0xc3, 0x50, 0x00]);
+        assert_eq!((&mut de).read_double(&DOUBLE).unwrap(), 100000.0);
+    }
+
+    #[test]
+    fn test_read_indefinite_string() {
+        // (_ "strea", "ming")
+        let data = [
+            0x7f, 0x65, b's', b't', b'r', b'e', b'a', 0x64, b'm', b'i', b'n', b'g', 0xff,
+        ];
+        let mut de = CborDeserializer::new(&data);
+        assert_eq!((&mut de).read_string(&STRING).unwrap(), "streaming");
+
+        // Chunked strings must be copied
+        let mut de = CborDeserializer::new(&data);
+        assert!(matches!((&mut de).read_str(&STRING).unwrap(), Cow::Owned(s) if s == "streaming"));
+    }
+
+    #[test]
+    fn test_read_borrowed_string() {
+        let mut de = CborDeserializer::new(&[0x65, b'h', b'e', b'l', b'l', b'o']);
+        assert!(matches!(
+            (&mut de).read_str(&STRING).unwrap(),
+            Cow::Borrowed("hello")
+        ));
+    }
+
+    #[test]
+    fn test_read_integer_out_of_range() {
+        // 1000 does not fit in an i8
+        let mut de = CborDeserializer::new(&[0x19, 0x03, 0xe8]);
+        assert!((&mut de).read_byte(&BYTE).is_err());
+    }
+
+    #[test]
+    fn test_is_null() {
+        let mut de = CborDeserializer::new(&[0xf6]);
+        assert!((&mut de).is_null());
+
+        let mut de = CborDeserializer::new(&[0xf7]);
+        assert!((&mut de).is_null());
+
+        let mut de = CborDeserializer::new(&[0x01]);
+        assert!(!(&mut de).is_null());
+    }
+
+    #[test]
+    fn test_skip_deeply_nested_items() {
+        // 100,000 nested tags, which would overflow the stack if skipped recursively
+        let mut data = vec![0xc6; 100_000];
+        data.push(0x00);
+        let mut de = CborDeserializer::new(&data);
+        assert!(matches!(
+            de.skip_item(),
+            Err(CborSerdeError::DepthLimitExceeded(DEFAULT_MAX_DEPTH))
+        ));
+
+        // [[[...0...]]] nested 100,000 times, with both definite and indefinite arrays
+        let mut data = [0x81, 0x9f].repeat(50_000);
+        data.push(0x00);
+        data.extend([0xff; 50_000]);
+        let mut de = CborDeserializer::new(&data);
+        assert!(matches!(
+            de.skip_item(),
+            Err(CborSerdeError::DepthLimitExceeded(DEFAULT_MAX_DEPTH))
+        ));
+    }
+
+    #[test]
+    fn test_read_deeply_nested_document() {
+        // {"a": {"a": ... 0}} nested 100,000 times
+        let mut data = [0xa1, 0x61, b'a'].repeat(100_000);
+        data.push(0x00);
+        let mut de = CborDeserializer::new(&data);
+        assert!(matches!(
+            (&mut de).read_document(&DOCUMENT),
+            Err(CborSerdeError::DepthLimitExceeded(DEFAULT_MAX_DEPTH))
+        ));
+    }
+
+    #[test]
+    fn test_configured_max_depth() {
+        let mut data = vec![0x81; 10];
+        data.push(0x00);
+        let mut de = CborDeserializer::new(&data).with_max_depth(10);
+        assert!(matches!(
+            (&mut de).read_document(&DOCUMENT),
+            Err(CborSerdeError::DepthLimitExceeded(10))
+        ));
+
+        let mut de = CborDeserializer::new(&data).with_max_depth(11);
+        assert!((&mut de).read_document(&DOCUMENT).is_ok());
+        let mut de = CborDeserializer::new(&data).with_max_depth(11);
+        de.skip_item().unwrap();
+        assert_eq!(de.pos, data.len());
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt::Display;
+
+use smithy4rs_core::{
+    schema::Schema,
+    serde::{
+        CodecError, deserializers::Error as DeserializerError,
+        serializers::Error as SerializerError,
+    },
+};
+use thiserror::Error;
+
+// TODO(errors): Better errors
+#[derive(Error, Debug)]
+pub enum CborSerdeError {
+    #[error("Failed to serialize: {0}")]
+    SerializationError(String),
+    #[error("Failed to deserialize: {0}")]
+    DeserializationError(String),
+    #[error("Failed to deserialize: duplicate member `{0}`")]
+    DuplicateMember(String),
+    #[error("Failed to deserialize: unknown members of `{shape}`: {}", members.join(", "))]
+    UnknownMembers { shape: String, members: Vec<String> },
+    #[error("Failed to deserialize: maximum nesting depth of {0} exceeded")]
+    DepthLimitExceeded(usize),
+}
+
+impl SerializerError for CborSerdeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        CborSerdeError::SerializationError(msg.to_string())
+    }
+}
+
+impl DeserializerError for CborSerdeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        CborSerdeError::DeserializationError(msg.to_string())
+    }
+
+    fn duplicate_member(member: &Schema) -> Self {
+        CborSerdeError::DuplicateMember(member.id().id().to_string())
+    }
+
+    fn unknown_members(schema: &Schema, members: Vec<String>) -> Self {
+        CborSerdeError::UnknownMembers {
+            shape: schema.id().id().to_string(),
+            members,
+        }
+    }
+}
+
+impl From<CborSerdeError> for CodecError {
+    fn from(value: CborSerdeError) -> Self {
+        match value {
+            CborSerdeError::DuplicateMember(member) => CodecError::DuplicateMember(member),
+            CborSerdeError::UnknownMembers { shape, members } => {
+                CodecError::UnknownMembers { shape, members }
+            }
+            CborSerdeError::SerializationError(msg) | CborSerdeError::DeserializationError(msg) => {
+                CodecError::Custom(msg)
+            }
+            error @ CborSerdeError::DepthLimitExceeded(_) => CodecError::Custom(error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! [CBOR](https://www.rfc-editor.org/rfc/rfc8949.html) serialization and deserialization
+//! for `smithy4rs` shapes.
+//!
+//! The encoding follows the data model used by the
+//! [Smithy RPC v2 CBOR](https://smithy.io/2.0/additional-specs/protocols/smithy-rpc-v2.html) protocol:
+//! - Structures and unions are encoded as maps keyed by member name.
+//! - Blobs are encoded as byte strings.
+//! - Timestamps are encoded with tag `1` as epoch seconds.
+//! - Big integers use the bignum tags (`2`/`3`) when they do not fit in a CBOR integer.
+//! - Big decimals use the decimal fraction tag (`4`).
+mod codec;
+mod deserialization;
+mod errors;
+mod serialization;
+
+pub use codec::CborCodec;
+pub use deserialization::{CborDeserializer, DEFAULT_MAX_DEPTH};
+pub use errors::CborSerdeError;
+pub use serialization::CborSerializer;
+
+smithy4rs_core::assert_send_sync!(CborSerdeError, CborDeserializer<'static>);
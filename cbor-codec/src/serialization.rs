@@ -0,0 +1,409 @@
+use num_bigint::Sign;
+use smithy4rs_core::{
+    BigDecimal, BigInt, Instant,
+    schema::{Blob, Schema},
+    serde::serializers::{ListWriter, MapWriter, SerializeWithSchema, Serializer, StructWriter},
+};
+
+use crate::errors::CborSerdeError;
+
+// CBOR major types (RFC 8949 section 3.1), pre-shifted into the high bits
+pub(crate) const MAJOR_UNSIGNED: u8 = 0 << 5;
+pub(crate) const MAJOR_NEGATIVE: u8 = 1 << 5;
+pub(crate) const MAJOR_BYTES: u8 = 2 << 5;
+pub(crate) const MAJOR_TEXT: u8 = 3 << 5;
+pub(crate) const MAJOR_ARRAY: u8 = 4 << 5;
+pub(crate) const MAJOR_MAP: u8 = 5 << 5;
+pub(crate) const MAJOR_TAG: u8 = 6 << 5;
+pub(crate) const MAJOR_SIMPLE: u8 = 7 << 5;
+
+// Simple values and special markers
+pub(crate) const FALSE: u8 = MAJOR_SIMPLE | 20;
+pub(crate) const TRUE: u8 = MAJOR_SIMPLE | 21;
+pub(crate) const NULL: u8 = MAJOR_SIMPLE | 22;
+pub(crate) const UNDEFINED: u8 = MAJOR_SIMPLE | 23;
+pub(crate) const FLOAT16: u8 = MAJOR_SIMPLE | 25;
+pub(crate) const FLOAT32: u8 = MAJOR_SIMPLE | 26;
+pub(crate) const FLOAT64: u8 = MAJOR_SIMPLE | 27;
+pub(crate) const INDEFINITE_MAP: u8 = MAJOR_MAP | 31;
+pub(crate) const BREAK: u8 = 0xff;
+
+// Tags used by the Smithy RPC v2 CBOR data model
+pub(crate) const TAG_EPOCH_TIMESTAMP: u64 = 1;
+pub(crate) const TAG_POSITIVE_BIGNUM: u64 = 2;
+pub(crate) const TAG_NEGATIVE_BIGNUM: u64 = 3;
+pub(crate) const TAG_DECIMAL_FRACTION: u64 = 4;
+
+/// CBOR serializer that writes directly to a `Vec<u8>`.
+pub struct CborSerializer<'a> {
+    buf: &'a mut Vec<u8>,
+}
+
+impl<'a> CborSerializer<'a> {
+    /// Create a new CBOR serializer that writes to the given buffer.
+    ///
+    /// The buffer will be cleared before use.
+    #[inline]
+    pub fn new(buf: &'a mut Vec<u8>) -> Self {
+        buf.clear();
+        Self { buf }
+    }
+
+    /// Create a new CBOR serializer with a capacity hint.
+    ///
+    /// The buffer will be cleared and reserved to at least `capacity` bytes.
+    #[inline]
+    pub fn with_capacity(buf: &'a mut Vec<u8>, capacity: usize) -> Self {
+        buf.clear();
+        buf.reserve(capacity);
+        Self { buf }
+    }
+
+    /// Get the serialized CBOR bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buf
+    }
+}
+
+impl<'a> Serializer for CborSerializer<'a> {
+    type Error = CborSerdeError;
+    type Ok = ();
+    type ListWriter = CborListSerializer<'a>;
+    type MapWriter = CborMapSerializer<'a>;
+    type StructWriter = CborStructSerializer<'a>;
+
+    #[inline]
+    fn write_struct(
+        self,
+        _schema: &Schema,
+        _len: usize,
+    ) -> Result<Self::StructWriter, Self::Error> {
+        // The number of members actually written is not known up front
+        // (optional members may be skipped), so structures always use
+        // an indefinite-length map.
+        self.buf.push(INDEFINITE_MAP);
+        Ok(CborStructSerializer { buf: self.buf })
+    }
+
+    #[inline]
+    fn write_map(self, _schema: &Schema, len: usize) -> Result<Self::MapWriter, Self::Error> {
+        write_cbor_header(self.buf, MAJOR_MAP, len as u64);
+        Ok(CborMapSerializer { buf: self.buf })
+    }
+
+    #[inline]
+    fn write_list(self, _schema: &Schema, len: usize) -> Result<Self::ListWriter, Self::Error> {
+        write_cbor_header(self.buf, MAJOR_ARRAY, len as u64);
+        Ok(CborListSerializer { buf: self.buf })
+    }
+
+    #[inline]
+    fn write_boolean(self, _schema: &Schema, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.buf.push(if value { TRUE } else { FALSE });
+        Ok(())
+    }
+
+    #[inline]
+    fn write_byte(self, _schema: &Schema, value: i8) -> Result<Self::Ok, Self::Error> {
+        write_cbor_integer(self.buf, i64::from(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_short(self, _schema: &Schema, value: i16) -> Result<Self::Ok, Self::Error> {
+        write_cbor_integer(self.buf, i64::from(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_integer(self, _schema: &Schema, value: i32) -> Result<Self::Ok, Self::Error> {
+        write_cbor_integer(self.buf, i64::from(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_long(self, _schema: &Schema, value: i64) -> Result<Self::Ok, Self::Error> {
+        write_cbor_integer(self.buf, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_float(self, _schema: &Schema, value: f32) -> Result<Self::Ok, Self::Error> {
+        self.buf.push(FLOAT32);
+        self.buf.extend_from_slice(&value.to_be_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_double(self, _schema: &Schema, value: f64) -> Result<Self::Ok, Self::Error> {
+        write_cbor_double(self.buf, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_big_integer(self, _schema: &Schema, value: &BigInt) -> Result<Self::Ok, Self::Error> {
+        write_cbor_big_integer(self.buf, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_big_decimal(
+        self,
+        _schema: &Schema,
+        value: &BigDecimal,
+    ) -> Result<Self::Ok, Self::Error> {
+        // Decimal fractions are encoded as `[exponent, mantissa]` where
+        // the value is `mantissa * 10^exponent`
+        let (mantissa, scale) = value.as_bigint_and_exponent();
+        write_cbor_header(self.buf, MAJOR_TAG, TAG_DECIMAL_FRACTION);
+        write_cbor_header(self.buf, MAJOR_ARRAY, 2);
+        write_cbor_integer(self.buf, -scale);
+        write_cbor_big_integer(self.buf, &mantissa);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_string(self, _schema: &Schema, value: &str) -> Result<Self::Ok, Self::Error> {
+        write_cbor_header(self.buf, MAJOR_TEXT, value.len() as u64);
+        self.buf.extend_from_slice(value.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_blob(self, _schema: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
+        let bytes = value.as_bytes();
+        write_cbor_header(self.buf, MAJOR_BYTES, bytes.len() as u64);
+        self.buf.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_timestamp(self, _schema: &Schema, value: &Instant) -> Result<Self::Ok, Self::Error> {
+        // Timestamps are always encoded as epoch seconds with millisecond precision
+        write_cbor_header(self.buf, MAJOR_TAG, TAG_EPOCH_TIMESTAMP);
+        write_cbor_double(self.buf, value.epoch_milliseconds() as f64 / 1000.0);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_null(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.buf.push(NULL);
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        // Skip does nothing for CBOR
+        Ok(())
+    }
+}
+
+pub struct CborListSerializer<'a> {
+    buf: &'a mut Vec<u8>,
+}
+
+impl ListWriter for CborListSerializer<'_> {
+    type Error = CborSerdeError;
+    type Ok = ();
+
+    #[inline]
+    fn write_element<T>(&mut self, element_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        value.serialize_with_schema(element_schema, CborSerializer { buf: self.buf })
+    }
+
+    #[inline]
+    fn end(self, _schema: &Schema) -> Result<(), Self::Error> {
+        // Lists are definite-length so there is nothing to close.
+        Ok(())
+    }
+}
+
+pub struct CborMapSerializer<'a> {
+    buf: &'a mut Vec<u8>,
+}
+
+impl MapWriter for CborMapSerializer<'_> {
+    type Error = CborSerdeError;
+    type Ok = ();
+
+    #[inline]
+    fn write_entry<K, V>(
+        &mut self,
+        key_schema: &Schema,
+        value_schema: &Schema,
+        key: &K,
+        value: &V,
+    ) -> Result<(), Self::Error>
+    where
+        K: SerializeWithSchema,
+        V: SerializeWithSchema,
+    {
+        key.serialize_with_schema(key_schema, CborSerializer { buf: self.buf })?;
+        value.serialize_with_schema(value_schema, CborSerializer { buf: self.buf })
+    }
+
+    #[inline]
+    fn end(self, _schema: &Schema) -> Result<(), Self::Error> {
+        // Maps are definite-length so there is nothing to close.
+        Ok(())
+    }
+}
+
+pub struct CborStructSerializer<'a> {
+    buf: &'a mut Vec<u8>,
+}
+
+impl StructWriter for CborStructSerializer<'_> {
+    type Error = CborSerdeError;
+    type Ok = ();
+
+    #[inline]
+    fn write_member<T>(&mut self, member_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let member = member_schema.as_member().ok_or_else(|| {
+            CborSerdeError::SerializationError("Expected member schema".to_string())
+        })?;
+        self.write_member_named(member.name(), member_schema, value)
+    }
+
+    #[inline]
+    fn write_member_named<T>(
+        &mut self,
+        member_name: &str,
+        member_schema: &Schema,
+        value: &T,
+    ) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        write_cbor_header(self.buf, MAJOR_TEXT, member_name.len() as u64);
+        self.buf.extend_from_slice(member_name.as_bytes());
+        value.serialize_with_schema(member_schema, CborSerializer { buf: self.buf })
+    }
+
+    #[inline]
+    fn end(self, _schema: &Schema) -> Result<(), Self::Error> {
+        self.buf.push(BREAK);
+        Ok(())
+    }
+}
+
+/// Write the initial byte (and any following argument bytes) of a data item
+/// using the shortest possible encoding for the argument.
+#[inline]
+pub(crate) fn write_cbor_header(buf: &mut Vec<u8>, major: u8, value: u64) {
+    if value < 24 {
+        buf.push(major | value as u8);
+    } else if let Ok(v) = u8::try_from(value) {
+        buf.push(major | 24);
+        buf.push(v);
+    } else if let Ok(v) = u16::try_from(value) {
+        buf.push(major | 25);
+        buf.extend_from_slice(&v.to_be_bytes());
+    } else if let Ok(v) = u32::try_from(value) {
+        buf.push(major | 26);
+        buf.extend_from_slice(&v.to_be_bytes());
+    } else {
+        buf.push(major | 27);
+        buf.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+/// Write a signed integer as either a major type 0 or major type 1 data item.
+#[inline]
+fn write_cbor_integer(buf: &mut Vec<u8>, value: i64) {
+    if value >= 0 {
+        write_cbor_header(buf, MAJOR_UNSIGNED, value as u64);
+    } else {
+        // Negative integers are encoded as `-1 - n`
+        write_cbor_header(buf, MAJOR_NEGATIVE, !value as u64);
+    }
+}
+
+#[inline]
+fn write_cbor_double(buf: &mut Vec<u8>, value: f64) {
+    buf.push(FLOAT64);
+    buf.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Write a big integer, using a plain CBOR integer if it fits and a
+/// tagged bignum otherwise.
+fn write_cbor_big_integer(buf: &mut Vec<u8>, value: &BigInt) {
+    if let Ok(v) = i64::try_from(value) {
+        write_cbor_integer(buf, v);
+        return;
+    }
+    let (tag, magnitude) = match value.sign() {
+        Sign::Minus => (TAG_NEGATIVE_BIGNUM, (-value - 1u8).to_bytes_be().1),
+        _ => (TAG_POSITIVE_BIGNUM, value.to_bytes_be().1),
+    };
+    write_cbor_header(buf, MAJOR_TAG, tag);
+    write_cbor_header(buf, MAJOR_BYTES, magnitude.len() as u64);
+    buf.extend_from_slice(&magnitude);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_uses_shortest_encoding() {
+        let mut buf = Vec::new();
+        write_cbor_header(&mut buf, MAJOR_UNSIGNED, 23);
+        assert_eq!(buf, [0x17]);
+
+        buf.clear();
+        write_cbor_header(&mut buf, MAJOR_UNSIGNED, 24);
+        assert_eq!(buf, [0x18, 0x18]);
+
+        buf.clear();
+        write_cbor_header(&mut buf, MAJOR_UNSIGNED, 1000);
+        assert_eq!(buf, [0x19, 0x03, 0xe8]);
+
+        buf.clear();
+        write_cbor_header(&mut buf, MAJOR_UNSIGNED, 1_000_000);
+        assert_eq!(buf, [0x1a, 0x00, 0x0f, 0x42, 0x40]);
+    }
+
+    #[test]
+    fn test_negative_integers() {
+        let mut buf = Vec::new();
+        write_cbor_integer(&mut buf, -1);
+        assert_eq!(buf, [0x20]);
+
+        buf.clear();
+        write_cbor_integer(&mut buf, -100);
+        assert_eq!(buf, [0x38, 0x63]);
+    }
+
+    #[test]
+    fn test_big_integer_bignum() {
+        // 2^64 (RFC 8949 appendix A)
+        let mut buf = Vec::new();
+        let value = BigInt::from(u64::MAX) + 1u8;
+        write_cbor_big_integer(&mut buf, &value);
+        assert_eq!(
+            buf,
+            [
+                0xc2, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ]
+        );
+
+        // -2^64 - 1 (RFC 8949 appendix A)
+        buf.clear();
+        let value = -BigInt::from(u64::MAX) - 2u8;
+        write_cbor_big_integer(&mut buf, &value);
+        assert_eq!(
+            buf,
+            [
+                0xc3, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ]
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use std::str::FromStr;
+
+use smithy4rs_cbor_codec::{CborCodec, CborDeserializer, CborSerdeError, CborSerializer};
+use smithy4rs_core::{
+    BigDecimal, BigInt, IndexMap, Instant,
+    prelude::{BIG_DECIMAL, BIG_INTEGER, BLOB, TIMESTAMP},
+    schema::{Blob, Schema, Timestamp},
+    serde::{
+        Buildable, Codec, ShapeBuilder,
+        de::{DeserializeWithSchema, UnknownEnumPolicy},
+        pool::BufferPool,
+        serializers::SerializeWithSchema,
+    },
+};
+use smithy4rs_test_utils::*;
+
+// ============================================================================
+// Roundtrip Test Helpers
+// ============================================================================
+
+fn serialize_to_cbor<T: SerializeWithSchema>(value: &T, schema: &Schema) -> Vec<u8> {
+    let mut buf = Vec::new();
+    let serializer = CborSerializer::new(&mut buf);
+    value.serialize_with_schema(schema, serializer).unwrap();
+    buf
+}
+
+fn deserialize_from_cbor<'de, B: ShapeBuilder<'de, T>, T: Buildable<'de, B>>(
+    data: &'de [u8],
+    schema: &Schema,
+) -> T {
+    let mut de = CborDeserializer::new(data);
+    B::deserialize_with_schema(schema, &mut de)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+fn roundtrip<T, B>(value: &T, schema: &Schema) -> T
+where
+    B: for<'de> ShapeBuilder<'de, T>,
+    T: SerializeWithSchema + for<'de> Buildable<'de, B>,
+{
+    let cbor = serialize_to_cbor(value, schema);
+    deserialize_from_cbor(&cbor, schema)
+}
+
+// ============================================================================
+// Roundtrip Tests
+// ============================================================================
+
+#[test]
+fn test_optional_data_with_value() {
+    let data = OptionalFieldsStructBuilder::new()
+        .required_field("required".to_string())
+        .optional_field("optional".to_string())
+        .build()
+        .unwrap();
+
+    let result = roundtrip(&data, &OPTIONAL_FIELDS_STRUCT_SCHEMA);
+    assert_eq!(data, result);
+}
+
+#[test]
+fn test_optional_data_without_value() {
+    let data = OptionalFieldsStructBuilder::new()
+        .required_field("required".to_string())
+        .build()
+        .unwrap();
+
+    let result = roundtrip(&data, &OPTIONAL_FIELDS_STRUCT_SCHEMA);
+    assert_eq!(data, result);
+}
+
+#[test]
+fn test_numbers_edge_cases() {
+    let numbers = NumericTypesStructBuilder::new()
+        .byte_val(i8::MIN)
+        .short_val(i16::MIN)
+        .int_val(i32::MAX)
+        .long_val(i64::MIN)
+        .float_val(f32::MIN_POSITIVE)
+        .double_val(f64::MAX)
+        .build()
+        .unwrap();
+
+    // CBOR floats are binary, so values roundtrip exactly
+    let result = roundtrip(&numbers, &NUMERIC_TYPES_STRUCT_SCHEMA);
+    assert_eq!(numbers, result);
+}
+
+#[test]
+fn test_all_primitives() {
+    let data = AllPrimitivesStructBuilder::new()
+        .string_field("Müller 李明 🎉")
+        .byte_field(1i8)
+        .short_field(2i16)
+        .integer_field(3)
+        .long_field(4i64)
+        .float_field(5.5f32)
+        .double_field(6.25)
+        .boolean_field(true)
+        .blob_field(Blob::from(vec![0u8, 1, 2, 255]))
+        .timestamp_field(Instant::from_epoch_milliseconds(1_700_000_000_123).unwrap())
+        .build()
+        .unwrap();
+
+    let result = roundtrip(&data, &ALL_PRIMITIVES_STRUCT_SCHEMA);
+    assert_eq!(data, result);
+}
+
+#[test]
+fn test_nested_collections() {
+    let inner = InnerStructBuilder::new()
+        .field_a("a")
+        .field_b("b")
+        .field_c("c")
+        .build()
+        .unwrap();
+    let mut map = IndexMap::new();
+    map.insert("key".to_string(), inner.clone());
+    let data = NestedCollectionsStructBuilder::new()
+        .name("nested")
+        .count(2)
+        .single_nested(inner.clone())
+        .list_nested(vec![inner.clone(), inner])
+        .map_nested(map)
+        .build()
+        .unwrap();
+
+    let result = roundtrip(&data, &NESTED_COLLECTIONS_STRUCT_SCHEMA);
+    assert_eq!(data, result);
+}
+
+#[test]
+fn test_unknown_members_are_skipped() {
+    // {_ "field_a": "test", "extra": [1, {"x": h'00'}], "field_b": 42}
+    let mut data = vec![0xbf, 0x67];
+    data.extend_from_slice(b"field_a");
+    data.push(0x64);
+    data.extend_from_slice(b"test");
+    data.push(0x65);
+    data.extend_from_slice(b"extra");
+    data.extend_from_slice(&[0x82, 0x01, 0xa1, 0x61, b'x', 0x41, 0x00]);
+    data.push(0x67);
+    data.extend_from_slice(b"field_b");
+    data.extend_from_slice(&[0x18, 0x2a, 0xff]);
+
+    let result: SimpleStruct = deserialize_from_cbor(&data, &SIMPLE_STRUCT_SCHEMA);
+    assert_eq!(result.field_a, "test");
+    assert_eq!(result.field_b, 42);
+}
+
+#[test]
+fn test_strict_mode_rejects_unknown_members() {
+    // {_ "field_a": "test", "extra": 1, "field_b": 42}
+    let mut data = vec![0xbf, 0x67];
+    data.extend_from_slice(b"field_a");
+    data.push(0x64);
+    data.extend_from_slice(b"test");
+    data.push(0x65);
+    data.extend_from_slice(b"extra");
+    data.push(0x01);
+    data.push(0x67);
+    data.extend_from_slice(b"field_b");
+    data.extend_from_slice(&[0x18, 0x2a, 0xff]);
+
+    let mut de = CborDeserializer::new(&data).with_strict_members(true);
+    let result = SimpleStructBuilder::deserialize_with_schema(&SIMPLE_STRUCT_SCHEMA, &mut de);
+    assert!(matches!(
+        result,
+        Err(CborSerdeError::UnknownMembers { members, .. }) if members == ["extra"]
+    ));
+}
+
+#[test]
+fn test_pre_epoch_timestamps() {
+    let data = AllPrimitivesStructBuilder::new()
+        .string_field("before")
+        .byte_field(1i8)
+        .short_field(2i16)
+        .integer_field(3)
+        .long_field(4i64)
+        .float_field(5.5f32)
+        .double_field(6.25)
+        .boolean_field(false)
+        .blob_field(Blob::new())
+        .timestamp_field(Instant::from_epoch_milliseconds(-1_234_567_890_123).unwrap())
+        .build()
+        .unwrap();
+    let result = roundtrip(&data, &ALL_PRIMITIVES_STRUCT_SCHEMA);
+    assert_eq!(data, result);
+
+    for millis in [-1, -1_500, -86_400_000] {
+        let timestamp = Timestamp::from_epoch_millis(millis).unwrap();
+        let cbor = serialize_to_cbor(&timestamp, &TIMESTAMP);
+        let mut de = CborDeserializer::new(&cbor);
+        assert_eq!(
+            Timestamp::deserialize_with_schema(&TIMESTAMP, &mut de).unwrap(),
+            timestamp
+        );
+    }
+}
+
+#[test]
+fn test_big_numbers() {
+    let big_int = BigInt::from_str("-123456789012345678901234567890").unwrap();
+    let cbor = serialize_to_cbor(&big_int, &BIG_INTEGER);
+    let mut de = CborDeserializer::new(&cbor);
+    assert_eq!(
+        BigInt::deserialize_with_schema(&BIG_INTEGER, &mut de).unwrap(),
+        big_int
+    );
+
+    let big_decimal = BigDecimal::from_str("-1234567890123456789.0123456789").unwrap();
+    let cbor = serialize_to_cbor(&big_decimal, &BIG_DECIMAL);
+    let mut de = CborDeserializer::new(&cbor);
+    assert_eq!(
+        BigDecimal::deserialize_with_schema(&BIG_DECIMAL, &mut de).unwrap(),
+        big_decimal
+    );
+}
+
+#[test]
+fn test_union() {
+    let data = TestUnion::B(42);
+    let cbor = serialize_to_cbor(&data, &UNION);
+    let mut de = CborDeserializer::new(&cbor);
+    let result = TestUnion::deserialize_with_schema(&UNION, &mut de).unwrap();
+    assert_eq!(data, result);
+}
+
+#[test]
+fn test_union_unknown_variant() {
+    let data = TestUnion::Unknown("d".to_string(), vec![1i32, 2i32].into());
+    let cbor = serialize_to_cbor(&data, &UNION);
+    // {_ "d": [1, 2]}
+    assert_eq!(cbor, vec![0xbf, 0x61, b'd', 0x82, 0x01, 0x02, 0xff]);
+}
+
+#[test]
+fn test_blob_drawn_from_buffer_pool() {
+    let pool = BufferPool::new();
+    let recycled = pool.take(64);
+    let ptr = recycled.as_ptr();
+    pool.give(recycled);
+
+    let value = Blob::from(vec![0u8, 1, 2, 255]);
+    let cbor = serialize_to_cbor(&value, &BLOB);
+    let mut de = CborDeserializer::new(&cbor).with_buffer_pool(pool.clone());
+    let blob = Blob::deserialize_with_schema(&BLOB, &mut de).unwrap();
+    assert_eq!(blob.as_bytes(), value.as_bytes());
+    assert_eq!(blob.as_bytes().as_ptr(), ptr);
+
+    pool.recycle(blob);
+    assert_eq!(pool.len(), 1);
+}
+
+#[test]
+fn test_codec_reads_documents() {
+    let codec: &dyn Codec = &CborCodec;
+    let value = NestedCollectionsStruct {
+        name: "doc".to_string(),
+        count: 1,
+        single_nested: InnerStruct {
+            field_a: "a".to_string(),
+            field_b: "b".to_string(),
+            field_c: "c".to_string(),
+        },
+        optional_nested: None,
+        list_nested: Vec::new(),
+        map_nested: IndexMap::new(),
+    };
+    let bytes = codec.serialize_shape(value.clone()).unwrap();
+    let document = codec
+        .deserialize_document(&NESTED_COLLECTIONS_STRUCT_SCHEMA, &bytes)
+        .unwrap();
+    assert_eq!(
+        document
+            .as_map()
+            .and_then(|map| map.get("name"))
+            .and_then(|name| name.as_string()),
+        Some("doc")
+    );
+    assert_eq!(
+        codec
+            .serialize_document(&NESTED_COLLECTIONS_STRUCT_SCHEMA, &document)
+            .unwrap(),
+        bytes
+    );
+    assert_eq!(
+        codec
+            .deserialize_shape::<NestedCollectionsStruct, NestedCollectionsStructBuilder>(&bytes)
+            .unwrap(),
+        value
+    );
+}
+
+#[test]
+fn test_unknown_enum_policy() {
+    let data = serialize_to_cbor(&TestEnum::Unknown("d".to_string()), &SIMPLE_ENUM);
+    let mut de = CborDeserializer::new(&data);
+    let result = TestEnum::deserialize_with_schema(&SIMPLE_ENUM, &mut de).unwrap();
+    assert_eq!(result, TestEnum::Unknown("d".to_string()));
+
+    let mut de = CborDeserializer::new(&data).with_unknown_enum_policy(UnknownEnumPolicy::Error);
+    assert!(TestEnum::deserialize_with_schema(&SIMPLE_ENUM, &mut de).is_err());
+}
@@ -0,0 +1,537 @@
+//! Capture and replay of the requests and responses exchanged by a client.
+//!
+//! A [`CaptureInterceptor`] records every exchange made by a [`Client`](crate::Client)
+//! into a [`CaptureSink`]. Credentials are redacted before an exchange is recorded,
+//! so captures can be attached to bug reports:
+//!
+//! ```rust,ignore
+//! let sink = JsonLinesSink::new(File::create("capture.jsonl")?);
+//! let client = Client::new(RestJson1::new(), &CITY_SERVICE, transport)
+//!     .with_interceptor(CaptureInterceptor::new(sink));
+//! ```
+//!
+//! Captured responses can then be replayed with a [`MockTransport`](crate::MockTransport)
+//! to reproduce the behavior of the client deterministically:
+//!
+//! ```rust,ignore
+//! let exchanges = read_exchanges(&fs::read("capture.jsonl")?)?;
+//! let client = Client::new(RestJson1::new(), &CITY_SERVICE, MockTransport::replay(exchanges)?);
+//! ```
+//!
+//! Header values marked as sensitive, and the values of the headers and query parameters
+//! configured on the interceptor, are replaced with `**REDACTED**`. Bodies are captured
+//! as is, and can be excluded with [`CaptureInterceptor::with_bodies`].
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    io::{self, Write},
+    sync::{
+        Arc, Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use http::{
+    HeaderMap, HeaderName, Request, Response, StatusCode,
+    header::{AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION, SET_COOKIE},
+};
+use percent_encoding::percent_decode_str;
+use smithy4rs_core::{
+    IndexMap,
+    derive::SmithyShape,
+    prelude::{BLOB, INTEGER, RequiredTrait, STRING},
+    schema::Blob,
+    serde::{CodecError, de::DeserializeWithSchema, se::SerializeWithSchema},
+    smithy,
+};
+use smithy4rs_json_codec::{JsonDeserializer, JsonSerializer};
+
+use crate::interceptor::{CorrelationId, Interceptor};
+
+const REDACTED: &str = "**REDACTED**";
+
+/// Headers that are redacted by default.
+const REDACTED_HEADERS: [HeaderName; 6] = [
+    AUTHORIZATION,
+    PROXY_AUTHORIZATION,
+    COOKIE,
+    SET_COOKIE,
+    HeaderName::from_static("x-api-key"),
+    HeaderName::from_static("x-amz-security-token"),
+];
+
+// ============================================================================
+// Captured Exchanges
+// ============================================================================
+
+smithy!("smithy4rs.client#CapturedHeaders": {
+    map CAPTURED_HEADERS_SCHEMA {
+        key: STRING
+        value: STRING
+    }
+});
+
+smithy!("smithy4rs.client#CapturedExchange": {
+    structure CAPTURED_EXCHANGE_SCHEMA {
+        @RequiredTrait::builder().build();
+        CORRELATION_ID: STRING = "correlation_id"
+        @RequiredTrait::builder().build();
+        METHOD: STRING = "method"
+        @RequiredTrait::builder().build();
+        URI: STRING = "uri"
+        REQUEST_HEADERS: CAPTURED_HEADERS_SCHEMA = "request_headers"
+        REQUEST_BODY: BLOB = "request_body"
+        STATUS: INTEGER = "status"
+        RESPONSE_HEADERS: CAPTURED_HEADERS_SCHEMA = "response_headers"
+        RESPONSE_BODY: BLOB = "response_body"
+        ERROR: STRING = "error"
+    }
+});
+
+/// A redacted request, and the response or transport error it received.
+#[derive(SmithyShape, Clone, PartialEq)]
+#[smithy_schema(CAPTURED_EXCHANGE_SCHEMA)]
+pub struct CapturedExchange {
+    /// Correlation ID of the call that sent the request
+    #[smithy_schema(CORRELATION_ID)]
+    pub correlation_id: String,
+    /// Request method
+    #[smithy_schema(METHOD)]
+    pub method: String,
+    /// Request URI, with redacted query parameters
+    #[smithy_schema(URI)]
+    pub uri: String,
+    /// Redacted request headers. Repeated headers are joined with `, `.
+    #[smithy_schema(REQUEST_HEADERS)]
+    pub request_headers: Option<IndexMap<String, String>>,
+    /// Request body, unless bodies are not captured
+    #[smithy_schema(REQUEST_BODY)]
+    pub request_body: Option<Blob>,
+    /// Response status code, if a response was received
+    #[smithy_schema(STATUS)]
+    pub status: Option<i32>,
+    /// Redacted response headers
+    #[smithy_schema(RESPONSE_HEADERS)]
+    pub response_headers: Option<IndexMap<String, String>>,
+    /// Response body, unless bodies are not captured
+    #[smithy_schema(RESPONSE_BODY)]
+    pub response_body: Option<Blob>,
+    /// Transport error returned instead of a response
+    #[smithy_schema(ERROR)]
+    pub error: Option<String>,
+}
+
+impl CapturedExchange {
+    /// Rebuild the response (or transport error) that was captured.
+    ///
+    /// Transport errors are rebuilt as [`io::ErrorKind::Other`] errors with the captured message.
+    ///
+    /// # Errors
+    /// Returns the captured transport error, or an [`io::ErrorKind::InvalidData`] error
+    /// if the captured response is invalid.
+    pub fn to_response(&self) -> io::Result<Response<Vec<u8>>> {
+        let Some(status) = self.status else {
+            return Err(io::Error::other(
+                self.error.as_deref().unwrap_or("No response captured"),
+            ));
+        };
+        let mut builder = Response::builder().status(
+            u16::try_from(status)
+                .ok()
+                .and_then(|status| StatusCode::from_u16(status).ok())
+                .ok_or_else(|| invalid(format!("Invalid status code `{status}`")))?,
+        );
+        for (name, value) in self.response_headers.iter().flatten() {
+            builder = builder.header(name, value);
+        }
+        let body = self
+            .response_body
+            .as_ref()
+            .map(|body| body.as_bytes().to_vec())
+            .unwrap_or_default();
+        builder.body(body).map_err(invalid)
+    }
+}
+
+fn invalid(error: impl ToString) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error.to_string())
+}
+
+/// Read exchanges written by a [`JsonLinesSink`].
+///
+/// # Errors
+/// Returns a [`CodecError`] if any line is not a valid [`CapturedExchange`].
+pub fn read_exchanges(data: &[u8]) -> Result<Vec<CapturedExchange>, CodecError> {
+    data.split(|byte| *byte == b'\n')
+        .filter(|line| !line.trim_ascii().is_empty())
+        .map(|line| {
+            Ok(CapturedExchangeBuilder::deserialize_with_schema(
+                &CAPTURED_EXCHANGE_SCHEMA,
+                &mut JsonDeserializer::new(line),
+            )?
+            .build()?)
+        })
+        .collect()
+}
+
+// ============================================================================
+// Sinks
+// ============================================================================
+
+/// Destination of the exchanges recorded by a [`CaptureInterceptor`].
+pub trait CaptureSink: Send + Sync + 'static {
+    /// Record a completed exchange.
+    ///
+    /// # Errors
+    /// Returns an [`io::Error`] if the exchange could not be recorded.
+    fn record(&self, exchange: CapturedExchange) -> io::Result<()>;
+}
+
+/// A [`CaptureSink`] that keeps exchanges in memory.
+///
+/// Cloning a memory sink shares the same exchanges.
+#[derive(Clone, Default)]
+pub struct MemorySink {
+    exchanges: Arc<Mutex<Vec<CapturedExchange>>>,
+}
+
+impl MemorySink {
+    /// Create an empty memory sink.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take all exchanges recorded so far.
+    #[must_use]
+    pub fn take(&self) -> Vec<CapturedExchange> {
+        std::mem::take(&mut *lock(&self.exchanges))
+    }
+
+    /// Number of exchanges recorded and not yet taken.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        lock(&self.exchanges).len()
+    }
+
+    /// Returns true if no exchanges have been recorded since the last [`MemorySink::take`].
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl CaptureSink for MemorySink {
+    fn record(&self, exchange: CapturedExchange) -> io::Result<()> {
+        lock(&self.exchanges).push(exchange);
+        Ok(())
+    }
+}
+
+impl Debug for MemorySink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemorySink")
+            .field("exchanges", &self.len())
+            .finish()
+    }
+}
+
+/// A [`CaptureSink`] that writes each exchange to a writer as a line of JSON.
+///
+/// Written exchanges can be read back with [`read_exchanges`].
+pub struct JsonLinesSink<W> {
+    writer: Mutex<W>,
+}
+
+impl<W: Write + Send + 'static> JsonLinesSink<W> {
+    /// Create a sink that writes exchanges to `writer`.
+    #[must_use]
+    pub fn new(writer: W) -> Self {
+        JsonLinesSink {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    /// Get the underlying writer.
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.writer
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<W: Write + Send + 'static> CaptureSink for JsonLinesSink<W> {
+    fn record(&self, exchange: CapturedExchange) -> io::Result<()> {
+        let mut line = Vec::new();
+        exchange
+            .serialize_with_schema(&CAPTURED_EXCHANGE_SCHEMA, JsonSerializer::new(&mut line))
+            .map_err(invalid)?;
+        line.push(b'\n');
+        let mut writer = lock(&self.writer);
+        writer.write_all(&line)?;
+        writer.flush()
+    }
+}
+
+impl<W> Debug for JsonLinesSink<W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonLinesSink").finish_non_exhaustive()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // Sinks are never left in an inconsistent state
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// ============================================================================
+// Interceptor
+// ============================================================================
+
+/// An [`Interceptor`] that records redacted exchanges into a [`CaptureSink`].
+///
+/// Exchanges are recorded once a response or transport error is received, or when the
+/// call is cancelled (with the error `Call was cancelled`). Errors
+/// returned by the sink do not fail the call, and are counted by [`CaptureInterceptor::failures`].
+pub struct CaptureInterceptor<S> {
+    sink: S,
+    headers: Vec<HeaderName>,
+    query: Vec<String>,
+    bodies: bool,
+    pending: Mutex<HashMap<CorrelationId, CapturedExchange>>,
+    failures: AtomicU64,
+}
+
+impl<S: CaptureSink> CaptureInterceptor<S> {
+    /// Create an interceptor that records exchanges into `sink`.
+    ///
+    /// The `Authorization`, `Proxy-Authorization`, `Cookie`, `Set-Cookie`, `X-Api-Key`,
+    /// and `X-Amz-Security-Token` headers are redacted by default.
+    #[must_use]
+    pub fn new(sink: S) -> Self {
+        CaptureInterceptor {
+            sink,
+            headers: REDACTED_HEADERS.to_vec(),
+            query: Vec::new(),
+            bodies: true,
+            pending: Mutex::new(HashMap::new()),
+            failures: AtomicU64::new(0),
+        }
+    }
+
+    /// Redact the value of a header.
+    pub fn redact_header(&mut self, name: HeaderName) -> &mut Self {
+        self.headers.push(name);
+        self
+    }
+
+    /// Builder-style version of [`CaptureInterceptor::redact_header`].
+    #[must_use]
+    pub fn with_redacted_header(mut self, name: HeaderName) -> Self {
+        self.redact_header(name);
+        self
+    }
+
+    /// Redact the value of a query parameter (for example, an API key sent in the query).
+    pub fn redact_query_param(&mut self, name: impl Into<String>) -> &mut Self {
+        self.query.push(name.into());
+        self
+    }
+
+    /// Builder-style version of [`CaptureInterceptor::redact_query_param`].
+    #[must_use]
+    pub fn with_redacted_query_param(mut self, name: impl Into<String>) -> Self {
+        self.redact_query_param(name);
+        self
+    }
+
+    /// Set whether request and response bodies are captured.
+    ///
+    /// Bodies are captured by default. Bodies are not redacted, so they should not be
+    /// captured for operations with `@sensitive` members.
+    pub fn set_bodies(&mut self, bodies: bool) -> &mut Self {
+        self.bodies = bodies;
+        self
+    }
+
+    /// Builder-style version of [`CaptureInterceptor::set_bodies`].
+    #[must_use]
+    pub fn with_bodies(mut self, bodies: bool) -> Self {
+        self.set_bodies(bodies);
+        self
+    }
+
+    /// Get the sink exchanges are recorded into.
+    #[must_use]
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Number of exchanges the sink failed to record.
+    #[must_use]
+    pub fn failures(&self) -> u64 {
+        self.failures.load(Ordering::Relaxed)
+    }
+
+    fn headers(&self, headers: &HeaderMap) -> IndexMap<String, String> {
+        let mut captured = IndexMap::<String, String>::new();
+        for (name, value) in headers {
+            let value = if value.is_sensitive() || self.headers.contains(name) {
+                REDACTED.into()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            captured
+                .entry(name.as_str().to_string())
+                .and_modify(|existing| {
+                    existing.push_str(", ");
+                    existing.push_str(&value);
+                })
+                .or_insert(value);
+        }
+        captured
+    }
+
+    fn uri(&self, request: &Request<Vec<u8>>) -> String {
+        let uri = request.uri();
+        let Some(query) = uri.query().filter(|_| !self.query.is_empty()) else {
+            return uri.to_string();
+        };
+        let query = query
+            .split('&')
+            .map(|pair| {
+                let name = pair.split_once('=').map_or(pair, |(name, _)| name);
+                if self
+                    .query
+                    .iter()
+                    .any(|redacted| percent_decode_str(name).decode_utf8_lossy() == *redacted)
+                {
+                    format!("{name}={REDACTED}")
+                } else {
+                    pair.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("&");
+        let base = uri.to_string();
+        let base = base.split_once('?').map_or(base.as_str(), |(base, _)| base);
+        format!("{base}?{query}")
+    }
+
+    fn record(&self, exchange: CapturedExchange) {
+        if self.sink.record(exchange).is_err() {
+            self.failures.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn body(&self, body: &[u8]) -> Option<Blob> {
+        self.bodies.then(|| Blob::copy_from_slice(body))
+    }
+}
+
+impl<S: CaptureSink> Interceptor for CaptureInterceptor<S> {
+    fn read_before_transmit(&self, id: CorrelationId, request: &Request<Vec<u8>>) {
+        let exchange = CapturedExchange {
+            correlation_id: id.to_string(),
+            method: request.method().to_string(),
+            uri: self.uri(request),
+            request_headers: Some(self.headers(request.headers())),
+            request_body: self.body(request.body()),
+            status: None,
+            response_headers: None,
+            response_body: None,
+            error: None,
+        };
+        lock(&self.pending).insert(id, exchange);
+    }
+
+    fn read_after_transmit(
+        &self,
+        id: CorrelationId,
+        response: Result<&Response<Vec<u8>>, &io::Error>,
+    ) {
+        let Some(mut exchange) = lock(&self.pending).remove(&id) else {
+            return;
+        };
+        match response {
+            Ok(response) => {
+                exchange.status = Some(i32::from(response.status().as_u16()));
+                exchange.response_headers = Some(self.headers(response.headers()));
+                exchange.response_body = self.body(response.body());
+            }
+            Err(error) => exchange.error = Some(error.to_string()),
+        }
+        self.record(exchange);
+    }
+
+    fn read_after_cancel(&self, id: CorrelationId) {
+        let Some(mut exchange) = lock(&self.pending).remove(&id) else {
+            return;
+        };
+        exchange.error = Some("Call was cancelled".into());
+        self.record(exchange);
+    }
+}
+
+impl<S: Debug> Debug for CaptureInterceptor<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CaptureInterceptor")
+            .field("sink", &self.sink)
+            .field("headers", &self.headers)
+            .field("query", &self.query)
+            .field("bodies", &self.bodies)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{HeaderValue, header::CONTENT_TYPE};
+
+    use super::*;
+
+    fn interceptor() -> CaptureInterceptor<MemorySink> {
+        CaptureInterceptor::new(MemorySink::new())
+    }
+
+    #[test]
+    fn redacts_query_params() {
+        let interceptor = interceptor().with_redacted_query_param("api key");
+        let request = Request::builder()
+            .uri("https://example.com/cities?page=2&api%20key=secret")
+            .body(Vec::new())
+            .unwrap();
+        assert_eq!(
+            interceptor.uri(&request),
+            "https://example.com/cities?page=2&api%20key=**REDACTED**"
+        );
+    }
+
+    #[test]
+    fn redacts_sensitive_and_configured_headers() {
+        let interceptor = interceptor().with_redacted_header(HeaderName::from_static("x-secret"));
+        let mut sensitive = HeaderValue::from_static("token");
+        sensitive.set_sensitive(true);
+        let mut headers = HeaderMap::new();
+        headers.insert("x-session", sensitive);
+        headers.insert("x-secret", HeaderValue::from_static("secret"));
+        headers.insert("x-api-key", HeaderValue::from_static("key"));
+        headers.append(CONTENT_TYPE, HeaderValue::from_static("a"));
+        headers.append(CONTENT_TYPE, HeaderValue::from_static("b"));
+
+        let captured = interceptor.headers(&headers);
+        assert_eq!(captured["x-session"], REDACTED);
+        assert_eq!(captured["x-secret"], REDACTED);
+        assert_eq!(captured["x-api-key"], REDACTED);
+        assert_eq!(captured["content-type"], "a, b");
+    }
+
+    #[test]
+    fn ignores_unmatched_responses() {
+        let interceptor = interceptor();
+        let response = Response::new(Vec::new());
+        interceptor.read_after_transmit(CorrelationId::new(7), Ok(&response));
+        assert!(interceptor.sink().is_empty());
+    }
+}
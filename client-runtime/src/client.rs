@@ -0,0 +1,370 @@
+use std::{
+    fmt::{Debug, Formatter},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use http::{Request, Response, Uri, uri::PathAndQuery};
+use smithy4rs_core::{
+    prelude::HttpTrait,
+    retry::{NoRetry, RetryPolicies},
+    schema::{Operation, Service, StaticSchemaShape},
+    serde::{Buildable, ShapeBuilder, ShapeRegistry, se::SerializeWithSchema},
+};
+use smithy4rs_http_binding::{
+    HttpBindingError,
+    auth::AuthSchemeRegistry,
+    compression::{CompressionRegistry, Gzip, Zstd},
+};
+
+use crate::{
+    errors::{ClientError, OperationError},
+    interceptor::{CorrelationId, Interceptor},
+    protocol::ClientProtocol,
+    transport::Transport,
+};
+
+/// Calls the operations of a service.
+///
+/// Each call:
+/// 1. Serializes the operation input into a request with a [`ClientProtocol`].
+/// 2. Compresses the request body if the operation has the `@requestCompression` trait.
+/// 3. Sends the request to the client's endpoint, if one is set.
+/// 4. Signs the request with the auth scheme selected for the operation by the
+///    client's [`AuthSchemeRegistry`].
+/// 5. Sends the request with a [`Transport`], calling each [`Interceptor`] before the
+///    request is sent and after its response is received.
+/// 6. Decompresses the response body with the encodings in its `Content-Encoding` header.
+/// 7. Deserializes the operation output, or the modeled error for unsuccessful responses.
+/// 8. Retries failed requests as allowed by the [`RetryPolicy`](smithy4rs_core::retry::RetryPolicy)
+///    of the operation, repeating steps 1-7.
+///
+/// ```rust,ignore
+/// let client = Client::new(RestJson1::new(), &CITY_SERVICE, transport)
+///     .with_endpoint(Uri::from_static("https://cities.example.com"))
+///     .with_auth(AuthSchemeRegistry::new().with(DefaultAuthScheme::bearer(token)))
+///     .with_retry(RetryPolicies::new(ExponentialBackoff::new()));
+///
+/// let errors = ShapeRegistry::new().with(GetCityError::NoSuchCity);
+/// let city: GetCityOutput = client.call(&GET_CITY, &input, &errors).await?;
+/// ```
+pub struct Client<P, T> {
+    protocol: P,
+    service: &'static Service,
+    transport: T,
+    endpoint: Option<Uri>,
+    auth: AuthSchemeRegistry,
+    compression: CompressionRegistry,
+    retry: RetryPolicies,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    next_id: AtomicU64,
+}
+
+impl<P: ClientProtocol, T: Transport> Client<P, T> {
+    /// Create a client that calls operations of `service` using `protocol`, and sends
+    /// requests with `transport`.
+    ///
+    /// Requests are not signed unless auth schemes are registered with [`Client::with_auth`],
+    /// and are not retried unless retry policies are set with [`Client::with_retry`].
+    /// Requests and responses are compressed with `gzip` or `zstd`.
+    #[must_use]
+    pub fn new(protocol: P, service: &'static Service, transport: T) -> Self {
+        Client {
+            protocol,
+            service,
+            transport,
+            endpoint: None,
+            auth: AuthSchemeRegistry::new(),
+            compression: CompressionRegistry::new()
+                .with(Gzip::default())
+                .with(Zstd::default()),
+            retry: RetryPolicies::new(NoRetry),
+            interceptors: Vec::new(),
+            next_id: AtomicU64::new(0),
+        }
+    }
+
+    /// Send requests to `endpoint`.
+    ///
+    /// The scheme and authority of the endpoint are used for every request, and the
+    /// path of each operation is appended to the path of the endpoint.
+    pub fn set_endpoint(&mut self, endpoint: Uri) -> &mut Self {
+        self.endpoint = Some(endpoint);
+        self
+    }
+
+    /// Builder-style version of [`Client::set_endpoint`].
+    #[must_use]
+    pub fn with_endpoint(mut self, endpoint: Uri) -> Self {
+        self.set_endpoint(endpoint);
+        self
+    }
+
+    /// Sign requests with the auth schemes in `auth`.
+    pub fn set_auth(&mut self, auth: AuthSchemeRegistry) -> &mut Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Builder-style version of [`Client::set_auth`].
+    #[must_use]
+    pub fn with_auth(mut self, auth: AuthSchemeRegistry) -> Self {
+        self.set_auth(auth);
+        self
+    }
+
+    /// Compress requests and decompress responses with the algorithms in `compression`.
+    ///
+    /// Requests are only compressed for operations with the `@requestCompression` trait.
+    pub fn set_compression(&mut self, compression: CompressionRegistry) -> &mut Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Builder-style version of [`Client::set_compression`].
+    #[must_use]
+    pub fn with_compression(mut self, compression: CompressionRegistry) -> Self {
+        self.set_compression(compression);
+        self
+    }
+
+    /// Retry failed calls with the policy `retry` selects for each operation.
+    pub fn set_retry(&mut self, retry: RetryPolicies) -> &mut Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Builder-style version of [`Client::set_retry`].
+    #[must_use]
+    pub fn with_retry(mut self, retry: RetryPolicies) -> Self {
+        self.set_retry(retry);
+        self
+    }
+
+    /// Add an interceptor that observes requests and responses.
+    ///
+    /// Interceptors are called in the order they are added.
+    pub fn add_interceptor(&mut self, interceptor: impl Interceptor) -> &mut Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Builder-style version of [`Client::add_interceptor`].
+    #[must_use]
+    pub fn with_interceptor(mut self, interceptor: impl Interceptor) -> Self {
+        self.add_interceptor(interceptor);
+        self
+    }
+
+    /// Get the protocol used to serialize requests.
+    #[must_use]
+    pub fn protocol(&self) -> &P {
+        &self.protocol
+    }
+
+    /// Get the transport used to send requests.
+    #[must_use]
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Get the auth schemes used to sign requests.
+    #[must_use]
+    pub fn auth(&self) -> &AuthSchemeRegistry {
+        &self.auth
+    }
+
+    /// Get the algorithms used to compress requests and decompress responses.
+    #[must_use]
+    pub fn compression(&self) -> &CompressionRegistry {
+        &self.compression
+    }
+
+    /// Get the policies used to retry failed calls.
+    #[must_use]
+    pub fn retry(&self) -> &RetryPolicies {
+        &self.retry
+    }
+
+    /// Serialize, compress, and sign the request for an operation without sending it.
+    ///
+    /// # Errors
+    /// Returns a [`ClientError`] if the input could not be serialized, or the
+    /// request could not be compressed or signed.
+    pub fn prepare<I, O, E>(
+        &self,
+        operation: &Operation<I, O, E>,
+        input: &I,
+    ) -> Result<Request<Vec<u8>>, ClientError<E>>
+    where
+        I: StaticSchemaShape + SerializeWithSchema,
+    {
+        let http = operation
+            .schema()
+            .get_trait_as::<HttpTrait>()
+            .ok_or_else(|| HttpBindingError::MissingHttpTrait(operation.id().id().to_string()))?;
+        let mut request = self
+            .protocol
+            .serialize_input(http, input)
+            .map_err(ClientError::Serialization)?;
+        self.compression
+            .compress_request(operation.schema(), &mut request)?;
+        if let Some(endpoint) = &self.endpoint {
+            *request.uri_mut() = resolve(endpoint, request.uri())?;
+        }
+        self.auth
+            .sign(self.service.schema(), operation.schema(), &mut request)?;
+        Ok(request)
+    }
+
+    /// Call an operation, returning its output.
+    ///
+    /// Unsuccessful responses are deserialized into one of the modeled `errors`
+    /// of the operation. Failed attempts are classified with [`ClientError::retry_kind`]
+    /// and retried after the delay given by the operation's retry policy. Each attempt
+    /// is serialized and signed again, and has its own [`CorrelationId`].
+    ///
+    /// # Cancellation
+    /// The returned future can be dropped at any point, for example by `select!` or a
+    /// timeout. The client keeps no state for in-flight calls, so dropping a call does not
+    /// affect other calls made with the client. If the request was already passed to the
+    /// transport, the transport's send future is dropped, and each interceptor is notified
+    /// with [`Interceptor::read_after_cancel`].
+    ///
+    /// # Errors
+    /// Returns [`ClientError::Service`] if the service returned a modeled error, or
+    /// another [`ClientError`] if the request could not be sent or the response
+    /// could not be read. If the call was retried, the error of the last attempt
+    /// is returned.
+    pub async fn call<I, O, B, E>(
+        &self,
+        operation: &Operation<I, O, E>,
+        input: &I,
+        errors: &ShapeRegistry<E>,
+    ) -> Result<O, ClientError<E>>
+    where
+        I: StaticSchemaShape + SerializeWithSchema,
+        O: for<'de> Buildable<'de, B>,
+        B: for<'de> ShapeBuilder<'de, O>,
+        E: OperationError,
+    {
+        let policy = self.retry.for_operation(operation.id());
+        let mut attempt = 1;
+        loop {
+            let error = match self.attempt(operation, input, errors).await {
+                Ok(output) => return Ok(output),
+                Err(error) => error,
+            };
+            let Some(delay) = policy.retry_delay(attempt, error.retry_kind()) else {
+                return Err(error);
+            };
+            self.transport.sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Send a single request for an operation and read its response.
+    async fn attempt<I, O, B, E>(
+        &self,
+        operation: &Operation<I, O, E>,
+        input: &I,
+        errors: &ShapeRegistry<E>,
+    ) -> Result<O, ClientError<E>>
+    where
+        I: StaticSchemaShape + SerializeWithSchema,
+        O: for<'de> Buildable<'de, B>,
+        B: for<'de> ShapeBuilder<'de, O>,
+    {
+        let mut request = self.prepare(operation, input)?;
+        let id = CorrelationId::new(self.next_id.fetch_add(1, Ordering::Relaxed));
+        request.extensions_mut().insert(id);
+        for interceptor in &self.interceptors {
+            interceptor.read_before_transmit(id, &request);
+        }
+        let in_flight = InFlight {
+            id,
+            interceptors: &self.interceptors,
+        };
+        let response = self.transport.send(request).await;
+        std::mem::forget(in_flight);
+        for interceptor in &self.interceptors {
+            interceptor.read_after_transmit(id, response.as_ref());
+        }
+        let (mut parts, mut body) = response.map_err(ClientError::Transport)?.into_parts();
+        self.compression.decompress(&mut parts.headers, &mut body)?;
+        let response = Response::from_parts(parts, body);
+        if response.status().is_success() {
+            self.protocol
+                .deserialize_output(&response)
+                .map_err(ClientError::Deserialization)
+        } else {
+            match self.protocol.deserialize_error(&response, errors) {
+                Ok(error) => Err(ClientError::Service(error)),
+                Err(error) => Err(ClientError::Deserialization(error)),
+            }
+        }
+    }
+}
+
+/// Notifies interceptors if a call is dropped while its request is in flight.
+struct InFlight<'a> {
+    id: CorrelationId,
+    interceptors: &'a [Arc<dyn Interceptor>],
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        for interceptor in self.interceptors {
+            interceptor.read_after_cancel(self.id);
+        }
+    }
+}
+
+/// Resolve the path and query of a request against an endpoint.
+fn resolve(endpoint: &Uri, uri: &Uri) -> Result<Uri, http::Error> {
+    let base = endpoint.path().trim_end_matches('/');
+    let path_and_query = uri.path_and_query().map_or("/", PathAndQuery::as_str);
+    let mut builder = Uri::builder().path_and_query(format!("{base}{path_and_query}"));
+    if let Some(scheme) = endpoint.scheme() {
+        builder = builder.scheme(scheme.clone());
+    }
+    if let Some(authority) = endpoint.authority() {
+        builder = builder.authority(authority.clone());
+    }
+    builder.build()
+}
+
+impl<P: Debug, T: Debug> Debug for Client<P, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("protocol", &self.protocol)
+            .field("service", self.service.id())
+            .field("transport", &self.transport)
+            .field("endpoint", &self.endpoint)
+            .field("auth", &self.auth)
+            .field("compression", &self.compression)
+            .field("retry", &self.retry)
+            .field("interceptors", &self.interceptors.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_paths_against_endpoint() {
+        let uri = Uri::from_static("/cities/Seattle?units=metric");
+        assert_eq!(
+            resolve(&Uri::from_static("https://example.com"), &uri).unwrap(),
+            "https://example.com/cities/Seattle?units=metric"
+        );
+        assert_eq!(
+            resolve(&Uri::from_static("http://localhost:8080/api/"), &uri).unwrap(),
+            "http://localhost:8080/api/cities/Seattle?units=metric"
+        );
+    }
+}
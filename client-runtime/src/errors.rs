@@ -0,0 +1,73 @@
+use std::io;
+
+use smithy4rs_core::{retry::RetryKind, serde::CodecError};
+use smithy4rs_http_binding::{HttpBindingError, auth::AuthError, compression::CompressionError};
+use thiserror::Error;
+
+/// Errors returned when calling an operation with a [`Client`](crate::Client).
+///
+/// Modeled errors returned by the service are wrapped in [`ClientError::Service`].
+#[derive(Error, Debug)]
+pub enum ClientError<E> {
+    /// The operation has no `@http` trait.
+    #[error(transparent)]
+    Binding(#[from] HttpBindingError),
+    /// The operation input could not be serialized into a request.
+    #[error("Failed to serialize request: {0}")]
+    Serialization(CodecError),
+    /// The request body could not be compressed, or the response body could not be decompressed.
+    #[error(transparent)]
+    Compression(#[from] CompressionError),
+    /// The request could not be sent to the client's endpoint.
+    #[error("Invalid endpoint: {0}")]
+    Endpoint(#[from] http::Error),
+    /// The request could not be signed.
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    /// The transport failed to send the request or read its response.
+    #[error("Failed to send request: {0}")]
+    Transport(io::Error),
+    /// The response could not be deserialized into the operation output or a modeled error.
+    #[error("Failed to deserialize response: {0}")]
+    Deserialization(CodecError),
+    /// The service returned a modeled error.
+    #[error("Service returned an error: {0:?}")]
+    Service(E),
+}
+
+impl<E: OperationError> ClientError<E> {
+    /// Classify this error to decide whether the call may be retried.
+    ///
+    /// Modeled errors are classified by [`OperationError::retry_kind`], and transport
+    /// errors by [`RetryKind::of_io_error`]. Errors raised before a request was sent
+    /// are never retried.
+    #[must_use]
+    pub fn retry_kind(&self) -> RetryKind {
+        match self {
+            ClientError::Service(error) => error.retry_kind(),
+            ClientError::Transport(error) => RetryKind::of_io_error(error),
+            ClientError::Deserialization(error) => RetryKind::of_codec_error(error),
+            _ => RetryKind::NotRetryable,
+        }
+    }
+}
+
+/// The modeled errors an operation can return.
+///
+/// Operation error enums classify each variant with [`RetryKind::of_error`], so
+/// errors with the `@retryable` trait are retried by a [`Client`](crate::Client):
+///
+/// ```rust,ignore
+/// impl OperationError for GetCityError {
+///     fn retry_kind(&self) -> RetryKind {
+///         match self {
+///             GetCityError::NoSuchCity(error) => RetryKind::of_error(error),
+///             GetCityError::Throttled(error) => RetryKind::of_error(error),
+///         }
+///     }
+/// }
+/// ```
+pub trait OperationError {
+    /// Classify this error to decide whether the call may be retried.
+    fn retry_kind(&self) -> RetryKind;
+}
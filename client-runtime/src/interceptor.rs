@@ -0,0 +1,64 @@
+use std::{
+    fmt::{Display, Formatter},
+    io,
+};
+
+use http::{Request, Response};
+
+/// Identifies a single call made by a [`Client`](crate::Client).
+///
+/// Correlation IDs are unique within a client, and are added to the extensions of
+/// each request so transports can use them to tag logs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CorrelationId(u64);
+
+impl CorrelationId {
+    /// Create a correlation ID from its raw value.
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        CorrelationId(value)
+    }
+
+    /// Get the raw value of this correlation ID.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for CorrelationId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// Observes the requests sent and responses received by a [`Client`](crate::Client).
+///
+/// Interceptors are called in the order they were added to the client, with the
+/// serialized and signed request just before it is sent, and with the response
+/// (or transport error) as soon as it is received. If a call is dropped while its request
+/// is in flight, [`Interceptor::read_after_cancel`] is called instead of
+/// [`Interceptor::read_after_transmit`]. All hooks default to doing nothing.
+pub trait Interceptor: Send + Sync + 'static {
+    /// Called with a request just before it is sent.
+    fn read_before_transmit(&self, id: CorrelationId, request: &Request<Vec<u8>>) {
+        let _ = (id, request);
+    }
+
+    /// Called with the response to a request, or the error returned by the transport.
+    fn read_after_transmit(
+        &self,
+        id: CorrelationId,
+        response: Result<&Response<Vec<u8>>, &io::Error>,
+    ) {
+        let _ = (id, response);
+    }
+
+    /// Called when a call is dropped after its request was passed to
+    /// [`Interceptor::read_before_transmit`], but before a response was received.
+    ///
+    /// Interceptors should release any state kept for the request.
+    fn read_after_cancel(&self, id: CorrelationId) {
+        let _ = id;
+    }
+}
@@ -0,0 +1,57 @@
+//! Client runtime for `smithy4rs` services.
+//!
+//! A [`Client`] calls the operations of a service. Operation inputs are serialized
+//! into requests by a [`ClientProtocol`], signed for the service's auth schemes with an
+//! [`AuthSchemeRegistry`](smithy4rs_http_binding::auth::AuthSchemeRegistry), and sent
+//! by a [`Transport`]. Responses are then deserialized into the operation output, or
+//! into one of the operation's modeled errors:
+//!
+//! ```rust,ignore
+//! let client = Client::new(RestJson1::new(), &CITY_SERVICE, transport)
+//!     .with_endpoint(Uri::from_static("https://cities.example.com"));
+//!
+//! let errors = ShapeRegistry::new().with(GetCityError::NoSuchCity);
+//! match client.call(&GET_CITY, &input, &errors).await {
+//!     Ok(city) => println!("{}", city.name),
+//!     Err(ClientError::Service(GetCityError::NoSuchCity(_))) => println!("No such city"),
+//!     Err(error) => return Err(error.into()),
+//! }
+//! ```
+//!
+//! The runtime does not include an HTTP client. Transports adapt an HTTP client of
+//! your choice, and a [`MockTransport`] answers requests with queued responses for tests.
+//!
+//! Requests for operations with the `@requestCompression` trait are compressed, and
+//! compressed responses are decoded, with the
+//! [`CompressionRegistry`](smithy4rs_http_binding::compression::CompressionRegistry) set
+//! with [`Client::with_compression`].
+//!
+//! Failed calls are retried according to the
+//! [`RetryPolicies`](smithy4rs_core::retry::RetryPolicies) set with [`Client::with_retry`].
+//! Transport failures are classified by their [`io::ErrorKind`](std::io::ErrorKind), and
+//! modeled errors by the `@retryable` trait (see [`OperationError`]).
+//!
+//! [`Interceptor`]s observe the requests sent and responses received by a client. The
+//! [`capture`] module uses them to record redacted exchanges that can be replayed with a
+//! [`MockTransport`].
+//!
+//! ## Features
+//! - `sigv4`: Enables the `aws.auth#sigv4` signer of `smithy4rs-http-binding`.
+pub mod capture;
+mod client;
+mod errors;
+mod interceptor;
+mod protocol;
+mod transport;
+
+pub use client::Client;
+pub use errors::{ClientError, OperationError};
+pub use interceptor::{CorrelationId, Interceptor};
+pub use protocol::ClientProtocol;
+pub use transport::{MockTransport, Transport};
+
+smithy4rs_core::assert_send_sync!(
+    MockTransport,
+    capture::CaptureInterceptor<capture::MemorySink>,
+    Client<smithy4rs_protocol_rest_json1::RestJson1, MockTransport>,
+);
@@ -0,0 +1,81 @@
+use http::{Request, Response};
+use smithy4rs_core::{
+    prelude::HttpTrait,
+    schema::StaticSchemaShape,
+    serde::{
+        Buildable, CodecError, Protocol, ShapeBuilder, ShapeRegistry, se::SerializeWithSchema,
+    },
+};
+use smithy4rs_protocol_rest_json1::RestJson1;
+
+/// A [`Protocol`] that can be used to call operations over HTTP.
+///
+/// Client protocols write operation inputs to requests and read operation
+/// outputs and modeled errors from responses.
+pub trait ClientProtocol: Protocol {
+    /// Serialize an operation input into a request for an operation with the
+    /// provided `@http` trait.
+    ///
+    /// The URI of the request contains only the path and query of the operation.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the input could not be serialized.
+    fn serialize_input<T>(
+        &self,
+        http: &HttpTrait,
+        input: &T,
+    ) -> Result<Request<Vec<u8>>, CodecError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema;
+
+    /// Deserialize and build an operation output from a successful response.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the response could not be deserialized or if
+    /// the built output is invalid.
+    fn deserialize_output<S, B>(&self, response: &Response<Vec<u8>>) -> Result<S, CodecError>
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: for<'de> ShapeBuilder<'de, S>;
+
+    /// Deserialize a modeled error from an error response, using a [`ShapeRegistry`]
+    /// of the errors the operation can return.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the response does not identify a registered error,
+    /// or the error could not be deserialized.
+    fn deserialize_error<E>(
+        &self,
+        response: &Response<Vec<u8>>,
+        errors: &ShapeRegistry<E>,
+    ) -> Result<E, CodecError>;
+}
+
+impl ClientProtocol for RestJson1 {
+    fn serialize_input<T>(
+        &self,
+        http: &HttpTrait,
+        input: &T,
+    ) -> Result<Request<Vec<u8>>, CodecError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema,
+    {
+        self.serialize_request(http, input)
+    }
+
+    fn deserialize_output<S, B>(&self, response: &Response<Vec<u8>>) -> Result<S, CodecError>
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: for<'de> ShapeBuilder<'de, S>,
+    {
+        self.deserialize_response(response)
+    }
+
+    fn deserialize_error<E>(
+        &self,
+        response: &Response<Vec<u8>>,
+        errors: &ShapeRegistry<E>,
+    ) -> Result<E, CodecError> {
+        RestJson1::deserialize_error(self, response, errors)
+    }
+}
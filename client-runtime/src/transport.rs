@@ -0,0 +1,165 @@
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter},
+    future::ready,
+    io,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use http::{Request, Response};
+
+use crate::capture::CapturedExchange;
+
+/// Sends serialized requests to a service.
+///
+/// Transports own any connections used to send requests, and read the complete
+/// response body before returning. Failures to send a request or read its response
+/// are returned as [`io::Error`]s, so they can be classified with
+/// [`RetryKind::of_io_error`](smithy4rs_core::retry::RetryKind::of_io_error).
+///
+/// # Cancellation
+/// The future returned by [`Transport::send`] may be dropped before it completes, when
+/// a call is cancelled. Transports must not leak connections when this happens: a
+/// connection with a partially written request or unread response should be closed
+/// instead of being returned to a connection pool.
+pub trait Transport: Send + Sync + 'static {
+    /// Send a request and read its response.
+    fn send(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> impl Future<Output = io::Result<Response<Vec<u8>>>> + Send;
+
+    /// Wait for `delay` before a failed request is retried.
+    ///
+    /// Transports should use the timer of the async runtime their HTTP client runs on.
+    fn sleep(&self, delay: Duration) -> impl Future<Output = ()> + Send;
+}
+
+// ============================================================================
+// Mock Transport
+// ============================================================================
+
+/// A [`Transport`] that answers requests with queued responses.
+///
+/// Responses are returned in the order they are queued, and every request sent is
+/// recorded so tests can check what a client sent:
+///
+/// ```rust,ignore
+/// let transport = MockTransport::new().with_response(response);
+/// let client = Client::new(RestJson1::new(), &CITY_SERVICE, transport.clone());
+///
+/// client.call(&GET_CITY, &input, &errors).await?;
+/// assert_eq!(transport.take_requests()[0].uri(), "/cities/Seattle");
+/// ```
+///
+/// Requests sent when no response is queued fail with [`io::ErrorKind::NotConnected`].
+/// Retry delays complete immediately, and are recorded so tests can check them.
+/// Cloning a mock transport shares the same queue.
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Default)]
+struct MockState {
+    responses: VecDeque<io::Result<Response<Vec<u8>>>>,
+    requests: Vec<Request<Vec<u8>>>,
+    delays: Vec<Duration>,
+}
+
+impl MockTransport {
+    /// Create a mock transport with no queued responses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a response to the next unanswered request.
+    pub fn push_response(&self, response: Response<Vec<u8>>) -> &Self {
+        self.lock().responses.push_back(Ok(response));
+        self
+    }
+
+    /// Builder-style version of [`MockTransport::push_response`].
+    #[must_use]
+    pub fn with_response(self, response: Response<Vec<u8>>) -> Self {
+        self.push_response(response);
+        self
+    }
+
+    /// Create a mock transport that replays captured responses in the order they were captured.
+    ///
+    /// Captured transport errors are replayed as errors, so a [`Client`](crate::Client)
+    /// using this transport sees the same results as the client that captured them.
+    #[must_use]
+    pub fn replay(exchanges: impl IntoIterator<Item = CapturedExchange>) -> Self {
+        let transport = Self::new();
+        transport
+            .lock()
+            .responses
+            .extend(exchanges.into_iter().map(|exchange| exchange.to_response()));
+        transport
+    }
+
+    /// Queue a transport failure for the next unanswered request.
+    pub fn push_error(&self, error: io::Error) -> &Self {
+        self.lock().responses.push_back(Err(error));
+        self
+    }
+
+    /// Take all requests sent so far.
+    #[must_use]
+    pub fn take_requests(&self) -> Vec<Request<Vec<u8>>> {
+        std::mem::take(&mut self.lock().requests)
+    }
+
+    /// Take all retry delays waited for so far.
+    #[must_use]
+    pub fn take_delays(&self) -> Vec<Duration> {
+        std::mem::take(&mut self.lock().delays)
+    }
+
+    /// Number of queued responses that have not been returned yet.
+    #[must_use]
+    pub fn pending(&self) -> usize {
+        self.lock().responses.len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        // Queues are never left in an inconsistent state
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> impl Future<Output = io::Result<Response<Vec<u8>>>> + Send {
+        let mut state = self.lock();
+        state.requests.push(request);
+        let response = state.responses.pop_front().unwrap_or_else(|| {
+            Err(io::Error::new(
+                io::ErrorKind::NotConnected,
+                "No response queued for request",
+            ))
+        });
+        ready(response)
+    }
+
+    fn sleep(&self, delay: Duration) -> impl Future<Output = ()> + Send {
+        self.lock().delays.push(delay);
+        ready(())
+    }
+}
+
+impl Debug for MockTransport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let state = self.lock();
+        f.debug_struct("MockTransport")
+            .field("pending", &state.responses.len())
+            .field("requests", &state.requests.len())
+            .finish()
+    }
+}
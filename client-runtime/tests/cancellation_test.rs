@@ -0,0 +1,234 @@
+use std::{
+    future::poll_fn,
+    io,
+    pin::pin,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    task::Poll,
+    time::Duration,
+};
+
+use http::{Request, Response};
+use smithy4rs_client_runtime::{
+    Client, ClientError, OperationError, Transport,
+    capture::{CaptureInterceptor, MemorySink},
+};
+use smithy4rs_core::{
+    derive::SmithyShape,
+    prelude::{HttpLabelTrait, HttpTrait, RequiredTrait, STRING},
+    retry::RetryKind,
+    schema::{Operation, Service},
+    serde::{ShapeRegistry, pool::BufferPool},
+    smithy,
+};
+use smithy4rs_protocol_rest_json1::RestJson1;
+use tokio::time::{sleep, timeout};
+
+smithy!("com.example#GetCityInput": {
+    structure GET_CITY_INPUT_SCHEMA {
+        @HttpLabelTrait::builder().build();
+        @RequiredTrait::builder().build();
+        CITY: STRING = "city"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_CITY_INPUT_SCHEMA)]
+pub struct GetCityInput {
+    #[smithy_schema(CITY)]
+    pub city: String,
+}
+
+smithy!("com.example#GetCityOutput": {
+    structure GET_CITY_OUTPUT_SCHEMA {
+        @RequiredTrait::builder().build();
+        NAME: STRING = "name"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_CITY_OUTPUT_SCHEMA)]
+pub struct GetCityOutput {
+    #[smithy_schema(NAME)]
+    pub name: String,
+}
+
+#[derive(Debug)]
+enum GetCityError {}
+
+impl OperationError for GetCityError {
+    fn retry_kind(&self) -> RetryKind {
+        match *self {}
+    }
+}
+
+smithy!("com.example#GetCity": {
+    @HttpTrait::builder().method("GET".to_string()).uri("/cities/{city}".to_string()).build().unwrap();
+    operation GET_CITY_SCHEMA
+});
+
+static GET_CITY: Operation<GetCityInput, GetCityOutput, GetCityError> =
+    Operation::new(&GET_CITY_SCHEMA);
+
+smithy!("com.example#CityService": {
+    service CITY_SERVICE_SCHEMA
+});
+
+static CITY_SERVICE: Service = Service::new(&CITY_SERVICE_SCHEMA, &[&GET_CITY_SCHEMA]);
+
+// ============================================================================
+// Pooled Transport
+// ============================================================================
+
+/// Transport that holds a connection and a pooled buffer while a response is pending.
+///
+/// Requests for `/cities/Slow` take a second to answer, all other requests take 10ms.
+#[derive(Clone, Default)]
+struct PooledTransport {
+    open: Arc<AtomicUsize>,
+    buffers: BufferPool,
+}
+
+/// Open connection, closed when dropped.
+struct Connection(Arc<AtomicUsize>);
+
+impl Connection {
+    fn open(open: &Arc<AtomicUsize>) -> Self {
+        open.fetch_add(1, Ordering::SeqCst);
+        Connection(open.clone())
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl Transport for PooledTransport {
+    fn send(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> impl Future<Output = io::Result<Response<Vec<u8>>>> + Send {
+        let transport = self.clone();
+        async move {
+            let _connection = Connection::open(&transport.open);
+            let mut body = transport.buffers.lease(64);
+            let delay = if request.uri().path().ends_with("/Slow") {
+                Duration::from_secs(1)
+            } else {
+                Duration::from_millis(10)
+            };
+            sleep(delay).await;
+            body.extend_from_slice(br#"{"name":"Seattle"}"#);
+            Ok(Response::builder()
+                .status(200)
+                .header("content-type", "application/json")
+                .body(body.to_vec())
+                .unwrap())
+        }
+    }
+
+    fn sleep(&self, delay: Duration) -> impl Future<Output = ()> + Send {
+        sleep(delay)
+    }
+}
+
+impl PooledTransport {
+    fn open(&self) -> usize {
+        self.open.load(Ordering::SeqCst)
+    }
+}
+
+fn client(transport: PooledTransport, sink: MemorySink) -> Client<RestJson1, PooledTransport> {
+    Client::new(RestJson1::new(), &CITY_SERVICE, transport)
+        .with_interceptor(CaptureInterceptor::new(sink))
+}
+
+async fn get_city(
+    client: &Client<RestJson1, PooledTransport>,
+    city: &str,
+) -> Result<GetCityOutput, ClientError<GetCityError>> {
+    let input = GetCityInput {
+        city: city.to_string(),
+    };
+    client.call(&GET_CITY, &input, &ShapeRegistry::new()).await
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[tokio::test(start_paused = true)]
+async fn aborted_calls_release_connections_and_buffers() {
+    let transport = PooledTransport::default();
+    let sink = MemorySink::new();
+    let client = Arc::new(client(transport.clone(), sink.clone()));
+
+    let call = tokio::spawn({
+        let client = client.clone();
+        async move { get_city(&client, "Slow").await.map(|output| output.name) }
+    });
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(transport.open(), 1);
+    assert!(transport.buffers.is_empty());
+
+    call.abort();
+    assert!(call.await.unwrap_err().is_cancelled());
+    assert_eq!(transport.open(), 0);
+    assert_eq!(transport.buffers.len(), 1);
+
+    let exchanges = sink.take();
+    assert_eq!(exchanges.len(), 1);
+    assert_eq!(exchanges[0].uri, "/cities/Slow");
+    assert_eq!(exchanges[0].error.as_deref(), Some("Call was cancelled"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn timed_out_calls_do_not_affect_later_calls() {
+    let transport = PooledTransport::default();
+    let sink = MemorySink::new();
+    let client = client(transport.clone(), sink.clone());
+
+    let timed_out = timeout(Duration::from_millis(100), get_city(&client, "Slow")).await;
+    assert!(timed_out.is_err());
+    assert_eq!(transport.open(), 0);
+
+    let output = get_city(&client, "Seattle").await.unwrap();
+    assert_eq!(output.name, "Seattle");
+    assert_eq!(transport.open(), 0);
+    assert_eq!(transport.buffers.len(), 1);
+
+    let exchanges = sink.take();
+    assert_eq!(exchanges.len(), 2);
+    assert_ne!(exchanges[0].correlation_id, exchanges[1].correlation_id);
+    assert!(exchanges[0].error.is_some());
+    assert_eq!(exchanges[1].status, Some(200));
+}
+
+/// Equivalent of `select!`, which expands to code forbidden by the workspace lints.
+#[tokio::test(start_paused = true)]
+async fn racing_calls_drop_the_loser() {
+    let transport = PooledTransport::default();
+    let sink = MemorySink::new();
+    let client = client(transport.clone(), sink.clone());
+
+    let mut fast = pin!(get_city(&client, "Seattle"));
+    let mut slow = Box::pin(get_city(&client, "Slow"));
+    let winner = poll_fn(|cx| {
+        if let Poll::Ready(output) = fast.as_mut().poll(cx) {
+            return Poll::Ready(output);
+        }
+        assert!(slow.as_mut().poll(cx).is_pending(), "Slow call should lose");
+        Poll::Pending
+    })
+    .await;
+    assert_eq!(transport.open(), 1);
+    drop(slow);
+
+    assert_eq!(winner.unwrap().name, "Seattle");
+    assert_eq!(transport.open(), 0);
+    assert_eq!(sink.len(), 2);
+}
@@ -0,0 +1,175 @@
+use std::io;
+
+use http::{Response, Uri, header::AUTHORIZATION};
+use smithy4rs_client_runtime::{
+    Client, ClientError, MockTransport, OperationError,
+    capture::{CaptureInterceptor, CaptureSink, JsonLinesSink, MemorySink, read_exchanges},
+};
+use smithy4rs_core::{
+    derive::SmithyShape,
+    doc_map,
+    prelude::{HttpLabelTrait, HttpTrait, RequiredTrait, STRING},
+    retry::RetryKind,
+    schema::{DynamicTrait, Operation, Service},
+    serde::ShapeRegistry,
+    smithy,
+};
+use smithy4rs_http_binding::auth::{
+    AuthSchemeRegistry, DefaultAuthScheme, HTTP_BEARER_AUTH, Identity,
+};
+use smithy4rs_protocol_rest_json1::RestJson1;
+use smithy4rs_test_utils::ready;
+
+smithy!("com.example#GetCityInput": {
+    structure GET_CITY_INPUT_SCHEMA {
+        @HttpLabelTrait::builder().build();
+        @RequiredTrait::builder().build();
+        CITY: STRING = "city"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_CITY_INPUT_SCHEMA)]
+pub struct GetCityInput {
+    #[smithy_schema(CITY)]
+    pub city: String,
+}
+
+smithy!("com.example#GetCityOutput": {
+    structure GET_CITY_OUTPUT_SCHEMA {
+        @RequiredTrait::builder().build();
+        NAME: STRING = "name"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_CITY_OUTPUT_SCHEMA)]
+pub struct GetCityOutput {
+    #[smithy_schema(NAME)]
+    pub name: String,
+}
+
+#[derive(Debug)]
+enum GetCityError {}
+
+impl OperationError for GetCityError {
+    fn retry_kind(&self) -> RetryKind {
+        match *self {}
+    }
+}
+
+smithy!("com.example#GetCity": {
+    @HttpTrait::builder().method("GET".to_string()).uri("/cities/{city}".to_string()).build().unwrap();
+    operation GET_CITY_SCHEMA
+});
+
+static GET_CITY: Operation<GetCityInput, GetCityOutput, GetCityError> =
+    Operation::new(&GET_CITY_SCHEMA);
+
+smithy!("com.example#CityService": {
+    @DynamicTrait::from(HTTP_BEARER_AUTH, doc_map![]);
+    service CITY_SERVICE_SCHEMA
+});
+
+static CITY_SERVICE: Service = Service::new(&CITY_SERVICE_SCHEMA, &[&GET_CITY_SCHEMA]);
+
+fn client(transport: MockTransport) -> Client<RestJson1, MockTransport> {
+    Client::new(RestJson1::new(), &CITY_SERVICE, transport)
+        .with_endpoint(Uri::from_static("https://cities.example.com"))
+        .with_auth(
+            AuthSchemeRegistry::new().with(DefaultAuthScheme::bearer(Identity::Token(
+                "secret-token".into(),
+            ))),
+        )
+}
+
+fn get_city(
+    client: &Client<RestJson1, MockTransport>,
+    city: &str,
+) -> Result<GetCityOutput, ClientError<GetCityError>> {
+    let input = GetCityInput {
+        city: city.to_string(),
+    };
+    ready(client.call(&GET_CITY, &input, &ShapeRegistry::new()))
+}
+
+fn transport() -> MockTransport {
+    let transport = MockTransport::new().with_response(
+        Response::builder()
+            .status(200)
+            .header("content-type", "application/json")
+            .header("set-cookie", "session=secret")
+            .body(br#"{"name":"Seattle"}"#.to_vec())
+            .unwrap(),
+    );
+    transport.push_error(io::Error::from(io::ErrorKind::ConnectionReset));
+    transport
+}
+
+#[test]
+fn captures_redacted_exchanges() {
+    let sink = MemorySink::new();
+    let client = client(transport()).with_interceptor(CaptureInterceptor::new(sink.clone()));
+
+    assert_eq!(get_city(&client, "Seattle").unwrap().name, "Seattle");
+    assert!(get_city(&client, "Portland").is_err());
+
+    let exchanges = sink.take();
+    assert_eq!(exchanges.len(), 2);
+    let (ok, failed) = (&exchanges[0], &exchanges[1]);
+    assert_ne!(ok.correlation_id, failed.correlation_id);
+
+    assert_eq!(ok.method, "GET");
+    assert_eq!(ok.uri, "https://cities.example.com/cities/Seattle");
+    let request_headers = ok.request_headers.as_ref().unwrap();
+    assert_eq!(request_headers[AUTHORIZATION.as_str()], "**REDACTED**");
+    assert_eq!(ok.status, Some(200));
+    let response_headers = ok.response_headers.as_ref().unwrap();
+    assert_eq!(response_headers["set-cookie"], "**REDACTED**");
+    assert_eq!(response_headers["content-type"], "application/json");
+    assert_eq!(
+        ok.response_body.as_ref().unwrap().as_bytes(),
+        br#"{"name":"Seattle"}"#
+    );
+
+    assert_eq!(failed.uri, "https://cities.example.com/cities/Portland");
+    assert_eq!(failed.status, None);
+    assert!(failed.error.is_some());
+}
+
+#[test]
+fn replays_captured_exchanges() {
+    let sink = MemorySink::new();
+    let capturing = client(transport()).with_interceptor(CaptureInterceptor::new(sink.clone()));
+    let _ = get_city(&capturing, "Seattle");
+    let _ = get_city(&capturing, "Portland");
+
+    // Round trip through the JSON lines format attached to bug reports
+    let lines = JsonLinesSink::new(Vec::new());
+    for exchange in sink.take() {
+        lines.record(exchange).unwrap();
+    }
+    let exchanges = read_exchanges(&lines.into_inner()).unwrap();
+    assert_eq!(exchanges.len(), 2);
+
+    let replay = MockTransport::replay(exchanges);
+    let replaying = client(replay.clone());
+    assert_eq!(get_city(&replaying, "Seattle").unwrap().name, "Seattle");
+    assert!(matches!(
+        get_city(&replaying, "Portland"),
+        Err(ClientError::Transport(_))
+    ));
+    assert_eq!(replay.pending(), 0);
+}
+
+#[test]
+fn bodies_can_be_excluded() {
+    let sink = MemorySink::new();
+    let client = client(transport())
+        .with_interceptor(CaptureInterceptor::new(sink.clone()).with_bodies(false));
+    let _ = get_city(&client, "Seattle");
+
+    let exchanges = sink.take();
+    assert_eq!(exchanges[0].status, Some(200));
+    assert!(exchanges[0].response_body.is_none());
+}
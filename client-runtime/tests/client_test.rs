@@ -0,0 +1,311 @@
+use std::{io, time::Duration};
+
+use http::{Response, Uri, header::AUTHORIZATION};
+use smithy4rs_client_runtime::{Client, ClientError, MockTransport, OperationError};
+use smithy4rs_core::{
+    derive::SmithyShape,
+    doc_map,
+    prelude::{
+        ErrorTrait, HttpErrorTrait, HttpLabelTrait, HttpTrait, RequiredTrait, RetryableTrait,
+        STRING,
+    },
+    retry::{ExponentialBackoff, NoRetry, RetryKind, RetryPolicies},
+    schema::{DynamicTrait, Operation, Service},
+    serde::ShapeRegistry,
+    smithy,
+};
+use smithy4rs_http_binding::auth::{
+    AuthSchemeRegistry, DefaultAuthScheme, HTTP_BEARER_AUTH, Identity,
+};
+use smithy4rs_protocol_rest_json1::RestJson1;
+use smithy4rs_test_utils::ready;
+
+smithy!("com.example#GetCityInput": {
+    structure GET_CITY_INPUT_SCHEMA {
+        @HttpLabelTrait::builder().build();
+        @RequiredTrait::builder().build();
+        CITY: STRING = "city"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_CITY_INPUT_SCHEMA)]
+pub struct GetCityInput {
+    #[smithy_schema(CITY)]
+    pub city: String,
+}
+
+smithy!("com.example#GetCityOutput": {
+    structure GET_CITY_OUTPUT_SCHEMA {
+        @RequiredTrait::builder().build();
+        NAME: STRING = "name"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_CITY_OUTPUT_SCHEMA)]
+pub struct GetCityOutput {
+    #[smithy_schema(NAME)]
+    pub name: String,
+}
+
+smithy!("com.example#NoSuchCity": {
+    @ErrorTrait::Client;
+    @HttpErrorTrait::new(404);
+    structure NO_SUCH_CITY_SCHEMA {
+        MESSAGE: STRING = "message"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(NO_SUCH_CITY_SCHEMA)]
+#[smithy_error]
+pub struct NoSuchCity {
+    #[smithy_schema(MESSAGE)]
+    pub message: Option<String>,
+}
+
+smithy!("com.example#TooManyRequests": {
+    @ErrorTrait::Client;
+    @HttpErrorTrait::new(429);
+    @RetryableTrait::builder().throttling(true).build().unwrap();
+    structure TOO_MANY_REQUESTS_SCHEMA {
+        MESSAGE: STRING = "message"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(TOO_MANY_REQUESTS_SCHEMA)]
+#[smithy_error]
+pub struct TooManyRequests {
+    #[smithy_schema(MESSAGE)]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+enum GetCityError {
+    NoSuchCity(NoSuchCity),
+    TooManyRequests(TooManyRequests),
+}
+
+impl OperationError for GetCityError {
+    fn retry_kind(&self) -> RetryKind {
+        match self {
+            GetCityError::NoSuchCity(error) => RetryKind::of_error(error),
+            GetCityError::TooManyRequests(error) => RetryKind::of_error(error),
+        }
+    }
+}
+
+smithy!("com.example#GetCity": {
+    @HttpTrait::builder().method("GET".to_string()).uri("/cities/{city}".to_string()).build().unwrap();
+    operation GET_CITY_SCHEMA
+});
+
+static GET_CITY: Operation<GetCityInput, GetCityOutput, GetCityError> =
+    Operation::new(&GET_CITY_SCHEMA);
+
+smithy!("com.example#CityService": {
+    @DynamicTrait::from(HTTP_BEARER_AUTH, doc_map![]);
+    service CITY_SERVICE_SCHEMA
+});
+
+static CITY_SERVICE: Service = Service::new(&CITY_SERVICE_SCHEMA, &[&GET_CITY_SCHEMA]);
+
+fn client(transport: MockTransport) -> Client<RestJson1, MockTransport> {
+    Client::new(RestJson1::new(), &CITY_SERVICE, transport)
+        .with_endpoint(Uri::from_static("https://cities.example.com/v1"))
+        .with_auth(
+            AuthSchemeRegistry::new()
+                .with(DefaultAuthScheme::bearer(Identity::Token("token".into()))),
+        )
+}
+
+fn errors() -> ShapeRegistry<GetCityError> {
+    ShapeRegistry::new()
+        .with(GetCityError::NoSuchCity)
+        .with(GetCityError::TooManyRequests)
+}
+
+fn retry() -> RetryPolicies {
+    RetryPolicies::new(
+        ExponentialBackoff::new()
+            .with_base_delay(Duration::from_millis(10))
+            .with_throttling_base_delay(Duration::from_secs(1))
+            .without_jitter(),
+    )
+}
+
+fn input(city: &str) -> GetCityInput {
+    GetCityInput {
+        city: city.to_string(),
+    }
+}
+
+fn response(status: u16, body: &str) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .body(body.as_bytes().to_vec())
+        .unwrap()
+}
+
+#[test]
+fn calls_operation() {
+    let transport = MockTransport::new().with_response(response(200, r#"{"name":"Seattle"}"#));
+    let client = client(transport.clone());
+
+    let output = ready(client.call(&GET_CITY, &input("Seattle"), &errors())).unwrap();
+    assert_eq!(output.name, "Seattle");
+
+    let requests = transport.take_requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method(), "GET");
+    assert_eq!(
+        requests[0].uri(),
+        "https://cities.example.com/v1/cities/Seattle"
+    );
+    assert_eq!(requests[0].headers()[AUTHORIZATION], "Bearer token");
+}
+
+#[test]
+fn returns_modeled_errors() {
+    let transport = MockTransport::new().with_response(
+        Response::builder()
+            .status(404)
+            .header("x-amzn-errortype", "NoSuchCity")
+            .body(br#"{"message":"Atlantis sank"}"#.to_vec())
+            .unwrap(),
+    );
+    let err = ready(client(transport).call(&GET_CITY, &input("Atlantis"), &errors())).unwrap_err();
+    let ClientError::Service(GetCityError::NoSuchCity(error)) = err else {
+        panic!("Expected a modeled error, got {err}");
+    };
+    assert_eq!(error.message.as_deref(), Some("Atlantis sank"));
+}
+
+#[test]
+fn unknown_errors_fail_deserialization() {
+    let transport = MockTransport::new().with_response(response(500, r#"{"code":"Boom"}"#));
+    let err = ready(client(transport).call(&GET_CITY, &input("Seattle"), &errors())).unwrap_err();
+    assert!(matches!(err, ClientError::Deserialization(_)));
+}
+
+#[test]
+fn returns_transport_errors() {
+    let transport = MockTransport::new();
+    transport.push_error(io::Error::from(io::ErrorKind::ConnectionReset));
+    let err = ready(client(transport).call(&GET_CITY, &input("Seattle"), &errors())).unwrap_err();
+    assert!(
+        matches!(err, ClientError::Transport(ref error) if error.kind() == io::ErrorKind::ConnectionReset)
+    );
+}
+
+#[test]
+fn retries_transient_failures() {
+    let transport = MockTransport::new();
+    transport.push_error(io::Error::from(io::ErrorKind::ConnectionReset));
+    transport.push_response(
+        Response::builder()
+            .status(429)
+            .header("x-amzn-errortype", "TooManyRequests")
+            .body(b"{}".to_vec())
+            .unwrap(),
+    );
+    transport.push_response(response(200, r#"{"name":"Seattle"}"#));
+    let client = client(transport.clone()).with_retry(retry());
+
+    let output = ready(client.call(&GET_CITY, &input("Seattle"), &errors())).unwrap();
+    assert_eq!(output.name, "Seattle");
+    assert_eq!(transport.take_requests().len(), 3);
+    assert_eq!(
+        transport.take_delays(),
+        [Duration::from_millis(10), Duration::from_secs(2)]
+    );
+}
+
+#[test]
+fn stops_retrying_after_max_attempts() {
+    let transport = MockTransport::new();
+    for _ in 0..3 {
+        transport.push_error(io::Error::from(io::ErrorKind::TimedOut));
+    }
+    transport.push_response(response(200, r#"{"name":"Seattle"}"#));
+    let client = client(transport.clone()).with_retry(retry());
+
+    let err = ready(client.call(&GET_CITY, &input("Seattle"), &errors())).unwrap_err();
+    assert!(
+        matches!(err, ClientError::Transport(ref error) if error.kind() == io::ErrorKind::TimedOut)
+    );
+    assert_eq!(transport.take_requests().len(), 3);
+    assert_eq!(transport.take_delays().len(), 2);
+    assert_eq!(transport.pending(), 1);
+}
+
+#[test]
+fn does_not_retry_non_retryable_errors() {
+    let transport = MockTransport::new().with_response(
+        Response::builder()
+            .status(404)
+            .header("x-amzn-errortype", "NoSuchCity")
+            .body(b"{}".to_vec())
+            .unwrap(),
+    );
+    let client = client(transport.clone()).with_retry(retry());
+
+    let err = ready(client.call(&GET_CITY, &input("Atlantis"), &errors())).unwrap_err();
+    assert!(matches!(
+        err,
+        ClientError::Service(GetCityError::NoSuchCity(_))
+    ));
+    assert_eq!(transport.take_requests().len(), 1);
+}
+
+#[test]
+fn uses_operation_retry_policy() {
+    let transport = MockTransport::new();
+    transport.push_error(io::Error::from(io::ErrorKind::ConnectionReset));
+    let client =
+        client(transport.clone()).with_retry(retry().with_override(GET_CITY.id(), NoRetry));
+
+    let err = ready(client.call(&GET_CITY, &input("Seattle"), &errors())).unwrap_err();
+    assert!(matches!(err, ClientError::Transport(_)));
+    assert_eq!(transport.take_requests().len(), 1);
+    assert!(transport.take_delays().is_empty());
+}
+
+#[test]
+fn unsigned_without_supported_scheme() {
+    let client = Client::new(RestJson1::new(), &CITY_SERVICE, MockTransport::new());
+    let err = client.prepare(&GET_CITY, &input("Seattle")).unwrap_err();
+    assert!(matches!(err, ClientError::Auth(_)));
+}
+
+#[cfg(feature = "sigv4")]
+#[test]
+fn signs_with_sigv4() {
+    use smithy4rs_http_binding::auth::SIGV4;
+
+    smithy!("com.example#SigV4CityService": {
+        @DynamicTrait::from(SIGV4, doc_map!["name" => "cities"]);
+        service SIGV4_SERVICE_SCHEMA
+    });
+    static SIGV4_SERVICE: Service = Service::new(&SIGV4_SERVICE_SCHEMA, &[&GET_CITY_SCHEMA]);
+
+    let credentials = Identity::Credentials {
+        access_key_id: "AKIDEXAMPLE".into(),
+        secret_access_key: "secret".into(),
+        session_token: None,
+    };
+    let client = Client::new(RestJson1::new(), &SIGV4_SERVICE, MockTransport::new())
+        .with_endpoint(Uri::from_static("https://cities.example.com"))
+        .with_auth(
+            AuthSchemeRegistry::new().with(DefaultAuthScheme::sigv4(credentials, "us-west-2")),
+        );
+
+    let request = client.prepare(&GET_CITY, &input("Seattle")).unwrap();
+    let authorization = request.headers()[AUTHORIZATION].to_str().unwrap();
+    assert!(authorization.starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/"));
+    assert!(authorization.contains("/us-west-2/cities/aws4_request"));
+    assert!(request.headers().contains_key("x-amz-date"));
+}
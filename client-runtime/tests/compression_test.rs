@@ -0,0 +1,151 @@
+use http::{
+    Response, Uri,
+    header::{CONTENT_ENCODING, CONTENT_TYPE},
+};
+use smithy4rs_client_runtime::{Client, ClientError, MockTransport, OperationError};
+use smithy4rs_core::{
+    derive::SmithyShape,
+    doc_map,
+    prelude::{HttpLabelTrait, HttpTrait, RequiredTrait, STRING},
+    retry::RetryKind,
+    schema::{DynamicTrait, Operation, Service},
+    serde::ShapeRegistry,
+    smithy,
+};
+use smithy4rs_http_binding::compression::{CompressionAlgorithm, Gzip};
+use smithy4rs_protocol_rest_json1::RestJson1;
+use smithy4rs_test_utils::ready;
+
+smithy!("com.example#PutCityInput": {
+    structure PUT_CITY_INPUT_SCHEMA {
+        @HttpLabelTrait::builder().build();
+        @RequiredTrait::builder().build();
+        CITY: STRING = "city"
+        DESCRIPTION: STRING = "description"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(PUT_CITY_INPUT_SCHEMA)]
+pub struct PutCityInput {
+    #[smithy_schema(CITY)]
+    pub city: String,
+    #[smithy_schema(DESCRIPTION)]
+    pub description: Option<String>,
+}
+
+smithy!("com.example#PutCityOutput": {
+    structure PUT_CITY_OUTPUT_SCHEMA {
+        @RequiredTrait::builder().build();
+        NAME: STRING = "name"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(PUT_CITY_OUTPUT_SCHEMA)]
+pub struct PutCityOutput {
+    #[smithy_schema(NAME)]
+    pub name: String,
+}
+
+#[derive(Debug)]
+enum PutCityError {}
+
+impl OperationError for PutCityError {
+    fn retry_kind(&self) -> RetryKind {
+        match *self {}
+    }
+}
+
+smithy!("com.example#PutCity": {
+    @HttpTrait::builder().method("PUT".to_string()).uri("/cities/{city}".to_string()).build().unwrap();
+    @DynamicTrait::from("smithy.api#requestCompression", doc_map!["encodings" => vec!["gzip"]]);
+    operation PUT_CITY_SCHEMA
+});
+
+static PUT_CITY: Operation<PutCityInput, PutCityOutput, PutCityError> =
+    Operation::new(&PUT_CITY_SCHEMA);
+
+smithy!("com.example#ReplaceCity": {
+    @HttpTrait::builder().method("PUT".to_string()).uri("/cities/{city}/replace".to_string()).build().unwrap();
+    operation REPLACE_CITY_SCHEMA
+});
+
+static REPLACE_CITY: Operation<PutCityInput, PutCityOutput, PutCityError> =
+    Operation::new(&REPLACE_CITY_SCHEMA);
+
+smithy!("com.example#CityService": {
+    service CITY_SERVICE_SCHEMA
+});
+
+static CITY_SERVICE: Service = Service::new(
+    &CITY_SERVICE_SCHEMA,
+    &[&PUT_CITY_SCHEMA, &REPLACE_CITY_SCHEMA],
+);
+
+fn client(transport: MockTransport) -> Client<RestJson1, MockTransport> {
+    Client::new(RestJson1::new(), &CITY_SERVICE, transport)
+        .with_endpoint(Uri::from_static("https://cities.example.com"))
+}
+
+fn input() -> PutCityInput {
+    PutCityInput {
+        city: "Seattle".to_string(),
+        description: Some("Rainy. ".repeat(2_000)),
+    }
+}
+
+fn response(encoding: &str, body: Vec<u8>) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(200)
+        .header(CONTENT_TYPE, "application/json")
+        .header(CONTENT_ENCODING, encoding)
+        .body(body)
+        .unwrap()
+}
+
+fn put_city(
+    client: &Client<RestJson1, MockTransport>,
+    operation: &Operation<PutCityInput, PutCityOutput, PutCityError>,
+) -> Result<PutCityOutput, ClientError<PutCityError>> {
+    ready(client.call(operation, &input(), &ShapeRegistry::new()))
+}
+
+#[test]
+fn compresses_requests_and_decompresses_responses() {
+    let gzip = Gzip::default();
+    let transport = MockTransport::new().with_response(response(
+        "gzip",
+        gzip.compress(br#"{"name":"Seattle"}"#).unwrap(),
+    ));
+    let client = client(transport.clone());
+
+    assert_eq!(put_city(&client, &PUT_CITY).unwrap().name, "Seattle");
+
+    let requests = transport.take_requests();
+    assert_eq!(requests[0].headers()[CONTENT_ENCODING], "gzip");
+    let body = gzip.decompress(requests[0].body(), usize::MAX).unwrap();
+    let description = input().description.unwrap();
+    assert!(String::from_utf8(body).unwrap().contains(&description));
+    assert!(requests[0].body().len() < description.len());
+}
+
+#[test]
+fn operations_without_trait_are_not_compressed() {
+    let transport =
+        MockTransport::new().with_response(response("identity", br#"{"name":"Seattle"}"#.to_vec()));
+    let client = client(transport.clone());
+
+    assert_eq!(put_city(&client, &REPLACE_CITY).unwrap().name, "Seattle");
+
+    let requests = transport.take_requests();
+    assert!(!requests[0].headers().contains_key(CONTENT_ENCODING));
+    assert!(requests[0].body().starts_with(b"{"));
+}
+
+#[test]
+fn unsupported_response_encodings_fail() {
+    let transport = MockTransport::new().with_response(response("br", b"???".to_vec()));
+    let err = put_city(&client(transport), &PUT_CITY).unwrap_err();
+    assert!(matches!(err, ClientError::Compression(_)));
+}
@@ -0,0 +1,91 @@
+/*
+ * Copyright Hunter Mellema & Hayden Baker. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+package dev.hmellema.smithy4rs.codegen;
+
+import java.io.ByteArrayInputStream;
+import java.io.IOException;
+import java.io.InputStream;
+import java.io.Reader;
+import java.io.StringReader;
+import java.io.StringWriter;
+import java.io.UncheckedIOException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.Arrays;
+import java.util.Set;
+import software.amazon.smithy.build.FileManifest;
+
+/**
+ * {@link FileManifest} that leaves generated files untouched if their contents have not changed.
+ *
+ * <p>Skipping identical writes preserves file modification times, so regenerating a
+ * large model does not force {@code cargo} to rebuild crates whose generated sources
+ * are unchanged.
+ */
+public final class IncrementalFileManifest implements FileManifest {
+    private final FileManifest delegate;
+
+    public IncrementalFileManifest(FileManifest delegate) {
+        this.delegate = delegate;
+    }
+
+    @Override
+    public Path getBaseDir() {
+        return delegate.getBaseDir();
+    }
+
+    @Override
+    public Set<Path> getFiles() {
+        return delegate.getFiles();
+    }
+
+    @Override
+    public Path addFile(Path path) {
+        return delegate.addFile(path);
+    }
+
+    @Override
+    public Path writeFile(Path path, Reader fileContentProvider) {
+        var content = new StringWriter();
+        try (fileContentProvider) {
+            fileContentProvider.transferTo(content);
+        } catch (IOException e) {
+            throw new UncheckedIOException("Unable to read content for " + path, e);
+        }
+        var bytes = content.toString().getBytes(StandardCharsets.UTF_8);
+        if (isUnchanged(path, bytes)) {
+            return addFile(path);
+        }
+        return delegate.writeFile(path, new StringReader(content.toString()));
+    }
+
+    @Override
+    public Path writeFile(Path path, InputStream fileContentProvider) {
+        byte[] bytes;
+        try (fileContentProvider) {
+            bytes = fileContentProvider.readAllBytes();
+        } catch (IOException e) {
+            throw new UncheckedIOException("Unable to read content for " + path, e);
+        }
+        if (isUnchanged(path, bytes)) {
+            return addFile(path);
+        }
+        return delegate.writeFile(path, new ByteArrayInputStream(bytes));
+    }
+
+    private boolean isUnchanged(Path path, byte[] content) {
+        var resolved = resolvePath(path);
+        if (!Files.isRegularFile(resolved)) {
+            return false;
+        }
+        try {
+            return Files.size(resolved) == content.length && Arrays.equals(Files.readAllBytes(resolved), content);
+        } catch (IOException e) {
+            // Fall back to re-writing the file
+            return false;
+        }
+    }
+}
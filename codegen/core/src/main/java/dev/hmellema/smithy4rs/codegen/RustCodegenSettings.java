@@ -4,14 +4,71 @@
  */
 package dev.hmellema.smithy4rs.codegen;
 
+import java.util.List;
+import java.util.Set;
 import software.amazon.smithy.model.node.ObjectNode;
+import software.amazon.smithy.model.node.StringNode;
 import software.amazon.smithy.model.shapes.ShapeId;
+import software.amazon.smithy.model.shapes.ToShapeId;
 
 /**
  * Settings for Rust codegen plugins
+ *
+ * @param service service shape to generate code for
+ * @param incremental if true, generated files are only re-written when their contents change
+ * @param roots if not empty, only shapes reachable from these shapes are generated
+ * @param services if not empty, each service is generated into its own crate
+ * @param sharedCrate name of the crate containing shapes shared by multiple services
+ * @param sharedShapes shapes that are imported from {@code sharedCrate} instead of being generated
  */
-public record RustCodegenSettings(ShapeId service) {
+public record RustCodegenSettings(
+        ShapeId service,
+        boolean incremental,
+        List<ShapeId> roots,
+        List<ShapeId> services,
+        String sharedCrate,
+        Set<ShapeId> sharedShapes
+) {
+    private static final String INCREMENTAL = "incremental";
+    private static final String ROOTS = "roots";
+    private static final String SERVICES = "services";
+    private static final String SHARED_CRATE = "sharedCrate";
+    private static final String DEFAULT_SHARED_CRATE = "shared_types";
+
     public static RustCodegenSettings fromNode(ObjectNode node) {
-        return new RustCodegenSettings(null);
+        return new RustCodegenSettings(
+                null,
+                node.getBooleanMemberOrDefault(INCREMENTAL, true),
+                getShapeIds(node, ROOTS),
+                getShapeIds(node, SERVICES),
+                node.getStringMemberOrDefault(SHARED_CRATE, DEFAULT_SHARED_CRATE),
+                Set.of());
+    }
+
+    /**
+     * Creates settings for generating a single service crate that imports shared shapes.
+     *
+     * @param service service to generate
+     * @param sharedShapes shapes to import from the shared crate
+     * @return updated settings
+     */
+    public RustCodegenSettings forService(ShapeId service, Set<ShapeId> sharedShapes) {
+        return new RustCodegenSettings(service, incremental, roots, services, sharedCrate, sharedShapes);
+    }
+
+    /**
+     * @return true if the shape is generated in the shared crate
+     */
+    public boolean isShared(ToShapeId shape) {
+        return sharedShapes.contains(shape.toShapeId());
+    }
+
+    private static List<ShapeId> getShapeIds(ObjectNode node, String member) {
+        return node.getArrayMember(member)
+                .map(array -> array.getElementsAs(StringNode.class)
+                        .stream()
+                        .map(StringNode::expectShapeId)
+                        .toList())
+                .orElse(List.of());
     }
 }
@@ -4,8 +4,10 @@
  */
 package dev.hmellema.smithy4rs.codegen;
 
+import dev.hmellema.smithy4rs.codegen.symbols.Smithy4Rs;
 import java.util.EnumSet;
 import java.util.Locale;
+import java.util.Set;
 import java.util.logging.Logger;
 import software.amazon.smithy.codegen.core.Symbol;
 import software.amazon.smithy.codegen.core.SymbolProvider;
@@ -14,14 +16,20 @@ import software.amazon.smithy.model.loader.Prelude;
 import software.amazon.smithy.model.shapes.*;
 import software.amazon.smithy.model.traits.DefaultTrait;
 import software.amazon.smithy.model.traits.RequiredTrait;
+import software.amazon.smithy.model.traits.StreamingTrait;
 import software.amazon.smithy.model.traits.TraitDefinition;
 import software.amazon.smithy.utils.CaseUtils;
 import software.amazon.smithy.utils.StringUtils;
 
 /**
  * Maps Smithy types to Rust symbols
+ *
+ * @param model model to generate symbols for
+ * @param sharedCrate crate that {@code sharedShapes} are imported from
+ * @param sharedShapes shapes generated in a separate, shared crate
  */
-public record RustSymbolProvider(Model model) implements ShapeVisitor<Symbol>, SymbolProvider {
+public record RustSymbolProvider(Model model, String sharedCrate, Set<ShapeId> sharedShapes)
+        implements ShapeVisitor<Symbol>, SymbolProvider {
     private static final Logger LOGGER = Logger.getLogger(RustSymbolProvider.class.getName());
     public static final String FILE = "smithy-generated.rs";
     private static final String DELIM = "::";
@@ -35,26 +43,53 @@ public record RustSymbolProvider(Model model) implements ShapeVisitor<Symbol>, S
             .namespace(String.format("%s::schema", Utils.crateIdent()), DELIM)
             .putProperty(SymbolProperties.IS_DYN, true)
             .build();
+    private static final EnumSet<ShapeType> SCHEMA_SUFFIX_TYPES = EnumSet.of(
+            ShapeType.UNION,
+            ShapeType.ENUM,
+            ShapeType.INT_ENUM,
+            ShapeType.STRUCTURE,
+            ShapeType.OPERATION,
+            ShapeType.SERVICE);
     private static final EnumSet<ShapeType> NO_BUILDER_TYPES = EnumSet.of(
             ShapeType.INT_ENUM,
             ShapeType.ENUM,
             ShapeType.UNION,
             ShapeType.DOCUMENT);
 
+    public RustSymbolProvider(Model model) {
+        this(model, null, Set.of());
+    }
+
     @Override
     public Symbol toSymbol(Shape shape) {
         var type = shape.accept(this);
         // Use wrapper types for scalar traits.
         if (shape.hasTrait(TraitDefinition.class) && !GENERATED_TYPES.contains(shape.getType())) {
-            return Symbol.builder()
+            type = Symbol.builder()
                     .name(getName(shape))
                     .putProperty(SymbolProperties.INNER, type)
                     .putProperty(SymbolProperties.SCHEMA_SYMBOL, getSchemaSymbol(shape))
                     .declarationFile(FILE)
                     .build();
-        } else {
-            return type;
         }
+        if (sharedShapes.contains(shape.getId())) {
+            return importFromSharedCrate(type);
+        }
+        return type;
+    }
+
+    // Shared shapes are generated in a separate crate, so both the type and schema must be imported
+    private Symbol importFromSharedCrate(Symbol symbol) {
+        var schema = symbol.expectProperty(SymbolProperties.SCHEMA_SYMBOL)
+                .toBuilder()
+                .namespace(sharedCrate, DELIM)
+                .build();
+        var builder = symbol.toBuilder().putProperty(SymbolProperties.SCHEMA_SYMBOL, schema);
+        // Only generated types live in the shared crate. Std types (i.e. `Vec`) keep their namespace.
+        if (symbol.getNamespace().isEmpty()) {
+            builder.namespace(sharedCrate, DELIM);
+        }
+        return builder.build();
     }
 
     @Override
@@ -64,10 +99,13 @@ public record RustSymbolProvider(Model model) implements ShapeVisitor<Symbol>, S
 
     @Override
     public Symbol blobShape(BlobShape blobShape) {
-        return Symbol.builder()
-                .name("ByteBuffer")
+        if (blobShape.hasTrait(StreamingTrait.class)) {
+            return Smithy4Rs.STREAMING_BLOB.toBuilder()
+                    .putProperty(SymbolProperties.SCHEMA_SYMBOL, getSchemaSymbol(blobShape))
+                    .build();
+        }
+        return Smithy4Rs.BLOB.toBuilder()
                 .putProperty(SymbolProperties.SCHEMA_SYMBOL, getSchemaSymbol(blobShape))
-                .namespace(Utils.crateIdent(), DELIM)
                 .build();
     }
 
@@ -197,7 +235,12 @@ public record RustSymbolProvider(Model model) implements ShapeVisitor<Symbol>, S
 
     @Override
     public Symbol operationShape(OperationShape operationShape) {
-        return null;
+        // Operations are represented by a static `Operation` descriptor
+        return Symbol.builder()
+                .name(getStaticName(operationShape))
+                .putProperty(SymbolProperties.SCHEMA_SYMBOL, getSchemaSymbol(operationShape))
+                .declarationFile(FILE)
+                .build();
     }
 
     @Override
@@ -207,7 +250,12 @@ public record RustSymbolProvider(Model model) implements ShapeVisitor<Symbol>, S
 
     @Override
     public Symbol serviceShape(ServiceShape serviceShape) {
-        return null;
+        // Services are represented by a static `Service` descriptor
+        return Symbol.builder()
+                .name(getStaticName(serviceShape))
+                .putProperty(SymbolProperties.SCHEMA_SYMBOL, getSchemaSymbol(serviceShape))
+                .declarationFile(FILE)
+                .build();
     }
 
     @Override
@@ -292,6 +340,10 @@ public record RustSymbolProvider(Model model) implements ShapeVisitor<Symbol>, S
         return name;
     }
 
+    private static String getStaticName(Shape shape) {
+        return CaseUtils.toSnakeCase(shape.getId().getName()).toUpperCase(Locale.ENGLISH);
+    }
+
     private static Symbol getSchemaSymbol(Shape shape) {
         return Symbol.builder()
                 .name(getSchemaName(shape))
@@ -310,7 +362,7 @@ public record RustSymbolProvider(Model model) implements ShapeVisitor<Symbol>, S
 
     private static String getSchemaName(Shape shapeId) {
         var baseName = CaseUtils.toSnakeCase(shapeId.toShapeId().getName()).toUpperCase(Locale.ENGLISH);
-        if (GENERATED_TYPES.contains(shapeId.getType())) {
+        if (SCHEMA_SUFFIX_TYPES.contains(shapeId.getType())) {
             return baseName + "_SCHEMA";
         }
         return baseName;
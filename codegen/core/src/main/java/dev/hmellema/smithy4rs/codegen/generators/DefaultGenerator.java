@@ -18,6 +18,7 @@ import software.amazon.smithy.model.node.NumberNode;
 import software.amazon.smithy.model.node.ObjectNode;
 import software.amazon.smithy.model.node.StringNode;
 import software.amazon.smithy.model.shapes.*;
+import software.amazon.smithy.model.traits.StreamingTrait;
 import software.amazon.smithy.utils.CaseUtils;
 import software.amazon.smithy.utils.StringUtils;
 
@@ -58,7 +59,7 @@ public final class DefaultGenerator extends ShapeVisitor.Default<Void> implement
     public Void blobShape(BlobShape blobShape) {
         writer.writeInline(
                 "$T::from_bytes($S.as_bytes())",
-                Smithy4Rs.BYTE_BUFFER,
+                blobShape.hasTrait(StreamingTrait.class) ? Smithy4Rs.STREAMING_BLOB : Smithy4Rs.BLOB,
                 value.expectStringNode().getValue());
         return null;
     }
@@ -0,0 +1,218 @@
+/*
+ * Copyright Hunter Mellema & Hayden Baker. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+package dev.hmellema.smithy4rs.codegen.generators;
+
+import dev.hmellema.smithy4rs.codegen.CodeGenerationContext;
+import dev.hmellema.smithy4rs.codegen.RustCodegenSettings;
+import dev.hmellema.smithy4rs.codegen.SymbolProperties;
+import dev.hmellema.smithy4rs.codegen.symbols.Smithy4Rs;
+import dev.hmellema.smithy4rs.codegen.writer.RustWriter;
+import java.util.List;
+import java.util.Locale;
+import java.util.function.Consumer;
+import software.amazon.smithy.codegen.core.SymbolProvider;
+import software.amazon.smithy.codegen.core.directed.GenerateOperationDirective;
+import software.amazon.smithy.model.Model;
+import software.amazon.smithy.model.node.Node;
+import software.amazon.smithy.model.shapes.OperationShape;
+import software.amazon.smithy.model.shapes.ShapeId;
+import software.amazon.smithy.model.traits.UnitTypeTrait;
+import software.amazon.smithy.utils.CaseUtils;
+import software.amazon.smithy.utils.StringUtils;
+
+/**
+ * Generates the schema and typed {@code Operation} descriptor for an operation shape.
+ *
+ * <p>Operations with modeled errors also generate an error enum with a synthetic
+ * union schema containing one variant per error. Operations without errors use
+ * {@code Unit} as their error type.
+ *
+ * <p>Operations with the {@code smithy.waiters#waitable} trait also generate a
+ * {@code wait_until_*} helper function for each of their waiters.
+ */
+public final class OperationGenerator
+        implements Consumer<GenerateOperationDirective<CodeGenerationContext, RustCodegenSettings>> {
+    private static final String SYNTHETIC_NAMESPACE = "smithy.synthetic";
+    private static final ShapeId WAITABLE = ShapeId.from("smithy.waiters#waitable");
+    private static final String SCHEMA_TEMPLATE = """
+            ${smithy:T}!(${id:S}: {
+                /// Schema for [`${operation:L}`]${?hasTraits}
+                ${traits:C|}${/hasTraits}
+                operation ${schema:L}
+            });
+            """;
+    private static final String ERROR_SCHEMA_TEMPLATE = """
+            ${smithy:T}!(${errorId:S}: {
+                /// Schema for [`${error:L}`]
+                union ${errorSchema:L} {${#errors}
+                    ${value:C|}${/errors}
+                }
+            });
+            """;
+    private static final String ERROR_ENUM_TEMPLATE = """
+            /// Errors that can be returned by [`${operation:L}`]
+            #[derive(${schemaDerive:T})]
+            #[smithy_schema(${errorSchema:L})]
+            pub enum ${error:L} {${#errorVariants}
+                ${value:C|}${/errorVariants}
+            }
+            """;
+    private static final String DESCRIPTOR_TEMPLATE = """
+            /// Descriptor for the `${id:L}` operation
+            pub static ${operation:L}: ${operationType:T}<${input:T}, ${output:T}, ${errorType:L}> = ${operationType:T}::new(&${schema:L});
+            """;
+
+    private static final String WAITER_TEMPLATE = """
+            /// Wait until the `${waiter:L}` waiter for [`${operation:L}`] reaches a success state${?docs}
+            ///${/docs}${#docs}
+            /// ${value:L}${/docs}${?deprecated}
+            #[deprecated]${/deprecated}
+            pub async fn wait_until_${function:L}<F, Fut, T, S, SFut>(
+                input: &${input:T},
+                max_wait: ${duration:T},
+                call: F,
+                sleep: S,
+            ) -> Result<Option<${output:T}>, ${waiterError:T}<${errorType:L}, T>>
+            where
+                F: FnMut(${input:T}) -> Fut,
+                Fut: Future<Output = Result<${output:T}, ${requestError:T}<${errorType:L}, T>>>,
+                S: FnMut(${duration:T}) -> SFut,
+                SFut: Future<Output = ()>,
+            {
+                ${operation:L}.wait_until(${waiter:S}, input, max_wait, call, sleep).await
+            }
+            """;
+
+    @Override
+    public void accept(GenerateOperationDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+        var shape = directive.shape();
+        // Do not generate synthetic operations
+        if (shape.getId().getNamespace().startsWith(SYNTHETIC_NAMESPACE)) {
+            return;
+        }
+        var model = directive.model();
+        var provider = directive.symbolProvider();
+        var symbol = provider.toSymbol(shape);
+        var schema = symbol.expectProperty(SymbolProperties.SCHEMA_SYMBOL).getName();
+        directive.context()
+                .writerDelegator()
+                .useShapeWriter(shape, writer -> {
+                    writer.pushState();
+                    writer.putContext("smithy", Smithy4Rs.SMITHY_MACRO);
+                    writer.putContext("id", shape.getId());
+                    writer.putContext("operation", symbol.getName());
+                    writer.putContext("schema", schema);
+                    // Generate operation schema
+                    writer.pushState();
+                    writer.putContext("hasTraits", TraitInitializerGenerator.hasTraits(shape));
+                    writer.putContext("traits",
+                            new TraitInitializerGenerator(writer, shape, directive.context()));
+                    writer.write(SCHEMA_TEMPLATE);
+                    writer.popState();
+                    // Generate error union
+                    String errorType;
+                    if (shape.getErrors().isEmpty()) {
+                        errorType = provider.toSymbol(model.expectShape(UnitTypeTrait.UNIT)).getName();
+                    } else {
+                        errorType = writeErrors(writer, model, provider, shape);
+                    }
+                    // Generate typed descriptor
+                    writer.pushState();
+                    writer.putContext("operationType", Smithy4Rs.OPERATION);
+                    writer.putContext("input", provider.toSymbol(model.expectShape(shape.getInputShape())));
+                    writer.putContext("output", provider.toSymbol(model.expectShape(shape.getOutputShape())));
+                    writer.putContext("errorType", errorType);
+                    writer.write(DESCRIPTOR_TEMPLATE);
+                    // Generate waiter helpers
+                    shape.findTrait(WAITABLE).ifPresent(waitable -> writeWaiters(writer, waitable.toNode()));
+                    writer.popState();
+                    writer.popState();
+                });
+    }
+
+    private static String writeErrors(
+            RustWriter writer,
+            Model model,
+            SymbolProvider provider,
+            OperationShape shape
+    ) {
+        var errorName = StringUtils.capitalize(shape.getId().getName()) + "Error";
+        var errorSchema = CaseUtils.toSnakeCase(errorName).toUpperCase(Locale.ENGLISH) + "_SCHEMA";
+        var errorSchemas = shape.getErrors()
+                .stream()
+                .map(id -> (Runnable) new ErrorSchema(writer, model, provider, id))
+                .toList();
+        var errorVariants = shape.getErrors()
+                .stream()
+                .map(id -> (Runnable) new ErrorVariant(writer, model, provider, id))
+                .toList();
+        writer.pushState();
+        writer.putContext("errorId", ShapeId.fromParts(SYNTHETIC_NAMESPACE, errorName));
+        writer.putContext("error", errorName);
+        writer.putContext("errorSchema", errorSchema);
+        writer.putContext("errors", errorSchemas);
+        writer.putContext("errorVariants", errorVariants);
+        writer.putContext("schemaDerive", Smithy4Rs.SCHEMA_DERIVE);
+        writer.write(ERROR_SCHEMA_TEMPLATE);
+        writer.write(ERROR_ENUM_TEMPLATE);
+        writer.popState();
+        return errorName;
+    }
+
+    private static void writeWaiters(RustWriter writer, Node waitable) {
+        writer.pushState();
+        writer.putContext("waiterError", Smithy4Rs.WAITER_ERROR);
+        writer.putContext("requestError", Smithy4Rs.REQUEST_ERROR);
+        writer.putContext("duration", Smithy4Rs.DURATION);
+        for (var entry : waitable.expectObjectNode().getStringMap().entrySet()) {
+            var waiter = entry.getValue().expectObjectNode();
+            var docs = waiter.getStringMemberOrDefault("documentation", "");
+            writer.pushState();
+            writer.putContext("waiter", entry.getKey());
+            writer.putContext("function", CaseUtils.toSnakeCase(entry.getKey()));
+            writer.putContext("docs", docs.isBlank() ? List.of() : docs.strip().lines().toList());
+            writer.putContext("deprecated", waiter.getBooleanMemberOrDefault("deprecated"));
+            writer.write(WAITER_TEMPLATE);
+            writer.popState();
+        }
+        writer.popState();
+    }
+
+    private record ErrorSchema(RustWriter writer, Model model, SymbolProvider provider, ShapeId id)
+            implements Runnable {
+        private static final String TEMPLATE = "${memberIdent:L}: ${error:I} = ${memberName:S}";
+
+        @Override
+        public void run() {
+            writer.pushState();
+            writer.putContext("memberIdent", getMemberIdent(id));
+            writer.putContext("error", provider.toSymbol(model.expectShape(id)));
+            writer.putContext("memberName", id.getName());
+            writer.write(TEMPLATE);
+            writer.popState();
+        }
+    }
+
+    private record ErrorVariant(RustWriter writer, Model model, SymbolProvider provider, ShapeId id)
+            implements Runnable {
+        private static final String TEMPLATE = """
+                #[smithy_schema(${memberIdent:L})]
+                ${variant:L}(${error:T}),""";
+
+        @Override
+        public void run() {
+            writer.pushState();
+            writer.putContext("memberIdent", getMemberIdent(id));
+            writer.putContext("variant", StringUtils.capitalize(id.getName()));
+            writer.putContext("error", provider.toSymbol(model.expectShape(id)));
+            writer.write(TEMPLATE);
+            writer.popState();
+        }
+    }
+
+    private static String getMemberIdent(ShapeId id) {
+        return CaseUtils.toSnakeCase(id.getName()).toUpperCase(Locale.ENGLISH);
+    }
+}
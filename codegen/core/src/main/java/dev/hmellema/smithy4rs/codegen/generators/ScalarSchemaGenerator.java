@@ -10,8 +10,10 @@ import dev.hmellema.smithy4rs.codegen.RustSymbolProvider;
 import dev.hmellema.smithy4rs.codegen.Utils;
 import dev.hmellema.smithy4rs.codegen.sections.SchemaSection;
 import dev.hmellema.smithy4rs.codegen.symbols.Smithy4Rs;
+import java.util.Comparator;
 import java.util.function.Consumer;
 import software.amazon.smithy.codegen.core.directed.CustomizeDirective;
+import software.amazon.smithy.model.shapes.Shape;
 import software.amazon.smithy.model.shapes.ShapeType;
 import software.amazon.smithy.model.traits.TraitDefinition;
 
@@ -24,9 +26,11 @@ public class ScalarSchemaGenerator implements Consumer<CustomizeDirective<CodeGe
                     var shapes = directive.model()
                             .shapes()
                             .filter(Utils::shouldInclude)
+                            .filter(s -> !directive.settings().isShared(s))
                             .filter(s -> !s.getType().isShapeType(ShapeType.ENUM)
                                     && !s.getType().isShapeType(ShapeType.INT_ENUM))
                             .filter(s -> s.getType().getCategory().equals(ShapeType.Category.SIMPLE))
+                            .sorted(Comparator.comparing(Shape::getId))
                             .toList();
                     writer.pushState();
                     writer.putContext("smithy", Smithy4Rs.SMITHY_MACRO);
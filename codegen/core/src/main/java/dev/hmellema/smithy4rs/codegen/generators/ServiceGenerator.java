@@ -0,0 +1,79 @@
+/*
+ * Copyright Hunter Mellema & Hayden Baker. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+package dev.hmellema.smithy4rs.codegen.generators;
+
+import dev.hmellema.smithy4rs.codegen.CodeGenerationContext;
+import dev.hmellema.smithy4rs.codegen.RustCodegenSettings;
+import dev.hmellema.smithy4rs.codegen.SymbolProperties;
+import dev.hmellema.smithy4rs.codegen.symbols.Smithy4Rs;
+import java.util.Comparator;
+import java.util.function.Consumer;
+import software.amazon.smithy.codegen.core.directed.CustomizeDirective;
+import software.amazon.smithy.model.knowledge.TopDownIndex;
+import software.amazon.smithy.model.shapes.OperationShape;
+import software.amazon.smithy.model.shapes.ServiceShape;
+
+/**
+ * Generates the schema and {@code Service} descriptor for all service shapes in a model.
+ *
+ * <p>Generated code uses a synthetic service as its root, so (non-synthetic) services
+ * are generated as a customization rather than through the service directive.
+ */
+public final class ServiceGenerator
+        implements Consumer<CustomizeDirective<CodeGenerationContext, RustCodegenSettings>> {
+    private static final String SYNTHETIC_NAMESPACE = "smithy.synthetic";
+    private static final String TEMPLATE = """
+            ${smithy:T}!(${id:S}: {
+                /// Schema for [`${service:L}`]${?hasTraits}
+                ${traits:C|}${/hasTraits}
+                service ${schema:L}
+            });
+
+            /// Descriptor for the `${id:L}` service
+            pub static ${service:L}: ${serviceType:T} = ${serviceType:T}::new(
+                &${schema:L},
+                &[${#operations}&${value:L}${^key.last}, ${/key.last}${/operations}],
+            );
+            """;
+
+    @Override
+    public void accept(CustomizeDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+        var index = TopDownIndex.of(directive.model());
+        var services = directive.model()
+                .getServiceShapes()
+                .stream()
+                .sorted(Comparator.comparing(ServiceShape::getId))
+                .toList();
+        for (ServiceShape service : services) {
+            if (service.getId().getNamespace().startsWith(SYNTHETIC_NAMESPACE)) {
+                continue;
+            }
+            var provider = directive.symbolProvider();
+            var symbol = provider.toSymbol(service);
+            var operations = index.getContainedOperations(service)
+                    .stream()
+                    .sorted(Comparator.comparing(OperationShape::getId))
+                    .map(op -> provider.toSymbol(op).expectProperty(SymbolProperties.SCHEMA_SYMBOL).getName())
+                    .toList();
+            directive.context()
+                    .writerDelegator()
+                    .useShapeWriter(service, writer -> {
+                        writer.pushState();
+                        writer.putContext("smithy", Smithy4Rs.SMITHY_MACRO);
+                        writer.putContext("id", service.getId());
+                        writer.putContext("service", symbol.getName());
+                        writer.putContext("schema",
+                                symbol.expectProperty(SymbolProperties.SCHEMA_SYMBOL).getName());
+                        writer.putContext("hasTraits", TraitInitializerGenerator.hasTraits(service));
+                        writer.putContext("traits",
+                                new TraitInitializerGenerator(writer, service, directive.context()));
+                        writer.putContext("serviceType", Smithy4Rs.SERVICE);
+                        writer.putContext("operations", operations);
+                        writer.write(TEMPLATE);
+                        writer.popState();
+                    });
+        }
+    }
+}
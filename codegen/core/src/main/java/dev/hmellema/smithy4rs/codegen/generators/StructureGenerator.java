@@ -14,14 +14,17 @@ import dev.hmellema.smithy4rs.codegen.writer.RustWriter;
 import java.util.Locale;
 import java.util.function.Consumer;
 import software.amazon.smithy.codegen.core.SymbolProvider;
-import software.amazon.smithy.codegen.core.directed.GenerateStructureDirective;
+import software.amazon.smithy.codegen.core.directed.ShapeDirective;
 import software.amazon.smithy.model.Model;
 import software.amazon.smithy.model.shapes.MemberShape;
+import software.amazon.smithy.model.shapes.StructureShape;
 import software.amazon.smithy.model.traits.DefaultTrait;
+import software.amazon.smithy.model.traits.ErrorTrait;
+import software.amazon.smithy.model.traits.IdempotencyTokenTrait;
 import software.amazon.smithy.utils.CaseUtils;
 
-public final class StructureGenerator implements
-        Consumer<GenerateStructureDirective<CodeGenerationContext, RustCodegenSettings>> {
+public final class StructureGenerator<T extends ShapeDirective<StructureShape, CodeGenerationContext, RustCodegenSettings>>
+        implements Consumer<T> {
 
     private static final String SCHEMA_TEMPLATE = """
             ${smithy:T}!(${id:S}: {
@@ -34,13 +37,14 @@ public final class StructureGenerator implements
             """;
     private static final String STRUCT_TEMPLATE = """
             ${derive:C|}
-            #[smithy_schema(${shape:I})]
+            #[smithy_schema(${shape:I})]${?error}
+            #[smithy_error]${/error}
             pub struct ${shape:T} {${#memberFields}
                 ${value:C|}${/memberFields}
             }
             """;
     @Override
-    public void accept(GenerateStructureDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+    public void accept(T directive) {
         // Do not generate synthetic structs
         if (directive.shape().getId().getNamespace().startsWith("smithy.synthetic")) {
             return;
@@ -85,6 +89,7 @@ public final class StructureGenerator implements
                     // Generate `struct` impl
                     writer.pushState(new ShapeSection(directive.shape()));
                     writer.putContext("derive", new DeriveGenerator(writer, directive.shape()));
+                    writer.putContext("error", directive.shape().hasTrait(ErrorTrait.class));
                     writer.putContext("memberFields", memberFields);
                     writer.write(STRUCT_TEMPLATE);
                     writer.popState();
@@ -126,7 +131,8 @@ public final class StructureGenerator implements
                 """
                         ${?noBuilder}#[no_builder]
                         ${/noBuilder}${?default}#[default(${default:C})]
-                        ${/default}#[smithy_schema(${memberIdent:L})]
+                        ${/default}${?idempotencyToken}#[idempotency_token]
+                        ${/idempotencyToken}#[smithy_schema(${memberIdent:L})]
                         pub ${memberName:L}: ${member:N},""";
 
         @Override
@@ -137,6 +143,7 @@ public final class StructureGenerator implements
             writer.putContext("memberName", provider.toMemberName(shape));
             writer.putContext("member", provider.toSymbol(shape));
             writer.putContext("memberIdent", getMemberIdent(membername));
+            writer.putContext("idempotencyToken", shape.hasTrait(IdempotencyTokenTrait.class));
             if (shape.hasTrait(DefaultTrait.class)) {
                 var defaultValue = shape.expectTrait(DefaultTrait.class).toNode();
                 writer.putContext("default", new DefaultGenerator(writer, model, shape, provider, defaultValue));
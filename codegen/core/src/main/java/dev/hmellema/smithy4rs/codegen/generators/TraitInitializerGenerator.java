@@ -54,6 +54,8 @@ record TraitInitializerGenerator(RustWriter writer, Shape shape, CodeGenerationC
                 .keySet()
                 .stream()
                 .filter(id -> !EXCLUDED_TRAITS.contains(id))
+                // Sort traits so output does not depend on the order traits were applied in the model
+                .sorted()
                 .toList();
         if (traitsToAdd.isEmpty()) {
             return;
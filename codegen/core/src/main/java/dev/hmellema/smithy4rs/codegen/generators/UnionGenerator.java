@@ -6,6 +6,7 @@ package dev.hmellema.smithy4rs.codegen.generators;
 
 import dev.hmellema.smithy4rs.codegen.CodeGenerationContext;
 import dev.hmellema.smithy4rs.codegen.RustCodegenSettings;
+import dev.hmellema.smithy4rs.codegen.SymbolProperties;
 import dev.hmellema.smithy4rs.codegen.sections.MemberSection;
 import dev.hmellema.smithy4rs.codegen.sections.ShapeSection;
 import dev.hmellema.smithy4rs.codegen.symbols.Smithy4Rs;
@@ -116,14 +117,17 @@ public class UnionGenerator implements
             String membername,
             MemberShape shape) implements Runnable {
         private static final String TEMPLATE = """
-                #[smithy_schema(${memberSchema:L})]
+                ${?noBuilder}#[no_builder]
+                ${/noBuilder}#[smithy_schema(${memberSchema:L})]
                 ${memberName:L}(${member:T}),""";
 
         @Override
         public void run() {
             writer.pushState(new MemberSection(shape));
+            var symbol = provider.toSymbol(shape);
+            writer.putContext("noBuilder", symbol.getProperty(SymbolProperties.NO_BUILDER));
             writer.putContext("memberSchema", getMemberIdent(membername));
-            writer.putContext("member", provider.toSymbol(shape));
+            writer.putContext("member", symbol);
             writer.putContext("memberName", toMemberName(membername));
             writer.write(TEMPLATE);
             writer.popState();
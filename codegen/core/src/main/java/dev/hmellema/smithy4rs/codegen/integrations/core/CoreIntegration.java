@@ -42,6 +42,7 @@ public final class CoreIntegration implements RustCodegenIntegration {
                 // Prelude initializers,
                 new LengthTraitInitializer(),
                 new RangeTraitInitializer(),
+                new ErrorTraitInitializer(),
                 // Service traits
                 // TODO(service traits): Add initializers for service-level traits
                 // Generic initializers (note: must come _after_ all others)
@@ -0,0 +1,31 @@
+/*
+ * Copyright Hunter Mellema & Hayden Baker. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+package dev.hmellema.smithy4rs.codegen.integrations.core;
+
+import dev.hmellema.smithy4rs.codegen.CodeGenerationContext;
+import dev.hmellema.smithy4rs.codegen.TraitInitializer;
+import dev.hmellema.smithy4rs.codegen.writer.RustWriter;
+import software.amazon.smithy.model.traits.ErrorTrait;
+import software.amazon.smithy.utils.StringUtils;
+
+/**
+ * Generates an initializer for the {@code @error} trait.
+ *
+ * <p>The error trait is an enum in the Rust prelude, so it is initialized
+ * using the enum variant rather than a string constructor.
+ */
+final class ErrorTraitInitializer implements TraitInitializer<ErrorTrait> {
+
+    @Override
+    public Class<ErrorTrait> traitClass() {
+        return ErrorTrait.class;
+    }
+
+    @Override
+    public void write(RustWriter writer, CodeGenerationContext context, ErrorTrait trait) {
+        var mapping = context.traitMapping(trait);
+        writer.writeInline("$T::$L", mapping, StringUtils.capitalize(trait.getValue()));
+    }
+}
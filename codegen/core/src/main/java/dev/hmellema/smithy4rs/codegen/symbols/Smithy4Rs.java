@@ -22,6 +22,10 @@ public final class Smithy4Rs {
         return String.format("%s::schema", Utils.crateIdent());
     }
 
+    private static String waiters() {
+        return String.format("%s::waiters", Utils.crateIdent());
+    }
+
     public static final Symbol SMITHY_MACRO = Symbol.builder()
             .name("smithy")
             .namespace(Utils.crateIdent(), Utils.DELIM)
@@ -38,6 +42,10 @@ public final class Smithy4Rs {
             .name("SmithyShape")
             .namespace(derive(), Utils.DELIM)
             .build();
+    public static final Symbol SCHEMA_DERIVE = Symbol.builder()
+            .name("SchemaShape")
+            .namespace(derive(), Utils.DELIM)
+            .build();
     public static final Symbol UNION_MACRO = Symbol.builder()
             .name("smithy_union")
             .namespace(derive(), Utils.DELIM)
@@ -50,9 +58,13 @@ public final class Smithy4Rs {
             .name("IndexMap")
             .namespace(Utils.crateIdent(), "::")
             .build();
-    public static final Symbol BYTE_BUFFER = Symbol.builder()
-            .name("ByteBuffer")
-            .namespace(Utils.crateIdent(), "::")
+    public static final Symbol BLOB = Symbol.builder()
+            .name("Blob")
+            .namespace(schema(), "::")
+            .build();
+    public static final Symbol STREAMING_BLOB = Symbol.builder()
+            .name("StreamingBlob")
+            .namespace(schema(), "::")
             .build();
     public static final Symbol BIG_INT = Symbol.builder()
             .name("BigInt")
@@ -62,8 +74,28 @@ public final class Smithy4Rs {
             .name("BigDecimal")
             .namespace(Utils.crateIdent(), "::")
             .build();
+    public static final Symbol OPERATION = Symbol.builder()
+            .name("Operation")
+            .namespace(schema(), "::")
+            .build();
+    public static final Symbol SERVICE = Symbol.builder()
+            .name("Service")
+            .namespace(schema(), "::")
+            .build();
     public static final Symbol DOCUMENT = Symbol.builder()
             .name("Document")
             .namespace(schema(), "::")
             .build();
+    public static final Symbol REQUEST_ERROR = Symbol.builder()
+            .name("RequestError")
+            .namespace(waiters(), "::")
+            .build();
+    public static final Symbol WAITER_ERROR = Symbol.builder()
+            .name("WaiterError")
+            .namespace(waiters(), "::")
+            .build();
+    public static final Symbol DURATION = Symbol.builder()
+            .name("Duration")
+            .namespace("std::time", "::")
+            .build();
 }
@@ -0,0 +1,54 @@
+/*
+ * Copyright Hunter Mellema & Hayden Baker. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+package dev.hmellema.smithy4rs.codegen.transforms;
+
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Set;
+import java.util.TreeSet;
+import java.util.logging.Logger;
+import software.amazon.smithy.model.Model;
+import software.amazon.smithy.model.knowledge.TopDownIndex;
+import software.amazon.smithy.model.shapes.OperationShape;
+import software.amazon.smithy.model.shapes.ServiceShape;
+import software.amazon.smithy.model.shapes.Shape;
+import software.amazon.smithy.model.shapes.ShapeId;
+import software.amazon.smithy.model.traits.PaginatedTrait;
+import software.amazon.smithy.model.transform.ModelTransformer;
+
+/**
+ * Merges the {@code @paginated} trait of each service into the {@code @paginated} traits of its operations.
+ *
+ * <p>A service can define default pagination members that its paginated operations inherit. The
+ * runtime paginators read pagination settings from the operation schema alone, so the merged trait
+ * is written back onto each operation.
+ *
+ * <p>If an operation is bound to more than one service, the defaults of the first service (by shape ID) are used.
+ */
+public final class PaginatedTraitTransform {
+    private static final Logger LOGGER = Logger.getLogger(PaginatedTraitTransform.class.getName());
+
+    public static Model transform(Model model) {
+        var index = TopDownIndex.of(model);
+        Set<ShapeId> merged = new TreeSet<>();
+        List<Shape> updated = new ArrayList<>();
+        for (ServiceShape service : new TreeSet<>(model.getServiceShapesWithTrait(PaginatedTrait.class))) {
+            var defaults = service.expectTrait(PaginatedTrait.class);
+            for (OperationShape operation : index.getContainedOperations(service)) {
+                var paginated = operation.getTrait(PaginatedTrait.class);
+                if (paginated.isEmpty() || !merged.add(operation.getId())) {
+                    continue;
+                }
+                updated.add(operation.toBuilder().addTrait(paginated.get().merge(defaults)).build());
+            }
+        }
+        LOGGER.fine(() -> "Merged service pagination defaults into " + updated.size() + " operations.");
+        return ModelTransformer.create().replaceShapes(model, updated);
+    }
+
+    private PaginatedTraitTransform() {
+        // Utility class should not be instantiated
+    }
+}
@@ -0,0 +1,95 @@
+/*
+ * Copyright Hunter Mellema & Hayden Baker. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+package dev.hmellema.smithy4rs.codegen.transforms;
+
+import java.util.ArrayDeque;
+import java.util.Collection;
+import java.util.Comparator;
+import java.util.Deque;
+import java.util.HashSet;
+import java.util.List;
+import java.util.Optional;
+import java.util.Set;
+import java.util.logging.Logger;
+import software.amazon.smithy.model.Model;
+import software.amazon.smithy.model.loader.Prelude;
+import software.amazon.smithy.model.neighbor.Walker;
+import software.amazon.smithy.model.shapes.Shape;
+import software.amazon.smithy.model.shapes.ShapeId;
+import software.amazon.smithy.model.transform.ModelTransformer;
+
+/**
+ * Removes all shapes that are not reachable from a set of root shapes (typically services or operations).
+ *
+ * <p>Definitions of traits applied to reachable shapes are always retained, as are prelude shapes.
+ * If no roots are provided the model is returned unchanged.
+ */
+public final class PruneUnreachableShapesTransform {
+    private static final Logger LOGGER = Logger.getLogger(PruneUnreachableShapesTransform.class.getName());
+
+    /**
+     * Result of pruning a model.
+     *
+     * @param model model containing only reachable shapes
+     * @param pruned IDs of all removed shapes, sorted by shape ID
+     */
+    public record Result(Model model, List<ShapeId> pruned) {}
+
+    public static Result transform(Model model, Collection<ShapeId> roots) {
+        if (roots.isEmpty()) {
+            return new Result(model, List.of());
+        }
+        var reachable = reachable(model, roots);
+        var pruned = model.shapes()
+                .filter(shape -> !shape.isMemberShape())
+                .filter(shape -> !Prelude.isPreludeShape(shape))
+                .filter(shape -> !reachable.contains(shape))
+                .sorted(Comparator.comparing(Shape::getId))
+                .toList();
+        LOGGER.info("Pruned " + pruned.size() + " shapes not reachable from " + roots);
+
+        return new Result(
+                ModelTransformer.create().removeShapes(model, pruned),
+                pruned.stream().map(Shape::getId).toList());
+    }
+
+    /**
+     * Computes all shapes reachable from a set of root shapes, including the definitions of applied traits.
+     *
+     * @param model model to walk
+     * @param roots shapes to start walking from
+     * @return all reachable shapes, including the roots themselves
+     */
+    public static Set<Shape> reachable(Model model, Collection<ShapeId> roots) {
+        var walker = new Walker(model);
+        Set<Shape> reachable = new HashSet<>();
+        Deque<Shape> toVisit = new ArrayDeque<>();
+        for (ShapeId root : roots) {
+            toVisit.add(model.getShape(root)
+                    .orElseThrow(() -> new IllegalArgumentException(
+                            "Could not find root shape `" + root + "` in model")));
+        }
+        while (!toVisit.isEmpty()) {
+            for (Shape shape : walker.walkShapes(toVisit.pop())) {
+                if (!reachable.add(shape)) {
+                    continue;
+                }
+                // Trait definitions are not connected by relationships, so walk them separately.
+                shape.getAllTraits()
+                        .keySet()
+                        .stream()
+                        .map(model::getShape)
+                        .flatMap(Optional::stream)
+                        .filter(traitShape -> !reachable.contains(traitShape))
+                        .forEach(toVisit::add);
+            }
+        }
+        return reachable;
+    }
+
+    private PruneUnreachableShapesTransform() {
+        // Utility class should not be instantiated
+    }
+}
@@ -0,0 +1,82 @@
+/*
+ * Copyright Hunter Mellema & Hayden Baker. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+package dev.hmellema.smithy4rs.codegen.transforms;
+
+import java.util.Collection;
+import java.util.EnumSet;
+import java.util.HashMap;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.SortedSet;
+import java.util.TreeSet;
+import java.util.logging.Logger;
+import java.util.stream.Collectors;
+import software.amazon.smithy.model.Model;
+import software.amazon.smithy.model.loader.Prelude;
+import software.amazon.smithy.model.shapes.Shape;
+import software.amazon.smithy.model.shapes.ShapeId;
+import software.amazon.smithy.model.shapes.ShapeType;
+
+/**
+ * Splits a model containing multiple services into one model per service plus a model of shared types.
+ *
+ * <p>A shape is shared if it is reachable from more than one of the selected services. Services,
+ * operations, and resources are never shared and are always generated in their service's crate.
+ * Every service model still contains the shared shapes it references so symbols can be resolved,
+ * but those shapes should be imported from the shared crate rather than generated.
+ */
+public final class SplitServicesTransform {
+    private static final Logger LOGGER = Logger.getLogger(SplitServicesTransform.class.getName());
+    private static final EnumSet<ShapeType> NEVER_SHARED = EnumSet.of(
+            ShapeType.SERVICE,
+            ShapeType.RESOURCE,
+            ShapeType.OPERATION);
+
+    /**
+     * Result of splitting a model.
+     *
+     * @param services model for each service, in the order the services were provided
+     * @param shared model containing only the shared shapes and their closure
+     * @param sharedShapes IDs of all shapes that should be generated in the shared crate, sorted by shape ID
+     */
+    public record Result(Map<ShapeId, Model> services, Model shared, SortedSet<ShapeId> sharedShapes) {}
+
+    public static Result transform(Model model, List<ShapeId> services) {
+        Map<ShapeId, Integer> usages = new HashMap<>();
+        for (ShapeId service : services) {
+            PruneUnreachableShapesTransform.reachable(model, List.of(service))
+                    .stream()
+                    .filter(shape -> !shape.isMemberShape())
+                    .filter(shape -> !Prelude.isPreludeShape(shape))
+                    .filter(shape -> !NEVER_SHARED.contains(shape.getType()))
+                    .map(Shape::getId)
+                    .forEach(id -> usages.merge(id, 1, Integer::sum));
+        }
+        var sharedShapes = usages.entrySet()
+                .stream()
+                .filter(entry -> entry.getValue() > 1)
+                .map(Map.Entry::getKey)
+                .collect(Collectors.toCollection(TreeSet::new));
+        LOGGER.info("Found " + sharedShapes.size() + " shapes shared by services " + services);
+
+        Map<ShapeId, Model> serviceModels = new LinkedHashMap<>();
+        for (ShapeId service : services) {
+            serviceModels.put(service, PruneUnreachableShapesTransform.transform(model, List.of(service)).model());
+        }
+        return new Result(serviceModels, prune(model, sharedShapes), sharedShapes);
+    }
+
+    private static Model prune(Model model, Collection<ShapeId> roots) {
+        if (roots.isEmpty()) {
+            return Model.builder().build();
+        }
+        return PruneUnreachableShapesTransform.transform(model, roots).model();
+    }
+
+    private SplitServicesTransform() {
+        // Utility class should not be instantiated
+    }
+}
@@ -5,11 +5,14 @@
 package dev.hmellema.smithy4rs.codegen.transforms;
 
 import dev.hmellema.smithy4rs.codegen.Utils;
+import java.util.Comparator;
 import java.util.HashSet;
 import java.util.Set;
+import java.util.TreeSet;
 import java.util.logging.Logger;
 import java.util.stream.Collectors;
 import software.amazon.smithy.model.Model;
+import software.amazon.smithy.model.knowledge.TopDownIndex;
 import software.amazon.smithy.model.neighbor.Walker;
 import software.amazon.smithy.model.shapes.OperationShape;
 import software.amazon.smithy.model.shapes.ServiceShape;
@@ -22,8 +25,9 @@ import software.amazon.smithy.model.transform.ModelTransformer;
 /**
  * Generates a synthetic service for a set of shapes. 
  *
- * <p>Adds a set of shapes to the closure of a synthetic service shape. Operations shapes are added directly
- * to the service shape while all other shapes are added to the service via synthetic operations with synthetic inputs.
+ * <p>Adds a set of shapes to the closure of a synthetic service shape. Operations shapes (including any operations
+ * contained by a service shape) are added directly to the service shape while all other shapes are added to the
+ * service via synthetic operations with synthetic inputs.
  *
  * <p>The logic here is mostly duplicated from: https://github
  * .com/smithy-lang/smithy-java/blob/main/codegen/plugins/types-codegen/src/main/java/software/amazon/smithy/java/codegen/types/TypeCodegenSettings.java
@@ -37,11 +41,13 @@ public final class SyntheticServiceTransform {
 
     public static Model transform(Model model) {
         var serviceBuilder = ServiceShape.builder().id(SYNTHETIC_SERVICE_ID);
+        var index = TopDownIndex.of(model);
 
         Set<Shape> shapesToAdd = new HashSet<>();
         for (Shape shape : getClosure(model)) {
             switch (shape.getType()) {
-                case SERVICE, RESOURCE -> LOGGER.fine(
+                case SERVICE -> index.getContainedOperations(shape).forEach(serviceBuilder::addOperation);
+                case RESOURCE -> LOGGER.fine(
                         () -> "Skipping service-associated shape {} for type codegen..." + shape);
                 case OPERATION -> serviceBuilder.addOperation(shape.asOperationShape().orElseThrow());
                 case STRUCTURE, ENUM, INT_ENUM, UNION, LIST, MAP -> {
@@ -84,7 +90,8 @@ public final class SyntheticServiceTransform {
     }
 
     private static Set<Shape> getClosure(Model model) {
-        Set<Shape> closure = new HashSet<>();
+        // Sorted so that synthetic operations are always added in the same order
+        Set<Shape> closure = new TreeSet<>(Comparator.comparing(Shape::getId));
         model.shapes()
                 .filter(s -> !s.isMemberShape())
                 .filter(Utils::shouldInclude)
@@ -0,0 +1,42 @@
+/*
+ * Copyright Hunter Mellema & Hayden Baker. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+package dev.hmellema.smithy4rs.codegen;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.attribute.FileTime;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import software.amazon.smithy.build.FileManifest;
+
+class IncrementalFileManifestTest {
+    private static final FileTime OLD = FileTime.fromMillis(0);
+
+    @Test
+    void unchangedFilesAreNotRewritten(@TempDir Path dir) throws IOException {
+        var manifest = new IncrementalFileManifest(FileManifest.create(dir));
+        var path = manifest.writeFile("generated.rs", "struct A;");
+        Files.setLastModifiedTime(path, OLD);
+
+        manifest.writeFile("generated.rs", "struct A;");
+        assertEquals(OLD, Files.getLastModifiedTime(path));
+        assertTrue(manifest.hasFile(path));
+    }
+
+    @Test
+    void changedFilesAreRewritten(@TempDir Path dir) throws IOException {
+        var manifest = new IncrementalFileManifest(FileManifest.create(dir));
+        var path = manifest.writeFile("generated.rs", "struct A;");
+        Files.setLastModifiedTime(path, OLD);
+
+        manifest.writeFile("generated.rs", "struct B;");
+        assertEquals("struct B;", Files.readString(path));
+        assertTrue(Files.getLastModifiedTime(path).compareTo(OLD) > 0);
+    }
+}
@@ -7,7 +7,9 @@ package dev.hmellema.smithy4rs.codegen;
 import dev.hmellema.smithy4rs.codegen.generators.EnumGenerator;
 import dev.hmellema.smithy4rs.codegen.generators.ListGenerator;
 import dev.hmellema.smithy4rs.codegen.generators.MapGenerator;
+import dev.hmellema.smithy4rs.codegen.generators.OperationGenerator;
 import dev.hmellema.smithy4rs.codegen.generators.ScalarSchemaGenerator;
+import dev.hmellema.smithy4rs.codegen.generators.ServiceGenerator;
 import dev.hmellema.smithy4rs.codegen.generators.StructureGenerator;
 import dev.hmellema.smithy4rs.codegen.generators.UnionGenerator;
 import software.amazon.smithy.codegen.core.SymbolProvider;
@@ -20,6 +22,7 @@ import software.amazon.smithy.codegen.core.directed.GenerateErrorDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateIntEnumDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateListDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateMapDirective;
+import software.amazon.smithy.codegen.core.directed.GenerateOperationDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateServiceDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateStructureDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateUnionDirective;
@@ -54,12 +57,17 @@ public class TestRustCodegen implements
 
     @Override
     public void generateStructure(GenerateStructureDirective<CodeGenerationContext, RustCodegenSettings> directive) {
-        new StructureGenerator().accept(directive);
+        new StructureGenerator<>().accept(directive);
     }
 
     @Override
     public void generateError(GenerateErrorDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+        new StructureGenerator<>().accept(directive);
+    }
 
+    @Override
+    public void generateOperation(GenerateOperationDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+        new OperationGenerator().accept(directive);
     }
 
     @Override
@@ -90,5 +98,6 @@ public class TestRustCodegen implements
     @Override
     public void customizeBeforeIntegrations(CustomizeDirective<CodeGenerationContext, RustCodegenSettings> directive) {
         new ScalarSchemaGenerator().accept(directive);
+        new ServiceGenerator().accept(directive);
     }
 }
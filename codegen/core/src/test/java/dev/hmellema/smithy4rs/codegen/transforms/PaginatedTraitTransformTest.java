@@ -0,0 +1,88 @@
+/*
+ * Copyright Hunter Mellema & Hayden Baker. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+package dev.hmellema.smithy4rs.codegen.transforms;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+
+import java.util.Optional;
+import org.junit.jupiter.api.Test;
+import software.amazon.smithy.model.Model;
+import software.amazon.smithy.model.shapes.ShapeId;
+import software.amazon.smithy.model.traits.PaginatedTrait;
+
+class PaginatedTraitTransformTest {
+    private static final Model MODEL = Model.assembler()
+            .addUnparsedModel("test.smithy", """
+                    $version: "2"
+                    namespace com.test
+
+                    @paginated(inputToken: "nextToken", outputToken: "nextToken", pageSize: "maxResults")
+                    service Service {
+                        operations: [ListItems, ListTags, GetItem]
+                    }
+
+                    @paginated(items: "items")
+                    operation ListItems {
+                        input := {
+                            nextToken: String
+                            maxResults: Integer
+                        }
+                        output := {
+                            nextToken: String
+                            items: StringList
+                        }
+                    }
+
+                    @paginated(inputToken: "after", outputToken: "cursor", items: "tags", pageSize: "limit")
+                    operation ListTags {
+                        input := {
+                            after: String
+                            limit: Integer
+                        }
+                        output := {
+                            cursor: String
+                            tags: StringList
+                        }
+                    }
+
+                    operation GetItem {}
+
+                    list StringList {
+                        member: String
+                    }
+                    """)
+            .assemble()
+            .unwrap();
+
+    @Test
+    void mergesServiceDefaultsIntoOperation() {
+        var result = PaginatedTraitTransform.transform(MODEL);
+        var paginated = result.expectShape(ShapeId.from("com.test#ListItems")).expectTrait(PaginatedTrait.class);
+
+        assertEquals(Optional.of("nextToken"), paginated.getInputToken());
+        assertEquals(Optional.of("nextToken"), paginated.getOutputToken());
+        assertEquals(Optional.of("items"), paginated.getItems());
+        assertEquals(Optional.of("maxResults"), paginated.getPageSize());
+    }
+
+    @Test
+    void keepsOperationOverrides() {
+        var result = PaginatedTraitTransform.transform(MODEL);
+        var paginated = result.expectShape(ShapeId.from("com.test#ListTags")).expectTrait(PaginatedTrait.class);
+
+        assertEquals(Optional.of("after"), paginated.getInputToken());
+        assertEquals(Optional.of("cursor"), paginated.getOutputToken());
+        assertEquals(Optional.of("tags"), paginated.getItems());
+        assertEquals(Optional.of("limit"), paginated.getPageSize());
+    }
+
+    @Test
+    void skipsUnpaginatedOperations() {
+        var result = PaginatedTraitTransform.transform(MODEL);
+
+        assertFalse(result.expectShape(ShapeId.from("com.test#GetItem")).hasTrait(PaginatedTrait.class));
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * Copyright Hunter Mellema & Hayden Baker. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+package dev.hmellema.smithy4rs.codegen.transforms;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertSame;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import java.util.List;
+import org.junit.jupiter.api.Test;
+import software.amazon.smithy.model.Model;
+import software.amazon.smithy.model.shapes.ShapeId;
+
+class PruneUnreachableShapesTransformTest {
+    private static final Model MODEL = Model.assembler()
+            .addUnparsedModel("test.smithy", """
+                    $version: "2"
+                    namespace com.test
+
+                    @trait
+                    string myTrait
+
+                    service Service {
+                        operations: [GetItem]
+                    }
+
+                    operation GetItem {
+                        input := {
+                            @myTrait("a")
+                            id: String
+                        }
+                    }
+
+                    structure Orphan {
+                        nested: OrphanList
+                    }
+
+                    list OrphanList {
+                        member: String
+                    }
+                    """)
+            .assemble()
+            .unwrap();
+
+    @Test
+    void prunesUnreachableShapes() {
+        var result = PruneUnreachableShapesTransform.transform(MODEL, List.of(ShapeId.from("com.test#Service")));
+        assertEquals(
+                List.of(ShapeId.from("com.test#Orphan"), ShapeId.from("com.test#OrphanList")),
+                result.pruned());
+        assertTrue(result.model().getShape(ShapeId.from("com.test#GetItem")).isPresent());
+        // Applied trait definitions are retained
+        assertTrue(result.model().getShape(ShapeId.from("com.test#myTrait")).isPresent());
+        assertTrue(result.model().getShape(ShapeId.from("com.test#Orphan")).isEmpty());
+    }
+
+    @Test
+    void noRootsKeepsAllShapes() {
+        var result = PruneUnreachableShapesTransform.transform(MODEL, List.of());
+        assertSame(MODEL, result.model());
+        assertTrue(result.pruned().isEmpty());
+    }
+
+    @Test
+    void missingRootFails() {
+        assertThrows(IllegalArgumentException.class,
+                () -> PruneUnreachableShapesTransform.transform(MODEL, List.of(ShapeId.from("com.test#Missing"))));
+    }
+}
@@ -0,0 +1,89 @@
+/*
+ * Copyright Hunter Mellema & Hayden Baker. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+package dev.hmellema.smithy4rs.codegen.transforms;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import java.util.List;
+import org.junit.jupiter.api.Test;
+import software.amazon.smithy.model.Model;
+import software.amazon.smithy.model.shapes.ShapeId;
+
+class SplitServicesTransformTest {
+    private static final ShapeId SERVICE_A = ShapeId.from("com.test#ServiceA");
+    private static final ShapeId SERVICE_B = ShapeId.from("com.test#ServiceB");
+    private static final Model MODEL = Model.assembler()
+            .addUnparsedModel("test.smithy", """
+                    $version: "2"
+                    namespace com.test
+
+                    service ServiceA {
+                        operations: [GetA, GetShared]
+                    }
+
+                    service ServiceB {
+                        operations: [GetB, GetShared]
+                    }
+
+                    operation GetA {
+                        input := {
+                            item: Item
+                            a: OnlyA
+                        }
+                    }
+
+                    operation GetB {
+                        input := {
+                            item: Item
+                        }
+                    }
+
+                    operation GetShared {}
+
+                    structure Item {
+                        tags: Tags
+                    }
+
+                    list Tags {
+                        member: String
+                    }
+
+                    structure OnlyA {}
+                    """)
+            .assemble()
+            .unwrap();
+
+    @Test
+    void sharesShapesUsedByMultipleServices() {
+        var result = SplitServicesTransform.transform(MODEL, List.of(SERVICE_A, SERVICE_B));
+        // Operations are never shared, even if they are bound to multiple services
+        assertEquals(
+                List.of(ShapeId.from("com.test#Item"), ShapeId.from("com.test#Tags")),
+                List.copyOf(result.sharedShapes()));
+        assertTrue(result.shared().getShape(ShapeId.from("com.test#Item")).isPresent());
+        assertTrue(result.shared().getShape(ShapeId.from("com.test#OnlyA")).isEmpty());
+        assertTrue(result.shared().getShape(SERVICE_A).isEmpty());
+    }
+
+    @Test
+    void createsModelPerService() {
+        var result = SplitServicesTransform.transform(MODEL, List.of(SERVICE_A, SERVICE_B));
+        assertEquals(List.of(SERVICE_A, SERVICE_B), List.copyOf(result.services().keySet()));
+
+        var modelB = result.services().get(SERVICE_B);
+        assertTrue(modelB.getShape(ShapeId.from("com.test#GetShared")).isPresent());
+        // Shared shapes are retained so service crates can reference them
+        assertTrue(modelB.getShape(ShapeId.from("com.test#Item")).isPresent());
+        assertTrue(modelB.getShape(ShapeId.from("com.test#OnlyA")).isEmpty());
+        assertTrue(modelB.getShape(SERVICE_A).isEmpty());
+    }
+
+    @Test
+    void singleServiceHasNoSharedShapes() {
+        var result = SplitServicesTransform.transform(MODEL, List.of(SERVICE_A));
+        assertTrue(result.sharedShapes().isEmpty());
+    }
+}
@@ -1,7 +1,6 @@
 use smithy4rs_core::{
     BigDecimal,
     BigInt,
-    ByteBuffer,
     IndexMap,
     Instant,
     derive::{
@@ -23,7 +22,10 @@ use smithy4rs_core::{
         STRING,
         TIMESTAMP,
     },
-    schema::Document,
+    schema::{
+        Blob,
+        Document,
+    },
     smithy,
 };
 
@@ -145,9 +147,9 @@ pub struct DefaultStructure {
     #[default("default".to_string())]
     #[smithy_schema(STRING)]
     pub string: String,
-    #[default(ByteBuffer::from_bytes("YmxvYg==".as_bytes()))]
+    #[default(Blob::from_bytes("YmxvYg==".as_bytes()))]
     #[smithy_schema(BLOB)]
-    pub blob: ByteBuffer,
+    pub blob: Blob,
     #[no_builder]
     #[default(true.into())]
     #[smithy_schema(BOOL_DOC)]
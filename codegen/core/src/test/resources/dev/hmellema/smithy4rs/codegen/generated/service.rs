@@ -0,0 +1,99 @@
+use smithy4rs_core::{
+    derive::{
+        SchemaShape,
+        SmithyShape,
+    },
+    doc_map,
+    prelude::{
+        ErrorTrait,
+        RequiredTrait,
+        STRING,
+    },
+    schema::{
+        DynamicTrait,
+        Operation,
+        Service,
+    },
+    smithy,
+};
+
+smithy!("com.test#GetItemInput": {
+    /// Schema for [`GetItemInput`]
+    @DynamicTrait::from("smithy.api#input", doc_map![]);
+    structure GET_ITEM_INPUT_SCHEMA {
+        @RequiredTrait::builder().build();
+        ID: STRING = "id"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_ITEM_INPUT_SCHEMA)]
+pub struct GetItemInput {
+    #[smithy_schema(ID)]
+    pub id: String,
+}
+
+smithy!("com.test#GetItemOutput": {
+    /// Schema for [`GetItemOutput`]
+    @DynamicTrait::from("smithy.api#output", doc_map![]);
+    structure GET_ITEM_OUTPUT_SCHEMA {
+        NAME: STRING = "name"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_ITEM_OUTPUT_SCHEMA)]
+pub struct GetItemOutput {
+    #[smithy_schema(NAME)]
+    pub name: Option<String>,
+}
+
+smithy!("com.test#ItemNotFound": {
+    /// Schema for [`ItemNotFound`]
+    @ErrorTrait::Client;
+    structure ITEM_NOT_FOUND_SCHEMA {
+        MESSAGE: STRING = "message"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(ITEM_NOT_FOUND_SCHEMA)]
+#[smithy_error]
+pub struct ItemNotFound {
+    #[smithy_schema(MESSAGE)]
+    pub message: Option<String>,
+}
+
+smithy!("com.test#GetItem": {
+    /// Schema for [`GET_ITEM`]
+    operation GET_ITEM_SCHEMA
+});
+
+smithy!("smithy.synthetic#GetItemError": {
+    /// Schema for [`GetItemError`]
+    union GET_ITEM_ERROR_SCHEMA {
+        ITEM_NOT_FOUND: ITEM_NOT_FOUND_SCHEMA = "ItemNotFound"
+    }
+});
+
+/// Errors that can be returned by [`GET_ITEM`]
+#[derive(SchemaShape)]
+#[smithy_schema(GET_ITEM_ERROR_SCHEMA)]
+pub enum GetItemError {
+    #[smithy_schema(ITEM_NOT_FOUND)]
+    ItemNotFound(ItemNotFound),
+}
+
+/// Descriptor for the `com.test#GetItem` operation
+pub static GET_ITEM: Operation<GetItemInput, GetItemOutput, GetItemError> = Operation::new(&GET_ITEM_SCHEMA);
+
+smithy!("com.test#ItemService": {
+    /// Schema for [`ITEM_SERVICE`]
+    service ITEM_SERVICE_SCHEMA
+});
+
+/// Descriptor for the `com.test#ItemService` service
+pub static ITEM_SERVICE: Service = Service::new(
+    &ITEM_SERVICE_SCHEMA,
+    &[&GET_ITEM_SCHEMA],
+);
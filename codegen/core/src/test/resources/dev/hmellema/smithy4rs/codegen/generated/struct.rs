@@ -1,5 +1,4 @@
 use smithy4rs_core::{
-    ByteBuffer,
     Instant,
     derive::SmithyShape,
     prelude::{
@@ -15,6 +14,7 @@ use smithy4rs_core::{
         STRING,
         TIMESTAMP,
     },
+    schema::Blob,
     smithy,
 };
 
@@ -59,7 +59,7 @@ pub struct AllPrimitivesStruct {
     #[smithy_schema(BOOLEAN_FIELD)]
     pub boolean_field: Option<bool>,
     #[smithy_schema(BLOB_FIELD)]
-    pub blob_field: Option<ByteBuffer>,
+    pub blob_field: Option<Blob>,
     #[smithy_schema(TIMESTAMP_FIELD)]
     pub timestamp_field: Instant,
 }
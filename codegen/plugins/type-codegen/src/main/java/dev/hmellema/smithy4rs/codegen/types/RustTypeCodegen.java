@@ -11,7 +11,9 @@ import dev.hmellema.smithy4rs.codegen.RustSymbolProvider;
 import dev.hmellema.smithy4rs.codegen.generators.EnumGenerator;
 import dev.hmellema.smithy4rs.codegen.generators.ListGenerator;
 import dev.hmellema.smithy4rs.codegen.generators.MapGenerator;
+import dev.hmellema.smithy4rs.codegen.generators.OperationGenerator;
 import dev.hmellema.smithy4rs.codegen.generators.ScalarSchemaGenerator;
+import dev.hmellema.smithy4rs.codegen.generators.ServiceGenerator;
 import dev.hmellema.smithy4rs.codegen.generators.StructureGenerator;
 import dev.hmellema.smithy4rs.codegen.generators.UnionGenerator;
 import software.amazon.smithy.codegen.core.SymbolProvider;
@@ -24,9 +26,11 @@ import software.amazon.smithy.codegen.core.directed.GenerateErrorDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateIntEnumDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateListDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateMapDirective;
+import software.amazon.smithy.codegen.core.directed.GenerateOperationDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateServiceDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateStructureDirective;
 import software.amazon.smithy.codegen.core.directed.GenerateUnionDirective;
+import software.amazon.smithy.codegen.core.directed.ShapeDirective;
 
 public class RustTypeCodegen implements
         DirectedCodegen<CodeGenerationContext, RustCodegenSettings, RustCodegenIntegration> {
@@ -47,7 +51,8 @@ public class RustTypeCodegen implements
     public SymbolProvider createSymbolProvider(
             CreateSymbolProviderDirective<RustCodegenSettings> directive
     ) {
-        return new RustSymbolProvider(directive.model());
+        var settings = directive.settings();
+        return new RustSymbolProvider(directive.model(), settings.sharedCrate(), settings.sharedShapes());
     }
 
     @Override
@@ -57,41 +62,76 @@ public class RustTypeCodegen implements
 
     @Override
     public void generateStructure(GenerateStructureDirective<CodeGenerationContext, RustCodegenSettings> directive) {
-        new StructureGenerator().accept(directive);
+        if (isShared(directive)) {
+            return;
+        }
+        new StructureGenerator<>().accept(directive);
     }
 
     @Override
     public void generateError(GenerateErrorDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+        if (isShared(directive)) {
+            return;
+        }
+        new StructureGenerator<>().accept(directive);
+    }
 
+    @Override
+    public void generateOperation(GenerateOperationDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+        if (isShared(directive)) {
+            return;
+        }
+        new OperationGenerator().accept(directive);
     }
 
     @Override
     public void generateUnion(GenerateUnionDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+        if (isShared(directive)) {
+            return;
+        }
         new UnionGenerator().accept(directive);
     }
 
     @Override
     public void generateList(GenerateListDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+        if (isShared(directive)) {
+            return;
+        }
         new ListGenerator().accept(directive);
     }
 
     @Override
     public void generateMap(GenerateMapDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+        if (isShared(directive)) {
+            return;
+        }
         new MapGenerator().accept(directive);
     }
 
     @Override
     public void generateEnumShape(GenerateEnumDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+        if (isShared(directive)) {
+            return;
+        }
         new EnumGenerator<>().accept(directive);
     }
 
     @Override
     public void generateIntEnumShape(GenerateIntEnumDirective<CodeGenerationContext, RustCodegenSettings> directive) {
+        if (isShared(directive)) {
+            return;
+        }
         new EnumGenerator<>().accept(directive);
     }
 
+    // Shared shapes are generated in the shared crate and imported by each service crate
+    private static boolean isShared(ShapeDirective<?, CodeGenerationContext, RustCodegenSettings> directive) {
+        return directive.settings().isShared(directive.shape());
+    }
+
     @Override
     public void customizeBeforeIntegrations(CustomizeDirective<CodeGenerationContext, RustCodegenSettings> directive) {
         new ScalarSchemaGenerator().accept(directive);
+        new ServiceGenerator().accept(directive);
     }
 }
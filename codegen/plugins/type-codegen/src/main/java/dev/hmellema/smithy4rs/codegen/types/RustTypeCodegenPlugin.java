@@ -5,18 +5,26 @@
 package dev.hmellema.smithy4rs.codegen.types;
 
 import dev.hmellema.smithy4rs.codegen.CodeGenerationContext;
+import dev.hmellema.smithy4rs.codegen.IncrementalFileManifest;
 import dev.hmellema.smithy4rs.codegen.RustCodegenIntegration;
 import dev.hmellema.smithy4rs.codegen.RustCodegenSettings;
+import dev.hmellema.smithy4rs.codegen.transforms.PaginatedTraitTransform;
+import dev.hmellema.smithy4rs.codegen.transforms.PruneUnreachableShapesTransform;
+import dev.hmellema.smithy4rs.codegen.transforms.SplitServicesTransform;
 import dev.hmellema.smithy4rs.codegen.transforms.SyntheticServiceTransform;
 import dev.hmellema.smithy4rs.codegen.writer.RustWriter;
+import software.amazon.smithy.build.FileManifest;
 import software.amazon.smithy.build.PluginContext;
 import software.amazon.smithy.build.SmithyBuildPlugin;
 import software.amazon.smithy.codegen.core.directed.CodegenDirector;
+import software.amazon.smithy.model.Model;
+import software.amazon.smithy.utils.CaseUtils;
 
 /**
  * Test code generator that executes Rust directed codegen for Snapshot tests
  */
 public final class RustTypeCodegenPlugin implements SmithyBuildPlugin {
+    private static final String PRUNED_REPORT = "pruned-shapes.txt";
 
     @Override
     public String getName() {
@@ -25,14 +33,49 @@ public final class RustTypeCodegenPlugin implements SmithyBuildPlugin {
 
     @Override
     public void execute(PluginContext context) {
+        var settings = RustCodegenSettings.fromNode(context.getSettings());
+        var manifest = context.getFileManifest();
+        if (!settings.services().isEmpty()) {
+            executeSplit(context.getModel(), settings, manifest);
+            return;
+        }
+        var pruneResult = PruneUnreachableShapesTransform.transform(context.getModel(), settings.roots());
+        if (!pruneResult.pruned().isEmpty()) {
+            var report = new StringBuilder();
+            pruneResult.pruned().forEach(id -> report.append(id).append(System.lineSeparator()));
+            manifest.writeFile(PRUNED_REPORT, report.toString());
+        }
+        generate(pruneResult.model(), settings, manifest);
+    }
+
+    // Generates each service into its own crate directory, with shapes used by more than one
+    // service generated once in a shared crate directory.
+    private static void executeSplit(Model model, RustCodegenSettings settings, FileManifest manifest) {
+        var split = SplitServicesTransform.transform(model, settings.services());
+        if (!split.sharedShapes().isEmpty()) {
+            generate(split.shared(), settings, subManifest(manifest, settings.sharedCrate()));
+        }
+        for (var entry : split.services().entrySet()) {
+            var crateName = CaseUtils.toSnakeCase(entry.getKey().getName());
+            generate(
+                    entry.getValue(),
+                    settings.forService(entry.getKey(), split.sharedShapes()),
+                    subManifest(manifest, crateName));
+        }
+    }
+
+    private static FileManifest subManifest(FileManifest manifest, String crateName) {
+        return FileManifest.create(manifest.getBaseDir().resolve(crateName));
+    }
+
+    private static void generate(Model model, RustCodegenSettings settings, FileManifest manifest) {
         CodegenDirector<RustWriter, RustCodegenIntegration, CodeGenerationContext, RustCodegenSettings> runner =
                 new CodegenDirector<>();
-        var settings = RustCodegenSettings.fromNode(context.getSettings());
         runner.settings(settings);
         runner.directedCodegen(new RustTypeCodegen());
-        runner.fileManifest(context.getFileManifest());
+        runner.fileManifest(settings.incremental() ? new IncrementalFileManifest(manifest) : manifest);
         runner.service(SyntheticServiceTransform.SYNTHETIC_SERVICE_ID);
-        runner.model(SyntheticServiceTransform.transform(context.getModel()));
+        runner.model(SyntheticServiceTransform.transform(PaginatedTraitTransform.transform(model)));
         runner.integrationClass(RustCodegenIntegration.class);
         // TODO(transforms): Add default transforms
         runner.run();
@@ -0,0 +1,348 @@
+//! # Conformance fixtures
+//!
+//! Serializes the `smithy4rs-test-utils` shape corpus into language-agnostic
+//! fixture files, so the output of the `smithy4rs` codecs can be compared
+//! byte-for-byte with other Smithy implementations (i.e. `smithy-java` or
+//! `smithy-kotlin`).
+//!
+//! Each [`Fixture`] is written to its own directory:
+//!
+//! ```text
+//! <out>/manifest.json
+//! <out>/<fixture>/input.json     # Shape value as a Smithy JSON node
+//! <out>/<fixture>/expected.json  # Expected bytes written by the JSON codec
+//! <out>/<fixture>/expected.cbor  # Expected bytes written by the CBOR codec
+//! ```
+//!
+//! The manifest lists every fixture with the ID of its shape, so that a test
+//! harness in another language can deserialize `input.json` into its own
+//! generated type, serialize it with each [`Protocol`], and compare the result
+//! against the expected bytes.
+//!
+//! Fixtures are regenerated with:
+//!
+//! ```text
+//! cargo run -p smithy4rs-conformance -- <out>
+//! ```
+use std::{fmt::Write as _, fs, io, path::Path};
+
+use smithy4rs_cbor_codec::CborCodec;
+use smithy4rs_core::{
+    IndexMap, Instant,
+    schema::{Blob, Document, Schema},
+    serde::{Codec, CodecError},
+};
+use smithy4rs_json_codec::JsonCodec;
+use smithy4rs_test_utils::{
+    ALL_PRIMITIVES_STRUCT_SCHEMA, AllPrimitivesStruct, EXPR_SCHEMA, Expr, INNER_STRUCT_SCHEMA,
+    InnerStruct, NESTED_COLLECTIONS_STRUCT_SCHEMA, NUMERIC_TYPES_STRUCT_SCHEMA,
+    NestedCollectionsStruct, NumericTypesStruct, OPTIONAL_FIELDS_STRUCT_SCHEMA,
+    OptionalFieldsStruct, SIMPLE_STRUCT_SCHEMA, SPARSE_COLLECTIONS_STRUCT_SCHEMA, SimpleStruct,
+    SparseCollectionsStruct, TREE_NODE_SCHEMA, TestUnion, TreeNode, UNION,
+};
+use thiserror::Error;
+
+/// Version of the fixture layout, written to the manifest.
+///
+/// Bumped whenever the layout of the output directory changes.
+pub const FIXTURE_VERSION: u32 = 1;
+
+/// Name of the manifest file written to the root of the output directory.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Name of the input document file written for each fixture.
+pub const INPUT_FILE: &str = "input.json";
+
+/// Error raised while generating fixtures.
+#[derive(Error, Debug)]
+pub enum FixtureError {
+    /// A fixture could not be serialized.
+    #[error("Failed to serialize fixture `{fixture}`: {source}")]
+    Codec {
+        /// Name of the fixture.
+        fixture: &'static str,
+        /// Underlying codec error.
+        source: CodecError,
+    },
+    /// A fixture file could not be written.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// A shape value from the test corpus.
+pub struct Fixture {
+    /// Unique name of the fixture, used as its directory name.
+    pub name: &'static str,
+    /// Schema of the shape.
+    pub schema: &'static Schema,
+    /// Value of the shape.
+    pub value: Box<dyn Document>,
+}
+
+impl Fixture {
+    fn new(
+        name: &'static str,
+        schema: &'static Schema,
+        value: impl Into<Box<dyn Document>>,
+    ) -> Self {
+        Fixture {
+            name,
+            schema,
+            value: value.into(),
+        }
+    }
+
+    /// Serialize the value of this fixture with `codec`.
+    ///
+    /// # Errors
+    /// Returns a [`FixtureError::Codec`] if the value could not be serialized.
+    pub fn serialize(&self, codec: &dyn Codec) -> Result<Vec<u8>, FixtureError> {
+        codec
+            .serialize_document(self.schema, &self.value)
+            .map_err(|source| FixtureError::Codec {
+                fixture: self.name,
+                source,
+            })
+    }
+}
+
+/// A wire format that expected bytes are written for.
+pub struct Protocol {
+    /// Name of the protocol, used as the extension of its expected output file.
+    pub name: &'static str,
+    /// Codec used to write the expected bytes.
+    pub codec: &'static dyn Codec,
+}
+
+impl Protocol {
+    /// Name of the file holding the expected bytes for this protocol.
+    #[must_use]
+    pub fn expected_file(&self) -> String {
+        format!("expected.{}", self.name)
+    }
+}
+
+/// Protocols that expected bytes are generated for.
+pub const PROTOCOLS: &[Protocol] = &[
+    Protocol {
+        name: "json",
+        codec: &JsonCodec,
+    },
+    Protocol {
+        name: "cbor",
+        codec: &CborCodec,
+    },
+];
+
+/// Get the shape corpus that fixtures are generated from.
+///
+/// Fixtures only use shapes and values that every protocol can represent.
+#[must_use]
+pub fn fixtures() -> Vec<Fixture> {
+    let inner = |suffix: &str| InnerStruct {
+        field_a: format!("a{suffix}"),
+        field_b: format!("b{suffix}"),
+        field_c: format!("c{suffix}"),
+    };
+    let mut map_nested = IndexMap::new();
+    map_nested.insert("first".to_string(), inner("1"));
+    map_nested.insert("second".to_string(), inner("2"));
+    let mut sparse_map = IndexMap::new();
+    sparse_map.insert("present".to_string(), Some("value".to_string()));
+    sparse_map.insert("absent".to_string(), None);
+
+    vec![
+        Fixture::new(
+            "simple_struct",
+            &SIMPLE_STRUCT_SCHEMA,
+            SimpleStruct {
+                field_a: "hello".to_string(),
+                field_b: 42,
+            },
+        ),
+        Fixture::new(
+            "optional_fields_unset",
+            &OPTIONAL_FIELDS_STRUCT_SCHEMA,
+            OptionalFieldsStruct {
+                required_field: "required".to_string(),
+                optional_field: None,
+            },
+        ),
+        Fixture::new(
+            "optional_fields_set",
+            &OPTIONAL_FIELDS_STRUCT_SCHEMA,
+            OptionalFieldsStruct {
+                required_field: "required".to_string(),
+                optional_field: Some("optional".to_string()),
+            },
+        ),
+        Fixture::new(
+            "all_primitives",
+            &ALL_PRIMITIVES_STRUCT_SCHEMA,
+            AllPrimitivesStruct {
+                string_field: "text with \"quotes\" and unicode: \u{e9}".to_string(),
+                byte_field: -8,
+                short_field: 1_024,
+                integer_field: -70_000,
+                long_field: 9_007_199_254_740_993,
+                float_field: 1.5,
+                double_field: -0.25,
+                boolean_field: true,
+                blob_field: Blob::from_static(b"blob\x00data"),
+                timestamp_field: Instant::from_epoch_milliseconds(1_700_000_000_000)
+                    .expect("valid timestamp"),
+            },
+        ),
+        Fixture::new(
+            "numeric_limits",
+            &NUMERIC_TYPES_STRUCT_SCHEMA,
+            NumericTypesStruct {
+                byte_val: i8::MIN,
+                short_val: i16::MAX,
+                int_val: i32::MIN,
+                long_val: i64::MAX,
+                float_val: f32::MAX,
+                double_val: f64::MIN_POSITIVE,
+            },
+        ),
+        Fixture::new("inner_struct", &INNER_STRUCT_SCHEMA, inner("")),
+        Fixture::new(
+            "nested_collections",
+            &NESTED_COLLECTIONS_STRUCT_SCHEMA,
+            NestedCollectionsStruct {
+                name: "nested".to_string(),
+                count: 2,
+                single_nested: inner("0"),
+                optional_nested: None,
+                list_nested: vec![inner("1"), inner("2")],
+                map_nested,
+            },
+        ),
+        Fixture::new(
+            "sparse_collections",
+            &SPARSE_COLLECTIONS_STRUCT_SCHEMA,
+            SparseCollectionsStruct {
+                sparse_list: vec![Some("value".to_string()), None],
+                sparse_map,
+            },
+        ),
+        Fixture::new(
+            "recursive_tree",
+            &TREE_NODE_SCHEMA,
+            TreeNode {
+                value: 1,
+                children: Some(vec![
+                    TreeNode {
+                        value: 2,
+                        children: Some(Vec::new()),
+                        parent: None,
+                    },
+                    TreeNode {
+                        value: 3,
+                        children: None,
+                        parent: None,
+                    },
+                ]),
+                parent: None,
+            },
+        ),
+        Fixture::new("union_string", &UNION, TestUnion::A("member".to_string())),
+        Fixture::new("union_integer", &UNION, TestUnion::B(7)),
+        Fixture::new("union_unit", &UNION, TestUnion::C),
+        Fixture::new(
+            "recursive_union",
+            &EXPR_SCHEMA,
+            Expr::Negate(Box::new(Expr::Negate(Box::new(Expr::Literal(5))))),
+        ),
+    ]
+}
+
+/// Write all [`fixtures`] and their expected bytes for every [`PROTOCOLS`] entry
+/// to `dir`, along with a manifest listing the fixtures.
+///
+/// Existing fixture files in `dir` are overwritten.
+///
+/// # Errors
+/// Returns a [`FixtureError`] if a fixture could not be serialized or written.
+pub fn write_fixtures(dir: &Path) -> Result<(), FixtureError> {
+    let fixtures = fixtures();
+    for fixture in &fixtures {
+        let fixture_dir = dir.join(fixture.name);
+        fs::create_dir_all(&fixture_dir)?;
+        fs::write(fixture_dir.join(INPUT_FILE), fixture.serialize(&JsonCodec)?)?;
+        for protocol in PROTOCOLS {
+            fs::write(
+                fixture_dir.join(protocol.expected_file()),
+                fixture.serialize(protocol.codec)?,
+            )?;
+        }
+    }
+    fs::write(dir.join(MANIFEST_FILE), manifest(&fixtures))?;
+    Ok(())
+}
+
+/// Render the manifest describing `fixtures`.
+///
+/// Fixture names, shape IDs, and protocol names never need escaping, so the
+/// manifest is written directly rather than through a codec.
+fn manifest(fixtures: &[Fixture]) -> String {
+    let protocols = PROTOCOLS
+        .iter()
+        .map(|protocol| format!("\"{}\"", protocol.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut out = String::new();
+    let _ = writeln!(out, "{{");
+    let _ = writeln!(out, "  \"version\": {FIXTURE_VERSION},");
+    let _ = writeln!(out, "  \"protocols\": [{protocols}],");
+    let _ = writeln!(out, "  \"fixtures\": [");
+    for (index, fixture) in fixtures.iter().enumerate() {
+        let expected = PROTOCOLS
+            .iter()
+            .map(|protocol| {
+                format!(
+                    "\"{}\": \"{}/{}\"",
+                    protocol.name,
+                    fixture.name,
+                    protocol.expected_file()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let separator = if index + 1 == fixtures.len() { "" } else { "," };
+        let _ = writeln!(
+            out,
+            "    {{\"name\": \"{name}\", \"shape\": \"{shape}\", \"input\": \"{name}/{INPUT_FILE}\", \"expected\": {{{expected}}}}}{separator}",
+            name = fixture.name,
+            shape = fixture.schema.id().id(),
+        );
+    }
+    let _ = writeln!(out, "  ]");
+    let _ = writeln!(out, "}}");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixture_names_are_unique() {
+        let fixtures = fixtures();
+        let mut names = fixtures.iter().map(|f| f.name).collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), fixtures.len());
+    }
+
+    #[test]
+    fn manifest_lists_every_fixture() {
+        let fixtures = fixtures();
+        let manifest = manifest(&fixtures);
+        assert!(manifest.contains("\"protocols\": [\"json\", \"cbor\"]"));
+        assert!(manifest.contains(
+            "{\"name\": \"simple_struct\", \"shape\": \"test#SimpleStruct\", \"input\": \"simple_struct/input.json\", \"expected\": {\"json\": \"simple_struct/expected.json\", \"cbor\": \"simple_struct/expected.cbor\"}},"
+        ));
+        assert_eq!(manifest.matches("\"name\"").count(), fixtures.len());
+    }
+}
@@ -0,0 +1,21 @@
+//! Writes conformance fixtures to the directory given as the first argument.
+use std::{path::PathBuf, process::ExitCode};
+
+use smithy4rs_conformance::write_fixtures;
+
+fn main() -> ExitCode {
+    let Some(dir) = std::env::args_os().nth(1).map(PathBuf::from) else {
+        eprintln!("Usage: smithy4rs-conformance <output-dir>");
+        return ExitCode::FAILURE;
+    };
+    match write_fixtures(&dir) {
+        Ok(()) => {
+            println!("Wrote conformance fixtures to {}", dir.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::fs;
+
+use smithy4rs_conformance::{INPUT_FILE, MANIFEST_FILE, PROTOCOLS, fixtures, write_fixtures};
+use smithy4rs_json_codec::JsonCodec;
+
+#[test]
+fn test_expected_bytes_round_trip() {
+    for fixture in fixtures() {
+        for protocol in PROTOCOLS {
+            let bytes = fixture.serialize(protocol.codec).unwrap();
+            let decoded = protocol
+                .codec
+                .deserialize_document(fixture.schema, &bytes)
+                .unwrap();
+            assert_eq!(
+                protocol
+                    .codec
+                    .serialize_document(fixture.schema, &decoded)
+                    .unwrap(),
+                bytes,
+                "`{}` did not round trip through {}",
+                fixture.name,
+                protocol.name
+            );
+        }
+    }
+}
+
+#[test]
+fn test_json_bytes_are_stable() {
+    let fixture = fixtures()
+        .into_iter()
+        .find(|f| f.name == "simple_struct")
+        .unwrap();
+    assert_eq!(
+        fixture.serialize(&JsonCodec).unwrap(),
+        br#"{"field_a":"hello","field_b":42}"#
+    );
+}
+
+#[test]
+fn test_writes_fixture_files() {
+    let dir = std::env::temp_dir().join(format!("smithy4rs-conformance-{}", std::process::id()));
+    write_fixtures(&dir).unwrap();
+
+    let manifest = fs::read_to_string(dir.join(MANIFEST_FILE)).unwrap();
+    for fixture in fixtures() {
+        assert!(manifest.contains(&format!("\"name\": \"{}\"", fixture.name)));
+        let fixture_dir = dir.join(fixture.name);
+        assert_eq!(
+            fs::read(fixture_dir.join(INPUT_FILE)).unwrap(),
+            fixture.serialize(&JsonCodec).unwrap()
+        );
+        for protocol in PROTOCOLS {
+            assert_eq!(
+                fs::read(fixture_dir.join(protocol.expected_file())).unwrap(),
+                fixture.serialize(protocol.codec).unwrap()
+            );
+        }
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -7,9 +7,13 @@ mod shapes;
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use shapes::utils::{get_builder_ident, get_crate_info, parse_enum_value, parse_schema};
+use shapes::utils::{
+    get_builder_ident, get_crate_info, get_crate_name, is_error, is_union, parse_enum_value,
+    parse_schema, parse_wrapper_type,
+};
 use syn::{
-    Data, DeriveInput, Fields, ItemEnum, Lit, Variant, parse, parse_macro_input, parse_quote,
+    Attribute, Data, DeriveInput, Error, Fields, FieldsUnnamed, ItemEnum, ItemStruct, Lit, Path,
+    Result, Token, Variant, parse, parse_macro_input, parse_quote, punctuated::Punctuated,
 };
 
 #[cfg(feature = "serde-adapter")]
@@ -17,15 +21,12 @@ use crate::shapes::adapter::{deser_adapter_impl, ser_adapter_impl};
 #[cfg(feature = "arbitrary")]
 use crate::shapes::arbitrary::arbitrary_impl;
 use crate::shapes::{
-    buildable, builder_impls, builder_struct, debug_impl, deref_impl, deserialization_impl,
-    enum_error_correction_impl, get_builder_fields, get_static_trait_id_impl,
-    get_try_from_document_impl, get_tuple_constructor, schema_impl, serialization_impl,
+    buildable, builder_impls, builder_struct, collection_builder_impls, collection_builder_struct,
+    debug_impl, deref_impl, deserialization_impl, display_impl, enum_error_correction_impl,
+    enum_shape_impl, error_impl, get_builder_fields, get_collection_item, get_static_trait_id_impl,
+    get_tuple_constructor, known_enum, schema_impl, serialization_impl, try_from_document_impl,
 };
 
-// TODO(errors): Make error handling use: `syn::Error::into_compile_error`
-// TODO(derive): Smithy Struct should automatically derive: PartialEq, and Clone
-//               if not already derived on shape.
-
 // ============================================================================
 // Attribute Macros
 // ============================================================================
@@ -33,6 +34,9 @@ use crate::shapes::{
 /// Modifies an enum to be usable as a Smithy Union
 ///
 /// This macro is used to automatically add an unknown variant for Union shapes.
+/// The unknown variant holds the name and raw value of a member that is not
+/// known to the union, so that unknown members can be re-serialized without loss.
+/// Any missing `PartialEq` and `Clone` derives are also added (see [`smithy_structure`]).
 #[proc_macro_attribute]
 pub fn smithy_union(
     args: proc_macro::TokenStream,
@@ -47,8 +51,17 @@ pub fn smithy_union(
     // empty (i.e. `UNIT`) values
     enum_struct.attrs.push(parse_quote!(#[smithy_union_enum]));
 
-    // Add unknown variants
-    unknown_variant(&mut enum_struct);
+    // Add unknown variant that retains the member name and raw value
+    let crate_name = match get_crate_name() {
+        Ok(crate_name) => crate_name,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    enum_struct.variants.push(unknown(
+        parse_quote!((String, Box<dyn #crate_name::schema::Document>)),
+    ));
+    if let Err(err) = add_missing_derives(&mut enum_struct.attrs, false) {
+        return err.into_compile_error().into();
+    }
 
     // Re-write structure with changes
     quote!(#enum_struct).into()
@@ -58,6 +71,28 @@ pub fn smithy_union(
 ///
 /// This macro is used to automatically add an unknown variant for Smithy Enums.
 /// It also allows us to use discriminants for both string and int enum definitions.
+/// Any missing `PartialEq` and `Clone` derives are also added (see [`smithy_structure`]).
+///
+/// A `Known{Enum}` enum with only the known variants is also generated, along with
+/// a `match_known` method that forces callers to handle unknown values separately:
+///
+/// ```rust,ignore
+/// #[smithy_enum]
+/// #[derive(SmithyShape)]
+/// #[smithy_schema(STATUS)]
+/// pub enum Status {
+///     Active = "active",
+///     Deleted = "deleted",
+/// }
+///
+/// let message = status.match_known(
+///     |known| match known {
+///         KnownStatus::Active => "active",
+///         KnownStatus::Deleted => "deleted",
+///     },
+///     |unknown| unknown,
+/// );
+/// ```
 #[proc_macro_attribute]
 pub fn smithy_enum(
     args: proc_macro::TokenStream,
@@ -70,10 +105,114 @@ pub fn smithy_enum(
     // *WARNING*: This must occur _BEFORE_ adding unknown variant
     discriminants_to_attributes(&mut enum_struct);
     // Add unknown variants
-    unknown_variant(&mut enum_struct);
+    if let Err(err) = unknown_variant(&mut enum_struct) {
+        return err.into_compile_error().into();
+    }
+    if let Err(err) = add_missing_derives(&mut enum_struct.attrs, false) {
+        return err.into_compile_error().into();
+    }
+    let known = match known_enum(&enum_struct) {
+        Ok(known) => known,
+        Err(err) => return err.into_compile_error().into(),
+    };
 
     // Re-write structure with changes
-    quote!(#enum_struct).into()
+    quote! {
+        #enum_struct
+        #known
+    }
+    .into()
+}
+
+/// Modifies a struct to be usable as a Smithy structure
+///
+/// This macro adds any of the `PartialEq` and `Clone` derives that are missing
+/// from a structure deriving `SmithyShape`, and removes `Debug` derives, which
+/// would conflict with the `Debug` implementation generated by `SmithyShape`:
+///
+/// ```rust,ignore
+/// #[smithy_structure]
+/// #[derive(SmithyShape)]
+/// #[smithy_schema(MY_SCHEMA)]
+/// pub struct MyStruct { ... }
+/// // Equivalent to `#[derive(SmithyShape, PartialEq, Clone)]`
+/// ```
+///
+/// The macro must be applied before the `derive` attribute, as derive macros
+/// cannot see the other derives of a shape.
+#[proc_macro_attribute]
+pub fn smithy_structure(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut item_struct = parse_macro_input!(input as ItemStruct);
+    // Expect NO args
+    let _ = parse_macro_input!(args as parse::Nothing);
+    let tuple_struct = matches!(item_struct.fields, Fields::Unnamed(_));
+    if let Err(err) = add_missing_derives(&mut item_struct.attrs, tuple_struct) {
+        return err.into_compile_error().into();
+    }
+
+    // Re-write structure with changes
+    quote!(#item_struct).into()
+}
+
+/// Derives added to shapes deriving `SmithyShape` if they are not already derived,
+/// along with the full path of each derive.
+const AUTO_DERIVES: [(&str, &str); 2] = [
+    ("PartialEq", "::core::cmp::PartialEq"),
+    ("Clone", "::core::clone::Clone"),
+];
+
+/// Adds the [`AUTO_DERIVES`] that are missing from a shape deriving `SmithyShape`.
+///
+/// `Debug` derives are removed, as `SmithyShape` already implements `Debug`
+/// (redacting `@sensitive` values). Tuple structs deriving `SmithyTraitImpl`
+/// already implement `PartialEq`.
+fn add_missing_derives(attrs: &mut Vec<Attribute>, tuple_struct: bool) -> Result<()> {
+    let mut derived = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("derive")) {
+        derived.extend(attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?);
+    }
+    let is_derived = |name: &str| {
+        derived
+            .iter()
+            .any(|path| path.segments.last().is_some_and(|s| s.ident == name))
+    };
+    if !is_derived("SmithyShape") {
+        return Ok(());
+    }
+
+    // Strip `Debug` derives, dropping any derive attribute left empty
+    let mut rewritten = Vec::with_capacity(attrs.len());
+    for attr in attrs.drain(..) {
+        if !attr.path().is_ident("derive") {
+            rewritten.push(attr);
+            continue;
+        }
+        let paths = attr
+            .parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?
+            .into_iter()
+            .filter(|path| path.segments.last().is_none_or(|s| s.ident != "Debug"))
+            .collect::<Vec<_>>();
+        if !paths.is_empty() {
+            rewritten.push(parse_quote!(#[derive(#(#paths),*)]));
+        }
+    }
+    *attrs = rewritten;
+
+    let missing = AUTO_DERIVES
+        .iter()
+        .filter(|(name, _)| !is_derived(name))
+        .filter(|(name, _)| {
+            !(*name == "PartialEq" && tuple_struct && is_derived("SmithyTraitImpl"))
+        })
+        .map(|(_, path)| syn::parse_str::<Path>(path))
+        .collect::<Result<Vec<_>>>()?;
+    if !missing.is_empty() {
+        attrs.push(parse_quote!(#[derive(#(#missing),*)]));
+    }
+    Ok(())
 }
 
 /// Convert discriminants to `[#enum_value]` attributes
@@ -87,31 +226,39 @@ fn discriminants_to_attributes(enum_data: &mut ItemEnum) {
     }
 }
 
-/// Adds an `Unknown` variant for Enums and Unions.
-fn unknown_variant(enum_data: &mut ItemEnum) {
-    // Determine if unknown should store string or int. Unions (without `enum_value` attr)
-    // will default tousing String as unknown data value.
-    let field = if let Some(val) = parse_enum_value(
-        &enum_data
-            .variants
-            .first()
-            .expect("Expected at least one variant")
-            .attrs,
-    ) && let Lit::Int(_) = val
-    {
+/// Adds an `Unknown` variant for Enums.
+fn unknown_variant(enum_data: &mut ItemEnum) -> Result<()> {
+    // Determine if unknown should store string or int.
+    let first = enum_data
+        .variants
+        .first()
+        .ok_or_else(|| Error::new_spanned(&enum_data.ident, "Expected at least one variant"))?;
+    let field = if let Some(Lit::Int(_)) = parse_enum_value(&first.attrs)? {
         parse_quote!((i32))
     } else {
         parse_quote!((String))
     };
-    enum_data.variants.push(Variant {
+    enum_data.variants.push(unknown(field));
+    Ok(())
+}
+
+/// Creates a hidden `Unknown` variant with the provided fields.
+fn unknown(fields: FieldsUnnamed) -> Variant {
+    Variant {
         attrs: vec![
             parse_quote!(#[automatically_derived]),
             parse_quote!(#[doc(hidden)]),
         ],
         discriminant: None,
-        fields: Fields::Unnamed(field),
+        fields: Fields::Unnamed(fields),
         ident: Ident::new("Unknown", Span::call_site()),
-    });
+    }
+}
+
+/// Convert the result of a macro expansion into tokens, reporting any
+/// error as a compile error located at its span.
+fn into_tokens(result: Result<TokenStream>) -> proc_macro::TokenStream {
+    result.unwrap_or_else(Error::into_compile_error).into()
 }
 
 // ============================================================================
@@ -120,31 +267,48 @@ fn unknown_variant(enum_data: &mut ItemEnum) {
 
 /// Convenience derive that combines `SchemaShape`, `SerializableShape`, and `DeserializableShape`
 /// for Smithy Enums, Structures, and Unions.
+///
+/// Structures marked with the `#[smithy_error]` attribute also implement `Display`,
+/// `std::error::Error`, and `ErrorShape`.
+///
+/// All shapes can also be converted from a `Document` with `TryFrom<Box<dyn Document>>`.
+/// Shapes also implement `Debug` and `Display` (see `smithy4rs_core::serde::fmt`), and shapes marked with the `#[smithy_structure]`,
+/// `#[smithy_union]`, or `#[smithy_enum]` attributes also derive `PartialEq` and `Clone`.
+///
+/// Structure fields and union variants that hold other structures are deserialized
+/// using the builder of that structure. Fields and variants that hold enums or unions
+/// must be marked with the `#[no_builder]` attribute.
+///
+/// Structure fields marked with the `#[idempotency_token]` attribute are filled with
+/// a new idempotency token when they are unset and the shape is built from its builder.
 #[proc_macro_derive(
     SmithyShape,
-    attributes(smithy_schema, enum_value, smithy_union_enum, default, no_builder)
+    attributes(
+        smithy_schema,
+        enum_value,
+        smithy_union_enum,
+        default,
+        no_builder,
+        idempotency_token,
+        smithy_error
+    )
 )]
 pub fn smithy_shape_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    // Generate all three derive expansions
-    let schema = schema_shape_derive(input.clone());
-    let serializable = serializable_shape_derive(input.clone());
-    let deserializable = deserializable_shape_derive(input.clone());
-
-    // Adapters for serde (if enabled)
-    #[cfg(feature = "serde-adapter")]
-    let serde = smithy_serde_adapter(input.clone());
+    let input = parse_macro_input!(input as DeriveInput);
+    into_tokens(expand_smithy_shape(&input))
+}
 
-    #[cfg(feature = "arbitrary")]
-    let arbitrary = smithy_arbitrary(input.clone());
+fn expand_smithy_shape(input: &DeriveInput) -> Result<TokenStream> {
+    // Generate all three derive expansions
+    let schema_tokens = expand_schema_shape(input)?;
+    let serializable_tokens = expand_serializable_shape(input)?;
+    let deserializable_tokens = expand_deserializable_shape(input)?;
 
     // Add additional core derivations
-    let debug = smithy_debug(input);
-
-    // Combine all outputs
-    let schema_tokens = TokenStream::from(schema);
-    let serializable_tokens = TokenStream::from(serializable);
-    let deserializable_tokens = TokenStream::from(deserializable);
-    let debug_tokens = TokenStream::from(debug);
+    let debug_tokens = expand_smithy_debug(input)?;
+    let display_tokens = expand_smithy_display(input)?;
+    let error_tokens = expand_smithy_error(input)?;
+    let document_tokens = expand_document_conversions(input)?;
 
     #[cfg(any(feature = "arbitrary", feature = "serde-adapter"))]
     let mut output = quote! {
@@ -152,6 +316,9 @@ pub fn smithy_shape_derive(input: proc_macro::TokenStream) -> proc_macro::TokenS
         #serializable_tokens
         #deserializable_tokens
         #debug_tokens
+        #display_tokens
+        #error_tokens
+        #document_tokens
     };
     #[cfg(not(any(feature = "arbitrary", feature = "serde-adapter")))]
     let output = quote! {
@@ -159,33 +326,51 @@ pub fn smithy_shape_derive(input: proc_macro::TokenStream) -> proc_macro::TokenS
         #serializable_tokens
         #deserializable_tokens
         #debug_tokens
+        #display_tokens
+        #error_tokens
+        #document_tokens
     };
 
+    // Adapters for serde (if enabled)
     #[cfg(feature = "serde-adapter")]
-    {
-        let serde_tokens = TokenStream::from(serde);
-        output.extend(serde_tokens);
-    }
+    output.extend(expand_serde_adapter(input)?);
 
     #[cfg(feature = "arbitrary")]
-    {
-        let arbitrary_tokens = TokenStream::from(arbitrary);
-        output.extend(arbitrary_tokens);
-    }
+    output.extend(expand_arbitrary(input)?);
 
-    output.into()
+    Ok(output)
 }
 
 /// Derives `SchemaShape` for a struct, backed by a static schema (`StaticSchemaShape`)
 #[proc_macro_derive(SchemaShape, attributes(smithy_schema))]
 pub fn schema_shape_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let schema_ident = parse_schema(&input.attrs);
+    into_tokens(expand_schema_shape(&input))
+}
+
+fn expand_schema_shape(input: &DeriveInput) -> Result<TokenStream> {
+    let schema_ident = parse_schema(&input.attrs, &input.ident)?;
     let shape_name = &input.ident;
-    let (extern_import, crate_ident) = get_crate_info();
+    let (extern_import, crate_ident) = get_crate_info()?;
     let schema_trait = schema_impl(shape_name, &schema_ident);
+    // Enums (but not unions) also expose metadata on their variants
+    let enum_shape = match &input.data {
+        Data::Enum(data) if !is_union(data)? => {
+            let enum_shape = enum_shape_impl(shape_name, data)?;
+            quote! {
+                const _: () = {
+                    #extern_import
+                    use #crate_ident::schema::EnumShape as _EnumShape;
+                    use #crate_ident::schema::EnumValue as _EnumValue;
 
-    quote! {
+                    #enum_shape
+                };
+            }
+        }
+        _ => quote! {},
+    };
+
+    Ok(quote! {
         const _: () = {
             #extern_import
             use #crate_ident::schema::Schema as _Schema;
@@ -193,52 +378,63 @@ pub fn schema_shape_derive(input: proc_macro::TokenStream) -> proc_macro::TokenS
 
             #schema_trait
         };
-    }
-    .into()
+
+        #enum_shape
+    })
 }
 
 /// Derives `SerializableShape` (`SerializeWithSchema` only, no schema)
 #[proc_macro_derive(SerializableShape, attributes(smithy_schema, enum_value))]
 pub fn serializable_shape_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    into_tokens(expand_serializable_shape(&input))
+}
+
+fn expand_serializable_shape(input: &DeriveInput) -> Result<TokenStream> {
     let shape_name = &input.ident;
-    let schema_ident = parse_schema(&input.attrs);
-    let (extern_import, crate_ident) = get_crate_info();
-    let serialization = serialization_impl(&crate_ident, shape_name, &schema_ident, &input);
-    quote! {
+    let schema_ident = parse_schema(&input.attrs, &input.ident)?;
+    let (extern_import, crate_ident) = get_crate_info()?;
+    let serialization = serialization_impl(&crate_ident, shape_name, &schema_ident, input)?;
+    Ok(quote! {
         const _: () = {
             #extern_import
             use #crate_ident::schema::Schema as _Schema;
 
             #serialization
         };
-    }
-    .into()
+    })
 }
 
 /// Derives `DeserializeWithSchema` and, implicitly `Deserialize` for a Shape.
-#[proc_macro_derive(DeserializableShape, attributes(smithy_schema, default))]
+#[proc_macro_derive(
+    DeserializableShape,
+    attributes(smithy_schema, default, idempotency_token)
+)]
 pub fn deserializable_shape_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    into_tokens(expand_deserializable_shape(&input))
+}
+
+fn expand_deserializable_shape(input: &DeriveInput) -> Result<TokenStream> {
     let shape_name = &input.ident;
-    let schema_ident = parse_schema(&input.attrs);
-    let (extern_import, crate_ident) = get_crate_info();
-    let deser = deserialization_impl(&crate_ident, shape_name, &schema_ident, &input);
+    let schema_ident = parse_schema(&input.attrs, &input.ident)?;
+    let (extern_import, crate_ident) = get_crate_info()?;
+    let deser = deserialization_impl(&crate_ident, shape_name, &schema_ident, input)?;
     match &input.data {
         Data::Struct(data) => {
             match &data.fields {
                 // Generate builder for structures with named fields
                 Fields::Named(fields) => {
-                    let field_data = get_builder_fields(&schema_ident, fields);
-                    let builder = builder_struct(shape_name, &field_data);
-                    let builder_impls = builder_impls(shape_name, &field_data);
+                    let field_data = get_builder_fields(&schema_ident, fields)?;
+                    let builder = builder_struct(shape_name, &field_data)?;
+                    let builder_impls = builder_impls(&crate_ident, shape_name, &field_data);
                     let builder_name = get_builder_ident(shape_name);
                     let builder_serializer =
-                        serialization_impl(&crate_ident, &builder_name, &schema_ident, &input);
+                        serialization_impl(&crate_ident, &builder_name, &schema_ident, input)?;
                     let buildable = buildable(shape_name, &builder_name);
                     let builder_schema = schema_impl(&builder_name, &schema_ident);
                     // Builder struct is generated outside the const block to make it publicly accessible
-                    quote! {
+                    Ok(quote! {
                         #builder
 
                         const _: () = {
@@ -259,24 +455,55 @@ pub fn deserializable_shape_derive(input: proc_macro::TokenStream) -> proc_macro
                             #builder_serializer
                             #buildable
                         };
-                    }
-                    .into()
+                    })
+                }
+                // Generate builders for wrappers of top-level list and map schemas
+                Fields::Unnamed(fields)
+                    if let Some(item) = get_collection_item(parse_wrapper_type(fields)?) =>
+                {
+                    let wrapped = parse_wrapper_type(fields)?;
+                    let builder = collection_builder_struct(shape_name, wrapped, &item)?;
+                    let builder_impls = collection_builder_impls(&crate_ident, shape_name, wrapped);
+                    let builder_name = get_builder_ident(shape_name);
+                    let builder_serializer =
+                        serialization_impl(&crate_ident, &builder_name, &schema_ident, input)?;
+                    let builder_schema = schema_impl(&builder_name, &schema_ident);
+                    Ok(quote! {
+                        #builder
+
+                        const _: () = {
+                            #extern_import
+                            use #crate_ident::schema::Schema as _Schema;
+                            use #crate_ident::schema::StaticSchemaShape as _StaticSchemaShape;
+
+                            #builder_schema
+                        };
+
+                        const _: () = {
+                            #extern_import
+                            use #crate_ident::schema::Schema as _Schema;
+
+                            #deser
+
+                            #builder_impls
+                            #builder_serializer
+                        };
+                    })
                 }
                 // Generate deser for wrappers
-                Fields::Unnamed(_) | Fields::Unit => quote! {
+                Fields::Unnamed(_) | Fields::Unit => Ok(quote! {
                     const _: () = {
                         #extern_import
                         use #crate_ident::schema::Schema as _Schema;
 
                         #deser
                     };
-                }
-                .into(),
+                }),
             }
         }
         Data::Enum(data) => {
-            let error_correction = enum_error_correction_impl(&crate_ident, shape_name, data);
-            quote! {
+            let error_correction = enum_error_correction_impl(&crate_ident, shape_name, data)?;
+            Ok(quote! {
                 const _: () = {
                     #extern_import
                     use #crate_ident::schema::Schema as _Schema;
@@ -289,10 +516,12 @@ pub fn deserializable_shape_derive(input: proc_macro::TokenStream) -> proc_macro
 
                     #error_correction
                 };
-            }
-            .into()
+            })
         }
-        _ => panic!("SerializableShape can only be derived for structs, enum, or unions"),
+        Data::Union(data) => Err(Error::new_spanned(
+            data.union_token,
+            "DeserializableShape can only be derived for structs, enum, or unions",
+        )),
     }
 }
 
@@ -300,12 +529,16 @@ pub fn deserializable_shape_derive(input: proc_macro::TokenStream) -> proc_macro
 #[proc_macro_derive(SmithyDebug)]
 pub fn smithy_debug(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    into_tokens(expand_smithy_debug(&input))
+}
+
+fn expand_smithy_debug(input: &DeriveInput) -> Result<TokenStream> {
     let shape_name = &input.ident;
-    let schema_ident = parse_schema(&input.attrs);
-    let (extern_import, crate_ident) = get_crate_info();
+    let schema_ident = parse_schema(&input.attrs, &input.ident)?;
+    let (extern_import, crate_ident) = get_crate_info()?;
     let debug = debug_impl(shape_name, &schema_ident);
 
-    quote! {
+    Ok(quote! {
         const _: () = {
             #extern_import
 
@@ -313,8 +546,75 @@ pub fn smithy_debug(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
             #debug
         };
+    })
+}
+
+/// Derives `Display` for a shape, backed by a static schema (`StaticSchemaShape`)
+///
+/// Shapes are displayed as `ShapeName[member=value, ...]`, redacting `@sensitive` values.
+#[proc_macro_derive(SmithyDisplay)]
+pub fn smithy_display(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    into_tokens(expand_smithy_display(&input))
+}
+
+fn expand_smithy_display(input: &DeriveInput) -> Result<TokenStream> {
+    // Errors display their message instead
+    if is_error(&input.attrs) {
+        return Ok(quote! {});
     }
-    .into()
+    let shape_name = &input.ident;
+    let schema_ident = parse_schema(&input.attrs, &input.ident)?;
+    let (extern_import, crate_ident) = get_crate_info()?;
+    let display = display_impl(shape_name, &schema_ident);
+
+    Ok(quote! {
+        const _: () = {
+            #extern_import
+
+            use #crate_ident::serde::fmt::DisplayWrapper as _DisplayWrapper;
+
+            #display
+        };
+    })
+}
+
+/// Derives error implementations for structures marked with `#[smithy_error]`.
+fn expand_smithy_error(input: &DeriveInput) -> Result<TokenStream> {
+    if !is_error(&input.attrs) {
+        return Ok(quote! {});
+    }
+    let shape_name = &input.ident;
+    let schema_ident = parse_schema(&input.attrs, &input.ident)?;
+    let (extern_import, crate_ident) = get_crate_info()?;
+    let error = error_impl(shape_name, &schema_ident, &input.data)?;
+
+    Ok(quote! {
+        const _: () = {
+            #extern_import
+
+            use #crate_ident::schema::ErrorShape as _ErrorShape;
+
+            #error
+        };
+    })
+}
+
+/// Derives `TryFrom<Box<dyn Document>>` (and `TryFromDocument` for structures).
+fn expand_document_conversions(input: &DeriveInput) -> Result<TokenStream> {
+    let (extern_import, crate_ident) = get_crate_info()?;
+    let conversions = try_from_document_impl(&crate_ident, &input.ident, &input.data);
+
+    Ok(quote! {
+        const _: () = {
+            #extern_import
+            use #crate_ident::schema::Document as _Document;
+            use #crate_ident::schema::DocumentError as _DocumentError;
+            use #crate_ident::schema::TryFromDocument as _TryFromDocument;
+
+            #conversions
+        };
+    })
 }
 
 // ============================================================================
@@ -326,13 +626,18 @@ pub fn smithy_debug(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 #[proc_macro_derive(SmithySerdeAdapter)]
 pub fn smithy_serde_adapter(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    into_tokens(expand_serde_adapter(&input))
+}
+
+#[cfg(feature = "serde-adapter")]
+fn expand_serde_adapter(input: &DeriveInput) -> Result<TokenStream> {
     let shape_name = &input.ident;
-    let schema_ident = parse_schema(&input.attrs);
-    let (extern_import, crate_ident) = get_crate_info();
+    let schema_ident = parse_schema(&input.attrs, &input.ident)?;
+    let (extern_import, crate_ident) = get_crate_info()?;
     let ser = ser_adapter_impl(&crate_ident, shape_name, &schema_ident);
-    let deser = deser_adapter_impl(&crate_ident, shape_name, &input);
+    let deser = deser_adapter_impl(&crate_ident, shape_name, input);
 
-    quote! {
+    Ok(quote! {
         const _: () = {
             #extern_import
             extern crate serde as _serde;
@@ -340,8 +645,7 @@ pub fn smithy_serde_adapter(input: proc_macro::TokenStream) -> proc_macro::Token
             #ser
             #deser
         };
-    }
-    .into()
+    })
 }
 
 // ============================================================================
@@ -353,20 +657,24 @@ pub fn smithy_serde_adapter(input: proc_macro::TokenStream) -> proc_macro::Token
 #[proc_macro_derive(SmithyArbitrary, attributes(smithy_schema))]
 pub fn smithy_arbitrary(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    into_tokens(expand_arbitrary(&input))
+}
+
+#[cfg(feature = "arbitrary")]
+fn expand_arbitrary(input: &DeriveInput) -> Result<TokenStream> {
     let shape_name = &input.ident;
-    let schema_ident = parse_schema(&input.attrs);
-    let (extern_import, crate_ident) = get_crate_info();
-    let arbitrary_tokens = arbitrary_impl(&crate_ident, shape_name, &schema_ident, &input);
+    let schema_ident = parse_schema(&input.attrs, &input.ident)?;
+    let (extern_import, crate_ident) = get_crate_info()?;
+    let arbitrary_tokens = arbitrary_impl(&crate_ident, shape_name, &schema_ident, input)?;
 
-    quote! {
+    Ok(quote! {
         const _: () = {
             #extern_import
             extern crate arbitrary as _arbitrary;
 
             #arbitrary_tokens
         };
-    }
-    .into()
+    })
 }
 
 // ============================================================================
@@ -376,9 +684,13 @@ pub fn smithy_arbitrary(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 #[proc_macro_derive(SmithyTraitImpl, attributes(smithy_schema))]
 pub fn smithy_trait_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    into_tokens(expand_smithy_trait(&input))
+}
+
+fn expand_smithy_trait(input: &DeriveInput) -> Result<TokenStream> {
     let shape_name = &input.ident;
-    let schema_ident = parse_schema(&input.attrs);
-    let (extern_import, crate_ident) = get_crate_info();
+    let schema_ident = parse_schema(&input.attrs, &input.ident)?;
+    let (extern_import, crate_ident) = get_crate_info()?;
     let static_id = get_static_trait_id_impl(&crate_ident, shape_name);
     // SmithyTrait implementations
     let contents = quote! {
@@ -389,45 +701,30 @@ pub fn smithy_trait_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStr
         };
     };
     // Generate a constructor for wrapper types
-    if let Data::Struct(data_struct) = input.data {
-        match &data_struct.fields {
-            Fields::Unnamed(fields) => {
-                let constructor = get_tuple_constructor(&schema_ident, shape_name, fields);
-                let deref = deref_impl(shape_name, fields);
-                // TODO: Re-evaluate partialEq location
-                quote! {
-                    #constructor
+    let Data::Struct(data_struct) = &input.data else {
+        return Ok(contents);
+    };
+    match &data_struct.fields {
+        Fields::Unnamed(fields) => {
+            let constructor = get_tuple_constructor(&schema_ident, shape_name, fields)?;
+            let deref = deref_impl(shape_name, fields)?;
+            // TODO: Re-evaluate partialEq location
+            Ok(quote! {
+                #constructor
 
-                    const _: () = {
-                        #deref
-                    };
+                const _: () = {
+                    #deref
+                };
 
-                    #contents
+                #contents
 
-                    impl PartialEq for #shape_name {
-                        fn eq(&self, other: &Self) -> bool {
-                            &self.0 == &other.0
-                        }
+                impl PartialEq for #shape_name {
+                    fn eq(&self, other: &Self) -> bool {
+                        &self.0 == &other.0
                     }
                 }
-                .into()
-            }
-            Fields::Named(_) => {
-                let try_from = get_try_from_document_impl(&crate_ident, shape_name);
-                quote! {
-                    const _: () = {
-                        #extern_import
-
-                        #try_from
-                    };
-
-                    #contents
-                }
-                .into()
-            }
-            Fields::Unit => contents.into(),
+            })
         }
-    } else {
-        contents.into()
+        Fields::Named(_) | Fields::Unit => Ok(contents),
     }
 }
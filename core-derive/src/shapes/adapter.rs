@@ -57,9 +57,7 @@ pub(crate) fn deser_adapter_impl(
         let builder_name = Ident::new(&format!("{shape_name}Builder"), Span::call_site());
         quote! {
             let seed = _SchemaSeed::<#builder_name>::new(#shape_name::schema());
-            seed.deserialize(deserializer)?
-                .build()
-                .map_err(D::Error::custom)
+            _ShapeBuilder::build(seed.deserialize(deserializer)?).map_err(D::Error::custom)
         }
     } else {
         quote! {
@@ -1,6 +1,6 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::{Data, DeriveInput, Fields};
+use syn::{Data, DeriveInput, Error, Fields, Result};
 
 /// `Arbitrary` implementation for generated shapes
 pub(crate) fn arbitrary_impl(
@@ -8,7 +8,7 @@ pub(crate) fn arbitrary_impl(
     shape_name: &Ident,
     schema_ident: &Ident,
     input: &DeriveInput,
-) -> TokenStream {
+) -> Result<TokenStream> {
     let arbitrary_impl = match &input.data {
         Data::Struct(ds) => match ds.fields {
             Fields::Named(_) => arbitrary_struct(crate_ident, shape_name, schema_ident),
@@ -17,9 +17,14 @@ pub(crate) fn arbitrary_impl(
             }
         },
         Data::Enum(_) => arbitrary_other(crate_ident, shape_name, schema_ident),
-        _ => panic!("SerializableShape can only be derived for structs, enum, or unions"),
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "SmithyArbitrary can only be derived for structs, enum, or unions",
+            ));
+        }
     };
-    quote! {
+    Ok(quote! {
         use _arbitrary::Unstructured as _Unstructured;
         use _arbitrary::Arbitrary as _Arbitrary;
         use _arbitrary::MaxRecursionReached as _MaxRecursionReached;
@@ -28,7 +33,7 @@ pub(crate) fn arbitrary_impl(
         use #crate_ident::serde::deserializers::DeserializableShape as _DeserializableShape;
 
         #arbitrary_impl
-    }
+    })
 }
 
 /// Generates an `Arbitrary` impl for a shape and its builder
@@ -44,9 +49,8 @@ fn arbitrary_struct(
         #[automatically_derived]
         impl<'a> _Arbitrary<'a> for #shape_name {
             fn arbitrary(u: &mut _Unstructured<'a>) -> _arbitrary::Result<Self> {
-                <#builder_name as _DeserializableShape>::deserialize(_ArbitraryDeserializer::new(u))?
-                .build()
-                .map_err(|_| _arbitrary::Error::IncorrectFormat)
+                _ShapeBuilder::build(<#builder_name as _DeserializableShape>::deserialize(_ArbitraryDeserializer::new(u))?)
+                    .map_err(|_| _arbitrary::Error::IncorrectFormat)
             }
 
             #[inline]
@@ -1,15 +1,19 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::{Field, FieldsNamed, Type};
+use syn::{Error, Field, FieldsNamed, Result, Type};
 
 use crate::shapes::utils::{
-    IdentOrExpr, extract_option_type, get_crate_ident, get_ident, get_inner_type, is_optional,
-    is_primitive, no_builder, parse_default, parse_schema, replace_inner,
+    IdentOrExpr, extract_option_type, get_builder_ident, get_crate_ident, get_ident,
+    get_inner_type, is_idempotency_token, is_optional, is_primitive, no_builder, parse_default,
+    parse_schema, replace_inner,
 };
 
-pub(crate) fn builder_struct(shape_name: &Ident, field_data: &[BuilderFieldData]) -> TokenStream {
+pub(crate) fn builder_struct(
+    shape_name: &Ident,
+    field_data: &[BuilderFieldData],
+) -> Result<TokenStream> {
     let builder_name = Ident::new(&format!("{shape_name}Builder"), Span::call_site());
-    let crate_ident = get_crate_ident();
+    let crate_ident = get_crate_ident()?;
 
     // Generate builder struct fields
     let builder_fields = field_data
@@ -23,13 +27,25 @@ pub(crate) fn builder_struct(shape_name: &Ident, field_data: &[BuilderFieldData]
         .map(|d| d.initializer(&crate_ident))
         .collect::<Vec<_>>();
 
-    // Generate setter methods - consuming for chaining
+    // Generate setter methods - consuming for chaining and `&mut self` for in-place updates
     let setters = field_data
         .iter()
         .map(|d| d.setters(&crate_ident))
         .collect::<Vec<_>>();
 
-    quote! {
+    // Unset idempotency tokens are filled before any user-built shape is validated.
+    // Builders used for deserialization are built with `ShapeBuilder` and are not filled.
+    let fill_tokens = field_data
+        .iter()
+        .filter_map(|d| d.fill_token(&crate_ident))
+        .collect::<Vec<_>>();
+    let receiver = if fill_tokens.is_empty() {
+        quote! { self }
+    } else {
+        quote! { mut self }
+    };
+
+    Ok(quote! {
         #[doc = concat!("Builder for [`", stringify!(#shape_name), "`]")]
         #[automatically_derived]
         impl #shape_name {
@@ -61,20 +77,40 @@ pub(crate) fn builder_struct(shape_name: &Ident, field_data: &[BuilderFieldData]
 
             /// Build the shape, validating with the default validator.
             #[inline]
-            pub fn build(self) -> #crate_ident::serde::validation::Validated<#shape_name> {
+            pub fn build(#receiver) -> #crate_ident::serde::validation::Validated<#shape_name> {
+                #(#fill_tokens)*
                 #crate_ident::serde::ShapeBuilder::build(self)
             }
 
             /// Build the shape using a custom validator.
             #[inline]
-            pub fn build_with_validator(self, validator: impl #crate_ident::serde::validation::Validator) -> #crate_ident::serde::validation::Validated<#shape_name> {
+            pub fn build_with_validator(#receiver, validator: impl #crate_ident::serde::validation::Validator) -> #crate_ident::serde::validation::Validated<#shape_name> {
+                #(#fill_tokens)*
                 #crate_ident::serde::ShapeBuilder::build_with_validator(self, validator)
             }
+
+            /// Build the shape, filling unset required fields before validating with the default validator.
+            #[inline]
+            pub fn build_with_correction(#receiver) -> #crate_ident::serde::validation::Validated<#shape_name> {
+                #(#fill_tokens)*
+                #crate_ident::serde::ShapeBuilder::build_with_correction(self)
+            }
+
+            /// Build the shape, filling unset required fields without any validation.
+            #[inline]
+            pub fn build_unchecked_corrected(#receiver) -> #shape_name {
+                #(#fill_tokens)*
+                #crate_ident::serde::ShapeBuilder::build_unchecked_corrected(self)
+            }
         }
-    }
+    })
 }
 
-pub fn builder_impls(shape_name: &Ident, field_data: &[BuilderFieldData]) -> TokenStream {
+pub fn builder_impls(
+    crate_ident: &TokenStream,
+    shape_name: &Ident,
+    field_data: &[BuilderFieldData],
+) -> TokenStream {
     let builder_name = Ident::new(&format!("{shape_name}Builder"), Span::call_site());
 
     // Generate correct() method used to automatically derive `build()` methods
@@ -108,34 +144,68 @@ pub fn builder_impls(shape_name: &Ident, field_data: &[BuilderFieldData]) -> Tok
                 #builder_name::new().correct()
             }
         }
+
+        #[automatically_derived]
+        impl From<#builder_name> for #crate_ident::serde::MaybeBuilt<#shape_name, #builder_name> {
+            fn from(value: #builder_name) -> Self {
+                #crate_ident::serde::MaybeBuilt::Builder(value)
+            }
+        }
+
+        // Boxed (i.e. recursive) members can be set from unboxed shapes and builders
+        #[automatically_derived]
+        impl From<#shape_name> for #crate_ident::serde::MaybeBuilt<Box<#shape_name>, Box<#builder_name>> {
+            fn from(value: #shape_name) -> Self {
+                #crate_ident::serde::MaybeBuilt::Struct(Box::new(value))
+            }
+        }
+
+        #[automatically_derived]
+        impl From<#builder_name> for #crate_ident::serde::MaybeBuilt<Box<#shape_name>, Box<#builder_name>> {
+            fn from(value: #builder_name) -> Self {
+                #crate_ident::serde::MaybeBuilt::Builder(Box::new(value))
+            }
+        }
     }
 }
 
-pub fn get_builder_fields(schema_ident: &Ident, fields: &FieldsNamed) -> Vec<BuilderFieldData> {
+pub fn get_builder_fields(
+    schema_ident: &Ident,
+    fields: &FieldsNamed,
+) -> Result<Vec<BuilderFieldData>> {
     let mut field_data = Vec::new();
     for field in &fields.named {
         let schema = Ident::new(
-            &format!("_{}_MEMBER_{}", schema_ident, parse_schema(&field.attrs)),
+            &format!(
+                "_{}_MEMBER_{}",
+                schema_ident,
+                parse_schema(&field.attrs, field)?
+            ),
             Span::call_site(),
         );
-        let field_ident = field.ident.as_ref().unwrap().clone();
+        let field_ident = field
+            .ident
+            .clone()
+            .ok_or_else(|| Error::new_spanned(field, "Expected a named field"))?;
         let field_ty = &field.ty;
         let default = parse_default(&field.attrs);
-        let optional = is_optional(field_ty) && default.is_none();
-        let target = resolve_build_target(field, optional);
+        let optional = is_optional(field_ty)? && default.is_none();
+        let target = resolve_build_target(field, optional)?;
+        let idempotency_token = is_idempotency_token(&field.attrs);
 
         field_data.push(BuilderFieldData {
             schema,
             field_ident,
             default,
             optional,
+            idempotency_token,
             target,
         });
     }
-    field_data
+    Ok(field_data)
 }
 
-fn resolve_build_target(field: &Field, optional: bool) -> BuildTarget {
+fn resolve_build_target(field: &Field, optional: bool) -> Result<BuildTarget> {
     // The target type is the inner type of any optional
     let ty = if optional {
         extract_option_type(&field.ty).unwrap_or(&field.ty)
@@ -147,22 +217,22 @@ fn resolve_build_target(field: &Field, optional: bool) -> BuildTarget {
     let inner_type = get_inner_type(ty);
 
     // If the inner type is a primitive type, just return that
-    if is_primitive(inner_type) || no_builder(field) {
-        return BuildTarget::Primitive(ty.clone());
+    if is_primitive(inner_type) || no_builder(&field.attrs) {
+        return Ok(BuildTarget::Primitive(ty.clone()));
     }
 
     // We will create two target types. One with the builder
     // and the other with the "built" type.
     let mut builder_type = ty.clone();
-    let type_ident = get_ident(inner_type);
+    let type_ident = get_ident(inner_type)?;
     let builder_ident = Ident::new(&format!("{type_ident}Builder"), Span::call_site());
     replace_inner(&mut builder_type, builder_ident);
 
     // Create the build target for a `MaybeBuilt<>` impl
-    BuildTarget::Builable {
+    Ok(BuildTarget::Builable {
         shape: ty.clone(),
         builder: builder_type.clone(),
-    }
+    })
 }
 
 pub(crate) struct BuilderFieldData {
@@ -170,6 +240,7 @@ pub(crate) struct BuilderFieldData {
     field_ident: Ident,
     default: Option<IdentOrExpr>,
     optional: bool,
+    idempotency_token: bool,
     target: BuildTarget,
 }
 #[allow(clippy::large_enum_variant)]
@@ -214,11 +285,35 @@ impl BuilderFieldData {
         }
     }
 
+    /// Statement that fills an unset idempotency token field, if this field is one.
+    fn fill_token(&self, crate_ident: &TokenStream) -> Option<TokenStream> {
+        if !self.idempotency_token {
+            return None;
+        }
+        let field_name = &self.field_ident;
+        let token = quote! { #crate_ident::idempotency::idempotency_token().into() };
+        Some(if self.optional {
+            quote! {
+                if self.#field_name.is_none() {
+                    self.#field_name = Some(#token);
+                }
+            }
+        } else {
+            quote! {
+                if matches!(self.#field_name, #crate_ident::serde::Required::Unset) {
+                    self.#field_name = #crate_ident::serde::Required::Set(#token);
+                }
+            }
+        })
+    }
+
     /// Generate builder setters.
     ///
-    /// Setters consume `self` to allow for chaining.
+    /// Fluent setters consume `self` to allow for chaining. A parallel family of
+    /// `set_*` methods take `&mut self` for use in loops and conditionals.
     fn setters(&self, crate_ident: &TokenStream) -> TokenStream {
         let field_name = &self.field_ident;
+        let set_fn = Ident::new(&format!("set_{field_name}"), Span::call_site());
         let wrapper = if self.optional {
             quote! { Some }
         } else {
@@ -228,16 +323,30 @@ impl BuilderFieldData {
         match &self.target {
             BuildTarget::Builable { shape, builder } => {
                 let builder_fn = Ident::new(&format!("{field_name}_builder"), Span::call_site());
+                let set_builder_fn =
+                    Ident::new(&format!("set_{field_name}_builder"), Span::call_site());
                 quote! {
-                    #[doc = concat!("Set `", stringify!(#field_name), "`.")]
-                    pub fn #field_name(mut self, value: #shape) -> Self {
-                        self.#field_name = #wrapper(#crate_ident::serde::MaybeBuilt::Struct(value));
+                    #[doc = concat!("Set `", stringify!(#field_name), "` from either a built shape or its builder.")]
+                    pub fn #field_name<T: Into<#crate_ident::serde::MaybeBuilt<#shape, #builder>>>(mut self, value: T) -> Self {
+                        self.#set_fn(value);
                         self
                     }
 
-                    #[doc = concat!("Set `", stringify!(#field_name), "`.")]
-                    pub fn #builder_fn(mut self, value: #builder) -> Self {
-                        self.#field_name = #wrapper(#crate_ident::serde::MaybeBuilt::Builder(value));
+                    #[doc = concat!("Set `", stringify!(#field_name), "` from either a built shape or its builder, in place.")]
+                    pub fn #set_fn<T: Into<#crate_ident::serde::MaybeBuilt<#shape, #builder>>>(&mut self, value: T) -> &mut Self {
+                        self.#field_name = #wrapper(value.into());
+                        self
+                    }
+
+                    #[doc = concat!("Set `", stringify!(#field_name), "` from a builder.")]
+                    pub fn #builder_fn<T: Into<#builder>>(mut self, value: T) -> Self {
+                        self.#set_builder_fn(value);
+                        self
+                    }
+
+                    #[doc = concat!("Set `", stringify!(#field_name), "` from a builder, in place.")]
+                    pub fn #set_builder_fn<T: Into<#builder>>(&mut self, value: T) -> &mut Self {
+                        self.#field_name = #wrapper(#crate_ident::serde::MaybeBuilt::Builder(value.into()));
                         self
                     }
                 }
@@ -246,6 +355,12 @@ impl BuilderFieldData {
                 quote! {
                     #[doc = concat!("Set `", stringify!(#field_name), "`.")]
                     pub fn #field_name<T: Into<#ty>>(mut self, value: T) -> Self {
+                        self.#set_fn(value);
+                        self
+                    }
+
+                    #[doc = concat!("Set `", stringify!(#field_name), "` in place.")]
+                    pub fn #set_fn<T: Into<#ty>>(&mut self, value: T) -> &mut Self {
                         self.#field_name = #wrapper(value.into());
                         self
                     }
@@ -329,5 +444,199 @@ impl BuilderFieldData {
 pub(crate) fn buildable(shape_name: &Ident, builder_name: &Ident) -> TokenStream {
     quote! {
        impl <'de> _Buildable<'de, #builder_name> for #shape_name {}
+
+       impl _HasBuilder for #shape_name {
+           type Builder = #builder_name;
+       }
+    }
+}
+
+// ============================================================================
+// Collection Builders
+// ============================================================================
+
+/// Element type of a wrapper shape with a top-level list or map schema.
+pub(crate) enum CollectionItem<'a> {
+    /// `Vec<T>` collected from values of `T`
+    List(&'a Type),
+    /// `IndexMap<K, V>` collected from `(K, V)` entries
+    Map(&'a Type, &'a Type),
+}
+impl CollectionItem<'_> {
+    fn item_type(&self) -> TokenStream {
+        match self {
+            CollectionItem::List(item) => quote! { #item },
+            CollectionItem::Map(key, value) => quote! { (#key, #value) },
+        }
+    }
+}
+
+/// Get the element type of a wrapped `Vec<T>` or `IndexMap<K, V>`.
+///
+/// Returns `None` if the wrapper does not contain a collection.
+pub(crate) fn get_collection_item(wrapped: &Type) -> Option<CollectionItem<'_>> {
+    let Type::Path(type_path) = wrapped else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let types = args
+        .args
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    match (segment.ident.to_string().as_str(), types.as_slice()) {
+        ("Vec", [item]) => Some(CollectionItem::List(item)),
+        ("IndexMap", [key, value]) => Some(CollectionItem::Map(key, value)),
+        _ => None,
+    }
+}
+
+/// Builder struct for a wrapper shape with a top-level list or map schema.
+///
+/// The builder wraps the same collection as the shape so it can be collected
+/// from (or extended with) an iterator and then validated on `build()`.
+pub(crate) fn collection_builder_struct(
+    shape_name: &Ident,
+    wrapped: &Type,
+    item: &CollectionItem<'_>,
+) -> Result<TokenStream> {
+    let builder_name = get_builder_ident(shape_name);
+    let crate_ident = get_crate_ident()?;
+    let item_type = item.item_type();
+
+    Ok(quote! {
+        #[doc = concat!("Builder for [`", stringify!(#shape_name), "`]")]
+        #[automatically_derived]
+        impl #shape_name {
+            /// Get a new builder for this shape.
+            #[must_use]
+            #[inline]
+            pub fn builder() -> #builder_name {
+                <Self as #crate_ident::serde::Buildable<#builder_name>>::builder()
+            }
+        }
+
+        #[doc = concat!("Builder for [`", stringify!(#shape_name), "`]")]
+        #[automatically_derived]
+        #[derive(Clone)]
+        pub struct #builder_name(#wrapped);
+
+        #[automatically_derived]
+        impl #builder_name {
+            #[doc = concat!("Create a new, empty `", stringify!(#builder_name), "` instance")]
+            pub fn new() -> Self {
+                Self(<#wrapped>::new())
+            }
+
+            /// Build the shape, validating with the default validator.
+            #[inline]
+            pub fn build(self) -> #crate_ident::serde::validation::Validated<#shape_name> {
+                #crate_ident::serde::ShapeBuilder::build(self)
+            }
+
+            /// Build the shape using a custom validator.
+            #[inline]
+            pub fn build_with_validator(self, validator: impl #crate_ident::serde::validation::Validator) -> #crate_ident::serde::validation::Validated<#shape_name> {
+                #crate_ident::serde::ShapeBuilder::build_with_validator(self, validator)
+            }
+        }
+
+        #[automatically_derived]
+        impl FromIterator<#item_type> for #builder_name {
+            fn from_iter<I: IntoIterator<Item = #item_type>>(iter: I) -> Self {
+                Self(iter.into_iter().collect())
+            }
+        }
+
+        #[automatically_derived]
+        impl Extend<#item_type> for #builder_name {
+            fn extend<I: IntoIterator<Item = #item_type>>(&mut self, iter: I) {
+                self.0.extend(iter);
+            }
+        }
+    })
+}
+
+/// Builder trait implementations for a wrapper shape with a top-level list or map schema.
+pub(crate) fn collection_builder_impls(
+    crate_ident: &TokenStream,
+    shape_name: &Ident,
+    wrapped: &Type,
+) -> TokenStream {
+    let builder_name = get_builder_ident(shape_name);
+    quote! {
+        use #crate_ident::serde::correction::ErrorCorrection as _ErrorCorrection;
+        use #crate_ident::serde::correction::ErrorCorrectionDefault as _ErrorCorrectionDefault;
+        use #crate_ident::serde::ShapeBuilder as _ShapeBuilder;
+        use #crate_ident::serde::Buildable as _Buildable;
+        use #crate_ident::serde::HasBuilder as _HasBuilder;
+
+        #[automatically_derived]
+        impl<'de> _DeserializeWithSchema<'de> for #builder_name {
+            #[inline]
+            fn deserialize_with_schema<D>(schema: &_Schema, deserializer: D) -> Result<Self, D::Error>
+            where
+                D: _Deserializer<'de>,
+            {
+                <#wrapped as _DeserializeWithSchema>::deserialize_with_schema(schema, deserializer).map(Self)
+            }
+        }
+
+        #[automatically_derived]
+        impl _ErrorCorrection for #builder_name {
+            type Value = #shape_name;
+
+            fn correct(self) -> Self::Value {
+                #shape_name(self.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'de> _ShapeBuilder<'de, #shape_name> for #builder_name {
+            fn new() -> Self {
+                Self::new()
+            }
+        }
+
+        #[automatically_derived]
+        impl _ErrorCorrectionDefault for #shape_name {
+            fn default() -> Self {
+                #builder_name::new().correct()
+            }
+        }
+
+        #[automatically_derived]
+        impl From<#builder_name> for #crate_ident::serde::MaybeBuilt<#shape_name, #builder_name> {
+            fn from(value: #builder_name) -> Self {
+                #crate_ident::serde::MaybeBuilt::Builder(value)
+            }
+        }
+
+        // Boxed (i.e. recursive) members can be set from unboxed shapes and builders
+        #[automatically_derived]
+        impl From<#shape_name> for #crate_ident::serde::MaybeBuilt<Box<#shape_name>, Box<#builder_name>> {
+            fn from(value: #shape_name) -> Self {
+                #crate_ident::serde::MaybeBuilt::Struct(Box::new(value))
+            }
+        }
+
+        #[automatically_derived]
+        impl From<#builder_name> for #crate_ident::serde::MaybeBuilt<Box<#shape_name>, Box<#builder_name>> {
+            fn from(value: #builder_name) -> Self {
+                #crate_ident::serde::MaybeBuilt::Builder(Box::new(value))
+            }
+        }
+
+        impl <'de> _Buildable<'de, #builder_name> for #shape_name {}
+
+        impl _HasBuilder for #shape_name {
+            type Builder = #builder_name;
+        }
     }
 }
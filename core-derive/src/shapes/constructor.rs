@@ -1,6 +1,6 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::FieldsUnnamed;
+use syn::{FieldsUnnamed, Result};
 
 use crate::shapes::utils::{get_crate_name, parse_wrapper_type};
 
@@ -12,10 +12,10 @@ pub(crate) fn get_tuple_constructor(
     schema_ident: &Ident,
     shape_name: &Ident,
     fields: &FieldsUnnamed,
-) -> TokenStream {
-    let inner_type = parse_wrapper_type(fields);
-    let crate_name = get_crate_name();
-    quote! {
+) -> Result<TokenStream> {
+    let inner_type = parse_wrapper_type(fields)?;
+    let crate_name = get_crate_name()?;
+    Ok(quote! {
         impl #shape_name {
             #[doc = concat!("Create a new [`", stringify!(#shape_name), "`] instance")]
             #[automatically_derived]
@@ -27,5 +27,5 @@ pub(crate) fn get_tuple_constructor(
                 Ok(res)
             }
         }
-    }
+    })
 }
@@ -12,3 +12,15 @@ pub(crate) fn debug_impl(shape_name: &Ident, schema_ident: &Ident) -> TokenStrea
         }
     }
 }
+
+/// Generates `Display` impl for Smithy Shapes.
+pub(crate) fn display_impl(shape_name: &Ident, schema_ident: &Ident) -> TokenStream {
+    quote! {
+        #[automatically_derived]
+        impl std::fmt::Display for #shape_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(&_DisplayWrapper::new(&#schema_ident, self), f)
+            }
+        }
+    }
+}
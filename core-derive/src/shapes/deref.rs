@@ -1,13 +1,13 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::FieldsUnnamed;
+use syn::{FieldsUnnamed, Result};
 
 use crate::shapes::utils::parse_wrapper_type;
 
 /// Implement deref for wrapper types
-pub(crate) fn deref_impl(shape_name: &Ident, fields: &FieldsUnnamed) -> TokenStream {
-    let inner_type = parse_wrapper_type(fields);
-    quote! {
+pub(crate) fn deref_impl(shape_name: &Ident, fields: &FieldsUnnamed) -> Result<TokenStream> {
+    let inner_type = parse_wrapper_type(fields)?;
+    Ok(quote! {
         use std::ops::Deref as _Deref;
 
         impl _Deref for #shape_name {
@@ -19,5 +19,5 @@ pub(crate) fn deref_impl(shape_name: &Ident, fields: &FieldsUnnamed) -> TokenStr
                 &self.0
             }
         }
-    }
+    })
 }
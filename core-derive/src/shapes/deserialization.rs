@@ -1,10 +1,16 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::{Data, DataEnum, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, Lit, Type, Variant};
+use syn::{
+    Data, DataEnum, DeriveInput, Error, Fields, FieldsNamed, FieldsUnnamed, Lit, Result, Type,
+    Variant,
+};
 
 use crate::shapes::{
     get_builder_fields,
-    utils::{get_builder_ident, is_union, parse_enum_value, parse_schema, parse_wrapper_type},
+    utils::{
+        first_variant, get_builder_ident, get_ident, get_inner_type, is_primitive, is_union,
+        no_builder, parse_schema, parse_variant_value, parse_wrapper_type,
+    },
 };
 
 /// Generate `DeserializeWithSchema` implementation for Smithy Shapes
@@ -13,32 +19,37 @@ pub(crate) fn deserialization_impl(
     shape_name: &Ident,
     schema_ident: &Ident,
     input: &DeriveInput,
-) -> TokenStream {
+) -> Result<TokenStream> {
     let deser_impl = match &input.data {
         // Structures are deserialized via builders
         Data::Struct(data) => match &data.fields {
             Fields::Named(fields) => {
-                deserialize_builder(crate_ident, schema_ident, shape_name, fields)
+                deserialize_builder(crate_ident, schema_ident, shape_name, fields)?
             }
-            Fields::Unnamed(field) => deserialize_wrapper(shape_name, field),
+            Fields::Unnamed(field) => deserialize_wrapper(shape_name, field)?,
             Fields::Unit => deserialize_unit(shape_name),
         },
         Data::Enum(data) => {
-            if is_union(data) {
-                deserialize_union(crate_ident, shape_name, schema_ident, data)
+            if is_union(data)? {
+                deserialize_union(crate_ident, shape_name, schema_ident, data)?
             } else {
-                deserialize_enum(shape_name, data)
+                deserialize_enum(crate_ident, shape_name, data)?
             }
         }
-        _ => panic!("SerializableShape can only be derived for structs, enum, or unions"),
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "DeserializableShape can only be derived for structs, enum, or unions",
+            ));
+        }
     };
-    quote! {
+    Ok(quote! {
         // Base deserialization imports
         use #crate_ident::serde::deserializers::Deserializer as _Deserializer;
         use #crate_ident::serde::deserializers::DeserializeWithSchema as _DeserializeWithSchema;
 
         #deser_impl
-    }
+    })
 }
 
 // ============================================================================
@@ -51,9 +62,9 @@ fn deserialize_builder(
     schema_ident: &Ident,
     shape_name: &Ident,
     fields: &FieldsNamed,
-) -> TokenStream {
+) -> Result<TokenStream> {
     let builder_name = get_builder_ident(shape_name);
-    let field_data = get_builder_fields(schema_ident, fields);
+    let field_data = get_builder_fields(schema_ident, fields)?;
 
     // Generate deserialize_member! or deserialize_optional_member! macro calls for each field
     let match_arms = field_data
@@ -61,13 +72,16 @@ fn deserialize_builder(
         .map(|d| d.deserialize_match_arm(crate_ident))
         .collect::<Vec<_>>();
 
-    quote! {
+    Ok(quote! {
         // builder-specific imports
         use #crate_ident::serde::correction::ErrorCorrection as _ErrorCorrection;
         use #crate_ident::serde::correction::ErrorCorrectionDefault as _ErrorCorrectionDefault;
         use #crate_ident::serde::ShapeBuilder as _ShapeBuilder;
         use #crate_ident::serde::Buildable as _Buildable;
+        use #crate_ident::serde::HasBuilder as _HasBuilder;
         use #crate_ident::serde::deserializers::StructReader as _StructReader;
+        use #crate_ident::serde::deserializers::MemberPresence as _MemberPresence;
+        use #crate_ident::serde::deserializers::Error as _;
 
         #[automatically_derived]
         impl<'de> _DeserializeWithSchema<'de> for #builder_name {
@@ -77,8 +91,13 @@ fn deserialize_builder(
             {
                 let mut builder = #builder_name::new();
                 let mut reader = deserializer.read_struct(schema)?;
+                // Members may be read in any order, but each may only appear once
+                let mut present = _MemberPresence::new();
 
                 while let Some(member_schema) = reader.read_member(schema)? {
+                    if !present.insert(member_schema) {
+                        return Err(D::Error::duplicate_member(member_schema));
+                    }
                     #(#match_arms)*
                     // Known schema member but unknown to this code version (forward compat)
                     reader.skip_value()?;
@@ -87,16 +106,16 @@ fn deserialize_builder(
                 Ok(builder)
             }
         }
-    }
+    })
 }
 
 // ============================================================================
 // Tuple (Wrapper) struct Deserialization
 // ============================================================================
 
-fn deserialize_wrapper(shape_name: &Ident, fields: &FieldsUnnamed) -> TokenStream {
-    let inner_type = parse_wrapper_type(fields);
-    quote! {
+fn deserialize_wrapper(shape_name: &Ident, fields: &FieldsUnnamed) -> Result<TokenStream> {
+    let inner_type = parse_wrapper_type(fields)?;
+    Ok(quote! {
         #[automatically_derived]
         impl<'de> _DeserializeWithSchema<'de> for #shape_name {
             #[inline]
@@ -108,7 +127,7 @@ fn deserialize_wrapper(shape_name: &Ident, fields: &FieldsUnnamed) -> TokenStrea
                 Ok(Self(inner))
             }
         }
-    }
+    })
 }
 
 // ============================================================================
@@ -135,58 +154,57 @@ fn deserialize_unit(shape_name: &Ident) -> TokenStream {
 // Enum Deserialization
 // ============================================================================
 
-fn deserialize_enum(shape_name: &Ident, data: &DataEnum) -> TokenStream {
-    let method = determine_enum_deser_method(data);
-    let match_val = determine_enum_match_method(data);
-    let unknown = syn::parse_str::<Ident>("Unknown").unwrap();
-    let variant = data
-        .variants
-        .iter()
-        .map(|v| &v.ident)
-        .filter(|i| **i != unknown);
-    let value = data.variants.iter().map(|v| parse_enum_value(&v.attrs));
-    quote! {
+fn deserialize_enum(
+    crate_ident: &TokenStream,
+    shape_name: &Ident,
+    data: &DataEnum,
+) -> Result<TokenStream> {
+    let (expected, other, match_val, unknown) = match parse_variant_value(first_variant(data)?)? {
+        Lit::Str(_) => (
+            quote! { _RawEnumValue::String(val) },
+            quote! { _RawEnumValue::Int(_) },
+            quote! { val.as_ref() },
+            quote! { val.into_owned() },
+        ),
+        _ => (
+            quote! { _RawEnumValue::Int(val) },
+            quote! { _RawEnumValue::String(_) },
+            quote! { val },
+            quote! { val },
+        ),
+    };
+    let mut variant = Vec::new();
+    let mut value = Vec::new();
+    for v in data.variants.iter().filter(|v| v.ident != "Unknown") {
+        variant.push(&v.ident);
+        value.push(parse_variant_value(v)?);
+    }
+    Ok(quote! {
+        use #crate_ident::serde::deserializers::Error as _;
+        use #crate_ident::serde::deserializers::RawEnumValue as _RawEnumValue;
+        use #crate_ident::serde::deserializers::UnknownEnumPolicy as _UnknownEnumPolicy;
+
         #[automatically_derived]
         impl<'de> _DeserializeWithSchema<'de> for #shape_name {
             fn deserialize_with_schema<D>(schema: &_Schema, deserializer: D) -> Result<Self, D::Error>
             where
                 D: _Deserializer<'de>,
             {
-                let val = deserializer.#method(schema)?;
-                let result = match #match_val {
-                    #(#value => #shape_name::#variant,)*
-                    _ => #shape_name::Unknown(val)
+                let policy = deserializer.unknown_enum_policy();
+                let result = match deserializer.read_enum(schema)? {
+                    #expected => match #match_val {
+                        #(#value => #shape_name::#variant,)*
+                        _ if policy == _UnknownEnumPolicy::Error => {
+                            return Err(D::Error::unknown_enum_value(schema, &val));
+                        }
+                        _ => #shape_name::Unknown(#unknown)
+                    },
+                    #other => return Err(D::Error::custom("Unexpected enum value type")),
                 };
                 Ok(result)
             }
         }
-    }
-}
-
-/// Determines enum method to use for deserializing an enum.
-fn determine_enum_deser_method(data: &DataEnum) -> Ident {
-    let first_var = data
-        .variants
-        .first()
-        .expect("At least one enum variant expected");
-    match parse_enum_value(&first_var.attrs) {
-        Some(Lit::Str(_)) => Ident::new("read_string", Span::call_site()),
-        Some(Lit::Int(_)) => Ident::new("read_integer", Span::call_site()),
-        _ => panic!("Unsupported enum value. Expected string or int literal."),
-    }
-}
-
-/// Determines how to correctly match on value
-fn determine_enum_match_method(data: &DataEnum) -> TokenStream {
-    let first_var = data
-        .variants
-        .first()
-        .expect("At least one enum variant expected");
-    match parse_enum_value(&first_var.attrs) {
-        Some(Lit::Str(_)) => quote! { val.as_str() },
-        Some(Lit::Int(_)) => quote! { val },
-        _ => panic!("Unsupported enum value. Expected string or int literal."),
-    }
+    })
 }
 
 // ============================================================================
@@ -198,10 +216,13 @@ fn deserialize_union(
     shape_name: &Ident,
     schema_ident: &Ident,
     data: &DataEnum,
-) -> TokenStream {
+) -> Result<TokenStream> {
     let mut imports = quote! {
+        use #crate_ident::prelude::DOCUMENT as _DOCUMENT;
         use #crate_ident::serde::deserializers::Error as _;
+        use #crate_ident::serde::deserializers::MemberKey as _MemberKey;
         use #crate_ident::serde::deserializers::StructReader as _StructReader;
+        use #crate_ident::serde::deserializers::set_union_value as _set_union_value;
     };
     if data.variants.iter().any(|v| v.fields.is_empty()) {
         imports = quote! {
@@ -209,15 +230,24 @@ fn deserialize_union(
             use #crate_ident::schema::Unit as _Unit;
         }
     }
-    let unknown = syn::parse_str::<Ident>("Unknown").unwrap();
     let variants = data
         .variants
         .iter()
-        .filter(|v| v.ident != unknown)
+        .filter(|v| v.ident != "Unknown")
         .map(UnionDeserVariant::from)
-        .map(|udv| udv.matcher(shape_name, schema_ident));
+        .collect::<Result<Vec<_>>>()?;
+    if variants.iter().any(|v| v.builder.is_some()) {
+        imports = quote! {
+            #imports
+            use #crate_ident::serde::ShapeBuilder as _ShapeBuilder;
+        }
+    }
+    let variants = variants
+        .iter()
+        .map(|v| v.matcher(shape_name, schema_ident))
+        .collect::<Vec<_>>();
 
-    quote! {
+    Ok(quote! {
         #imports
 
         #[automatically_derived]
@@ -229,47 +259,66 @@ fn deserialize_union(
                 let mut reader = deserializer.read_struct(schema)?;
                 let mut result: Option<#shape_name> = None;
 
-                while let Some(member_schema) = reader.read_member(schema)? {
-                    if result.is_some() {
-                        return Err(D::Error::custom("Attempted to set union value twice"));
-                    }
+                while let Some(member) = reader.read_member_or_unknown(schema)? {
+                    let member_schema = match member {
+                        _MemberKey::Known(member_schema) => member_schema,
+                        _MemberKey::Unknown(name) => {
+                            let value = reader
+                                .read_value::<Option<_>>(&_DOCUMENT)?
+                                .map(|value| #shape_name::Unknown(name, value));
+                            _set_union_value(&mut result, value)?;
+                            continue;
+                        }
+                    };
                     #(#variants)*
                     // Known schema member but unknown to this code version (forward compat)
-                    result = Some(#shape_name::Unknown("unknown".to_string()));
-                    continue;
+                    let name = member_schema
+                        .as_member()
+                        .map_or_else(String::new, |member| member.name().to_string());
+                    let value = reader
+                        .read_value::<Option<_>>(&_DOCUMENT)?
+                        .map(|value| #shape_name::Unknown(name, value));
+                    _set_union_value(&mut result, value)?;
                 }
 
-                result.ok_or(D::Error::custom("Failed to deserialize union"))
+                result.ok_or(D::Error::custom("Expected exactly one non-null union member to be set"))
             }
         }
-    }
+    })
 }
 
 struct UnionDeserVariant {
     schema: Ident,
     var_ident: Ident,
+    /// Type of the variant value, or `None` for unit variants
     ty: Option<Type>,
-    unit: bool,
+    /// Builder used to deserialize structure variants
+    builder: Option<Ident>,
 }
 
 impl UnionDeserVariant {
-    fn from(variant: &Variant) -> Self {
-        let schema = parse_schema(&variant.attrs);
+    fn from(variant: &Variant) -> Result<Self> {
+        let schema = parse_schema(&variant.attrs, variant)?;
         let var_ident = variant.ident.clone();
-        let unit = variant.fields.is_empty();
-        let ty = variant
-            .fields
-            .iter()
-            .map(|f| f.ty.clone())
-            .collect::<Vec<_>>()
-            .first()
-            .cloned();
-        UnionDeserVariant {
+        let ty = variant.fields.iter().next().map(|f| f.ty.clone());
+        // Structures are deserialized via their builder. Parametrized types (i.e. `Vec<T>`)
+        // and types marked with `#[no_builder]` (enums, unions) are deserialized directly.
+        let builder = match &ty {
+            Some(ty)
+                if std::ptr::eq(get_inner_type(ty), ty)
+                    && !is_primitive(ty)
+                    && !no_builder(&variant.attrs) =>
+            {
+                Some(get_builder_ident(get_ident(ty)?))
+            }
+            _ => None,
+        };
+        Ok(UnionDeserVariant {
             schema,
             var_ident,
             ty,
-            unit,
-        }
+            builder,
+        })
     }
 
     fn matcher(&self, shape_name: &Ident, schema_ident: &Ident) -> TokenStream {
@@ -278,23 +327,37 @@ impl UnionDeserVariant {
             &format!("_{}_MEMBER_{}", schema_ident, &self.schema),
             Span::call_site(),
         );
-        if self.unit {
-            quote! {
+        match (&self.ty, &self.builder) {
+            (None, _) => quote! {
                 if member_schema == *#member_schema_const {
-                    let _: _Unit = reader.read_value(member_schema)?;
-                    result = Some(#shape_name::#variant_name);
+                    let value = reader
+                        .read_value::<Option<_Unit>>(member_schema)?
+                        .map(|_| #shape_name::#variant_name);
+                    _set_union_value(&mut result, value)?;
                     continue;
                 }
-            }
-        } else {
-            let ty = self.ty.as_ref().expect("Expected a type");
-            quote! {
+            },
+            (Some(_), Some(builder)) => quote! {
                 if member_schema == *#member_schema_const {
-                    let value: #ty = reader.read_value(member_schema)?;
-                    result = Some(#shape_name::#variant_name(value));
+                    let value = reader
+                        .read_value::<Option<#builder>>(member_schema)?
+                        .map(_ShapeBuilder::build)
+                        .transpose()
+                        .map_err(D::Error::custom)?
+                        .map(#shape_name::#variant_name);
+                    _set_union_value(&mut result, value)?;
                     continue;
                 }
-            }
+            },
+            (Some(ty), None) => quote! {
+                if member_schema == *#member_schema_const {
+                    let value = reader
+                        .read_value::<Option<#ty>>(member_schema)?
+                        .map(#shape_name::#variant_name);
+                    _set_union_value(&mut result, value)?;
+                    continue;
+                }
+            },
         }
     }
 }
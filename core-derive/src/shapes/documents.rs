@@ -0,0 +1,47 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Data, Fields};
+
+use crate::shapes::utils::get_builder_ident;
+
+/// Generates conversions from a `Document` into a Smithy Shape.
+///
+/// Structures with named fields are built from their builder. All other shapes
+/// implement `TryFromDocument` via their `DeserializableShape` implementation.
+///
+/// NOTE: The reverse conversion (`From<Shape> for Box<dyn Document>`) is
+/// provided by a blanket implementation in the core crate.
+pub(crate) fn try_from_document_impl(
+    crate_ident: &TokenStream,
+    shape_name: &Ident,
+    data: &Data,
+) -> TokenStream {
+    let builder_conversion = match data {
+        Data::Struct(data) if matches!(data.fields, Fields::Named(_)) => {
+            let builder = get_builder_ident(shape_name);
+            quote! {
+                #[automatically_derived]
+                impl _TryFromDocument for #shape_name {
+                    fn try_from(document: Box<dyn _Document>) -> Result<Self, _DocumentError> {
+                        use #crate_ident::serde::ShapeBuilder as _ShapeBuilder;
+                        let builder = <#builder as _TryFromDocument>::try_from(document)?;
+                        Ok(_ShapeBuilder::build(builder)?)
+                    }
+                }
+            }
+        }
+        _ => quote! {},
+    };
+    quote! {
+        #builder_conversion
+
+        #[automatically_derived]
+        impl TryFrom<Box<dyn _Document>> for #shape_name {
+            type Error = _DocumentError;
+
+            fn try_from(document: Box<dyn _Document>) -> Result<Self, _DocumentError> {
+                <Self as _TryFromDocument>::try_from(document)
+            }
+        }
+    }
+}
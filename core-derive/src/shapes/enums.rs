@@ -0,0 +1,112 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DataEnum, ItemEnum, Lit, Result};
+
+use crate::shapes::utils::parse_variant_value;
+
+/// Generates `EnumShape` implementation for Smithy Enums and Int Enums.
+pub(crate) fn enum_shape_impl(shape_name: &Ident, data: &DataEnum) -> Result<TokenStream> {
+    let mut variant = Vec::new();
+    let mut value = Vec::new();
+    for v in data.variants.iter().filter(|v| v.ident != "Unknown") {
+        variant.push(&v.ident);
+        value.push(match parse_variant_value(v)? {
+            Lit::Int(value) => quote! { _EnumValue::Int(#value) },
+            value => quote! { _EnumValue::String(#value) },
+        });
+    }
+    let name = variant.iter().map(ToString::to_string);
+    Ok(quote! {
+        #[automatically_derived]
+        impl _EnumShape for #shape_name {
+            #[inline]
+            fn variants() -> &'static [(&'static str, _EnumValue)] {
+                &[#((#name, #value)),*]
+            }
+
+            fn from_enum_value(value: &_EnumValue) -> Option<Self> {
+                match value {
+                    #(#value => Some(#shape_name::#variant),)*
+                    _ => None,
+                }
+            }
+        }
+    })
+}
+
+/// Generates a `Known{Enum}` enum holding only the known variants of a Smithy enum,
+/// along with a `match_known` combinator that converts to it.
+///
+/// Must be called _AFTER_ the `Unknown` variant is added and discriminants are
+/// converted to `#[enum_value]` attributes.
+pub(crate) fn known_enum(item: &ItemEnum) -> Result<TokenStream> {
+    let vis = &item.vis;
+    let shape_name = &item.ident;
+    let known_name = format_ident!("Known{}", shape_name);
+    let mut variant = Vec::new();
+    let mut docs = Vec::new();
+    let mut int_enum = false;
+    for v in item.variants.iter().filter(|v| v.ident != "Unknown") {
+        int_enum = matches!(parse_variant_value(v)?, Lit::Int(_));
+        variant.push(&v.ident);
+        docs.push(
+            v.attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("doc"))
+                .collect::<Vec<_>>(),
+        );
+    }
+    let (unknown_ty, unknown_value) = if int_enum {
+        (quote! { i32 }, quote! { *value })
+    } else {
+        (quote! { &str }, quote! { value })
+    };
+    let known_doc = format!(
+        "Known variants of [`{shape_name}`].\n\nMatches on this enum are exhaustive, \
+        so variants added to the model are caught at compile time."
+    );
+    let match_doc = format!(
+        "Call `known` with the variant of this enum, or `unknown` with the raw value \
+        of an unknown variant.\n\nUse this rather than matching on [`{shape_name}`] \
+        directly to handle every known variant of the enum exhaustively."
+    );
+    Ok(quote! {
+        #[doc = #known_doc]
+        #[derive(
+            ::core::fmt::Debug,
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::hash::Hash
+        )]
+        #vis enum #known_name {
+            #(#(#docs)* #variant,)*
+        }
+
+        impl #shape_name {
+            #[doc = #match_doc]
+            #[inline]
+            pub fn match_known<R>(
+                &self,
+                known: impl FnOnce(#known_name) -> R,
+                unknown: impl FnOnce(#unknown_ty) -> R,
+            ) -> R {
+                match self {
+                    #(#shape_name::#variant => known(#known_name::#variant),)*
+                    #shape_name::Unknown(value) => unknown(#unknown_value),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::From<#known_name> for #shape_name {
+            #[inline]
+            fn from(value: #known_name) -> Self {
+                match value {
+                    #(#known_name::#variant => #shape_name::#variant,)*
+                }
+            }
+        }
+    })
+}
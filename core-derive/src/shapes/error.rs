@@ -0,0 +1,55 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Data, Error, Fields, Result};
+
+use crate::shapes::utils::is_optional;
+
+/// Generates `Display`, `Error`, and `ErrorShape` impls for error structures.
+///
+/// The error message is read from a field named `message`, if one exists.
+pub(crate) fn error_impl(
+    shape_name: &Ident,
+    schema_ident: &Ident,
+    data: &Data,
+) -> Result<TokenStream> {
+    let Data::Struct(data) = data else {
+        return Err(Error::new_spanned(
+            shape_name,
+            "`smithy_error` can only be used on structures",
+        ));
+    };
+    let message = match &data.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .find(|field| field.ident.as_ref().is_some_and(|ident| ident == "message")),
+        _ => None,
+    };
+    let message = match message {
+        Some(field) if is_optional(&field.ty)? => quote! { self.message.as_deref() },
+        Some(_) => quote! { Some(&self.message) },
+        None => quote! { None },
+    };
+    Ok(quote! {
+        #[automatically_derived]
+        impl std::fmt::Display for #shape_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let name = #schema_ident.id().name();
+                match _ErrorShape::message(self) {
+                    Some(message) => write!(f, "{}: {}", name, message),
+                    None => f.write_str(name),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl std::error::Error for #shape_name {}
+
+        #[automatically_derived]
+        impl _ErrorShape for #shape_name {
+            fn message(&self) -> Option<&str> {
+                #message
+            }
+        }
+    })
+}
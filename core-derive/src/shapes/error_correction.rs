@@ -1,16 +1,16 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{DataEnum, Lit};
+use syn::{DataEnum, Lit, Result};
 
-use crate::shapes::utils::parse_enum_value;
+use crate::shapes::utils::{first_variant, is_union, parse_enum_value};
 
 pub(crate) fn enum_error_correction_impl(
     crate_ident: &TokenStream,
     shape_name: &Ident,
     data: &DataEnum,
-) -> TokenStream {
-    let filler = determine_enum_filler_value(data);
-    quote! {
+) -> Result<TokenStream> {
+    let filler = determine_enum_filler_value(crate_ident, data)?;
+    Ok(quote! {
         use #crate_ident::serde::correction::ErrorCorrectionDefault as _ErrorCorrectionDefault;
 
         impl _ErrorCorrectionDefault for #shape_name {
@@ -20,18 +20,20 @@ pub(crate) fn enum_error_correction_impl(
                 #shape_name::Unknown(#filler)
             }
         }
-    }
+    })
 }
 
 /// Determines how to correctly match on value
-fn determine_enum_filler_value(data: &DataEnum) -> TokenStream {
-    let first_var = data
-        .variants
-        .first()
-        .expect("At least one enum variant expected");
-    if matches!(parse_enum_value(&first_var.attrs), Some(Lit::Int(_))) {
-        quote! { 0i32 }
-    } else {
-        quote! { "".to_string() }
+fn determine_enum_filler_value(crate_ident: &TokenStream, data: &DataEnum) -> Result<TokenStream> {
+    if is_union(data)? {
+        return Ok(quote! { String::new(), #crate_ident::schema::NULL.clone() });
     }
+    let first_var = first_variant(data)?;
+    Ok(
+        if matches!(parse_enum_value(&first_var.attrs)?, Some(Lit::Int(_))) {
+            quote! { 0i32 }
+        } else {
+            quote! { "".to_string() }
+        },
+    )
 }
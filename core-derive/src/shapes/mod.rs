@@ -7,6 +7,9 @@ pub(crate) use debug::*;
 mod deserialization;
 pub(crate) use deserialization::*;
 
+mod documents;
+pub(crate) use documents::*;
+
 mod schema;
 pub(crate) use schema::*;
 
@@ -23,9 +26,13 @@ mod constructor;
 pub(crate) use constructor::*;
 
 mod deref;
+mod enums;
+mod error;
 mod error_correction;
 mod traits;
 pub(crate) mod utils;
 pub(crate) use deref::*;
+pub(crate) use enums::*;
+pub(crate) use error::*;
 pub(crate) use error_correction::*;
 pub(crate) use traits::*;
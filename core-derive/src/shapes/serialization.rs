@@ -1,10 +1,15 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::{Data, DataEnum, DeriveInput, Field, Fields, FieldsNamed, FieldsUnnamed, Lit, Variant};
+use syn::{
+    Data, DataEnum, DeriveInput, Error, Field, Fields, FieldsNamed, FieldsUnnamed, Lit, Result,
+    Variant,
+};
 
 use crate::{
     parse_schema,
-    shapes::utils::{is_optional, is_union, parse_enum_value},
+    shapes::utils::{
+        first_variant, is_optional, is_union, parse_variant_value, parse_wrapper_type,
+    },
 };
 
 /// Generates the `SerializeWithSchema` implementation for a shape.
@@ -13,7 +18,7 @@ pub(crate) fn serialization_impl(
     shape_name: &Ident,
     schema_ident: &Ident,
     input: &DeriveInput,
-) -> TokenStream {
+) -> Result<TokenStream> {
     let mut imports = quote! {
         use #crate_ident::serde::serializers::Serializer as _Serializer;
         use #crate_ident::serde::serializers::SerializeWithSchema as _SerializeWithSchema;
@@ -27,13 +32,13 @@ pub(crate) fn serialization_impl(
                 use #crate_ident::serde::serializers::StructWriter as _StructWriter;
             };
             match &data.fields {
-                Fields::Named(fields) => serialize_struct(schema_ident, fields),
-                Fields::Unnamed(fields) => serialize_tuple(fields),
+                Fields::Named(fields) => serialize_struct(schema_ident, fields)?,
+                Fields::Unnamed(fields) => serialize_tuple(fields)?,
                 Fields::Unit => serialize_unit(),
             }
         }
         Data::Enum(data) => {
-            if is_union(data) {
+            if is_union(data)? {
                 imports = quote! {
                     #imports
                     use #crate_ident::serde::serializers::StructWriter as _StructWriter;
@@ -44,15 +49,20 @@ pub(crate) fn serialization_impl(
                         use #crate_ident::schema::Unit as _Unit;
                     };
                 }
-                serialize_union(shape_name, schema_ident, data)
+                serialize_union(shape_name, schema_ident, data)?
             } else {
-                serialize_enum(shape_name, data)
+                serialize_enum(shape_name, data)?
             }
         }
-        _ => panic!("SerializableShape can only be derived for structs, enum, or unions"),
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "SerializableShape can only be derived for structs, enum, or unions",
+            ));
+        }
     };
 
-    quote! {
+    Ok(quote! {
         #imports
 
         #[automatically_derived]
@@ -65,7 +75,7 @@ pub(crate) fn serialization_impl(
                 #body
             }
         }
-    }
+    })
 }
 
 // ============================================================================
@@ -73,9 +83,13 @@ pub(crate) fn serialization_impl(
 // ============================================================================
 
 /// Generates body of serialization impl for Structures
-fn serialize_struct(schema_ident: &Ident, fields: &FieldsNamed) -> TokenStream {
+fn serialize_struct(schema_ident: &Ident, fields: &FieldsNamed) -> Result<TokenStream> {
     let length = &fields.named.len();
-    let field_data: Vec<FieldData> = fields.named.iter().map(FieldData::from).collect::<Vec<_>>();
+    let field_data = fields
+        .named
+        .iter()
+        .map(FieldData::from)
+        .collect::<Result<Vec<_>>>()?;
     // Now write the thing
     let method = field_data.iter().map(|d| d.method_call());
     let member_schema = field_data.iter().map(|d| d.member_schema(schema_ident));
@@ -83,11 +97,11 @@ fn serialize_struct(schema_ident: &Ident, fields: &FieldsNamed) -> TokenStream {
     // TODO: This needs to be the exact member name used in the schema. I think it might differ from the field name
     // in some cases
     let member_name_str = field_data.iter().map(|d| d.field_ident.to_string());
-    quote! {
+    Ok(quote! {
         let mut ser = serializer.write_struct(schema, #length)?;
         #(ser.#method(#member_name_str, &#member_schema, &self.#member_name)?;)*
         ser.end(schema)
-    }
+    })
 }
 
 struct FieldData {
@@ -111,15 +125,18 @@ impl FieldData {
         )
     }
 
-    fn from(field: &Field) -> Self {
-        let schema = parse_schema(&field.attrs);
-        let field_ident = field.ident.as_ref().unwrap().clone();
-        let optional = is_optional(&field.ty);
-        FieldData {
+    fn from(field: &Field) -> Result<Self> {
+        let schema = parse_schema(&field.attrs, field)?;
+        let field_ident = field
+            .ident
+            .clone()
+            .ok_or_else(|| Error::new_spanned(field, "Expected a named field"))?;
+        let optional = is_optional(&field.ty)?;
+        Ok(FieldData {
             schema,
             field_ident,
             optional,
-        }
+        })
     }
 }
 
@@ -128,62 +145,43 @@ impl FieldData {
 // ============================================================================
 
 /// Generates body of serialization impl for Enums
-fn serialize_enum(shape_name: &Ident, data: &DataEnum) -> TokenStream {
-    let method = determine_enum_ser_method(data);
-    let unknown = syn::parse_str::<Ident>("Unknown").unwrap();
-    let variant = data
-        .variants
-        .iter()
-        .map(|v| &v.ident)
-        .filter(|i| **i != unknown);
-    let value = data
-        .variants
-        .iter()
-        .map(|v| parse_enum_value(&v.attrs).expect("parsable #[enum_value] attribute"));
-    let is_string = matches!(
-        parse_enum_value(&data.variants.first().expect("at least one variant").attrs),
-        Some(Lit::Str(_))
-    );
-    let value_ident = if is_string {
-        quote! { value.as_str() }
+fn serialize_enum(shape_name: &Ident, data: &DataEnum) -> Result<TokenStream> {
+    let is_string = matches!(parse_variant_value(first_variant(data)?)?, Lit::Str(_));
+    let (method, value_ident) = if is_string {
+        (
+            Ident::new("write_string", Span::call_site()),
+            quote! { value.as_str() },
+        )
     } else {
-        quote! { *value }
+        (
+            Ident::new("write_integer", Span::call_site()),
+            quote! { *value },
+        )
     };
-    quote! {
+    let mut variant = Vec::new();
+    let mut value = Vec::new();
+    for v in data.variants.iter().filter(|v| v.ident != "Unknown") {
+        variant.push(&v.ident);
+        value.push(parse_variant_value(v)?);
+    }
+    Ok(quote! {
         let value = match self {
             #(#shape_name::#variant => #value,)*
             #shape_name::Unknown(value) => #value_ident
         };
         serializer.#method(schema, value)
-    }
-}
-
-/// Determines enum method to use for serializing an enum.
-fn determine_enum_ser_method(data: &DataEnum) -> Ident {
-    let first_var = data
-        .variants
-        .first()
-        .expect("At least one enum variant expected");
-    match parse_enum_value(&first_var.attrs) {
-        Some(Lit::Str(_)) => Ident::new("write_string", Span::call_site()),
-        Some(Lit::Int(_)) => Ident::new("write_integer", Span::call_site()),
-        _ => panic!("Unsupported enum value. Expected string or int literal."),
-    }
+    })
 }
 
 // ============================================================================
 // Wrapper (tuple) Serialization
 // ============================================================================
 
-fn serialize_tuple(data: &FieldsUnnamed) -> TokenStream {
-    assert_eq!(
-        data.unnamed.len(),
-        1,
-        "Trait wrappers must have only one field"
-    );
-    quote! {
+fn serialize_tuple(data: &FieldsUnnamed) -> Result<TokenStream> {
+    parse_wrapper_type(data)?;
+    Ok(quote! {
         self.0.serialize_with_schema(schema, serializer)
-    }
+    })
 }
 
 // ============================================================================
@@ -201,25 +199,28 @@ fn serialize_unit() -> TokenStream {
 // ============================================================================
 
 /// Generates body of serialization impl for Enums
-fn serialize_union(shape_name: &Ident, schema_ident: &Ident, data: &DataEnum) -> TokenStream {
-    let unknown = syn::parse_str::<Ident>("Unknown").unwrap();
+fn serialize_union(
+    shape_name: &Ident,
+    schema_ident: &Ident,
+    data: &DataEnum,
+) -> Result<TokenStream> {
     let variants = data
         .variants
         .iter()
-        .filter(|v| v.ident != unknown)
+        .filter(|v| v.ident != "Unknown")
         .map(UnionVariant::from)
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>>>()?;
     let match_arm = variants
         .iter()
         .map(|v| v.match_arm(shape_name, schema_ident));
-    quote! {
+    Ok(quote! {
         let mut ser = serializer.write_struct(schema, 1)?;
         match self {
             #(#match_arm,)*
-            #shape_name::Unknown(unknown) => ser.write_unknown(schema, unknown)?,
+            #shape_name::Unknown(name, value) => ser.write_unknown_member(name, value)?,
         }
         ser.end(schema)
-    }
+    })
 }
 
 struct UnionVariant {
@@ -236,15 +237,15 @@ impl UnionVariant {
         )
     }
 
-    fn from(variant: &Variant) -> Self {
-        let schema = parse_schema(&variant.attrs);
+    fn from(variant: &Variant) -> Result<Self> {
+        let schema = parse_schema(&variant.attrs, variant)?;
         let field_ident = variant.ident.clone();
         let unit = variant.fields.is_empty();
-        UnionVariant {
+        Ok(UnionVariant {
             schema,
             field_ident,
             unit,
-        }
+        })
     }
 
     fn match_arm(&self, shape_name: &Ident, schema_ident: &Ident) -> TokenStream {
@@ -1,26 +1,28 @@
 use proc_macro_crate::{FoundCrate, crate_name};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{ToTokens, quote};
-use syn::{__private::TokenStream2, Attribute, DataEnum, Expr, Field, Lit, Type};
+use syn::{__private::TokenStream2, Attribute, DataEnum, Error, Expr, Lit, Result, Type, Variant};
 
 /// Parses out attribute data for the `smithy_schema` macro attribute from the struct and
 /// its fields.
-pub(crate) fn parse_schema(attrs: &[Attribute]) -> Ident {
+///
+/// Errors are reported at the attribute if it is malformed, or at `target` if it is missing.
+pub(crate) fn parse_schema(attrs: &[Attribute], target: &impl ToTokens) -> Result<Ident> {
     let mut target_schema = None;
     for attr in attrs {
         if attr.path().is_ident("smithy_schema") {
-            target_schema = Some(
-                attr.parse_args::<Ident>()
-                    .expect("`smithy_schema` attribute should be an identifier"),
-            );
+            target_schema = Some(attr.parse_args::<Ident>().map_err(|_| {
+                Error::new_spanned(attr, "`smithy_schema` attribute should be an identifier")
+            })?);
         }
     }
-    target_schema.expect("Could not find `smithy_schema` attribute")
+    target_schema
+        .ok_or_else(|| Error::new_spanned(target, "Could not find `smithy_schema` attribute"))
 }
 
 /// Determine if a type is an `Option<T>`
-pub(crate) fn is_optional(ty: &Type) -> bool {
-    match ty {
+pub(crate) fn is_optional(ty: &Type) -> Result<bool> {
+    Ok(match ty {
         Type::Path(type_path) => {
             let path = &type_path.path;
             let idents_of_path = path.segments.iter().fold(String::new(), |mut acc, v| {
@@ -34,8 +36,13 @@ pub(crate) fn is_optional(ty: &Type) -> bool {
                 .into_iter()
                 .any(|s| idents_of_path == *s)
         }
-        _ => panic!("Serde can only be derived for resolvable types"),
-    }
+        _ => {
+            return Err(Error::new_spanned(
+                ty,
+                "Smithy shapes can only be derived for resolvable types",
+            ));
+        }
+    })
 }
 
 /// Get the inner type of `Option<T>` if possible.
@@ -67,10 +74,19 @@ pub(crate) fn get_inner_type(ty: &Type) -> &Type {
     ty
 }
 
+/// Find the core crate in the manifest of the crate being compiled.
+fn find_core_crate() -> Result<FoundCrate> {
+    crate_name("smithy4rs-core").map_err(|_| {
+        Error::new(
+            Span::call_site(),
+            "`smithy4rs-core` must be present in `Cargo.toml`",
+        )
+    })
+}
+
 /// Get references for the core crate.
-pub(crate) fn get_crate_info() -> (TokenStream, TokenStream) {
-    let found_crate =
-        crate_name("smithy4rs-core").expect("smithy4rs-core is present in `Cargo.toml`");
+pub(crate) fn get_crate_info() -> Result<(TokenStream, TokenStream)> {
+    let found_crate = find_core_crate()?;
     let extern_import = match &found_crate {
         FoundCrate::Itself => quote!(),
         FoundCrate::Name(name) => {
@@ -87,33 +103,31 @@ pub(crate) fn get_crate_info() -> (TokenStream, TokenStream) {
             quote!( #ident )
         }
     };
-    (extern_import, crate_ident)
+    Ok((extern_import, crate_ident))
 }
 
 /// Get name to use for direct imports (either `crate` or `smithy4rs_core`)
-pub(crate) fn get_crate_name() -> TokenStream {
-    let found_crate =
-        crate_name("smithy4rs-core").expect("smithy4rs-core is present in `Cargo.toml`");
-    match &found_crate {
+pub(crate) fn get_crate_name() -> Result<TokenStream> {
+    let found_crate = find_core_crate()?;
+    Ok(match &found_crate {
         FoundCrate::Itself => quote! { crate },
         FoundCrate::Name(name) => {
             let ident = Ident::new(name, Span::call_site());
             quote! { #ident }
         }
-    }
+    })
 }
 
 /// Get identifier to use outside `const` block for crate
-pub(crate) fn get_crate_ident() -> TokenStream {
-    let found_crate =
-        crate_name("smithy4rs-core").expect("smithy4rs-core is present in `Cargo.toml`");
-    match &found_crate {
+pub(crate) fn get_crate_ident() -> Result<TokenStream> {
+    let found_crate = find_core_crate()?;
+    Ok(match &found_crate {
         FoundCrate::Itself => quote!(crate),
         FoundCrate::Name(_) => {
             let ident = Ident::new("smithy4rs_core", Span::call_site());
             quote!( #ident )
         }
-    }
+    })
 }
 
 /// Checks if a type is a Smithy data model primitive.
@@ -132,8 +146,11 @@ pub(crate) fn is_primitive(field_ty: &Type) -> bool {
             || segment.ident == "BigInt"
             || segment.ident == "BigDecimal"
             || segment.ident == "Instant"
+            || segment.ident == "Timestamp"
             || segment.ident == "Document"
-            || segment.ident == "ByteBuffer";
+            || segment.ident == "ByteBuffer"
+            || segment.ident == "Blob"
+            || segment.ident == "StreamingBlob";
     }
     false
 }
@@ -170,33 +187,59 @@ fn expect_inner_mut(ty: &mut Type) -> &mut Type {
     }
 }
 
-pub(crate) fn get_ident(ty: &Type) -> &Ident {
-    if let Type::Path(type_path) = ty {
-        return &type_path.path.segments.last().unwrap().ident;
+pub(crate) fn get_ident(ty: &Type) -> Result<&Ident> {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+    {
+        return Ok(&segment.ident);
     }
-    panic!("Expected to get ident: {:?}", ty)
+    Err(Error::new_spanned(ty, "Expected a named type"))
 }
 
 /// Parse an `#[enum_value(...)` attribute
-pub(crate) fn parse_enum_value(attrs: &[Attribute]) -> Option<Lit> {
+pub(crate) fn parse_enum_value(attrs: &[Attribute]) -> Result<Option<Lit>> {
     let mut value = None;
     for attr in attrs {
         if attr.path().is_ident("enum_value") {
-            value = Some(
-                attr.parse_args::<Lit>()
-                    .expect("`enum_value` attribute should be an identifier"),
-            );
+            value = Some(attr.parse_args::<Lit>().map_err(|_| {
+                Error::new_spanned(attr, "`enum_value` attribute should be a literal")
+            })?);
         }
     }
-    value
+    Ok(value)
+}
+
+/// Parse the `#[enum_value(...)]` attribute of an enum variant, which must be
+/// a string or integer literal.
+pub(crate) fn parse_variant_value(variant: &Variant) -> Result<Lit> {
+    match parse_enum_value(&variant.attrs)? {
+        Some(value @ (Lit::Str(_) | Lit::Int(_))) => Ok(value),
+        Some(other) => Err(Error::new_spanned(
+            other,
+            "Enum values must be string or integer literals",
+        )),
+        None => Err(Error::new_spanned(
+            variant,
+            "Expected an `#[enum_value(...)]` attribute",
+        )),
+    }
+}
+
+/// Get the first variant of an enum, which is used to determine the
+/// type of enum values.
+pub(crate) fn first_variant(data_enum: &DataEnum) -> Result<&Variant> {
+    data_enum.variants.first().ok_or_else(|| {
+        Error::new_spanned(data_enum.enum_token, "Enum must have at least one variant")
+    })
 }
 
 pub(crate) fn get_builder_ident(shape_name: &Ident) -> Ident {
     Ident::new(&format!("{}Builder", shape_name), Span::call_site())
 }
 
-pub(crate) fn no_builder(field: &Field) -> bool {
-    for attr in &field.attrs {
+/// Determines if a field or union variant is marked with the `no_builder` attribute.
+pub(crate) fn no_builder(attrs: &[Attribute]) -> bool {
+    for attr in attrs {
         if attr.path().is_ident("no_builder") {
             return true;
         }
@@ -204,17 +247,28 @@ pub(crate) fn no_builder(field: &Field) -> bool {
     false
 }
 
+/// Determines if a field is marked with the `idempotency_token` attribute.
+pub(crate) fn is_idempotency_token(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("idempotency_token"))
+}
+
+/// Determines if the shape is marked as a modeled error with the `smithy_error` attribute.
+pub(crate) fn is_error(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("smithy_error"))
+}
+
 /// Determines if the shape should be treated as a regular enum or a union.
 ///
 /// Union's have member schemas for their variants.
-pub(crate) fn is_union(data_enum: &DataEnum) -> bool {
-    data_enum
-        .variants
-        .first()
-        .expect("Enum must have at least one variant")
+pub(crate) fn is_union(data_enum: &DataEnum) -> Result<bool> {
+    Ok(first_variant(data_enum)?
         .attrs
         .iter()
-        .any(|attr| attr.path().is_ident("smithy_schema"))
+        .any(|attr| attr.path().is_ident("smithy_schema")))
 }
 
 /// Parses out attribute data for the `smithy_schema` macro attribute from the struct and
@@ -247,13 +301,14 @@ impl ToTokens for IdentOrExpr {
     }
 }
 
-pub(crate) fn parse_wrapper_type(fields: &syn::FieldsUnnamed) -> &Type {
-    assert_eq!(
-        fields.unnamed.len(),
-        1,
-        "Wrapper shapes must have only one field"
-    );
-    &fields.unnamed.first().expect("At least one field.").ty
+pub(crate) fn parse_wrapper_type(fields: &syn::FieldsUnnamed) -> Result<&Type> {
+    match fields.unnamed.first() {
+        Some(field) if fields.unnamed.len() == 1 => Ok(&field.ty),
+        _ => Err(Error::new_spanned(
+            fields,
+            "Wrapper shapes must have only one field",
+        )),
+    }
 }
 
 #[cfg(test)]
@@ -267,9 +322,54 @@ mod tests {
         let optional_simple = syn::parse_str::<Type>("Option<A>").unwrap();
         let not_optional = syn::parse_str::<Type>("Other<B>").unwrap();
         let optional_nested = syn::parse_str::<Type>("Option<Vec<Vec<B>>>").unwrap();
-        assert!(is_optional(&optional_simple));
-        assert!(!is_optional(&not_optional));
-        assert!(is_optional(&optional_nested));
+        assert!(is_optional(&optional_simple).unwrap());
+        assert!(!is_optional(&not_optional).unwrap());
+        assert!(is_optional(&optional_nested).unwrap());
+    }
+
+    #[test]
+    fn parse_schema_errors() {
+        let missing: syn::DeriveInput = syn::parse_quote! { struct A; };
+        let err = parse_schema(&missing.attrs, &missing.ident).unwrap_err();
+        assert_eq!(err.to_string(), "Could not find `smithy_schema` attribute");
+
+        let malformed: syn::DeriveInput = syn::parse_quote! {
+            #[smithy_schema("SCHEMA")]
+            struct A;
+        };
+        let err = parse_schema(&malformed.attrs, &malformed.ident).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`smithy_schema` attribute should be an identifier"
+        );
+    }
+
+    #[test]
+    fn parse_variant_value_errors() {
+        let data: syn::ItemEnum = syn::parse_quote! {
+            enum A {
+                #[enum_value(1.5)]
+                B,
+                C,
+            }
+        };
+        let err = parse_variant_value(&data.variants[0]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Enum values must be string or integer literals"
+        );
+        let err = parse_variant_value(&data.variants[1]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected an `#[enum_value(...)]` attribute"
+        );
+    }
+
+    #[test]
+    fn parse_wrapper_type_errors() {
+        let fields: syn::FieldsUnnamed = syn::parse_quote! { (String, i32) };
+        let err = parse_wrapper_type(&fields).unwrap_err();
+        assert_eq!(err.to_string(), "Wrapper shapes must have only one field");
     }
 
     #[test]
@@ -356,6 +456,8 @@ mod tests {
         assert!(is_primitive(&primitive));
         assert!(!is_primitive(&not_primitive));
         assert!(is_primitive(&primitive_with_qualified_type));
+        let blob = syn::parse_str::<Type>("smithy4rs::schema::Blob").unwrap();
+        assert!(is_primitive(&blob));
     }
 
     #[test]
@@ -32,6 +32,30 @@ const _: () = {
         }
     }
 };
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::schema::EnumShape as _EnumShape;
+    use _smithy4rs::schema::EnumValue as _EnumValue;
+    #[automatically_derived]
+    impl _EnumShape for TestEnum {
+        #[inline]
+        fn variants() -> &'static [(&'static str, _EnumValue)] {
+            &[
+                ("A", _EnumValue::String("a")),
+                ("B", _EnumValue::String("b")),
+                ("C", _EnumValue::String("c")),
+            ]
+        }
+        fn from_enum_value(value: &_EnumValue) -> Option<Self> {
+            match value {
+                _EnumValue::String("a") => Some(TestEnum::A),
+                _EnumValue::String("b") => Some(TestEnum::B),
+                _EnumValue::String("c") => Some(TestEnum::C),
+                _ => None,
+            }
+        }
+    }
+};
 const _: () = {
     extern crate smithy4rs_core as _smithy4rs;
     use _smithy4rs::schema::Schema as _Schema;
@@ -59,6 +83,9 @@ const _: () = {
     use _smithy4rs::schema::Schema as _Schema;
     use _smithy4rs::serde::deserializers::Deserializer as _Deserializer;
     use _smithy4rs::serde::deserializers::DeserializeWithSchema as _DeserializeWithSchema;
+    use _smithy4rs::serde::deserializers::Error as _;
+    use _smithy4rs::serde::deserializers::RawEnumValue as _RawEnumValue;
+    use _smithy4rs::serde::deserializers::UnknownEnumPolicy as _UnknownEnumPolicy;
     #[automatically_derived]
     impl<'de> _DeserializeWithSchema<'de> for TestEnum {
         fn deserialize_with_schema<D>(
@@ -68,12 +95,22 @@ const _: () = {
         where
             D: _Deserializer<'de>,
         {
-            let val = deserializer.read_string(schema)?;
-            let result = match val.as_str() {
-                "a" => TestEnum::A,
-                "b" => TestEnum::B,
-                "c" => TestEnum::C,
-                _ => TestEnum::Unknown(val),
+            let policy = deserializer.unknown_enum_policy();
+            let result = match deserializer.read_enum(schema)? {
+                _RawEnumValue::String(val) => {
+                    match val.as_ref() {
+                        "a" => TestEnum::A,
+                        "b" => TestEnum::B,
+                        "c" => TestEnum::C,
+                        _ if policy == _UnknownEnumPolicy::Error => {
+                            return Err(D::Error::unknown_enum_value(schema, &val));
+                        }
+                        _ => TestEnum::Unknown(val.into_owned()),
+                    }
+                }
+                _RawEnumValue::Int(_) => {
+                    return Err(D::Error::custom("Unexpected enum value type"));
+                }
             };
             Ok(result)
         }
@@ -100,3 +137,143 @@ const _: () = {
         }
     }
 };
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::serde::fmt::DisplayWrapper as _DisplayWrapper;
+    #[automatically_derived]
+    impl std::fmt::Display for TestEnum {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::Display::fmt(&_DisplayWrapper::new(&SIMPLE_ENUM, self), f)
+        }
+    }
+};
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::schema::Document as _Document;
+    use _smithy4rs::schema::DocumentError as _DocumentError;
+    use _smithy4rs::schema::TryFromDocument as _TryFromDocument;
+    #[automatically_derived]
+    impl TryFrom<Box<dyn _Document>> for TestEnum {
+        type Error = _DocumentError;
+        fn try_from(document: Box<dyn _Document>) -> Result<Self, _DocumentError> {
+            <Self as _TryFromDocument>::try_from(document)
+        }
+    }
+};
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for TestEnum {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for TestEnum {
+    #[inline]
+    fn eq(&self, other: &TestEnum) -> bool {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        let __arg1_discr = ::core::intrinsics::discriminant_value(other);
+        __self_discr == __arg1_discr
+            && match (self, other) {
+                (TestEnum::Unknown(__self_0), TestEnum::Unknown(__arg1_0)) => {
+                    __self_0 == __arg1_0
+                }
+                _ => true,
+            }
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for TestEnum {
+    #[inline]
+    fn clone(&self) -> TestEnum {
+        match self {
+            TestEnum::A => TestEnum::A,
+            TestEnum::B => TestEnum::B,
+            TestEnum::C => TestEnum::C,
+            TestEnum::Unknown(__self_0) => {
+                TestEnum::Unknown(::core::clone::Clone::clone(__self_0))
+            }
+        }
+    }
+}
+///Known variants of [`TestEnum`].
+///
+///Matches on this enum are exhaustive, so variants added to the model are caught at compile time.
+pub enum KnownTestEnum {
+    A,
+    B,
+    C,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for KnownTestEnum {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::write_str(
+            f,
+            match self {
+                KnownTestEnum::A => "A",
+                KnownTestEnum::B => "B",
+                KnownTestEnum::C => "C",
+            },
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for KnownTestEnum {
+    #[inline]
+    fn clone(&self) -> KnownTestEnum {
+        *self
+    }
+}
+#[automatically_derived]
+impl ::core::marker::Copy for KnownTestEnum {}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for KnownTestEnum {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for KnownTestEnum {
+    #[inline]
+    fn eq(&self, other: &KnownTestEnum) -> bool {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        let __arg1_discr = ::core::intrinsics::discriminant_value(other);
+        __self_discr == __arg1_discr
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for KnownTestEnum {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_receiver_is_total_eq(&self) -> () {}
+}
+#[automatically_derived]
+impl ::core::hash::Hash for KnownTestEnum {
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) -> () {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        ::core::hash::Hash::hash(&__self_discr, state)
+    }
+}
+impl TestEnum {
+    ///Call `known` with the variant of this enum, or `unknown` with the raw value of an unknown variant.
+    ///
+    ///Use this rather than matching on [`TestEnum`] directly to handle every known variant of the enum exhaustively.
+    #[inline]
+    pub fn match_known<R>(
+        &self,
+        known: impl FnOnce(KnownTestEnum) -> R,
+        unknown: impl FnOnce(&str) -> R,
+    ) -> R {
+        match self {
+            TestEnum::A => known(KnownTestEnum::A),
+            TestEnum::B => known(KnownTestEnum::B),
+            TestEnum::C => known(KnownTestEnum::C),
+            TestEnum::Unknown(value) => unknown(value),
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::convert::From<KnownTestEnum> for TestEnum {
+    #[inline]
+    fn from(value: KnownTestEnum) -> Self {
+        match value {
+            KnownTestEnum::A => TestEnum::A,
+            KnownTestEnum::B => TestEnum::B,
+            KnownTestEnum::C => TestEnum::C,
+        }
+    }
+}
@@ -33,6 +33,30 @@ const _: () = {
         }
     }
 };
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::schema::EnumShape as _EnumShape;
+    use _smithy4rs::schema::EnumValue as _EnumValue;
+    #[automatically_derived]
+    impl _EnumShape for TestIntEnum {
+        #[inline]
+        fn variants() -> &'static [(&'static str, _EnumValue)] {
+            &[
+                ("A", _EnumValue::Int(1)),
+                ("B", _EnumValue::Int(2)),
+                ("C", _EnumValue::Int(3)),
+            ]
+        }
+        fn from_enum_value(value: &_EnumValue) -> Option<Self> {
+            match value {
+                _EnumValue::Int(1) => Some(TestIntEnum::A),
+                _EnumValue::Int(2) => Some(TestIntEnum::B),
+                _EnumValue::Int(3) => Some(TestIntEnum::C),
+                _ => None,
+            }
+        }
+    }
+};
 const _: () = {
     extern crate smithy4rs_core as _smithy4rs;
     use _smithy4rs::schema::Schema as _Schema;
@@ -60,6 +84,9 @@ const _: () = {
     use _smithy4rs::schema::Schema as _Schema;
     use _smithy4rs::serde::deserializers::Deserializer as _Deserializer;
     use _smithy4rs::serde::deserializers::DeserializeWithSchema as _DeserializeWithSchema;
+    use _smithy4rs::serde::deserializers::Error as _;
+    use _smithy4rs::serde::deserializers::RawEnumValue as _RawEnumValue;
+    use _smithy4rs::serde::deserializers::UnknownEnumPolicy as _UnknownEnumPolicy;
     #[automatically_derived]
     impl<'de> _DeserializeWithSchema<'de> for TestIntEnum {
         fn deserialize_with_schema<D>(
@@ -69,12 +96,22 @@ const _: () = {
         where
             D: _Deserializer<'de>,
         {
-            let val = deserializer.read_integer(schema)?;
-            let result = match val {
-                1 => TestIntEnum::A,
-                2 => TestIntEnum::B,
-                3 => TestIntEnum::C,
-                _ => TestIntEnum::Unknown(val),
+            let policy = deserializer.unknown_enum_policy();
+            let result = match deserializer.read_enum(schema)? {
+                _RawEnumValue::Int(val) => {
+                    match val {
+                        1 => TestIntEnum::A,
+                        2 => TestIntEnum::B,
+                        3 => TestIntEnum::C,
+                        _ if policy == _UnknownEnumPolicy::Error => {
+                            return Err(D::Error::unknown_enum_value(schema, &val));
+                        }
+                        _ => TestIntEnum::Unknown(val),
+                    }
+                }
+                _RawEnumValue::String(_) => {
+                    return Err(D::Error::custom("Unexpected enum value type"));
+                }
             };
             Ok(result)
         }
@@ -101,3 +138,143 @@ const _: () = {
         }
     }
 };
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::serde::fmt::DisplayWrapper as _DisplayWrapper;
+    #[automatically_derived]
+    impl std::fmt::Display for TestIntEnum {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::Display::fmt(&_DisplayWrapper::new(&SIMPLE_INT_ENUM, self), f)
+        }
+    }
+};
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::schema::Document as _Document;
+    use _smithy4rs::schema::DocumentError as _DocumentError;
+    use _smithy4rs::schema::TryFromDocument as _TryFromDocument;
+    #[automatically_derived]
+    impl TryFrom<Box<dyn _Document>> for TestIntEnum {
+        type Error = _DocumentError;
+        fn try_from(document: Box<dyn _Document>) -> Result<Self, _DocumentError> {
+            <Self as _TryFromDocument>::try_from(document)
+        }
+    }
+};
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for TestIntEnum {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for TestIntEnum {
+    #[inline]
+    fn eq(&self, other: &TestIntEnum) -> bool {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        let __arg1_discr = ::core::intrinsics::discriminant_value(other);
+        __self_discr == __arg1_discr
+            && match (self, other) {
+                (TestIntEnum::Unknown(__self_0), TestIntEnum::Unknown(__arg1_0)) => {
+                    __self_0 == __arg1_0
+                }
+                _ => true,
+            }
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for TestIntEnum {
+    #[inline]
+    fn clone(&self) -> TestIntEnum {
+        match self {
+            TestIntEnum::A => TestIntEnum::A,
+            TestIntEnum::B => TestIntEnum::B,
+            TestIntEnum::C => TestIntEnum::C,
+            TestIntEnum::Unknown(__self_0) => {
+                TestIntEnum::Unknown(::core::clone::Clone::clone(__self_0))
+            }
+        }
+    }
+}
+///Known variants of [`TestIntEnum`].
+///
+///Matches on this enum are exhaustive, so variants added to the model are caught at compile time.
+pub enum KnownTestIntEnum {
+    A,
+    B,
+    C,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for KnownTestIntEnum {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::write_str(
+            f,
+            match self {
+                KnownTestIntEnum::A => "A",
+                KnownTestIntEnum::B => "B",
+                KnownTestIntEnum::C => "C",
+            },
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for KnownTestIntEnum {
+    #[inline]
+    fn clone(&self) -> KnownTestIntEnum {
+        *self
+    }
+}
+#[automatically_derived]
+impl ::core::marker::Copy for KnownTestIntEnum {}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for KnownTestIntEnum {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for KnownTestIntEnum {
+    #[inline]
+    fn eq(&self, other: &KnownTestIntEnum) -> bool {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        let __arg1_discr = ::core::intrinsics::discriminant_value(other);
+        __self_discr == __arg1_discr
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for KnownTestIntEnum {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_receiver_is_total_eq(&self) -> () {}
+}
+#[automatically_derived]
+impl ::core::hash::Hash for KnownTestIntEnum {
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) -> () {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        ::core::hash::Hash::hash(&__self_discr, state)
+    }
+}
+impl TestIntEnum {
+    ///Call `known` with the variant of this enum, or `unknown` with the raw value of an unknown variant.
+    ///
+    ///Use this rather than matching on [`TestIntEnum`] directly to handle every known variant of the enum exhaustively.
+    #[inline]
+    pub fn match_known<R>(
+        &self,
+        known: impl FnOnce(KnownTestIntEnum) -> R,
+        unknown: impl FnOnce(i32) -> R,
+    ) -> R {
+        match self {
+            TestIntEnum::A => known(KnownTestIntEnum::A),
+            TestIntEnum::B => known(KnownTestIntEnum::B),
+            TestIntEnum::C => known(KnownTestIntEnum::C),
+            TestIntEnum::Unknown(value) => unknown(*value),
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::convert::From<KnownTestIntEnum> for TestIntEnum {
+    #[inline]
+    fn from(value: KnownTestIntEnum) -> Self {
+        match value {
+            KnownTestIntEnum::A => TestIntEnum::A,
+            KnownTestIntEnum::B => TestIntEnum::B,
+            KnownTestIntEnum::C => TestIntEnum::C,
+        }
+    }
+}
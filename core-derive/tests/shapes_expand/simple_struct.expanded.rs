@@ -113,22 +113,50 @@ impl SimpleStructBuilder {
     }
     ///Set `field_a`.
     pub fn field_a<T: Into<String>>(mut self, value: T) -> Self {
+        self.set_field_a(value);
+        self
+    }
+    ///Set `field_a` in place.
+    pub fn set_field_a<T: Into<String>>(&mut self, value: T) -> &mut Self {
         self.field_a = smithy4rs_core::serde::Required::Set(value.into());
         self
     }
     ///Set `field_b`.
     pub fn field_b<T: Into<i32>>(mut self, value: T) -> Self {
+        self.set_field_b(value);
+        self
+    }
+    ///Set `field_b` in place.
+    pub fn set_field_b<T: Into<i32>>(&mut self, value: T) -> &mut Self {
         self.field_b = smithy4rs_core::serde::Required::Set(value.into());
         self
     }
-    ///Set `field_c`.
-    pub fn field_c(mut self, value: Nested) -> Self {
-        self.field_c = Some(smithy4rs_core::serde::MaybeBuilt::Struct(value));
+    ///Set `field_c` from either a built shape or its builder.
+    pub fn field_c<T: Into<smithy4rs_core::serde::MaybeBuilt<Nested, NestedBuilder>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.set_field_c(value);
         self
     }
-    ///Set `field_c`.
-    pub fn field_c_builder(mut self, value: NestedBuilder) -> Self {
-        self.field_c = Some(smithy4rs_core::serde::MaybeBuilt::Builder(value));
+    ///Set `field_c` from either a built shape or its builder, in place.
+    pub fn set_field_c<
+        T: Into<smithy4rs_core::serde::MaybeBuilt<Nested, NestedBuilder>>,
+    >(&mut self, value: T) -> &mut Self {
+        self.field_c = Some(value.into());
+        self
+    }
+    ///Set `field_c` from a builder.
+    pub fn field_c_builder<T: Into<NestedBuilder>>(mut self, value: T) -> Self {
+        self.set_field_c_builder(value);
+        self
+    }
+    ///Set `field_c` from a builder, in place.
+    pub fn set_field_c_builder<T: Into<NestedBuilder>>(
+        &mut self,
+        value: T,
+    ) -> &mut Self {
+        self.field_c = Some(smithy4rs_core::serde::MaybeBuilt::Builder(value.into()));
         self
     }
     /// Build the shape, validating with the default validator.
@@ -144,6 +172,18 @@ impl SimpleStructBuilder {
     ) -> smithy4rs_core::serde::validation::Validated<SimpleStruct> {
         smithy4rs_core::serde::ShapeBuilder::build_with_validator(self, validator)
     }
+    /// Build the shape, filling unset required fields before validating with the default validator.
+    #[inline]
+    pub fn build_with_correction(
+        self,
+    ) -> smithy4rs_core::serde::validation::Validated<SimpleStruct> {
+        smithy4rs_core::serde::ShapeBuilder::build_with_correction(self)
+    }
+    /// Build the shape, filling unset required fields without any validation.
+    #[inline]
+    pub fn build_unchecked_corrected(self) -> SimpleStruct {
+        smithy4rs_core::serde::ShapeBuilder::build_unchecked_corrected(self)
+    }
 }
 const _: () = {
     extern crate smithy4rs_core as _smithy4rs;
@@ -166,7 +206,10 @@ const _: () = {
     use _smithy4rs::serde::correction::ErrorCorrectionDefault as _ErrorCorrectionDefault;
     use _smithy4rs::serde::ShapeBuilder as _ShapeBuilder;
     use _smithy4rs::serde::Buildable as _Buildable;
+    use _smithy4rs::serde::HasBuilder as _HasBuilder;
     use _smithy4rs::serde::deserializers::StructReader as _StructReader;
+    use _smithy4rs::serde::deserializers::MemberPresence as _MemberPresence;
+    use _smithy4rs::serde::deserializers::Error as _;
     #[automatically_derived]
     impl<'de> _DeserializeWithSchema<'de> for SimpleStructBuilder {
         fn deserialize_with_schema<D>(
@@ -178,7 +221,11 @@ const _: () = {
         {
             let mut builder = SimpleStructBuilder::new();
             let mut reader = deserializer.read_struct(schema)?;
+            let mut present = _MemberPresence::new();
             while let Some(member_schema) = reader.read_member(schema)? {
+                if !present.insert(member_schema) {
+                    return Err(D::Error::duplicate_member(member_schema));
+                }
                 if member_schema == *_SIMPLE_SCHEMA_MEMBER_A {
                     let value: String = reader.read_value(member_schema)?;
                     builder = builder.field_a(value);
@@ -224,6 +271,26 @@ const _: () = {
             SimpleStructBuilder::new().correct()
         }
     }
+    #[automatically_derived]
+    impl From<SimpleStructBuilder> for _smithy4rs::serde::MaybeBuilt<SimpleStruct, SimpleStructBuilder> {
+        fn from(value: SimpleStructBuilder) -> Self {
+            _smithy4rs::serde::MaybeBuilt::Builder(value)
+        }
+    }
+    #[automatically_derived]
+    impl From<SimpleStruct>
+    for _smithy4rs::serde::MaybeBuilt<Box<SimpleStruct>, Box<SimpleStructBuilder>> {
+        fn from(value: SimpleStruct) -> Self {
+            _smithy4rs::serde::MaybeBuilt::Struct(Box::new(value))
+        }
+    }
+    #[automatically_derived]
+    impl From<SimpleStructBuilder>
+    for _smithy4rs::serde::MaybeBuilt<Box<SimpleStruct>, Box<SimpleStructBuilder>> {
+        fn from(value: SimpleStructBuilder) -> Self {
+            _smithy4rs::serde::MaybeBuilt::Builder(Box::new(value))
+        }
+    }
     use _smithy4rs::serde::serializers::Serializer as _Serializer;
     use _smithy4rs::serde::serializers::SerializeWithSchema as _SerializeWithSchema;
     use _smithy4rs::serde::serializers::StructWriter as _StructWriter;
@@ -246,6 +313,9 @@ const _: () = {
         }
     }
     impl<'de> _Buildable<'de, SimpleStructBuilder> for SimpleStruct {}
+    impl _HasBuilder for SimpleStruct {
+        type Builder = SimpleStructBuilder;
+    }
 };
 const _: () = {
     extern crate smithy4rs_core as _smithy4rs;
@@ -257,6 +327,37 @@ const _: () = {
         }
     }
 };
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::serde::fmt::DisplayWrapper as _DisplayWrapper;
+    #[automatically_derived]
+    impl std::fmt::Display for SimpleStruct {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::Display::fmt(&_DisplayWrapper::new(&SIMPLE_SCHEMA, self), f)
+        }
+    }
+};
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::schema::Document as _Document;
+    use _smithy4rs::schema::DocumentError as _DocumentError;
+    use _smithy4rs::schema::TryFromDocument as _TryFromDocument;
+    #[automatically_derived]
+    impl _TryFromDocument for SimpleStruct {
+        fn try_from(document: Box<dyn _Document>) -> Result<Self, _DocumentError> {
+            use _smithy4rs::serde::ShapeBuilder as _ShapeBuilder;
+            let builder = <SimpleStructBuilder as _TryFromDocument>::try_from(document)?;
+            Ok(_ShapeBuilder::build(builder)?)
+        }
+    }
+    #[automatically_derived]
+    impl TryFrom<Box<dyn _Document>> for SimpleStruct {
+        type Error = _DocumentError;
+        fn try_from(document: Box<dyn _Document>) -> Result<Self, _DocumentError> {
+            <Self as _TryFromDocument>::try_from(document)
+        }
+    }
+};
 #[automatically_derived]
 impl ::core::marker::StructuralPartialEq for SimpleStruct {}
 #[automatically_derived]
@@ -359,6 +460,11 @@ impl NestedBuilder {
     }
     ///Set `field_a`.
     pub fn field_a<T: Into<String>>(mut self, value: T) -> Self {
+        self.set_field_a(value);
+        self
+    }
+    ///Set `field_a` in place.
+    pub fn set_field_a<T: Into<String>>(&mut self, value: T) -> &mut Self {
         self.field_a = smithy4rs_core::serde::Required::Set(value.into());
         self
     }
@@ -375,6 +481,18 @@ impl NestedBuilder {
     ) -> smithy4rs_core::serde::validation::Validated<Nested> {
         smithy4rs_core::serde::ShapeBuilder::build_with_validator(self, validator)
     }
+    /// Build the shape, filling unset required fields before validating with the default validator.
+    #[inline]
+    pub fn build_with_correction(
+        self,
+    ) -> smithy4rs_core::serde::validation::Validated<Nested> {
+        smithy4rs_core::serde::ShapeBuilder::build_with_correction(self)
+    }
+    /// Build the shape, filling unset required fields without any validation.
+    #[inline]
+    pub fn build_unchecked_corrected(self) -> Nested {
+        smithy4rs_core::serde::ShapeBuilder::build_unchecked_corrected(self)
+    }
 }
 const _: () = {
     extern crate smithy4rs_core as _smithy4rs;
@@ -397,7 +515,10 @@ const _: () = {
     use _smithy4rs::serde::correction::ErrorCorrectionDefault as _ErrorCorrectionDefault;
     use _smithy4rs::serde::ShapeBuilder as _ShapeBuilder;
     use _smithy4rs::serde::Buildable as _Buildable;
+    use _smithy4rs::serde::HasBuilder as _HasBuilder;
     use _smithy4rs::serde::deserializers::StructReader as _StructReader;
+    use _smithy4rs::serde::deserializers::MemberPresence as _MemberPresence;
+    use _smithy4rs::serde::deserializers::Error as _;
     #[automatically_derived]
     impl<'de> _DeserializeWithSchema<'de> for NestedBuilder {
         fn deserialize_with_schema<D>(
@@ -409,7 +530,11 @@ const _: () = {
         {
             let mut builder = NestedBuilder::new();
             let mut reader = deserializer.read_struct(schema)?;
+            let mut present = _MemberPresence::new();
             while let Some(member_schema) = reader.read_member(schema)? {
+                if !present.insert(member_schema) {
+                    return Err(D::Error::duplicate_member(member_schema));
+                }
                 if member_schema == *_NESTED_SCHEMA_MEMBER_D {
                     let value: String = reader.read_value(member_schema)?;
                     builder = builder.field_a(value);
@@ -441,6 +566,26 @@ const _: () = {
             NestedBuilder::new().correct()
         }
     }
+    #[automatically_derived]
+    impl From<NestedBuilder> for _smithy4rs::serde::MaybeBuilt<Nested, NestedBuilder> {
+        fn from(value: NestedBuilder) -> Self {
+            _smithy4rs::serde::MaybeBuilt::Builder(value)
+        }
+    }
+    #[automatically_derived]
+    impl From<Nested>
+    for _smithy4rs::serde::MaybeBuilt<Box<Nested>, Box<NestedBuilder>> {
+        fn from(value: Nested) -> Self {
+            _smithy4rs::serde::MaybeBuilt::Struct(Box::new(value))
+        }
+    }
+    #[automatically_derived]
+    impl From<NestedBuilder>
+    for _smithy4rs::serde::MaybeBuilt<Box<Nested>, Box<NestedBuilder>> {
+        fn from(value: NestedBuilder) -> Self {
+            _smithy4rs::serde::MaybeBuilt::Builder(Box::new(value))
+        }
+    }
     use _smithy4rs::serde::serializers::Serializer as _Serializer;
     use _smithy4rs::serde::serializers::SerializeWithSchema as _SerializeWithSchema;
     use _smithy4rs::serde::serializers::StructWriter as _StructWriter;
@@ -457,6 +602,9 @@ const _: () = {
         }
     }
     impl<'de> _Buildable<'de, NestedBuilder> for Nested {}
+    impl _HasBuilder for Nested {
+        type Builder = NestedBuilder;
+    }
 };
 const _: () = {
     extern crate smithy4rs_core as _smithy4rs;
@@ -468,6 +616,27 @@ const _: () = {
         }
     }
 };
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::schema::Document as _Document;
+    use _smithy4rs::schema::DocumentError as _DocumentError;
+    use _smithy4rs::schema::TryFromDocument as _TryFromDocument;
+    #[automatically_derived]
+    impl _TryFromDocument for Nested {
+        fn try_from(document: Box<dyn _Document>) -> Result<Self, _DocumentError> {
+            use _smithy4rs::serde::ShapeBuilder as _ShapeBuilder;
+            let builder = <NestedBuilder as _TryFromDocument>::try_from(document)?;
+            Ok(_ShapeBuilder::build(builder)?)
+        }
+    }
+    #[automatically_derived]
+    impl TryFrom<Box<dyn _Document>> for Nested {
+        type Error = _DocumentError;
+        fn try_from(document: Box<dyn _Document>) -> Result<Self, _DocumentError> {
+            <Self as _TryFromDocument>::try_from(document)
+        }
+    }
+};
 #[automatically_derived]
 impl ::core::marker::StructuralPartialEq for Nested {}
 #[automatically_derived]
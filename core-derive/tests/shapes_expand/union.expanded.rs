@@ -34,7 +34,7 @@ pub enum TestEnum {
     C,
     #[automatically_derived]
     #[doc(hidden)]
-    Unknown(String),
+    Unknown(String, Box<dyn smithy4rs_core::schema::Document>),
 }
 const _: () = {
     extern crate smithy4rs_core as _smithy4rs;
@@ -67,7 +67,7 @@ const _: () = {
                 TestEnum::A(val) => ser.write_member_named("a", &_UNION_MEMBER_A, val)?,
                 TestEnum::B(val) => ser.write_member_named("b", &_UNION_MEMBER_B, val)?,
                 TestEnum::C => ser.write_member_named("c", &_UNION_MEMBER_C, &_Unit)?,
-                TestEnum::Unknown(unknown) => ser.write_unknown(schema, unknown)?,
+                TestEnum::Unknown(name, value) => ser.write_unknown_member(name, value)?,
             }
             ser.end(schema)
         }
@@ -78,8 +78,11 @@ const _: () = {
     use _smithy4rs::schema::Schema as _Schema;
     use _smithy4rs::serde::deserializers::Deserializer as _Deserializer;
     use _smithy4rs::serde::deserializers::DeserializeWithSchema as _DeserializeWithSchema;
+    use _smithy4rs::prelude::DOCUMENT as _DOCUMENT;
     use _smithy4rs::serde::deserializers::Error as _;
+    use _smithy4rs::serde::deserializers::MemberKey as _MemberKey;
     use _smithy4rs::serde::deserializers::StructReader as _StructReader;
+    use _smithy4rs::serde::deserializers::set_union_value as _set_union_value;
     use _smithy4rs::schema::Unit as _Unit;
     #[automatically_derived]
     impl<'de> _DeserializeWithSchema<'de> for TestEnum {
@@ -92,29 +95,52 @@ const _: () = {
         {
             let mut reader = deserializer.read_struct(schema)?;
             let mut result: Option<TestEnum> = None;
-            while let Some(member_schema) = reader.read_member(schema)? {
-                if result.is_some() {
-                    return Err(D::Error::custom("Attempted to set union value twice"));
-                }
+            while let Some(member) = reader.read_member_or_unknown(schema)? {
+                let member_schema = match member {
+                    _MemberKey::Known(member_schema) => member_schema,
+                    _MemberKey::Unknown(name) => {
+                        let value = reader
+                            .read_value::<Option<_>>(&_DOCUMENT)?
+                            .map(|value| TestEnum::Unknown(name, value));
+                        _set_union_value(&mut result, value)?;
+                        continue;
+                    }
+                };
                 if member_schema == *_UNION_MEMBER_A {
-                    let value: String = reader.read_value(member_schema)?;
-                    result = Some(TestEnum::A(value));
+                    let value = reader
+                        .read_value::<Option<String>>(member_schema)?
+                        .map(TestEnum::A);
+                    _set_union_value(&mut result, value)?;
                     continue;
                 }
                 if member_schema == *_UNION_MEMBER_B {
-                    let value: i32 = reader.read_value(member_schema)?;
-                    result = Some(TestEnum::B(value));
+                    let value = reader
+                        .read_value::<Option<i32>>(member_schema)?
+                        .map(TestEnum::B);
+                    _set_union_value(&mut result, value)?;
                     continue;
                 }
                 if member_schema == *_UNION_MEMBER_C {
-                    let _: _Unit = reader.read_value(member_schema)?;
-                    result = Some(TestEnum::C);
+                    let value = reader
+                        .read_value::<Option<_Unit>>(member_schema)?
+                        .map(|_| TestEnum::C);
+                    _set_union_value(&mut result, value)?;
                     continue;
                 }
-                result = Some(TestEnum::Unknown("unknown".to_string()));
-                continue;
+                let name = member_schema
+                    .as_member()
+                    .map_or_else(String::new, |member| member.name().to_string());
+                let value = reader
+                    .read_value::<Option<_>>(&_DOCUMENT)?
+                    .map(|value| TestEnum::Unknown(name, value));
+                _set_union_value(&mut result, value)?;
             }
-            result.ok_or(D::Error::custom("Failed to deserialize union"))
+            result
+                .ok_or(
+                    D::Error::custom(
+                        "Expected exactly one non-null union member to be set",
+                    ),
+                )
         }
     }
 };
@@ -125,7 +151,7 @@ const _: () = {
         #[inline]
         #[automatically_derived]
         fn default() -> Self {
-            TestEnum::Unknown("".to_string())
+            TestEnum::Unknown(String::new(), _smithy4rs::schema::NULL.clone())
         }
     }
 };
@@ -139,3 +165,67 @@ const _: () = {
         }
     }
 };
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::serde::fmt::DisplayWrapper as _DisplayWrapper;
+    #[automatically_derived]
+    impl std::fmt::Display for TestEnum {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::Display::fmt(&_DisplayWrapper::new(&UNION, self), f)
+        }
+    }
+};
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::schema::Document as _Document;
+    use _smithy4rs::schema::DocumentError as _DocumentError;
+    use _smithy4rs::schema::TryFromDocument as _TryFromDocument;
+    #[automatically_derived]
+    impl TryFrom<Box<dyn _Document>> for TestEnum {
+        type Error = _DocumentError;
+        fn try_from(document: Box<dyn _Document>) -> Result<Self, _DocumentError> {
+            <Self as _TryFromDocument>::try_from(document)
+        }
+    }
+};
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for TestEnum {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for TestEnum {
+    #[inline]
+    fn eq(&self, other: &TestEnum) -> bool {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        let __arg1_discr = ::core::intrinsics::discriminant_value(other);
+        __self_discr == __arg1_discr
+            && match (self, other) {
+                (TestEnum::A(__self_0), TestEnum::A(__arg1_0)) => {
+                    __self_0 == __arg1_0
+                }
+                (TestEnum::B(__self_0), TestEnum::B(__arg1_0)) => {
+                    __self_0 == __arg1_0
+                }
+                (TestEnum::Unknown(__self_0, __self_1), TestEnum::Unknown(__arg1_0, __arg1_1)) => {
+                    __self_0 == __arg1_0 && __self_1 == __arg1_1
+                }
+                _ => true,
+            }
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for TestEnum {
+    #[inline]
+    fn clone(&self) -> TestEnum {
+        match self {
+            TestEnum::A(__self_0) => {
+                TestEnum::A(::core::clone::Clone::clone(__self_0))
+            }
+            TestEnum::B(__self_0) => {
+                TestEnum::B(::core::clone::Clone::clone(__self_0))
+            }
+            TestEnum::C => TestEnum::C,
+            TestEnum::Unknown(__self_0, __self_1) => {
+                TestEnum::Unknown(::core::clone::Clone::clone(__self_0), ::core::clone::Clone::clone(__self_1))
+            }
+        }
+    }
+}
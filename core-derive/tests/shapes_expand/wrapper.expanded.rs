@@ -66,6 +66,29 @@ const _: () = {
         }
     }
 };
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::serde::fmt::DisplayWrapper as _DisplayWrapper;
+    #[automatically_derived]
+    impl std::fmt::Display for SimpleTrait {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::Display::fmt(&_DisplayWrapper::new(&STRING_TRAIT, self), f)
+        }
+    }
+};
+const _: () = {
+    extern crate smithy4rs_core as _smithy4rs;
+    use _smithy4rs::schema::Document as _Document;
+    use _smithy4rs::schema::DocumentError as _DocumentError;
+    use _smithy4rs::schema::TryFromDocument as _TryFromDocument;
+    #[automatically_derived]
+    impl TryFrom<Box<dyn _Document>> for SimpleTrait {
+        type Error = _DocumentError;
+        fn try_from(document: Box<dyn _Document>) -> Result<Self, _DocumentError> {
+            <Self as _TryFromDocument>::try_from(document)
+        }
+    }
+};
 impl SimpleTrait {
     ///Create a new [`SimpleTrait`] instance
     #[automatically_derived]
@@ -100,6 +123,7 @@ const _: () = {
     use _smithy4rs::schema::ShapeId as _ShapeId;
     use _smithy4rs::LazyLock as _LazyLock;
     use _smithy4rs::schema::StaticSchemaShape as _StaticSchemaShape;
+    use _smithy4rs::schema::Document as _Document;
     impl _StaticTraitId for SimpleTrait {
         #[inline]
         #[automatically_derived]
@@ -109,6 +133,11 @@ const _: () = {
             });
             *ID
         }
+        #[inline]
+        #[automatically_derived]
+        fn to_document(&self) -> Option<Box<dyn _Document>> {
+            Some(::core::clone::Clone::clone(self).into())
+        }
     }
 };
 impl PartialEq for SimpleTrait {
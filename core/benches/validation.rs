@@ -110,7 +110,7 @@ pub struct StructWithList {
 // ==== Benchmarks ====
 pub fn validate_builder(c: &mut Criterion) {
     let builder = ValidatedStructBuilder::new()
-        .string("string".to_string())
+        .string("string")
         .required_int(1);
     c.bench_function("Validate Shape Builder", |b| {
         b.iter(|| {
@@ -1,19 +1,32 @@
 #![allow(dead_code)]
 
 use std::{
+    borrow::Cow,
     error::Error as StdError,
     fmt,
     fmt::{Debug, Display, Formatter},
     marker::PhantomData,
 };
 
-use serde::de::{DeserializeSeed, Error as SerdeDeError, MapAccess, SeqAccess, Visitor};
+use base64::{Engine, prelude::BASE64_STANDARD};
+use serde::{
+    Deserialize,
+    de::{
+        DeserializeSeed, Error as SerdeDeError, MapAccess, SeqAccess, Visitor, value as de_value,
+    },
+};
 
 use crate::{
-    schema::{Schema, ShapeType},
-    serde::deserializers::{
-        DeserializeWithSchema, Deserializer, Error as DeserError, ListReader, MapReader,
-        StructReader,
+    BigDecimal, BigInt, IndexMap, Instant,
+    features::adapters::timestamp_format,
+    prelude::TimestampFormatTrait,
+    schema::{Blob, Document, NULL, Schema, ShapeType, Timestamp},
+    serde::{
+        CodecError,
+        deserializers::{
+            DeserializeWithSchema, Deserializer, Error as DeserError, ListReader, MapReader,
+            StructReader,
+        },
     },
 };
 
@@ -45,6 +58,12 @@ impl<E: SerdeDeError> From<E> for DeserdeErrorWrapper<E> {
     }
 }
 
+impl<E: SerdeDeError> From<DeserdeErrorWrapper<E>> for CodecError {
+    fn from(value: DeserdeErrorWrapper<E>) -> Self {
+        CodecError::Custom(value.to_string())
+    }
+}
+
 //========================================================================
 // Reader Types
 //========================================================================
@@ -88,11 +107,11 @@ impl<'de, M: MapAccess<'de>> StructReader<'de> for SerdeStructReader<'de, M> {
         loop {
             match self
                 .map_access
-                .next_key::<&str>()
+                .next_key_seed(KeySeed)
                 .map_err(DeserdeErrorWrapper)?
             {
                 Some(key) => {
-                    if let Some(member_schema) = schema.get_member(key) {
+                    if let Some(member_schema) = schema.get_member_or_alias(&key) {
                         return Ok(Some(member_schema));
                     }
                     // Unknown key — skip the value internally
@@ -139,8 +158,8 @@ impl<'de, M: MapAccess<'de>> MapReader<'de> for SerdeMapReader<'de, M> {
 
     fn read_key(&mut self) -> Result<Option<String>, Self::Error> {
         self.map_access
-            .next_key::<&str>()
-            .map(|opt| opt.map(String::from))
+            .next_key_seed(KeySeed)
+            .map(|opt| opt.map(Cow::into_owned))
             .map_err(DeserdeErrorWrapper)
     }
 
@@ -303,6 +322,24 @@ where
     type Value = T;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // Explicit `null` values are read with a `NullDeserializer` so that
+        // optional values (i.e. union or struct members) are read as `None`.
+        deserializer.deserialize_option(NullableVisitor {
+            schema: self.schema,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'a, 'de, T> SchemaSeed<'a, T>
+where
+    T: DeserializeWithSchema<'de>,
+{
+    /// Deserialize a non-null value
+    fn deserialize_value<D>(self, deserializer: D) -> Result<T, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -336,7 +373,10 @@ where
             | ShapeType::Double
             | ShapeType::BigInteger
             | ShapeType::BigDecimal
-            | ShapeType::String => {
+            | ShapeType::String
+            | ShapeType::Blob
+            | ShapeType::Timestamp
+            | ShapeType::Document => {
                 T::deserialize_with_schema(self.schema, PrimitiveWrapper::new(deserializer))
                     .map_err(|e| e.0)
             }
@@ -352,6 +392,97 @@ where
 // Visitors
 //========================================================================
 
+/// Visitor for values that may be `null`
+///
+/// Deserializers that do not distinguish optional values (i.e. serde's primitive
+/// `IntoDeserializer` types) forward `deserialize_option` to `deserialize_any`, so
+/// primitive values are visited directly and then deserialized based on the schema.
+struct NullableVisitor<'a, T> {
+    schema: &'a Schema,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, 'de, T: DeserializeWithSchema<'de>> Visitor<'de> for NullableVisitor<'a, T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "a value or null")
+    }
+
+    fn visit_none<E: SerdeDeError>(self) -> Result<T, E> {
+        T::deserialize_with_schema(self.schema, NullDeserializer(PhantomData)).map_err(|e| e.0)
+    }
+
+    fn visit_unit<E: SerdeDeError>(self) -> Result<T, E> {
+        self.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<T, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        SchemaSeed::<T>::new(self.schema).deserialize_value(deserializer)
+    }
+
+    fn visit_bool<E: SerdeDeError>(self, v: bool) -> Result<T, E> {
+        self.visit_some(de_value::BoolDeserializer::new(v))
+    }
+
+    fn visit_i64<E: SerdeDeError>(self, v: i64) -> Result<T, E> {
+        self.visit_some(de_value::I64Deserializer::new(v))
+    }
+
+    fn visit_i128<E: SerdeDeError>(self, v: i128) -> Result<T, E> {
+        self.visit_some(de_value::I128Deserializer::new(v))
+    }
+
+    fn visit_u64<E: SerdeDeError>(self, v: u64) -> Result<T, E> {
+        self.visit_some(de_value::U64Deserializer::new(v))
+    }
+
+    fn visit_u128<E: SerdeDeError>(self, v: u128) -> Result<T, E> {
+        self.visit_some(de_value::U128Deserializer::new(v))
+    }
+
+    fn visit_f64<E: SerdeDeError>(self, v: f64) -> Result<T, E> {
+        self.visit_some(de_value::F64Deserializer::new(v))
+    }
+
+    fn visit_str<E: SerdeDeError>(self, v: &str) -> Result<T, E> {
+        self.visit_some(de_value::StrDeserializer::new(v))
+    }
+
+    fn visit_borrowed_str<E: SerdeDeError>(self, v: &'de str) -> Result<T, E> {
+        self.visit_some(de_value::BorrowedStrDeserializer::new(v))
+    }
+
+    fn visit_string<E: SerdeDeError>(self, v: String) -> Result<T, E> {
+        self.visit_some(de_value::StringDeserializer::new(v))
+    }
+
+    fn visit_bytes<E: SerdeDeError>(self, v: &[u8]) -> Result<T, E> {
+        self.visit_some(de_value::BytesDeserializer::new(v))
+    }
+
+    fn visit_borrowed_bytes<E: SerdeDeError>(self, v: &'de [u8]) -> Result<T, E> {
+        self.visit_some(de_value::BorrowedBytesDeserializer::new(v))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<T, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.visit_some(de_value::SeqAccessDeserializer::new(seq))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<T, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.visit_some(de_value::MapAccessDeserializer::new(map))
+    }
+}
+
 /// Visitor for lists - receives a [`SeqAccess`] and creates adapter
 struct ListVisitor<'a, T> {
     schema: &'a Schema,
@@ -398,6 +529,53 @@ impl<'a, 'de, T: DeserializeWithSchema<'de>> Visitor<'de> for MapVisitor<'a, T>
     }
 }
 
+/// Visitor for strings that borrows from the input data where possible.
+struct StrVisitor;
+
+impl<'de> Visitor<'de> for StrVisitor {
+    type Value = Cow<'de, str>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a string value")
+    }
+
+    fn visit_str<E: SerdeDeError>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(v.to_string()))
+    }
+
+    fn visit_borrowed_str<E: SerdeDeError>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(Cow::Borrowed(v))
+    }
+}
+
+//========================================================================
+// Null Deserializer
+//========================================================================
+
+/// Deserializer for an explicit `null` value.
+///
+/// Only `Option` values and documents can be read from this deserializer.
+struct NullDeserializer<'de, E>(PhantomData<(&'de (), E)>);
+
+impl<'de, E: SerdeDeError> Deserializer<'de> for NullDeserializer<'de, E> {
+    type Error = DeserdeErrorWrapper<E>;
+    type StructReader = SerdeStructReader<'de, NeverMapAccess<E>>;
+    type ListReader = SerdeListReader<'de, NeverSeqAccess<E>>;
+    type MapReader = SerdeMapReader<'de, NeverMapAccess<E>>;
+
+    fn read_document(self, _schema: &Schema) -> Result<Box<dyn Document>, Self::Error> {
+        Ok(NULL.clone())
+    }
+
+    fn is_null(&mut self) -> bool {
+        true
+    }
+
+    fn read_null(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
 //========================================================================
 // Enum Deserializer
 //========================================================================
@@ -506,6 +684,10 @@ impl<'de, D: serde::Deserializer<'de>> Deserializer<'de> for EnumWrapper<'de, D>
             .take_deserializer()?
             .deserialize_string(StringVisitor)?)
     }
+
+    fn read_str(mut self, _schema: &Schema) -> Result<Cow<'de, str>, Self::Error> {
+        Ok(self.take_deserializer()?.deserialize_str(StrVisitor)?)
+    }
 }
 
 //========================================================================
@@ -705,11 +887,205 @@ impl<'de, D: serde::Deserializer<'de>> Deserializer<'de> for PrimitiveWrapper<'d
             .deserialize_string(StringVisitor)
             .map_err(DeserdeErrorWrapper)
     }
+
+    fn read_str(mut self, _schema: &Schema) -> Result<Cow<'de, str>, Self::Error> {
+        self.take_deserializer()?
+            .deserialize_str(StrVisitor)
+            .map_err(DeserdeErrorWrapper)
+    }
+
+    fn read_big_integer(mut self, _schema: &Schema) -> Result<BigInt, Self::Error> {
+        BigInt::deserialize(self.take_deserializer()?).map_err(DeserdeErrorWrapper)
+    }
+
+    fn read_big_decimal(mut self, _schema: &Schema) -> Result<BigDecimal, Self::Error> {
+        BigDecimal::deserialize(self.take_deserializer()?).map_err(DeserdeErrorWrapper)
+    }
+
+    fn read_blob(mut self, _schema: &Schema) -> Result<Blob, Self::Error> {
+        struct BlobVisitor;
+        impl<'de> Visitor<'de> for BlobVisitor {
+            type Value = Blob;
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("bytes or a base64 encoded string")
+            }
+            fn visit_str<E: SerdeDeError>(self, v: &str) -> Result<Self::Value, E> {
+                BASE64_STANDARD
+                    .decode(v)
+                    .map(Blob::from)
+                    .map_err(SerdeDeError::custom)
+            }
+            fn visit_bytes<E: SerdeDeError>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(Blob::copy_from_slice(v))
+            }
+            fn visit_byte_buf<E: SerdeDeError>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+                Ok(Blob::from(v))
+            }
+        }
+        let deserializer = self.take_deserializer()?;
+        // Human-readable formats have no native bytes type, so blobs are base64 encoded
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BlobVisitor)
+        } else {
+            deserializer.deserialize_bytes(BlobVisitor)
+        }
+        .map_err(DeserdeErrorWrapper)
+    }
+
+    fn read_timestamp(mut self, schema: &Schema) -> Result<Instant, Self::Error> {
+        struct TimestampVisitor<'a>(&'a TimestampFormatTrait);
+        impl<'de> Visitor<'de> for TimestampVisitor<'_> {
+            type Value = Timestamp;
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("epoch seconds or a formatted timestamp string")
+            }
+            fn visit_i64<E: SerdeDeError>(self, v: i64) -> Result<Self::Value, E> {
+                let millis = v
+                    .checked_mul(1000)
+                    .ok_or_else(|| E::custom("timestamp out of range"))?;
+                Timestamp::from_epoch_millis(millis).map_err(SerdeDeError::custom)
+            }
+            fn visit_u64<E: SerdeDeError>(self, v: u64) -> Result<Self::Value, E> {
+                self.visit_i64(v.try_into().map_err(SerdeDeError::custom)?)
+            }
+            fn visit_f64<E: SerdeDeError>(self, v: f64) -> Result<Self::Value, E> {
+                Timestamp::from_epoch_seconds(v).map_err(SerdeDeError::custom)
+            }
+            fn visit_str<E: SerdeDeError>(self, v: &str) -> Result<Self::Value, E> {
+                Timestamp::parse(v, self.0).map_err(SerdeDeError::custom)
+            }
+        }
+        let visitor = TimestampVisitor(timestamp_format(schema));
+        self.take_deserializer()?
+            .deserialize_any(visitor)
+            .map(Instant::from)
+            .map_err(DeserdeErrorWrapper)
+    }
+
+    fn read_document(mut self, _schema: &Schema) -> Result<Box<dyn Document>, Self::Error> {
+        self.take_deserializer()?
+            .deserialize_any(DocumentVisitor)
+            .map_err(DeserdeErrorWrapper)
+    }
+}
+
+/// Visitor for untyped documents.
+///
+/// Builds a document from whatever value the serde data format contains.
+struct DocumentVisitor;
+
+impl<'de> Visitor<'de> for DocumentVisitor {
+    type Value = Box<dyn Document>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("a document value")
+    }
+
+    fn visit_bool<E: SerdeDeError>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(v.into())
+    }
+
+    fn visit_i64<E: SerdeDeError>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(v.into())
+    }
+
+    fn visit_i128<E: SerdeDeError>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(BigInt::from(v).into())
+    }
+
+    fn visit_u64<E: SerdeDeError>(self, v: u64) -> Result<Self::Value, E> {
+        // Only values that do not fit in a `long` are widened to a big integer
+        Ok(i64::try_from(v).map_or_else(|_| BigInt::from(v).into(), Into::into))
+    }
+
+    fn visit_u128<E: SerdeDeError>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(BigInt::from(v).into())
+    }
+
+    fn visit_f64<E: SerdeDeError>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(v.into())
+    }
+
+    fn visit_str<E: SerdeDeError>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(v.into())
+    }
+
+    fn visit_bytes<E: SerdeDeError>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Blob::copy_from_slice(v).into())
+    }
+
+    fn visit_none<E: SerdeDeError>(self) -> Result<Self::Value, E> {
+        Ok(NULL.clone())
+    }
+
+    fn visit_unit<E: SerdeDeError>(self) -> Result<Self::Value, E> {
+        Ok(NULL.clone())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut elements: Vec<Box<dyn Document>> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(element) = seq.next_element_seed(DocumentSeed)? {
+            elements.push(element);
+        }
+        Ok(elements.into())
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries: IndexMap<String, Box<dyn Document>> =
+            IndexMap::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(key) = map.next_key_seed(KeySeed)? {
+            let value = map.next_value_seed(DocumentSeed)?;
+            entries.insert(key.into_owned(), value);
+        }
+        Ok(entries.into())
+    }
+}
+
+/// Seed for document map keys.
+struct KeySeed;
+
+impl<'de> DeserializeSeed<'de> for KeySeed {
+    type Value = Cow<'de, str>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrVisitor)
+    }
+}
+
+/// Seed for nested document values.
+struct DocumentSeed;
+
+impl<'de> DeserializeSeed<'de> for DocumentSeed {
+    type Value = Box<dyn Document>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DocumentVisitor)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use indexmap::IndexMap;
+    use std::str::FromStr;
+
     use smithy4rs_core_derive::{SmithyShape, smithy_enum, smithy_union};
 
     use super::*;
@@ -734,6 +1110,19 @@ mod tests {
         assert_eq!(result, vec!["hello", "world", "test"]);
     }
 
+    #[test]
+    fn test_list_of_borrowed_strings() {
+        let json = r#"["hello", "escaped\nvalue"]"#;
+
+        let seed = SchemaSeed::<Vec<Cow<'_, str>>>::new(&STRING_LIST_SCHEMA);
+        let result: Vec<Cow<'_, str>> = seed
+            .deserialize(&mut serde_json::Deserializer::from_str(json))
+            .unwrap();
+
+        assert!(matches!(&result[0], Cow::Borrowed("hello")));
+        assert!(matches!(&result[1], Cow::Owned(value) if value == "escaped\nvalue"));
+    }
+
     smithy!("test#OptionalFieldsStruct": {
         structure OPTIONAL_FIELDS_STRUCT_SCHEMA {
             REQUIRED: STRING = "required_field"
@@ -1163,6 +1552,24 @@ mod tests {
         assert_eq!(value, "192.168.5.0")
     }
 
+    #[test]
+    fn test_union_null_members_are_unset() {
+        let result: IpAddr = serde_json::from_str(r#"{"v4": null, "v6": "::1"}"#).unwrap();
+        assert!(result == IpAddr::V6("::1".to_string()));
+        assert!(serde_json::from_str::<IpAddr>(r#"{"v4": null}"#).is_err());
+        assert!(serde_json::from_str::<IpAddr>(r#"{"v4": "a", "v6": "b"}"#).is_err());
+    }
+
+    #[test]
+    fn test_optional_member_null() {
+        let result: OptionalFieldsStruct =
+            serde_json::from_str(r#"{"required_field": "a", "optional_field": null}"#).unwrap();
+        assert_eq!(result.optional_field, None);
+        assert!(
+            serde_json::from_str::<OptionalFieldsStruct>(r#"{"required_field": null}"#).is_err()
+        );
+    }
+
     smithy!("test#StringEnum": {
         enum A_OR_B {
             A = "a"
@@ -1171,7 +1578,7 @@ mod tests {
     });
 
     #[smithy_enum]
-    #[derive(SmithyShape)]
+    #[derive(SmithyShape, PartialEq, Clone)]
     #[smithy_schema(A_OR_B)]
     pub enum AorB {
         A = "a",
@@ -1195,7 +1602,7 @@ mod tests {
     });
 
     #[smithy_enum]
-    #[derive(SmithyShape)]
+    #[derive(SmithyShape, PartialEq, Clone)]
     #[smithy_schema(C_OR_D)]
     pub enum CorD {
         C = 1,
@@ -1210,4 +1617,157 @@ mod tests {
             panic!("Expected D")
         };
     }
+
+    // --------------------------------------------------------------------
+    // SerAdapter parity tests
+    // --------------------------------------------------------------------
+
+    smithy!("test#EnumMap": {
+        map ENUM_MAP_SCHEMA {
+            key: STRING
+            value: A_OR_B
+        }
+    });
+
+    smithy!("test#DateTime": {
+        @TimestampFormatTrait::DateTime;
+        timestamp DATE_TIME_SCHEMA
+    });
+
+    smithy!("test#AllTypes": {
+        structure ALL_TYPES_SCHEMA {
+            BLOB_FIELD: BLOB = "blob"
+            EPOCH: TIMESTAMP = "epoch"
+            DATE_TIME: DATE_TIME_SCHEMA = "dateTime"
+            BIG_INT: BIG_INTEGER = "bigInt"
+            BIG_DEC: BIG_DECIMAL = "bigDec"
+            ENUM_FIELD: A_OR_B = "enum"
+            INT_ENUM_FIELD: C_OR_D = "intEnum"
+            ENUMS: ENUM_MAP_SCHEMA = "enums"
+        }
+    });
+
+    #[derive(SmithyShape, PartialEq)]
+    #[smithy_schema(ALL_TYPES_SCHEMA)]
+    pub struct AllTypes {
+        #[smithy_schema(BLOB_FIELD)]
+        blob: Blob,
+        #[smithy_schema(EPOCH)]
+        epoch: Instant,
+        #[smithy_schema(DATE_TIME)]
+        date_time: Instant,
+        #[smithy_schema(BIG_INT)]
+        big_int: BigInt,
+        #[smithy_schema(BIG_DEC)]
+        big_dec: BigDecimal,
+        #[no_builder]
+        #[smithy_schema(ENUM_FIELD)]
+        enum_field: AorB,
+        #[no_builder]
+        #[smithy_schema(INT_ENUM_FIELD)]
+        int_enum: CorD,
+        #[no_builder]
+        #[smithy_schema(ENUMS)]
+        enums: IndexMap<String, AorB>,
+    }
+
+    fn all_types() -> AllTypes {
+        let mut enums = IndexMap::new();
+        enums.insert("first".to_string(), AorB::A);
+        enums.insert("second".to_string(), AorB::B);
+        AllTypes {
+            blob: Blob::from_static(b"hello"),
+            epoch: Timestamp::from_epoch_millis(1_515_531_081_500)
+                .unwrap()
+                .into(),
+            date_time: Timestamp::from_epoch_millis(1_515_531_081_000)
+                .unwrap()
+                .into(),
+            big_int: BigInt::from(u128::MAX),
+            big_dec: BigDecimal::from_str("3.14159").unwrap(),
+            enum_field: AorB::B,
+            int_enum: CorD::C,
+            enums,
+        }
+    }
+
+    #[test]
+    fn test_ser_adapter_round_trip() {
+        let value = all_types();
+        let json = serde_json::to_string(&value).unwrap();
+        let result: AllTypes = serde_json::from_str(&json).unwrap();
+        assert!(result == value);
+    }
+
+    #[test]
+    fn test_blob_and_timestamp_representation() {
+        let json = serde_json::to_value(all_types()).unwrap();
+        assert_eq!(json["blob"], "aGVsbG8=");
+        assert_eq!(json["epoch"], 1_515_531_081.5);
+        assert_eq!(json["dateTime"], "2018-01-09T20:51:21Z");
+        assert_eq!(json["enums"]["second"], "b");
+    }
+
+    #[test]
+    fn test_primitive_deserializers() {
+        use serde::de::IntoDeserializer;
+        type E = serde::de::value::Error;
+
+        let seed = SchemaSeed::<i32>::new(&INTEGER);
+        assert_eq!(seed.deserialize(5i32.into_deserializer()), Ok::<_, E>(5));
+        let seed = SchemaSeed::<bool>::new(&BOOLEAN);
+        assert_eq!(seed.deserialize(true.into_deserializer()), Ok::<_, E>(true));
+        let seed = SchemaSeed::<String>::new(&STRING);
+        assert_eq!(
+            seed.deserialize("text".into_deserializer()),
+            Ok::<_, E>("text".to_string())
+        );
+        let seed = SchemaSeed::<Option<String>>::new(&STRING);
+        assert_eq!(seed.deserialize(().into_deserializer()), Ok::<_, E>(None));
+        let seed = SchemaSeed::<AorB>::new(&A_OR_B);
+        assert!(seed.deserialize("b".into_deserializer()) == Ok::<_, E>(AorB::B));
+
+        let value = serde_json::json!({"v4": null, "v6": "::1"});
+        let result: IpAddr = serde_json::from_value(value).unwrap();
+        assert!(result == IpAddr::V6("::1".to_string()));
+        let result: AorB = serde_json::from_value(serde_json::json!("a")).unwrap();
+        assert!(result == AorB::A);
+    }
+
+    #[test]
+    fn test_top_level_scalars() {
+        let seed = SchemaSeed::<Box<dyn Document>>::new(&DOCUMENT);
+        let document = seed
+            .deserialize(&mut serde_json::Deserializer::from_str(
+                r#"{"a": [1, null]}"#,
+            ))
+            .unwrap();
+        let list = document
+            .as_map()
+            .unwrap()
+            .get("a")
+            .unwrap()
+            .as_list()
+            .unwrap();
+        assert_eq!(list[0].as_long(), Some(1));
+        assert!(list[1].get_type().is_none());
+
+        let seed = SchemaSeed::<Blob>::new(&BLOB);
+        let blob = seed
+            .deserialize(&mut serde_json::Deserializer::from_str(r#""aGVsbG8=""#))
+            .unwrap();
+        assert_eq!(blob.as_bytes(), b"hello");
+
+        let seed = SchemaSeed::<Instant>::new(&TIMESTAMP);
+        let timestamp = seed
+            .deserialize(&mut serde_json::Deserializer::from_str("1515531081"))
+            .unwrap();
+        assert_eq!(Timestamp::from(timestamp).epoch_millis(), 1_515_531_081_000);
+
+        let seed = SchemaSeed::<BigDecimal>::new(&BIG_DECIMAL);
+        let decimal = seed
+            .deserialize(&mut serde_json::Deserializer::from_str(r#""1.5""#))
+            .unwrap();
+        assert_eq!(decimal, BigDecimal::from_str("1.5").unwrap());
+    }
 }
@@ -1,3 +1,5 @@
+use crate::{prelude::TimestampFormatTrait, schema::Schema};
+
 /// Serialization adapters for `serde`
 mod serialization;
 pub use serialization::SerAdapter;
@@ -5,3 +7,12 @@ pub use serialization::SerAdapter;
 /// Deserialization adapters for `serde`
 mod deserialization;
 pub use deserialization::SchemaSeed;
+
+/// Get the format used to (de)serialize a timestamp.
+///
+/// Timestamps default to `epoch-seconds` if no format is specified, matching JSON protocols.
+fn timestamp_format(schema: &Schema) -> &TimestampFormatTrait {
+    schema
+        .get_trait_as::<TimestampFormatTrait>()
+        .unwrap_or(&TimestampFormatTrait::EpochSeconds)
+}
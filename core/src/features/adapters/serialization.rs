@@ -11,14 +11,17 @@ use serde::{
 use static_str_ops::staticize;
 
 use crate::{
-    BigDecimal, BigInt, ByteBuffer, Instant,
-    prelude::{JsonNameTrait, XmlAttributeTrait, XmlNameTrait},
-    schema::Schema,
+    BigDecimal, BigInt, Instant,
+    features::adapters::timestamp_format,
+    prelude::{JsonNameTrait, TimestampFormatTrait, XmlAttributeTrait, XmlNameTrait},
+    schema::{Blob, Schema, Timestamp},
     serde::{
+        CodecError,
         se::{ListWriter, MapWriter, SerializeWithSchema, StructWriter},
         serializers::{Error, Serializer},
     },
 };
+use base64::{Engine, prelude::BASE64_STANDARD};
 
 //========================================================================
 // Errors
@@ -53,6 +56,12 @@ impl<E: SerdeError> From<E> for SerErrorWrapper<E> {
         SerErrorWrapper(e)
     }
 }
+impl<E: SerdeError> From<SerErrorWrapper<E>> for CodecError {
+    #[inline]
+    fn from(value: SerErrorWrapper<E>) -> Self {
+        CodecError::Custom(value.to_string())
+    }
+}
 
 //========================================================================
 // Serialization Adapter
@@ -225,15 +234,32 @@ impl<S: serde::Serializer> Serializer for SerAdapter<S> {
     }
 
     #[inline]
-    fn write_blob(self, _: &Schema, _value: &ByteBuffer) -> Result<Self::Ok, Self::Error> {
-        // TODO(streams): How to support data streams?
-        todo!()
+    fn write_blob(self, _: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
+        // Human-readable formats have no native bytes type, so blobs are base64 encoded
+        if self.serializer.is_human_readable() {
+            Ok(self
+                .serializer
+                .serialize_str(&BASE64_STANDARD.encode(value.as_bytes()))?)
+        } else {
+            Ok(self.serializer.serialize_bytes(value.as_bytes())?)
+        }
     }
 
     #[inline]
-    fn write_timestamp(self, _: &Schema, _value: &Instant) -> Result<Self::Ok, Self::Error> {
-        // TODO(timestamp formatting): How to write timestamps with formatting traits?
-        todo!()
+    fn write_timestamp(self, schema: &Schema, value: &Instant) -> Result<Self::Ok, Self::Error> {
+        let format = timestamp_format(schema);
+        let formatted = Timestamp::from(*value)
+            .format(format)
+            .map_err(SerErrorWrapper::custom)?;
+        if !matches!(format, TimestampFormatTrait::EpochSeconds) {
+            return Ok(self.serializer.serialize_str(&formatted)?);
+        }
+        if let Ok(seconds) = formatted.parse::<i64>() {
+            Ok(self.serializer.serialize_i64(seconds)?)
+        } else {
+            let seconds = formatted.parse::<f64>().map_err(SerErrorWrapper::custom)?;
+            Ok(self.serializer.serialize_f64(seconds)?)
+        }
     }
 
     #[inline]
@@ -7,12 +7,11 @@ use std::{
 
 use arbitrary::{Arbitrary, Unstructured};
 use bigdecimal::BigDecimal;
-use bytebuffer::ByteBuffer;
 use num_bigint::BigInt;
 use temporal_rs::Instant;
 
 use crate::{
-    schema::{Document, Schema, SchemaValue, ShapeType},
+    schema::{Blob, Document, Schema, SchemaValue, ShapeType},
     serde::de::{DeserializeWithSchema, Deserializer, ListReader, MapReader, StructReader},
 };
 
@@ -256,9 +255,8 @@ impl<'de, 'a, 'u> Deserializer<'de> for ArbitraryDeserializer<'a, 'u> {
     }
 
     #[inline]
-    fn read_blob(self, _: &Schema) -> Result<ByteBuffer, Self::Error> {
-        let bytes = Vec::<u8>::arbitrary(self.u)?;
-        Ok(ByteBuffer::from_bytes(bytes.as_slice()))
+    fn read_blob(self, _: &Schema) -> Result<Blob, Self::Error> {
+        Ok(Vec::<u8>::arbitrary(self.u)?.into())
     }
 
     fn read_timestamp(self, _: &Schema) -> Result<Instant, Self::Error> {
@@ -0,0 +1,205 @@
+//! # Idempotency Tokens
+//!
+//! Members with the [`@idempotencyToken`](https://smithy.io/2.0/spec/behavior-traits.html#idempotencytoken-trait)
+//! trait identify a request so that the service can discard retries of a request
+//! it has already handled. Clients should fill these members automatically when
+//! they are not set by the caller.
+//!
+//! Generated shapes mark idempotency token members with the `#[idempotency_token]`
+//! attribute. Building one of these shapes from its builder fills any unset token
+//! member with a token from the current [`IdempotencyTokenProvider`] before the
+//! shape is validated:
+//!
+//! ```rust,ignore
+//! let input = CreateItemInput::builder().name("item").build()?;
+//! assert!(input.client_token.is_some());
+//! ```
+//!
+//! Shapes built while deserializing a request are not filled, so servers can
+//! still tell when a client did not send a token.
+//!
+//! By default, tokens are random UUIDs (version 4). Tests can replace the
+//! provider for the current thread with [`with_token_provider`]:
+//!
+//! ```rust,ignore
+//! let input = with_token_provider(|| "token".to_string(), || {
+//!     CreateItemInput::builder().name("item").build()
+//! })?;
+//! assert_eq!(input.client_token.as_deref(), Some("token"));
+//! ```
+
+use std::{
+    cell::RefCell,
+    fmt::Write,
+    hash::{BuildHasher, Hasher, RandomState},
+    sync::Arc,
+};
+
+// ============================================================================
+// Providers
+// ============================================================================
+
+/// Generates tokens for unset idempotency token members.
+pub trait IdempotencyTokenProvider: Send + Sync {
+    /// Generate a new token.
+    fn token(&self) -> String;
+}
+
+impl<F: Fn() -> String + Send + Sync> IdempotencyTokenProvider for F {
+    fn token(&self) -> String {
+        self()
+    }
+}
+
+/// Default [`IdempotencyTokenProvider`] that generates random version 4 UUIDs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidTokenProvider;
+
+impl IdempotencyTokenProvider for UuidTokenProvider {
+    fn token(&self) -> String {
+        // Each `RandomState` is seeded with different keys, so hashing nothing
+        // gives cheap random bits without an extra dependency.
+        let high = RandomState::new().build_hasher().finish();
+        let low = RandomState::new().build_hasher().finish();
+        // Set the version (4) and variant (RFC 9562) bits
+        let high = (high & !0xF000) | 0x4000;
+        let low = (low & !(0b11 << 62)) | (0b10 << 62);
+        let bytes = [high.to_be_bytes(), low.to_be_bytes()].concat();
+        let mut uuid = String::with_capacity(36);
+        for (index, byte) in bytes.iter().enumerate() {
+            if matches!(index, 4 | 6 | 8 | 10) {
+                uuid.push('-');
+            }
+            let _ = write!(uuid, "{byte:02x}");
+        }
+        uuid
+    }
+}
+
+// ============================================================================
+// Current provider
+// ============================================================================
+
+thread_local! {
+    static PROVIDER: RefCell<Option<Arc<dyn IdempotencyTokenProvider>>> = const { RefCell::new(None) };
+}
+
+/// Generate a token with the current [`IdempotencyTokenProvider`].
+///
+/// Uses the provider set by [`with_token_provider`] if called within it, and the
+/// [`UuidTokenProvider`] otherwise.
+#[must_use]
+pub fn idempotency_token() -> String {
+    PROVIDER
+        .with_borrow(Clone::clone)
+        .map_or_else(|| UuidTokenProvider.token(), |provider| provider.token())
+}
+
+/// Run `f` with `provider` generating all idempotency tokens on the current thread.
+///
+/// The previous provider is restored when `f` returns (or panics).
+pub fn with_token_provider<R>(
+    provider: impl IdempotencyTokenProvider + 'static,
+    f: impl FnOnce() -> R,
+) -> R {
+    struct Restore(Option<Arc<dyn IdempotencyTokenProvider>>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            PROVIDER.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(PROVIDER.replace(Some(Arc::new(provider))));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        derive::SmithyShape,
+        prelude::{IdempotencyTokenTrait, STRING},
+        smithy,
+    };
+
+    smithy!("com.example#CreateItemInput": {
+        structure CREATE_ITEM_INPUT {
+            NAME: STRING = "name"
+            @IdempotencyTokenTrait::builder().build();
+            CLIENT_TOKEN: STRING = "clientToken"
+        }
+    });
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(CREATE_ITEM_INPUT)]
+    pub struct CreateItemInput {
+        #[smithy_schema(NAME)]
+        pub name: String,
+        #[idempotency_token]
+        #[smithy_schema(CLIENT_TOKEN)]
+        pub client_token: Option<String>,
+    }
+
+    #[test]
+    fn generates_uuids() {
+        let token = UuidTokenProvider.token();
+        assert_eq!(token.len(), 36);
+        let groups: Vec<_> = token.split('-').map(str::len).collect();
+        assert_eq!(groups, vec![8, 4, 4, 4, 12]);
+        assert_eq!(&token[14..15], "4");
+        assert!(matches!(&token[19..20], "8" | "9" | "a" | "b"));
+        assert_ne!(token, UuidTokenProvider.token());
+    }
+
+    #[test]
+    fn restores_previous_provider() {
+        let token = with_token_provider(
+            || "outer".to_string(),
+            || {
+                assert_eq!(
+                    with_token_provider(|| "inner".to_string(), idempotency_token),
+                    "inner"
+                );
+                idempotency_token()
+            },
+        );
+        assert_eq!(token, "outer");
+        assert_eq!(idempotency_token().len(), 36);
+    }
+
+    #[test]
+    fn builders_fill_unset_tokens() {
+        let input = with_token_provider(
+            || "token".to_string(),
+            || CreateItemInput::builder().name("item").build(),
+        )
+        .unwrap();
+        assert_eq!(input.client_token.as_deref(), Some("token"));
+    }
+
+    #[test]
+    fn builders_keep_set_tokens() {
+        let input = with_token_provider(
+            || "token".to_string(),
+            || {
+                CreateItemInput::builder()
+                    .name("item")
+                    .client_token("caller")
+                    .build()
+            },
+        )
+        .unwrap();
+        assert_eq!(input.client_token.as_deref(), Some("caller"));
+    }
+
+    #[test]
+    fn deserialized_shapes_are_not_filled() {
+        let document: Box<dyn crate::schema::Document> = crate::doc_map!["name" => "item"].into();
+        let input = with_token_provider(
+            || "token".to_string(),
+            || CreateItemInput::try_from(document),
+        )
+        .unwrap();
+        assert_eq!(input.client_token, None);
+    }
+}
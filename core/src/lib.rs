@@ -21,6 +21,10 @@ pub mod schema;
 /// Schema-guided (De)serialization
 pub mod serde;
 
+pub mod idempotency;
+pub mod retry;
+pub mod waiters;
+
 /// Optional features supported by `smithy4rs`
 pub mod features;
 
@@ -74,3 +78,43 @@ use indexmap::IndexSet;
 
 pub(crate) type FxIndexMap<K, V> = IndexMap<K, V, FxBuildHasher>;
 pub(crate) type FxIndexSet<T> = IndexSet<T, FxBuildHasher>;
+
+// =================================================================
+// Thread safety
+// -------------
+// Public types that are used across async tasks must be `Send + Sync`.
+// =================================================================
+assert_send_sync!(
+    // Schemas
+    schema::Schema,
+    schema::ShapeId,
+    schema::TraitRef,
+    schema::SchemaBuilder,
+    schema::loader::SchemaIndex,
+    schema::Operation<schema::Unit, schema::Unit, schema::Unit>,
+    schema::Service,
+    // Documents and data types
+    Box<dyn schema::Document>,
+    schema::DocumentError,
+    schema::StreamingBlob,
+    schema::Timestamp,
+    serde::eventstream::EventStream<Box<dyn schema::Document>>,
+    serde::eventstream::Message,
+    // Codecs and protocols
+    dyn serde::Codec,
+    dyn serde::Protocol,
+    serde::ProtocolRegistry,
+    serde::CodecError,
+    serde::validation::DefaultValidator,
+    serde::validation::ValidationErrors,
+    serde::pool::BufferPool,
+    serde::eventstream::EventStreamError,
+    // Retries
+    idempotency::UuidTokenProvider,
+    dyn idempotency::IdempotencyTokenProvider,
+    retry::RetryPolicies,
+    dyn retry::RetryPolicy,
+    // Waiters
+    waiters::WaitableTrait,
+    waiters::WaiterError<schema::Unit, schema::DocumentError>,
+);
@@ -5,6 +5,58 @@
 /// Generated schemas can be used by `smithy4rs` proc macros to automatically implement
 /// schema-guided (de)serialization for structures and enums.
 ///
+/// Simple shapes (other than operations, resources, and services) with no traits are
+/// created in a `const` context instead, so they are not lazily initialized or
+/// allocated on first use. These schemas are plain `static` [`Schema`](crate::schema::Schema)
+/// values rather than a `LazyLock`.
+///
+/// ## Traits
+/// Any trait can be applied to a shape or member with a `@<initializer>;` statement
+/// before it. Common prelude traits can also be applied with a bracketed list of
+/// annotations after the shape name or member name, using their Smithy IDL names:
+///
+/// ```rust, ignore
+/// smithy!("test#Code": {
+///     string CODE [length(min: 1, max: 4), pattern("^a+$")]
+/// });
+///
+/// smithy!("test#Annotated": {
+///     @DynamicTrait::from("test#custom", doc_map![]);
+///     structure ANNOTATED [sensitive] {
+///         @RequiredTrait::builder().build();
+///         CODE_MEMBER: CODE = "code" [jsonName("Code")]
+///         COUNT: INTEGER = "count" [range(min: 0, max: 10), default(1)]
+///     }
+/// });
+/// ```
+///
+/// Using an annotation for a trait that has no shorthand is a compile error.
+///
+/// ## Recursive Shapes
+/// A member can target the shape that contains it with `(@self)`. Shapes that
+/// recurse through another aggregate (i.e. a structure with a list of itself) must
+/// target the recursive shape with `(@lazy TARGET)` in at least one place, so the
+/// target is not resolved while it is still being built:
+///
+/// ```rust, ignore
+/// smithy!("test#TreeNodes": {
+///     list TREE_NODES {
+///         member: (@lazy TREE_NODE)
+///     }
+/// });
+///
+/// smithy!("test#TreeNode": {
+///     structure TREE_NODE {
+///         CHILDREN: TREE_NODES = "children"
+///         PARENT: (@self) = "parent"
+///     }
+/// });
+/// ```
+///
+/// Recursive members of derived shapes must be boxed (i.e. `Option<Box<TreeNode>>`)
+/// unless they are in a collection.
+///
+/// ## Example
 /// ```rust, ignore
 /// smithy!("test#SimpleStruct": {
 ///     structure SIMPLE_STRUCT_SCHEMA {
@@ -38,88 +90,98 @@ macro_rules! smithy_internal {
     //
     // Must be invoked as: smithy_internal!($($smithy)+)
     // ============================================================================
+    // === Simple types with no traits ===
+    // These do not need to be lazily initialized, so they are created in a
+    // const context instead.
+    ($id:literal: {
+        $(#[$outer:meta])*
+        $shape:ident $name:ident
+    }) => (
+        $crate::smithy!(@simple $shape $name $id [$(#[$outer])*]);
+    );
+
     // === Simple types ===
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        boolean $name:ident
+        boolean $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_boolean($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_boolean($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        byte $name:ident
+        byte $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_byte($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_byte($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        short $name:ident
+        short $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_short($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_short($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        integer $name:ident
+        integer $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_integer($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_integer($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
      ($id:literal: {
          $(#[$outer:meta])*
          $(@$t:expr;)*
-         long $name:ident
+         long $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_long($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_long($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        float $name:ident
+        float $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_float($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_float($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        double $name:ident
+        double $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_double($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_double($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
@@ -127,72 +189,72 @@ macro_rules! smithy_internal {
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        bigInteger $name:ident
+        bigInteger $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_big_integer($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_big_integer($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        bigDecimal $name:ident
+        bigDecimal $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_big_decimal($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_big_decimal($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        timestamp $name:ident
+        timestamp $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_timestamp($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_timestamp($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        string $name:ident
+        string $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_string($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_string($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        blob $name:ident
+        blob $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_blob($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_blob($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        document $name:ident
+        document $name:ident $([$($a:tt)*])?
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_document($id, $crate::traits!($($t),*))
+            $crate::schema::Schema::create_document($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
@@ -200,28 +262,28 @@ macro_rules! smithy_internal {
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        enum $name:ident {$(
+        enum $name:ident $([$($a:tt)*])? {$(
             $_variant:ident = $value:literal
         )*}
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_enum($id, Box::new([$($value),*]), $crate::traits!($($t),*))
+            $crate::schema::Schema::create_enum($id, Box::new([$($value),*]), $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        intEnum $name:ident {$(
+        intEnum $name:ident $([$($a:tt)*])? {$(
             $_variant:ident = $value:literal
         )*}
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::create_int_enum($id, Box::new([$($value),*]), $crate::traits!($($t),*))
+            $crate::schema::Schema::create_int_enum($id, Box::new([$($value),*]), $crate::smithy!(@traits [$($t),*] $($($a)*)?))
         );
     );
 
@@ -231,15 +293,15 @@ macro_rules! smithy_internal {
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        list $name:ident {
-            $(@$m:expr;)* member: $member:ident
+        list $name:ident $([$($a:tt)*])? {
+            $(@$m:expr;)* member: $member:tt $([$($ma:tt)*])?
         }
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::list_builder($id, $crate::traits!($($t),*)),
-            ("member", $member, $crate::traits!($($m),*))
+            $crate::schema::Schema::list_builder($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?)),
+            ("member", $member, $crate::smithy!(@traits [$($m),*] $($($ma)*)?))
         );
     );
 
@@ -247,19 +309,19 @@ macro_rules! smithy_internal {
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        map $name:ident {
+        map $name:ident $([$($a:tt)*])? {
             $(@$k:expr;)*
-            key: $key:ident
+            key: $key:tt $([$($ka:tt)*])?
             $(@$v:expr;)*
-            value: $value:ident
+            value: $value:tt $([$($va:tt)*])?
         }
     }) => (
         $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::map_builder($id, $crate::traits!($($t),*)),
-            ("key", $key, $crate::traits!($($k),*)),
-            ("value", $value, $crate::traits!($($v),*))
+            $crate::schema::Schema::map_builder($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?)),
+            ("key", $key, $crate::smithy!(@traits [$($k),*] $($($ka)*)?)),
+            ("value", $value, $crate::smithy!(@traits [$($v),*] $($($va)*)?))
         );
     );
 
@@ -268,49 +330,83 @@ macro_rules! smithy_internal {
      ($id:literal: {
          $(#[$outer:meta])*
          $(@$t:expr;)*
-         structure $name:ident {}
+         structure $name:ident $([$($a:tt)*])? {}
     }) => (
        $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::structure_builder($id, $crate::traits!($($t),*)).build()
+            $crate::schema::Schema::structure_builder($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?)).build()
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        structure $name:ident {$(
+        structure $name:ident $([$($a:tt)*])? {$(
             $(@$m:expr;)*
-            $member_ident:ident : $member_schema:tt = $member_name:literal
+            $member_ident:ident : $member_schema:tt = $member_name:literal $([$($ma:tt)*])?
         )*}
     }) => (
        $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::structure_builder($id, $crate::traits!($($t),*)),
-            $(($member_ident, $member_name, $member_schema, $crate::traits!($($m),*))),*
+            $crate::schema::Schema::structure_builder($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?)),
+            $(($member_ident, $member_name, $member_schema, $crate::smithy!(@traits [$($m),*] $($($ma)*)?))),*
         );
     );
 
     ($id:literal: {
         $(#[$outer:meta])*
         $(@$t:expr;)*
-        union $name:ident {$(
+        union $name:ident $([$($a:tt)*])? {$(
             $(@$m:expr;)*
-            $member_ident:ident : $member_schema:tt = $member_name:literal
+            $member_ident:ident : $member_schema:tt = $member_name:literal $([$($ma:tt)*])?
         )*}
     }) => (
        $crate::smithy!(@inner
             $name,
             $(@attr[$outer]),*
-            $crate::schema::Schema::union_builder($id, $crate::traits!($($t),*)),
-            $(($member_ident, $member_name, $member_schema, $crate::traits!($($m),*))),*
+            $crate::schema::Schema::union_builder($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?)),
+            $(($member_ident, $member_name, $member_schema, $crate::smithy!(@traits [$($m),*] $($($ma)*)?))),*
         );
     );
 
     // === Service Shapes ===
-    // TODO(service shapes): Add Operation, Resource, Service schema macros
+    ($id:literal: {
+        $(#[$outer:meta])*
+        $(@$t:expr;)*
+        operation $name:ident $([$($a:tt)*])?
+    }) => (
+        $crate::smithy!(@inner
+            $name,
+            $(@attr[$outer]),*
+            $crate::schema::Schema::create_operation($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
+        );
+    );
+
+    ($id:literal: {
+        $(#[$outer:meta])*
+        $(@$t:expr;)*
+        resource $name:ident $([$($a:tt)*])?
+    }) => (
+        $crate::smithy!(@inner
+            $name,
+            $(@attr[$outer]),*
+            $crate::schema::Schema::create_resource($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
+        );
+    );
+
+    ($id:literal: {
+        $(#[$outer:meta])*
+        $(@$t:expr;)*
+        service $name:ident $([$($a:tt)*])?
+    }) => (
+        $crate::smithy!(@inner
+            $name,
+            $(@attr[$outer]),*
+            $crate::schema::Schema::create_service($id, $crate::smithy!(@traits [$($t),*] $($($a)*)?))
+        );
+    );
 
     // ============================================================================
     // Actual impl of schema
@@ -375,6 +471,136 @@ macro_rules! smithy_internal {
         });
     };
 
+    // Simple schema created in a const context
+    (
+        @static
+        $schema_name:ident,
+        $shape_type:ident,
+        $id:literal,
+        [$(#[$outer:meta])*]
+    ) => {
+        $crate::pastey::paste! {
+            #[doc(hidden)]
+            pub static [<$schema_name _VALUE>]: $crate::schema::SchemaValue =
+                $crate::schema::SchemaValue::const_scalar($crate::schema::ShapeType::$shape_type, $id);
+
+            $(#[$outer])*
+            pub static $schema_name: $crate::schema::Schema =
+                $crate::schema::Schema::from_static(&[<$schema_name _VALUE>]);
+        }
+    };
+
+    // ============================================================================
+    // Internal helpers to map simple shape keywords to a const schema
+    //
+    // INTERNAL API
+    // ============================================================================
+
+    (@simple boolean $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, Boolean, $id, [$($outer)*]);
+    };
+    (@simple byte $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, Byte, $id, [$($outer)*]);
+    };
+    (@simple short $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, Short, $id, [$($outer)*]);
+    };
+    (@simple integer $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, Integer, $id, [$($outer)*]);
+    };
+    (@simple long $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, Long, $id, [$($outer)*]);
+    };
+    (@simple float $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, Float, $id, [$($outer)*]);
+    };
+    (@simple double $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, Double, $id, [$($outer)*]);
+    };
+    (@simple bigInteger $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, BigInteger, $id, [$($outer)*]);
+    };
+    (@simple bigDecimal $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, BigDecimal, $id, [$($outer)*]);
+    };
+    (@simple timestamp $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, Timestamp, $id, [$($outer)*]);
+    };
+    (@simple string $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, String, $id, [$($outer)*]);
+    };
+    (@simple blob $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, Blob, $id, [$($outer)*]);
+    };
+    (@simple document $name:ident $id:literal [$($outer:tt)*]) => {
+        $crate::smithy!(@static $name, Document, $id, [$($outer)*]);
+    };
+    // Service shapes are still lazily created so they can be referenced by operation descriptors.
+    (@simple operation $name:ident $id:literal [$(#[$outer:meta])*]) => {
+        $crate::smithy!(@inner $name, $(@attr[$outer]),* $crate::schema::Schema::create_operation($id, Vec::new()));
+    };
+    (@simple resource $name:ident $id:literal [$(#[$outer:meta])*]) => {
+        $crate::smithy!(@inner $name, $(@attr[$outer]),* $crate::schema::Schema::create_resource($id, Vec::new()));
+    };
+    (@simple service $name:ident $id:literal [$(#[$outer:meta])*]) => {
+        $crate::smithy!(@inner $name, $(@attr[$outer]),* $crate::schema::Schema::create_service($id, Vec::new()));
+    };
+
+    // ============================================================================
+    // Internal helpers to map trait annotations (i.e. `[length(max: 4)]`) to
+    // trait initializers
+    //
+    // INTERNAL API
+    // ============================================================================
+
+    (@traits [$($t:expr),*] $($name:ident $(($($args:tt)*))?),* $(,)?) => {
+        $crate::traits!($($t,)* $($crate::smithy!(@trait $name $(($($args)*))?)),*)
+    };
+
+    // Constraint traits
+    (@trait length($($key:ident : $value:expr),* $(,)?)) => {
+        $crate::prelude::LengthTrait::builder()$(.$key($value))*.build()
+    };
+    (@trait range($($key:ident : $value:expr),* $(,)?)) => {
+        $crate::prelude::RangeTrait::builder()$(.$key($value))*.build()
+    };
+    (@trait pattern($pattern:expr)) => { $crate::prelude::PatternTrait::new($pattern) };
+    (@trait required) => { $crate::prelude::RequiredTrait::builder().build() };
+    (@trait sparse) => { $crate::prelude::SparseTrait::builder().build() };
+    (@trait uniqueItems) => { $crate::prelude::UniqueItemsTrait::builder().build() };
+    (@trait default($value:expr)) => { $crate::prelude::DefaultTrait::new($value) };
+    (@trait sensitive) => { $crate::prelude::SensitiveTrait::builder().build() };
+    // Serialization traits
+    (@trait jsonName($name:expr)) => { $crate::prelude::JsonNameTrait::new($name) };
+    (@trait xmlName($name:expr)) => { $crate::prelude::XmlNameTrait::new($name) };
+    (@trait xmlAttribute) => { $crate::prelude::XmlAttributeTrait::builder().build() };
+    (@trait xmlFlattened) => { $crate::prelude::XmlFlattenedTrait::builder().build() };
+    (@trait mediaType($media_type:expr)) => { $crate::prelude::MediaTypeTrait::new($media_type) };
+    (@trait timestampFormat(date-time)) => { $crate::prelude::TimestampFormatTrait::DateTime };
+    (@trait timestampFormat(epoch-seconds)) => { $crate::prelude::TimestampFormatTrait::EpochSeconds };
+    (@trait timestampFormat(http-date)) => { $crate::prelude::TimestampFormatTrait::HttpDate };
+    // HTTP binding traits
+    (@trait httpLabel) => { $crate::prelude::HttpLabelTrait::builder().build() };
+    (@trait httpPayload) => { $crate::prelude::HttpPayloadTrait::builder().build() };
+    (@trait httpHeader($name:expr)) => { $crate::prelude::HttpHeaderTrait::new($name) };
+    (@trait httpPrefixHeaders($prefix:expr)) => { $crate::prelude::HttpPrefixHeadersTrait::new($prefix) };
+    (@trait httpQuery($name:expr)) => { $crate::prelude::HttpQueryTrait::new($name) };
+    (@trait httpQueryParams) => { $crate::prelude::HttpQueryParamsTrait::builder().build() };
+    (@trait httpResponseCode) => { $crate::prelude::HttpResponseCodeTrait::builder().build() };
+    (@trait httpError($code:expr)) => { $crate::prelude::HttpErrorTrait::new($code) };
+    // Error and behavior traits
+    (@trait error(client)) => { $crate::prelude::ErrorTrait::Client };
+    (@trait error(server)) => { $crate::prelude::ErrorTrait::Server };
+    (@trait idempotencyToken) => { $crate::prelude::IdempotencyTokenTrait::builder().build() };
+    (@trait streaming) => { $crate::prelude::StreamingTrait::builder().build() };
+    (@trait documentation($docs:expr)) => { $crate::prelude::DocumentationTrait::new($docs) };
+    (@trait $name:ident $($_args:tt)?) => {
+        compile_error!(concat!(
+            "Unsupported trait annotation `", stringify!($name), "`. ",
+            "Use the `@Trait::builder().build();` syntax for other traits."
+        ))
+    };
+
     // ============================================================================
     // Internal helpers to build chain of member `put` statements
     //
@@ -385,6 +611,10 @@ macro_rules! smithy_internal {
     (@build_chain $builder:expr, $builder_ref:expr, ($member_ident:literal, (@ self), $member_traits:expr) $(, $rest:tt)*) => {
         $crate::smithy!(@build_chain $builder.put_member($member_ident, $builder_ref, $member_traits), $builder_ref $(, $rest)*)
     };
+    // Case - deferred target for mutually recursive shapes (matches (@lazy TARGET) as single tt)
+    (@build_chain $builder:expr, $builder_ref:expr, ($member_ident:literal, (@ lazy $target:path), $member_traits:expr) $(, $rest:tt)*) => {
+        $crate::smithy!(@build_chain $builder.put_member($member_ident, $crate::schema::MemberTarget::deferred(|| &$target), $member_traits), $builder_ref $(, $rest)*)
+    };
     // Case - members to add to chain.
     (@build_chain $builder:expr, $builder_ref:expr, ($member_ident:literal, $member_schema:tt, $member_traits:expr) $(, $rest:tt)*) => {
         $crate::smithy!(@build_chain $builder.put_member($member_ident, &$member_schema, $member_traits), $builder_ref $(, $rest)*)
@@ -499,3 +729,23 @@ macro_rules! string_map {
         $crate::IndexMap::<String, _>::from_iter([$(($key.into(), $val.into()),)*])
     }
 }
+
+/// Asserts at compile time that each listed type is `Send + Sync`.
+///
+/// Used to check that shapes, documents, schemas and codecs can be shared across
+/// async tasks, so a change that makes a type `!Send` or `!Sync` fails to build
+/// instead of surfacing as a bound error in downstream code.
+///
+/// ```rust, ignore
+/// assert_send_sync!(Schema, Box<dyn Document>, JsonCodec);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assert_send_sync {
+    ($($ty:ty),+ $(,)?) => {
+        const _: fn() = || {
+            fn assert_send_sync<T: ?Sized + Send + Sync>() {}
+            $(assert_send_sync::<$ty>();)+
+        };
+    };
+}
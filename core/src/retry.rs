@@ -0,0 +1,440 @@
+//! # Retries
+//!
+//! Clients use a [`RetryPolicy`] to decide whether a failed request should be
+//! retried and how long to wait before the next attempt.
+//!
+//! Failures are first classified into a [`RetryKind`]:
+//! - Modeled errors are classified by the `@retryable` trait on their schema.
+//! - Transport failures (timeouts, dropped connections, etc.) are classified
+//!   from their [`io::ErrorKind`].
+//!
+//! The [`RetryPolicies`] for a client hold a default policy along with
+//! per-operation overrides:
+//!
+//! ```rust,ignore
+//! let policies = RetryPolicies::new(ExponentialBackoff::new().with_max_attempts(5))
+//!     .with_override(GET_ITEM.id(), NoRetry);
+//!
+//! let kind = RetryKind::of_error(&error);
+//! if let Some(delay) = policies.for_operation(GET_ITEM.id()).retry_delay(attempt, kind) {
+//!     sleep(delay).await;
+//! }
+//! ```
+
+use std::{
+    fmt::{Debug, Formatter},
+    hash::{BuildHasher, Hasher, RandomState},
+    io,
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{
+    FxIndexMap,
+    prelude::RetryableTrait,
+    schema::{ErrorShape, Schema, ShapeId},
+    serde::CodecError,
+};
+
+// ============================================================================
+// Classification
+// ============================================================================
+
+/// Classification of a failed request used to decide whether to retry it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RetryKind {
+    /// The service is throttling requests. Retries should back off more aggressively.
+    Throttling,
+    /// A transient failure that may succeed if retried.
+    Transient,
+    /// A failure that will not succeed if retried.
+    NotRetryable,
+}
+
+impl RetryKind {
+    /// Classify a modeled error by the `@retryable` trait on its schema.
+    #[must_use]
+    pub fn of_schema(schema: &Schema) -> Self {
+        match schema.get_trait_as::<RetryableTrait>() {
+            Some(retryable) if retryable.throttling == Some(true) => RetryKind::Throttling,
+            Some(_) => RetryKind::Transient,
+            None => RetryKind::NotRetryable,
+        }
+    }
+
+    /// Classify a modeled error shape.
+    #[must_use]
+    pub fn of_error<E: ErrorShape>(error: &E) -> Self {
+        if error.is_throttling() {
+            RetryKind::Throttling
+        } else if error.is_retryable() {
+            RetryKind::Transient
+        } else {
+            RetryKind::NotRetryable
+        }
+    }
+
+    /// Classify a transport error.
+    ///
+    /// Timeouts and dropped or refused connections are transient. All other
+    /// errors are not retryable.
+    #[must_use]
+    pub fn of_io_error(error: &io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::TimedOut
+            | io::ErrorKind::Interrupted
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::ConnectionRefused
+            | io::ErrorKind::NotConnected
+            | io::ErrorKind::BrokenPipe
+            | io::ErrorKind::UnexpectedEof => RetryKind::Transient,
+            _ => RetryKind::NotRetryable,
+        }
+    }
+
+    /// Classify a codec error.
+    ///
+    /// Only IO errors raised while reading or writing a message may be retried.
+    #[must_use]
+    pub fn of_codec_error(error: &CodecError) -> Self {
+        match error {
+            CodecError::Io(err) => Self::of_io_error(err),
+            _ => RetryKind::NotRetryable,
+        }
+    }
+
+    /// Returns `true` if a failure of this kind may be retried.
+    #[must_use]
+    pub const fn is_retryable(self) -> bool {
+        !matches!(self, RetryKind::NotRetryable)
+    }
+}
+
+// ============================================================================
+// Policies
+// ============================================================================
+
+/// Decides whether and when a failed request is retried.
+pub trait RetryPolicy: Send + Sync {
+    /// Get the delay before the next attempt of a request.
+    ///
+    /// `attempt` is the number of attempts made so far (starting at `1`) and
+    /// `kind` is the classification of the last failure.
+    ///
+    /// Returns `None` if the request should not be retried.
+    fn retry_delay(&self, attempt: u32, kind: RetryKind) -> Option<Duration>;
+}
+
+/// Policy that never retries a request.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoRetry;
+
+impl RetryPolicy for NoRetry {
+    fn retry_delay(&self, _attempt: u32, _kind: RetryKind) -> Option<Duration> {
+        None
+    }
+}
+
+/// Exponential backoff with "full" jitter.
+///
+/// The delay before attempt `n + 1` is a random duration between zero and
+/// `base_delay * 2^(n - 1)`, capped at `max_delay`. Throttling errors use a
+/// separate (typically larger) base delay.
+#[derive(Debug, Clone, Copy)]
+pub struct ExponentialBackoff {
+    max_attempts: u32,
+    base_delay: Duration,
+    throttling_base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+}
+
+impl ExponentialBackoff {
+    /// Create a new backoff policy with default settings.
+    ///
+    /// Requests are attempted at most 3 times, with a base delay of `100ms`
+    /// (`1s` for throttling errors) and a maximum delay of `20s`.
+    #[must_use]
+    pub const fn new() -> Self {
+        ExponentialBackoff {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            throttling_base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(20),
+            jitter: true,
+        }
+    }
+
+    /// Set the maximum number of attempts, including the initial request.
+    #[must_use]
+    pub const fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set the base delay used for transient errors.
+    #[must_use]
+    pub const fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the base delay used for throttling errors.
+    #[must_use]
+    pub const fn with_throttling_base_delay(mut self, base_delay: Duration) -> Self {
+        self.throttling_base_delay = base_delay;
+        self
+    }
+
+    /// Set the maximum delay between attempts.
+    #[must_use]
+    pub const fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Disable jitter, always waiting for the full backoff delay.
+    #[must_use]
+    pub const fn without_jitter(mut self) -> Self {
+        self.jitter = false;
+        self
+    }
+
+    /// Get the maximum number of attempts, including the initial request.
+    #[must_use]
+    pub const fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    fn backoff(&self, attempt: u32, kind: RetryKind) -> Duration {
+        let base = match kind {
+            RetryKind::Throttling => self.throttling_base_delay,
+            _ => self.base_delay,
+        };
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        base.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryPolicy for ExponentialBackoff {
+    fn retry_delay(&self, attempt: u32, kind: RetryKind) -> Option<Duration> {
+        if !kind.is_retryable() || attempt >= self.max_attempts {
+            return None;
+        }
+        let delay = self.backoff(attempt, kind);
+        Some(if self.jitter {
+            delay.mul_f64(random_fraction())
+        } else {
+            delay
+        })
+    }
+}
+
+/// Random value in `[0, 1)`.
+///
+/// Each [`RandomState`] is seeded with different keys, so hashing nothing
+/// gives a cheap random value without an extra dependency.
+pub(crate) fn random_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+// ============================================================================
+// Per-operation policies
+// ============================================================================
+
+/// Default [`RetryPolicy`] for a client along with per-operation overrides.
+#[derive(Clone)]
+pub struct RetryPolicies {
+    default: Arc<dyn RetryPolicy>,
+    overrides: FxIndexMap<ShapeId, Arc<dyn RetryPolicy>>,
+}
+
+impl RetryPolicies {
+    /// Create a new set of policies using `default` for all operations.
+    #[must_use]
+    pub fn new(default: impl RetryPolicy + 'static) -> Self {
+        RetryPolicies {
+            default: Arc::new(default),
+            overrides: FxIndexMap::default(),
+        }
+    }
+
+    /// Use `policy` instead of the default policy for an operation.
+    ///
+    /// Overriding an operation more than once replaces the previous override.
+    pub fn register_override(
+        &mut self,
+        operation: &ShapeId,
+        policy: impl RetryPolicy + 'static,
+    ) -> &mut Self {
+        self.overrides.insert(operation.clone(), Arc::new(policy));
+        self
+    }
+
+    /// Builder-style version of [`RetryPolicies::register_override`].
+    #[must_use]
+    pub fn with_override(
+        mut self,
+        operation: &ShapeId,
+        policy: impl RetryPolicy + 'static,
+    ) -> Self {
+        self.register_override(operation, policy);
+        self
+    }
+
+    /// Get the policy used for the operation with the given [`ShapeId`].
+    #[must_use]
+    pub fn for_operation(&self, operation: &ShapeId) -> &dyn RetryPolicy {
+        self.overrides
+            .get(operation)
+            .map_or(&*self.default, |policy| &**policy)
+    }
+
+    /// Get the policy used for operations without an override.
+    #[must_use]
+    pub fn default_policy(&self) -> &dyn RetryPolicy {
+        &*self.default
+    }
+}
+
+impl Default for RetryPolicies {
+    fn default() -> Self {
+        Self::new(ExponentialBackoff::new())
+    }
+}
+
+impl Debug for RetryPolicies {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicies")
+            .field("overrides", &self.overrides.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{ErrorTrait, STRING},
+        smithy,
+    };
+
+    smithy!("com.example#NotFound": {
+        @ErrorTrait::Client;
+        structure NOT_FOUND_SCHEMA {
+            MESSAGE: STRING = "message"
+        }
+    });
+
+    smithy!("com.example#Unavailable": {
+        @ErrorTrait::Server;
+        @RetryableTrait::builder().build().unwrap();
+        structure UNAVAILABLE_SCHEMA {
+            MESSAGE: STRING = "message"
+        }
+    });
+
+    smithy!("com.example#Throttled": {
+        @ErrorTrait::Server;
+        @RetryableTrait::builder().throttling(true).build().unwrap();
+        structure THROTTLED_SCHEMA {
+            MESSAGE: STRING = "message"
+        }
+    });
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn classifies_errors_by_retryable_trait() {
+        assert_eq!(
+            RetryKind::of_schema(&NOT_FOUND_SCHEMA),
+            RetryKind::NotRetryable
+        );
+        assert_eq!(
+            RetryKind::of_schema(&UNAVAILABLE_SCHEMA),
+            RetryKind::Transient
+        );
+        assert_eq!(
+            RetryKind::of_schema(&THROTTLED_SCHEMA),
+            RetryKind::Throttling
+        );
+    }
+
+    #[test]
+    fn classifies_transport_errors() {
+        let timeout = io::Error::from(io::ErrorKind::TimedOut);
+        assert_eq!(RetryKind::of_io_error(&timeout), RetryKind::Transient);
+        let denied = io::Error::from(io::ErrorKind::PermissionDenied);
+        assert_eq!(RetryKind::of_io_error(&denied), RetryKind::NotRetryable);
+
+        let reset = CodecError::Io(io::Error::from(io::ErrorKind::ConnectionReset));
+        assert_eq!(RetryKind::of_codec_error(&reset), RetryKind::Transient);
+        let syntax = CodecError::syntax(None, "bad");
+        assert_eq!(RetryKind::of_codec_error(&syntax), RetryKind::NotRetryable);
+    }
+
+    #[test]
+    fn exponential_backoff_doubles_up_to_max_attempts() {
+        let policy = ExponentialBackoff::new()
+            .with_max_attempts(4)
+            .with_base_delay(10 * MS)
+            .without_jitter();
+        assert_eq!(policy.retry_delay(1, RetryKind::Transient), Some(10 * MS));
+        assert_eq!(policy.retry_delay(2, RetryKind::Transient), Some(20 * MS));
+        assert_eq!(policy.retry_delay(3, RetryKind::Transient), Some(40 * MS));
+        assert_eq!(policy.retry_delay(4, RetryKind::Transient), None);
+        assert_eq!(policy.retry_delay(1, RetryKind::NotRetryable), None);
+    }
+
+    #[test]
+    fn exponential_backoff_caps_delay() {
+        let policy = ExponentialBackoff::new()
+            .with_max_attempts(u32::MAX)
+            .with_base_delay(10 * MS)
+            .with_throttling_base_delay(100 * MS)
+            .with_max_delay(150 * MS)
+            .without_jitter();
+        assert_eq!(policy.retry_delay(1, RetryKind::Throttling), Some(100 * MS));
+        assert_eq!(policy.retry_delay(2, RetryKind::Throttling), Some(150 * MS));
+        assert_eq!(
+            policy.retry_delay(100, RetryKind::Transient),
+            Some(150 * MS)
+        );
+    }
+
+    #[test]
+    fn jitter_stays_within_backoff() {
+        let policy = ExponentialBackoff::new().with_base_delay(10 * MS);
+        for _ in 0..100 {
+            let delay = policy.retry_delay(2, RetryKind::Transient).unwrap();
+            assert!(delay <= 20 * MS);
+        }
+    }
+
+    #[test]
+    fn per_operation_overrides() {
+        let get_item = ShapeId::from("com.example#GetItem");
+        let put_item = ShapeId::from("com.example#PutItem");
+        let policies = RetryPolicies::default().with_override(&put_item, NoRetry);
+        assert!(
+            policies
+                .for_operation(&get_item)
+                .retry_delay(1, RetryKind::Transient)
+                .is_some()
+        );
+        assert_eq!(
+            policies
+                .for_operation(&put_item)
+                .retry_delay(1, RetryKind::Transient),
+            None
+        );
+    }
+}
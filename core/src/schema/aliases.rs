@@ -0,0 +1,99 @@
+//! # Member Aliases
+//!
+//! Renaming a structure or union member in a model changes its name on the wire,
+//! which breaks clients and stored data that still use the old name. The
+//! [`AliasesTrait`] lists previous names of a member that deserializers continue
+//! to accept, so a rename can be rolled out over several releases before the
+//! old names are removed from the model:
+//!
+//! ```rust,ignore
+//! smithy!("com.example#City": {
+//!     structure CITY_SCHEMA {
+//!         @AliasesTrait::new(vec!["cityName".to_string()]);
+//!         NAME: STRING = "name"
+//!     }
+//! });
+//!
+//! // Both `{"name": "Seattle"}` and `{"cityName": "Seattle"}` deserialize
+//! // into the `name` member. Serializers always write the current name.
+//! ```
+//!
+//! Deserializers resolve members with [`SchemaValue::get_member_or_alias`].
+
+use crate::{
+    derive::{SmithyShape, SmithyTraitImpl},
+    prelude::STRING,
+    schema::{Schema, SchemaValue},
+    smithy,
+};
+
+smithy!("smithy4rs.api#aliases": {
+    /// Schema for [`AliasesTrait`]
+    list ALIASES {
+        member: STRING
+    }
+});
+
+/// Previous names of a structure or union member that are still accepted
+/// when deserializing.
+#[derive(SmithyShape, SmithyTraitImpl, Clone)]
+#[smithy_schema(ALIASES)]
+#[repr(transparent)]
+pub struct AliasesTrait(Vec<String>);
+
+impl SchemaValue {
+    /// Get a member schema by its name or by one of its [`AliasesTrait`] aliases.
+    ///
+    /// Current member names always take precedence over aliases.
+    #[must_use]
+    pub fn get_member_or_alias(&self, name: &str) -> Option<&Schema> {
+        self.get_member(name).or_else(|| {
+            self.members().values().find(|member| {
+                member
+                    .get_trait_as::<AliasesTrait>()
+                    .is_some_and(|aliases| aliases.iter().any(|alias| alias == name))
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::INTEGER;
+
+    smithy!("api.smithy#City": {
+        structure CITY {
+            @AliasesTrait::new(vec!["cityName".to_string(), "title".to_string()]);
+            CITY_NAME: STRING = "name"
+            @AliasesTrait::new(vec!["name".to_string()]);
+            CITY_POPULATION: INTEGER = "population"
+        }
+    });
+
+    #[test]
+    fn resolves_members_by_alias() {
+        assert_eq!(
+            CITY.get_member_or_alias("cityName"),
+            Some(CITY.expect_member("name"))
+        );
+        assert_eq!(
+            CITY.get_member_or_alias("title"),
+            Some(CITY.expect_member("name"))
+        );
+        assert_eq!(
+            CITY.get_member_or_alias("population"),
+            Some(CITY.expect_member("population"))
+        );
+        assert!(CITY.get_member_or_alias("unknown").is_none());
+        assert!(CITY.get_member("cityName").is_none());
+    }
+
+    #[test]
+    fn member_names_take_precedence_over_aliases() {
+        assert_eq!(
+            CITY.get_member_or_alias("name"),
+            Some(CITY.expect_member("name"))
+        );
+    }
+}
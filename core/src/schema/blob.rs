@@ -0,0 +1,214 @@
+use std::ops::Deref;
+
+use bytes::Bytes;
+
+use crate::{
+    ByteBuffer,
+    schema::Schema,
+    serde::{
+        correction::ErrorCorrectionDefault,
+        de::{DeserializeWithSchema, Deserializer},
+        se::{SerializeWithSchema, Serializer},
+    },
+};
+
+/// # Blob
+///
+/// Binary data for a Smithy `blob` value.
+///
+/// Blobs are backed by reference-counted [`Bytes`], so cloning a blob or
+/// creating one from an owned buffer (`Vec<u8>`, [`Bytes`], or [`ByteBuffer`])
+/// does not copy the underlying data. This lets codecs pass large payloads
+/// through [`Serializer::write_blob`] and [`Deserializer::read_blob`] without
+/// copying them.
+///
+/// ```rust
+/// use smithy4rs_core::schema::Blob;
+///
+/// let blob = Blob::from_bytes("hello");
+/// assert_eq!(blob, b"hello"[..]);
+/// assert_eq!(blob.clone().as_ptr(), blob.as_ptr());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Blob(Bytes);
+
+impl Blob {
+    /// Create a new, empty blob.
+    #[must_use]
+    pub const fn new() -> Self {
+        Blob(Bytes::new())
+    }
+
+    /// Create a blob from a static slice without copying it.
+    #[must_use]
+    pub const fn from_static(bytes: &'static [u8]) -> Self {
+        Blob(Bytes::from_static(bytes))
+    }
+
+    /// Create a blob from in-memory data.
+    pub fn from_bytes(bytes: impl Into<Bytes>) -> Self {
+        Blob(bytes.into())
+    }
+
+    /// Create a blob by copying a borrowed slice.
+    #[must_use]
+    pub fn copy_from_slice(data: &[u8]) -> Self {
+        Blob(Bytes::copy_from_slice(data))
+    }
+
+    /// Get the data of this blob as a slice.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Convert this blob into [`Bytes`].
+    #[must_use]
+    pub fn into_bytes(self) -> Bytes {
+        self.0
+    }
+
+    /// Convert this blob into a `Vec<u8>`.
+    ///
+    /// The data is only copied if it is shared with another blob.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<u8> {
+        Vec::from(self.0)
+    }
+}
+
+impl Deref for Blob {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Blob {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl PartialEq<[u8]> for Blob {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.0 == other
+    }
+}
+
+// ============================================================================
+// Conversions
+// ============================================================================
+
+impl From<Bytes> for Blob {
+    fn from(bytes: Bytes) -> Self {
+        Blob(bytes)
+    }
+}
+
+impl From<Vec<u8>> for Blob {
+    fn from(bytes: Vec<u8>) -> Self {
+        Blob(Bytes::from(bytes))
+    }
+}
+
+impl From<&'static [u8]> for Blob {
+    fn from(bytes: &'static [u8]) -> Self {
+        Blob::from_static(bytes)
+    }
+}
+
+impl From<ByteBuffer> for Blob {
+    fn from(buffer: ByteBuffer) -> Self {
+        Blob::from(buffer.into_vec())
+    }
+}
+
+impl From<Blob> for Bytes {
+    fn from(blob: Blob) -> Self {
+        blob.0
+    }
+}
+
+impl From<Blob> for Vec<u8> {
+    fn from(blob: Blob) -> Self {
+        blob.into_vec()
+    }
+}
+
+impl From<Blob> for ByteBuffer {
+    fn from(blob: Blob) -> Self {
+        ByteBuffer::from_vec(blob.into_vec())
+    }
+}
+
+// ============================================================================
+// Serde
+// ============================================================================
+
+impl SerializeWithSchema for Blob {
+    #[inline]
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.write_blob(schema, self)
+    }
+}
+
+impl<'de> DeserializeWithSchema<'de> for Blob {
+    #[inline]
+    fn deserialize_with_schema<D>(schema: &Schema, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.read_blob(schema)
+    }
+}
+
+impl ErrorCorrectionDefault for Blob {
+    fn default() -> Self {
+        Blob::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_owned_buffers_without_copying() {
+        let data = vec![1, 2, 3];
+        let ptr = data.as_ptr();
+        let blob = Blob::from(data);
+        let shared = blob.clone();
+        assert_eq!(shared.as_ptr(), ptr);
+        drop(shared);
+        let data = blob.into_vec();
+        assert_eq!(data.as_ptr(), ptr);
+
+        let buffer = ByteBuffer::from_vec(vec![4, 5, 6]);
+        let ptr = buffer.as_bytes().as_ptr();
+        let blob = Blob::from(buffer);
+        assert_eq!(blob.as_ptr(), ptr);
+        let buffer = ByteBuffer::from(blob);
+        assert_eq!(buffer.as_bytes().as_ptr(), ptr);
+    }
+
+    #[test]
+    fn copies_shared_data_into_vec() {
+        let blob = Blob::from(vec![1, 2, 3]);
+        let shared = blob.clone();
+        assert_eq!(blob.into_vec(), vec![1, 2, 3]);
+        assert_eq!(shared, [1, 2, 3][..]);
+    }
+
+    #[test]
+    fn compares_with_slices() {
+        assert_eq!(Blob::from_static(b"abc"), b"abc"[..]);
+        assert_eq!(Blob::copy_from_slice(b"abc"), Blob::from_bytes("abc"));
+        assert!(Blob::new().is_empty());
+    }
+}
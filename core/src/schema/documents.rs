@@ -1,15 +1,15 @@
-use std::{error::Error, fmt::Debug, sync::LazyLock};
+use std::{borrow::Cow, error::Error, fmt::Debug, sync::LazyLock};
 
 use thiserror::Error;
 
 use crate::{
     BigDecimal, BigInt, ByteBuffer, IndexMap, Instant,
     schema::{
-        SchemaShape, ShapeId, ShapeType,
+        Blob, Schema, SchemaShape, ShapeId, ShapeType,
         default::{Number, Value},
         prelude::*,
     },
-    serde::validation::ValidationErrors,
+    serde::{Buildable, CodecError, ShapeBuilder, ShapeRegistry, validation::ValidationErrors},
     smithy,
 };
 // ============================================================================
@@ -90,10 +90,10 @@ use crate::{
 /// }
 /// ```
 ///
-/// `Display`-ing such a structure should result in the `@sensitive` field being redacted:
+/// `Debug`-ing such a structure should result in the `@sensitive` field being redacted:
 /// ```rust,ignore
-/// let myStructInstance = MyStruct::builder().password("Secret".to_string()).build()?;
-/// assert_eq!("MyStruct[password:**REDACTED**]", format!("{myStructInstance}"))
+/// let myStructInstance = MyStructRedacted::builder().password("Secret").build()?;
+/// assert_eq!("MyStructRedacted { password: **REDACTED** }", format!("{myStructInstance:?}"))
 /// ```
 ///
 /// We do _not_ want conversion to a document to suddenly start leaking a sensitive field.
@@ -155,9 +155,11 @@ use crate::{
 /// We deserialize the JSON data to a Document, extract the discriminator, and use it to:
 ///
 /// ```rust,ignore
-/// let error_document = Document::deserialize(json_deserializer)?;
-/// let builder = error_document.get_builder(error_document.discriminator());
-/// let output = builder.deserialize(error_document)?.build();
+/// let registry = ShapeRegistry::new()
+///     .with(MyOperationError::MyClientError)
+///     .with(MyOperationError::MyServerError);
+/// let error_document = Box::<dyn Document>::deserialize(json_deserializer)?;
+/// let output: MyOperationError = error_document.deserialize_dynamic(&registry)?;
 /// ```
 ///
 /// The initial `error_document` has no schema information (beyond the base `Document` schema), so it does not perform any protocol-specific
@@ -204,9 +206,9 @@ pub trait Document: SchemaShape + Send + Sync {
     /// Get the `blob` value of the Document if it is a `blob`.
     ///
     /// Returns `None` if the document could not be converted to a
-    /// `blob` ([`ByteBuffer`]) value.
+    /// `blob` ([`Blob`]) value.
     #[must_use]
-    fn as_blob(&self) -> Option<&ByteBuffer>;
+    fn as_blob(&self) -> Option<&Blob>;
 
     /// Get the `boolean` value of the Document if it is a `boolean` or can be converted into one.
     ///
@@ -273,17 +275,34 @@ pub trait Document: SchemaShape + Send + Sync {
 
     /// Get the `bigInteger` value of the Document if it is a `bigInteger` or can be converted into one.
     ///
+    /// Integral values are widened to a `bigInteger`, so the value is only
+    /// borrowed if the document holds a `bigInteger`.
+    ///
     /// Returns `None` if the document could not be converted to
     /// `bigInteger` ([`BigInt`]) value.
     #[must_use]
-    fn as_big_integer(&self) -> Option<&BigInt>;
+    fn as_big_integer(&self) -> Option<Cow<'_, BigInt>>;
 
     /// Get the `bigDecimal` value of the Document if it is a `bigDecimal` or can be converted into one.
     ///
+    /// Integral and finite floating point values are widened to a `bigDecimal`
+    /// without loss of precision, so the value is only borrowed if the document
+    /// holds a `bigDecimal`.
+    ///
     /// Returns `None` if the document could not be converted to
     /// `bigDecimal` ([`BigDecimal`]) value.
     #[must_use]
-    fn as_big_decimal(&self) -> Option<&BigDecimal>;
+    fn as_big_decimal(&self) -> Option<Cow<'_, BigDecimal>>;
+
+    /// Get the original text of a numeric Document, if the codec that created it preserved it.
+    ///
+    /// Codecs may keep the exact literal a number was read from (including negative zero,
+    /// exponent notation, and trailing zeros) so that the value can be re-serialized
+    /// byte-for-byte. Returns `None` by default.
+    #[must_use]
+    fn as_number_literal(&self) -> Option<&str> {
+        None
+    }
 
     /// Get the list contents of the Document if it is a list.
     ///
@@ -307,8 +326,8 @@ pub trait Document: SchemaShape + Send + Sync {
     ///
     /// # Errors
     /// Returns a [`DocumentError`] if the document could not be converted to a
-    /// `blob` ([`ByteBuffer`]) value.
-    fn into_blob(self: Box<Self>) -> Result<ByteBuffer, DocumentError>;
+    /// `blob` ([`Blob`]) value.
+    fn into_blob(self: Box<Self>) -> Result<Blob, DocumentError>;
 
     /// Convert the [`Document`] to a `boolean`.
     ///
@@ -407,48 +426,138 @@ pub trait Document: SchemaShape + Send + Sync {
 // Blanket impl for dyn Documents
 // ================================================================
 
+/// Documents are equal if they hold equal values with the same schema.
+///
+/// Map (and structure) entries are compared by key, regardless of the order they
+/// were inserted in. Use [`eq_ordered`](#method.eq_ordered) to also compare the
+/// order of entries.
 impl PartialEq for dyn Document {
     fn eq(&self, other: &Self) -> bool {
-        if self.schema() != other.schema() {
-            return false;
-        }
-        if self.discriminator() != other.discriminator() {
-            return false;
+        documents_eq(self, other, false)
+    }
+}
+
+fn documents_eq(left: &dyn Document, right: &dyn Document, ordered: bool) -> bool {
+    if left.discriminator() != right.discriminator() {
+        return false;
+    }
+    // Numbers are compared by value, so documents of different numeric types
+    // (and therefore different schemas) can still be equal.
+    if let (Some(left_kind), Some(right_kind)) = (
+        NumericKind::of(left.get_type()),
+        NumericKind::of(right.get_type()),
+    ) {
+        return numeric_eq(left, left_kind, right, right_kind);
+    }
+    if left.schema() != right.schema() {
+        return false;
+    }
+    match (left.get_type(), right.get_type()) {
+        (Some(ShapeType::String), Some(ShapeType::String)) => left.as_string() == right.as_string(),
+        (Some(ShapeType::Blob), Some(ShapeType::Blob)) => left.as_blob() == right.as_blob(),
+        (Some(ShapeType::Boolean), Some(ShapeType::Boolean)) => left.as_bool() == right.as_bool(),
+        (Some(ShapeType::Timestamp), Some(ShapeType::Timestamp)) => {
+            left.as_timestamp() == right.as_timestamp()
         }
-        // TODO(numerical comparison): We should compare compatible numeric types
-        // if possible. This is just a placeholder to get test working.
-        match (self.get_type(), other.get_type()) {
-            (Some(ShapeType::String), Some(ShapeType::String)) => {
-                self.as_string() == other.as_string()
-            }
-            (Some(ShapeType::Blob), Some(ShapeType::Blob)) => self.as_blob() == other.as_blob(),
-            (Some(ShapeType::Boolean), Some(ShapeType::Boolean)) => {
-                self.as_bool() == other.as_bool()
+        (Some(ShapeType::List), Some(ShapeType::List)) => match (left.as_list(), right.as_list()) {
+            (Some(left), Some(right)) => {
+                left.len() == right.len()
+                    && left
+                        .iter()
+                        .zip(right)
+                        .all(|(left, right)| documents_eq(left.as_ref(), right.as_ref(), ordered))
             }
-            (Some(ShapeType::Timestamp), Some(ShapeType::Timestamp)) => {
-                self.as_timestamp() == other.as_timestamp()
-            }
-            (Some(ShapeType::Byte), Some(ShapeType::Byte)) => self.as_byte() == other.as_byte(),
-            (Some(ShapeType::Short), Some(ShapeType::Short)) => self.as_short() == other.as_short(),
-            (Some(ShapeType::Integer), Some(ShapeType::Integer)) => {
-                self.as_integer() == other.as_integer()
-            }
-            (Some(ShapeType::Long), Some(ShapeType::Long)) => self.as_long() == other.as_long(),
-            (Some(ShapeType::Float), Some(ShapeType::Float)) => self.as_float() == other.as_float(),
-            (Some(ShapeType::Double), Some(ShapeType::Double)) => {
-                self.as_double() == other.as_double()
-            }
-            (Some(ShapeType::BigInteger), Some(ShapeType::BigInteger)) => {
-                self.as_big_integer() == other.as_big_integer()
+            _ => false,
+        },
+        (
+            Some(ShapeType::Map | ShapeType::Structure | ShapeType::Union),
+            Some(ShapeType::Map | ShapeType::Structure | ShapeType::Union),
+        ) => match (left.as_map(), right.as_map()) {
+            (Some(left), Some(right)) if ordered => {
+                left.len() == right.len()
+                    && left.iter().zip(right).all(|((lk, lv), (rk, rv))| {
+                        lk == rk && documents_eq(lv.as_ref(), rv.as_ref(), ordered)
+                    })
             }
-            (Some(ShapeType::BigDecimal), Some(ShapeType::BigDecimal)) => {
-                self.as_big_decimal() == other.as_big_decimal()
+            (Some(left), Some(right)) => {
+                left.len() == right.len()
+                    && left.iter().all(|(key, value)| {
+                        right.get(key).is_some_and(|other| {
+                            documents_eq(value.as_ref(), other.as_ref(), ordered)
+                        })
+                    })
             }
-            (Some(ShapeType::List), Some(ShapeType::List)) => self.as_list() == other.as_list(),
-            (Some(ShapeType::Map), Some(ShapeType::Map)) => self.as_map() == other.as_map(),
-            (None, None) => true,
             _ => false,
+        },
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Groups numeric shape types by how their values can be compared.
+#[derive(Clone, Copy)]
+enum NumericKind {
+    /// `byte`, `short`, `integer`, and `long` (all losslessly widen to `i64`)
+    Integral,
+    /// `float` and `double` (all losslessly widen to `f64`)
+    Floating,
+    BigInteger,
+    BigDecimal,
+}
+
+impl NumericKind {
+    fn of(shape_type: Option<&ShapeType>) -> Option<Self> {
+        match shape_type? {
+            ShapeType::Byte | ShapeType::Short | ShapeType::Integer | ShapeType::Long => {
+                Some(NumericKind::Integral)
+            }
+            ShapeType::Float | ShapeType::Double => Some(NumericKind::Floating),
+            ShapeType::BigInteger => Some(NumericKind::BigInteger),
+            ShapeType::BigDecimal => Some(NumericKind::BigDecimal),
+            _ => None,
+        }
+    }
+}
+
+/// Compare two numeric documents by their exact mathematical value.
+///
+/// Floating point values are only equal to an integral or arbitrary-precision value
+/// if they represent it exactly (i.e. `Double(0.1)` does not equal `BigDecimal("0.1")`).
+fn numeric_eq(
+    left: &dyn Document,
+    left_kind: NumericKind,
+    right: &dyn Document,
+    right_kind: NumericKind,
+) -> bool {
+    match (left_kind, right_kind) {
+        (NumericKind::Integral, NumericKind::Integral) => left.as_long() == right.as_long(),
+        (NumericKind::Floating, NumericKind::Floating) => left.as_double() == right.as_double(),
+        (NumericKind::BigInteger, NumericKind::BigInteger) => {
+            left.as_big_integer() == right.as_big_integer()
         }
+        _ => match (
+            exact_decimal(left, left_kind),
+            exact_decimal(right, right_kind),
+        ) {
+            (Some(left), Some(right)) => left == right,
+            _ => false,
+        },
+    }
+}
+
+/// Convert a numeric document to a [`BigDecimal`] without any loss of precision.
+///
+/// Returns `None` for non-finite floating point values.
+fn exact_decimal(document: &dyn Document, kind: NumericKind) -> Option<BigDecimal> {
+    match kind {
+        NumericKind::Integral => document.as_long().map(BigDecimal::from),
+        NumericKind::Floating => document
+            .as_double()
+            .and_then(|value| <BigDecimal as TryFrom<f64>>::try_from(value).ok()),
+        NumericKind::BigInteger => document
+            .as_big_integer()
+            .map(|value| BigDecimal::from(value.into_owned())),
+        NumericKind::BigDecimal => document.as_big_decimal().map(Cow::into_owned),
     }
 }
 
@@ -474,13 +583,19 @@ pub enum DocumentError {
     DocumentConversion(String),
     /// An unknown error
     #[error("Encountered unknown error")]
-    Unknown(#[from] Box<dyn Error>),
+    Unknown(#[from] Box<dyn Error + Send + Sync>),
     /// Encountered validation error while deserializing document
     #[error("Invalid Document")]
     Invalid(#[from] ValidationErrors),
     /// A custom error
     #[error("Encountered error: {0}")]
     CustomError(String),
+    /// Document has no discriminator to dispatch on
+    #[error("Document has no discriminator")]
+    MissingDiscriminator,
+    /// No shape is registered for the document discriminator
+    #[error("No shape registered for discriminator {0}")]
+    UnknownDiscriminator(String),
 }
 
 impl crate::serde::de::Error for DocumentError {
@@ -489,16 +604,32 @@ impl crate::serde::de::Error for DocumentError {
     }
 }
 
+impl From<DocumentError> for CodecError {
+    fn from(value: DocumentError) -> Self {
+        match value {
+            DocumentError::Invalid(errors) => CodecError::Validation(errors),
+            DocumentError::CustomError(msg) => CodecError::Custom(msg),
+            DocumentError::Unknown(_) => CodecError::Custom(value.to_string()),
+            DocumentError::DocumentSerialization(_)
+            | DocumentError::DocumentConversion(_)
+            | DocumentError::MissingDiscriminator
+            | DocumentError::UnknownDiscriminator(_) => CodecError::type_mismatch("", value),
+        }
+    }
+}
+
 // ============================================================================
 // Default Document Implementation
 // ============================================================================
 
 pub(crate) mod default {
+    use std::borrow::Cow;
+
     use bigdecimal::ToPrimitive;
 
     use crate::{
-        BigDecimal, BigInt, ByteBuffer, IndexMap, Instant,
-        schema::{DocumentError, Schema, SchemaShape, ShapeId, ShapeType},
+        BigDecimal, BigInt, IndexMap, Instant,
+        schema::{Blob, DocumentError, Schema, SchemaShape, ShapeId, ShapeType},
     };
 
     #[derive(Clone, PartialEq, Debug)]
@@ -519,7 +650,7 @@ pub(crate) mod default {
         Null,
         Number(Number),
         Boolean(bool),
-        Blob(ByteBuffer),
+        Blob(Blob),
         String(String),
         Timestamp(Instant),
         List(Vec<Box<dyn super::Document>>),
@@ -569,7 +700,7 @@ pub(crate) mod default {
             }
         }
 
-        fn as_blob(&self) -> Option<&ByteBuffer> {
+        fn as_blob(&self) -> Option<&Blob> {
             if let Value::Blob(b) = &self.value {
                 Some(b)
             } else {
@@ -681,12 +812,34 @@ pub(crate) mod default {
             }
         }
 
-        fn as_big_integer(&self) -> Option<&BigInt> {
-            todo!()
+        fn as_big_integer(&self) -> Option<Cow<'_, BigInt>> {
+            match &self.value {
+                Value::Number(n) => match n {
+                    &Number::Byte(b) => Some(Cow::Owned(b.into())),
+                    &Number::Short(s) => Some(Cow::Owned(s.into())),
+                    &Number::Integer(i) => Some(Cow::Owned(i.into())),
+                    &Number::Long(l) => Some(Cow::Owned(l.into())),
+                    Number::BigInt(b) => Some(Cow::Borrowed(b)),
+                    _ => None,
+                },
+                _ => None,
+            }
         }
 
-        fn as_big_decimal(&self) -> Option<&BigDecimal> {
-            todo!()
+        fn as_big_decimal(&self) -> Option<Cow<'_, BigDecimal>> {
+            match &self.value {
+                Value::Number(n) => match n {
+                    &Number::Byte(b) => Some(Cow::Owned(b.into())),
+                    &Number::Short(s) => Some(Cow::Owned(s.into())),
+                    &Number::Integer(i) => Some(Cow::Owned(i.into())),
+                    &Number::Long(l) => Some(Cow::Owned(l.into())),
+                    Number::BigInt(b) => Some(Cow::Owned(b.clone().into())),
+                    &Number::Float(f) => BigDecimal::try_from(f).ok().map(Cow::Owned),
+                    &Number::Double(d) => BigDecimal::try_from(d).ok().map(Cow::Owned),
+                    Number::BigDecimal(b) => Some(Cow::Borrowed(b)),
+                },
+                _ => None,
+            }
         }
 
         fn as_list(&self) -> Option<&Vec<Box<dyn super::Document>>> {
@@ -709,7 +862,7 @@ pub(crate) mod default {
             matches!(self.value, Value::Null)
         }
 
-        fn into_blob(self: Box<Self>) -> Result<ByteBuffer, DocumentError> {
+        fn into_blob(self: Box<Self>) -> Result<Blob, DocumentError> {
             if let Value::Blob(value) = self.value {
                 Ok(value)
             } else {
@@ -786,17 +939,23 @@ pub(crate) mod default {
             })
         }
 
-        // TODO(numeric conversion): These shouldnt need to clone.
         fn into_big_integer(self: Box<Self>) -> Result<BigInt, DocumentError> {
-            self.as_big_integer().cloned().ok_or_else(|| {
-                DocumentError::DocumentConversion("Expected bigInteger document".to_string())
-            })
+            match self.value {
+                Value::Number(Number::BigInt(value)) => Ok(value),
+                _ => self.as_big_integer().map(Cow::into_owned).ok_or_else(|| {
+                    DocumentError::DocumentConversion("Expected bigInteger document".to_string())
+                }),
+            }
         }
 
         fn into_big_decimal(self: Box<Self>) -> Result<BigDecimal, DocumentError> {
-            self.as_big_decimal().cloned().ok_or_else(|| {
-                DocumentError::DocumentConversion("Expected bigInteger document".to_string())
-            })
+            match self.value {
+                Value::Number(Number::BigDecimal(value)) => Ok(value),
+                Value::Number(Number::BigInt(value)) => Ok(value.into()),
+                _ => self.as_big_decimal().map(Cow::into_owned).ok_or_else(|| {
+                    DocumentError::DocumentConversion("Expected bigDecimal document".to_string())
+                }),
+            }
         }
 
         fn into_list(self: Box<Self>) -> Result<Vec<Box<dyn super::Document>>, DocumentError> {
@@ -977,9 +1136,9 @@ impl From<BigDecimal> for Box<dyn Document> {
     }
 }
 
-impl From<ByteBuffer> for Box<dyn Document> {
+impl From<Blob> for Box<dyn Document> {
     #[inline]
-    fn from(value: ByteBuffer) -> Self {
+    fn from(value: Blob) -> Self {
         default::Document {
             schema: BLOB.clone(),
             value: Value::Blob(value),
@@ -989,6 +1148,13 @@ impl From<ByteBuffer> for Box<dyn Document> {
     }
 }
 
+impl From<ByteBuffer> for Box<dyn Document> {
+    #[inline]
+    fn from(value: ByteBuffer) -> Self {
+        Blob::from(value).into()
+    }
+}
+
 impl From<String> for Box<dyn Document> {
     #[inline]
     fn from(value: String) -> Self {
@@ -1091,6 +1257,7 @@ macro_rules! option_conversion {
 option_conversion!(String, STRING);
 option_conversion!(bool, BOOLEAN);
 option_conversion!(Instant, TIMESTAMP);
+option_conversion!(Blob, BLOB);
 option_conversion!(ByteBuffer, BLOB);
 option_conversion!(i8, BYTE);
 option_conversion!(i16, SHORT);
@@ -1185,13 +1352,20 @@ impl TryFromDocument for BigDecimal {
     }
 }
 
-impl TryFromDocument for ByteBuffer {
+impl TryFromDocument for Blob {
     #[inline]
     fn try_from(document: Box<dyn Document>) -> Result<Self, DocumentError> {
         document.into_blob()
     }
 }
 
+impl TryFromDocument for ByteBuffer {
+    #[inline]
+    fn try_from(document: Box<dyn Document>) -> Result<Self, DocumentError> {
+        document.into_blob().map(ByteBuffer::from)
+    }
+}
+
 impl TryFromDocument for String {
     #[inline]
     fn try_from(document: Box<dyn Document>) -> Result<Self, DocumentError> {
@@ -1250,6 +1424,155 @@ impl dyn Document {
     pub fn try_into<S: TryFromDocument>(self: Box<Self>) -> Result<S, DocumentError> {
         <S as TryFromDocument>::try_from(self)
     }
+
+    /// Deserialize a document into the builder for shape `S` and then build the
+    /// final shape, validating it with the [`DefaultValidator`](crate::serde::validation::DefaultValidator).
+    ///
+    /// ```rust,ignore
+    /// let shape: MyStruct = document.deserialize_into()?;
+    /// ```
+    ///
+    /// ## Errors
+    /// Returns [`DocumentError`] if the document could not be deserialized into
+    /// the builder or if the built shape is invalid.
+    pub fn deserialize_into<S, B>(self: Box<Self>) -> Result<S, DocumentError>
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: TryFromDocument + for<'de> ShapeBuilder<'de, S>,
+    {
+        Ok(self.try_into::<B>()?.build()?)
+    }
+
+    /// Deserialize a document into the shape identified by its discriminator,
+    /// using a [`ShapeRegistry`] to find the builder for that shape.
+    ///
+    /// ## Errors
+    /// Returns [`DocumentError::MissingDiscriminator`] if the document has no
+    /// discriminator, [`DocumentError::UnknownDiscriminator`] if no shape is
+    /// registered for the discriminator, or any error encountered while
+    /// deserializing the shape.
+    pub fn deserialize_dynamic<T>(
+        self: Box<Self>,
+        registry: &ShapeRegistry<T>,
+    ) -> Result<T, DocumentError> {
+        let id = self
+            .discriminator()
+            .cloned()
+            .ok_or(DocumentError::MissingDiscriminator)?;
+        registry.deserialize(&id, self)
+    }
+
+    /// Compare two documents, requiring map (and structure) entries to also be in the same order.
+    ///
+    /// The `==` operator ignores the order of map entries.
+    #[must_use]
+    pub fn eq_ordered(&self, other: &dyn Document) -> bool {
+        documents_eq(self, other, true)
+    }
+
+    /// Create a copy of this document with the entries of every map sorted by key.
+    ///
+    /// Maps are serialized in the order of their entries, so this can be used to
+    /// get a stable output regardless of the order values were inserted in (for
+    /// example when signing or comparing serialized documents). Structures always
+    /// serialize their members in schema order.
+    #[must_use]
+    pub fn sort_keys(&self) -> Box<dyn Document> {
+        self.transform(|_, value| {
+            let Some(map) = value.as_map() else {
+                return value;
+            };
+            let mut map = map.clone();
+            map.sort_unstable_keys();
+            default::Document {
+                schema: value.schema().clone(),
+                value: Value::Map(map),
+                discriminator: value.discriminator().cloned(),
+            }
+            .into()
+        })
+    }
+
+    /// Create a new document by mapping every value in this document with `f`.
+    ///
+    /// `f` is called once for each value, children before their parents, with the
+    /// schema of the value and a copy of the value (with its children already
+    /// transformed). Values nested in structures, lists and maps are passed the
+    /// member schema they were found under, so traits applied to members can be
+    /// used to guide the transformation:
+    ///
+    /// ```rust,ignore
+    /// let redacted = document.transform(|schema, value| {
+    ///     if schema.contains_type::<SensitiveTrait>() {
+    ///         "*** SENSITIVE ***".into()
+    ///     } else {
+    ///         value
+    ///     }
+    /// });
+    /// ```
+    #[must_use]
+    pub fn transform<F>(&self, mut f: F) -> Box<dyn Document>
+    where
+        F: FnMut(&Schema, Box<dyn Document>) -> Box<dyn Document>,
+    {
+        transform_document(self, self.schema(), &mut f)
+    }
+}
+
+type TransformFn<'a> = dyn FnMut(&Schema, Box<dyn Document>) -> Box<dyn Document> + 'a;
+
+fn transform_document(
+    document: &dyn Document,
+    schema: &Schema,
+    f: &mut TransformFn<'_>,
+) -> Box<dyn Document> {
+    // Generic document members carry no useful information, so fall back to
+    // the schema of the nested value itself.
+    let nested_schema = |member: Option<&Schema>, value: &dyn Document| -> Schema {
+        member
+            .filter(|member| member.shape_type() != &ShapeType::Document)
+            .unwrap_or_else(|| value.schema())
+            .clone()
+    };
+    let value = if let Some(list) = document.as_list() {
+        let member = schema.get_list_member();
+        let elements = list
+            .iter()
+            .map(|element| {
+                let element_schema = nested_schema(member, element.as_ref());
+                transform_document(element.as_ref(), &element_schema, f)
+            })
+            .collect();
+        Some(Value::List(elements))
+    } else if let Some(map) = document.as_map() {
+        let value_member = schema.get_key_value().map(|(_, value)| value);
+        let entries = map
+            .iter()
+            .map(|(key, value)| {
+                let member = value_member.or_else(|| schema.get_member(key));
+                let value_schema = nested_schema(member, value.as_ref());
+                (
+                    key.clone(),
+                    transform_document(value.as_ref(), &value_schema, f),
+                )
+            })
+            .collect();
+        Some(Value::Map(entries))
+    } else {
+        None
+    };
+    let copy = value.map_or_else(
+        || document.box_clone(),
+        |value| {
+            default::Document {
+                schema: document.schema().clone(),
+                value,
+                discriminator: document.discriminator().cloned(),
+            }
+            .into()
+        },
+    );
+    f(schema, copy)
 }
 
 // =========================================================================
@@ -1351,4 +1674,234 @@ mod tests {
         let double_value: f64 = double.try_into().unwrap();
         assert_eq!(double_value, 1f64);
     }
+
+    #[test]
+    fn integral_documents_compare_across_widths() {
+        let byte: Box<dyn Document> = 1i8.into();
+        let integer: Box<dyn Document> = 1i32.into();
+        let long: Box<dyn Document> = 1i64.into();
+        assert!(*byte == *integer);
+        assert!(*integer == *long);
+
+        let other: Box<dyn Document> = 2i16.into();
+        assert!(*byte != *other);
+    }
+
+    #[test]
+    fn floating_documents_compare_exactly() {
+        let float: Box<dyn Document> = 1.5f32.into();
+        let double: Box<dyn Document> = 1.5f64.into();
+        assert!(*float == *double);
+
+        // `0.1f32` widens to `0.100000001490116...` and is not exactly `0.1f64`
+        let float: Box<dyn Document> = 0.1f32.into();
+        let double: Box<dyn Document> = 0.1f64.into();
+        assert!(*float != *double);
+
+        let nan: Box<dyn Document> = f64::NAN.into();
+        assert!(*nan != *nan.clone());
+    }
+
+    #[test]
+    fn integral_and_floating_documents_compare() {
+        let integer: Box<dyn Document> = 3i32.into();
+        let double: Box<dyn Document> = 3.0f64.into();
+        assert!(*integer == *double);
+
+        let fractional: Box<dyn Document> = 3.5f64.into();
+        assert!(*integer != *fractional);
+    }
+
+    #[test]
+    fn big_number_documents_widen_smaller_values() {
+        let long: Box<dyn Document> = 42i64.into();
+        assert_eq!(long.as_big_integer().as_deref(), Some(&BigInt::from(42)));
+        assert_eq!(
+            long.as_big_decimal().as_deref(),
+            Some(&BigDecimal::from(42))
+        );
+
+        let double: Box<dyn Document> = 0.5f64.into();
+        assert_eq!(double.as_big_integer(), None);
+        let half: BigDecimal = "0.5".parse().unwrap();
+        assert_eq!(double.as_big_decimal().as_deref(), Some(&half));
+
+        let nan: Box<dyn Document> = f32::NAN.into();
+        assert_eq!(nan.as_big_decimal(), None);
+
+        let big_int: Box<dyn Document> = BigInt::from(7).into();
+        assert!(matches!(big_int.as_big_integer(), Some(Cow::Borrowed(_))));
+        assert_eq!(big_int.into_big_decimal().unwrap(), BigDecimal::from(7));
+
+        let string: Box<dyn Document> = "42".into();
+        assert_eq!(string.as_big_integer(), None);
+        assert!(string.into_big_integer().is_err());
+    }
+
+    #[test]
+    fn big_number_documents_convert_into_owned_values() {
+        let byte: Box<dyn Document> = 3i8.into();
+        assert_eq!(byte.into_big_integer().unwrap(), BigInt::from(3));
+
+        let float: Box<dyn Document> = 1.25f32.into();
+        let expected: BigDecimal = "1.25".parse().unwrap();
+        assert_eq!(float.into_big_decimal().unwrap(), expected);
+
+        let big_decimal: Box<dyn Document> = expected.clone().into();
+        assert_eq!(big_decimal.into_big_decimal().unwrap(), expected);
+    }
+
+    #[test]
+    fn big_number_documents_compare() {
+        let big_int: Box<dyn Document> = BigInt::from(42).into();
+        let long: Box<dyn Document> = 42i64.into();
+        let big_decimal: Box<dyn Document> = BigDecimal::from(42).into();
+        assert!(*big_int == *long);
+        assert!(*big_int == *big_decimal);
+
+        let half: Box<dyn Document> = "0.5".parse::<BigDecimal>().unwrap().into();
+        let double: Box<dyn Document> = 0.5f64.into();
+        assert!(*half == *double);
+
+        // `0.1` is not exactly representable as a double
+        let tenth: Box<dyn Document> = "0.1".parse::<BigDecimal>().unwrap().into();
+        let double: Box<dyn Document> = 0.1f64.into();
+        assert!(*tenth != *double);
+    }
+
+    #[test]
+    fn numeric_documents_do_not_equal_other_types() {
+        let integer: Box<dyn Document> = 1i32.into();
+        let string: Box<dyn Document> = "1".into();
+        let boolean: Box<dyn Document> = true.into();
+        assert!(*integer != *string);
+        assert!(*integer != *boolean);
+    }
+
+    #[test]
+    fn list_documents_compare_numeric_elements() {
+        let ints: Box<dyn Document> = vec![1i8, 2i8].into();
+        let longs: Box<dyn Document> = vec![1i64, 2i64].into();
+        assert!(*ints == *longs);
+    }
+
+    smithy!("com.example#Credentials": {
+        structure CREDENTIALS {
+            USERNAME: STRING = "username"
+            @SensitiveTrait::builder().build();
+            PASSWORD: STRING = "password"
+        }
+    });
+
+    #[test]
+    fn transform_redacts_sensitive_members() {
+        let mut members: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+        members.insert("username".to_string(), "user".into());
+        members.insert("password".to_string(), "hunter2".into());
+        let document: Box<dyn Document> = default::Document {
+            schema: CREDENTIALS.clone(),
+            value: Value::Map(members),
+            discriminator: Some(CREDENTIALS.id().clone()),
+        }
+        .into();
+        let redacted = document.transform(|schema, value| {
+            if schema.contains_type::<SensitiveTrait>() {
+                "***".into()
+            } else {
+                value
+            }
+        });
+        assert_eq!(redacted.schema(), &*CREDENTIALS);
+        let map = redacted.as_map().unwrap();
+        assert_eq!(map["username"].as_string(), Some("user"));
+        assert_eq!(map["password"].as_string(), Some("***"));
+        // The original document is unchanged
+        assert_eq!(
+            document.as_map().unwrap()["password"].as_string(),
+            Some("hunter2")
+        );
+    }
+
+    #[test]
+    fn transform_visits_nested_values() {
+        let mut inner: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+        inner.insert("short".to_string(), "abc".into());
+        inner.insert("long".to_string(), "abcdefgh".into());
+        inner.insert("count".to_string(), 12i32.into());
+        let document: Box<dyn Document> = vec![inner].into();
+
+        let mut visited = 0;
+        let truncated = document.transform(|_, value| {
+            visited += 1;
+            match value.as_string() {
+                Some(s) if s.len() > 4 => s[..4].into(),
+                _ => value,
+            }
+        });
+        // Three map values, the map, and the list
+        assert_eq!(visited, 5);
+        let map = &truncated.as_list().unwrap()[0].as_map().unwrap();
+        assert_eq!(map["short"].as_string(), Some("abc"));
+        assert_eq!(map["long"].as_string(), Some("abcd"));
+        assert_eq!(map["count"].as_integer(), Some(12));
+    }
+
+    fn map_document(entries: &[(&str, i32)]) -> Box<dyn Document> {
+        entries
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), *value))
+            .collect::<IndexMap<_, _>>()
+            .into()
+    }
+
+    #[test]
+    fn map_documents_compare_regardless_of_order() {
+        let left = map_document(&[("a", 1), ("b", 2)]);
+        let right = map_document(&[("b", 2), ("a", 1)]);
+        assert!(*left == *right);
+        assert!(!left.eq_ordered(right.as_ref()));
+        assert!(left.eq_ordered(left.clone().as_ref()));
+        assert!(*left != *map_document(&[("a", 1), ("b", 3)]));
+        assert!(*left != *map_document(&[("a", 1)]));
+    }
+
+    #[test]
+    fn structure_documents_compare() {
+        let mut members: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+        members.insert("username".to_string(), "user".into());
+        let document: Box<dyn Document> = default::Document {
+            schema: CREDENTIALS.clone(),
+            value: Value::Map(members),
+            discriminator: None,
+        }
+        .into();
+        assert!(*document == *document.clone());
+        assert!(*document != *map_document(&[("username", 1)]));
+    }
+
+    #[test]
+    fn sort_keys_sorts_nested_maps() {
+        let mut outer: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+        outer.insert("z".to_string(), map_document(&[("b", 2), ("a", 1)]));
+        outer.insert(
+            "y".to_string(),
+            vec![map_document(&[("d", 4), ("c", 3)])].into(),
+        );
+        let document: Box<dyn Document> = outer.into();
+
+        let sorted = document.sort_keys();
+        assert!(*sorted == *document);
+        let map = sorted.as_map().unwrap();
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec!["y", "z"]);
+        assert_eq!(
+            map["z"].as_map().unwrap().keys().collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+        let nested = &map["y"].as_list().unwrap()[0];
+        assert_eq!(
+            nested.as_map().unwrap().keys().collect::<Vec<_>>(),
+            vec!["c", "d"]
+        );
+        assert_eq!(sorted.schema(), document.schema());
+    }
 }
@@ -0,0 +1,444 @@
+//! # Smithy IDL Printer
+//! Renders a [`Schema`] as [Smithy IDL](https://smithy.io/2.0/spec/idl.html) source.
+//!
+//! This is primarily useful for inspecting schemas that were built at runtime
+//! (i.e. with the [`loader`](crate::schema::loader)) and for tooling that needs to
+//! write a model back out as text.
+//!
+//! ```rust,ignore
+//! let index = SchemaIndex::load(&*model)?;
+//! println!("{}", schema_to_idl(index.get(&"com.example#MyStruct".into()).unwrap()));
+//! ```
+//!
+//! The printed model contains the schema and every shape it (transitively) targets in
+//! the same namespace. Shapes from the prelude or from other namespaces are referenced,
+//! but not defined, as a single IDL file can only define shapes in one namespace.
+//!
+//! <div class ="note">
+//! **NOTE**: Schemas do not retain the member names of `enum` and `intEnum` shapes,
+//! so enum members are named after their values (i.e. `"in-progress"` is printed as
+//! `IN_PROGRESS = "in-progress"`).
+//! </div>
+
+use std::fmt::{self, Write};
+
+use crate::{
+    FxIndexSet,
+    schema::{Document, Schema, ShapeId, ShapeType, Timestamp, TraitMap},
+};
+
+const PRELUDE_NAMESPACE: &str = "smithy.api";
+const INDENT: &str = "    ";
+
+/// Render a [`Schema`] and the shapes it targets as Smithy IDL.
+///
+/// Traits are rendered from their [`Document`](crate::schema::SmithyTrait::value) values.
+/// Traits with no document representation are rendered as annotation traits.
+///
+/// If a member schema is provided, its target is rendered instead.
+#[must_use]
+pub fn schema_to_idl(schema: &Schema) -> String {
+    let root = schema.as_member().map_or(schema, |member| member.target());
+    let namespace = root.id().namespace();
+    let mut writer = IdlWriter {
+        namespace,
+        out: String::new(),
+    };
+    writer
+        .write_model(&collect_shapes(root, namespace))
+        .expect("Writing to a String is infallible");
+    writer.out
+}
+
+/// Collect all shapes in `namespace` that are reachable from `root`, in the order
+/// they are first encountered.
+fn collect_shapes<'a>(root: &'a Schema, namespace: &str) -> Vec<&'a Schema> {
+    let mut seen = FxIndexSet::default();
+    seen.insert(root.id().clone());
+    let mut shapes = vec![root];
+    let mut next = 0;
+    while let Some(shape) = shapes.get(next).copied() {
+        next += 1;
+        for member in members(shape) {
+            let Some(target) = member.as_member().map(|m| m.target()) else {
+                continue;
+            };
+            if target.id().namespace() == namespace && seen.insert(target.id().clone()) {
+                shapes.push(target);
+            }
+        }
+    }
+    shapes
+}
+
+/// Get the member schemas of a shape in definition order.
+fn members(schema: &Schema) -> Vec<&Schema> {
+    match schema.shape_type() {
+        ShapeType::List => schema.get_list_member().into_iter().collect(),
+        ShapeType::Map => schema
+            .get_key_value()
+            .map(|(key, value)| vec![key, value])
+            .unwrap_or_default(),
+        _ => schema.members().values().collect(),
+    }
+}
+
+struct IdlWriter<'a> {
+    namespace: &'a str,
+    out: String,
+}
+
+impl IdlWriter<'_> {
+    fn write_model(&mut self, shapes: &[&Schema]) -> fmt::Result {
+        writeln!(self.out, "$version: \"2\"")?;
+        writeln!(self.out)?;
+        writeln!(self.out, "namespace {}", self.namespace)?;
+        for shape in shapes {
+            writeln!(self.out)?;
+            self.write_shape(shape)?;
+        }
+        Ok(())
+    }
+
+    fn write_shape(&mut self, schema: &Schema) -> fmt::Result {
+        self.write_traits(schema.direct_traits(), "")?;
+        let name = schema.id().name();
+        let keyword = keyword(schema.shape_type());
+        match schema.shape_type() {
+            ShapeType::Structure | ShapeType::Union | ShapeType::List | ShapeType::Map => {
+                let members = members(schema);
+                if members.is_empty() {
+                    return writeln!(self.out, "{keyword} {name} {{}}");
+                }
+                writeln!(self.out, "{keyword} {name} {{")?;
+                for member in members {
+                    self.write_member(member)?;
+                }
+                writeln!(self.out, "}}")
+            }
+            ShapeType::Enum => {
+                writeln!(self.out, "{keyword} {name} {{")?;
+                for value in schema
+                    .as_enum()
+                    .map(|e| e.values().iter())
+                    .into_iter()
+                    .flatten()
+                {
+                    write!(self.out, "{INDENT}{} = ", enum_member_name(value))?;
+                    write_string(&mut self.out, value)?;
+                    writeln!(self.out)?;
+                }
+                writeln!(self.out, "}}")
+            }
+            ShapeType::IntEnum => {
+                writeln!(self.out, "{keyword} {name} {{")?;
+                for value in schema
+                    .as_int_enum()
+                    .map(|e| e.values().iter())
+                    .into_iter()
+                    .flatten()
+                {
+                    let member = if *value < 0 {
+                        format!("VALUE_NEG_{}", value.unsigned_abs())
+                    } else {
+                        format!("VALUE_{value}")
+                    };
+                    writeln!(self.out, "{INDENT}{member} = {value}")?;
+                }
+                writeln!(self.out, "}}")
+            }
+            ShapeType::Service | ShapeType::Resource | ShapeType::Operation => {
+                writeln!(self.out, "{keyword} {name} {{}}")
+            }
+            _ => writeln!(self.out, "{keyword} {name}"),
+        }
+    }
+
+    fn write_member(&mut self, schema: &Schema) -> fmt::Result {
+        let Some(member) = schema.as_member() else {
+            return Ok(());
+        };
+        self.write_traits(schema.direct_traits(), INDENT)?;
+        let target = self.reference(member.target().id());
+        writeln!(self.out, "{INDENT}{}: {target}", member.name())
+    }
+
+    fn write_traits(&mut self, traits: &TraitMap, indent: &str) -> fmt::Result {
+        for smithy_trait in traits.iter() {
+            let id = self.reference(smithy_trait.id());
+            write!(self.out, "{indent}@{id}")?;
+            match smithy_trait.value() {
+                // Annotation traits
+                None => {}
+                Some(value) if value.as_map().is_some_and(|m| m.is_empty()) => {}
+                Some(value) => {
+                    self.out.write_char('(')?;
+                    if let Some(map) = value.as_map() {
+                        self.write_entries(map.iter())?;
+                    } else {
+                        self.write_node(&*value)?;
+                    }
+                    self.out.write_char(')')?;
+                }
+            }
+            writeln!(self.out)?;
+        }
+        Ok(())
+    }
+
+    /// Write a [node value](https://smithy.io/2.0/spec/idl.html#node-values).
+    fn write_node(&mut self, value: &dyn Document) -> fmt::Result {
+        if let Some(map) = value.as_map() {
+            self.out.write_char('{')?;
+            self.write_entries(map.iter())?;
+            return self.out.write_char('}');
+        }
+        if let Some(list) = value.as_list() {
+            self.out.write_char('[')?;
+            for (index, item) in list.iter().enumerate() {
+                if index > 0 {
+                    self.out.write_str(", ")?;
+                }
+                self.write_node(&**item)?;
+            }
+            return self.out.write_char(']');
+        }
+        if let Some(string) = value.as_string() {
+            return write_string(&mut self.out, string);
+        }
+        if let Some(boolean) = value.as_bool() {
+            return write!(self.out, "{boolean}");
+        }
+        if let Some(literal) = value.as_number_literal() {
+            return self.out.write_str(literal);
+        }
+        if let Some(long) = value.as_long() {
+            return write!(self.out, "{long}");
+        }
+        if let Some(big_integer) = value.as_big_integer() {
+            return write!(self.out, "{big_integer}");
+        }
+        if let Some(double) = value.as_double().filter(|d| d.is_finite()) {
+            return write!(self.out, "{double}");
+        }
+        if let Some(big_decimal) = value.as_big_decimal() {
+            return write!(self.out, "{big_decimal}");
+        }
+        if let Some(timestamp) = value.as_timestamp() {
+            return write_string(&mut self.out, &Timestamp::from(*timestamp).to_string());
+        }
+        if let Some(blob) = value.as_blob() {
+            return write_string(&mut self.out, &String::from_utf8_lossy(blob.as_bytes()));
+        }
+        self.out.write_str("null")
+    }
+
+    fn write_entries<'d>(
+        &mut self,
+        entries: impl Iterator<Item = (&'d String, &'d Box<dyn Document>)>,
+    ) -> fmt::Result {
+        for (index, (key, value)) in entries.enumerate() {
+            if index > 0 {
+                self.out.write_str(", ")?;
+            }
+            if is_identifier(key) {
+                self.out.write_str(key)?;
+            } else {
+                write_string(&mut self.out, key)?;
+            }
+            self.out.write_str(": ")?;
+            self.write_node(&**value)?;
+        }
+        Ok(())
+    }
+
+    /// Get the relative name of a shape if it can be referenced without a namespace.
+    fn reference<'s>(&self, id: &'s ShapeId) -> &'s str {
+        let namespace = id.namespace();
+        if namespace == self.namespace || namespace == PRELUDE_NAMESPACE {
+            id.name()
+        } else {
+            id.id()
+        }
+    }
+}
+
+const fn keyword(shape_type: &ShapeType) -> &'static str {
+    match shape_type {
+        ShapeType::Blob => "blob",
+        ShapeType::Boolean => "boolean",
+        ShapeType::String => "string",
+        ShapeType::Timestamp => "timestamp",
+        ShapeType::Byte => "byte",
+        ShapeType::Short => "short",
+        ShapeType::Integer => "integer",
+        ShapeType::Long => "long",
+        ShapeType::Float => "float",
+        ShapeType::Double => "double",
+        ShapeType::BigInteger => "bigInteger",
+        ShapeType::BigDecimal => "bigDecimal",
+        ShapeType::Document => "document",
+        ShapeType::Enum => "enum",
+        ShapeType::IntEnum => "intEnum",
+        ShapeType::List => "list",
+        ShapeType::Map => "map",
+        ShapeType::Structure => "structure",
+        ShapeType::Union => "union",
+        ShapeType::Member => "member",
+        ShapeType::Service => "service",
+        ShapeType::Resource => "resource",
+        ShapeType::Operation => "operation",
+    }
+}
+
+/// Derive an enum member name from its value.
+fn enum_member_name(value: &str) -> String {
+    let mut name: String = value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.insert(0, '_');
+    }
+    name
+}
+
+fn is_identifier(value: &str) -> bool {
+    value.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn write_string(out: &mut String, value: &str) -> fmt::Result {
+    out.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '\n' => out.write_str("\\n")?,
+            '\r' => out.write_str("\\r")?,
+            '\t' => out.write_str("\\t")?,
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.write_char(c)?,
+        }
+    }
+    out.write_char('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        doc_map,
+        schema::{
+            DynamicTrait,
+            prelude::{INTEGER, LengthTrait, RequiredTrait, STRING},
+        },
+        smithy,
+    };
+
+    smithy!("com.example#Name": {
+        @LengthTrait::builder().min(1).max(64).build();
+        string NAME
+    });
+    smithy!("com.example#Status": {
+        enum STATUS {
+            InProgress = "in-progress"
+            Done = "done"
+        }
+    });
+    smithy!("com.example#Priority": {
+        intEnum PRIORITY {
+            Low = 1
+            High = -2
+        }
+    });
+    smithy!("com.example#Tags": {
+        list TAGS {
+            member: STRING
+        }
+    });
+    smithy!("com.example#Scores": {
+        map SCORES {
+            key: STRING
+            value: INTEGER
+        }
+    });
+    smithy!("com.example#Task": {
+        @DynamicTrait::from("smithy.api#documentation", "A \"task\"");
+        @DynamicTrait::from("com.other#tags", vec!["a", "b"]);
+        structure TASK {
+            @RequiredTrait::builder().build();
+            NAME: NAME = "name"
+            STATUS: STATUS = "status"
+            PRIORITY: PRIORITY = "priority"
+            TAGS: TAGS = "tags"
+            SCORES: SCORES = "scores"
+            @DynamicTrait::from("com.example#meta", doc_map!["kebab-key" => 1, "flag" => true]);
+            PARENT: (@self) = "parent"
+        }
+    });
+
+    #[test]
+    fn renders_shapes_in_namespace() {
+        let expected = r#"$version: "2"
+
+namespace com.example
+
+@com.other#tags(["a", "b"])
+@documentation("A \"task\"")
+structure Task {
+    @required
+    name: Name
+    status: Status
+    priority: Priority
+    tags: Tags
+    scores: Scores
+    @meta("kebab-key": 1, flag: true)
+    parent: Task
+}
+
+@length(min: 1, max: 64)
+string Name
+
+enum Status {
+    IN_PROGRESS = "in-progress"
+    DONE = "done"
+}
+
+intEnum Priority {
+    VALUE_1 = 1
+    VALUE_NEG_2 = -2
+}
+
+list Tags {
+    member: String
+}
+
+map Scores {
+    key: String
+    value: Integer
+}
+"#;
+        assert_eq!(schema_to_idl(&TASK), expected);
+    }
+
+    #[test]
+    fn renders_member_target() {
+        assert_eq!(
+            schema_to_idl(TASK.expect_member("tags")),
+            schema_to_idl(&TAGS)
+        );
+    }
+
+    #[test]
+    fn derives_enum_member_names() {
+        assert_eq!(enum_member_name("in-progress"), "IN_PROGRESS");
+        assert_eq!(enum_member_name("2xx"), "_2XX");
+    }
+}
@@ -0,0 +1,380 @@
+//! # Schema Loader
+//! Builds [`Schema`]s at runtime from a [Smithy JSON AST](https://smithy.io/2.0/spec/json-ast.html)
+//! model.
+//!
+//! Schemas are usually compile-time statics generated from a model. Dynamic tooling
+//! (i.e. proxies, model explorers, or document-oriented clients) may instead need to
+//! load a model that is only known at runtime.
+//!
+//! The loader is protocol-agnostic and operates on a [`Document`] representation of the
+//! JSON AST, so the model file must first be parsed with a JSON codec:
+//!
+//! ```rust,ignore
+//! let mut de = JsonDeserializer::new(model_json.as_bytes());
+//! let model = Box::<dyn Document>::deserialize_with_schema(&DOCUMENT, &mut de)?;
+//! let index = SchemaIndex::load(&*model)?;
+//! let schema = index.get(&"com.example#MyStruct".into());
+//! ```
+//!
+//! All traits in the model are attached as [`DynamicTrait`]s, which can still
+//! be accessed as their concrete implementations with [`SchemaValue::get_trait_as`](crate::schema::SchemaValue::get_trait_as).
+//!
+//! <div class ="note">
+//! **NOTE**: `enum` values must be `'static` and are interned for the lifetime of the program.
+//! </div>
+
+use std::collections::HashMap;
+
+use static_str_ops::staticize;
+use thiserror::Error;
+
+use crate::{
+    FxIndexMap, IndexMap, Ref,
+    schema::{Document, DynamicTrait, Schema, SchemaBuilder, ShapeId, TraitRef, UNIT, prelude::*},
+};
+
+const ENUM_VALUE: &str = "smithy.api#enumValue";
+
+/// Errors that can occur when loading a [`SchemaIndex`] from a model.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ModelError {
+    /// The model does not match the structure of a Smithy JSON AST.
+    #[error("Invalid model: {0}")]
+    InvalidModel(String),
+    /// A shape has an unrecognized `type`.
+    #[error("Unsupported type `{shape_type}` for shape `{id}`")]
+    UnsupportedType {
+        /// ID of the shape
+        id: String,
+        /// Type found in the model
+        shape_type: String,
+    },
+    /// A member targets a shape that is neither in the model nor in the prelude.
+    #[error("Member `{member}` targets unknown shape `{target}`")]
+    UnresolvedTarget {
+        /// ID of the member
+        member: String,
+        /// ID of the missing target
+        target: String,
+    },
+}
+
+/// Index of [`Schema`]s loaded from a model, keyed by [`ShapeId`].
+#[derive(Debug, Default)]
+pub struct SchemaIndex {
+    schemas: FxIndexMap<ShapeId, Schema>,
+}
+
+impl SchemaIndex {
+    /// Load all shapes from a Smithy JSON AST model.
+    ///
+    /// Member targets may reference shapes in the model (including recursive references)
+    /// or shapes from the Smithy prelude (i.e. `smithy.api#String`).
+    ///
+    /// # Errors
+    /// Returns a [`ModelError`] if the document is not a valid JSON AST model or a
+    /// member targets an unknown shape.
+    pub fn load(model: &dyn Document) -> Result<Self, ModelError> {
+        let shapes = model
+            .as_map()
+            .and_then(|m| m.get("shapes"))
+            .and_then(|s| s.as_map())
+            .ok_or_else(|| ModelError::InvalidModel("expected a `shapes` object".to_string()))?;
+
+        // First pass: create every shape so members can reference shapes in any order.
+        let mut pending: HashMap<&str, PendingShape<'_>> = HashMap::with_capacity(shapes.len());
+        for (id, shape) in shapes {
+            let shape_id = parse_id(id)?;
+            let shape_type = get_str(shape.as_ref(), "type", id)?;
+            let traits = load_traits(shape.as_ref(), id)?;
+            let loaded = match shape_type {
+                "blob" => Loaded::Schema(Schema::create_blob(shape_id, traits)),
+                "boolean" => Loaded::Schema(Schema::create_boolean(shape_id, traits)),
+                "string" => Loaded::Schema(Schema::create_string(shape_id, traits)),
+                "timestamp" => Loaded::Schema(Schema::create_timestamp(shape_id, traits)),
+                "byte" => Loaded::Schema(Schema::create_byte(shape_id, traits)),
+                "short" => Loaded::Schema(Schema::create_short(shape_id, traits)),
+                "integer" => Loaded::Schema(Schema::create_integer(shape_id, traits)),
+                "long" => Loaded::Schema(Schema::create_long(shape_id, traits)),
+                "float" => Loaded::Schema(Schema::create_float(shape_id, traits)),
+                "double" => Loaded::Schema(Schema::create_double(shape_id, traits)),
+                "bigInteger" => Loaded::Schema(Schema::create_big_integer(shape_id, traits)),
+                "bigDecimal" => Loaded::Schema(Schema::create_big_decimal(shape_id, traits)),
+                "document" => Loaded::Schema(Schema::create_document(shape_id, traits)),
+                "service" => Loaded::Schema(Schema::create_service(shape_id, traits)),
+                "operation" => Loaded::Schema(Schema::create_operation(shape_id, traits)),
+                "resource" => Loaded::Schema(Schema::create_resource(shape_id, traits)),
+                "enum" => Loaded::Schema(Schema::create_enum(
+                    shape_id,
+                    enum_values(shape.as_ref(), id, |name, value| {
+                        value
+                            .map_or_else(|| Some(staticize(name)), |v| v.as_string().map(staticize))
+                    })?,
+                    traits,
+                )),
+                "intEnum" => Loaded::Schema(Schema::create_int_enum(
+                    shape_id,
+                    enum_values(shape.as_ref(), id, |_, value| value?.as_integer())?,
+                    traits,
+                )),
+                "structure" => {
+                    Loaded::Builder(Ref::new(Schema::structure_builder(shape_id, traits)))
+                }
+                "union" => Loaded::Builder(Ref::new(Schema::union_builder(shape_id, traits))),
+                "list" | "set" => Loaded::Builder(Ref::new(Schema::list_builder(shape_id, traits))),
+                "map" => Loaded::Builder(Ref::new(Schema::map_builder(shape_id, traits))),
+                // Trait applications are already merged into shapes by Smithy build tooling.
+                "apply" => continue,
+                other => {
+                    return Err(ModelError::UnsupportedType {
+                        id: id.clone(),
+                        shape_type: other.to_string(),
+                    });
+                }
+            };
+            pending.insert(
+                id,
+                PendingShape {
+                    shape: shape.as_ref(),
+                    loaded,
+                },
+            );
+        }
+
+        // Second pass: attach members to aggregate shapes.
+        for (id, pending_shape) in &pending {
+            let Loaded::Builder(builder) = &pending_shape.loaded else {
+                continue;
+            };
+            for (name, member) in members(pending_shape.shape, id)? {
+                let member_id = format!("{id}${name}");
+                let target = get_str(member, "target", &member_id)?;
+                let traits = load_traits(member, &member_id)?;
+                let _ = match pending.get(target).map(|p| &p.loaded) {
+                    Some(Loaded::Schema(schema)) => builder.put_member(name, schema, traits),
+                    Some(Loaded::Builder(target_builder)) => {
+                        builder.put_member(name, target_builder, traits)
+                    }
+                    None => {
+                        let prelude =
+                            prelude_schema(target).ok_or_else(|| ModelError::UnresolvedTarget {
+                                member: member_id.clone(),
+                                target: target.to_string(),
+                            })?;
+                        builder.put_member(name, prelude, traits)
+                    }
+                };
+            }
+        }
+
+        // Preserve the order shapes were defined in the model
+        let mut schemas = FxIndexMap::with_capacity_and_hasher(pending.len(), Default::default());
+        for id in shapes.keys() {
+            let Some(pending_shape) = pending.get(id.as_str()) else {
+                continue;
+            };
+            let schema = match &pending_shape.loaded {
+                Loaded::Schema(schema) => schema.clone(),
+                Loaded::Builder(builder) => builder.build(),
+            };
+            schemas.insert(schema.id().clone(), schema);
+        }
+        Ok(SchemaIndex { schemas })
+    }
+
+    /// Get the [`Schema`] for a shape by its ID.
+    #[must_use]
+    pub fn get(&self, id: &ShapeId) -> Option<&Schema> {
+        self.schemas.get(id)
+    }
+
+    /// Returns true if the index contains a shape with the given ID.
+    #[must_use]
+    pub fn contains(&self, id: &ShapeId) -> bool {
+        self.schemas.contains_key(id)
+    }
+
+    /// Number of shapes in the index.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.schemas.len()
+    }
+
+    /// Returns true if the index contains no shapes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.schemas.is_empty()
+    }
+
+    /// Iterate over all loaded schemas in model definition order.
+    pub fn iter(&self) -> impl Iterator<Item = (&ShapeId, &Schema)> {
+        self.schemas.iter()
+    }
+}
+
+struct PendingShape<'a> {
+    shape: &'a dyn Document,
+    loaded: Loaded,
+}
+
+enum Loaded {
+    Schema(Schema),
+    Builder(Ref<SchemaBuilder>),
+}
+
+fn parse_id(id: &str) -> Result<ShapeId, ModelError> {
+    if id.contains('#') {
+        Ok(ShapeId::from(id))
+    } else {
+        Err(ModelError::InvalidModel(format!(
+            "`{id}` is not an absolute shape ID"
+        )))
+    }
+}
+
+fn get_str<'a>(node: &'a dyn Document, key: &str, id: &str) -> Result<&'a str, ModelError> {
+    node.as_map()
+        .and_then(|m| m.get(key))
+        .and_then(|v| v.as_string())
+        .ok_or_else(|| ModelError::InvalidModel(format!("`{id}` is missing string `{key}`")))
+}
+
+fn members<'a>(
+    shape: &'a dyn Document,
+    id: &str,
+) -> Result<Vec<(&'a str, &'a dyn Document)>, ModelError> {
+    let Some(map) = shape.as_map() else {
+        return Err(ModelError::InvalidModel(format!("`{id}` is not an object")));
+    };
+    let mut output = Vec::new();
+    // List and map members are top-level properties in the JSON AST
+    for name in ["member", "key", "value"] {
+        if let Some(member) = map.get(name) {
+            output.push((name, member.as_ref()));
+        }
+    }
+    if let Some(members) = map.get("members") {
+        let members = members
+            .as_map()
+            .ok_or_else(|| ModelError::InvalidModel(format!("`{id}` members is not an object")))?;
+        output.extend(members.iter().map(|(k, v)| (k.as_str(), v.as_ref())));
+    }
+    Ok(output)
+}
+
+fn load_traits(node: &dyn Document, id: &str) -> Result<Vec<TraitRef>, ModelError> {
+    let Some(traits) = node.as_map().and_then(|m| m.get("traits")) else {
+        return Ok(Vec::new());
+    };
+    let traits = traits
+        .as_map()
+        .ok_or_else(|| ModelError::InvalidModel(format!("`{id}` traits is not an object")))?;
+    traits
+        .iter()
+        .map(|(trait_id, value)| Ok(DynamicTrait::from(parse_id(trait_id)?, value.clone())))
+        .collect()
+}
+
+fn enum_values<T>(
+    shape: &dyn Document,
+    id: &str,
+    value_of: impl Fn(&str, Option<&Box<dyn Document>>) -> Option<T>,
+) -> Result<Box<[T]>, ModelError> {
+    members(shape, id)?
+        .into_iter()
+        .map(|(name, member)| {
+            let value = member
+                .as_map()
+                .and_then(|m| m.get("traits"))
+                .and_then(|t| t.as_map())
+                .and_then(|t: &IndexMap<String, Box<dyn Document>>| t.get(ENUM_VALUE));
+            value_of(name, value).ok_or_else(|| {
+                ModelError::InvalidModel(format!("`{id}${name}` has an invalid enum value"))
+            })
+        })
+        .collect()
+}
+
+fn prelude_schema(id: &str) -> Option<&'static Schema> {
+    let schema: &'static Schema = match id {
+        "smithy.api#Blob" => &BLOB,
+        "smithy.api#Boolean" => &BOOLEAN,
+        "smithy.api#PrimitiveBoolean" => &PRIMITIVE_BOOLEAN,
+        "smithy.api#String" => &STRING,
+        "smithy.api#Timestamp" => &TIMESTAMP,
+        "smithy.api#Byte" => &BYTE,
+        "smithy.api#PrimitiveByte" => &PRIMITIVE_BYTE,
+        "smithy.api#Short" => &SHORT,
+        "smithy.api#PrimitiveShort" => &PRIMITIVE_SHORT,
+        "smithy.api#Integer" => &INTEGER,
+        "smithy.api#PrimitiveInteger" => &PRIMITIVE_INTEGER,
+        "smithy.api#Long" => &LONG,
+        "smithy.api#PrimitiveLong" => &PRIMITIVE_LONG,
+        "smithy.api#Float" => &FLOAT,
+        "smithy.api#PrimitiveFloat" => &PRIMITIVE_FLOAT,
+        "smithy.api#Double" => &DOUBLE,
+        "smithy.api#PrimitiveDouble" => &PRIMITIVE_DOUBLE,
+        "smithy.api#BigInteger" => &BIG_INTEGER,
+        "smithy.api#BigDecimal" => &BIG_DECIMAL,
+        "smithy.api#Document" => &DOCUMENT,
+        "smithy.api#Unit" => &UNIT,
+        _ => return None,
+    };
+    Some(schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::ShapeType;
+
+    fn shape(shape_type: &str) -> Box<dyn Document> {
+        let mut map: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+        map.insert("type".to_string(), shape_type.into());
+        map.into()
+    }
+
+    fn model(shapes: IndexMap<String, Box<dyn Document>>) -> Box<dyn Document> {
+        let mut map: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+        map.insert("smithy".to_string(), "2.0".into());
+        map.insert("shapes".to_string(), shapes.into());
+        map.into()
+    }
+
+    #[test]
+    fn loads_simple_shapes() {
+        let mut shapes = IndexMap::new();
+        shapes.insert("com.example#MyString".to_string(), shape("string"));
+        shapes.insert("com.example#MyLong".to_string(), shape("long"));
+        let index = SchemaIndex::load(&*model(shapes)).unwrap();
+
+        assert_eq!(index.len(), 2);
+        let schema = index.get(&"com.example#MyString".into()).unwrap();
+        assert_eq!(schema.shape_type(), &ShapeType::String);
+        let schema = index.get(&"com.example#MyLong".into()).unwrap();
+        assert_eq!(schema.shape_type(), &ShapeType::Long);
+    }
+
+    #[test]
+    fn rejects_unknown_shape_type() {
+        let mut shapes = IndexMap::new();
+        shapes.insert("com.example#Shape".to_string(), shape("widget"));
+        let err = SchemaIndex::load(&*model(shapes)).unwrap_err();
+        assert_eq!(
+            err,
+            ModelError::UnsupportedType {
+                id: "com.example#Shape".to_string(),
+                shape_type: "widget".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_missing_shapes() {
+        let empty: Box<dyn Document> = IndexMap::<String, Box<dyn Document>>::new().into();
+        assert!(matches!(
+            SchemaIndex::load(&*empty),
+            Err(ModelError::InvalidModel(_))
+        ));
+    }
+}
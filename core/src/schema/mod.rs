@@ -14,6 +14,32 @@ pub use shapes::*;
 mod schemas;
 pub use schemas::*;
 
+mod aliases;
+pub use aliases::*;
+
+mod operations;
+pub use operations::*;
+
+mod blob;
+pub use blob::*;
+
+mod streaming;
+pub use streaming::*;
+
+mod paginators;
+pub use paginators::*;
+
+mod path;
+pub use path::*;
+
+mod timestamp;
+pub use timestamp::*;
+
+pub mod loader;
+
+mod idl;
+pub use idl::schema_to_idl;
+
 mod unit;
 
 // Do not include the unit trait as it can remain private.
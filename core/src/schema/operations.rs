@@ -0,0 +1,277 @@
+use std::{fmt::Debug, marker::PhantomData, sync::LazyLock};
+
+use crate::schema::{Schema, ShapeId, StaticSchemaShape};
+
+// ============================================================================
+// Operation
+// ============================================================================
+
+/// Typed descriptor for a Smithy [Operation](https://smithy.io/2.0/spec/service-types.html#operation) shape.
+///
+/// An operation descriptor ties together the operation schema with the
+/// shapes used for its input, output, and errors:
+/// * `I` - Operation input shape.
+/// * `O` - Operation output shape.
+/// * `E` - Union of all errors the operation can return.
+///
+/// Operations with no modeled input, output, or errors use [`Unit`](crate::schema::Unit)
+/// for the corresponding type.
+///
+/// ```rust, ignore
+/// smithy!("com.example#GetItem": {
+///     operation GET_ITEM_SCHEMA
+/// });
+///
+/// pub static GET_ITEM: Operation<GetItemInput, GetItemOutput, GetItemError> =
+///     Operation::new(&GET_ITEM_SCHEMA);
+/// ```
+pub struct Operation<I, O, E> {
+    schema: &'static LazyLock<Schema>,
+    _shapes: PhantomData<OperationShapes<I, O, E>>,
+}
+
+// `fn() -> T` keeps the descriptor `Send + Sync` regardless of `T`
+type OperationShapes<I, O, E> = fn() -> (I, O, E);
+
+impl<I, O, E> Operation<I, O, E> {
+    /// Create a new operation descriptor from a static operation schema.
+    #[must_use]
+    pub const fn new(schema: &'static LazyLock<Schema>) -> Self {
+        Operation {
+            schema,
+            _shapes: PhantomData,
+        }
+    }
+
+    /// Get the schema of the operation.
+    #[inline]
+    #[must_use]
+    pub fn schema(&self) -> &'static Schema {
+        self.schema
+    }
+
+    /// Get the [`ShapeId`] of the operation.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> &'static ShapeId {
+        self.schema().id()
+    }
+}
+
+impl<I: StaticSchemaShape, O, E> Operation<I, O, E> {
+    /// Get the schema of the operation input.
+    #[inline]
+    #[must_use]
+    pub fn input_schema(&self) -> &'static Schema {
+        I::schema()
+    }
+}
+
+impl<I, O: StaticSchemaShape, E> Operation<I, O, E> {
+    /// Get the schema of the operation output.
+    #[inline]
+    #[must_use]
+    pub fn output_schema(&self) -> &'static Schema {
+        O::schema()
+    }
+}
+
+impl<I, O, E: StaticSchemaShape> Operation<I, O, E> {
+    /// Get the schema of the union of operation errors.
+    #[inline]
+    #[must_use]
+    pub fn error_schema(&self) -> &'static Schema {
+        E::schema()
+    }
+}
+
+impl<I, O, E> Debug for Operation<I, O, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Operation").field(self.id()).finish()
+    }
+}
+
+// ============================================================================
+// Service
+// ============================================================================
+
+/// Descriptor for a Smithy [Service](https://smithy.io/2.0/spec/service-types.html#service) shape.
+///
+/// The service descriptor holds the service schema along with the schemas
+/// of every operation bound to the service (including operations bound via resources).
+///
+/// ```rust, ignore
+/// smithy!("com.example#ItemService": {
+///     service ITEM_SERVICE_SCHEMA
+/// });
+///
+/// pub static ITEM_SERVICE: Service = Service::new(
+///     &ITEM_SERVICE_SCHEMA,
+///     &[&GET_ITEM_SCHEMA, &PUT_ITEM_SCHEMA]
+/// );
+/// ```
+pub struct Service {
+    schema: &'static LazyLock<Schema>,
+    operations: &'static [&'static LazyLock<Schema>],
+}
+
+impl Service {
+    /// Create a new service descriptor from a static service schema and its operation schemas.
+    #[must_use]
+    pub const fn new(
+        schema: &'static LazyLock<Schema>,
+        operations: &'static [&'static LazyLock<Schema>],
+    ) -> Self {
+        Service { schema, operations }
+    }
+
+    /// Get the schema of the service.
+    #[inline]
+    #[must_use]
+    pub fn schema(&self) -> &'static Schema {
+        self.schema
+    }
+
+    /// Get the [`ShapeId`] of the service.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> &'static ShapeId {
+        self.schema().id()
+    }
+
+    /// Iterate over the schemas of all operations bound to the service.
+    pub fn operations(&self) -> impl Iterator<Item = &'static Schema> {
+        self.operations.iter().map(|schema| &***schema)
+    }
+
+    /// Get the schema of a bound operation by its [`ShapeId`].
+    ///
+    /// Returns `None` if no such operation is bound to the service.
+    #[must_use]
+    pub fn get_operation(&self, id: &ShapeId) -> Option<&'static Schema> {
+        self.operations().find(|schema| schema.id() == id)
+    }
+}
+
+impl Debug for Service {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Service")
+            .field("id", self.id())
+            .field(
+                "operations",
+                &self
+                    .operations()
+                    .map(|schema| schema.id())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        derive::{SchemaShape, SmithyShape},
+        prelude::{ErrorTrait, STRING},
+        schema::{ShapeType, Unit},
+        smithy,
+    };
+
+    smithy!("com.example#GetItemInput": {
+        structure GET_ITEM_INPUT_SCHEMA {
+            ID: STRING = "id"
+        }
+    });
+    smithy!("com.example#GetItemOutput": {
+        structure GET_ITEM_OUTPUT_SCHEMA {
+            NAME: STRING = "name"
+        }
+    });
+    smithy!("com.example#NotFound": {
+        @ErrorTrait::Client;
+        structure NOT_FOUND_SCHEMA {
+            MESSAGE: STRING = "message"
+        }
+    });
+    smithy!("smithy.synthetic#GetItemError": {
+        union GET_ITEM_ERROR_SCHEMA {
+            NOT_FOUND: NOT_FOUND_SCHEMA = "NotFound"
+        }
+    });
+    smithy!("com.example#GetItem": {
+        operation GET_ITEM_SCHEMA
+    });
+    smithy!("com.example#Ping": {
+        operation PING_SCHEMA
+    });
+    smithy!("com.example#ItemService": {
+        service ITEM_SERVICE_SCHEMA
+    });
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(GET_ITEM_INPUT_SCHEMA)]
+    pub struct GetItemInput {
+        #[smithy_schema(ID)]
+        id: String,
+    }
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(GET_ITEM_OUTPUT_SCHEMA)]
+    pub struct GetItemOutput {
+        #[smithy_schema(NAME)]
+        name: String,
+    }
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(NOT_FOUND_SCHEMA)]
+    pub struct NotFound {
+        #[smithy_schema(MESSAGE)]
+        message: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(SchemaShape)]
+    #[smithy_schema(GET_ITEM_ERROR_SCHEMA)]
+    pub enum GetItemError {
+        #[smithy_schema(NOT_FOUND)]
+        NotFound(NotFound),
+    }
+
+    static GET_ITEM: Operation<GetItemInput, GetItemOutput, GetItemError> =
+        Operation::new(&GET_ITEM_SCHEMA);
+    static PING: Operation<Unit, Unit, Unit> = Operation::new(&PING_SCHEMA);
+    static ITEM_SERVICE: Service =
+        Service::new(&ITEM_SERVICE_SCHEMA, &[&GET_ITEM_SCHEMA, &PING_SCHEMA]);
+
+    #[test]
+    fn operation_exposes_schemas() {
+        assert_eq!(GET_ITEM.schema().shape_type(), &ShapeType::Operation);
+        assert_eq!(GET_ITEM.id(), &ShapeId::from("com.example#GetItem"));
+        assert_eq!(GET_ITEM.input_schema(), &*GET_ITEM_INPUT_SCHEMA);
+        assert_eq!(GET_ITEM.output_schema(), &*GET_ITEM_OUTPUT_SCHEMA);
+        assert_eq!(GET_ITEM.error_schema(), &*GET_ITEM_ERROR_SCHEMA);
+    }
+
+    #[test]
+    fn operation_with_unit_shapes() {
+        assert_eq!(PING.input_schema().id(), &ShapeId::from("smithy.api#Unit"));
+        assert_eq!(PING.output_schema().id(), &ShapeId::from("smithy.api#Unit"));
+        assert_eq!(PING.error_schema().id(), &ShapeId::from("smithy.api#Unit"));
+    }
+
+    #[test]
+    fn service_exposes_operations() {
+        assert_eq!(ITEM_SERVICE.schema().shape_type(), &ShapeType::Service);
+        assert_eq!(ITEM_SERVICE.operations().count(), 2);
+        assert_eq!(
+            ITEM_SERVICE.get_operation(&ShapeId::from("com.example#Ping")),
+            Some(PING.schema())
+        );
+        assert!(
+            ITEM_SERVICE
+                .get_operation(&ShapeId::from("com.example#Missing"))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,633 @@
+use std::{
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+
+use crate::{
+    IndexMap,
+    prelude::PaginatedTrait,
+    schema::{
+        Document, DocumentError, Operation, Schema, StaticSchemaShape, TryFromDocument,
+        documents::default::{Document as DocumentImpl, Value},
+    },
+    serde::{DocumentParser, se::SerializeWithSchema},
+};
+
+// ============================================================================
+// Pagination
+// ============================================================================
+
+/// Pagination settings of an operation, read from its [`@paginated`](https://smithy.io/2.0/spec/behavior-traits.html#paginated-trait) trait.
+///
+/// `output_token` and `items` may be paths to nested output members
+/// (for example `result.nextToken`).
+///
+/// <div class ="note">
+/// **NOTE**: Code generation merges the `@paginated` trait of a service into the traits of its
+/// operations, so the operation schema always holds the complete pagination settings.
+/// </div>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pagination {
+    input_token: String,
+    output_token: String,
+    items: Option<String>,
+    page_size: Option<String>,
+}
+
+impl Pagination {
+    /// Read the pagination settings from an operation schema.
+    ///
+    /// Returns `None` if the operation has no `@paginated` trait, or the trait
+    /// does not define both an input and output token.
+    #[must_use]
+    pub fn of(operation: &Schema) -> Option<Self> {
+        let paginated = operation.get_trait_as::<PaginatedTrait>()?;
+        Some(Pagination {
+            input_token: paginated.input_token.clone()?,
+            output_token: paginated.output_token.clone()?,
+            items: paginated.items.clone(),
+            page_size: paginated.page_size.clone(),
+        })
+    }
+
+    /// Name of the input member that holds the continuation token.
+    #[must_use]
+    pub fn input_token(&self) -> &str {
+        &self.input_token
+    }
+
+    /// Path to the output member that holds the continuation token.
+    #[must_use]
+    pub fn output_token(&self) -> &str {
+        &self.output_token
+    }
+
+    /// Path to the output member that holds the paginated items, if any.
+    #[must_use]
+    pub fn items(&self) -> Option<&str> {
+        self.items.as_deref()
+    }
+
+    /// Name of the input member that limits the number of items per page, if any.
+    #[must_use]
+    pub fn page_size(&self) -> Option<&str> {
+        self.page_size.as_deref()
+    }
+
+    /// Get the continuation token of a page of output.
+    ///
+    /// Returns `None` if the token is unset or empty, indicating that there are no more pages.
+    ///
+    /// # Errors
+    /// Returns [`DocumentError`] if the output could not be converted to a document.
+    pub fn next_token<O>(&self, output: &O) -> Result<Option<String>, DocumentError>
+    where
+        O: StaticSchemaShape + SerializeWithSchema,
+    {
+        let document = output.serialize_with_schema(O::schema(), DocumentParser)?;
+        Ok(get_path(document.as_ref(), &self.output_token)
+            .and_then(|token| token.as_string())
+            .filter(|token| !token.is_empty())
+            .map(str::to_string))
+    }
+
+    /// Get the items of a page of output.
+    ///
+    /// Items of a list member are returned in order. Values of a map member
+    /// are returned in the order of their keys.
+    ///
+    /// # Errors
+    /// Returns [`DocumentError`] if the output could not be converted to a document
+    /// or an item could not be converted into `T`.
+    pub fn page_items<O, T>(&self, output: &O) -> Result<Vec<T>, DocumentError>
+    where
+        O: StaticSchemaShape + SerializeWithSchema,
+        T: TryFromDocument,
+    {
+        let Some(items) = &self.items else {
+            return Ok(Vec::new());
+        };
+        let document = output.serialize_with_schema(O::schema(), DocumentParser)?;
+        let Some(items) = get_path(document.as_ref(), items) else {
+            return Ok(Vec::new());
+        };
+        let values: Vec<Box<dyn Document>> = items.as_list().map_or_else(
+            || {
+                items
+                    .as_map()
+                    .map(|map| map.values().cloned().collect())
+                    .unwrap_or_default()
+            },
+            Clone::clone,
+        );
+        values.into_iter().map(|item| item.try_into()).collect()
+    }
+
+    fn with_member<I>(input: &I, member: &str, value: Box<dyn Document>) -> Result<I, DocumentError>
+    where
+        I: StaticSchemaShape + SerializeWithSchema + TryFromDocument,
+    {
+        let mut members = input
+            .serialize_with_schema(I::schema(), DocumentParser)?
+            .into_map()?;
+        members.insert(member.to_string(), value);
+        struct_document::<I>(members).try_into()
+    }
+}
+
+fn get_path<'d>(document: &'d dyn Document, path: &str) -> Option<&'d dyn Document> {
+    path.split('.')
+        .try_fold(document, |document, member| {
+            document.as_map()?.get(member).map(AsRef::as_ref)
+        })
+        .filter(|document| !document.is_null())
+}
+
+fn struct_document<I: StaticSchemaShape>(
+    members: IndexMap<String, Box<dyn Document>>,
+) -> Box<dyn Document> {
+    DocumentImpl {
+        schema: I::schema().clone(),
+        value: Value::Map(members),
+        discriminator: Some(I::schema().id().clone()),
+    }
+    .into()
+}
+
+// ============================================================================
+// Paginators
+// ============================================================================
+
+impl<I, O, E> Operation<I, O, E>
+where
+    I: StaticSchemaShape + SerializeWithSchema + TryFromDocument,
+    O: StaticSchemaShape + SerializeWithSchema,
+{
+    /// Iterate over all pages of a `@paginated` operation.
+    ///
+    /// `call` sends a single request for the operation. The first request is sent
+    /// with `input`, and each following request is sent with the input token set to the
+    /// output token of the previous page. Iteration stops after a page with no output
+    /// token, a page that returns the same token as the previous page, or an error.
+    ///
+    /// ```rust,ignore
+    /// for page in LIST_CITIES.paginate(input, |input| client.list_cities(input))? {
+    ///     let page = page?;
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`DocumentError`] if the operation does not have the `@paginated` trait.
+    pub fn paginate<F, Err>(&self, input: I, call: F) -> Result<Paginator<I, F>, DocumentError>
+    where
+        F: FnMut(I) -> Result<O, Err>,
+        Err: From<DocumentError>,
+    {
+        Ok(Paginator {
+            state: PaginationState::new(self.pagination()?, input),
+            call,
+        })
+    }
+
+    /// Stream all pages of a `@paginated` operation.
+    ///
+    /// Async version of [`Operation::paginate`], where `call` returns a future that
+    /// resolves to a single page.
+    ///
+    /// # Errors
+    /// Returns [`DocumentError`] if the operation does not have the `@paginated` trait.
+    pub fn paginate_stream<F, Fut, Err>(
+        &self,
+        input: I,
+        call: F,
+    ) -> Result<PaginatorStream<I, F, Fut>, DocumentError>
+    where
+        F: FnMut(I) -> Fut,
+        Fut: Future<Output = Result<O, Err>>,
+        Err: From<DocumentError>,
+    {
+        Ok(PaginatorStream {
+            state: PaginationState::new(self.pagination()?, input),
+            call,
+            pending: None,
+        })
+    }
+
+    fn pagination(&self) -> Result<Pagination, DocumentError> {
+        Pagination::of(self.schema()).ok_or_else(|| {
+            DocumentError::CustomError(format!("Operation `{}` is not paginated", self.id().id()))
+        })
+    }
+}
+
+/// State shared by [`Paginator`] and [`PaginatorStream`].
+struct PaginationState<I> {
+    pagination: Pagination,
+    next: Option<I>,
+    token: Option<String>,
+}
+
+impl<I> PaginationState<I>
+where
+    I: StaticSchemaShape + SerializeWithSchema + TryFromDocument,
+{
+    const fn new(pagination: Pagination, input: I) -> Self {
+        PaginationState {
+            pagination,
+            next: Some(input),
+            token: None,
+        }
+    }
+
+    /// Take the input for the next request, if there are more pages.
+    fn take_input(&mut self) -> Option<I> {
+        self.next.take()
+    }
+
+    /// Prepare the input of the next page from the input and output of the current page.
+    fn advance<O>(&mut self, input: &I, output: &O) -> Result<(), DocumentError>
+    where
+        O: StaticSchemaShape + SerializeWithSchema,
+    {
+        let Some(token) = self.pagination.next_token(output)? else {
+            return Ok(());
+        };
+        if self.token.as_ref() == Some(&token) {
+            return Ok(());
+        }
+        self.next = Some(Pagination::with_member(
+            input,
+            &self.pagination.input_token,
+            token.as_str().into(),
+        )?);
+        self.token = Some(token);
+        Ok(())
+    }
+
+    fn set_page_size(&mut self, page_size: i32) -> Result<(), DocumentError> {
+        let (Some(member), Some(input)) = (&self.pagination.page_size, &self.next) else {
+            return Err(DocumentError::CustomError(
+                "Operation has no page size member".to_string(),
+            ));
+        };
+        self.next = Some(Pagination::with_member(input, member, page_size.into())?);
+        Ok(())
+    }
+}
+
+/// Iterator over the pages of a `@paginated` operation.
+///
+/// Created with [`Operation::paginate`].
+pub struct Paginator<I, F> {
+    state: PaginationState<I>,
+    call: F,
+}
+
+impl<I, F> Paginator<I, F>
+where
+    I: StaticSchemaShape + SerializeWithSchema + TryFromDocument,
+{
+    /// Get the pagination settings of the operation.
+    #[must_use]
+    pub const fn pagination(&self) -> &Pagination {
+        &self.state.pagination
+    }
+
+    /// Set the maximum number of items returned in each page.
+    ///
+    /// # Errors
+    /// Returns [`DocumentError`] if the operation has no page size member.
+    pub fn with_page_size(mut self, page_size: i32) -> Result<Self, DocumentError> {
+        self.state.set_page_size(page_size)?;
+        Ok(self)
+    }
+}
+
+impl<I, O, F, Err> Iterator for Paginator<I, F>
+where
+    I: StaticSchemaShape + SerializeWithSchema + TryFromDocument,
+    O: StaticSchemaShape + SerializeWithSchema,
+    F: FnMut(I) -> Result<O, Err>,
+    Err: From<DocumentError>,
+{
+    type Item = Result<O, Err>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.state.take_input()?;
+        // The next input is built from a snapshot of this one, as `call` takes ownership
+        let snapshot = match input.serialize_with_schema(I::schema(), DocumentParser) {
+            Ok(document) => document,
+            Err(err) => return Some(Err(err.into())),
+        };
+        let output = match (self.call)(input) {
+            Ok(output) => output,
+            Err(err) => return Some(Err(err)),
+        };
+        let advanced = snapshot
+            .try_into::<I>()
+            .and_then(|input| self.state.advance(&input, &output));
+        Some(advanced.map(|()| output).map_err(Err::from))
+    }
+}
+
+impl<I, F> Debug for Paginator<I, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Paginator")
+            .field("pagination", &self.state.pagination)
+            .field("done", &self.state.next.is_none())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Stream of the pages of a `@paginated` operation.
+///
+/// Created with [`Operation::paginate_stream`].
+pub struct PaginatorStream<I, F, Fut> {
+    state: PaginationState<I>,
+    call: F,
+    pending: Option<PendingPage<Fut>>,
+}
+
+/// In-flight request of a [`PaginatorStream`], along with a snapshot of its input.
+type PendingPage<Fut> = (Pin<Box<Fut>>, Box<dyn Document>);
+
+impl<I, F, Fut> PaginatorStream<I, F, Fut>
+where
+    I: StaticSchemaShape + SerializeWithSchema + TryFromDocument,
+{
+    /// Get the pagination settings of the operation.
+    #[must_use]
+    pub const fn pagination(&self) -> &Pagination {
+        &self.state.pagination
+    }
+
+    /// Set the maximum number of items returned in each page.
+    ///
+    /// # Errors
+    /// Returns [`DocumentError`] if the operation has no page size member.
+    pub fn with_page_size(mut self, page_size: i32) -> Result<Self, DocumentError> {
+        self.state.set_page_size(page_size)?;
+        Ok(self)
+    }
+}
+
+// Futures are boxed, so the stream itself never needs to be pinned
+impl<I, F, Fut> Unpin for PaginatorStream<I, F, Fut> {}
+
+impl<I, O, F, Fut, Err> Stream for PaginatorStream<I, F, Fut>
+where
+    I: StaticSchemaShape + SerializeWithSchema + TryFromDocument,
+    O: StaticSchemaShape + SerializeWithSchema,
+    F: FnMut(I) -> Fut,
+    Fut: Future<Output = Result<O, Err>>,
+    Err: From<DocumentError>,
+{
+    type Item = Result<O, Err>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.pending.is_none() {
+            let Some(input) = this.state.take_input() else {
+                return Poll::Ready(None);
+            };
+            let snapshot = match input.serialize_with_schema(I::schema(), DocumentParser) {
+                Ok(document) => document,
+                Err(err) => return Poll::Ready(Some(Err(err.into()))),
+            };
+            this.pending = Some((Box::pin((this.call)(input)), snapshot));
+        }
+        let Some((future, _)) = &mut this.pending else {
+            return Poll::Ready(None);
+        };
+        let result = match future.as_mut().poll(cx) {
+            Poll::Ready(result) => result,
+            Poll::Pending => return Poll::Pending,
+        };
+        let Some((_, snapshot)) = this.pending.take() else {
+            return Poll::Ready(None);
+        };
+        let output = match result {
+            Ok(output) => output,
+            Err(err) => return Poll::Ready(Some(Err(err))),
+        };
+        let advanced = snapshot
+            .try_into::<I>()
+            .and_then(|input| this.state.advance(&input, &output));
+        Poll::Ready(Some(advanced.map(|()| output).map_err(Err::from)))
+    }
+}
+
+impl<I, F, Fut> Debug for PaginatorStream<I, F, Fut> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PaginatorStream")
+            .field("pagination", &self.state.pagination)
+            .field(
+                "done",
+                &(self.state.next.is_none() && self.pending.is_none()),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use smithy4rs_test_utils::ready_items;
+
+    use super::*;
+    use crate::{
+        derive::SmithyShape,
+        prelude::{INTEGER, PaginatedTrait, STRING},
+        schema::{DocumentError, Unit},
+        smithy,
+    };
+
+    smithy!("com.example#ListCitiesInput": {
+        structure LIST_CITIES_INPUT_SCHEMA {
+            NEXT_TOKEN: STRING = "nextToken"
+            MAX_RESULTS: INTEGER = "maxResults"
+        }
+    });
+    smithy!("com.example#CityList": {
+        list CITY_LIST_SCHEMA {
+            member: STRING
+        }
+    });
+    smithy!("com.example#ListCitiesOutput": {
+        structure LIST_CITIES_OUTPUT_SCHEMA {
+            NEXT_TOKEN: STRING = "nextToken"
+            CITIES: CITY_LIST_SCHEMA = "cities"
+        }
+    });
+    smithy!("com.example#ListCities": {
+        @PaginatedTrait::builder()
+            .input_token("nextToken")
+            .output_token("nextToken")
+            .items("cities")
+            .page_size("maxResults")
+            .build();
+        operation LIST_CITIES_SCHEMA
+    });
+    smithy!("com.example#GetCity": {
+        operation GET_CITY_SCHEMA
+    });
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(LIST_CITIES_INPUT_SCHEMA)]
+    pub struct ListCitiesInput {
+        #[smithy_schema(NEXT_TOKEN)]
+        next_token: Option<String>,
+        #[smithy_schema(MAX_RESULTS)]
+        max_results: Option<i32>,
+    }
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(LIST_CITIES_OUTPUT_SCHEMA)]
+    pub struct ListCitiesOutput {
+        #[smithy_schema(NEXT_TOKEN)]
+        next_token: Option<String>,
+        #[smithy_schema(CITIES)]
+        cities: Vec<String>,
+    }
+
+    static LIST_CITIES: Operation<ListCitiesInput, ListCitiesOutput, Unit> =
+        Operation::new(&LIST_CITIES_SCHEMA);
+    static GET_CITY: Operation<ListCitiesInput, ListCitiesOutput, Unit> =
+        Operation::new(&GET_CITY_SCHEMA);
+
+    const PAGES: [(&str, &[&str]); 3] = [
+        ("", &["Seattle", "Portland"]),
+        ("a", &["Boston"]),
+        ("b", &["Austin", "Denver"]),
+    ];
+
+    /// Serve pages from [`PAGES`], keyed by the input token.
+    fn list_cities(input: &ListCitiesInput) -> ListCitiesOutput {
+        let token = input.next_token.as_deref().unwrap_or_default();
+        let index = PAGES.iter().position(|(t, _)| *t == token).unwrap();
+        ListCitiesOutput {
+            next_token: PAGES.get(index + 1).map(|(t, _)| (*t).to_string()),
+            cities: PAGES[index].1.iter().map(|c| (*c).to_string()).collect(),
+        }
+    }
+
+    fn first_page() -> ListCitiesInput {
+        ListCitiesInput {
+            next_token: None,
+            max_results: None,
+        }
+    }
+
+    #[test]
+    fn reads_pagination_from_operation() {
+        let pagination = Pagination::of(LIST_CITIES.schema()).unwrap();
+        assert_eq!(pagination.input_token(), "nextToken");
+        assert_eq!(pagination.output_token(), "nextToken");
+        assert_eq!(pagination.items(), Some("cities"));
+        assert_eq!(pagination.page_size(), Some("maxResults"));
+        assert!(Pagination::of(GET_CITY.schema()).is_none());
+    }
+
+    #[test]
+    fn iterates_over_all_pages() {
+        let requests = RefCell::new(Vec::new());
+        let pages = LIST_CITIES
+            .paginate(first_page(), |input| {
+                requests.borrow_mut().push(input.next_token.clone());
+                Ok::<_, DocumentError>(list_cities(&input))
+            })
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(pages.len(), 3);
+        assert_eq!(
+            *requests.borrow(),
+            vec![None, Some("a".to_string()), Some("b".to_string())]
+        );
+        let pagination = Pagination::of(LIST_CITIES.schema()).unwrap();
+        let cities = pages
+            .iter()
+            .map(|page| pagination.page_items::<_, String>(page))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+            .concat();
+        assert_eq!(
+            cities,
+            vec!["Seattle", "Portland", "Boston", "Austin", "Denver"]
+        );
+    }
+
+    #[test]
+    fn stops_on_repeated_token() {
+        let mut calls = 0;
+        let pages = LIST_CITIES
+            .paginate(first_page(), |_| {
+                calls += 1;
+                Ok::<_, DocumentError>(ListCitiesOutput {
+                    next_token: Some("same".to_string()),
+                    cities: Vec::new(),
+                })
+            })
+            .unwrap()
+            .count();
+        assert_eq!(pages, 2);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn sets_page_size() {
+        let mut sizes = Vec::new();
+        let paginator = LIST_CITIES
+            .paginate(first_page(), |input| {
+                sizes.push(input.max_results);
+                Ok::<_, DocumentError>(list_cities(&input))
+            })
+            .unwrap()
+            .with_page_size(2)
+            .unwrap();
+        assert_eq!(paginator.count(), 3);
+        assert_eq!(sizes, vec![Some(2); 3]);
+    }
+
+    #[test]
+    fn stops_after_error() {
+        let mut paginator = LIST_CITIES
+            .paginate(first_page(), |_| {
+                Err::<ListCitiesOutput, _>(DocumentError::CustomError("failed".to_string()))
+            })
+            .unwrap();
+        assert!(matches!(paginator.next(), Some(Err(_))));
+        assert!(paginator.next().is_none());
+    }
+
+    #[test]
+    fn rejects_unpaginated_operation() {
+        let result = GET_CITY.paginate(first_page(), |input| {
+            Ok::<_, DocumentError>(list_cities(&input))
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn streams_all_pages() {
+        let stream = LIST_CITIES
+            .paginate_stream(first_page(), |input| async move {
+                Ok::<_, DocumentError>(list_cities(&input))
+            })
+            .unwrap();
+        let tokens = ready_items(stream)
+            .into_iter()
+            .map(|page| page.unwrap().next_token)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            vec![Some("a".to_string()), Some("b".to_string()), None]
+        );
+    }
+}
@@ -0,0 +1,319 @@
+//! # Document Paths
+//! Query values nested in a [`Document`] with a subset of [JMESPath](https://jmespath.org/):
+//! - field access (`item.status`)
+//! - list indexes, counting from the end of the list if negative (`items[0]`, `items[-1]`)
+//! - list and object projections (`items[].status`, `items[*].status`)
+//!
+//! ```rust,ignore
+//! let status = document.path("items[0].status").and_then(|d| d.as_string());
+//! let statuses = document.select("items[].status");
+//! ```
+//!
+//! Paths can also be built without parsing an expression:
+//!
+//! ```rust,ignore
+//! let path = DocumentPath::new().field("items").project().field("status");
+//! let statuses = path.select(&*document);
+//! ```
+//!
+//! As in [JMESPath](https://jmespath.org/specification.html#projections), `null` values are treated as missing and are skipped by projections.
+
+use std::fmt::Display;
+
+use crate::schema::{Document, DocumentError};
+
+/// Parsed path to values nested in a [`Document`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentPath {
+    segments: Vec<PathSegment>,
+}
+
+/// Single step of a [`DocumentPath`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    /// Value of a map entry (or structure member)
+    Field(String),
+    /// Element of a list. Negative indexes count back from the end of the list.
+    Index(i64),
+    /// Apply the rest of the path to every element of a list or value of a map
+    Projection,
+}
+
+impl DocumentPath {
+    /// Create an empty path that selects the root document.
+    #[must_use]
+    pub const fn new() -> Self {
+        DocumentPath {
+            segments: Vec::new(),
+        }
+    }
+
+    /// Parse a path expression, such as `a.b[0].c` or `items[*].name`.
+    ///
+    /// # Errors
+    /// Returns [`DocumentError`] if the expression is not a valid path.
+    pub fn parse(expression: &str) -> Result<Self, DocumentError> {
+        let mut rest = expression.trim();
+        let path = parse_prefix(&mut rest).map_err(|message| path_error(expression, message))?;
+        if !rest.trim().is_empty() {
+            return Err(path_error(expression, "unexpected trailing characters"));
+        }
+        Ok(path)
+    }
+
+    /// Add a field access to the path.
+    #[must_use]
+    pub fn field(mut self, name: impl Into<String>) -> Self {
+        self.segments.push(PathSegment::Field(name.into()));
+        self
+    }
+
+    /// Add a list index to the path.
+    #[must_use]
+    pub fn index(mut self, index: i64) -> Self {
+        self.segments.push(PathSegment::Index(index));
+        self
+    }
+
+    /// Add a projection to the path.
+    #[must_use]
+    pub fn project(mut self) -> Self {
+        self.segments.push(PathSegment::Projection);
+        self
+    }
+
+    /// Segments of the path, in order.
+    #[must_use]
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Returns `true` if the path contains a projection, and so may select multiple values.
+    #[must_use]
+    pub fn is_projection(&self) -> bool {
+        self.segments.contains(&PathSegment::Projection)
+    }
+
+    /// Get the single value at this path.
+    ///
+    /// Returns `None` if the value is missing or `null`, or if the path contains a projection.
+    #[must_use]
+    pub fn get<'d>(&self, document: &'d dyn Document) -> Option<&'d dyn Document> {
+        let mut current = document;
+        for segment in &self.segments {
+            current = step(current, segment)?;
+        }
+        (!current.is_null()).then_some(current)
+    }
+
+    /// Get all values selected by this path.
+    ///
+    /// Projections apply the rest of the path to each element, flattening the results.
+    /// Paths with no projection select at most one value.
+    #[must_use]
+    pub fn select<'d>(&self, document: &'d dyn Document) -> Vec<&'d dyn Document> {
+        let mut selected = Vec::new();
+        select_into(document, &self.segments, &mut selected);
+        selected
+    }
+}
+
+/// Apply a single non-projection segment.
+fn step<'d>(document: &'d dyn Document, segment: &PathSegment) -> Option<&'d dyn Document> {
+    match segment {
+        PathSegment::Field(name) => document.as_map()?.get(name).map(AsRef::as_ref),
+        PathSegment::Index(index) => {
+            let items = document.as_list()?;
+            let index = if *index < 0 {
+                i64::try_from(items.len()).ok()? + index
+            } else {
+                *index
+            };
+            items.get(usize::try_from(index).ok()?).map(AsRef::as_ref)
+        }
+        PathSegment::Projection => None,
+    }
+}
+
+fn select_into<'d>(
+    document: &'d dyn Document,
+    segments: &[PathSegment],
+    selected: &mut Vec<&'d dyn Document>,
+) {
+    let Some((segment, rest)) = segments.split_first() else {
+        if !document.is_null() {
+            selected.push(document);
+        }
+        return;
+    };
+    if *segment != PathSegment::Projection {
+        if let Some(next) = step(document, segment) {
+            select_into(next, rest, selected);
+        }
+        return;
+    }
+    if let Some(items) = document.as_list() {
+        for item in items {
+            select_into(&**item, rest, selected);
+        }
+    } else if let Some(map) = document.as_map() {
+        for value in map.values() {
+            select_into(&**value, rest, selected);
+        }
+    }
+}
+
+/// Parse a path from the start of `rest`, advancing it past the parsed path.
+pub(crate) fn parse_prefix(rest: &mut &str) -> Result<DocumentPath, String> {
+    let mut segments = Vec::new();
+    loop {
+        if let Some(tail) = rest.strip_prefix('[') {
+            let end = tail.find(']').ok_or("unclosed `[`")?;
+            let index = tail[..end].trim();
+            segments.push(match index {
+                "" | "*" => PathSegment::Projection,
+                index => PathSegment::Index(
+                    index
+                        .parse()
+                        .map_err(|_| format!("invalid index `{index}`"))?,
+                ),
+            });
+            *rest = &tail[end + 1..];
+        } else if segments.is_empty() || rest.starts_with('.') {
+            let tail = if segments.is_empty() {
+                *rest
+            } else {
+                &rest[1..]
+            };
+            let end = tail
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(tail.len());
+            if end == 0 {
+                return Err("expected field name".to_string());
+            }
+            segments.push(PathSegment::Field(tail[..end].to_string()));
+            *rest = &tail[end..];
+        } else {
+            return Ok(DocumentPath { segments });
+        }
+    }
+}
+
+fn path_error(expression: &str, message: impl Display) -> DocumentError {
+    DocumentError::CustomError(format!("Invalid document path `{expression}`: {message}"))
+}
+
+impl dyn Document {
+    /// Get the single value at a path expression, such as `a.b[0].c`.
+    ///
+    /// Returns `None` if the expression is invalid, or if the value is missing or `null`.
+    /// Use [`DocumentPath::parse`] to check the expression for errors.
+    #[must_use]
+    pub fn path(&self, path: &str) -> Option<&dyn Document> {
+        DocumentPath::parse(path).ok()?.get(self)
+    }
+
+    /// Get all values selected by a path expression, such as `items[*].name`.
+    ///
+    /// Returns no values if the expression is invalid.
+    /// Use [`DocumentPath::parse`] to check the expression for errors.
+    #[must_use]
+    pub fn select(&self, path: &str) -> Vec<&dyn Document> {
+        DocumentPath::parse(path)
+            .map(|path| path.select(self))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc_map;
+
+    fn document() -> Box<dyn Document> {
+        doc_map![
+            "name" => "a",
+            "items" => vec![
+                Box::<dyn Document>::from(doc_map!["state" => "on", "tags" => vec!["x", "y"]]),
+                doc_map!["state" => "off", "tags" => vec!["z"]].into(),
+                doc_map!["other" => 1].into(),
+            ],
+            "nested" => doc_map!["count" => 2, "inner" => doc_map!["value" => "deep"]],
+        ]
+        .into()
+    }
+
+    fn strings(values: &[&dyn Document]) -> Vec<String> {
+        values
+            .iter()
+            .filter_map(|value| value.as_string().map(ToString::to_string))
+            .collect()
+    }
+
+    #[test]
+    fn gets_nested_values() {
+        let document = document();
+        assert_eq!(
+            document
+                .path("nested.inner.value")
+                .and_then(|d| d.as_string()),
+            Some("deep")
+        );
+        assert_eq!(
+            document.path("nested.count").and_then(|d| d.as_integer()),
+            Some(2)
+        );
+        assert_eq!(
+            document.path("items[1].state").and_then(|d| d.as_string()),
+            Some("off")
+        );
+        assert_eq!(
+            document
+                .path("items[-3].tags[1]")
+                .and_then(|d| d.as_string()),
+            Some("y")
+        );
+        assert!(document.path("items[3]").is_none());
+        assert!(document.path("items[-4]").is_none());
+        assert!(document.path("name.missing").is_none());
+        assert!(document.path("items[].state").is_none());
+        assert!(document.path("items[").is_none());
+    }
+
+    #[test]
+    fn selects_projected_values() {
+        let document = document();
+        assert_eq!(
+            strings(&document.select("items[].state")),
+            vec!["on", "off"]
+        );
+        assert_eq!(
+            strings(&document.select("items[*].tags[]")),
+            vec!["x", "y", "z"]
+        );
+        assert_eq!(strings(&document.select("nested.inner[*]")), vec!["deep"]);
+        assert_eq!(strings(&document.select("name")), vec!["a"]);
+        assert!(document.select("missing[]").is_empty());
+    }
+
+    #[test]
+    fn builds_paths() {
+        let path = DocumentPath::new().field("items").project().field("state");
+        assert_eq!(path, DocumentPath::parse("items[*].state").unwrap());
+        assert!(path.is_projection());
+        assert_eq!(
+            DocumentPath::new().field("items").index(-1).segments(),
+            &[PathSegment::Field("items".into()), PathSegment::Index(-1)]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_paths() {
+        for expression in ["", "a.", "a[", "a[x]", "a b", ".a"] {
+            assert!(
+                DocumentPath::parse(expression).is_err(),
+                "expected `{expression}` to be invalid"
+            );
+        }
+    }
+}
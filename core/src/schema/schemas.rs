@@ -8,6 +8,7 @@ use std::{
 
 use fast_str::FastStr;
 use rustc_hash::FxBuildHasher;
+use thiserror::Error;
 
 use crate::{
     FxIndexMap, FxIndexSet, Ref,
@@ -25,25 +26,59 @@ use crate::{
 /// Allows for cheap copying and read only access to schema data.
 /// This type is also used to handle indirection required to build
 /// aggregate schemas and potentially recursive schemas.
-#[derive(Clone, PartialEq)]
-#[repr(transparent)]
-pub struct Schema(Ref<SchemaValue>);
+///
+/// Schemas for simple shapes with no traits can be created in a `const` context
+/// with [`Schema::from_static`], which avoids lazily allocating them on first use.
+#[derive(Clone)]
+pub struct Schema(SchemaRef);
+
+#[derive(Clone)]
+enum SchemaRef {
+    /// Schema value stored in a `static`
+    Static(&'static SchemaValue),
+    /// Schema value allocated at runtime
+    Shared(Ref<SchemaValue>),
+}
+
+impl Schema {
+    /// Create a schema that references a statically allocated [`SchemaValue`].
+    ///
+    /// Cloning the returned schema only copies the reference.
+    ///
+    /// ```rust,ignore
+    /// static CITY_NAME_VALUE: SchemaValue =
+    ///     SchemaValue::const_scalar(ShapeType::String, "com.example#CityName");
+    /// pub static CITY_NAME: Schema = Schema::from_static(&CITY_NAME_VALUE);
+    /// ```
+    #[must_use]
+    pub const fn from_static(value: &'static SchemaValue) -> Schema {
+        Schema(SchemaRef::Static(value))
+    }
+}
 impl Deref for Schema {
     type Target = SchemaValue;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        match &self.0 {
+            SchemaRef::Static(value) => value,
+            SchemaRef::Shared(value) => value,
+        }
+    }
+}
+impl PartialEq for Schema {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq::<SchemaValue>(&**self, &**other) || **self == **other
     }
 }
 impl From<SchemaValue> for Schema {
     fn from(value: SchemaValue) -> Self {
-        Self(value.into())
+        Self(SchemaRef::Shared(value.into()))
     }
 }
 impl Debug for Schema {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         // Remove extra wrapping from this pointer in debug print
-        self.0.fmt(f)
+        (**self).fmt(f)
     }
 }
 
@@ -169,6 +204,20 @@ impl Schema {
     pub fn create_service(id: impl Into<ShapeId>, traits: TraitList) -> Schema {
         Self::scalar(ShapeType::Service, id, traits)
     }
+
+    /// Create a standalone [member](https://smithy.io/2.0/spec/model.html#members) Schema
+    /// targeting `target`.
+    ///
+    /// Standalone members are useful for tests and custom codecs that need
+    /// a member schema without defining a containing aggregate shape.
+    /// As the member has no containing shape, its ID is derived from the
+    /// target (i.e. `<target>$<name>`).
+    pub fn member<M: Into<MemberTarget>>(name: &str, target: M, traits: TraitList) -> Schema {
+        let target = target.into();
+        let member =
+            MemberSchemaBuilder::new(name.into(), target.id().with_member(name), target, traits);
+        member.build(member.reaches_constraint(&mut Vec::new()))
+    }
 }
 
 // BUILDER FACTORIES
@@ -196,6 +245,50 @@ impl Schema {
     pub fn map_builder<I: Into<ShapeId>>(id: I, traits: TraitList) -> SchemaBuilder {
         SchemaBuilder::new(id, ShapeType::Map, traits)
     }
+
+    /// Create a new [`SchemaBuilder`] with the same ID, traits, and members as this schema.
+    ///
+    /// The returned builder can be modified to derive a new schema from an
+    /// existing one (for example, to add server-only traits) without affecting
+    /// the original schema. Member targets are shared with the original schema.
+    ///
+    /// Returns `None` if this is not a structure, union, list, or map schema.
+    #[must_use]
+    pub fn to_builder(&self) -> Option<SchemaBuilder> {
+        let (shape_type, traits, members): (ShapeType, &TraitMap, Vec<&Schema>) = match &**self {
+            SchemaValue::Struct(StructSchema {
+                shape_type,
+                traits,
+                members,
+                ..
+            }) => (*shape_type, traits, members.values().collect()),
+            SchemaValue::List(ListSchema { traits, member, .. }) => {
+                (ShapeType::List, traits, vec![member])
+            }
+            SchemaValue::Map(MapSchema {
+                traits, key, value, ..
+            }) => (ShapeType::Map, traits, vec![key, value]),
+            _ => return None,
+        };
+        let members = members
+            .into_iter()
+            .filter_map(|member| member.as_member())
+            .map(|member| MemberSchemaBuilder {
+                name: member.name.to_string(),
+                id: member.id.clone(),
+                member_target: member.target.clone(),
+                traits: member.traits.clone(),
+                member_index: None,
+            })
+            .collect();
+        Some(SchemaBuilder {
+            id: self.id().clone(),
+            shape_type,
+            members: RwLock::new(members),
+            traits: RwLock::new(traits.clone()),
+            built: OnceLock::new(),
+        })
+    }
 }
 
 // ============================================================================
@@ -221,6 +314,38 @@ pub enum SchemaValue {
     Member(MemberSchema),
 }
 
+impl SchemaValue {
+    /// Create the value of a simple shape schema with no traits in a `const` context.
+    ///
+    /// Use with [`Schema::from_static`] to create schemas that do not need to
+    /// be lazily initialized.
+    ///
+    /// # Panics
+    /// If `shape_type` is not a simple or service shape type, or if `id` is not a
+    /// valid shape ID. In a `const` context these are reported as compile-time errors.
+    #[must_use]
+    pub const fn const_scalar(shape_type: ShapeType, id: &'static str) -> SchemaValue {
+        assert!(
+            !matches!(
+                shape_type,
+                ShapeType::Enum
+                    | ShapeType::IntEnum
+                    | ShapeType::List
+                    | ShapeType::Map
+                    | ShapeType::Structure
+                    | ShapeType::Union
+                    | ShapeType::Member
+            ),
+            "Only simple shapes can be created in a const context"
+        );
+        SchemaValue::Scalar(ScalarSchema {
+            id: ShapeId::from_static(id),
+            shape_type,
+            traits: TraitMap::new(),
+        })
+    }
+}
+
 // ======== Getters =======
 
 impl SchemaValue {
@@ -265,13 +390,24 @@ impl SchemaValue {
         }
     }
 
+    /// Traits applied directly to this schema.
+    ///
+    /// Unlike [`SchemaValue::traits`], member schemas do not include traits from their target.
+    #[inline]
+    pub(crate) fn direct_traits(&self) -> &TraitMap {
+        match self {
+            SchemaValue::Member(MemberSchema { traits, .. }) => traits,
+            _ => self.traits(),
+        }
+    }
+
     /// Get a map of all members attached to this schema.
     ///
     /// <div class ="note">
     /// **NOTE**: Scalar schemas with no members will return an empty map.
     /// </div>
-    #[allow(dead_code)]
-    pub(crate) fn members(&self) -> &FxIndexMap<String, Schema> {
+    #[must_use]
+    pub fn members(&self) -> &FxIndexMap<String, Schema> {
         match self {
             // TODO(errors): Error handling
             SchemaValue::Struct(StructSchema { members, .. }) => members,
@@ -327,18 +463,32 @@ impl SchemaValue {
         }
     }
 
+    /// Get the schema for a specific member by member name, returning an
+    /// error if the member does not exist.
+    ///
+    /// # Errors
+    /// Returns [`SchemaError::MissingMember`] if the member does not exist on the schema.
+    pub fn try_member(&self, member_name: &str) -> Result<&Schema, SchemaError> {
+        self.get_member(member_name)
+            .ok_or_else(|| SchemaError::MissingMember {
+                id: self.id().clone(),
+                member: member_name.to_string(),
+            })
+    }
+
     /// Returns member schema reference or *panics*
     ///
     /// <div class ="warning">
     /// In general this should only be used in generated code.
+    /// Prefer [`SchemaValue::try_member`] elsewhere.
     /// </div>
     ///
     /// # Panics
     /// If the expected member does not exist on the schema
     #[must_use]
     pub fn expect_member(&self, member_name: &str) -> &Schema {
-        self.get_member(member_name)
-            .unwrap_or_else(|| panic!("Expected member: {member_name}"))
+        self.try_member(member_name)
+            .unwrap_or_else(|e| panic!("{e}"))
     }
 
     /// Returns true if the map contains a value for the specified trait ID.
@@ -372,6 +522,44 @@ impl SchemaValue {
     }
 }
 
+// ===== Constraints ==========
+impl SchemaValue {
+    /// Returns true if this schema, or any schema reachable from it, can fail
+    /// validation for a reason other than a missing `@required` member.
+    ///
+    /// Constraints include the `@length`, `@range`, `@pattern`, and `@uniqueItems`
+    /// traits as well as `enum` and `intEnum` values. The result is computed when
+    /// aggregate and member schemas are built. Members with a [`MemberTarget::deferred`]
+    /// target are always treated as constrained, as their target cannot be resolved
+    /// while the schema is built.
+    #[must_use]
+    pub fn has_constraints(&self) -> bool {
+        match self {
+            SchemaValue::Scalar(scalar) => has_constraint_traits(&scalar.traits),
+            SchemaValue::Enum(_) | SchemaValue::IntEnum(_) => true,
+            SchemaValue::Struct(StructSchema { constrained, .. })
+            | SchemaValue::List(ListSchema { constrained, .. })
+            | SchemaValue::Map(MapSchema { constrained, .. })
+            | SchemaValue::Member(MemberSchema { constrained, .. }) => *constrained,
+        }
+    }
+}
+
+/// IDs of the traits that constrain a value.
+///
+/// Trait types resolve their IDs from their own schemas, so the IDs are spelled out
+/// here to check for constraints while those schemas are being built.
+static CONSTRAINT_TRAITS: [ShapeId; 4] = [
+    ShapeId::from_static("smithy.api#length"),
+    ShapeId::from_static("smithy.api#range"),
+    ShapeId::from_static("smithy.api#pattern"),
+    ShapeId::from_static("smithy.api#uniqueItems"),
+];
+
+fn has_constraint_traits(traits: &TraitMap) -> bool {
+    CONSTRAINT_TRAITS.iter().any(|id| traits.contains(id))
+}
+
 // ===== As-ers ==========
 impl SchemaValue {
     /// Get as a [`MemberSchema`] type if possible, otherwise `None`.
@@ -464,19 +652,21 @@ pub struct StructSchema {
     /// Members (i.e. fields) of the structure schema
     pub(crate) members: FxIndexMap<String, Schema>,
     traits: TraitMap,
+    constrained: bool,
 }
 
 /// Schema for a Smithy [List](https://smithy.io/2.0/spec/aggregate-types.html#list) data type.
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct ListSchema {
     id: ShapeId,
     /// Member representing items in the list
     member: Schema,
     traits: TraitMap,
+    constrained: bool,
 }
 
 /// Schema for a Smithy [Map](https://smithy.io/2.0/spec/aggregate-types.html#map) data type.
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct MapSchema {
     id: ShapeId,
     /// Member representing keys of the map
@@ -484,6 +674,7 @@ pub struct MapSchema {
     /// Member representing values in the map
     value: Schema,
     traits: TraitMap,
+    constrained: bool,
 }
 
 /// Schema for a Smithy [Enum](https://smithy.io/2.0/spec/aggregate-types.html#map) data type.
@@ -517,6 +708,7 @@ pub struct MemberSchema {
     index: usize,
     traits: TraitMap,
     flattened_traits: OnceLock<TraitMap>,
+    constrained: bool,
 }
 // We have to implement this manually to avoid infinite recursion
 impl PartialEq for MemberSchema {
@@ -555,6 +747,42 @@ impl MemberSchema {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Get the index of the member in its parent shape.
+    #[inline]
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get the schema of the shape targeted by this member.
+    ///
+    /// <div class="note">
+    /// Note: Calling this method will resolve the target if it has not
+    /// already been resolved.
+    /// </div>
+    #[inline]
+    #[must_use]
+    pub fn target(&self) -> &Schema {
+        &self.target
+    }
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Errors that can occur when accessing or constructing a [`Schema`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum SchemaError {
+    /// Requested member does not exist on the schema.
+    #[error("Expected member `{member}` on schema `{}`", id.id())]
+    MissingMember {
+        /// ID of the schema the member was requested from
+        id: ShapeId,
+        /// Name of the missing member
+        member: String,
+    },
 }
 
 // ============================================================================
@@ -613,6 +841,163 @@ impl SchemaBuilder {
         self
     }
 
+    /// Replace the member named `name`, keeping its position in the builder.
+    ///
+    /// If no member with that name exists, the member is added as with
+    /// [`SchemaBuilder::put_member`].
+    ///
+    /// # Panics
+    /// If the lock on the member target is poisoned.
+    #[must_use]
+    pub fn replace_member<M: Into<MemberTarget>>(
+        &self,
+        name: &str,
+        target: M,
+        traits: TraitList,
+    ) -> &Self {
+        self.validate_member_name(name);
+        let member = MemberSchemaBuilder::new(
+            name.into(),
+            self.id.with_member(name),
+            target.into(),
+            traits,
+        );
+        let mut members = self.members.write().expect("Lock poisoned");
+        match members.iter_mut().find(|existing| existing.name == name) {
+            Some(existing) => *existing = member,
+            None => members.push(member),
+        }
+        drop(members);
+        self
+    }
+
+    /// Remove the member named `name`, if it exists.
+    ///
+    /// # Panics
+    /// If the lock on the member target is poisoned.
+    #[must_use]
+    pub fn remove_member(&self, name: &str) -> &Self {
+        self.members
+            .write()
+            .expect("Lock poisoned")
+            .retain(|member| member.name != name);
+        self
+    }
+
+    /// Move the member named `name` to position `index`, shifting the members
+    /// after it back by one.
+    ///
+    /// <div class="note">
+    /// **NOTE**: Required members with no default value are still placed before
+    /// all other members when the schema is built.
+    /// </div>
+    ///
+    /// # Panics
+    /// If no member named `name` exists, if `index` is out of bounds, or if the
+    /// lock on the member target is poisoned.
+    #[must_use]
+    pub fn move_member(&self, name: &str, index: usize) -> &Self {
+        let mut members = self.members.write().expect("Lock poisoned");
+        let position = members
+            .iter()
+            .position(|member| member.name == name)
+            .unwrap_or_else(|| panic!("Expected member `{name}` on schema `{}`", self.id.id()));
+        let member = members.remove(position);
+        members.insert(index, member);
+        drop(members);
+        self
+    }
+
+    /// Add traits to the member named `name`, replacing any existing traits
+    /// with the same IDs.
+    ///
+    /// # Panics
+    /// If no member named `name` exists, or if the lock on the member target is poisoned.
+    #[must_use]
+    pub fn merge_member_traits(&self, name: &str, traits: TraitList) -> &Self {
+        let mut members = self.members.write().expect("Lock poisoned");
+        let member = members
+            .iter_mut()
+            .find(|member| member.name == name)
+            .unwrap_or_else(|| panic!("Expected member `{name}` on schema `{}`", self.id.id()));
+        for smithy_trait in traits {
+            member.traits.insert(smithy_trait);
+        }
+        drop(members);
+        self
+    }
+
+    /// Add traits to the shape, replacing any existing traits with the same IDs.
+    ///
+    /// # Panics
+    /// If the lock on the traits is poisoned.
+    #[must_use]
+    pub fn merge_traits(&self, traits: TraitList) -> &Self {
+        let mut trait_map = self.traits.write().expect("Lock poisoned");
+        for smithy_trait in traits {
+            trait_map.insert(smithy_trait);
+        }
+        drop(trait_map);
+        self
+    }
+
+    /// Remove the trait with the ID `id` from the shape, if it exists.
+    ///
+    /// # Panics
+    /// If the lock on the traits is poisoned.
+    #[must_use]
+    pub fn remove_trait(&self, id: &ShapeId) -> &Self {
+        self.traits.write().expect("Lock poisoned").remove(id);
+        self
+    }
+
+    /// Returns true if the built schema will have constraints (see [`SchemaValue::has_constraints`]).
+    ///
+    /// Unbuilt targets are checked through their builders, as recursive shapes are
+    /// built before all of their targets are.
+    fn reaches_constraint(&self, visiting: &mut Vec<ShapeId>) -> bool {
+        if let Some(schema) = self.built.get() {
+            return schema.has_constraints();
+        }
+        // Recursive shapes add no constraints beyond those already being checked
+        if visiting.contains(&self.id) {
+            return false;
+        }
+        visiting.push(self.id.clone());
+        let constrained = has_constraint_traits(&self.traits.read().expect("Lock poisoned"))
+            || self
+                .members
+                .read()
+                .expect("Lock poisoned")
+                .iter()
+                .any(|member| member.reaches_constraint(visiting));
+        visiting.pop();
+        constrained
+    }
+
+    /// Returns true for each member that will have constraints once built.
+    ///
+    /// Members may target this builder, so their targets are checked without
+    /// holding a lock on the members.
+    fn member_constraints(&self) -> Vec<bool> {
+        let members: Vec<(bool, MemberTarget)> = self
+            .members
+            .read()
+            .expect("Lock poisoned")
+            .iter()
+            .map(|member| {
+                (
+                    has_constraint_traits(&member.traits),
+                    member.member_target.clone(),
+                )
+            })
+            .collect();
+        members
+            .into_iter()
+            .map(|(constrained, target)| constrained || target.reaches_constraint(&mut Vec::new()))
+            .collect()
+    }
+
     fn validate_member_name(&self, name: &str) {
         // TODO(errors): Return a result instead of panicking?
         match self.shape_type {
@@ -640,56 +1025,62 @@ impl SchemaBuilder {
             return schema.clone();
         }
 
+        if matches!(self.shape_type, ShapeType::Structure | ShapeType::Union) {
+            let mut members_mut = self.members.write().expect("Lock poisoned.");
+            members_mut.sort();
+            for (idx, member_builder) in members_mut.iter_mut().enumerate() {
+                member_builder.set_index(idx);
+            }
+        }
+        let constrained = self.reaches_constraint(&mut Vec::new());
+        let member_constraints = self.member_constraints();
         let mut traits = TraitMap::new();
         traits.extend(&self.traits.read().unwrap());
+        let members = self.members.read().expect("Lock poisoned.");
+        let mut built_members = members
+            .iter()
+            .zip(member_constraints)
+            .map(|(member_builder, constrained)| member_builder.build(constrained));
         let output: Schema = match self.shape_type {
             ShapeType::Structure | ShapeType::Union => {
-                let mut members_mut = self.members.write().expect("Lock poisoned.");
-                members_mut.sort();
-                let mut members =
-                    FxIndexMap::with_capacity_and_hasher(members_mut.len(), FxBuildHasher);
-                for (idx, member_builder) in members_mut.iter_mut().enumerate() {
-                    member_builder.set_index(idx);
-                    members.insert(member_builder.name.clone(), member_builder.build());
+                let mut struct_members =
+                    FxIndexMap::with_capacity_and_hasher(members.len(), FxBuildHasher);
+                for (member_builder, member) in members.iter().zip(built_members) {
+                    struct_members.insert(member_builder.name.clone(), member);
                 }
                 SchemaValue::Struct(StructSchema {
                     id: self.id.clone(),
                     shape_type: self.shape_type,
-                    members,
-                    traits,
-                })
-                .into()
-            }
-            ShapeType::List => {
-                let members = self.members.read().expect("Lock poisoned.");
-                SchemaValue::List(ListSchema {
-                    id: self.id.clone(),
-                    member: members
-                        .first()
-                        .expect("Expected `member` member for List Schema")
-                        .build(),
-                    traits,
-                })
-                .into()
-            }
-            ShapeType::Map => {
-                let members = self.members.read().expect("Lock poisoned.");
-                SchemaValue::Map(MapSchema {
-                    id: self.id.clone(),
-                    key: members
-                        .first()
-                        .expect("Expected `key` member for Map schema")
-                        .build(),
-                    value: members
-                        .get(1)
-                        .expect("Expected `value` member for Map schema")
-                        .build(),
+                    members: struct_members,
                     traits,
+                    constrained,
                 })
                 .into()
             }
+            ShapeType::List => SchemaValue::List(ListSchema {
+                id: self.id.clone(),
+                member: built_members
+                    .next()
+                    .expect("Expected `member` member for List Schema"),
+                traits,
+                constrained,
+            })
+            .into(),
+            ShapeType::Map => SchemaValue::Map(MapSchema {
+                id: self.id.clone(),
+                key: built_members
+                    .next()
+                    .expect("Expected `key` member for Map schema"),
+                value: built_members
+                    .next()
+                    .expect("Expected `value` member for Map schema"),
+                traits,
+                constrained,
+            })
+            .into(),
             _ => unreachable!("Builder can only be created for aggregate types."),
         };
+        drop(members);
         self.built.set(output.clone()).expect("Lock poisoned");
         output
     }
@@ -715,6 +1106,35 @@ pub enum MemberTarget {
         /// Target schema, lazily set.
         value: OnceLock<Schema>,
     },
+    /// A statically defined schema that is only resolved on first use.
+    ///
+    /// Used for mutually recursive shapes, where resolving the target while
+    /// the targeting schema is still being built would deadlock.
+    Deferred(fn() -> &'static Schema),
+}
+impl MemberTarget {
+    /// Create a target that is resolved from a static schema on first use.
+    ///
+    /// ```rust,ignore
+    /// builder.put_member("children", MemberTarget::deferred(|| &TREE_NODES), traits![]);
+    /// ```
+    #[must_use]
+    pub const fn deferred(resolve: fn() -> &'static Schema) -> Self {
+        MemberTarget::Deferred(resolve)
+    }
+}
+impl MemberTarget {
+    fn reaches_constraint(&self, visiting: &mut Vec<ShapeId>) -> bool {
+        match self {
+            MemberTarget::Resolved(target) => target.has_constraints(),
+            MemberTarget::Lazy { builder, value } => value.get().map_or_else(
+                || builder.reaches_constraint(visiting),
+                |target| target.has_constraints(),
+            ),
+            // Resolving a deferred target while building could deadlock on a recursive static
+            MemberTarget::Deferred(_) => true,
+        }
+    }
 }
 impl Deref for MemberTarget {
     type Target = Schema;
@@ -722,6 +1142,7 @@ impl Deref for MemberTarget {
         match self {
             MemberTarget::Resolved(target) => target,
             MemberTarget::Lazy { builder, value } => value.get_or_init(|| builder.build()),
+            MemberTarget::Deferred(resolve) => resolve(),
         }
     }
 }
@@ -801,7 +1222,11 @@ impl MemberSchemaBuilder {
         self.traits.contains_type::<RequiredTrait>() && !self.traits.contains_type::<DefaultTrait>()
     }
 
-    fn build(&self) -> Schema {
+    fn reaches_constraint(&self, visiting: &mut Vec<ShapeId>) -> bool {
+        has_constraint_traits(&self.traits) || self.member_target.reaches_constraint(visiting)
+    }
+
+    fn build(&self, constrained: bool) -> Schema {
         SchemaValue::Member(MemberSchema {
             id: self.id.clone(),
             target: self.member_target.clone(),
@@ -809,6 +1234,7 @@ impl MemberSchemaBuilder {
             index: self.member_index.unwrap_or_default(),
             traits: self.traits.clone(),
             flattened_traits: OnceLock::new(),
+            constrained,
         })
         .into()
     }
@@ -847,6 +1273,27 @@ impl Debug for StructSchema {
     }
 }
 
+impl Debug for ListSchema {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut l = f.debug_struct("ListSchema");
+        l.field("id", &self.id);
+        l.field("member", &self.member);
+        l.field("traits", &self.traits);
+        l.finish()
+    }
+}
+
+impl Debug for MapSchema {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut m = f.debug_struct("MapSchema");
+        m.field("id", &self.id);
+        m.field("key", &self.key);
+        m.field("value", &self.value);
+        m.field("traits", &self.traits);
+        m.finish()
+    }
+}
+
 impl Debug for MemberSchema {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut m = f.debug_map();
@@ -860,7 +1307,10 @@ impl Debug for MemberSchema {
 mod tests {
     use super::*;
     use crate::{
-        schema::prelude::{JsonNameTrait, STRING},
+        schema::prelude::{
+            HttpHeaderTrait, INTEGER, JsonNameTrait, LengthTrait, PatternTrait, RangeTrait, STRING,
+            SensitiveTrait, UniqueItemsTrait,
+        },
         traits,
     };
 
@@ -871,6 +1321,51 @@ mod tests {
         assert_eq!(schema.id(), &ShapeId::from("api.example#Integer"));
     }
 
+    static CITY_NAME_VALUE: SchemaValue =
+        SchemaValue::const_scalar(ShapeType::String, "api.smithy#CityName");
+    static CITY_NAME: Schema = Schema::from_static(&CITY_NAME_VALUE);
+
+    crate::smithy!("api.smithy#Population": {
+        integer POPULATION
+    });
+
+    #[test]
+    fn static_schemas() {
+        assert_eq!(CITY_NAME.shape_type(), &ShapeType::String);
+        assert_eq!(
+            CITY_NAME,
+            Schema::create_string(ShapeId::from("api.smithy#CityName"), traits![])
+        );
+        assert!(matches!(CITY_NAME.clone().0, SchemaRef::Static(_)));
+        // Simple shapes with no traits are created in a const context by the schema macro
+        assert!(matches!(POPULATION.0, SchemaRef::Static(_)));
+        assert!(matches!(STRING.0, SchemaRef::Static(_)));
+        assert_eq!(POPULATION.id(), &ShapeId::from("api.smithy#Population"));
+    }
+
+    #[test]
+    fn static_member_targets() {
+        let schema = Schema::structure_builder(ShapeId::from("api.smithy#City"), traits![])
+            .put_member(
+                "name",
+                &CITY_NAME,
+                traits![LengthTrait::builder().max(4).build()],
+            )
+            .put_member("population", &POPULATION, traits![])
+            .build();
+        let name = schema.expect_member("name");
+        assert_eq!(name.shape_type(), &ShapeType::String);
+        assert!(name.contains_type::<LengthTrait>());
+        assert_eq!(name.as_member().unwrap().target(), &CITY_NAME);
+        assert!(!CITY_NAME.contains_type::<LengthTrait>());
+    }
+
+    #[test]
+    #[should_panic(expected = "Only simple shapes can be created in a const context")]
+    fn const_aggregate_schema() {
+        let _ = SchemaValue::const_scalar(ShapeType::Structure, "api.smithy#City");
+    }
+
     #[test]
     fn structure_schema() {
         let target = Schema::create_integer(ShapeId::from("api.smithy#Target"), traits![]);
@@ -889,6 +1384,35 @@ mod tests {
         assert_eq!(&member_schema.target.id(), &target.id());
     }
 
+    #[test]
+    fn standalone_member_schema() {
+        let member = Schema::member("field", &STRING, traits![JsonNameTrait::new("Field")]);
+        assert_eq!(member.shape_type(), &ShapeType::String);
+        assert_eq!(member.id(), &ShapeId::from("smithy.api#String$field"));
+        assert_eq!(member.as_member().unwrap().name(), "field");
+        assert!(member.contains_type::<JsonNameTrait>());
+    }
+
+    #[test]
+    fn missing_member_error() {
+        let schema = Schema::structure_builder(ShapeId::from("api.smithy#Example"), traits![])
+            .put_member("a", &STRING, traits![])
+            .build();
+        assert!(schema.try_member("a").is_ok());
+        let error = schema.try_member("b").unwrap_err();
+        assert_eq!(
+            error,
+            SchemaError::MissingMember {
+                id: ShapeId::from("api.smithy#Example"),
+                member: "b".to_string(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Expected member `b` on schema `api.smithy#Example`"
+        );
+    }
+
     #[test]
     #[should_panic(expected = "Lists can only have members named `member`. Found `bad`")]
     fn disallowed_list_schema() {
@@ -1100,6 +1624,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn detects_constraints() {
+        let unconstrained = Schema::structure_builder("api.smithy#Plain", traits![])
+            .put_member("a", &STRING, traits![RequiredTrait::builder().build()])
+            .build();
+        assert!(!unconstrained.has_constraints());
+
+        let list = Schema::list_builder("api.smithy#List", traits![])
+            .put_member(
+                "member",
+                &STRING,
+                traits![LengthTrait::builder().max(3).build()],
+            )
+            .build();
+        let constrained = Schema::structure_builder("api.smithy#Constrained", traits![])
+            .put_member("list", &list, traits![])
+            .build();
+        assert!(constrained.has_constraints());
+        assert!(constrained.get_member("list").unwrap().has_constraints());
+    }
+
+    #[test]
+    fn constraint_trait_ids_match_trait_types() {
+        assert!(CONSTRAINT_TRAITS.contains(LengthTrait::trait_id()));
+        assert!(CONSTRAINT_TRAITS.contains(RangeTrait::trait_id()));
+        assert!(CONSTRAINT_TRAITS.contains(PatternTrait::trait_id()));
+        assert!(CONSTRAINT_TRAITS.contains(UniqueItemsTrait::trait_id()));
+    }
+
+    #[test]
+    fn detects_constraints_in_recursive_schemas() {
+        let builder_a = Ref::new(Schema::structure_builder("api.smithy#A", traits![]));
+        let builder_b = Ref::new(Schema::structure_builder("api.smithy#B", traits![]));
+        let _ = builder_a.put_member("b", &builder_b, traits![]).put_member(
+            "self",
+            &builder_a,
+            traits![],
+        );
+        let _ = builder_b.put_member("a", &builder_a, traits![]).put_member(
+            "s",
+            &STRING,
+            traits![LengthTrait::builder().max(3).build()],
+        );
+        let output_a = builder_a.build();
+        let output_b = builder_b.build();
+        assert!(output_b.has_constraints());
+        assert!(output_a.has_constraints());
+
+        let recursive = Ref::new(Schema::structure_builder("api.smithy#Recursive", traits![]));
+        let output = recursive.put_member("self", &recursive, traits![]).build();
+        assert!(!output.has_constraints());
+    }
+
     #[test]
     fn sorts_members() {
         let schema = Schema::structure_builder(ShapeId::from("api.smithy#Example"), traits![])
@@ -1134,4 +1711,198 @@ mod tests {
         assert_eq!(fourth, "target_c");
         assert_eq!(fifth, "target_e");
     }
+
+    #[test]
+    fn replaces_and_removes_members() {
+        let schema = Schema::structure_builder(ShapeId::from("api.smithy#Example"), traits![])
+            .put_member("a", &STRING, traits![])
+            .put_member("b", &STRING, traits![])
+            .put_member("c", &STRING, traits![])
+            .replace_member("b", &INTEGER, traits![JsonNameTrait::new("B")])
+            .remove_member("a")
+            .replace_member("d", &STRING, traits![])
+            .build();
+        let names: Vec<_> = schema.members().keys().map(String::as_str).collect();
+        assert_eq!(names, vec!["b", "c", "d"]);
+        let member = schema.expect_member("b");
+        assert_eq!(member.shape_type(), &ShapeType::Integer);
+        assert!(member.contains_type::<JsonNameTrait>());
+    }
+
+    #[test]
+    fn moves_members() {
+        let schema = Schema::structure_builder(ShapeId::from("api.smithy#Example"), traits![])
+            .put_member("a", &STRING, traits![])
+            .put_member("b", &STRING, traits![])
+            .put_member("c", &STRING, traits![])
+            .move_member("c", 0)
+            .build();
+        let names: Vec<_> = schema.members().keys().map(String::as_str).collect();
+        assert_eq!(names, vec!["c", "a", "b"]);
+        assert_eq!(schema.expect_member("c").as_member().unwrap().index(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected member `z` on schema `api.smithy#Example`")]
+    fn move_missing_member() {
+        let _builder = Schema::structure_builder(ShapeId::from("api.smithy#Example"), traits![])
+            .put_member("a", &STRING, traits![])
+            .move_member("z", 0);
+    }
+
+    #[test]
+    fn merges_traits() {
+        let schema = Schema::structure_builder(
+            ShapeId::from("api.smithy#Example"),
+            traits![JsonNameTrait::new("old")],
+        )
+        .put_member("a", &STRING, traits![JsonNameTrait::new("old")])
+        .merge_traits(traits![
+            JsonNameTrait::new("new"),
+            SensitiveTrait::builder().build()
+        ])
+        .merge_member_traits("a", traits![LengthTrait::builder().max(4).build()])
+        .build();
+        assert_eq!(
+            **schema.get_trait_as::<JsonNameTrait>().unwrap(),
+            "new".to_string()
+        );
+        assert!(schema.contains_type::<SensitiveTrait>());
+        let member = schema.expect_member("a");
+        assert!(member.contains_type::<JsonNameTrait>());
+        assert!(member.contains_type::<LengthTrait>());
+        assert!(member.has_constraints());
+    }
+
+    #[test]
+    fn derives_schema_from_existing_schema() {
+        let original = Schema::structure_builder(
+            ShapeId::from("api.smithy#Example"),
+            traits![JsonNameTrait::new("example")],
+        )
+        .put_member("a", &STRING, traits![JsonNameTrait::new("A")])
+        .put_member("b", &INTEGER, traits![])
+        .build();
+        let derived = original
+            .to_builder()
+            .unwrap()
+            .merge_traits(traits![SensitiveTrait::builder().build()])
+            .remove_trait(JsonNameTrait::trait_id())
+            .remove_member("b")
+            .build();
+
+        assert_eq!(derived.id(), original.id());
+        assert!(derived.contains_type::<SensitiveTrait>());
+        assert!(!derived.contains_type::<JsonNameTrait>());
+        assert_eq!(derived.members().len(), 1);
+        assert!(derived.expect_member("a").contains_type::<JsonNameTrait>());
+
+        // Original schema is unchanged
+        assert!(!original.contains_type::<SensitiveTrait>());
+        assert_eq!(original.members().len(), 2);
+        assert_eq!(original.to_builder().unwrap().build(), original);
+    }
+
+    #[test]
+    fn derives_list_and_map_schemas() {
+        let list = Schema::list_builder(ShapeId::from("api.smithy#List"), traits![])
+            .put_member("member", &STRING, traits![])
+            .build();
+        let derived = list
+            .to_builder()
+            .unwrap()
+            .replace_member("member", &INTEGER, traits![])
+            .build();
+        assert_eq!(
+            derived.get_list_member().unwrap().shape_type(),
+            &ShapeType::Integer
+        );
+
+        let map = Schema::map_builder(ShapeId::from("api.smithy#Map"), traits![])
+            .put_member("key", &STRING, traits![])
+            .put_member("value", &STRING, traits![])
+            .build();
+        assert_eq!(map.to_builder().unwrap().build(), map);
+        assert!(STRING.to_builder().is_none());
+    }
+
+    crate::smithy!("api.smithy#Code": {
+        string CODE [length(min: 1, max: 4), pattern("^a+$")]
+    });
+
+    crate::smithy!("api.smithy#Codes": {
+        list CODES [uniqueItems] {
+            member: STRING [length(max: 2)]
+        }
+    });
+
+    crate::smithy!("api.smithy#Annotated": {
+        @JsonNameTrait::new("shape");
+        structure ANNOTATED [sensitive] {
+            @RequiredTrait::builder().build();
+            ANNOTATED_CODE: CODE = "code" [jsonName("Code"), httpHeader("X-Code")]
+            ANNOTATED_COUNT: INTEGER = "count" [range(min: 0, max: 10), default(1)]
+            ANNOTATED_CODES: CODES = "codes"
+        }
+    });
+
+    #[test]
+    fn trait_annotations() {
+        let length = CODE.get_trait_as::<LengthTrait>().unwrap();
+        assert_eq!((length.min, length.max), (Some(1), Some(4)));
+        assert!(CODE.contains_type::<PatternTrait>());
+        assert!(CODES.contains_type::<UniqueItemsTrait>());
+        assert_eq!(
+            CODES
+                .get_list_member()
+                .unwrap()
+                .get_trait_as::<LengthTrait>()
+                .unwrap()
+                .max,
+            Some(2)
+        );
+
+        assert!(ANNOTATED.contains_type::<JsonNameTrait>());
+        assert!(ANNOTATED.contains_type::<SensitiveTrait>());
+        let code = ANNOTATED.expect_member("code");
+        assert!(code.contains_type::<RequiredTrait>());
+        assert_eq!(
+            code.get_trait_as::<JsonNameTrait>().unwrap().as_str(),
+            "Code"
+        );
+        assert!(code.contains_type::<HttpHeaderTrait>());
+        let count = ANNOTATED.expect_member("count");
+        assert!(count.contains_type::<RangeTrait>());
+        assert!(count.contains_type::<DefaultTrait>());
+        assert!(!count.contains_type::<RequiredTrait>());
+        assert!(
+            !ANNOTATED
+                .expect_member("codes")
+                .contains_type::<RequiredTrait>()
+        );
+    }
+
+    crate::smithy!("api.smithy#Branches": {
+        list BRANCHES {
+            member: (@lazy BRANCH)
+        }
+    });
+
+    crate::smithy!("api.smithy#Branch": {
+        structure BRANCH {
+            BRANCH_CHILDREN: BRANCHES = "children"
+        }
+    });
+
+    #[test]
+    fn deferred_member_targets() {
+        // Resolving the list first must not deadlock on the structure targeting it
+        let member = BRANCHES.get_list_member().unwrap();
+        assert_eq!(member.as_member().unwrap().target(), &*BRANCH);
+        let children = BRANCH.expect_member("children");
+        assert_eq!(children.as_member().unwrap().target(), &*BRANCHES);
+        assert_eq!(MemberTarget::deferred(|| &POPULATION).id(), POPULATION.id());
+        // Deferred targets are not resolved while building, so they are assumed to be constrained
+        assert!(BRANCHES.has_constraints());
+    }
 }
@@ -4,13 +4,19 @@
 //!
 
 use std::{
+    borrow::Cow,
+    error::Error,
     fmt::{Debug, Display, Formatter},
     hash::Hash,
+    sync::Arc,
 };
 
 use fast_str::FastStr;
 
-use crate::schema::Schema;
+use crate::{
+    prelude::{ErrorTrait, RetryableTrait},
+    schema::Schema,
+};
 
 /// Immutable identifier for a shape in a Smithy model.
 ///
@@ -70,6 +76,49 @@ impl From<&str> for ShapeId {
 }
 
 impl ShapeId {
+    /// Creates a shape ID from a static string in a `const` context.
+    ///
+    /// # Panics
+    /// If the shape ID does not contain a namespace separator (`#`). In a `const`
+    /// context this is reported as a compile-time error.
+    #[must_use]
+    pub const fn from_static(value: &'static str) -> ShapeId {
+        let bytes = value.as_bytes();
+        let mut hash = None;
+        let mut dollar = None;
+        let mut index = 0;
+        while index < bytes.len() {
+            match bytes[index] {
+                b'#' if hash.is_none() => hash = Some(index),
+                b'$' if hash.is_some() && dollar.is_none() => dollar = Some(index),
+                _ => {}
+            }
+            index += 1;
+        }
+        let Some(hash) = hash else {
+            panic!("Invalid Shape Id");
+        };
+        let (namespace, rest) = value.split_at(hash);
+        let (_, rest) = rest.split_at(1);
+        let (name, member) = match dollar {
+            Some(dollar) => {
+                let (name, member) = rest.split_at(dollar - hash - 1);
+                let (_, member) = member.split_at(1);
+                (name, Some(member))
+            }
+            None => (rest, None),
+        };
+        ShapeId {
+            id: FastStr::from_static(value),
+            namespace: FastStr::from_static(namespace),
+            name: FastStr::from_static(name),
+            member: match member {
+                Some(member) => Some(FastStr::from_static(member)),
+                None => None,
+            },
+        }
+    }
+
     /// Creates a shape ID from parts of a shape ID.
     pub fn from_parts(namespace: &str, name: &str, member: Option<&str>) -> ShapeId {
         let mut id = namespace.to_string() + "#" + name;
@@ -218,14 +267,186 @@ impl<T: StaticSchemaShape> SchemaShape for T {
 ///
 /// This trait is for shapes with statically-defined schemas, which are
 /// those would be generated from a model.
+///
+/// ## Blanket Implementations
+/// References and smart pointers (`&T`, `Box<T>`, `Arc<T>`, and `Cow<'_, T>`)
+/// share the static schema of the shape they point to, so borrowed
+/// shapes can be passed to serialization APIs without cloning.
 pub trait StaticSchemaShape {
     /// Get a reference to the (static) Schema of this shape.
     fn schema() -> &'static Schema;
 }
 
+impl<T: StaticSchemaShape + ?Sized> StaticSchemaShape for &T {
+    #[inline]
+    fn schema() -> &'static Schema {
+        T::schema()
+    }
+}
+
+impl<T: StaticSchemaShape + ?Sized> StaticSchemaShape for Box<T> {
+    #[inline]
+    fn schema() -> &'static Schema {
+        T::schema()
+    }
+}
+
+impl<T: StaticSchemaShape + ?Sized> StaticSchemaShape for Arc<T> {
+    #[inline]
+    fn schema() -> &'static Schema {
+        T::schema()
+    }
+}
+
+impl<T: StaticSchemaShape + ToOwned + ?Sized> StaticSchemaShape for Cow<'_, T> {
+    #[inline]
+    fn schema() -> &'static Schema {
+        T::schema()
+    }
+}
+
+/// Value of a known variant of a Smithy `enum` or `intEnum` shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnumValue {
+    /// Value of an [`enum`](https://smithy.io/2.0/spec/simple-types.html#enum) variant.
+    String(&'static str),
+    /// Value of an [`intEnum`](https://smithy.io/2.0/spec/simple-types.html#intenum) variant.
+    Int(i32),
+}
+
+/// Provides metadata on the known variants of a generated enum shape.
+///
+/// This allows generic tooling (for example, UI or validation tooling) to
+/// introspect the possible values of an enum without parsing its schema.
+///
+/// <div class ="note">
+/// **NOTE**: This trait is automatically implemented when deriving `SmithyShape` for enums.
+/// </div>
+pub trait EnumShape: StaticSchemaShape + Sized {
+    /// Get the name and value of each known variant, in definition order.
+    ///
+    /// The `Unknown` variant is never included.
+    fn variants() -> &'static [(&'static str, EnumValue)];
+
+    /// Get the known variant with the given value.
+    ///
+    /// Returns `None` if no known variant has the given value.
+    fn from_enum_value(value: &EnumValue) -> Option<Self>;
+}
+
+/// Indicates whether a modeled error was caused by the client or the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorFault {
+    /// Error was caused by the client (for example, invalid input).
+    Client,
+    /// Error was caused by the server.
+    Server,
+}
+
+/// Provides metadata on a generated [error](https://smithy.io/2.0/spec/type-refinement-traits.html#error-trait)
+/// shape.
+///
+/// The fault and retry behavior of an error are read from the `@error` and
+/// `@retryable` traits on its schema.
+///
+/// <div class ="note">
+/// **NOTE**: This trait is automatically implemented when deriving `SmithyError`.
+/// </div>
+pub trait ErrorShape: StaticSchemaShape + Error {
+    /// Get the error message, if the error has a `message` member that is set.
+    fn message(&self) -> Option<&str>;
+
+    /// Get whether the error was caused by the client or the server.
+    ///
+    /// Errors with no `@error` trait are treated as server faults.
+    fn fault(&self) -> ErrorFault {
+        match <Self as StaticSchemaShape>::schema().get_trait_as::<ErrorTrait>() {
+            Some(ErrorTrait::Client) => ErrorFault::Client,
+            _ => ErrorFault::Server,
+        }
+    }
+
+    /// Returns `true` if the error has the `@retryable` trait.
+    fn is_retryable(&self) -> bool {
+        <Self as StaticSchemaShape>::schema().contains_type::<RetryableTrait>()
+    }
+
+    /// Returns `true` if the error is `@retryable` and classified as throttling.
+    fn is_throttling(&self) -> bool {
+        <Self as StaticSchemaShape>::schema()
+            .get_trait_as::<RetryableTrait>()
+            .is_some_and(|retryable| retryable.throttling == Some(true))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::TimestampFormatTrait;
+
+    // Defined in a separate module as `SchemaShape` would make derived `schema()` calls ambiguous
+    mod priority {
+        use crate::{
+            derive::{SmithyShape, smithy_enum},
+            smithy,
+        };
+
+        smithy!("com.example#Priority": {
+            intEnum PRIORITY_SCHEMA {
+                LOW = 1
+                HIGH = 10
+            }
+        });
+
+        #[smithy_enum]
+        #[derive(SmithyShape, PartialEq)]
+        #[smithy_schema(PRIORITY_SCHEMA)]
+        pub enum Priority {
+            Low = 1,
+            High = 10,
+        }
+    }
+    use priority::Priority;
+
+    mod errors {
+        use crate::{
+            derive::SmithyShape,
+            prelude::{ErrorTrait, INTEGER, RetryableTrait, STRING},
+            smithy,
+        };
+
+        smithy!("com.example#NotFound": {
+            @ErrorTrait::Client;
+            structure NOT_FOUND_SCHEMA {
+                MESSAGE: STRING = "message"
+            }
+        });
+
+        #[derive(SmithyShape, PartialEq)]
+        #[smithy_schema(NOT_FOUND_SCHEMA)]
+        #[smithy_error]
+        pub struct NotFound {
+            #[smithy_schema(MESSAGE)]
+            pub message: Option<String>,
+        }
+
+        smithy!("com.example#Throttled": {
+            @ErrorTrait::Server;
+            @RetryableTrait::builder().throttling(true).build().unwrap();
+            structure THROTTLED_SCHEMA {
+                RETRY_AFTER: INTEGER = "retryAfter"
+            }
+        });
+
+        #[derive(SmithyShape, PartialEq)]
+        #[smithy_schema(THROTTLED_SCHEMA)]
+        #[smithy_error]
+        pub struct Throttled {
+            #[smithy_schema(RETRY_AFTER)]
+            pub retry_after: i32,
+        }
+    }
+    use errors::{NotFound, Throttled};
 
     #[test]
     fn shape_id_from_str() {
@@ -249,6 +470,24 @@ mod tests {
         let _ = ShapeId::from("com.example.no.shape");
     }
 
+    #[test]
+    fn shape_id_from_static() {
+        const SHAPE_ID: ShapeId = ShapeId::from_static("com.example#MyShape");
+        const MEMBER_ID: ShapeId = ShapeId::from_static("com.example#MyShape$member");
+        assert_eq!(SHAPE_ID, ShapeId::from("com.example#MyShape"));
+        assert_eq!(SHAPE_ID.member, None);
+        assert_eq!(MEMBER_ID, ShapeId::from("com.example#MyShape$member"));
+        assert_eq!(MEMBER_ID.namespace, "com.example");
+        assert_eq!(MEMBER_ID.name, "MyShape");
+        assert_eq!(MEMBER_ID.member.as_deref(), Some("member"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid Shape Id")]
+    fn invalid_id_from_static() {
+        let _ = ShapeId::from_static("com.example.no.shape");
+    }
+
     #[test]
     fn shape_id_from_parts() {
         let shape_id = ShapeId::from_parts("com.example", "MyShape", Some("member"));
@@ -267,4 +506,92 @@ mod tests {
         assert_eq!(shape_id.member.unwrap(), "member");
         assert_eq!(shape_id.id, "com.example#MyShape$member");
     }
+
+    #[test]
+    fn enum_variants() {
+        assert_eq!(
+            TimestampFormatTrait::variants(),
+            &[
+                ("DateTime", EnumValue::String("date-time")),
+                ("EpochSeconds", EnumValue::String("epoch-seconds")),
+                ("HttpDate", EnumValue::String("http-date")),
+            ]
+        );
+        assert!(matches!(
+            TimestampFormatTrait::from_enum_value(&EnumValue::String("http-date")),
+            Some(TimestampFormatTrait::HttpDate)
+        ));
+        assert!(TimestampFormatTrait::from_enum_value(&EnumValue::String("other")).is_none());
+    }
+
+    #[test]
+    fn int_enum_variants() {
+        assert_eq!(
+            Priority::variants(),
+            &[("Low", EnumValue::Int(1)), ("High", EnumValue::Int(10))]
+        );
+        assert_eq!(
+            Priority::from_enum_value(&EnumValue::Int(10)),
+            Some(Priority::High)
+        );
+        assert_eq!(Priority::from_enum_value(&EnumValue::Int(2)), None);
+        assert_eq!(Priority::from_enum_value(&EnumValue::String("1")), None);
+    }
+
+    #[test]
+    fn match_known_enum_variants() {
+        use crate::waiters::{AcceptorState, KnownAcceptorState};
+
+        let describe = |state: &AcceptorState| {
+            state.match_known(
+                |known| match known {
+                    KnownAcceptorState::Success => "success".to_string(),
+                    KnownAcceptorState::Failure => "failure".to_string(),
+                    KnownAcceptorState::Retry => "retry".to_string(),
+                },
+                |unknown| format!("unknown: {unknown}"),
+            )
+        };
+        assert_eq!(describe(&AcceptorState::Retry), "retry");
+        assert_eq!(
+            describe(&AcceptorState::Unknown("pending".to_string())),
+            "unknown: pending"
+        );
+        assert_eq!(
+            AcceptorState::from(KnownAcceptorState::Failure),
+            AcceptorState::Failure
+        );
+    }
+
+    #[test]
+    fn match_known_int_enum_variants() {
+        use priority::KnownPriority;
+
+        assert_eq!(
+            Priority::High.match_known(Some, |_| None),
+            Some(KnownPriority::High)
+        );
+        assert_eq!(Priority::Unknown(7).match_known(|_| 0, |value| value), 7);
+        assert_eq!(Priority::from(KnownPriority::Low), Priority::Low);
+    }
+
+    #[test]
+    fn error_shapes() {
+        let error = NotFound {
+            message: Some("No such city".to_string()),
+        };
+        assert_eq!(error.message(), Some("No such city"));
+        assert_eq!(error.to_string(), "NotFound: No such city");
+        assert_eq!(error.fault(), ErrorFault::Client);
+        assert!(!error.is_retryable());
+        assert_eq!(NotFound { message: None }.to_string(), "NotFound");
+
+        let error = Throttled { retry_after: 5 };
+        assert_eq!(error.message(), None);
+        assert_eq!(error.to_string(), "Throttled");
+        assert_eq!(error.fault(), ErrorFault::Server);
+        assert!(error.is_retryable());
+        assert!(error.is_throttling());
+        let _: &dyn Error = &error;
+    }
 }
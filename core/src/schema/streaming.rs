@@ -0,0 +1,401 @@
+use std::{
+    fmt::{Debug, Formatter},
+    future::poll_fn,
+    io,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use bytes::{Bytes, BytesMut};
+use futures_core::Stream;
+use futures_io::AsyncRead;
+use thiserror::Error;
+
+use crate::{
+    ByteBuffer,
+    schema::{Blob, Schema},
+    serde::{
+        correction::ErrorCorrectionDefault,
+        de::{DeserializeWithSchema, Deserializer},
+        se::{SerializeWithSchema, Serializer},
+    },
+};
+
+/// Size of chunks read from an [`AsyncRead`] source.
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Stream of data chunks backing a [`StreamingBlob`].
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes, io::Error>> + Send>>;
+
+/// # Streaming Blob
+///
+/// Binary data for a [`@streaming`](https://smithy.io/2.0/spec/streaming.html#streaming-trait)
+/// blob that may be too large to buffer in memory.
+///
+/// A streaming blob is either backed by in-memory [`Bytes`] or by a [`ByteStream`]
+/// (for example, a file or network body). The stream can only be consumed once,
+/// using [`StreamingBlob::take_stream`] or [`StreamingBlob::collect`].
+/// Clones of a streaming blob share the same underlying data, so taking the
+/// stream from one clone consumes it for all of them.
+///
+/// Codecs that can stream data (such as HTTP payload bindings) receive the blob
+/// using the [`Serializer::write_streaming_blob`] and
+/// [`Deserializer::read_streaming_blob`] hooks. All other codecs fall back to
+/// (de)serializing an in-memory blob.
+///
+/// ```rust
+/// use smithy4rs_core::schema::StreamingBlob;
+///
+/// let blob = StreamingBlob::from_bytes("hello");
+/// assert_eq!(blob.as_bytes().unwrap(), "hello");
+/// ```
+#[derive(Clone)]
+pub struct StreamingBlob {
+    inner: Arc<Mutex<Inner>>,
+}
+
+enum Inner {
+    Bytes(Bytes),
+    Stream(ByteStream),
+    Consumed,
+}
+
+impl StreamingBlob {
+    /// Create a new streaming blob from in-memory data.
+    pub fn from_bytes(bytes: impl Into<Bytes>) -> Self {
+        Self::new(Inner::Bytes(bytes.into()))
+    }
+
+    /// Create a new streaming blob from a [`Stream`] of data chunks.
+    pub fn from_stream<S>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<Bytes, io::Error>> + Send + 'static,
+    {
+        Self::new(Inner::Stream(Box::pin(stream)))
+    }
+
+    /// Create a new streaming blob that reads data from an [`AsyncRead`] source.
+    pub fn from_async_read<R>(reader: R) -> Self
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        Self::from_stream(ReaderStream {
+            reader: Box::pin(reader),
+            buf: BytesMut::new(),
+        })
+    }
+
+    fn new(inner: Inner) -> Self {
+        StreamingBlob {
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+
+    /// Get the data of this blob if it is held in memory.
+    ///
+    /// Returns `None` if the blob is backed by a stream or has been consumed.
+    ///
+    /// # Panics
+    /// If the lock on the blob data is poisoned.
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<Bytes> {
+        match &*self.inner.lock().expect("Lock poisoned") {
+            Inner::Bytes(bytes) => Some(bytes.clone()),
+            Inner::Stream(_) | Inner::Consumed => None,
+        }
+    }
+
+    /// Returns `true` if the data of this blob has already been taken.
+    ///
+    /// # Panics
+    /// If the lock on the blob data is poisoned.
+    #[must_use]
+    pub fn is_consumed(&self) -> bool {
+        matches!(*self.inner.lock().expect("Lock poisoned"), Inner::Consumed)
+    }
+
+    /// Take the data of this blob as a [`ByteStream`].
+    ///
+    /// In-memory data is returned as a stream with a single chunk.
+    /// Because the blob is shared by reference during serialization,
+    /// the data can only be taken once.
+    ///
+    /// # Errors
+    /// Returns [`StreamingBlobError::Consumed`] if the data has already been taken.
+    ///
+    /// # Panics
+    /// If the lock on the blob data is poisoned.
+    pub fn take_stream(&self) -> Result<ByteStream, StreamingBlobError> {
+        let mut inner = self.inner.lock().expect("Lock poisoned");
+        match std::mem::replace(&mut *inner, Inner::Consumed) {
+            Inner::Bytes(bytes) => Ok(Box::pin(Once(Some(bytes)))),
+            Inner::Stream(stream) => Ok(stream),
+            Inner::Consumed => Err(StreamingBlobError::Consumed),
+        }
+    }
+
+    /// Read the entire blob into memory.
+    ///
+    /// # Errors
+    /// Returns [`StreamingBlobError::Consumed`] if the data has already been taken,
+    /// or [`StreamingBlobError::Io`] if the underlying stream fails.
+    pub async fn collect(self) -> Result<Bytes, StreamingBlobError> {
+        let mut stream = self.take_stream()?;
+        let mut buf = BytesMut::new();
+        while let Some(chunk) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            buf.extend_from_slice(&chunk?);
+        }
+        Ok(buf.freeze())
+    }
+}
+
+impl From<Bytes> for StreamingBlob {
+    fn from(bytes: Bytes) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<Vec<u8>> for StreamingBlob {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<Blob> for StreamingBlob {
+    fn from(blob: Blob) -> Self {
+        Self::from_bytes(blob.into_bytes())
+    }
+}
+
+impl From<ByteBuffer> for StreamingBlob {
+    fn from(buffer: ByteBuffer) -> Self {
+        Self::from_bytes(buffer.into_vec())
+    }
+}
+
+impl Debug for StreamingBlob {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Avoid blocking if the blob data is currently being taken
+        match self.inner.try_lock().as_deref() {
+            Ok(Inner::Bytes(bytes)) => f.debug_tuple("StreamingBlob").field(bytes).finish(),
+            Ok(Inner::Stream(_)) => f.write_str("StreamingBlob(<stream>)"),
+            Ok(Inner::Consumed) => f.write_str("StreamingBlob(<consumed>)"),
+            Err(_) => f.write_str("StreamingBlob(<locked>)"),
+        }
+    }
+}
+
+/// Errors that can occur when reading a [`StreamingBlob`].
+#[derive(Error, Debug)]
+pub enum StreamingBlobError {
+    /// Data of the blob has already been taken.
+    #[error("Streaming blob has already been consumed")]
+    Consumed,
+    /// Underlying stream returned an error.
+    #[error("Failed to read streaming blob: {0}")]
+    Io(#[from] io::Error),
+}
+
+// ============================================================================
+// Streams
+// ============================================================================
+
+/// Stream that yields a single chunk.
+struct Once(Option<Bytes>);
+
+impl Stream for Once {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.0.take().map(Ok))
+    }
+}
+
+/// Adapts an [`AsyncRead`] source into a [`Stream`] of chunks.
+struct ReaderStream {
+    reader: Pin<Box<dyn AsyncRead + Send>>,
+    buf: BytesMut,
+}
+
+impl Stream for ReaderStream {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        this.buf.resize(READ_CHUNK_SIZE, 0);
+        match this.reader.as_mut().poll_read(cx, &mut this.buf) {
+            Poll::Ready(Ok(0)) => Poll::Ready(None),
+            Poll::Ready(Ok(read)) => Poll::Ready(Some(Ok(this.buf.split_to(read).freeze()))),
+            Poll::Ready(Err(err)) => Poll::Ready(Some(Err(err))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+// ============================================================================
+// Serde
+// ============================================================================
+
+impl SerializeWithSchema for StreamingBlob {
+    #[inline]
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.write_streaming_blob(schema, self)
+    }
+}
+
+impl<'de> DeserializeWithSchema<'de> for StreamingBlob {
+    #[inline]
+    fn deserialize_with_schema<D>(schema: &Schema, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.read_streaming_blob(schema)
+    }
+}
+
+impl ErrorCorrectionDefault for StreamingBlob {
+    fn default() -> Self {
+        StreamingBlob::from_bytes(Bytes::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smithy4rs_test_utils::ready;
+
+    use super::*;
+    use crate::{
+        derive::SmithyShape,
+        prelude::{STRING, StreamingTrait},
+        schema::Document,
+        smithy,
+    };
+
+    smithy!("com.example#Payload": {
+        @StreamingTrait::builder().build();
+        blob PAYLOAD_SCHEMA
+    });
+    smithy!("com.example#Upload": {
+        structure UPLOAD_SCHEMA {
+            KEY: STRING = "key"
+            BODY: PAYLOAD_SCHEMA = "body"
+        }
+    });
+
+    #[derive(SmithyShape)]
+    #[smithy_schema(UPLOAD_SCHEMA)]
+    pub struct Upload {
+        #[smithy_schema(KEY)]
+        key: String,
+        #[smithy_schema(BODY)]
+        body: StreamingBlob,
+    }
+
+    struct Chunks(Vec<&'static [u8]>);
+
+    impl Stream for Chunks {
+        type Item = Result<Bytes, io::Error>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            if self.0.is_empty() {
+                Poll::Ready(None)
+            } else {
+                Poll::Ready(Some(Ok(Bytes::from_static(self.0.remove(0)))))
+            }
+        }
+    }
+
+    #[test]
+    fn collects_in_memory_blob() {
+        let blob = StreamingBlob::from_bytes("data");
+        assert_eq!(blob.as_bytes().unwrap(), "data");
+        assert_eq!(ready(blob.collect()).unwrap(), "data");
+    }
+
+    #[test]
+    fn collects_stream() {
+        let blob = StreamingBlob::from_stream(Chunks(vec![b"a", b"bc", b"def"]));
+        assert!(blob.as_bytes().is_none());
+        assert_eq!(ready(blob.collect()).unwrap(), "abcdef");
+    }
+
+    #[test]
+    fn clones_share_stream() {
+        let blob = StreamingBlob::from_stream(Chunks(vec![b"a"]));
+        let clone = blob.clone();
+        assert!(clone.take_stream().is_ok());
+        assert!(blob.is_consumed());
+        assert!(matches!(
+            blob.take_stream(),
+            Err(StreamingBlobError::Consumed)
+        ));
+    }
+
+    #[test]
+    fn collects_async_read() {
+        let data = vec![7u8; READ_CHUNK_SIZE * 2 + 10];
+        let blob = StreamingBlob::from_async_read(futures_io_cursor(data.clone()));
+        assert_eq!(ready(blob.collect()).unwrap(), data);
+    }
+
+    #[test]
+    fn stream_can_only_be_taken_once() {
+        let blob = StreamingBlob::from_stream(Chunks(vec![b"a"]));
+        assert!(blob.take_stream().is_ok());
+        assert!(blob.is_consumed());
+        assert!(matches!(
+            blob.take_stream(),
+            Err(StreamingBlobError::Consumed)
+        ));
+        assert_eq!(format!("{blob:?}"), "StreamingBlob(<consumed>)");
+    }
+
+    /// Minimal in-memory [`AsyncRead`] implementation
+    fn futures_io_cursor(data: Vec<u8>) -> impl AsyncRead + Send {
+        struct Cursor(Vec<u8>, usize);
+        impl AsyncRead for Cursor {
+            fn poll_read(
+                mut self: Pin<&mut Self>,
+                _cx: &mut Context<'_>,
+                buf: &mut [u8],
+            ) -> Poll<io::Result<usize>> {
+                let remaining = &self.0[self.1..];
+                let read = remaining.len().min(buf.len());
+                buf[..read].copy_from_slice(&remaining[..read]);
+                self.1 += read;
+                Poll::Ready(Ok(read))
+            }
+        }
+        Cursor(data, 0)
+    }
+
+    #[test]
+    fn shapes_fall_back_to_in_memory_blobs() {
+        let upload = Upload {
+            key: "file.txt".to_string(),
+            body: StreamingBlob::from_bytes("contents"),
+        };
+        assert!(format!("{upload:?}").contains("contents"));
+
+        let document: Box<dyn Document> = upload.into();
+        let builder: UploadBuilder = document.try_into().unwrap();
+        let result = builder.build().unwrap();
+        assert_eq!(result.key, "file.txt");
+        assert_eq!(result.body.as_bytes().unwrap(), "contents");
+    }
+
+    #[test]
+    fn debug_does_not_consume_stream() {
+        let upload = Upload {
+            key: "file.txt".to_string(),
+            body: StreamingBlob::from_stream(Chunks(vec![b"a"])),
+        };
+        assert!(format!("{upload:?}").contains("StreamingBlob(<stream>)"));
+        assert!(!upload.body.is_consumed());
+    }
+}
@@ -0,0 +1,747 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Sub, SubAssign},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use thiserror::Error;
+
+use crate::{
+    Instant,
+    prelude::TimestampFormatTrait,
+    schema::Schema,
+    serde::{
+        correction::ErrorCorrectionDefault,
+        de::{DeserializeWithSchema, Deserializer},
+        se::{SerializeWithSchema, Serializer},
+    },
+};
+
+const NANOS_PER_MILLI: i128 = 1_000_000;
+
+/// # Timestamp
+///
+/// Thin wrapper around an [`Instant`] that provides common time arithmetic
+/// and conversions for Smithy `timestamp` values without requiring users
+/// to depend on `temporal_rs` directly.
+///
+/// Timestamps have nanosecond precision and may be before the Unix epoch
+/// (i.e. have a negative epoch value).
+///
+/// ```rust
+/// use std::time::Duration;
+/// use smithy4rs_core::schema::Timestamp;
+///
+/// let start = Timestamp::from_epoch_millis(1_000).unwrap();
+/// let later = start + Duration::from_secs(1);
+/// assert!(later > start);
+/// assert_eq!(later.duration_since(start), Some(Duration::from_secs(1)));
+/// assert_eq!(later.epoch_millis(), 2_000);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp(Instant);
+
+impl Timestamp {
+    /// Get a timestamp for the Unix epoch (`1970-01-01T00:00:00Z`).
+    #[must_use]
+    pub fn unix_epoch() -> Self {
+        Self::from_epoch_nanos(0).expect("Unix epoch should always be a valid timestamp")
+    }
+
+    /// Get a timestamp for the current system time.
+    ///
+    /// # Panics
+    /// If the system clock is set outside the range supported by timestamps.
+    #[must_use]
+    pub fn now() -> Self {
+        Timestamp::try_from(SystemTime::now())
+            .expect("System time should be within the range of supported timestamps")
+    }
+
+    /// Create a timestamp from a (possibly negative) number of milliseconds since the Unix epoch.
+    ///
+    /// # Errors
+    /// Returns [`TimestampError::OutOfRange`] if the value is outside the supported range.
+    pub fn from_epoch_millis(millis: i64) -> Result<Self, TimestampError> {
+        Self::from_epoch_nanos(i128::from(millis) * NANOS_PER_MILLI)
+    }
+
+    /// Create a timestamp from a (possibly negative) number of nanoseconds since the Unix epoch.
+    ///
+    /// # Errors
+    /// Returns [`TimestampError::OutOfRange`] if the value is outside the supported range.
+    pub fn from_epoch_nanos(nanos: i128) -> Result<Self, TimestampError> {
+        Instant::try_new(nanos)
+            .map(Timestamp)
+            .map_err(|_| TimestampError::OutOfRange)
+    }
+
+    /// Milliseconds since the Unix epoch, rounded towards negative infinity.
+    #[must_use]
+    pub fn epoch_millis(&self) -> i64 {
+        self.0.epoch_milliseconds()
+    }
+
+    /// Nanoseconds since the Unix epoch.
+    #[must_use]
+    pub fn epoch_nanos(&self) -> i128 {
+        self.0.as_i128()
+    }
+
+    /// Amount of time elapsed since this timestamp.
+    ///
+    /// Returns [`Duration::ZERO`] if the timestamp is in the future.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        Timestamp::now().duration_since(*self).unwrap_or_default()
+    }
+
+    /// Amount of time elapsed from an `earlier` timestamp to this one.
+    ///
+    /// Returns `None` if `earlier` is after this timestamp.
+    #[must_use]
+    pub fn duration_since(&self, earlier: Timestamp) -> Option<Duration> {
+        nanos_to_duration(self.epoch_nanos() - earlier.epoch_nanos())
+    }
+
+    /// Add a [`Duration`] to this timestamp.
+    ///
+    /// Returns `None` if the result is outside the supported range.
+    #[must_use]
+    pub fn checked_add(&self, duration: Duration) -> Option<Timestamp> {
+        let nanos = self
+            .epoch_nanos()
+            .checked_add(duration.as_nanos() as i128)?;
+        Self::from_epoch_nanos(nanos).ok()
+    }
+
+    /// Subtract a [`Duration`] from this timestamp.
+    ///
+    /// Returns `None` if the result is outside the supported range.
+    #[must_use]
+    pub fn checked_sub(&self, duration: Duration) -> Option<Timestamp> {
+        let nanos = self
+            .epoch_nanos()
+            .checked_sub(duration.as_nanos() as i128)?;
+        Self::from_epoch_nanos(nanos).ok()
+    }
+
+    /// Get a reference to the underlying [`Instant`].
+    #[must_use]
+    pub const fn as_instant(&self) -> &Instant {
+        &self.0
+    }
+}
+
+fn nanos_to_duration(nanos: i128) -> Option<Duration> {
+    if nanos < 0 {
+        return None;
+    }
+    let secs = u64::try_from(nanos / 1_000_000_000).ok()?;
+    Some(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let nanos = self.epoch_nanos();
+        let sign = if nanos < 0 { "-" } else { "" };
+        let nanos = nanos.unsigned_abs();
+        write!(
+            f,
+            "{sign}{}.{:09}",
+            nanos / 1_000_000_000,
+            nanos % 1_000_000_000
+        )
+    }
+}
+
+// ============================================================================
+// Arithmetic
+// ============================================================================
+
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// # Panics
+    /// If the result is outside the supported range. See [`Timestamp::checked_add`].
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("overflow when adding duration to timestamp")
+    }
+}
+
+impl AddAssign<Duration> for Timestamp {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// # Panics
+    /// If the result is outside the supported range. See [`Timestamp::checked_sub`].
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting duration from timestamp")
+    }
+}
+
+impl SubAssign<Duration> for Timestamp {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl PartialEq<Instant> for Timestamp {
+    fn eq(&self, other: &Instant) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialOrd<Instant> for Timestamp {
+    fn partial_cmp(&self, other: &Instant) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+// ============================================================================
+// Conversions
+// ============================================================================
+
+impl From<Instant> for Timestamp {
+    fn from(value: Instant) -> Self {
+        Timestamp(value)
+    }
+}
+
+impl From<Timestamp> for Instant {
+    fn from(value: Timestamp) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<Instant> for Timestamp {
+    fn as_ref(&self) -> &Instant {
+        &self.0
+    }
+}
+
+impl TryFrom<SystemTime> for Timestamp {
+    type Error = TimestampError;
+
+    fn try_from(value: SystemTime) -> Result<Self, Self::Error> {
+        let nanos = match value.duration_since(UNIX_EPOCH) {
+            Ok(after) => i128::try_from(after.as_nanos()),
+            Err(before) => i128::try_from(before.duration().as_nanos()).map(|nanos| -nanos),
+        }
+        .map_err(|_| TimestampError::OutOfRange)?;
+        Self::from_epoch_nanos(nanos)
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(value: Timestamp) -> Self {
+        let nanos = value.epoch_nanos();
+        let offset = nanos_to_duration(nanos.abs())
+            .expect("Timestamp range should always be representable as a duration");
+        if nanos < 0 {
+            UNIX_EPOCH - offset
+        } else {
+            UNIX_EPOCH + offset
+        }
+    }
+}
+
+// ============================================================================
+// Formatting
+// ----------
+// Supports the formats defined by the Smithy `@timestampFormat` trait.
+// See: https://smithy.io/2.0/spec/protocol-traits.html#timestampformat-trait
+// ============================================================================
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+impl Timestamp {
+    /// Create a timestamp from a (possibly fractional) number of seconds since the Unix epoch.
+    ///
+    /// The value is rounded to millisecond precision.
+    ///
+    /// # Errors
+    /// Returns [`TimestampError::OutOfRange`] if the value is not finite or
+    /// is outside the supported range.
+    pub fn from_epoch_seconds(seconds: f64) -> Result<Self, TimestampError> {
+        let millis = (seconds * 1000.0).round();
+        if !millis.is_finite() || millis.abs() > i64::MAX as f64 {
+            return Err(TimestampError::OutOfRange);
+        }
+        Self::from_epoch_millis(millis as i64)
+    }
+
+    /// Format the timestamp as a string using a Smithy timestamp format.
+    ///
+    /// Fractional seconds are only included if they are non-zero. `epoch-seconds`
+    /// timestamps are formatted as a decimal number (e.g. `1515531081.123`).
+    ///
+    /// # Errors
+    /// Returns [`TimestampError::UnsupportedFormat`] if the format is unknown.
+    pub fn format(&self, format: &TimestampFormatTrait) -> Result<String, TimestampError> {
+        match format {
+            TimestampFormatTrait::DateTime => Ok(self.format_date_time()),
+            TimestampFormatTrait::HttpDate => Ok(self.format_http_date()),
+            TimestampFormatTrait::EpochSeconds => Ok(self.format_epoch_seconds()),
+            TimestampFormatTrait::Unknown(other) => {
+                Err(TimestampError::UnsupportedFormat(other.clone()))
+            }
+        }
+    }
+
+    /// Parse a timestamp from a string using a Smithy timestamp format.
+    ///
+    /// # Errors
+    /// Returns [`TimestampError::Parse`] if the value is not valid for the format,
+    /// or [`TimestampError::UnsupportedFormat`] if the format is unknown.
+    pub fn parse(value: &str, format: &TimestampFormatTrait) -> Result<Self, TimestampError> {
+        match format {
+            TimestampFormatTrait::DateTime => Instant::from_utf8(value.as_bytes())
+                .map(Timestamp)
+                .map_err(|_| TimestampError::Parse(value.to_string())),
+            TimestampFormatTrait::HttpDate => parse_http_date(value),
+            TimestampFormatTrait::EpochSeconds => parse_epoch_seconds(value),
+            TimestampFormatTrait::Unknown(other) => {
+                Err(TimestampError::UnsupportedFormat(other.clone()))
+            }
+        }
+    }
+
+    /// Split into whole seconds since the epoch (rounded towards negative infinity)
+    /// and the remaining (positive) nanoseconds.
+    fn split_seconds(&self) -> (i64, u32) {
+        let nanos = self.epoch_nanos();
+        (
+            nanos.div_euclid(NANOS_PER_SECOND) as i64,
+            nanos.rem_euclid(NANOS_PER_SECOND) as u32,
+        )
+    }
+
+    fn format_epoch_seconds(&self) -> String {
+        let nanos = self.epoch_nanos();
+        let sign = if nanos < 0 { "-" } else { "" };
+        let nanos = nanos.unsigned_abs();
+        format!(
+            "{sign}{}{}",
+            nanos / NANOS_PER_SECOND as u128,
+            fraction((nanos % NANOS_PER_SECOND as u128) as u32)
+        )
+    }
+
+    fn format_date_time(&self) -> String {
+        let (seconds, nanos) = self.split_seconds();
+        let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+        let time = seconds.rem_euclid(SECONDS_PER_DAY);
+        let year = if (0..=9999).contains(&year) {
+            format!("{year:04}")
+        } else {
+            // Expanded (6-digit, signed) year representation from RFC 9557
+            format!("{year:+07}")
+        };
+        format!(
+            "{year}-{month:02}-{day:02}T{:02}:{:02}:{:02}{}Z",
+            time / 3600,
+            (time % 3600) / 60,
+            time % 60,
+            fraction(nanos)
+        )
+    }
+
+    fn format_http_date(&self) -> String {
+        let (seconds, nanos) = self.split_seconds();
+        let days = seconds.div_euclid(SECONDS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        let time = seconds.rem_euclid(SECONDS_PER_DAY);
+        // 1970-01-01 was a Thursday
+        let weekday = WEEKDAYS[(days + 4).rem_euclid(7) as usize];
+        format!(
+            "{weekday}, {day:02} {} {year:04} {:02}:{:02}:{:02}{} GMT",
+            MONTHS[month as usize - 1],
+            time / 3600,
+            (time % 3600) / 60,
+            time % 60,
+            fraction(nanos)
+        )
+    }
+}
+
+/// Format fractional seconds, omitting trailing zeros (and the fraction entirely if zero).
+fn fraction(nanos: u32) -> String {
+    if nanos == 0 {
+        return String::new();
+    }
+    let digits = format!(".{nanos:09}");
+    digits.trim_end_matches('0').to_string()
+}
+
+/// Parse fractional second digits (without the leading `.`) into nanoseconds.
+///
+/// Digits beyond nanosecond precision are truncated.
+fn parse_fraction(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let truncated = &digits[..digits.len().min(9)];
+    let value: u32 = truncated.parse().ok()?;
+    Some(value * 10u32.pow(9 - truncated.len() as u32))
+}
+
+fn parse_epoch_seconds(value: &str) -> Result<Timestamp, TimestampError> {
+    let err = || TimestampError::Parse(value.to_string());
+    // Exponential notation is uncommon, so fall back to (millisecond-precision) float parsing
+    if value.contains(['e', 'E']) {
+        return Timestamp::from_epoch_seconds(value.parse().map_err(|_| err())?);
+    }
+    let (negative, unsigned) = value
+        .strip_prefix('-')
+        .map_or((false, value), |rest| (true, rest));
+    let (whole, fractional) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(err());
+    }
+    let seconds: i128 = whole.parse().map_err(|_| err())?;
+    let nanos = if fractional.is_empty() && !unsigned.ends_with('.') {
+        0
+    } else {
+        parse_fraction(fractional).ok_or_else(err)?
+    };
+    let total = seconds
+        .checked_mul(NANOS_PER_SECOND)
+        .and_then(|s| s.checked_add(i128::from(nanos)))
+        .ok_or(TimestampError::OutOfRange)?;
+    Timestamp::from_epoch_nanos(if negative { -total } else { total })
+}
+
+/// Parse an [IMF-fixdate](https://httpwg.org/specs/rfc9110.html#http.date) timestamp
+/// (e.g. `Tue, 29 Apr 2014 18:30:38 GMT`), with optional fractional seconds.
+fn parse_http_date(value: &str) -> Result<Timestamp, TimestampError> {
+    let err = || TimestampError::Parse(value.to_string());
+    let mut parts = value.split_ascii_whitespace();
+    let (Some(weekday), Some(day), Some(month), Some(year), Some(time), Some("GMT"), None) = (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) else {
+        return Err(err());
+    };
+    if !weekday
+        .strip_suffix(',')
+        .is_some_and(|w| WEEKDAYS.contains(&w))
+    {
+        return Err(err());
+    }
+    let day: u32 = parse_digits(day, 2).ok_or_else(err)?;
+    let month = MONTHS.iter().position(|m| *m == month).ok_or_else(err)? as u32 + 1;
+    let year: i64 = parse_digits(year, 4).ok_or_else(err)?;
+    let (hms, fractional) = time.split_once('.').unwrap_or((time, ""));
+    let mut hms = hms.split(':');
+    let (Some(hour), Some(minute), Some(second), None) =
+        (hms.next(), hms.next(), hms.next(), hms.next())
+    else {
+        return Err(err());
+    };
+    let hour: i64 = parse_digits(hour, 2).ok_or_else(err)?;
+    let minute: i64 = parse_digits(minute, 2).ok_or_else(err)?;
+    let second: i64 = parse_digits(second, 2).ok_or_else(err)?;
+    let nanos = if time.contains('.') {
+        parse_fraction(fractional).ok_or_else(err)?
+    } else {
+        0
+    };
+    if !(1..=days_in_month(year, month)).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return Err(err());
+    }
+    let seconds =
+        days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second;
+    Timestamp::from_epoch_nanos(i128::from(seconds) * NANOS_PER_SECOND + i128::from(nanos))
+}
+
+/// Parse a fixed-width, unsigned, decimal field.
+fn parse_digits<T: std::str::FromStr>(value: &str, width: usize) -> Option<T> {
+    if value.len() != width || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+const fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+const fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since the Unix epoch for a proleptic Gregorian calendar date.
+///
+/// See: <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+const fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian calendar date for a number of days since the Unix epoch.
+///
+/// See: <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+const fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+/// Errors that can occur when creating or converting a [`Timestamp`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum TimestampError {
+    /// Value is outside the range of supported timestamps.
+    #[error("Timestamp is outside the supported range")]
+    OutOfRange,
+    /// Value could not be parsed as a timestamp in the expected format.
+    #[error("Invalid timestamp `{0}`")]
+    Parse(String),
+    /// Timestamp format is not supported.
+    #[error("Unsupported timestamp format: {0}")]
+    UnsupportedFormat(String),
+}
+
+// ============================================================================
+// Serde
+// ============================================================================
+
+impl SerializeWithSchema for Timestamp {
+    #[inline]
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.write_timestamp(schema, &self.0)
+    }
+}
+
+impl<'de> DeserializeWithSchema<'de> for Timestamp {
+    #[inline]
+    fn deserialize_with_schema<D>(schema: &Schema, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.read_timestamp(schema).map(Timestamp)
+    }
+}
+
+impl ErrorCorrectionDefault for Timestamp {
+    #[inline]
+    fn default() -> Self {
+        Timestamp::unix_epoch()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_comparison() {
+        let start = Timestamp::from_epoch_millis(1_500).unwrap();
+        let later = start + Duration::from_millis(250);
+        assert!(later > start);
+        assert_eq!(later.epoch_millis(), 1_750);
+        assert_eq!(later - Duration::from_millis(250), start);
+        assert_eq!(
+            later.duration_since(start),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(start.duration_since(later), None);
+
+        let mut value = start;
+        value += Duration::from_secs(1);
+        value -= Duration::from_millis(500);
+        assert_eq!(value.epoch_millis(), 2_000);
+    }
+
+    #[test]
+    fn supports_pre_epoch_timestamps() {
+        let before = Timestamp::unix_epoch() - Duration::from_millis(1_500);
+        assert_eq!(before.epoch_millis(), -1_500);
+        assert_eq!(before.epoch_nanos(), -1_500_000_000);
+        assert!(before < Timestamp::unix_epoch());
+        assert_eq!(before.to_string(), "-1.500000000");
+        assert_eq!(
+            Timestamp::unix_epoch().duration_since(before),
+            Some(Duration::from_millis(1_500))
+        );
+    }
+
+    #[test]
+    fn rounds_partial_millis_towards_negative_infinity() {
+        let ts = Timestamp::from_epoch_nanos(-1).unwrap();
+        assert_eq!(ts.epoch_millis(), -1);
+    }
+
+    #[test]
+    fn converts_to_and_from_system_time() {
+        for millis in [0, 1_234_567, -1_234_567] {
+            let ts = Timestamp::from_epoch_millis(millis).unwrap();
+            let system = SystemTime::from(ts);
+            assert_eq!(Timestamp::try_from(system).unwrap(), ts);
+        }
+        let before = SystemTime::from(Timestamp::from_epoch_millis(-1_000).unwrap());
+        assert_eq!(
+            UNIX_EPOCH.duration_since(before).unwrap(),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn out_of_range_values_are_rejected() {
+        assert_eq!(
+            Timestamp::from_epoch_millis(i64::MAX),
+            Err(TimestampError::OutOfRange)
+        );
+        let max = Timestamp::from_epoch_millis(8_640_000_000_000_000).unwrap();
+        assert_eq!(max.checked_add(Duration::from_millis(1)), None);
+    }
+
+    #[test]
+    fn now_and_elapsed() {
+        let now = Timestamp::now();
+        assert!(now > Timestamp::unix_epoch());
+        assert_eq!((now + Duration::from_secs(60)).elapsed(), Duration::ZERO);
+        assert!((now - Duration::from_secs(60)).elapsed() >= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn converts_to_and_from_instant() {
+        let instant = Instant::from_epoch_milliseconds(-42).unwrap();
+        let ts = Timestamp::from(instant);
+        assert_eq!(ts, instant);
+        assert_eq!(Instant::from(ts), instant);
+    }
+
+    #[test]
+    fn formats_and_parses_date_time() {
+        let ts = Timestamp::from_epoch_millis(1_398_796_238_123).unwrap();
+        let formatted = ts.format(&TimestampFormatTrait::DateTime).unwrap();
+        assert_eq!(formatted, "2014-04-29T18:30:38.123Z");
+        assert_eq!(
+            Timestamp::parse(&formatted, &TimestampFormatTrait::DateTime).unwrap(),
+            ts
+        );
+        let before = Timestamp::from_epoch_millis(-1).unwrap();
+        assert_eq!(
+            before.format(&TimestampFormatTrait::DateTime).unwrap(),
+            "1969-12-31T23:59:59.999Z"
+        );
+    }
+
+    #[test]
+    fn formats_and_parses_http_date() {
+        let ts = Timestamp::from_epoch_millis(1_398_796_238_000).unwrap();
+        let formatted = ts.format(&TimestampFormatTrait::HttpDate).unwrap();
+        assert_eq!(formatted, "Tue, 29 Apr 2014 18:30:38 GMT");
+        assert_eq!(
+            Timestamp::parse(&formatted, &TimestampFormatTrait::HttpDate).unwrap(),
+            ts
+        );
+
+        let before = Timestamp::from_epoch_millis(-1_500).unwrap();
+        let formatted = before.format(&TimestampFormatTrait::HttpDate).unwrap();
+        assert_eq!(formatted, "Wed, 31 Dec 1969 23:59:58.5 GMT");
+        assert_eq!(
+            Timestamp::parse(&formatted, &TimestampFormatTrait::HttpDate).unwrap(),
+            before
+        );
+
+        for invalid in [
+            "Tue, 29 Apr 2014 18:30:38",
+            "Tue, 31 Apr 2014 18:30:38 GMT",
+            "Tue 29 Apr 2014 18:30:38 GMT",
+            "Tue, 29 Foo 2014 18:30:38 GMT",
+        ] {
+            assert!(matches!(
+                Timestamp::parse(invalid, &TimestampFormatTrait::HttpDate),
+                Err(TimestampError::Parse(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn formats_and_parses_epoch_seconds() {
+        let format = TimestampFormatTrait::EpochSeconds;
+        for (millis, expected) in [
+            (0, "0"),
+            (1_515_531_081_123, "1515531081.123"),
+            (-1_500, "-1.5"),
+            (-500, "-0.5"),
+        ] {
+            let ts = Timestamp::from_epoch_millis(millis).unwrap();
+            assert_eq!(ts.format(&format).unwrap(), expected);
+            assert_eq!(Timestamp::parse(expected, &format).unwrap(), ts);
+        }
+        assert_eq!(
+            Timestamp::parse("1.5e3", &format).unwrap().epoch_millis(),
+            1_500_000
+        );
+        assert!(matches!(
+            Timestamp::parse("1.", &format),
+            Err(TimestampError::Parse(_))
+        ));
+        assert!(matches!(
+            Timestamp::parse("abc", &format),
+            Err(TimestampError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn formats_expanded_years() {
+        let max = Timestamp::from_epoch_millis(8_640_000_000_000_000).unwrap();
+        assert_eq!(
+            max.format(&TimestampFormatTrait::DateTime).unwrap(),
+            "+275760-09-13T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_formats() {
+        let format = TimestampFormatTrait::Unknown("custom".to_string());
+        assert_eq!(
+            Timestamp::unix_epoch().format(&format),
+            Err(TimestampError::UnsupportedFormat("custom".to_string()))
+        );
+    }
+}
@@ -95,6 +95,11 @@ use crate::{
 pub trait SmithyTrait: DowncastSync + Debug {
     /// The ID of the trait as expressed in the Smithy model.
     fn id(&self) -> &ShapeId;
+
+    /// The value of the trait as a [`Document`], if it can be represented as one.
+    fn value(&self) -> Option<Box<dyn Document>> {
+        None
+    }
 }
 impl_downcast!(sync SmithyTrait);
 
@@ -107,6 +112,14 @@ impl_downcast!(sync SmithyTrait);
 pub trait StaticTraitId: SmithyTrait {
     /// Static trait ID as found in Smithy model definition of the trait.
     fn trait_id() -> &'static ShapeId;
+
+    /// Convert this trait into a [`Document`] holding its value.
+    ///
+    /// Implementations derived with `SmithyTraitImpl` serialize the trait using its schema.
+    /// Returns `None` by default.
+    fn to_document(&self) -> Option<Box<dyn Document>> {
+        None
+    }
 }
 
 // === Blanket Impl ===
@@ -114,6 +127,10 @@ impl<T: StaticTraitId> SmithyTrait for T {
     fn id(&self) -> &ShapeId {
         T::trait_id()
     }
+
+    fn value(&self) -> Option<Box<dyn Document>> {
+        self.to_document()
+    }
 }
 
 /// Convenience type for cheaply-cloneable reference to a dynamic trait.
@@ -243,6 +260,10 @@ impl SmithyTrait for DynamicTrait {
     fn id(&self) -> &ShapeId {
         &self.id
     }
+
+    fn value(&self) -> Option<Box<dyn Document>> {
+        Some(self.value.clone())
+    }
 }
 
 /// Map used to track the traits applied to a [`Schema`].
@@ -267,7 +288,7 @@ impl TraitMap {
     ///
     /// Initially created with 0 capacity so it will not allocate until it
     /// is first inserted into.
-    pub fn new() -> TraitMap {
+    pub const fn new() -> TraitMap {
         TraitMap {
             map: BTreeMap::new(),
         }
@@ -319,13 +340,28 @@ impl TraitMap {
         self.map.extend(trait_map.map.clone());
     }
 
+    /// Inserts a trait, replacing any existing trait with the same ID.
+    pub fn insert(&mut self, smithy_trait: TraitRef) {
+        self.map.insert(smithy_trait.id().clone(), smithy_trait);
+    }
+
+    /// Removes the trait with the specified ID, returning it if it was present.
+    pub fn remove(&mut self, id: &ShapeId) -> Option<TraitRef> {
+        self.map.remove(id)
+    }
+
+    /// Iterate over the traits in the map, ordered by trait ID.
+    pub fn iter(&self) -> impl Iterator<Item = &TraitRef> {
+        self.map.values()
+    }
+
     /// Create a new [`TraitMap`] from a vector of [`SmithyTraits`].
     ///
     /// This method is primarily used for constructing Schemas.
     pub(crate) fn of(traits: Vec<TraitRef>) -> Self {
         let mut map: TraitMap = TraitMap::new();
         for smithy_trait in traits {
-            map.map.insert(smithy_trait.id().clone(), smithy_trait);
+            map.insert(smithy_trait);
         }
         map
     }
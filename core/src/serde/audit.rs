@@ -0,0 +1,566 @@
+//! Data classification auditing
+//!
+//! Models can tag members or shapes with the custom `@dataClassification` trait
+//! to record the kind of data they hold (for example `"pii"` or `"financial"`):
+//!
+//! ```smithy
+//! @trait
+//! string dataClassification
+//!
+//! structure Customer {
+//!     @dataClassification("pii")
+//!     email: String
+//! }
+//! ```
+//!
+//! The [`AuditSerializer`] walks a value using its schema and produces an
+//! [`AuditReport`] listing the path and classification of every classified
+//! member, list element, and map value the value contains. Optional members
+//! that are not set are not reported. As with [`crate::serde::hooks`], the root
+//! shape passed to the serializer is never reported itself.
+//!
+//! Classifications are read with [`Schema::get_trait_as`], so a code-generated
+//! dynamic `@dataClassification` trait is resolved to a [`DataClassificationTrait`].
+//!
+//! ## Example
+//! ```rust,ignore
+//! let report = audit(&customer)?;
+//! for field in report.fields() {
+//!     println!("{field}"); // i.e. `/email: pii`
+//! }
+//! ```
+//!
+use std::fmt::{Display, Formatter};
+
+use thiserror::Error;
+
+use crate::{
+    BigDecimal, BigInt, Instant,
+    derive::{SmithyShape, SmithyTraitImpl},
+    schema::{Blob, Document, Schema},
+    serde::{
+        CodecError,
+        se::{
+            ListWriter, MapWriter, SerializableShape, SerializeWithSchema, Serializer, StructWriter,
+        },
+        serializers,
+        utils::KeySerializer,
+        validation::{PathElement, json_pointer},
+    },
+    smithy,
+};
+
+// ============================================================================
+// Classification Trait
+// ============================================================================
+
+smithy!("smithy4rs.api#dataClassification": {
+    /// Schema for [`DataClassificationTrait`]
+    string DATA_CLASSIFICATION
+});
+
+/// Tags a member or shape with the classification of the data it holds.
+#[derive(SmithyShape, SmithyTraitImpl, Clone)]
+#[smithy_schema(DATA_CLASSIFICATION)]
+#[repr(transparent)]
+pub struct DataClassificationTrait(String);
+
+// ============================================================================
+// Report
+// ============================================================================
+
+/// Classified fields found by an [`AuditSerializer`], in the order they were written.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditReport {
+    fields: Vec<ClassifiedField>,
+}
+
+impl AuditReport {
+    /// Get all classified fields in the report.
+    #[must_use]
+    pub fn fields(&self) -> &[ClassifiedField] {
+        &self.fields
+    }
+
+    /// Returns `true` if no classified fields were found.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Get the classified fields with a specific classification.
+    pub fn with_classification<'a>(
+        &'a self,
+        classification: &'a str,
+    ) -> impl Iterator<Item = &'a ClassifiedField> {
+        self.fields
+            .iter()
+            .filter(move |field| field.classification == classification)
+    }
+}
+
+/// A classified value and its location.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassifiedField {
+    paths: Vec<PathElement>,
+    classification: String,
+}
+
+impl ClassifiedField {
+    /// Get the path to the classified value.
+    #[must_use]
+    pub fn paths(&self) -> &[PathElement] {
+        &self.paths
+    }
+
+    /// Get the classification of the value.
+    #[must_use]
+    pub fn classification(&self) -> &str {
+        &self.classification
+    }
+
+    /// Render the path to the classified value as an [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901)
+    /// JSON Pointer (for example `/customer/email`).
+    #[must_use]
+    pub fn json_pointer(&self) -> String {
+        json_pointer(&self.paths)
+    }
+}
+
+impl Display for ClassifiedField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.json_pointer(), self.classification)
+    }
+}
+
+/// Audit a shape, returning all of the classified fields it contains.
+///
+/// # Errors
+/// Returns an [`AuditError`] if the shape could not be walked, for example
+/// if a map key cannot be converted to a string.
+pub fn audit<T: SerializableShape>(shape: &T) -> Result<AuditReport, AuditError> {
+    let mut auditor = AuditSerializer::new();
+    shape.serialize(&mut auditor)?;
+    Ok(auditor.finish())
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Errors that can occur while auditing a shape.
+#[derive(Error, Debug)]
+#[error("Audit error: {0}")]
+pub struct AuditError(String);
+
+impl serializers::Error for AuditError {
+    fn custom<T: Display>(msg: T) -> Self {
+        AuditError(msg.to_string())
+    }
+}
+
+impl From<AuditError> for CodecError {
+    fn from(value: AuditError) -> Self {
+        CodecError::Custom(value.to_string())
+    }
+}
+
+// ============================================================================
+// Audit Serializer
+// ============================================================================
+
+/// [`Serializer`] that records the classified fields of a value.
+///
+/// Nothing is written, the serializer only tracks the path to the current value.
+#[derive(Debug, Default)]
+pub struct AuditSerializer {
+    path: Vec<PathElement>,
+    fields: Vec<ClassifiedField>,
+}
+
+impl AuditSerializer {
+    /// Create a new auditor with an empty report.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consume the auditor, returning the report of all values written to it.
+    #[must_use]
+    pub fn finish(self) -> AuditReport {
+        AuditReport {
+            fields: self.fields,
+        }
+    }
+
+    /// Record the value at a path if its schema is classified, then walk the value.
+    fn visit<T: SerializeWithSchema>(
+        &mut self,
+        element: PathElement,
+        schema: &Schema,
+        value: &T,
+    ) -> Result<(), AuditError> {
+        self.path.push(element);
+        if let Some(classification) = schema.get_trait_as::<DataClassificationTrait>() {
+            self.fields.push(ClassifiedField {
+                paths: self.path.clone(),
+                classification: classification.to_string(),
+            });
+        }
+        value.serialize_with_schema(schema, &mut *self)?;
+        self.path.pop();
+        Ok(())
+    }
+}
+
+impl<'a> Serializer for &'a mut AuditSerializer {
+    type Error = AuditError;
+    type Ok = ();
+    type ListWriter = AuditListWriter<'a>;
+    type MapWriter = AuditWriter<'a>;
+    type StructWriter = AuditWriter<'a>;
+
+    #[inline]
+    fn write_struct(
+        self,
+        _schema: &Schema,
+        _len: usize,
+    ) -> Result<Self::StructWriter, Self::Error> {
+        Ok(AuditWriter { root: self })
+    }
+
+    #[inline]
+    fn write_map(self, _schema: &Schema, _len: usize) -> Result<Self::MapWriter, Self::Error> {
+        Ok(AuditWriter { root: self })
+    }
+
+    #[inline]
+    fn write_list(self, _schema: &Schema, _len: usize) -> Result<Self::ListWriter, Self::Error> {
+        Ok(AuditListWriter {
+            root: self,
+            index: 0,
+        })
+    }
+
+    #[inline]
+    fn write_boolean(self, _schema: &Schema, _value: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_byte(self, _schema: &Schema, _value: i8) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_short(self, _schema: &Schema, _value: i16) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_integer(self, _schema: &Schema, _value: i32) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_long(self, _schema: &Schema, _value: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_float(self, _schema: &Schema, _value: f32) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_double(self, _schema: &Schema, _value: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_big_integer(self, _schema: &Schema, _value: &BigInt) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_big_decimal(
+        self,
+        _schema: &Schema,
+        _value: &BigDecimal,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_string(self, _schema: &Schema, _value: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_blob(self, _schema: &Schema, _value: &Blob) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_timestamp(self, _schema: &Schema, _value: &Instant) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_document(
+        self,
+        _schema: &Schema,
+        _value: &Box<dyn Document>,
+    ) -> Result<Self::Ok, Self::Error> {
+        // Untyped documents carry no classifications
+        Ok(())
+    }
+
+    #[inline]
+    fn write_null(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+pub struct AuditListWriter<'a> {
+    root: &'a mut AuditSerializer,
+    index: usize,
+}
+
+impl ListWriter for AuditListWriter<'_> {
+    type Error = AuditError;
+    type Ok = ();
+
+    #[inline]
+    fn write_element<T>(&mut self, element_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        self.root
+            .visit(PathElement::Index(self.index), element_schema, value)?;
+        self.index += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+pub struct AuditWriter<'a> {
+    root: &'a mut AuditSerializer,
+}
+
+impl MapWriter for AuditWriter<'_> {
+    type Error = AuditError;
+    type Ok = ();
+
+    #[inline]
+    fn write_entry<K, V>(
+        &mut self,
+        key_schema: &Schema,
+        value_schema: &Schema,
+        key: &K,
+        value: &V,
+    ) -> Result<(), Self::Error>
+    where
+        K: SerializeWithSchema,
+        V: SerializeWithSchema,
+    {
+        let key = key.serialize_with_schema(key_schema, &mut KeySerializer::<AuditError>::new())?;
+        self.root.visit(PathElement::Key(key), value_schema, value)
+    }
+
+    #[inline]
+    fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+impl StructWriter for AuditWriter<'_> {
+    type Error = AuditError;
+    type Ok = ();
+
+    #[inline]
+    fn write_member<T>(&mut self, member_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        self.root.visit(member_schema.into(), member_schema, value)
+    }
+
+    // Members that are not in the schema have no classification
+    #[inline]
+    fn write_unknown_member(
+        &mut self,
+        _member_name: &str,
+        _value: &Box<dyn Document>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use super::*;
+    use crate::{
+        prelude::{INTEGER, STRING},
+        schema::DynamicTrait,
+    };
+
+    smithy!("com.example#Tags": {
+        map TAGS {
+            key: STRING
+            @DataClassificationTrait::new("internal");
+            value: STRING
+        }
+    });
+
+    smithy!("com.example#Emails": {
+        list EMAILS {
+            @DataClassificationTrait::new("pii");
+            member: STRING
+        }
+    });
+
+    smithy!("com.example#Address": {
+        structure ADDRESS {
+            @DataClassificationTrait::new("pii");
+            STREET: STRING = "street"
+            COUNTRY: STRING = "country"
+        }
+    });
+
+    smithy!("com.example#Customer": {
+        structure CUSTOMER {
+            ID: INTEGER = "id"
+            @DataClassificationTrait::new("pii");
+            NAME: STRING = "name"
+            @DataClassificationTrait::new("financial");
+            CARD: STRING = "card"
+            ADDRESS_MEMBER: ADDRESS = "address"
+            EMAILS_MEMBER: EMAILS = "emails"
+            TAGS_MEMBER: TAGS = "tags"
+        }
+    });
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(ADDRESS)]
+    pub struct Address {
+        #[smithy_schema(STREET)]
+        pub street: String,
+        #[smithy_schema(COUNTRY)]
+        pub country: String,
+    }
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(CUSTOMER)]
+    pub struct Customer {
+        #[smithy_schema(ID)]
+        pub id: i32,
+        #[smithy_schema(NAME)]
+        pub name: String,
+        #[smithy_schema(CARD)]
+        pub card: Option<String>,
+        #[smithy_schema(ADDRESS_MEMBER)]
+        pub address: Address,
+        #[smithy_schema(EMAILS_MEMBER)]
+        pub emails: Vec<String>,
+        #[smithy_schema(TAGS_MEMBER)]
+        pub tags: IndexMap<String, String>,
+    }
+
+    fn customer() -> Customer {
+        let mut tags = IndexMap::new();
+        tags.insert("team/owner".to_string(), "billing".to_string());
+        Customer {
+            id: 1,
+            name: "Jane".to_string(),
+            card: None,
+            address: Address {
+                street: "1 Main St".to_string(),
+                country: "US".to_string(),
+            },
+            emails: vec!["a@example.com".to_string(), "b@example.com".to_string()],
+            tags,
+        }
+    }
+
+    fn pointers(report: &AuditReport) -> Vec<String> {
+        report.fields().iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn reports_classified_fields() {
+        let report = audit(&customer()).unwrap();
+        assert_eq!(
+            pointers(&report),
+            vec![
+                "/name: pii",
+                "/address/street: pii",
+                "/emails/0: pii",
+                "/emails/1: pii",
+                "/tags/team~1owner: internal",
+            ]
+        );
+        assert_eq!(report.with_classification("pii").count(), 4);
+        assert_eq!(report.with_classification("financial").count(), 0);
+    }
+
+    #[test]
+    fn reports_set_optional_members() {
+        let mut customer = customer();
+        customer.card = Some("4111".to_string());
+        let report = audit(&customer).unwrap();
+        let financial: Vec<_> = report.with_classification("financial").collect();
+        assert_eq!(financial.len(), 1);
+        assert_eq!(financial[0].json_pointer(), "/card");
+    }
+
+    #[test]
+    fn unclassified_values_are_not_reported() {
+        let report = audit(&Address {
+            street: "1 Main St".to_string(),
+            country: "US".to_string(),
+        })
+        .unwrap();
+        assert_eq!(pointers(&report), vec!["/street: pii"]);
+    }
+
+    #[test]
+    fn resolves_dynamic_classification_trait() {
+        smithy!("com.example#Dynamic": {
+            structure DYNAMIC {
+                @DynamicTrait::from("smithy4rs.api#dataClassification", "secret");
+                VALUE: STRING = "value"
+            }
+        });
+        #[derive(SmithyShape, Clone, PartialEq)]
+        #[smithy_schema(DYNAMIC)]
+        pub struct Dynamic {
+            #[smithy_schema(VALUE)]
+            pub value: String,
+        }
+
+        let report = audit(&Dynamic {
+            value: "x".to_string(),
+        })
+        .unwrap();
+        assert_eq!(pointers(&report), vec!["/value: secret"]);
+    }
+}
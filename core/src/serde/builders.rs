@@ -20,7 +20,16 @@
 //! Will automatically generate a `TestBuilder` structure that can be used to
 //! construct and instance of the `Test` shape:
 //! ```rust,ignore
-//! let built: Test = Test::builder().a("stuff".into()).build()
+//! let built: Test = Test::builder().a("stuff").build()
+//! ```
+//!
+//! Builders also provide `set_*` methods that update the builder in place,
+//! which can be more convenient in loops and conditionals:
+//! ```rust,ignore
+//! let mut builder = Test::builder();
+//! if let Some(a) = maybe_a {
+//!     builder.set_a(a);
+//! }
 //! ```
 
 use crate::{
@@ -79,6 +88,44 @@ pub trait ShapeBuilder<'de, S: StaticSchemaShape>:
         validator.validate(S::schema(), &self)?;
         Ok(self.correct())
     }
+
+    /// Build the final shape from the builder, filling any unset required
+    /// fields before validating the result.
+    ///
+    /// Unset required fields are filled with their `@default` value if one
+    /// was modeled, or with the [`ErrorCorrectionDefault`] zero value for their
+    /// type otherwise. The corrected shape is then checked with the [`DefaultValidator`],
+    /// so constraints such as `@length` or `@range` are still enforced.
+    ///
+    /// This is intended for clients that want to tolerate missing required values
+    /// in responses (see [client error correction](https://smithy.io/2.0/spec/aggregate-types.html#client-error-correction)).
+    /// Servers should generally prefer [`Self::build()`].
+    ///
+    /// # Errors
+    /// Returns validation errors detected by the `DefaultValidator` on the
+    /// corrected shape.
+    #[inline]
+    fn build_with_correction(self) -> Validated<S>
+    where
+        S: SerializeWithSchema,
+    {
+        let shape = self.correct();
+        DefaultValidator::new().validate(S::schema(), &shape)?;
+        Ok(shape)
+    }
+
+    /// Build the final shape from the builder, filling any unset required
+    /// fields without performing any validation.
+    ///
+    /// <div class="warning">
+    /// **WARNING**: The returned shape may violate constraints from its model.
+    /// Prefer [`Self::build()`] or [`Self::build_with_correction()`] unless the
+    /// shape is known to be valid or will be validated separately.
+    /// </div>
+    #[inline]
+    fn build_unchecked_corrected(self) -> S {
+        self.correct()
+    }
 }
 
 /// Shape that can create a builder for deserialization
@@ -95,6 +142,16 @@ where
     }
 }
 
+/// Shape with a known builder type.
+///
+/// Lets generic code name the builder for a shape where an extra builder type
+/// parameter would be unconstrained (for example, in trait implementations
+/// over a shape type).
+pub trait HasBuilder: Sized + StaticSchemaShape {
+    /// Builder used to construct and deserialize this shape
+    type Builder: for<'de> ShapeBuilder<'de, Self>;
+}
+
 //============================================================================
 // Builder Adapter Types
 //============================================================================
@@ -147,6 +204,15 @@ pub enum MaybeBuilt<
     /// A builder for a structure
     Builder(B),
 }
+impl<
+    S: ErrorCorrectionDefault + SerializeWithSchema,
+    B: ErrorCorrection<Value = S> + SerializeWithSchema,
+> From<S> for MaybeBuilt<S, B>
+{
+    fn from(value: S) -> Self {
+        MaybeBuilt::Struct(value)
+    }
+}
 impl<
     E: ErrorCorrectionDefault + SerializeWithSchema,
     B: ErrorCorrection<Value = E> + SerializeWithSchema,
@@ -28,6 +28,12 @@ use crate::{
 /// of invalid responses from a server while servers should simply reject such invalid
 /// request from a client.
 ///
+/// Rather than calling [`ErrorCorrection::correct()`] directly, callers should select
+/// a correction policy when building a shape:
+/// - [`ShapeBuilder::build()`](crate::serde::ShapeBuilder::build): Validate the builder, rejecting missing required values.
+/// - [`ShapeBuilder::build_with_correction()`](crate::serde::ShapeBuilder::build_with_correction): Fill missing required values, then validate.
+/// - [`ShapeBuilder::build_unchecked_corrected()`](crate::serde::ShapeBuilder::build_unchecked_corrected): Fill missing required values with no validation.
+///
 /// - **See**: [Structure member optionality](https://smithy.io/2.0/spec/aggregate-types.html#structure-member-optionality)
 ///
 pub trait ErrorCorrection {
@@ -80,7 +86,7 @@ macro_rules! correction_default_impl {
         }
     };
 }
-correction_default_impl!(bool, true);
+correction_default_impl!(bool, false);
 correction_default_impl!(i8, 0i8);
 correction_default_impl!(i16, 0i16);
 correction_default_impl!(i32, 0i32);
@@ -0,0 +1,211 @@
+//! # Deadlines
+//!
+//! Deserializing and validating a very large (or deliberately slow) payload can
+//! occupy a worker for a long time. A [`Deadline`] bounds that work: codecs and
+//! validators that support deadlines call [`Deadline::check`] as they step through
+//! collection elements and structure members, and abort with [`DeadlineExceeded`]
+//! once the deadline has passed or its [`CancellationToken`] has been cancelled.
+//!
+//! ```rust,ignore
+//! let token = CancellationToken::new();
+//! let deadline = Deadline::after(Duration::from_millis(50)).with_token(token.clone());
+//!
+//! let mut de = JsonDeserializer::new(body).with_deadline(deadline.clone());
+//! let builder = MyShapeBuilder::deserialize_with_schema(&MY_SHAPE_SCHEMA, &mut de)?;
+//! let shape = builder.build_with_validator(&mut DefaultValidator::new().with_deadline(deadline))?;
+//! ```
+//!
+//! Checks are cooperative, so a single value (for example, one very long string)
+//! is always read in full before the deadline is checked again.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+
+/// Number of calls to [`Deadline::check`] between reads of the clock.
+///
+/// Reading the clock is much slower than checking a cancellation token, so the
+/// clock is only read periodically.
+const CLOCK_CHECK_INTERVAL: u32 = 64;
+
+// ============================================================================
+// Cancellation
+// ============================================================================
+
+/// Shared flag used to cancel in-progress work from another thread or task.
+///
+/// Clones of a token share the same flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a new token that has not been cancelled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel all work that is checking this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the token has been cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// ============================================================================
+// Deadlines
+// ============================================================================
+
+/// Bounds the time spent deserializing or validating a shape.
+///
+/// The default deadline never expires.
+#[derive(Debug, Clone, Default)]
+pub struct Deadline {
+    expires_at: Option<Instant>,
+    token: Option<CancellationToken>,
+    checks: u32,
+}
+
+impl Deadline {
+    /// Create a deadline that never expires.
+    #[must_use]
+    pub const fn none() -> Self {
+        Deadline {
+            expires_at: None,
+            token: None,
+            checks: 0,
+        }
+    }
+
+    /// Create a deadline that expires at `instant`.
+    #[must_use]
+    pub const fn at(instant: Instant) -> Self {
+        Deadline {
+            expires_at: Some(instant),
+            token: None,
+            checks: 0,
+        }
+    }
+
+    /// Create a deadline that expires once `duration` has elapsed.
+    #[must_use]
+    pub fn after(duration: Duration) -> Self {
+        Instant::now()
+            .checked_add(duration)
+            .map_or_else(Self::none, Self::at)
+    }
+
+    /// Also expire the deadline when `token` is cancelled.
+    #[must_use]
+    pub fn with_token(mut self, token: CancellationToken) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Get the instant at which this deadline expires, if any.
+    #[must_use]
+    pub const fn expires_at(&self) -> Option<Instant> {
+        self.expires_at
+    }
+
+    /// Check whether the deadline has expired.
+    ///
+    /// The cancellation token is checked on every call, but the clock is only
+    /// read every few calls, so this is cheap enough to call once per value.
+    ///
+    /// # Errors
+    /// Returns [`DeadlineExceeded`] if the token was cancelled or the deadline has passed.
+    #[inline]
+    pub fn check(&mut self) -> Result<(), DeadlineExceeded> {
+        if let Some(token) = &self.token
+            && token.is_cancelled()
+        {
+            return Err(DeadlineExceeded::Cancelled);
+        }
+        let Some(expires_at) = self.expires_at else {
+            return Ok(());
+        };
+        let checks = self.checks;
+        self.checks = checks.wrapping_add(1);
+        if checks.is_multiple_of(CLOCK_CHECK_INTERVAL) && Instant::now() >= expires_at {
+            return Err(DeadlineExceeded::TimedOut);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the token was cancelled or the deadline has passed.
+    ///
+    /// Unlike [`Deadline::check`], this always reads the clock.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.token
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+            || self
+                .expires_at
+                .is_some_and(|expires_at| Instant::now() >= expires_at)
+    }
+}
+
+/// Error raised when work is aborted by a [`Deadline`].
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadlineExceeded {
+    /// The deadline passed before the work completed.
+    #[error("Deadline exceeded")]
+    TimedOut,
+    /// The deadline's [`CancellationToken`] was cancelled.
+    #[error("Cancelled")]
+    Cancelled,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_deadline_never_expires() {
+        let mut deadline = Deadline::default();
+        for _ in 0..(CLOCK_CHECK_INTERVAL * 2) {
+            assert!(deadline.check().is_ok());
+        }
+        assert!(!deadline.is_expired());
+    }
+
+    #[test]
+    fn past_deadline_expires_on_first_check() {
+        let mut deadline = Deadline::at(Instant::now());
+        assert!(deadline.is_expired());
+        assert_eq!(deadline.check(), Err(DeadlineExceeded::TimedOut));
+    }
+
+    #[test]
+    fn far_deadline_does_not_expire() {
+        let mut deadline = Deadline::after(Duration::from_secs(3600));
+        assert!(deadline.check().is_ok());
+        assert!(!deadline.is_expired());
+        // Durations that overflow the clock never expire
+        assert_eq!(Deadline::after(Duration::MAX).expires_at(), None);
+    }
+
+    #[test]
+    fn cancelled_token_expires_immediately() {
+        let token = CancellationToken::new();
+        let mut deadline = Deadline::none().with_token(token.clone());
+        assert!(deadline.check().is_ok());
+        token.cancel();
+        assert!(token.is_cancelled());
+        assert!(deadline.is_expired());
+        assert_eq!(deadline.check(), Err(DeadlineExceeded::Cancelled));
+    }
+}
@@ -18,10 +18,12 @@ use std::fmt::{Debug, DebugList, DebugMap, DebugStruct, Display, Error, Formatte
 use thiserror::Error;
 
 use crate::{
-    BigDecimal, BigInt, ByteBuffer, Instant,
-    schema::{Document, Schema, prelude::SensitiveTrait},
+    BigDecimal, BigInt, Instant,
+    schema::{Blob, Document, Schema, StreamingBlob, prelude::SensitiveTrait},
     serde::{
+        CodecError,
         debug::FmtError::Custom,
+        eventstream::EventStream,
         se::{ListWriter, MapWriter, SerializeWithSchema, Serializer, StructWriter},
     },
 };
@@ -74,6 +76,14 @@ impl SerdeError for FmtError {
         Custom(msg.to_string())
     }
 }
+impl From<FmtError> for CodecError {
+    fn from(value: FmtError) -> Self {
+        match value {
+            FmtError::ExpectedMember(_) => CodecError::type_mismatch("", value),
+            FmtError::Fmt(_) | Custom(_) => CodecError::Custom(value.to_string()),
+        }
+    }
+}
 
 // ============================================================================
 // Debug Serializer
@@ -93,9 +103,20 @@ macro_rules! redact {
 struct DebugSerializer<'a, 'b: 'a> {
     fmt: &'a mut Formatter<'b>,
 }
-const REDACTED_ITEM: &str = "**REDACTED**";
-const REDACTED_LIST: &str = "[**REDACTED**]";
-const REDACTED_MAP: &str = "{**REDACTED**}";
+pub(super) const REDACTED_ITEM: &str = "**REDACTED**";
+pub(super) const REDACTED_LIST: &str = "[**REDACTED**]";
+pub(super) const REDACTED_MAP: &str = "{**REDACTED**}";
+
+/// Get the name of the shape a schema represents.
+///
+/// Member schemas share the ID of their parent shape, so the
+/// name of the member target is used instead.
+#[inline]
+pub(super) fn shape_name(schema: &Schema) -> &str {
+    schema
+        .as_member()
+        .map_or_else(|| schema.id().name(), |member| member.target.id().name())
+}
 
 impl<'a, 'b> Serializer for DebugSerializer<'a, 'b> {
     type Error = FmtError;
@@ -106,13 +127,13 @@ impl<'a, 'b> Serializer for DebugSerializer<'a, 'b> {
 
     fn write_struct(self, schema: &Schema, _: usize) -> Result<Self::StructWriter, Self::Error> {
         if schema.contains_type::<SensitiveTrait>() {
-            self.fmt.write_str(schema.id().name())?;
+            self.fmt.write_str(shape_name(schema))?;
             // Replace entire structure contents with redacted placeholder
             self.fmt.write_str(REDACTED_MAP)?;
             Ok(DebugStructSerializer::Redacted)
         } else {
             Ok(DebugStructSerializer::Unredacted(
-                self.fmt.debug_struct(schema.id().name()),
+                self.fmt.debug_struct(shape_name(schema)),
             ))
         }
     }
@@ -202,11 +223,31 @@ impl<'a, 'b> Serializer for DebugSerializer<'a, 'b> {
     }
 
     #[inline]
-    fn write_blob(self, schema: &Schema, value: &ByteBuffer) -> Result<Self::Ok, Self::Error> {
+    fn write_blob(self, schema: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
+        redact!(self, schema, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_streaming_blob(
+        self,
+        schema: &Schema,
+        value: &StreamingBlob,
+    ) -> Result<Self::Ok, Self::Error> {
         redact!(self, schema, value);
         Ok(())
     }
 
+    #[inline]
+    fn write_event_stream<T>(
+        self,
+        _schema: &Schema,
+        value: &EventStream<T>,
+    ) -> Result<Self::Ok, Self::Error> {
+        Debug::fmt(value, self.fmt)?;
+        Ok(())
+    }
+
     #[inline]
     fn write_timestamp(self, schema: &Schema, value: &Instant) -> Result<Self::Ok, Self::Error> {
         redact!(self, schema, value);
@@ -377,7 +418,7 @@ mod tests {
     use super::*;
     use crate::{
         IndexMap,
-        derive::SmithyShape,
+        derive::{SmithyShape, smithy_structure, smithy_union},
         schema::prelude::{MediaTypeTrait, STRING},
         smithy,
     };
@@ -500,6 +541,103 @@ mod tests {
         );
     }
 
+    smithy!("com.example#Password": {
+        @SensitiveTrait::builder().build();
+        string PASSWORD
+    });
+    smithy!("com.example#Credentials": {
+        @SensitiveTrait::builder().build();
+        structure CREDENTIALS {
+            TOKEN: STRING = "token"
+        }
+    });
+    smithy!("com.example#Login": {
+        structure LOGIN {
+            USER: STRING = "user"
+            PASSWORD: PASSWORD = "password"
+            CREDENTIALS: CREDENTIALS = "credentials"
+        }
+    });
+    smithy!("com.example#Secret": {
+        union SECRET_UNION {
+            PLAIN: STRING = "plain"
+            HIDDEN: PASSWORD = "hidden"
+        }
+    });
+
+    #[smithy_structure]
+    #[derive(SmithyShape)]
+    #[smithy_schema(CREDENTIALS)]
+    pub struct Credentials {
+        #[smithy_schema(TOKEN)]
+        pub token: String,
+    }
+
+    // `Debug` is removed so that it does not conflict with the derived redacting impl
+    #[smithy_structure]
+    #[derive(SmithyShape, Clone, Debug)]
+    #[smithy_schema(LOGIN)]
+    pub struct Login {
+        #[smithy_schema(USER)]
+        pub user: String,
+        #[smithy_schema(PASSWORD)]
+        pub password: String,
+        #[smithy_schema(CREDENTIALS)]
+        pub credentials: Credentials,
+    }
+
+    #[smithy_union]
+    #[derive(SmithyShape)]
+    #[smithy_schema(SECRET_UNION)]
+    pub enum Secret {
+        #[smithy_schema(PLAIN)]
+        Plain(String),
+        #[smithy_schema(HIDDEN)]
+        Hidden(String),
+    }
+
+    #[test]
+    fn redacts_members_targeting_sensitive_shapes() {
+        let login = Login {
+            user: "user".to_string(),
+            password: "hunter2".to_string(),
+            credentials: Credentials {
+                token: "abc".to_string(),
+            },
+        };
+        assert_eq!(
+            format!("{login:?}"),
+            "Login { user: \"user\", password: **REDACTED**, credentials: Credentials{**REDACTED**} }"
+        );
+    }
+
+    #[test]
+    fn missing_derives_are_added() {
+        let login = Login {
+            user: "user".to_string(),
+            password: "hunter2".to_string(),
+            credentials: Credentials {
+                token: "abc".to_string(),
+            },
+        };
+        let copy = login.clone();
+        assert!(copy == login);
+        assert!(copy.credentials == login.credentials);
+        assert_eq!(format!("{copy:?}"), format!("{login:?}"));
+
+        let secret = Secret::Hidden("hunter2".to_string());
+        assert!(secret.clone() == secret);
+        assert!(secret != Secret::Plain("hunter2".to_string()));
+    }
+
+    #[test]
+    fn redacts_sensitive_union_variants() {
+        let plain = Secret::Plain("visible".to_string());
+        assert_eq!(format!("{plain:?}"), "Secret { plain: \"visible\" }");
+        let hidden = Secret::Hidden("hunter2".to_string());
+        assert_eq!(format!("{hidden:?}"), "Secret { hidden: **REDACTED** }");
+    }
+
     #[test]
     fn document_conversion_retains_redaction() {
         let mut map = IndexMap::new();
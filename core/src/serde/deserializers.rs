@@ -15,11 +15,12 @@
 //! This design (inspired by `serde`) separates iteration from value reading,
 //! allowing callers to control the deserialization flow.
 
-use std::{error::Error as StdError, fmt::Display};
+use std::{borrow::Cow, error::Error as StdError, fmt::Display};
 
 use crate::{
     BigDecimal, BigInt, ByteBuffer, IndexMap, Instant,
-    schema::{Document, Schema, SchemaShape, StaticSchemaShape},
+    schema::{Blob, Document, Schema, SchemaShape, ShapeType, StaticSchemaShape, StreamingBlob},
+    serde::eventstream::EventStream,
 };
 
 // ============================================================================
@@ -30,6 +31,36 @@ use crate::{
 pub trait Error: Sized + StdError {
     /// Create a custom error message
     fn custom<T: Display>(msg: T) -> Self;
+
+    /// Create an error for a struct member that appears more than once in the input.
+    ///
+    /// Defaults to a [`Error::custom`] error naming the member.
+    fn duplicate_member(member: &Schema) -> Self {
+        Self::custom(format!("Duplicate member `{}`", member.id().id()))
+    }
+
+    /// Create an error for members of a struct that are not in its schema.
+    ///
+    /// Only raised in strict mode (see [`UnknownMembers`](crate::serde::strict::UnknownMembers)). Defaults to a
+    /// [`Error::custom`] error naming the shape and the unknown members.
+    fn unknown_members(schema: &Schema, members: Vec<String>) -> Self {
+        Self::custom(format!(
+            "Unknown members of `{}`: {}",
+            schema.id().id(),
+            members.join(", ")
+        ))
+    }
+
+    /// Create an error for a value that is not a known variant of an `enum` or `intEnum`.
+    ///
+    /// Only raised when unknown values are rejected (see [`UnknownEnumPolicy::Error`]).
+    /// Defaults to a [`Error::custom`] error naming the enum and the value.
+    fn unknown_enum_value<V: Display>(schema: &Schema, value: V) -> Self {
+        Self::custom(format!(
+            "Unknown value `{value}` for enum `{}`",
+            schema.id().id()
+        ))
+    }
 }
 
 // ============================================================================
@@ -77,6 +108,21 @@ pub trait StructReader<'de> {
     /// `skip_value()` before calling `read_member()` again.
     fn read_member<'a>(&mut self, schema: &'a Schema) -> Result<Option<&'a Schema>, Self::Error>;
 
+    /// Read the next member, returning the name of members that are not in the
+    /// schema instead of skipping them.
+    ///
+    /// This allows shapes such as unions to retain the raw value of members they do
+    /// not know about. Unknown values can be read as a document using the
+    /// [`DOCUMENT`](crate::prelude::DOCUMENT) schema.
+    ///
+    /// By default, unknown members are skipped as in [`StructReader::read_member`].
+    fn read_member_or_unknown<'a>(
+        &mut self,
+        schema: &'a Schema,
+    ) -> Result<Option<MemberKey<'a>>, Self::Error> {
+        Ok(self.read_member(schema)?.map(MemberKey::Known))
+    }
+
     /// Read the current member's value.
     ///
     /// Must be called after `read_member()` returns `Some`.
@@ -96,6 +142,104 @@ pub trait StructReader<'de> {
     }
 }
 
+/// Key of a member read by [`StructReader::read_member_or_unknown`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MemberKey<'a> {
+    /// Member found in the schema
+    Known(&'a Schema),
+    /// Name of a member that is not in the schema
+    Unknown(String),
+}
+
+/// Tracks which members of a struct have been read, by member index.
+///
+/// Structs may be read with members in any order, so this is used to detect
+/// members that appear more than once instead of silently overwriting the
+/// earlier value.
+///
+/// ```ignore
+/// let mut present = MemberPresence::new();
+/// while let Some(member_schema) = reader.read_member(schema)? {
+///     if !present.insert(member_schema) {
+///         return Err(D::Error::duplicate_member(member_schema));
+///     }
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemberPresence {
+    bits: u64,
+    overflow: Vec<u64>,
+}
+
+impl MemberPresence {
+    /// Create a new, empty set of present members.
+    #[must_use]
+    pub const fn new() -> Self {
+        MemberPresence {
+            bits: 0,
+            overflow: Vec::new(),
+        }
+    }
+
+    /// Mark a member as present.
+    ///
+    /// Returns `false` if the member was already present. Schemas that are
+    /// not members are always treated as newly present.
+    pub fn insert(&mut self, member: &Schema) -> bool {
+        let Some(member) = member.as_member() else {
+            return true;
+        };
+        let index = member.index();
+        let word = if index < 64 {
+            &mut self.bits
+        } else {
+            let word_index = index / 64 - 1;
+            if self.overflow.len() <= word_index {
+                self.overflow.resize(word_index + 1, 0);
+            }
+            &mut self.overflow[word_index]
+        };
+        let mask = 1 << (index % 64);
+        let newly_present = *word & mask == 0;
+        *word |= mask;
+        newly_present
+    }
+
+    /// Returns `true` if the member has been marked as present.
+    #[must_use]
+    pub fn contains(&self, member: &Schema) -> bool {
+        let Some(member) = member.as_member() else {
+            return false;
+        };
+        let index = member.index();
+        let word = if index < 64 {
+            self.bits
+        } else {
+            self.overflow.get(index / 64 - 1).copied().unwrap_or(0)
+        };
+        word & (1 << (index % 64)) != 0
+    }
+}
+
+/// Set the value of a union being deserialized.
+///
+/// Members with an explicit `null` value (read as `None`) are treated as if they
+/// were not set at all, so `{"a": null, "b": 1}` selects the `b` member.
+///
+/// # Errors
+/// Returns [`Error`] if a non-null member of the union has already been set.
+pub fn set_union_value<T, E: Error>(result: &mut Option<T>, value: Option<T>) -> Result<(), E> {
+    let Some(value) = value else {
+        return Ok(());
+    };
+    if result.is_some() {
+        return Err(E::custom("Attempted to set union value twice"));
+    }
+    *result = Some(value);
+    Ok(())
+}
+
 /// Reader for list elements.
 ///
 /// Iterates through list elements, deserializing each one.
@@ -176,12 +320,42 @@ pub trait MapReader<'de> {
 // Core Deserializer Trait
 // ============================================================================
 
+/// Raw value of an `enum` or `intEnum` read by [`Deserializer::read_enum`].
+///
+/// Unlike [`EnumValue`](crate::schema::EnumValue), this may hold a value that
+/// does not match any known variant of the enum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RawEnumValue<'de> {
+    /// Value of an `enum`
+    String(Cow<'de, str>),
+    /// Value of an `intEnum`
+    Int(i32),
+}
+
+/// How values that do not match a known variant of an `enum` or `intEnum` are deserialized.
+///
+/// Smithy enums are open, so by default unknown values are kept in the `Unknown`
+/// variant of the enum. Servers that must reject unknown values can configure their
+/// deserializer to raise an [`Error::unknown_enum_value`] error instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnknownEnumPolicy {
+    /// Deserialize unknown values into the `Unknown` variant
+    #[default]
+    MapToUnknown,
+    /// Fail deserialization on unknown values
+    Error,
+}
+
 /// A `Deserializer` reads data from an input source, guided by Smithy schemas.
 ///
 /// This trait mirrors the [`Serializer`](crate::serde::se::Serializer) trait, providing
 /// schema-guided deserialization for all Smithy data types. It uses a reader pattern
 /// for compound types (structs, lists, maps) where the deserializer returns reader
 /// objects that allow iterating through members/elements/entries.
+///
+/// All `read_*` methods have default implementations that return an error naming the
+/// method and the shape being read, so deserializers only need to implement the
+/// methods for data types their format can represent.
 pub trait Deserializer<'de>: Sized {
     /// The error type that can be returned if deserialization fails.
     type Error: Error;
@@ -201,130 +375,166 @@ pub trait Deserializer<'de>: Sized {
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a bool.
-    fn read_bool(self, _schema: &Schema) -> Result<bool, Self::Error> {
-        Err(Error::custom(
-            "read_bool is not supported by this deserializer",
-        ))
+    fn read_bool(self, schema: &Schema) -> Result<bool, Self::Error> {
+        Err(unsupported("read_bool", schema))
     }
 
     /// Read a byte (`i8`)
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a `byte`.
-    fn read_byte(self, _schema: &Schema) -> Result<i8, Self::Error> {
-        Err(Error::custom(
-            "read_byte is not supported by this deserializer",
-        ))
+    fn read_byte(self, schema: &Schema) -> Result<i8, Self::Error> {
+        Err(unsupported("read_byte", schema))
     }
 
     /// Read a short (`i16`)
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a `short`.
-    fn read_short(self, _schema: &Schema) -> Result<i16, Self::Error> {
-        Err(Error::custom(
-            "read_short is not supported by this deserializer",
-        ))
+    fn read_short(self, schema: &Schema) -> Result<i16, Self::Error> {
+        Err(unsupported("read_short", schema))
     }
 
     /// Read an integer (`i32`)
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as an `integer`.
-    fn read_integer(self, _schema: &Schema) -> Result<i32, Self::Error> {
-        Err(Error::custom(
-            "read_integer is not supported by this deserializer",
-        ))
+    fn read_integer(self, schema: &Schema) -> Result<i32, Self::Error> {
+        Err(unsupported("read_integer", schema))
     }
 
     /// Read a long (i64)
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a `long`.
-    fn read_long(self, _schema: &Schema) -> Result<i64, Self::Error> {
-        Err(Error::custom(
-            "read_long is not supported by this deserializer",
-        ))
+    fn read_long(self, schema: &Schema) -> Result<i64, Self::Error> {
+        Err(unsupported("read_long", schema))
     }
 
     /// Read a float (`f32`)
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a `float`.
-    fn read_float(self, _schema: &Schema) -> Result<f32, Self::Error> {
-        Err(Error::custom(
-            "read_float is not supported by this deserializer",
-        ))
+    fn read_float(self, schema: &Schema) -> Result<f32, Self::Error> {
+        Err(unsupported("read_float", schema))
     }
 
     /// Read a double (`f64`)
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a `double`.
-    fn read_double(self, _schema: &Schema) -> Result<f64, Self::Error> {
-        Err(Error::custom(
-            "read_double is not supported by this deserializer",
-        ))
+    fn read_double(self, schema: &Schema) -> Result<f64, Self::Error> {
+        Err(unsupported("read_double", schema))
     }
 
     /// Read a big integer
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a `bigInteger`.
-    fn read_big_integer(self, _schema: &Schema) -> Result<BigInt, Self::Error> {
-        Err(Error::custom(
-            "read_big_integer is not supported by this deserializer",
-        ))
+    fn read_big_integer(self, schema: &Schema) -> Result<BigInt, Self::Error> {
+        Err(unsupported("read_big_integer", schema))
     }
 
     /// Read a big decimal
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a `bigDecimal`.
-    fn read_big_decimal(self, _schema: &Schema) -> Result<BigDecimal, Self::Error> {
-        Err(Error::custom(
-            "read_big_decimal is not supported by this deserializer",
-        ))
+    fn read_big_decimal(self, schema: &Schema) -> Result<BigDecimal, Self::Error> {
+        Err(unsupported("read_big_decimal", schema))
     }
 
     /// Read a string
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a `string`.
-    fn read_string(self, _schema: &Schema) -> Result<String, Self::Error> {
-        Err(Error::custom(
-            "read_string is not supported by this deserializer",
-        ))
+    fn read_string(self, schema: &Schema) -> Result<String, Self::Error> {
+        Err(unsupported("read_string", schema))
     }
 
-    /// Read a blob
+    /// Read a string, borrowing from the input data if possible.
+    ///
+    /// Deserializers that can return strings directly from their input buffer
+    /// (i.e. strings that do not require unescaping) should override this method
+    /// to avoid allocating. By default, this allocates using [`Deserializer::read_string`].
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the data could not be read as a `string`.
+    fn read_str(self, schema: &Schema) -> Result<Cow<'de, str>, Self::Error> {
+        self.read_string(schema).map(Cow::Owned)
+    }
+
+    /// Read the value of an `enum` or `intEnum`.
+    ///
+    /// Deserializers for formats that encode enums differently from other strings
+    /// or integers (for example, as a variant index or an XML attribute) should
+    /// override this method. By default, `intEnum` values are read using
+    /// [`Deserializer::read_integer`] and `enum` values using [`Deserializer::read_str`].
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the data could not be read as an enum value.
+    fn read_enum(self, schema: &Schema) -> Result<RawEnumValue<'de>, Self::Error> {
+        match schema.shape_type() {
+            ShapeType::IntEnum | ShapeType::Integer => {
+                self.read_integer(schema).map(RawEnumValue::Int)
+            }
+            _ => self.read_str(schema).map(RawEnumValue::String),
+        }
+    }
+
+    /// Get the policy for enum values that do not match a known variant.
+    ///
+    /// Checked by `enum` and `intEnum` shapes after reading a value with
+    /// [`Deserializer::read_enum`]. Defaults to [`UnknownEnumPolicy::MapToUnknown`].
+    fn unknown_enum_policy(&self) -> UnknownEnumPolicy {
+        UnknownEnumPolicy::MapToUnknown
+    }
+
+    /// Read a [`Blob`]
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a `blob`.
-    fn read_blob(self, _schema: &Schema) -> Result<ByteBuffer, Self::Error> {
-        Err(Error::custom(
-            "read_blob is not supported by this deserializer",
-        ))
+    fn read_blob(self, schema: &Schema) -> Result<Blob, Self::Error> {
+        Err(unsupported("read_blob", schema))
+    }
+
+    /// Read a [`@streaming`](https://smithy.io/2.0/spec/streaming.html#streaming-trait) blob.
+    ///
+    /// Deserializers that can stream data should override this method to avoid
+    /// buffering the blob. By default, this reads the data into memory using
+    /// [`Deserializer::read_blob`].
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the data could not be read as a streaming `blob`.
+    fn read_streaming_blob(self, schema: &Schema) -> Result<StreamingBlob, Self::Error> {
+        self.read_blob(schema).map(StreamingBlob::from)
+    }
+
+    /// Read a member targeting a [`@streaming`](https://smithy.io/2.0/spec/streaming.html#event-streams) union.
+    ///
+    /// Protocols that support event streams should override this method to
+    /// return the events received as separate messages. By default, event
+    /// streams are not supported.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the data could not be read as an event stream.
+    fn read_event_stream<T>(self, schema: &Schema) -> Result<EventStream<T>, Self::Error> {
+        Err(unsupported("read_event_stream", schema))
     }
 
     /// Read a timestamp
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a `timestamp`.
-    fn read_timestamp(self, _schema: &Schema) -> Result<Instant, Self::Error> {
-        Err(Error::custom(
-            "read_timestamp is not supported by this deserializer",
-        ))
+    fn read_timestamp(self, schema: &Schema) -> Result<Instant, Self::Error> {
+        Err(unsupported("read_timestamp", schema))
     }
 
     /// Read data as untyped [`Document`]
     ///
     /// # Errors
     /// Returns [`Error`] if the data could not be read as a `document`.
-    fn read_document(self, _schema: &Schema) -> Result<Box<dyn Document>, Self::Error> {
-        Err(Error::custom(
-            "read_document is not supported by this deserializer",
-        ))
+    fn read_document(self, schema: &Schema) -> Result<Box<dyn Document>, Self::Error> {
+        Err(unsupported("read_document", schema))
     }
 
     // === Compound types ===
@@ -373,10 +583,8 @@ pub trait Deserializer<'de>: Sized {
     ///
     /// # Errors
     /// Returns [`Error`] if the struct could not be started (e.g., expected `{`).
-    fn read_struct(self, _schema: &Schema) -> Result<Self::StructReader, Self::Error> {
-        Err(Error::custom(
-            "read_struct is not supported by this deserializer",
-        ))
+    fn read_struct(self, schema: &Schema) -> Result<Self::StructReader, Self::Error> {
+        Err(unsupported("read_struct", schema))
     }
 
     /// Begin reading a list, returning a reader for its elements.
@@ -398,10 +606,8 @@ pub trait Deserializer<'de>: Sized {
     ///
     /// # Errors
     /// Returns [`Error`] if the list could not be started (e.g., expected `[`).
-    fn read_list(self, _schema: &Schema) -> Result<Self::ListReader, Self::Error> {
-        Err(Error::custom(
-            "read_list is not supported by this deserializer",
-        ))
+    fn read_list(self, schema: &Schema) -> Result<Self::ListReader, Self::Error> {
+        Err(unsupported("read_list", schema))
     }
 
     /// Begin reading a map, returning a reader for its entries.
@@ -425,10 +631,8 @@ pub trait Deserializer<'de>: Sized {
     ///
     /// # Errors
     /// Returns [`Error`] if the map could not be started (e.g., expected `{`).
-    fn read_map(self, _schema: &Schema) -> Result<Self::MapReader, Self::Error> {
-        Err(Error::custom(
-            "read_map is not supported by this deserializer",
-        ))
+    fn read_map(self, schema: &Schema) -> Result<Self::MapReader, Self::Error> {
+        Err(unsupported("read_map", schema))
     }
 
     // === Null handling ===
@@ -450,6 +654,16 @@ pub trait Deserializer<'de>: Sized {
     }
 }
 
+/// Error returned by default [`Deserializer`] methods for types a deserializer does not support.
+#[cold]
+fn unsupported<E: Error>(method: &str, schema: &Schema) -> E {
+    E::custom(format!(
+        "{method} is not supported by this deserializer (reading {} `{}`)",
+        schema.shape_type(),
+        schema.id().id()
+    ))
+}
+
 /// Deserialize a shape with its pre-defined schema.
 ///
 /// This trait provides an automatic, blanket implementation for all shapes
@@ -591,12 +805,21 @@ impl<'de> DeserializeWithSchema<'de> for String {
     }
 }
 
+impl<'de> DeserializeWithSchema<'de> for Cow<'de, str> {
+    fn deserialize_with_schema<D>(schema: &Schema, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.read_str(schema)
+    }
+}
+
 impl<'de> DeserializeWithSchema<'de> for ByteBuffer {
     fn deserialize_with_schema<D>(schema: &Schema, deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.read_blob(schema)
+        deserializer.read_blob(schema).map(ByteBuffer::from)
     }
 }
 
@@ -613,7 +836,7 @@ impl<'de> DeserializeWithSchema<'de> for Instant {
 
 impl<'de, T> DeserializeWithSchema<'de> for Vec<T>
 where
-    T: for<'di> DeserializeWithSchema<'di>,
+    T: DeserializeWithSchema<'de>,
 {
     fn deserialize_with_schema<D>(schema: &Schema, deserializer: D) -> Result<Self, D::Error>
     where
@@ -697,3 +920,125 @@ where
         T::deserialize_with_schema(schema, deserializer).map(Box::new)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{INTEGER, STRING},
+        schema::ShapeId,
+        serde::CodecError,
+        traits,
+    };
+
+    /// Deserializer that only supports strings.
+    struct StringDeserializer(&'static str);
+
+    enum NeverReader {}
+
+    impl<'de> StructReader<'de> for NeverReader {
+        type Error = CodecError;
+
+        fn read_member<'a>(
+            &mut self,
+            _schema: &'a Schema,
+        ) -> Result<Option<&'a Schema>, Self::Error> {
+            match *self {}
+        }
+
+        fn read_value<T: DeserializeWithSchema<'de>>(
+            &mut self,
+            _schema: &Schema,
+        ) -> Result<T, Self::Error> {
+            match *self {}
+        }
+
+        fn skip_value(&mut self) -> Result<(), Self::Error> {
+            match *self {}
+        }
+    }
+
+    impl<'de> ListReader<'de> for NeverReader {
+        type Error = CodecError;
+
+        fn read_element<T: DeserializeWithSchema<'de>>(
+            &mut self,
+            _schema: &Schema,
+        ) -> Result<Option<T>, Self::Error> {
+            match *self {}
+        }
+    }
+
+    impl<'de> MapReader<'de> for NeverReader {
+        type Error = CodecError;
+
+        fn read_key(&mut self) -> Result<Option<String>, Self::Error> {
+            match *self {}
+        }
+
+        fn read_value<V: DeserializeWithSchema<'de>>(
+            &mut self,
+            _schema: &Schema,
+        ) -> Result<V, Self::Error> {
+            match *self {}
+        }
+
+        fn skip_value(&mut self) -> Result<(), Self::Error> {
+            match *self {}
+        }
+    }
+
+    impl Deserializer<'_> for StringDeserializer {
+        type Error = CodecError;
+        type StructReader = NeverReader;
+        type ListReader = NeverReader;
+        type MapReader = NeverReader;
+
+        fn read_string(self, _schema: &Schema) -> Result<String, Self::Error> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn default_methods_name_unsupported_type() {
+        assert_eq!(
+            String::deserialize_with_schema(&STRING, StringDeserializer("a")).unwrap(),
+            "a"
+        );
+        let err = i32::deserialize_with_schema(&INTEGER, StringDeserializer("a")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "read_integer is not supported by this deserializer (reading Integer `smithy.api#Integer`)"
+        );
+    }
+
+    #[test]
+    fn read_enum_falls_back_to_shape_type() {
+        assert_eq!(
+            StringDeserializer("a").read_enum(&STRING).unwrap(),
+            RawEnumValue::String(Cow::Borrowed("a"))
+        );
+        let err = StringDeserializer("a").read_enum(&INTEGER).unwrap_err();
+        assert!(err.to_string().starts_with("read_integer is not supported"));
+    }
+
+    #[test]
+    fn member_presence_detects_duplicates() {
+        let builder = Schema::structure_builder(ShapeId::from("com.example#Wide"), traits![]);
+        for i in 0..130 {
+            let _ = builder.put_member(&format!("m{i}"), &INTEGER, traits![]);
+        }
+        let schema = builder.build();
+        let first = schema.expect_member("m0");
+        let overflow = schema.expect_member("m129");
+
+        let mut present = MemberPresence::new();
+        assert!(!present.contains(first));
+        assert!(present.insert(first));
+        assert!(present.insert(overflow));
+        assert!(!present.insert(first));
+        assert!(!present.insert(overflow));
+        assert!(present.contains(overflow));
+        assert!(!present.contains(schema.expect_member("m64")));
+    }
+}
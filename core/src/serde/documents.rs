@@ -1,15 +1,16 @@
 use std::fmt::Display;
 
 use crate::{
-    BigDecimal, BigInt, ByteBuffer, IndexMap, Instant,
-    prelude::RequiredTrait,
+    BigDecimal, BigInt, IndexMap, Instant,
+    prelude::{RequiredTrait, TimestampFormatTrait},
     schema::{
-        Document, DocumentError, NULL, Schema, ShapeId, ShapeType, StaticSchemaShape,
-        TryFromDocument, default::Value,
+        Blob, Document, DocumentError, NULL, Schema, ShapeId, ShapeType, StaticSchemaShape,
+        Timestamp, TryFromDocument, default::Value,
     },
     serde::{
-        de::{DeserializeWithSchema, Deserializer, ListReader, MapReader, StructReader},
+        de::{DeserializeWithSchema, Deserializer, ListReader, MapReader, MemberKey, StructReader},
         deserializers::DeserializableShape,
+        eventstream::EventStream,
         se::{ListWriter, MapWriter, Serializer, StructWriter},
         serializers::{Error, SerializeWithSchema},
         utils::KeySerializer,
@@ -58,10 +59,10 @@ impl SerializeWithSchema for Box<dyn Document> {
             Some(ShapeType::Float) => serializer.write_float(schema, self.as_float().unwrap()),
             Some(ShapeType::Double) => serializer.write_double(schema, self.as_double().unwrap()),
             Some(ShapeType::BigInteger) => {
-                serializer.write_big_integer(schema, self.as_big_integer().unwrap())
+                serializer.write_big_integer(schema, &self.as_big_integer().unwrap())
             }
             Some(ShapeType::BigDecimal) => {
-                serializer.write_big_decimal(schema, self.as_big_decimal().unwrap())
+                serializer.write_big_decimal(schema, &self.as_big_decimal().unwrap())
             }
             Some(ShapeType::List) => self
                 .as_list()
@@ -123,7 +124,7 @@ where
     }
 }
 
-struct DocumentParser;
+pub(crate) struct DocumentParser;
 // TODO(document validation): Should this have schema type validation?
 impl Serializer for DocumentParser {
     type Error = DocumentError;
@@ -214,7 +215,7 @@ impl Serializer for DocumentParser {
     }
 
     #[inline]
-    fn write_blob(self, _schema: &Schema, value: &ByteBuffer) -> Result<Self::Ok, Self::Error> {
+    fn write_blob(self, _schema: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
         Ok(value.clone().into())
     }
 
@@ -232,6 +233,16 @@ impl Serializer for DocumentParser {
         Ok(value.clone())
     }
 
+    #[inline]
+    fn write_event_stream<T>(
+        self,
+        _schema: &Schema,
+        _value: &EventStream<T>,
+    ) -> Result<Self::Ok, Self::Error> {
+        // Events are sent as separate messages, so they are not part of the document
+        Ok(NULL.clone())
+    }
+
     #[inline]
     fn write_null(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
         Ok(NULL.clone())
@@ -335,6 +346,16 @@ impl StructWriter for DocumentMapAccumulator {
         Ok(())
     }
 
+    #[inline]
+    fn write_unknown_member(
+        &mut self,
+        member_name: &str,
+        value: &Box<dyn Document>,
+    ) -> Result<(), Self::Error> {
+        self.values.insert(member_name.to_string(), value.clone());
+        Ok(())
+    }
+
     #[inline]
     fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
         Ok(crate::schema::default::Document {
@@ -446,12 +467,29 @@ impl<'de> Deserializer<'de> for DocumentDeserializer {
     }
 
     #[inline]
-    fn read_blob(self, _schema: &Schema) -> Result<ByteBuffer, Self::Error> {
+    fn read_blob(self, _schema: &Schema) -> Result<Blob, Self::Error> {
         self.document.into_blob()
     }
 
     #[inline]
-    fn read_timestamp(self, _schema: &Schema) -> Result<Instant, Self::Error> {
+    fn read_timestamp(self, schema: &Schema) -> Result<Instant, Self::Error> {
+        // Documents built from a serialized format (e.g. JSON) may store timestamps
+        // as strings or numbers, so convert those using the schema's timestamp format.
+        if let Some(value) = self.document.as_string() {
+            let format = schema
+                .get_trait_as::<TimestampFormatTrait>()
+                .unwrap_or(&TimestampFormatTrait::DateTime);
+            return Timestamp::parse(value, format)
+                .map(Instant::from)
+                .map_err(|e| DocumentError::DocumentConversion(e.to_string()));
+        }
+        if self.document.as_timestamp().is_none()
+            && let Some(seconds) = self.document.as_double()
+        {
+            return Timestamp::from_epoch_seconds(seconds)
+                .map(Instant::from)
+                .map_err(|e| DocumentError::DocumentConversion(e.to_string()));
+        }
         self.document.into_timestamp()
     }
 
@@ -510,7 +548,7 @@ impl<'de> StructReader<'de> for DocumentStructReader {
         loop {
             match self.iter.next() {
                 Some((key, value)) => {
-                    if let Some(member_schema) = schema.get_member(&key) {
+                    if let Some(member_schema) = schema.get_member_or_alias(&key) {
                         self.current_value = Some(value);
                         return Ok(Some(member_schema));
                     }
@@ -521,6 +559,21 @@ impl<'de> StructReader<'de> for DocumentStructReader {
         }
     }
 
+    fn read_member_or_unknown<'a>(
+        &mut self,
+        schema: &'a Schema,
+    ) -> Result<Option<MemberKey<'a>>, Self::Error> {
+        let Some((key, value)) = self.iter.next() else {
+            return Ok(None);
+        };
+        self.current_value = Some(value);
+        Ok(Some(
+            schema
+                .get_member_or_alias(&key)
+                .map_or(MemberKey::Unknown(key), MemberKey::Known),
+        ))
+    }
+
     fn read_value<T: DeserializeWithSchema<'de>>(
         &mut self,
         schema: &Schema,
@@ -600,7 +653,11 @@ mod tests {
     use std::str::FromStr;
 
     use super::*;
-    use crate::{derive::SmithyShape, schema::prelude::*, smithy};
+    use crate::{
+        derive::{SmithyShape, smithy_union},
+        schema::prelude::*,
+        smithy,
+    };
 
     smithy!("com.example#Map": {
         map MAP_SCHEMA {
@@ -638,6 +695,58 @@ mod tests {
         pub member_map: IndexMap<String, String>,
     }
 
+    smithy!("com.example#Timestamps": {
+        structure TIMESTAMPS_SCHEMA {
+            @TimestampFormatTrait::HttpDate;
+            HTTP_DATE: TIMESTAMP = "httpDate"
+            DATE_TIME: TIMESTAMP = "dateTime"
+            EPOCH_SECONDS: TIMESTAMP = "epochSeconds"
+        }
+    });
+    smithy!("com.example#WireTimestamps": {
+        structure WIRE_TIMESTAMPS_SCHEMA {
+            HTTP_DATE: STRING = "httpDate"
+            DATE_TIME: STRING = "dateTime"
+            EPOCH_SECONDS: DOUBLE = "epochSeconds"
+        }
+    });
+
+    smithy!("com.example#Choice": {
+        union CHOICE_SCHEMA {
+            A: STRING = "a"
+        }
+    });
+
+    #[smithy_union]
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(CHOICE_SCHEMA)]
+    pub enum Choice {
+        #[smithy_schema(A)]
+        A(String),
+    }
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(TIMESTAMPS_SCHEMA)]
+    pub struct Timestamps {
+        #[smithy_schema(HTTP_DATE)]
+        pub http_date: Instant,
+        #[smithy_schema(DATE_TIME)]
+        pub date_time: Instant,
+        #[smithy_schema(EPOCH_SECONDS)]
+        pub epoch_seconds: Instant,
+    }
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(WIRE_TIMESTAMPS_SCHEMA)]
+    pub struct WireTimestamps {
+        #[smithy_schema(HTTP_DATE)]
+        pub http_date: String,
+        #[smithy_schema(DATE_TIME)]
+        pub date_time: String,
+        #[smithy_schema(EPOCH_SECONDS)]
+        pub epoch_seconds: f64,
+    }
+
     #[test]
     fn struct_to_document() {
         let mut map = IndexMap::new();
@@ -666,6 +775,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn union_retains_unknown_members() {
+        let mut members: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+        members.insert("b".to_string(), vec![1i32, 2i32].into());
+        let document: Box<dyn Document> = members.into();
+        let choice: Choice = document.try_into().unwrap();
+        let Choice::Unknown(name, value) = &choice else {
+            panic!("Expected unknown variant");
+        };
+        assert_eq!(name, "b");
+        assert_eq!(value.as_list().unwrap().len(), 2);
+
+        let document: Box<dyn Document> = choice.into();
+        let members = document.as_map().unwrap();
+        assert_eq!(members.get("b").unwrap().as_list().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn union_skips_null_members() {
+        let mut members: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+        members.insert("b".to_string(), NULL.clone());
+        members.insert("a".to_string(), "value".into());
+        let document: Box<dyn Document> = members.into();
+        let choice: Choice = document.try_into().unwrap();
+        assert!(matches!(choice, Choice::A(value) if value == "value"));
+
+        let mut members: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+        members.insert("a".to_string(), NULL.clone());
+        let document: Box<dyn Document> = members.into();
+        assert!(TryInto::<Choice>::try_into(document).is_err());
+    }
+
+    #[test]
+    fn timestamps_from_formatted_values() {
+        let wire = WireTimestamps {
+            http_date: "Tue, 29 Apr 2014 18:30:38 GMT".to_string(),
+            date_time: "2014-04-29T18:30:38.5Z".to_string(),
+            epoch_seconds: 1_398_796_238.5,
+        };
+        let document: Box<dyn Document> = wire.into();
+        let builder: TimestampsBuilder = document.try_into().unwrap();
+        let result = builder.build().unwrap();
+        assert_eq!(result.http_date.epoch_milliseconds(), 1_398_796_238_000);
+        assert_eq!(result.date_time.epoch_milliseconds(), 1_398_796_238_500);
+        assert_eq!(result.epoch_seconds.epoch_milliseconds(), 1_398_796_238_500);
+
+        let invalid = WireTimestamps {
+            http_date: "2014-04-29T18:30:38Z".to_string(),
+            date_time: "2014-04-29T18:30:38Z".to_string(),
+            epoch_seconds: 0.0,
+        };
+        let document: Box<dyn Document> = invalid.into();
+        let result: Result<TimestampsBuilder, _> = document.try_into();
+        assert!(matches!(result, Err(DocumentError::DocumentConversion(_))));
+    }
+
     #[test]
     fn string_document_value() {
         let document_str: Box<dyn Document> = "MyStr".into();
@@ -701,6 +866,29 @@ mod tests {
         assert_eq!(result, struct_to_convert);
     }
 
+    #[test]
+    fn shapes_convert_from_documents() {
+        let shape = SerializeMe {
+            member_a: "a".to_string(),
+            member_b: "b".to_string(),
+            member_optional: None,
+            member_map: IndexMap::new(),
+            member_list: Vec::new(),
+        };
+        // NOTE: `TryFrom` is fully qualified as `TryFromDocument` is also in scope
+        let result: SerializeMe =
+            TryFrom::try_from(Box::<dyn Document>::from(shape.clone())).unwrap();
+        assert_eq!(result, shape);
+
+        let choice = Choice::A("a".to_string());
+        let result: Choice = TryFrom::try_from(Box::<dyn Document>::from(choice.clone())).unwrap();
+        assert_eq!(result, choice);
+
+        let invalid: Box<dyn Document> = "not a struct".into();
+        let result: Result<SerializeMe, _> = TryFrom::try_from(invalid);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn roundtrip_bool() {
         let original = true;
@@ -759,7 +947,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "BigDecimal/BigInteger serialization not yet implemented"]
     fn roundtrip_big_numbers() {
         // BigInteger
         let original_big_int = BigInt::from(123_456_789);
@@ -0,0 +1,149 @@
+//! # Codec Errors
+//!
+//! Each codec (and adapter) defines its own error type so that it can
+//! capture format-specific information. To avoid per-codec error handling
+//! downstream, all codec errors can be converted into the shared [`CodecError`]
+//! taxonomy:
+//!
+//! ```rust,ignore
+//! fn parse(data: &[u8]) -> Result<MyShape, CodecError> {
+//!     let mut de = JsonDeserializer::new(data);
+//!     Ok(MyShapeBuilder::deserialize_with_schema(&MY_SHAPE_SCHEMA, &mut de)?.build()?)
+//! }
+//! ```
+
+use std::fmt::Display;
+
+use thiserror::Error;
+
+use crate::{
+    schema::Schema,
+    serde::{
+        de::Error as DeserializerError, deadline::DeadlineExceeded, se::Error as SerializerError,
+        validation::ValidationErrors,
+    },
+};
+
+/// Shared error type that all codec and adapter errors can be converted into.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum CodecError {
+    /// Failed to read from or write to an underlying data source or sink
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    /// Input data was malformed.
+    ///
+    /// `offset` is the byte offset in the input at which the error was
+    /// detected, if known.
+    #[error("Syntax error{}: {message}", offset.map(|o| format!(" at offset {o}")).unwrap_or_default())]
+    Syntax {
+        /// Byte offset of the error in the input, if known
+        offset: Option<usize>,
+        /// Description of the error
+        message: String,
+    },
+    /// Data did not match the type expected by the schema.
+    ///
+    /// `path` is a [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to
+    /// the mismatched value (an empty path indicates the root value).
+    #[error("Type mismatch at `{path}`: {message}")]
+    TypeMismatch {
+        /// JSON Pointer to the mismatched value
+        path: String,
+        /// Description of the error
+        message: String,
+    },
+    /// Operation is not supported by the codec
+    #[error("Unsupported: {0}")]
+    Unsupported(String),
+    /// A struct member appeared more than once in the input
+    #[error("Duplicate member `{0}`")]
+    DuplicateMember(String),
+    /// Input for a struct contained members that are not in its schema.
+    ///
+    /// Only raised in strict mode (see [`UnknownMembers`](crate::serde::strict::UnknownMembers)).
+    #[error("Unknown members of `{shape}`: {}", members.join(", "))]
+    UnknownMembers {
+        /// ID of the shape or member being deserialized
+        shape: String,
+        /// Names of the unknown members
+        members: Vec<String>,
+    },
+    /// Validation of a deserialized shape failed
+    #[error(transparent)]
+    Validation(#[from] ValidationErrors),
+    /// Processing was aborted by a [`Deadline`](crate::serde::deadline::Deadline)
+    #[error(transparent)]
+    DeadlineExceeded(#[from] DeadlineExceeded),
+    /// Any other error
+    #[error("{0}")]
+    Custom(String),
+}
+
+impl CodecError {
+    /// Create a new [`CodecError::Syntax`] error.
+    pub fn syntax(offset: Option<usize>, message: impl Display) -> Self {
+        CodecError::Syntax {
+            offset,
+            message: message.to_string(),
+        }
+    }
+
+    /// Create a new [`CodecError::TypeMismatch`] error.
+    pub fn type_mismatch(path: impl Into<String>, message: impl Display) -> Self {
+        CodecError::TypeMismatch {
+            path: path.into(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl SerializerError for CodecError {
+    fn custom<T: Display>(msg: T) -> Self {
+        CodecError::Custom(msg.to_string())
+    }
+}
+
+impl DeserializerError for CodecError {
+    fn custom<T: Display>(msg: T) -> Self {
+        CodecError::Custom(msg.to_string())
+    }
+
+    fn duplicate_member(member: &Schema) -> Self {
+        CodecError::DuplicateMember(member.id().id().to_string())
+    }
+
+    fn unknown_members(schema: &Schema, members: Vec<String>) -> Self {
+        CodecError::UnknownMembers {
+            shape: schema.id().id().to_string(),
+            members,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::DocumentError;
+
+    #[test]
+    fn displays_syntax_offset() {
+        assert_eq!(
+            CodecError::syntax(Some(12), "unexpected token").to_string(),
+            "Syntax error at offset 12: unexpected token"
+        );
+        assert_eq!(
+            CodecError::syntax(None, "unexpected token").to_string(),
+            "Syntax error: unexpected token"
+        );
+    }
+
+    #[test]
+    fn converts_document_errors() {
+        let error: CodecError = DocumentError::DocumentConversion("string".to_string()).into();
+        assert!(matches!(error, CodecError::TypeMismatch { path, .. } if path.is_empty()));
+
+        let error: CodecError = DocumentError::CustomError("oops".to_string()).into();
+        assert!(matches!(error, CodecError::Custom(msg) if msg == "oops"));
+    }
+}
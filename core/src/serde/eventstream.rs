@@ -0,0 +1,1290 @@
+//! # Event Streams
+//!
+//! Support for Smithy [event streams](https://smithy.io/2.0/spec/streaming.html#event-streams),
+//! which are modeled as members targeting a `@streaming` union.
+//!
+//! An event stream is a sequence of [`Message`]'s, each made up of a set of typed
+//! headers and a binary payload. Each event (a member of the streaming union) is
+//! converted to and from a message by an [`EventMarshaller`]:
+//!
+//! - Members with the `@eventHeader` trait are bound to message headers.
+//! - A member with the `@eventPayload` trait is bound to the message payload.
+//!   `blob` and `string` payloads are written as-is, all other payloads use the protocol [`Codec`].
+//! - All remaining members are serialized into the message payload with the protocol [`Codec`].
+//!
+//! Messages are written to and read from the wire by a [`FrameEncoder`] and [`FrameDecoder`].
+//! Protocols implement these traits for their framing format (for example,
+//! `application/vnd.amazon.eventstream`), while the marshalling of events is shared.
+//!
+//! Operations with event streams may also send an `initial-request` or
+//! `initial-response` message containing the non-streaming members of the
+//! operation input or output (see [`EventMarshaller::initial_request`]).
+//!
+//! Received events can be buffered ahead of a slow consumer with [`EventStream::bounded`],
+//! which stops reading from the transport once a fixed number of events are buffered.
+//!
+//! ```rust,ignore
+//! let marshaller = EventMarshaller::for_protocol(protocol);
+//! let message = marshaller.marshal(&event)?;
+//! encoder.encode(&message, &mut buf)?;
+//!
+//! while let Some(message) = decoder.decode(&mut buf)? {
+//!     let event: MyEvents = marshaller.unmarshal(&message)?;
+//! }
+//! ```
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter},
+    future::poll_fn,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+};
+
+use bytes::{Bytes, BytesMut};
+use futures_core::Stream;
+use thiserror::Error;
+
+use crate::{
+    FxIndexMap, IndexMap, Instant,
+    prelude::{ErrorTrait, EventHeaderTrait, EventPayloadTrait, StreamingTrait},
+    schema::{
+        Blob, Document, DocumentError, MemberSchema, Schema, ShapeType, StaticSchemaShape,
+        TryFromDocument,
+        default::{self, Value},
+    },
+    serde::{
+        CodecError, Protocol,
+        correction::ErrorCorrectionDefault,
+        de::{DeserializeWithSchema, Deserializer},
+        documents::DocumentParser,
+        protocol::Codec,
+        se::{SerializeWithSchema, Serializer},
+    },
+};
+
+/// Header containing the type of message (`event`, `exception`, or `error`).
+pub const MESSAGE_TYPE_HEADER: &str = ":message-type";
+/// Header containing the name of the union member an `event` message contains.
+pub const EVENT_TYPE_HEADER: &str = ":event-type";
+/// Header containing the name of the union member an `exception` message contains.
+pub const EXCEPTION_TYPE_HEADER: &str = ":exception-type";
+/// Header containing the media type of the message payload.
+pub const CONTENT_TYPE_HEADER: &str = ":content-type";
+/// Header containing the error code of an `error` message.
+pub const ERROR_CODE_HEADER: &str = ":error-code";
+/// Header containing the error message of an `error` message.
+pub const ERROR_MESSAGE_HEADER: &str = ":error-message";
+
+/// Event type of the message containing the non-streaming members of an operation input.
+pub const INITIAL_REQUEST: &str = "initial-request";
+/// Event type of the message containing the non-streaming members of an operation output.
+pub const INITIAL_RESPONSE: &str = "initial-response";
+
+const EVENT: &str = "event";
+const EXCEPTION: &str = "exception";
+const ERROR: &str = "error";
+const BLOB_CONTENT_TYPE: &str = "application/octet-stream";
+const STRING_CONTENT_TYPE: &str = "text/plain";
+
+/// Returns `true` if the schema is (or targets) a `@streaming` union.
+#[must_use]
+pub fn is_event_stream(schema: &Schema) -> bool {
+    let target = schema.as_member().map_or(schema, MemberSchema::target);
+    target.shape_type() == &ShapeType::Union && target.contains_type::<StreamingTrait>()
+}
+
+// ============================================================================
+// Messages
+// ============================================================================
+
+/// Value of an event stream [`Message`] header.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeaderValue {
+    /// `boolean` header
+    Bool(bool),
+    /// `byte` header
+    Byte(i8),
+    /// `short` header
+    Short(i16),
+    /// `integer` header
+    Integer(i32),
+    /// `long` header
+    Long(i64),
+    /// `blob` header
+    ByteArray(Bytes),
+    /// `string` header
+    String(String),
+    /// `timestamp` header
+    Timestamp(Instant),
+    /// UUID header. Not used by modeled members, but may be sent by protocols.
+    Uuid([u8; 16]),
+}
+
+impl HeaderValue {
+    /// Get the value of a string header.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            HeaderValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for HeaderValue {
+    fn from(value: &str) -> Self {
+        HeaderValue::String(value.to_string())
+    }
+}
+
+impl From<String> for HeaderValue {
+    fn from(value: String) -> Self {
+        HeaderValue::String(value)
+    }
+}
+
+/// A single message of an event stream, made up of headers and a binary payload.
+///
+/// Headers are kept in insertion order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Message {
+    headers: FxIndexMap<String, HeaderValue>,
+    payload: Bytes,
+}
+
+impl Message {
+    /// Create a new message with no headers.
+    pub fn new(payload: impl Into<Bytes>) -> Self {
+        Message {
+            headers: FxIndexMap::default(),
+            payload: payload.into(),
+        }
+    }
+
+    /// Set a header, replacing any existing value with the same name.
+    pub fn insert_header(&mut self, name: impl Into<String>, value: impl Into<HeaderValue>) {
+        self.headers.insert(name.into(), value.into());
+    }
+
+    /// Builder-style version of [`Message::insert_header`].
+    #[must_use]
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<HeaderValue>) -> Self {
+        self.insert_header(name, value);
+        self
+    }
+
+    /// Get a header by name.
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&HeaderValue> {
+        self.headers.get(name)
+    }
+
+    /// Iterate over all headers in insertion order.
+    pub fn headers(&self) -> impl Iterator<Item = (&str, &HeaderValue)> {
+        self.headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Get the message payload.
+    #[must_use]
+    pub const fn payload(&self) -> &Bytes {
+        &self.payload
+    }
+
+    /// Get the value of the [`MESSAGE_TYPE_HEADER`].
+    #[must_use]
+    pub fn message_type(&self) -> Option<&str> {
+        self.string_header(MESSAGE_TYPE_HEADER)
+    }
+
+    /// Get the value of the [`EVENT_TYPE_HEADER`].
+    #[must_use]
+    pub fn event_type(&self) -> Option<&str> {
+        self.string_header(EVENT_TYPE_HEADER)
+    }
+
+    /// Get the value of the [`EXCEPTION_TYPE_HEADER`].
+    #[must_use]
+    pub fn exception_type(&self) -> Option<&str> {
+        self.string_header(EXCEPTION_TYPE_HEADER)
+    }
+
+    fn string_header(&self, name: &str) -> Option<&str> {
+        self.header(name).and_then(HeaderValue::as_str)
+    }
+}
+
+// ============================================================================
+// Framing
+// ============================================================================
+
+/// Writes [`Message`]'s to the wire format used by a protocol.
+///
+/// Protocols using `application/vnd.amazon.eventstream` implement this trait to
+/// write the prelude, headers, payload and checksums of each message.
+pub trait FrameEncoder: Send {
+    /// Encode a message and append it to `dst`.
+    ///
+    /// # Errors
+    /// Returns [`EventStreamError::Frame`] if the message cannot be represented
+    /// by this framing format (for example, if it is too large).
+    fn encode(&mut self, message: &Message, dst: &mut BytesMut) -> Result<(), EventStreamError>;
+}
+
+/// Reads [`Message`]'s from the wire format used by a protocol.
+///
+/// Data is read incrementally: decoders are called whenever more data is
+/// available and should only consume `src` once a complete message has been received.
+pub trait FrameDecoder: Send {
+    /// Decode the next message from `src`.
+    ///
+    /// Returns `Ok(None)` if `src` does not yet contain a complete message.
+    ///
+    /// # Errors
+    /// Returns [`EventStreamError::Frame`] if the data is not a valid frame.
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Message>, EventStreamError>;
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Errors that can occur when sending or receiving an event stream.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum EventStreamError {
+    /// Events of the stream have already been taken.
+    #[error("Event stream has already been consumed")]
+    Consumed,
+    /// Data could not be read or written as a message frame.
+    #[error("Invalid event stream frame: {0}")]
+    Frame(String),
+    /// Message is missing a required header or has an invalid header value.
+    #[error("Invalid event stream message: {0}")]
+    Message(String),
+    /// Message contains an event that is not a member of the event stream union.
+    #[error("Unknown event type `{0}`")]
+    UnknownEvent(String),
+    /// Peer sent an `error` message.
+    #[error("Event stream error `{code}`: {message}")]
+    Remote {
+        /// Value of the [`ERROR_CODE_HEADER`]
+        code: String,
+        /// Value of the [`ERROR_MESSAGE_HEADER`]
+        message: String,
+    },
+    /// Event could not be serialized or deserialized.
+    #[error(transparent)]
+    Codec(#[from] CodecError),
+}
+
+impl From<DocumentError> for EventStreamError {
+    fn from(value: DocumentError) -> Self {
+        EventStreamError::Codec(value.into())
+    }
+}
+
+// ============================================================================
+// Marshalling
+// ============================================================================
+
+/// Converts events to and from event stream [`Message`]'s using a protocol [`Codec`].
+#[derive(Clone, Copy)]
+pub struct EventMarshaller<'a> {
+    codec: &'a dyn Codec,
+    content_type: &'a str,
+}
+
+impl<'a> EventMarshaller<'a> {
+    /// Create a new marshaller that writes message payloads with `codec`.
+    ///
+    /// `content_type` is written to the [`CONTENT_TYPE_HEADER`] of messages with codec payloads.
+    #[must_use]
+    pub const fn new(codec: &'a dyn Codec, content_type: &'a str) -> Self {
+        EventMarshaller {
+            codec,
+            content_type,
+        }
+    }
+
+    /// Create a new marshaller that uses the codec and content type of a [`Protocol`].
+    #[must_use]
+    pub fn for_protocol(protocol: &'a dyn Protocol) -> Self {
+        Self::new(protocol.codec(), protocol.content_type())
+    }
+
+    /// Convert an event (a `@streaming` union) into a message.
+    ///
+    /// Members targeting an `@error` structure are sent as `exception` messages,
+    /// all other members are sent as `event` messages.
+    ///
+    /// # Errors
+    /// Returns an [`EventStreamError`] if the event could not be serialized.
+    pub fn marshal<T>(&self, event: &T) -> Result<Message, EventStreamError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema,
+    {
+        let document = event.serialize_with_schema(T::schema(), DocumentParser)?;
+        let Some((name, value)) = document.into_map()?.into_iter().next() else {
+            return Err(EventStreamError::Message(
+                "Event has no members set".to_string(),
+            ));
+        };
+        let member = T::schema()
+            .get_member(&name)
+            .ok_or_else(|| EventStreamError::UnknownEvent(name.clone()))?;
+        let target = member_target(member);
+        let mut message = self.write_members(target, value)?;
+        if target.contains_type::<ErrorTrait>() {
+            message.insert_header(MESSAGE_TYPE_HEADER, EXCEPTION);
+            message.insert_header(EXCEPTION_TYPE_HEADER, name);
+        } else {
+            message.insert_header(MESSAGE_TYPE_HEADER, EVENT);
+            message.insert_header(EVENT_TYPE_HEADER, name);
+        }
+        Ok(message)
+    }
+
+    /// Convert a message into an event (a `@streaming` union).
+    ///
+    /// # Errors
+    /// Returns [`EventStreamError::Remote`] for `error` messages,
+    /// [`EventStreamError::UnknownEvent`] if the message does not contain a
+    /// member of the union, or any error encountered while deserializing the event.
+    pub fn unmarshal<S>(&self, message: &Message) -> Result<S, EventStreamError>
+    where
+        S: StaticSchemaShape + TryFromDocument,
+    {
+        let name = match message.message_type() {
+            Some(EVENT) => message.event_type(),
+            Some(EXCEPTION) => message.exception_type(),
+            Some(ERROR) => return Err(remote_error(message)),
+            Some(other) => {
+                return Err(EventStreamError::Message(format!(
+                    "Unknown message type `{other}`"
+                )));
+            }
+            None => None,
+        }
+        .ok_or_else(|| EventStreamError::Message("Missing event type header".to_string()))?;
+        let member = S::schema()
+            .get_member(name)
+            .ok_or_else(|| EventStreamError::UnknownEvent(name.to_string()))?;
+        let value = self.read_members(member_target(member), message)?;
+        let mut members = IndexMap::new();
+        members.insert(name.to_string(), value);
+        Ok(struct_document(S::schema(), members).try_into::<S>()?)
+    }
+
+    /// Create the `initial-request` message for the non-streaming members of an operation input.
+    ///
+    /// # Errors
+    /// Returns an [`EventStreamError`] if the input could not be serialized.
+    pub fn initial_request<T>(&self, input: &T) -> Result<Message, EventStreamError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema,
+    {
+        self.initial_message(INITIAL_REQUEST, input)
+    }
+
+    /// Create the `initial-response` message for the non-streaming members of an operation output.
+    ///
+    /// # Errors
+    /// Returns an [`EventStreamError`] if the output could not be serialized.
+    pub fn initial_response<T>(&self, output: &T) -> Result<Message, EventStreamError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema,
+    {
+        self.initial_message(INITIAL_RESPONSE, output)
+    }
+
+    /// Read the non-streaming members of an operation input or output from an
+    /// `initial-request` or `initial-response` message.
+    ///
+    /// The event stream member of the returned shape is empty.
+    ///
+    /// # Errors
+    /// Returns [`EventStreamError::Message`] if the message is not an initial
+    /// message, or any error encountered while deserializing the shape.
+    pub fn read_initial<S>(&self, message: &Message) -> Result<S, EventStreamError>
+    where
+        S: StaticSchemaShape + TryFromDocument,
+    {
+        if message.message_type() == Some(ERROR) {
+            return Err(remote_error(message));
+        }
+        match message.event_type() {
+            Some(INITIAL_REQUEST | INITIAL_RESPONSE) => {}
+            other => {
+                return Err(EventStreamError::Message(format!(
+                    "Expected an initial message, found `{}`",
+                    other.unwrap_or_default()
+                )));
+            }
+        }
+        Ok(self.read_members(S::schema(), message)?.try_into::<S>()?)
+    }
+
+    fn initial_message<T>(&self, event_type: &str, shape: &T) -> Result<Message, EventStreamError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema,
+    {
+        let document = shape.serialize_with_schema(T::schema(), DocumentParser)?;
+        Ok(self
+            .write_members(T::schema(), document)?
+            .with_header(MESSAGE_TYPE_HEADER, EVENT)
+            .with_header(EVENT_TYPE_HEADER, event_type))
+    }
+
+    /// Write the members of a structure document to message headers and payload.
+    fn write_members(
+        &self,
+        schema: &Schema,
+        document: Box<dyn Document>,
+    ) -> Result<Message, EventStreamError> {
+        let mut headers = Vec::new();
+        let mut payload = None;
+        let mut body = IndexMap::new();
+        for (name, value) in document.into_map()? {
+            let Some(member) = schema.get_member(&name) else {
+                continue;
+            };
+            if value.is_null() || is_event_stream(member) {
+                continue;
+            }
+            if member.contains_type::<EventHeaderTrait>() {
+                headers.push((name, to_header(member, &*value)?));
+            } else if member.contains_type::<EventPayloadTrait>() {
+                payload = Some((member, value));
+            } else {
+                body.insert(name, value);
+            }
+        }
+
+        let mut message = match payload {
+            Some((member, value)) => match member.shape_type() {
+                ShapeType::Blob => {
+                    Message::new(blob(&*value)?).with_header(CONTENT_TYPE_HEADER, BLOB_CONTENT_TYPE)
+                }
+                ShapeType::String | ShapeType::Enum => Message::new(string(&*value)?.to_string())
+                    .with_header(CONTENT_TYPE_HEADER, STRING_CONTENT_TYPE),
+                _ => Message::new(self.codec.serialize_document(member, &value)?)
+                    .with_header(CONTENT_TYPE_HEADER, self.content_type),
+            },
+            None if body.is_empty() && !headers.is_empty() => Message::default(),
+            None => Message::new(
+                self.codec
+                    .serialize_document(schema, &struct_document(schema, body))?,
+            )
+            .with_header(CONTENT_TYPE_HEADER, self.content_type),
+        };
+        for (name, value) in headers {
+            message.insert_header(name, value);
+        }
+        Ok(message)
+    }
+
+    /// Read the members of a structure from message headers and payload.
+    fn read_members(
+        &self,
+        schema: &Schema,
+        message: &Message,
+    ) -> Result<Box<dyn Document>, EventStreamError> {
+        let mut members = IndexMap::new();
+        let payload_member = schema
+            .members()
+            .values()
+            .find(|member| member.contains_type::<EventPayloadTrait>());
+        match payload_member {
+            Some(member) => {
+                let value = match member.shape_type() {
+                    ShapeType::Blob => Blob::from(message.payload().clone()).into(),
+                    ShapeType::String | ShapeType::Enum => {
+                        String::from_utf8(message.payload().to_vec())
+                            .map_err(|e| EventStreamError::Message(e.to_string()))?
+                            .into()
+                    }
+                    _ => self.codec.deserialize_document(member, message.payload())?,
+                };
+                members.insert(member_name(member).to_string(), value);
+            }
+            None if !message.payload().is_empty() => {
+                members = self
+                    .codec
+                    .deserialize_document(schema, message.payload())?
+                    .into_map()?;
+            }
+            None => {}
+        }
+        for member in schema.members().values() {
+            if !member.contains_type::<EventHeaderTrait>() {
+                continue;
+            }
+            let name = member_name(member);
+            if let Some(header) = message.header(name) {
+                members.insert(name.to_string(), from_header(header)?);
+            }
+        }
+        Ok(struct_document(schema, members))
+    }
+}
+
+impl Debug for EventMarshaller<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventMarshaller")
+            .field("content_type", &self.content_type)
+            .finish_non_exhaustive()
+    }
+}
+
+fn member_target(schema: &Schema) -> &Schema {
+    schema.as_member().map_or(schema, MemberSchema::target)
+}
+
+fn member_name(schema: &Schema) -> &str {
+    schema.as_member().map_or("", MemberSchema::name)
+}
+
+fn struct_document(
+    schema: &Schema,
+    members: IndexMap<String, Box<dyn Document>>,
+) -> Box<dyn Document> {
+    default::Document {
+        schema: schema.clone(),
+        value: Value::Map(members),
+        discriminator: None,
+    }
+    .into()
+}
+
+fn remote_error(message: &Message) -> EventStreamError {
+    let header = |name| message.string_header(name).unwrap_or_default().to_string();
+    EventStreamError::Remote {
+        code: header(ERROR_CODE_HEADER),
+        message: header(ERROR_MESSAGE_HEADER),
+    }
+}
+
+fn blob(value: &dyn Document) -> Result<Bytes, EventStreamError> {
+    value
+        .as_blob()
+        .map(|blob| blob.clone().into_bytes())
+        .ok_or_else(|| EventStreamError::Message("Expected blob value".to_string()))
+}
+
+fn string(value: &dyn Document) -> Result<&str, EventStreamError> {
+    value
+        .as_string()
+        .ok_or_else(|| EventStreamError::Message("Expected string value".to_string()))
+}
+
+fn to_header(member: &Schema, value: &dyn Document) -> Result<HeaderValue, EventStreamError> {
+    let header = match member.shape_type() {
+        ShapeType::Boolean => value.as_bool().map(HeaderValue::Bool),
+        ShapeType::Byte => value.as_byte().map(HeaderValue::Byte),
+        ShapeType::Short => value.as_short().map(HeaderValue::Short),
+        ShapeType::Integer | ShapeType::IntEnum => value.as_integer().map(HeaderValue::Integer),
+        ShapeType::Long => value.as_long().map(HeaderValue::Long),
+        ShapeType::Blob => return blob(value).map(HeaderValue::ByteArray),
+        ShapeType::String | ShapeType::Enum => return string(value).map(HeaderValue::from),
+        ShapeType::Timestamp => value.as_timestamp().copied().map(HeaderValue::Timestamp),
+        other => {
+            return Err(EventStreamError::Message(format!(
+                "{other} members cannot be bound to event headers"
+            )));
+        }
+    };
+    header.ok_or_else(|| {
+        EventStreamError::Message(format!(
+            "Invalid value for event header `{}`",
+            member_name(member)
+        ))
+    })
+}
+
+fn from_header(header: &HeaderValue) -> Result<Box<dyn Document>, EventStreamError> {
+    Ok(match header {
+        HeaderValue::Bool(value) => (*value).into(),
+        HeaderValue::Byte(value) => (*value).into(),
+        HeaderValue::Short(value) => (*value).into(),
+        HeaderValue::Integer(value) => (*value).into(),
+        HeaderValue::Long(value) => (*value).into(),
+        HeaderValue::ByteArray(value) => Blob::from(value.clone()).into(),
+        HeaderValue::String(value) => value.as_str().into(),
+        HeaderValue::Timestamp(value) => value.into(),
+        HeaderValue::Uuid(_) => {
+            return Err(EventStreamError::Message(
+                "UUID headers cannot be bound to members".to_string(),
+            ));
+        }
+    })
+}
+
+// ============================================================================
+// Event Stream
+// ============================================================================
+
+/// Stream of events backing an [`EventStream`].
+pub type EventSource<T> = Pin<Box<dyn Stream<Item = Result<T, EventStreamError>> + Send>>;
+
+/// # Event Stream
+///
+/// Events sent or received on a member targeting a `@streaming` union.
+///
+/// Like [`StreamingBlob`](crate::schema::StreamingBlob), the events can only be
+/// consumed once, using [`EventStream::take_stream`] or [`EventStream::collect`],
+/// and clones of an event stream share the same underlying events.
+///
+/// Event streams are not part of the message body. Protocols send them as
+/// separate messages, and receive them using the [`Serializer::write_event_stream`]
+/// and [`Deserializer::read_event_stream`] hooks.
+///
+/// ```rust
+/// use smithy4rs_core::serde::eventstream::EventStream;
+///
+/// let events = EventStream::from_events(vec![1, 2, 3]);
+/// assert!(!events.is_consumed());
+/// ```
+pub struct EventStream<T> {
+    inner: Arc<Mutex<Option<EventSource<T>>>>,
+}
+
+impl<T> EventStream<T> {
+    /// Create a new event stream from a [`Stream`] of events.
+    pub fn from_stream<S>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<T, EventStreamError>> + Send + 'static,
+    {
+        EventStream {
+            inner: Arc::new(Mutex::new(Some(Box::pin(stream)))),
+        }
+    }
+
+    /// Create a new event stream from in-memory events.
+    pub fn from_events<I>(events: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: Send + Unpin + 'static,
+    {
+        Self::from_stream(Iter(events.into_iter()))
+    }
+
+    /// Returns `true` if the events of this stream have already been taken.
+    ///
+    /// # Panics
+    /// If the lock on the stream is poisoned.
+    #[must_use]
+    pub fn is_consumed(&self) -> bool {
+        self.inner.lock().expect("Lock poisoned").is_none()
+    }
+
+    /// Take the events of this stream.
+    ///
+    /// # Errors
+    /// Returns [`EventStreamError::Consumed`] if the events have already been taken.
+    ///
+    /// # Panics
+    /// If the lock on the stream is poisoned.
+    pub fn take_stream(&self) -> Result<EventSource<T>, EventStreamError> {
+        self.inner
+            .lock()
+            .expect("Lock poisoned")
+            .take()
+            .ok_or(EventStreamError::Consumed)
+    }
+
+    /// Take the events of this stream, buffering up to `capacity` events ahead of
+    /// the consumer.
+    ///
+    /// Events are read from the underlying stream by the returned [`EventPump`], which
+    /// must be polled to completion (usually by spawning it on an executor), and are
+    /// received from the returned [`EventReceiver`]. Once `capacity` events are buffered,
+    /// the pump stops polling the underlying stream until the receiver catches up, so a
+    /// slow consumer applies backpressure to the transport instead of growing the buffer.
+    ///
+    /// A `capacity` of `0` is treated as `1`.
+    ///
+    /// ```rust,ignore
+    /// let (pump, mut receiver) = events.bounded(DEFAULT_EVENT_BUFFER_SIZE)?;
+    /// tokio::spawn(pump);
+    /// while let Some(event) = receiver.next().await {
+    ///     handle(event?).await;
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`EventStreamError::Consumed`] if the events have already been taken.
+    pub fn bounded(
+        &self,
+        capacity: usize,
+    ) -> Result<(EventPump<T>, EventReceiver<T>), EventStreamError> {
+        let source = self.take_stream()?;
+        let channel = Arc::new(Mutex::new(Channel {
+            buffer: VecDeque::with_capacity(capacity.max(1)),
+            capacity: capacity.max(1),
+            closed: false,
+            receiver_dropped: false,
+            pump_waker: None,
+            receiver_waker: None,
+            stats: ReceiverStats::default(),
+        }));
+        Ok((
+            EventPump {
+                source: Some(source),
+                channel: Arc::clone(&channel),
+            },
+            EventReceiver { channel },
+        ))
+    }
+
+    /// Read all events of the stream into memory.
+    ///
+    /// # Errors
+    /// Returns [`EventStreamError::Consumed`] if the events have already been
+    /// taken, or the first error returned by the underlying stream.
+    pub async fn collect(self) -> Result<Vec<T>, EventStreamError> {
+        let mut stream = self.take_stream()?;
+        let mut events = Vec::new();
+        while let Some(event) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            events.push(event?);
+        }
+        Ok(events)
+    }
+}
+
+impl<T> Clone for EventStream<T> {
+    fn clone(&self) -> Self {
+        EventStream {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Debug for EventStream<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Avoid blocking if the events are currently being taken
+        match self.inner.try_lock().as_deref() {
+            Ok(Some(_)) => f.write_str("EventStream(<stream>)"),
+            Ok(None) => f.write_str("EventStream(<consumed>)"),
+            Err(_) => f.write_str("EventStream(<locked>)"),
+        }
+    }
+}
+
+/// Stream that yields the items of an iterator.
+struct Iter<I>(I);
+
+impl<I: Iterator + Unpin> Stream for Iter<I> {
+    type Item = Result<I::Item, EventStreamError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.0.next().map(Ok))
+    }
+}
+
+// ============================================================================
+// Bounded Receiver
+// ============================================================================
+
+/// Default number of events to buffer ahead of the consumer of an [`EventStream::bounded`] receiver.
+pub const DEFAULT_EVENT_BUFFER_SIZE: usize = 16;
+
+/// Statistics of an [`EventReceiver`], used to detect consumers that fall behind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReceiverStats {
+    /// Number of events currently buffered and not yet received (the current lag).
+    pub buffered: usize,
+    /// Largest number of events that have been buffered at once.
+    pub max_buffered: usize,
+    /// Total number of events read from the underlying stream.
+    pub pumped: u64,
+    /// Number of times reading from the underlying stream was paused because the buffer was full.
+    pub lagged: u64,
+}
+
+/// State shared by an [`EventPump`] and its [`EventReceiver`].
+struct Channel<T> {
+    buffer: VecDeque<Result<T, EventStreamError>>,
+    capacity: usize,
+    /// Set once the underlying stream ends or the pump is dropped
+    closed: bool,
+    receiver_dropped: bool,
+    pump_waker: Option<Waker>,
+    receiver_waker: Option<Waker>,
+    stats: ReceiverStats,
+}
+
+fn lock<T>(channel: &Mutex<Channel<T>>) -> MutexGuard<'_, Channel<T>> {
+    channel.lock().expect("Lock poisoned")
+}
+
+fn wake(waker: &mut Option<Waker>) {
+    if let Some(waker) = waker.take() {
+        waker.wake();
+    }
+}
+
+/// Future that reads events from the underlying stream of an [`EventStream::bounded`]
+/// receiver into its buffer.
+///
+/// The pump completes once the underlying stream ends or the [`EventReceiver`] is dropped.
+#[must_use = "events are only received while the pump is polled"]
+pub struct EventPump<T> {
+    source: Option<EventSource<T>>,
+    channel: Arc<Mutex<Channel<T>>>,
+}
+
+impl<T> Future for EventPump<T> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            let receiver_dropped = {
+                let mut channel = lock(&self.channel);
+                if !channel.receiver_dropped && channel.buffer.len() >= channel.capacity {
+                    channel.stats.lagged += 1;
+                    channel.pump_waker = Some(cx.waker().clone());
+                    return Poll::Pending;
+                }
+                channel.receiver_dropped
+            };
+            if receiver_dropped {
+                self.source = None;
+                return Poll::Ready(());
+            }
+            let Some(source) = self.source.as_mut() else {
+                return Poll::Ready(());
+            };
+            let event = match source.as_mut().poll_next(cx) {
+                Poll::Ready(event) => event,
+                Poll::Pending => return Poll::Pending,
+            };
+            let channel = Arc::clone(&self.channel);
+            let mut channel = lock(&channel);
+            match event {
+                Some(event) => {
+                    channel.buffer.push_back(event);
+                    channel.stats.pumped += 1;
+                    channel.stats.max_buffered =
+                        channel.stats.max_buffered.max(channel.buffer.len());
+                }
+                None => channel.closed = true,
+            }
+            wake(&mut channel.receiver_waker);
+            if channel.closed {
+                drop(channel);
+                self.source = None;
+                return Poll::Ready(());
+            }
+        }
+    }
+}
+
+impl<T> Drop for EventPump<T> {
+    fn drop(&mut self) {
+        let mut channel = lock(&self.channel);
+        channel.closed = true;
+        wake(&mut channel.receiver_waker);
+    }
+}
+
+impl<T> Debug for EventPump<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventPump")
+            .field("finished", &self.source.is_none())
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`Stream`] of the events buffered by an [`EventPump`].
+///
+/// The stream ends once the underlying stream ends (or the pump is dropped) and
+/// all buffered events have been received.
+pub struct EventReceiver<T> {
+    channel: Arc<Mutex<Channel<T>>>,
+}
+
+impl<T> EventReceiver<T> {
+    /// Get the current [`ReceiverStats`] of this receiver.
+    ///
+    /// # Panics
+    /// If the lock on the buffer is poisoned.
+    #[must_use]
+    pub fn stats(&self) -> ReceiverStats {
+        let channel = lock(&self.channel);
+        ReceiverStats {
+            buffered: channel.buffer.len(),
+            ..channel.stats
+        }
+    }
+
+    /// Get the maximum number of events buffered ahead of the consumer.
+    ///
+    /// # Panics
+    /// If the lock on the buffer is poisoned.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        lock(&self.channel).capacity
+    }
+}
+
+impl<T> Stream for EventReceiver<T> {
+    type Item = Result<T, EventStreamError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut channel = lock(&self.channel);
+        if let Some(event) = channel.buffer.pop_front() {
+            wake(&mut channel.pump_waker);
+            return Poll::Ready(Some(event));
+        }
+        if channel.closed {
+            return Poll::Ready(None);
+        }
+        channel.receiver_waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let channel = lock(&self.channel);
+        let buffered = channel.buffer.len();
+        (buffered, channel.closed.then_some(buffered))
+    }
+}
+
+impl<T> Drop for EventReceiver<T> {
+    fn drop(&mut self) {
+        let mut channel = lock(&self.channel);
+        channel.receiver_dropped = true;
+        channel.buffer.clear();
+        wake(&mut channel.pump_waker);
+    }
+}
+
+impl<T> Debug for EventReceiver<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventReceiver")
+            .field("stats", &self.stats())
+            .finish_non_exhaustive()
+    }
+}
+
+// ============================================================================
+// Serde
+// ============================================================================
+
+impl<T> SerializeWithSchema for EventStream<T> {
+    #[inline]
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.write_event_stream(schema, self)
+    }
+}
+
+impl<'de, T> DeserializeWithSchema<'de> for EventStream<T> {
+    #[inline]
+    fn deserialize_with_schema<D>(schema: &Schema, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.read_event_stream(schema)
+    }
+}
+
+impl<T: 'static> ErrorCorrectionDefault for EventStream<T> {
+    fn default() -> Self {
+        EventStream::from_stream(Iter(std::iter::empty()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smithy4rs_test_utils::{noop_context, ready, ready_items};
+
+    use super::*;
+    use crate::{
+        derive::{SmithyShape, smithy_union},
+        prelude::{BLOB, INTEGER, STRING},
+        smithy,
+    };
+
+    /// Codec that fails on every call, used to check that bound members skip the codec.
+    struct NoopCodec;
+
+    impl Codec for NoopCodec {
+        #[allow(clippy::borrowed_box)]
+        fn serialize_document(
+            &self,
+            _schema: &Schema,
+            _document: &Box<dyn Document>,
+        ) -> Result<Vec<u8>, CodecError> {
+            Err(CodecError::Unsupported("serialize".to_string()))
+        }
+
+        fn deserialize_document(
+            &self,
+            _schema: &Schema,
+            _data: &[u8],
+        ) -> Result<Box<dyn Document>, CodecError> {
+            Err(CodecError::Unsupported("deserialize".to_string()))
+        }
+    }
+
+    smithy!("com.example#Chunk": {
+        structure CHUNK_SCHEMA {
+            @EventHeaderTrait::builder().build();
+            SEQUENCE: INTEGER = "sequence"
+            @EventPayloadTrait::builder().build();
+            DATA: BLOB = "data"
+        }
+    });
+    smithy!("com.example#Ping": {
+        structure PING_SCHEMA {
+            @EventHeaderTrait::builder().build();
+            ID: STRING = "id"
+        }
+    });
+    smithy!("com.example#Events": {
+        @StreamingTrait::builder().build();
+        union EVENTS_SCHEMA {
+            CHUNK: CHUNK_SCHEMA = "chunk"
+            PING: PING_SCHEMA = "ping"
+        }
+    });
+    smithy!("com.example#Upload": {
+        structure UPLOAD_SCHEMA {
+            NAME: STRING = "name"
+            EVENTS: EVENTS_SCHEMA = "events"
+        }
+    });
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(CHUNK_SCHEMA)]
+    pub struct Chunk {
+        #[smithy_schema(SEQUENCE)]
+        sequence: i32,
+        #[smithy_schema(DATA)]
+        data: Blob,
+    }
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(PING_SCHEMA)]
+    pub struct Ping {
+        #[smithy_schema(ID)]
+        id: String,
+    }
+
+    #[smithy_union]
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(EVENTS_SCHEMA)]
+    pub enum Events {
+        #[smithy_schema(CHUNK)]
+        Chunk(Chunk),
+        #[smithy_schema(PING)]
+        Ping(Ping),
+    }
+
+    #[derive(SmithyShape)]
+    #[smithy_schema(UPLOAD_SCHEMA)]
+    pub struct Upload {
+        #[smithy_schema(NAME)]
+        name: String,
+        #[no_builder]
+        #[smithy_schema(EVENTS)]
+        events: EventStream<Events>,
+    }
+
+    fn marshaller() -> EventMarshaller<'static> {
+        EventMarshaller::new(&NoopCodec, "application/test")
+    }
+
+    #[test]
+    fn detects_event_streams() {
+        assert!(is_event_stream(&EVENTS_SCHEMA));
+        assert!(is_event_stream(UPLOAD_SCHEMA.expect_member("events")));
+        assert!(!is_event_stream(&CHUNK_SCHEMA));
+    }
+
+    #[test]
+    fn message_headers_keep_insertion_order() {
+        let message = Message::new("data")
+            .with_header("b", HeaderValue::Integer(1))
+            .with_header("a", "value")
+            .with_header("b", HeaderValue::Bool(true));
+        let names: Vec<_> = message.headers().map(|(name, _)| name).collect();
+        assert_eq!(names, vec!["b", "a"]);
+        assert_eq!(message.header("b"), Some(&HeaderValue::Bool(true)));
+        assert_eq!(message.payload(), "data");
+    }
+
+    #[test]
+    fn marshals_header_and_payload_members() {
+        let event = Events::Chunk(Chunk {
+            sequence: 3,
+            data: Blob::from_static(b"abc"),
+        });
+        let message = marshaller().marshal(&event).unwrap();
+        assert_eq!(message.message_type(), Some("event"));
+        assert_eq!(message.event_type(), Some("chunk"));
+        assert_eq!(message.header("sequence"), Some(&HeaderValue::Integer(3)));
+        assert_eq!(
+            message.header(CONTENT_TYPE_HEADER),
+            Some(&HeaderValue::from(BLOB_CONTENT_TYPE))
+        );
+        assert_eq!(message.payload(), "abc");
+
+        let output: Events = marshaller().unmarshal(&message).unwrap();
+        assert!(output == event);
+    }
+
+    #[test]
+    fn header_only_events_have_empty_payload() {
+        let event = Events::Ping(Ping {
+            id: "1".to_string(),
+        });
+        let message = marshaller().marshal(&event).unwrap();
+        assert!(message.payload().is_empty());
+        assert!(message.header(CONTENT_TYPE_HEADER).is_none());
+
+        let output: Events = marshaller().unmarshal(&message).unwrap();
+        assert!(output == event);
+    }
+
+    #[test]
+    fn unmarshal_rejects_unknown_events() {
+        let message = Message::default()
+            .with_header(MESSAGE_TYPE_HEADER, "event")
+            .with_header(EVENT_TYPE_HEADER, "other");
+        let result = marshaller().unmarshal::<Events>(&message);
+        assert!(matches!(result, Err(EventStreamError::UnknownEvent(name)) if name == "other"));
+    }
+
+    #[test]
+    fn unmarshal_returns_remote_errors() {
+        let message = Message::default()
+            .with_header(MESSAGE_TYPE_HEADER, "error")
+            .with_header(ERROR_CODE_HEADER, "Throttled")
+            .with_header(ERROR_MESSAGE_HEADER, "Slow down");
+        let result = marshaller().unmarshal::<Events>(&message);
+        assert!(matches!(
+            result,
+            Err(EventStreamError::Remote { code, message }) if code == "Throttled" && message == "Slow down"
+        ));
+    }
+
+    #[test]
+    fn collects_events() {
+        let events = EventStream::from_events(vec![1, 2, 3]);
+        assert_eq!(ready(events.collect()).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn events_can_only_be_taken_once() {
+        let events = EventStream::from_events(vec![1]);
+        let clone = events.clone();
+        assert!(clone.take_stream().is_ok());
+        assert!(events.is_consumed());
+        assert!(matches!(
+            events.take_stream(),
+            Err(EventStreamError::Consumed)
+        ));
+        assert_eq!(format!("{events:?}"), "EventStream(<consumed>)");
+    }
+
+    #[test]
+    fn event_streams_are_not_part_of_documents() {
+        let upload = Upload {
+            name: "file".to_string(),
+            events: EventStream::from_events(Vec::new()),
+        };
+        assert!(format!("{upload:?}").contains("EventStream(<stream>)"));
+
+        let document: Box<dyn Document> = upload.into();
+        assert!(document.as_map().unwrap()["events"].is_null());
+    }
+
+    /// Poll a pump once, returning `true` if it completed.
+    fn run_pump<T>(pump: &mut EventPump<T>) -> bool {
+        Pin::new(pump).poll(&mut noop_context()).is_ready()
+    }
+
+    fn next<T>(receiver: &mut EventReceiver<T>) -> Poll<Option<T>> {
+        Pin::new(receiver)
+            .poll_next(&mut noop_context())
+            .map(|event| event.map(Result::unwrap))
+    }
+
+    #[test]
+    fn bounded_receiver_applies_backpressure() {
+        let events = EventStream::from_events(1..=5);
+        let (mut pump, mut receiver) = events.bounded(2).unwrap();
+        assert!(events.is_consumed());
+        assert_eq!(receiver.capacity(), 2);
+        assert_eq!(next(&mut receiver), Poll::Pending);
+
+        // The pump stops reading once the buffer is full
+        assert!(!run_pump(&mut pump));
+        let stats = receiver.stats();
+        assert_eq!((stats.buffered, stats.pumped, stats.lagged), (2, 2, 1));
+
+        assert_eq!(next(&mut receiver), Poll::Ready(Some(1)));
+        assert_eq!(receiver.stats().buffered, 1);
+        assert!(!run_pump(&mut pump));
+        assert_eq!(receiver.stats().pumped, 3);
+
+        // Drain the buffer so the pump can reach the end of the stream
+        let mut received = vec![2, 3];
+        assert_eq!(next(&mut receiver), Poll::Ready(Some(2)));
+        assert_eq!(next(&mut receiver), Poll::Ready(Some(3)));
+        while !run_pump(&mut pump) {
+            let Poll::Ready(Some(event)) = next(&mut receiver) else {
+                panic!("Expected a buffered event");
+            };
+            received.push(event);
+        }
+        received.extend(ready_items(&mut receiver).into_iter().map(Result::unwrap));
+        assert_eq!(received, vec![2, 3, 4, 5]);
+        assert_eq!(next(&mut receiver), Poll::Ready(None));
+
+        let stats = receiver.stats();
+        assert_eq!(stats.buffered, 0);
+        assert_eq!(stats.max_buffered, 2);
+        assert_eq!(stats.pumped, 5);
+    }
+
+    #[test]
+    fn bounded_receiver_stops_pump_when_dropped() {
+        let (mut pump, receiver) = EventStream::from_events(0..).bounded(4).unwrap();
+        assert!(!run_pump(&mut pump));
+        drop(receiver);
+        assert!(run_pump(&mut pump));
+    }
+
+    #[test]
+    fn bounded_receiver_ends_when_pump_is_dropped() {
+        let (mut pump, mut receiver) = EventStream::from_events(0..).bounded(1).unwrap();
+        assert!(!run_pump(&mut pump));
+        drop(pump);
+        assert_eq!(next(&mut receiver), Poll::Ready(Some(0)));
+        assert_eq!(next(&mut receiver), Poll::Ready(None));
+    }
+
+    #[test]
+    fn bounded_receiver_takes_events_once() {
+        let events = EventStream::from_events(vec![1]);
+        let _ = events.take_stream().unwrap();
+        assert!(matches!(
+            events.bounded(DEFAULT_EVENT_BUFFER_SIZE),
+            Err(EventStreamError::Consumed)
+        ));
+
+        let (pump, receiver) = EventStream::from_events(vec![1, 2]).bounded(0).unwrap();
+        assert_eq!(receiver.capacity(), 1);
+        assert_eq!(format!("{pump:?}"), "EventPump { finished: false, .. }");
+    }
+}
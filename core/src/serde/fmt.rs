@@ -0,0 +1,517 @@
+//! Utilities for implementing [`Display`] for generated shapes
+//!
+//! The [`FmtSerializer`] renders any shape as a compact, human-readable string,
+//! similar to the `toString` implementations of other Smithy implementations:
+//!
+//! ```text
+//! Login[user=ferris, password=**REDACTED**, tags=[a, b], scores={math=1}]
+//! ```
+//!
+//! - Structures and unions are written as `ShapeName[member=value, ...]`.
+//! - Lists are written as `[value, ...]`, and maps as `{key=value, ...}`.
+//! - Strings are written without quotes, and blobs as their length in bytes.
+//!
+//! As with the [`Debug`](crate::serde::debug) implementations, values with the
+//! `@sensitive` trait are always redacted.
+//!
+//! ## Derived Display Implementations
+//!
+//! The `SmithyShape` derive macro implements `Display` using the [`FmtSerializer`]
+//! for all shapes except errors, which display their error message instead.
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::{
+    BigDecimal, BigInt, Instant,
+    schema::{
+        Blob, Document, Schema, ShapeType, StreamingBlob, Timestamp, prelude::SensitiveTrait,
+    },
+    serde::{
+        debug::{FmtError, REDACTED_ITEM, REDACTED_LIST, REDACTED_MAP, shape_name},
+        eventstream::EventStream,
+        se::{ListWriter, MapWriter, SerializeWithSchema, Serializer, StructWriter},
+    },
+};
+
+// ============================================================================
+// Wrapper
+// ============================================================================
+
+/// Wrapper struct used to adapt `Display` implementations to use schema-based serialization
+///
+/// This class should not be used directly by users. Instead, users should use generated
+/// `Display` implementation for shapes.
+pub struct DisplayWrapper<'a, T: SerializeWithSchema>(&'a Schema, &'a T);
+impl<'a, T: SerializeWithSchema> DisplayWrapper<'a, T> {
+    /// Construct a new Display wrapper to format type `T` using the provided schema.
+    pub const fn new(schema: &'a Schema, value: &'a T) -> Self {
+        DisplayWrapper(schema, value)
+    }
+}
+impl<T: SerializeWithSchema> Display for DisplayWrapper<'_, T> {
+    #[inline]
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        self.1
+            .serialize_with_schema(self.0, FmtSerializer::new(fmt))
+            .map_err(|_| fmt::Error)
+    }
+}
+
+// ============================================================================
+// Fmt Serializer
+// ============================================================================
+
+/// Serializer that writes shapes as `ShapeName[member=value, ...]`, redacting `@sensitive` values.
+///
+/// The serializer writes to any [`fmt::Write`] implementation, such as a `String`:
+///
+/// ```rust,ignore
+/// let mut output = String::new();
+/// shape.serialize_with_schema(MyShape::schema(), FmtSerializer::new(&mut output))?;
+/// ```
+pub struct FmtSerializer<'a, W: Write> {
+    out: &'a mut W,
+}
+
+impl<'a, W: Write> FmtSerializer<'a, W> {
+    /// Create a new serializer writing to `out`.
+    pub const fn new(out: &'a mut W) -> Self {
+        FmtSerializer { out }
+    }
+
+    /// Write a value with its `Display` implementation, unless `schema` is `@sensitive`.
+    #[inline]
+    fn display<T: Display>(self, schema: &Schema, value: T) -> Result<(), FmtError> {
+        if schema.contains_type::<SensitiveTrait>() {
+            self.out.write_str(REDACTED_ITEM)?;
+        } else {
+            write!(self.out, "{value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, W: Write> Serializer for FmtSerializer<'a, W> {
+    type Error = FmtError;
+    type Ok = ();
+    type ListWriter = FmtListWriter<'a, W>;
+    type MapWriter = FmtMapWriter<'a, W>;
+    type StructWriter = FmtStructWriter<'a, W>;
+
+    fn write_struct(self, schema: &Schema, _: usize) -> Result<Self::StructWriter, Self::Error> {
+        self.out.write_str(shape_name(schema))?;
+        let redacted = schema.contains_type::<SensitiveTrait>();
+        self.out
+            .write_str(if redacted { REDACTED_LIST } else { "[" })?;
+        Ok(FmtStructWriter(Entries::new(self.out, redacted)))
+    }
+
+    fn write_map(self, schema: &Schema, _: usize) -> Result<Self::MapWriter, Self::Error> {
+        let redacted = schema.contains_type::<SensitiveTrait>();
+        self.out
+            .write_str(if redacted { REDACTED_MAP } else { "{" })?;
+        Ok(FmtMapWriter(Entries::new(self.out, redacted)))
+    }
+
+    fn write_list(self, schema: &Schema, _: usize) -> Result<Self::ListWriter, Self::Error> {
+        let redacted = schema.contains_type::<SensitiveTrait>();
+        self.out
+            .write_str(if redacted { REDACTED_LIST } else { "[" })?;
+        Ok(FmtListWriter(Entries::new(self.out, redacted)))
+    }
+
+    #[inline]
+    fn write_boolean(self, schema: &Schema, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, value)
+    }
+
+    #[inline]
+    fn write_byte(self, schema: &Schema, value: i8) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, value)
+    }
+
+    #[inline]
+    fn write_short(self, schema: &Schema, value: i16) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, value)
+    }
+
+    #[inline]
+    fn write_integer(self, schema: &Schema, value: i32) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, value)
+    }
+
+    #[inline]
+    fn write_long(self, schema: &Schema, value: i64) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, value)
+    }
+
+    #[inline]
+    fn write_float(self, schema: &Schema, value: f32) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, value)
+    }
+
+    #[inline]
+    fn write_double(self, schema: &Schema, value: f64) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, value)
+    }
+
+    #[inline]
+    fn write_big_integer(self, schema: &Schema, value: &BigInt) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, value)
+    }
+
+    #[inline]
+    fn write_big_decimal(
+        self,
+        schema: &Schema,
+        value: &BigDecimal,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, value)
+    }
+
+    #[inline]
+    fn write_string(self, schema: &Schema, value: &str) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, value)
+    }
+
+    #[inline]
+    fn write_blob(self, schema: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, format_args!("<{} bytes>", value.len()))
+    }
+
+    #[inline]
+    fn write_streaming_blob(
+        self,
+        schema: &Schema,
+        value: &StreamingBlob,
+    ) -> Result<Self::Ok, Self::Error> {
+        match value.as_bytes() {
+            Some(bytes) => self.display(schema, format_args!("<{} bytes>", bytes.len())),
+            None => self.display(schema, "<stream>"),
+        }
+    }
+
+    #[inline]
+    fn write_event_stream<T>(
+        self,
+        _schema: &Schema,
+        _value: &EventStream<T>,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.out.write_str("<event stream>")?;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_timestamp(self, schema: &Schema, value: &Instant) -> Result<Self::Ok, Self::Error> {
+        self.display(schema, Timestamp::from(*value))
+    }
+
+    fn write_document(
+        self,
+        schema: &Schema,
+        value: &Box<dyn Document>,
+    ) -> Result<Self::Ok, Self::Error> {
+        if schema.contains_type::<SensitiveTrait>() {
+            self.out.write_str(REDACTED_ITEM)?;
+            return Ok(());
+        }
+        if value.get_type().is_none() || value.schema().shape_type() == &ShapeType::Document {
+            return self.write_null(schema);
+        }
+        value.serialize_with_schema(value.schema(), self)
+    }
+
+    #[inline]
+    fn write_null(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.out.write_str("null")?;
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+/// Comma-separated entries of a compound value.
+///
+/// Redacted values have already been written in full, so their entries are ignored.
+struct Entries<'a, W: Write> {
+    out: &'a mut W,
+    redacted: bool,
+    first: bool,
+}
+
+impl<'a, W: Write> Entries<'a, W> {
+    const fn new(out: &'a mut W, redacted: bool) -> Self {
+        Entries {
+            out,
+            redacted,
+            first: true,
+        }
+    }
+
+    /// Start the next entry, returning `None` if entries are redacted.
+    fn next(&mut self) -> Result<Option<&mut W>, FmtError> {
+        if self.redacted {
+            return Ok(None);
+        }
+        if !self.first {
+            self.out.write_str(", ")?;
+        }
+        self.first = false;
+        Ok(Some(&mut *self.out))
+    }
+
+    fn end(self, close: &str) -> Result<(), FmtError> {
+        if !self.redacted {
+            self.out.write_str(close)?;
+        }
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+pub struct FmtListWriter<'a, W: Write>(Entries<'a, W>);
+
+impl<W: Write> ListWriter for FmtListWriter<'_, W> {
+    type Error = FmtError;
+    type Ok = ();
+
+    fn write_element<T>(&mut self, element_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let Some(out) = self.0.next()? else {
+            return Ok(());
+        };
+        value.serialize_with_schema(element_schema, FmtSerializer::new(out))
+    }
+
+    #[inline]
+    fn end(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.0.end("]")
+    }
+}
+
+#[doc(hidden)]
+pub struct FmtMapWriter<'a, W: Write>(Entries<'a, W>);
+
+impl<W: Write> MapWriter for FmtMapWriter<'_, W> {
+    type Error = FmtError;
+    type Ok = ();
+
+    fn write_entry<K, V>(
+        &mut self,
+        key_schema: &Schema,
+        value_schema: &Schema,
+        key: &K,
+        value: &V,
+    ) -> Result<(), Self::Error>
+    where
+        K: SerializeWithSchema,
+        V: SerializeWithSchema,
+    {
+        let Some(out) = self.0.next()? else {
+            return Ok(());
+        };
+        key.serialize_with_schema(key_schema, FmtSerializer::new(out))?;
+        out.write_char('=')?;
+        value.serialize_with_schema(value_schema, FmtSerializer::new(out))
+    }
+
+    #[inline]
+    fn end(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.0.end("}")
+    }
+}
+
+#[doc(hidden)]
+pub struct FmtStructWriter<'a, W: Write>(Entries<'a, W>);
+
+impl<W: Write> StructWriter for FmtStructWriter<'_, W> {
+    type Error = FmtError;
+    type Ok = ();
+
+    fn write_member<T>(&mut self, member_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let Some(me) = member_schema.as_member() else {
+            return Err(FmtError::ExpectedMember(format!(
+                "{:?}",
+                member_schema.id()
+            )));
+        };
+        self.write_member_named(me.name(), member_schema, value)
+    }
+
+    fn write_member_named<T>(
+        &mut self,
+        member_name: &str,
+        member_schema: &Schema,
+        value: &T,
+    ) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let Some(out) = self.0.next()? else {
+            return Ok(());
+        };
+        write!(out, "{member_name}=")?;
+        value.serialize_with_schema(member_schema, FmtSerializer::new(out))
+    }
+
+    #[inline]
+    fn end(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.0.end("]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        IndexMap,
+        derive::{SmithyShape, smithy_structure, smithy_union},
+        schema::prelude::{BLOB, INTEGER, STRING},
+        smithy,
+    };
+
+    smithy!("com.example#Password": {
+        @SensitiveTrait::builder().build();
+        string PASSWORD
+    });
+    smithy!("com.example#IntegerList": {
+        list INTEGER_LIST {
+            member: INTEGER
+        }
+    });
+    smithy!("com.example#Tags": {
+        list TAGS {
+            member: STRING
+        }
+    });
+    smithy!("com.example#Scores": {
+        map SCORES {
+            key: STRING
+            value: INTEGER
+        }
+    });
+    smithy!("com.example#Token": {
+        @SensitiveTrait::builder().build();
+        structure TOKEN {
+            VALUE: STRING = "value"
+        }
+    });
+    smithy!("com.example#Login": {
+        structure LOGIN {
+            USER: STRING = "user"
+            PASSWORD: PASSWORD = "password"
+            TAGS: TAGS = "tags"
+            SCORES: SCORES = "scores"
+            AVATAR: BLOB = "avatar"
+            TOKEN: TOKEN = "token"
+        }
+    });
+    smithy!("com.example#Credential": {
+        union CREDENTIAL {
+            NAME: STRING = "name"
+            SECRET: PASSWORD = "secret"
+        }
+    });
+
+    #[smithy_structure]
+    #[derive(SmithyShape)]
+    #[smithy_schema(TOKEN)]
+    pub struct Token {
+        #[smithy_schema(VALUE)]
+        pub value: String,
+    }
+
+    #[smithy_structure]
+    #[derive(SmithyShape)]
+    #[smithy_schema(LOGIN)]
+    pub struct Login {
+        #[smithy_schema(USER)]
+        pub user: String,
+        #[smithy_schema(PASSWORD)]
+        pub password: String,
+        #[smithy_schema(TAGS)]
+        pub tags: Vec<String>,
+        #[smithy_schema(SCORES)]
+        pub scores: IndexMap<String, i32>,
+        #[smithy_schema(AVATAR)]
+        pub avatar: Option<Blob>,
+        #[smithy_schema(TOKEN)]
+        pub token: Option<Token>,
+    }
+
+    #[smithy_union]
+    #[derive(SmithyShape)]
+    #[smithy_schema(CREDENTIAL)]
+    pub enum Credential {
+        #[smithy_schema(NAME)]
+        Name(String),
+        #[smithy_schema(SECRET)]
+        Secret(String),
+    }
+
+    fn login() -> Login {
+        let mut scores = IndexMap::new();
+        scores.insert("math".to_string(), 1);
+        scores.insert("art".to_string(), 2);
+        Login {
+            user: "ferris".to_string(),
+            password: "hunter2".to_string(),
+            tags: vec!["a".to_string(), "b".to_string()],
+            scores,
+            avatar: None,
+            token: None,
+        }
+    }
+
+    #[test]
+    fn displays_structures() {
+        assert_eq!(
+            login().to_string(),
+            "Login[user=ferris, password=**REDACTED**, tags=[a, b], scores={math=1, art=2}]"
+        );
+    }
+
+    #[test]
+    fn redacts_sensitive_structures() {
+        let login = Login {
+            avatar: Some(Blob::from(vec![1, 2, 3])),
+            token: Some(Token {
+                value: "secret".to_string(),
+            }),
+            ..login()
+        };
+        let output = login.to_string();
+        assert!(
+            output.ends_with(", avatar=<3 bytes>, token=Token[**REDACTED**]]"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn displays_unions() {
+        assert_eq!(
+            Credential::Name("ferris".to_string()).to_string(),
+            "Credential[name=ferris]"
+        );
+        assert_eq!(
+            Credential::Secret("hunter2".to_string()).to_string(),
+            "Credential[secret=**REDACTED**]"
+        );
+    }
+
+    #[test]
+    fn writes_to_any_fmt_writer() {
+        let mut output = String::new();
+        vec![1, 2, 3]
+            .serialize_with_schema(&INTEGER_LIST, FmtSerializer::new(&mut output))
+            .unwrap();
+        assert_eq!(output, "[1, 2, 3]");
+    }
+}
@@ -0,0 +1,582 @@
+//! Trait-based serialization hooks
+//!
+//! [`TraitHooks`] allow a protocol to attach handlers to custom traits without
+//! modifying codec code. When a [`HookedSerializer`] encounters a member, list
+//! element, or map value whose schema has a trait with a registered hook,
+//! the value is converted into a [`Document`] and passed to the hook. The
+//! document returned by the hook is then written by the wrapped serializer
+//! in place of the original value.
+//!
+//! Hooks are only applied to members, list elements, and map values, so the
+//! root shape passed to the serializer is always written as-is.
+//!
+//! ## Example
+//! ```rust,ignore
+//! let hooks = TraitHooks::new().with(
+//!     "com.example#compression",
+//!     |_schema: &Schema, _trait: &TraitRef, value: Box<dyn Document>| {
+//!         let bytes = value.as_blob().ok_or_else(|| {
+//!             DocumentError::CustomError("expected a blob".to_string())
+//!         })?;
+//!         Ok(Blob::from(compress(bytes)).into())
+//!     },
+//! );
+//! let mut ser = JsonSerializer::new(&mut output);
+//! shape.serialize_with_schema(Shape::schema(), HookedSerializer::new(&mut ser, &hooks))?;
+//! ```
+//!
+use std::fmt::{Debug, Formatter};
+
+use crate::{
+    BigDecimal, BigInt, FxIndexMap, Instant,
+    schema::{Blob, Document, DocumentError, Schema, ShapeId, StreamingBlob, TraitRef},
+    serde::{
+        documents::DocumentParser,
+        eventstream::EventStream,
+        se::{ListWriter, MapWriter, SerializeWithSchema, Serializer, StructWriter},
+        serializers::Error,
+    },
+};
+
+// ============================================================================
+// Hooks
+// ============================================================================
+
+/// Handler invoked for values whose schema has a specific trait.
+///
+/// Hooks receive the schema of the value, the trait the hook was registered
+/// for, and the value converted into a [`Document`]. The returned document
+/// is serialized in place of the original value.
+pub trait TraitHook: Send + Sync {
+    /// Transform a value before it is serialized.
+    ///
+    /// # Errors
+    /// Returns a [`DocumentError`] if the value could not be transformed.
+    /// The error is converted into the error type of the wrapped serializer.
+    fn serialize(
+        &self,
+        schema: &Schema,
+        trait_value: &TraitRef,
+        value: Box<dyn Document>,
+    ) -> Result<Box<dyn Document>, DocumentError>;
+}
+
+impl<F> TraitHook for F
+where
+    F: Fn(&Schema, &TraitRef, Box<dyn Document>) -> Result<Box<dyn Document>, DocumentError>
+        + Send
+        + Sync,
+{
+    #[inline]
+    fn serialize(
+        &self,
+        schema: &Schema,
+        trait_value: &TraitRef,
+        value: Box<dyn Document>,
+    ) -> Result<Box<dyn Document>, DocumentError> {
+        self(schema, trait_value, value)
+    }
+}
+
+/// Registry mapping trait IDs to the [`TraitHook`]s invoked for them.
+///
+/// If a schema has more than one trait with a registered hook, the hooks
+/// are applied in registration order, each receiving the output of the last.
+pub struct TraitHooks {
+    hooks: FxIndexMap<ShapeId, Box<dyn TraitHook>>,
+}
+
+impl TraitHooks {
+    /// Create a new, empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        TraitHooks {
+            hooks: FxIndexMap::default(),
+        }
+    }
+
+    /// Register a hook for the trait with the given ID.
+    ///
+    /// Registering a hook for the same trait more than once replaces the previous hook.
+    pub fn register(
+        &mut self,
+        trait_id: impl Into<ShapeId>,
+        hook: impl TraitHook + 'static,
+    ) -> &mut Self {
+        self.hooks.insert(trait_id.into(), Box::new(hook));
+        self
+    }
+
+    /// Builder-style version of [`TraitHooks::register`].
+    #[must_use]
+    pub fn with(mut self, trait_id: impl Into<ShapeId>, hook: impl TraitHook + 'static) -> Self {
+        self.register(trait_id, hook);
+        self
+    }
+
+    /// Returns `true` if a hook is registered for the given trait ID.
+    #[must_use]
+    pub fn contains(&self, trait_id: &ShapeId) -> bool {
+        self.hooks.contains_key(trait_id)
+    }
+
+    /// Returns `true` if no hooks are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Returns `true` if any registered hook applies to the schema.
+    #[must_use]
+    pub fn applies_to(&self, schema: &Schema) -> bool {
+        self.hooks.keys().any(|id| schema.contains_trait(id))
+    }
+
+    /// Apply all hooks registered for traits of `schema` to a document.
+    ///
+    /// # Errors
+    /// Returns the first error raised by a hook.
+    pub fn apply(
+        &self,
+        schema: &Schema,
+        mut value: Box<dyn Document>,
+    ) -> Result<Box<dyn Document>, DocumentError> {
+        for (id, hook) in &self.hooks {
+            if let Some(trait_value) = schema.get_trait(id) {
+                value = hook.serialize(schema, trait_value, value)?;
+            }
+        }
+        Ok(value)
+    }
+
+    /// Serialize a value with `serializer`, applying hooks to the value itself if
+    /// its schema has a hooked trait and to all nested values.
+    fn serialize_value<T, S>(
+        &self,
+        schema: &Schema,
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: SerializeWithSchema + ?Sized,
+        S: Serializer,
+    {
+        if !self.applies_to(schema) {
+            return value.serialize_with_schema(schema, HookedSerializer::new(serializer, self));
+        }
+        let document = value
+            .serialize_with_schema(schema, DocumentParser)
+            .and_then(|document| self.apply(schema, document))
+            .map_err(S::Error::custom)?;
+        document.serialize_with_schema(schema, serializer)
+    }
+}
+
+impl Default for TraitHooks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for TraitHooks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.hooks.keys()).finish()
+    }
+}
+
+/// Value that is serialized with [`TraitHooks`] applied.
+struct Hooked<'a, T: ?Sized> {
+    value: &'a T,
+    hooks: &'a TraitHooks,
+}
+
+impl<T: SerializeWithSchema + ?Sized> SerializeWithSchema for Hooked<'_, T> {
+    #[inline]
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        self.hooks.serialize_value(schema, self.value, serializer)
+    }
+}
+
+// ============================================================================
+// Hooked Serializer
+// ============================================================================
+
+/// [`Serializer`] wrapper that applies [`TraitHooks`] to nested values.
+///
+/// All writes are delegated to the wrapped serializer.
+pub struct HookedSerializer<'h, S> {
+    inner: S,
+    hooks: &'h TraitHooks,
+}
+
+impl<'h, S> HookedSerializer<'h, S> {
+    /// Wrap a serializer with a set of trait hooks.
+    pub const fn new(inner: S, hooks: &'h TraitHooks) -> Self {
+        Self { inner, hooks }
+    }
+}
+
+impl<'h, S: Serializer> Serializer for HookedSerializer<'h, S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+    type ListWriter = HookedWriter<'h, S::ListWriter>;
+    type MapWriter = HookedWriter<'h, S::MapWriter>;
+    type StructWriter = HookedWriter<'h, S::StructWriter>;
+
+    #[inline]
+    fn write_struct(self, schema: &Schema, len: usize) -> Result<Self::StructWriter, Self::Error> {
+        Ok(HookedWriter {
+            inner: self.inner.write_struct(schema, len)?,
+            hooks: self.hooks,
+        })
+    }
+
+    #[inline]
+    fn write_map(self, schema: &Schema, len: usize) -> Result<Self::MapWriter, Self::Error> {
+        Ok(HookedWriter {
+            inner: self.inner.write_map(schema, len)?,
+            hooks: self.hooks,
+        })
+    }
+
+    #[inline]
+    fn write_list(self, schema: &Schema, len: usize) -> Result<Self::ListWriter, Self::Error> {
+        Ok(HookedWriter {
+            inner: self.inner.write_list(schema, len)?,
+            hooks: self.hooks,
+        })
+    }
+
+    #[inline]
+    fn write_boolean(self, schema: &Schema, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_boolean(schema, value)
+    }
+
+    #[inline]
+    fn write_byte(self, schema: &Schema, value: i8) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_byte(schema, value)
+    }
+
+    #[inline]
+    fn write_short(self, schema: &Schema, value: i16) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_short(schema, value)
+    }
+
+    #[inline]
+    fn write_integer(self, schema: &Schema, value: i32) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_integer(schema, value)
+    }
+
+    #[inline]
+    fn write_long(self, schema: &Schema, value: i64) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_long(schema, value)
+    }
+
+    #[inline]
+    fn write_float(self, schema: &Schema, value: f32) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_float(schema, value)
+    }
+
+    #[inline]
+    fn write_double(self, schema: &Schema, value: f64) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_double(schema, value)
+    }
+
+    #[inline]
+    fn write_big_integer(self, schema: &Schema, value: &BigInt) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_big_integer(schema, value)
+    }
+
+    #[inline]
+    fn write_big_decimal(
+        self,
+        schema: &Schema,
+        value: &BigDecimal,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_big_decimal(schema, value)
+    }
+
+    #[inline]
+    fn write_string(self, schema: &Schema, value: &str) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_string(schema, value)
+    }
+
+    #[inline]
+    fn write_blob(self, schema: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_blob(schema, value)
+    }
+
+    #[inline]
+    fn write_streaming_blob(
+        self,
+        schema: &Schema,
+        value: &StreamingBlob,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_streaming_blob(schema, value)
+    }
+
+    #[inline]
+    fn write_event_stream<T>(
+        self,
+        schema: &Schema,
+        value: &EventStream<T>,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_event_stream(schema, value)
+    }
+
+    #[inline]
+    fn write_timestamp(self, schema: &Schema, value: &Instant) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_timestamp(schema, value)
+    }
+
+    #[inline]
+    fn write_document(
+        self,
+        schema: &Schema,
+        value: &Box<dyn Document>,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_document(schema, value)
+    }
+
+    #[inline]
+    fn write_null(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_null(schema)
+    }
+
+    #[inline]
+    fn write_missing(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_missing(schema)
+    }
+
+    #[inline]
+    fn skip(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.skip(schema)
+    }
+
+    #[inline]
+    fn flush(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.flush()
+    }
+}
+
+/// Struct, list, and map writer that applies [`TraitHooks`] to each value written.
+pub struct HookedWriter<'h, W> {
+    inner: W,
+    hooks: &'h TraitHooks,
+}
+
+impl<W: StructWriter> StructWriter for HookedWriter<'_, W> {
+    type Error = W::Error;
+    type Ok = W::Ok;
+
+    #[inline]
+    fn write_discriminator(&mut self, discriminator: &ShapeId) -> Result<(), Self::Error> {
+        self.inner.write_discriminator(discriminator)
+    }
+
+    #[inline]
+    fn write_member<T>(&mut self, member_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let hooks = self.hooks;
+        self.inner
+            .write_member(member_schema, &Hooked { value, hooks })
+    }
+
+    #[inline]
+    fn write_member_named<T>(
+        &mut self,
+        member_name: &str,
+        member_schema: &Schema,
+        value: &T,
+    ) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let hooks = self.hooks;
+        self.inner
+            .write_member_named(member_name, member_schema, &Hooked { value, hooks })
+    }
+
+    #[inline]
+    fn skip_member(&mut self, schema: &Schema) -> Result<(), Self::Error> {
+        self.inner.skip_member(schema)
+    }
+
+    #[inline]
+    fn write_unknown(&mut self, schema: &Schema, name: &String) -> Result<(), Self::Error> {
+        self.inner.write_unknown(schema, name)
+    }
+
+    #[inline]
+    fn write_unknown_member(
+        &mut self,
+        member_name: &str,
+        value: &Box<dyn Document>,
+    ) -> Result<(), Self::Error> {
+        self.inner.write_unknown_member(member_name, value)
+    }
+
+    #[inline]
+    fn end(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.end(schema)
+    }
+}
+
+impl<W: ListWriter> ListWriter for HookedWriter<'_, W> {
+    type Error = W::Error;
+    type Ok = W::Ok;
+
+    #[inline]
+    fn write_element<T>(&mut self, element_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let hooks = self.hooks;
+        self.inner
+            .write_element(element_schema, &Hooked { value, hooks })
+    }
+
+    #[inline]
+    fn end(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.end(schema)
+    }
+}
+
+impl<W: MapWriter> MapWriter for HookedWriter<'_, W> {
+    type Error = W::Error;
+    type Ok = W::Ok;
+
+    #[inline]
+    fn write_entry<K, V>(
+        &mut self,
+        key_schema: &Schema,
+        value_schema: &Schema,
+        key: &K,
+        value: &V,
+    ) -> Result<(), Self::Error>
+    where
+        K: SerializeWithSchema,
+        V: SerializeWithSchema,
+    {
+        let hooks = self.hooks;
+        self.inner
+            .write_entry(key_schema, value_schema, key, &Hooked { value, hooks })
+    }
+
+    #[inline]
+    fn end(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.end(schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        derive::SmithyShape,
+        prelude::{INTEGER, STRING},
+        schema::DynamicTrait,
+        smithy,
+    };
+
+    smithy!("com.example#Tags": {
+        list TAGS_SCHEMA {
+            @DynamicTrait::from("com.example#shout", true);
+            member: STRING
+        }
+    });
+    smithy!("com.example#Message": {
+        structure MESSAGE_SCHEMA {
+            @DynamicTrait::from("com.example#shout", true);
+            BODY: STRING = "body"
+            COUNT: INTEGER = "count"
+            TAGS: TAGS_SCHEMA = "tags"
+        }
+    });
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(MESSAGE_SCHEMA)]
+    pub struct Message {
+        #[smithy_schema(BODY)]
+        body: String,
+        #[smithy_schema(COUNT)]
+        count: i32,
+        #[smithy_schema(TAGS)]
+        tags: Vec<String>,
+    }
+
+    fn message() -> Message {
+        Message {
+            body: "hello".to_string(),
+            count: 2,
+            tags: vec!["a".to_string(), "b".to_string()],
+        }
+    }
+
+    fn shout(
+        _schema: &Schema,
+        _trait_value: &TraitRef,
+        value: Box<dyn Document>,
+    ) -> Result<Box<dyn Document>, DocumentError> {
+        let value = value
+            .as_string()
+            .ok_or_else(|| DocumentError::CustomError("expected a string".to_string()))?;
+        Ok(value.to_uppercase().into())
+    }
+
+    #[test]
+    fn applies_hooks_to_members_and_elements() {
+        let hooks = TraitHooks::new().with("com.example#shout", shout);
+        assert!(hooks.contains(&ShapeId::from("com.example#shout")));
+
+        let document = message()
+            .serialize_with_schema(
+                &MESSAGE_SCHEMA,
+                HookedSerializer::new(DocumentParser, &hooks),
+            )
+            .unwrap();
+        let members = document.as_map().unwrap();
+        assert_eq!(members.get("body").unwrap().as_string(), Some("HELLO"));
+        assert_eq!(members.get("count").unwrap().as_integer(), Some(2));
+        let tags = members.get("tags").unwrap().as_list().unwrap();
+        assert_eq!(tags[0].as_string(), Some("A"));
+        assert_eq!(tags[1].as_string(), Some("B"));
+    }
+
+    #[test]
+    fn serializes_unchanged_without_hooks() {
+        let hooks = TraitHooks::new();
+        assert!(hooks.is_empty());
+        let document = message()
+            .serialize_with_schema(
+                &MESSAGE_SCHEMA,
+                HookedSerializer::new(DocumentParser, &hooks),
+            )
+            .unwrap();
+        let members = document.as_map().unwrap();
+        assert_eq!(members.get("body").unwrap().as_string(), Some("hello"));
+    }
+
+    #[test]
+    fn propagates_hook_errors() {
+        let hooks = TraitHooks::new().with(
+            "com.example#shout",
+            |_: &Schema, _: &TraitRef, _: Box<dyn Document>| {
+                Err(DocumentError::CustomError("no shouting".to_string()))
+            },
+        );
+        let result = message().serialize_with_schema(
+            &MESSAGE_SCHEMA,
+            HookedSerializer::new(DocumentParser, &hooks),
+        );
+        assert!(
+            matches!(result, Err(DocumentError::CustomError(msg)) if msg.contains("no shouting"))
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! # HTTP Bindings
+//!
+//! Utilities for protocols that bind structure members to locations in an HTTP
+//! message using the [HTTP binding traits](https://smithy.io/2.0/spec/http-bindings.html).
+//!
+//! The default serialization format of some values depends on where they are bound.
+//! For example, a timestamp with no `@timestampFormat` trait is serialized as an
+//! `http-date` when bound to a header, as a `date-time` when bound to a query
+//! string or URI label, and using the protocol's default format when serialized
+//! in the message body:
+//!
+//! ```rust,ignore
+//! let binding = HttpBinding::of(member_schema);
+//! let format = binding.timestamp_format(member_schema, &TimestampFormatTrait::EpochSeconds);
+//! ```
+
+use crate::{
+    prelude::{
+        ErrorTrait, HttpErrorTrait, HttpHeaderTrait, HttpLabelTrait, HttpPayloadTrait,
+        HttpPrefixHeadersTrait, HttpQueryParamsTrait, HttpQueryTrait, HttpResponseCodeTrait,
+        TimestampFormatTrait,
+    },
+    schema::Schema,
+};
+
+/// Location a member is bound to in an HTTP message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpBinding {
+    /// Member is bound to a header (`@httpHeader`).
+    Header,
+    /// Member is bound to a set of prefixed headers (`@httpPrefixHeaders`).
+    PrefixHeaders,
+    /// Member is bound to a query string parameter (`@httpQuery`).
+    Query,
+    /// Member is bound to all unbound query string parameters (`@httpQueryParams`).
+    QueryParams,
+    /// Member is bound to a URI label (`@httpLabel`).
+    Label,
+    /// Member is bound to the entire message body (`@httpPayload`).
+    Payload,
+    /// Member is bound to the response status code (`@httpResponseCode`).
+    ResponseCode,
+    /// Member has no binding trait, and is serialized as part of the message body.
+    Body,
+}
+
+impl HttpBinding {
+    /// Determine the location a member is bound to from its HTTP binding traits.
+    #[must_use]
+    pub fn of(schema: &Schema) -> Self {
+        if schema.contains_type::<HttpHeaderTrait>() {
+            HttpBinding::Header
+        } else if schema.contains_type::<HttpPrefixHeadersTrait>() {
+            HttpBinding::PrefixHeaders
+        } else if schema.contains_type::<HttpQueryTrait>() {
+            HttpBinding::Query
+        } else if schema.contains_type::<HttpQueryParamsTrait>() {
+            HttpBinding::QueryParams
+        } else if schema.contains_type::<HttpLabelTrait>() {
+            HttpBinding::Label
+        } else if schema.contains_type::<HttpPayloadTrait>() {
+            HttpBinding::Payload
+        } else if schema.contains_type::<HttpResponseCodeTrait>() {
+            HttpBinding::ResponseCode
+        } else {
+            HttpBinding::Body
+        }
+    }
+
+    /// Resolve the format of a timestamp bound to this location.
+    ///
+    /// An explicit `@timestampFormat` trait on `schema` always takes precedence.
+    /// Otherwise, headers default to `http-date`, query strings and URI labels
+    /// default to `date-time`, and body values use the protocol's `body_default`.
+    ///
+    /// `schema` may differ from the bound member (for example, the value schema of
+    /// a list bound to a header), so the binding is resolved separately.
+    #[must_use]
+    pub fn timestamp_format(
+        self,
+        schema: &Schema,
+        body_default: &TimestampFormatTrait,
+    ) -> TimestampFormatTrait {
+        if let Some(format) = schema.get_trait_as::<TimestampFormatTrait>() {
+            return format.clone();
+        }
+        match self {
+            HttpBinding::Header | HttpBinding::PrefixHeaders => TimestampFormatTrait::HttpDate,
+            HttpBinding::Query | HttpBinding::QueryParams | HttpBinding::Label => {
+                TimestampFormatTrait::DateTime
+            }
+            HttpBinding::Payload | HttpBinding::ResponseCode | HttpBinding::Body => {
+                body_default.clone()
+            }
+        }
+    }
+}
+
+/// Resolve the status code of a response for an error shape.
+///
+/// An explicit `@httpError` trait always takes precedence. Otherwise, `client`
+/// errors default to `400` and all other errors default to `500`.
+#[must_use]
+pub fn error_code(schema: &Schema) -> i32 {
+    if let Some(code) = schema.get_trait_as::<HttpErrorTrait>() {
+        return **code;
+    }
+    match schema.get_trait_as::<ErrorTrait>() {
+        Some(ErrorTrait::Client) => 400,
+        _ => 500,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, smithy};
+
+    smithy!("com.example#GetEventInput": {
+        structure GET_EVENT_INPUT_SCHEMA {
+            @HttpLabelTrait::builder().build();
+            LABEL: TIMESTAMP = "label"
+            @HttpQueryTrait::new("since");
+            QUERY: TIMESTAMP = "query"
+            @HttpHeaderTrait::new("X-Modified");
+            HEADER: TIMESTAMP = "header"
+            @HttpHeaderTrait::new("X-Expires");
+            @TimestampFormatTrait::EpochSeconds;
+            FORMATTED_HEADER: TIMESTAMP = "formattedHeader"
+            BODY: TIMESTAMP = "body"
+        }
+    });
+
+    fn resolve(member: &str) -> TimestampFormatTrait {
+        let schema = GET_EVENT_INPUT_SCHEMA.expect_member(member);
+        HttpBinding::of(schema).timestamp_format(schema, &TimestampFormatTrait::EpochSeconds)
+    }
+
+    #[test]
+    fn resolves_binding_location() {
+        assert_eq!(
+            HttpBinding::of(GET_EVENT_INPUT_SCHEMA.expect_member("label")),
+            HttpBinding::Label
+        );
+        assert_eq!(
+            HttpBinding::of(GET_EVENT_INPUT_SCHEMA.expect_member("header")),
+            HttpBinding::Header
+        );
+        assert_eq!(
+            HttpBinding::of(GET_EVENT_INPUT_SCHEMA.expect_member("body")),
+            HttpBinding::Body
+        );
+    }
+
+    #[test]
+    fn defaults_timestamp_format_by_location() {
+        assert!(matches!(resolve("label"), TimestampFormatTrait::DateTime));
+        assert!(matches!(resolve("query"), TimestampFormatTrait::DateTime));
+        assert!(matches!(resolve("header"), TimestampFormatTrait::HttpDate));
+        assert!(matches!(
+            resolve("body"),
+            TimestampFormatTrait::EpochSeconds
+        ));
+    }
+
+    #[test]
+    fn explicit_timestamp_format_takes_precedence() {
+        assert!(matches!(
+            resolve("formattedHeader"),
+            TimestampFormatTrait::EpochSeconds
+        ));
+    }
+
+    smithy!("com.example#NotFound": {
+        @ErrorTrait::Client;
+        @HttpErrorTrait::new(404);
+        structure NOT_FOUND_SCHEMA {}
+    });
+    smithy!("com.example#BadRequest": {
+        @ErrorTrait::Client;
+        structure BAD_REQUEST_SCHEMA {}
+    });
+    smithy!("com.example#Fault": {
+        @ErrorTrait::Server;
+        structure FAULT_SCHEMA {}
+    });
+
+    #[test]
+    fn resolves_error_code() {
+        assert_eq!(error_code(&NOT_FOUND_SCHEMA), 404);
+        assert_eq!(error_code(&BAD_REQUEST_SCHEMA), 400);
+        assert_eq!(error_code(&FAULT_SCHEMA), 500);
+    }
+}
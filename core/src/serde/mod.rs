@@ -1,16 +1,59 @@
+//! Schema-guided serialization, deserialization, and validation of shapes.
+//!
+//! ## Extension points for codec authors
+//! The following traits are stable extension points that third-party codecs
+//! (and protocols) may implement:
+//!
+//! | Trait | Purpose |
+//! |-------|---------|
+//! | [`serializers::Serializer`](crate::serde::serializers::Serializer), [`serializers::StructWriter`](crate::serde::serializers::StructWriter), [`serializers::ListWriter`](crate::serde::serializers::ListWriter), [`serializers::MapWriter`](crate::serde::serializers::MapWriter) | Write shapes to a data format |
+//! | [`deserializers::Deserializer`](crate::serde::deserializers::Deserializer), [`deserializers::StructReader`](crate::serde::deserializers::StructReader), [`deserializers::ListReader`](crate::serde::deserializers::ListReader), [`deserializers::MapReader`](crate::serde::deserializers::MapReader) | Read shapes from a data format |
+//! | [`serializers::Error`](crate::serde::serializers::Error), [`deserializers::Error`](crate::serde::deserializers::Error) | Create codec errors from shared code |
+//! | [`Codec`](crate::serde::Codec), [`Protocol`](crate::serde::Protocol) | Expose a codec or protocol to clients and servers |
+//! | [`validation::Validator`](crate::serde::validation::Validator) | Custom validation of built shapes |
+//! | [`eventstream::FrameEncoder`](crate::serde::eventstream::FrameEncoder), [`eventstream::FrameDecoder`](crate::serde::eventstream::FrameDecoder) | Write and read event stream messages in a protocol's framing format |
+//!
+//! New methods are only ever added to these traits with a default implementation,
+//! so adding support for a new data type will not break existing codecs. Error enums
+//! shared with codecs (i.e. [`CodecError`](crate::serde::CodecError)) are `#[non_exhaustive]` for the same reason.
+//!
+//! All other traits in this module (i.e. [`serializers::SerializeWithSchema`](crate::serde::serializers::SerializeWithSchema),
+//! [`deserializers::DeserializeWithSchema`](crate::serde::deserializers::DeserializeWithSchema), and [`ShapeBuilder`](crate::serde::ShapeBuilder)) are implemented by
+//! generated code and may change between releases. Codecs should call,
+//! but not implement, these traits.
+//!
+//! See the `codec-author` example crate for a minimal codec built on these traits.
 mod builders;
 pub use builders::*;
 
+pub mod audit;
 pub mod correction;
+pub mod deadline;
 pub mod debug;
 pub mod deserializers;
 mod documents;
 pub use documents::*;
+mod errors;
+pub mod eventstream;
+pub mod fmt;
+pub use errors::*;
+pub mod hooks;
+pub mod http;
+pub mod pool;
+pub mod projection;
+mod protocol;
+pub mod selection;
+pub use protocol::*;
+mod registry;
+pub use registry::*;
 pub mod serializers;
+pub mod strict;
+pub mod strings;
 mod unit;
 
 mod utils;
 pub mod validation;
+pub mod visitor;
 
 pub use deserializers as de;
 pub use serializers as se;
@@ -0,0 +1,313 @@
+//! # Buffer Pools
+//!
+//! Reusable byte buffers for codecs that allocate while deserializing.
+//!
+//! Blob members and escaped strings require an owned buffer for each value read.
+//! Servers handling many requests with medium-sized binary members spend a
+//! noticeable amount of time allocating and freeing those buffers. A [`BufferPool`]
+//! lets a deserializer draw buffers from (and return scratch buffers to) a shared
+//! free list instead.
+//!
+//! Pools are intended to be scoped to a single request (or connection). Blobs
+//! produced while handling the request can be handed back with
+//! [`BufferPool::recycle`] once they are no longer needed:
+//!
+//! ```rust
+//! use smithy4rs_core::{schema::Blob, serde::pool::BufferPool};
+//!
+//! let pool = BufferPool::new();
+//! let mut buffer = pool.take(16);
+//! buffer.extend_from_slice(b"hello");
+//! let blob = Blob::from(buffer);
+//!
+//! // ... use the blob ...
+//!
+//! pool.recycle(blob);
+//! assert_eq!(pool.len(), 1);
+//! assert!(pool.take(8).capacity() >= 16);
+//! ```
+//!
+//! Scratch buffers should be leased with [`BufferPool::lease`] instead. A [`PooledBuffer`]
+//! is returned to its pool when dropped, so buffers are not lost when deserialization
+//! fails part way through, or when the future driving it is cancelled.
+
+use std::{
+    fmt::{Debug, Formatter},
+    ops::{Deref, DerefMut},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use crate::schema::Blob;
+
+/// Default maximum number of idle buffers retained by a pool.
+const DEFAULT_MAX_BUFFERS: usize = 32;
+/// Default maximum capacity (in bytes) of a buffer retained by a pool.
+const DEFAULT_MAX_CAPACITY: usize = 1024 * 1024;
+
+/// A shared free list of byte buffers.
+///
+/// Cloning a pool is cheap and returns a handle to the same free list, so a
+/// single pool can be handed to every deserializer used for a request.
+#[derive(Debug, Clone)]
+pub struct BufferPool {
+    buffers: Arc<Mutex<Vec<Vec<u8>>>>,
+    max_buffers: usize,
+    max_capacity: usize,
+}
+
+impl BufferPool {
+    /// Create an empty pool with default limits.
+    #[must_use]
+    pub fn new() -> Self {
+        BufferPool {
+            buffers: Arc::new(Mutex::new(Vec::new())),
+            max_buffers: DEFAULT_MAX_BUFFERS,
+            max_capacity: DEFAULT_MAX_CAPACITY,
+        }
+    }
+
+    /// Set the maximum number of idle buffers the pool retains.
+    ///
+    /// Buffers returned to a full pool are dropped.
+    #[must_use]
+    pub fn with_max_buffers(mut self, max_buffers: usize) -> Self {
+        self.max_buffers = max_buffers;
+        self
+    }
+
+    /// Set the maximum capacity (in bytes) of a buffer the pool retains.
+    ///
+    /// Larger buffers are dropped when returned so that a single large payload
+    /// does not pin memory for the lifetime of the pool.
+    #[must_use]
+    pub fn with_max_capacity(mut self, max_capacity: usize) -> Self {
+        self.max_capacity = max_capacity;
+        self
+    }
+
+    /// Take an empty buffer with at least `capacity` bytes of capacity.
+    ///
+    /// The smallest pooled buffer that is large enough is reused. If no pooled
+    /// buffer is large enough, one is grown, and if the pool is empty a new
+    /// buffer is allocated.
+    #[must_use]
+    pub fn take(&self, capacity: usize) -> Vec<u8> {
+        let mut buffers = self.lock();
+        let best = buffers
+            .iter()
+            .enumerate()
+            .filter(|(_, buffer)| buffer.capacity() >= capacity)
+            .min_by_key(|(_, buffer)| buffer.capacity())
+            .map(|(index, _)| index);
+        let buffer = match best {
+            Some(index) => Some(buffers.swap_remove(index)),
+            None => buffers.pop(),
+        };
+        drop(buffers);
+        buffer.map_or_else(
+            || Vec::with_capacity(capacity),
+            |mut buffer| {
+                buffer.reserve(capacity);
+                buffer
+            },
+        )
+    }
+
+    /// Lease an empty buffer with at least `capacity` bytes of capacity.
+    ///
+    /// The buffer is returned to the pool when the lease is dropped, unless it is
+    /// detached with [`PooledBuffer::into_inner`].
+    #[must_use]
+    pub fn lease(&self, capacity: usize) -> PooledBuffer {
+        PooledBuffer {
+            buffer: self.take(capacity),
+            pool: Some(self.clone()),
+        }
+    }
+
+    /// Return a buffer to the pool.
+    ///
+    /// The buffer is cleared before it is retained. Buffers exceeding the pool's
+    /// maximum capacity, or returned while the pool is full, are dropped.
+    pub fn give(&self, mut buffer: Vec<u8>) {
+        if buffer.capacity() == 0 || buffer.capacity() > self.max_capacity {
+            return;
+        }
+        let mut buffers = self.lock();
+        if buffers.len() < self.max_buffers {
+            buffer.clear();
+            buffers.push(buffer);
+        }
+    }
+
+    /// Return the storage backing a blob to the pool.
+    ///
+    /// If the blob's data is still shared with another blob, it is copied into
+    /// a new buffer first, so prefer recycling the last remaining clone.
+    pub fn recycle(&self, blob: Blob) {
+        self.give(blob.into_vec());
+    }
+
+    /// Number of idle buffers currently held by the pool.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if the pool holds no idle buffers.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Vec<u8>>> {
+        // The free list cannot be left in an inconsistent state, so a panic
+        // while it was held does not invalidate it.
+        self.buffers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for BufferPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// Pooled Buffers
+// ============================================================================
+
+/// A buffer leased from a [`BufferPool`].
+///
+/// The buffer is cleared and returned to its pool when dropped.
+pub struct PooledBuffer {
+    buffer: Vec<u8>,
+    pool: Option<BufferPool>,
+}
+
+impl PooledBuffer {
+    /// Lease a buffer from `pool`, or allocate a buffer that is not pooled if there is no pool.
+    #[must_use]
+    pub fn take(pool: Option<&BufferPool>, capacity: usize) -> Self {
+        pool.map_or_else(
+            || PooledBuffer {
+                buffer: Vec::with_capacity(capacity),
+                pool: None,
+            },
+            |pool| pool.lease(capacity),
+        )
+    }
+
+    /// Detach the buffer from its pool, so it is not returned when dropped.
+    #[must_use]
+    pub fn into_inner(mut self) -> Vec<u8> {
+        self.pool = None;
+        std::mem::take(&mut self.buffer)
+    }
+}
+
+impl Deref for PooledBuffer {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl DerefMut for PooledBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+impl Drop for PooledBuffer {
+    fn drop(&mut self) {
+        if let Some(pool) = &self.pool {
+            pool.give(std::mem::take(&mut self.buffer));
+        }
+    }
+}
+
+impl Debug for PooledBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PooledBuffer")
+            .field("len", &self.buffer.len())
+            .field("capacity", &self.buffer.capacity())
+            .field("pooled", &self.pool.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuses_returned_buffers() {
+        let pool = BufferPool::new();
+        let mut buffer = pool.take(64);
+        buffer.extend_from_slice(b"data");
+        let ptr = buffer.as_ptr();
+        pool.give(buffer);
+        assert_eq!(pool.len(), 1);
+
+        let reused = pool.take(32);
+        assert!(reused.is_empty());
+        assert_eq!(reused.as_ptr(), ptr);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn takes_smallest_sufficient_buffer() {
+        let pool = BufferPool::new();
+        pool.give(Vec::with_capacity(1024));
+        pool.give(Vec::with_capacity(16));
+        pool.give(Vec::with_capacity(128));
+
+        assert!((128..1024).contains(&pool.take(100).capacity()));
+        assert!(pool.take(2000).capacity() >= 2000);
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn drops_buffers_over_limits() {
+        let pool = BufferPool::new().with_max_buffers(1).with_max_capacity(64);
+        pool.give(Vec::with_capacity(128));
+        assert!(pool.is_empty());
+
+        pool.give(Vec::with_capacity(32));
+        pool.give(Vec::with_capacity(32));
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn clones_share_free_list() {
+        let pool = BufferPool::new();
+        let handle = pool.clone();
+        handle.recycle(Blob::from(Vec::with_capacity(8)));
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn leases_return_buffers_when_dropped() {
+        let pool = BufferPool::new();
+        let mut lease = pool.lease(64);
+        lease.extend_from_slice(b"scratch");
+        assert!(pool.is_empty());
+        drop(lease);
+        assert_eq!(pool.len(), 1);
+        assert!(pool.take(8).is_empty());
+    }
+
+    #[test]
+    fn detached_leases_are_not_returned() {
+        let pool = BufferPool::new();
+        let mut lease = PooledBuffer::take(Some(&pool), 16);
+        lease.push(1);
+        assert_eq!(lease.into_inner(), vec![1]);
+        assert!(pool.is_empty());
+
+        let unpooled = PooledBuffer::take(None, 16);
+        assert!(unpooled.capacity() >= 16);
+    }
+}
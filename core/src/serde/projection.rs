@@ -0,0 +1,251 @@
+//! Projection of structure members during deserialization
+//!
+//! A [`Projection`] restricts deserialization of a structure to a subset
+//! of its members. Members outside the projection are skipped using the
+//! underlying codec's [`StructReader::skip_value`] implementation, so their values
+//! are never built. This makes it cheap to extract a few small members
+//! (for example, routing keys) from very large payloads.
+//!
+//! Projections only apply to the top-level structure being deserialized.
+//! Nested structures, lists, and maps of a projected member are read in full.
+//!
+//! ## Example
+//! ```rust,ignore
+//! let projection = Projection::new(["id", "region"]);
+//! let mut de = JsonDeserializer::new(data);
+//! let builder = OrderBuilder::deserialize_with_schema(
+//!     Order::schema(),
+//!     ProjectedDeserializer::new(&mut de, &projection)
+//! )?;
+//! // Un-projected required members will be unset, so use error correction
+//! // to build the shape.
+//! let order = builder.build_unchecked_corrected();
+//! ```
+//!
+use std::borrow::Cow;
+
+use crate::{
+    BigDecimal, BigInt, FxIndexSet, Instant,
+    schema::{Blob, Document, Schema, StreamingBlob},
+    serde::{
+        de::{DeserializeWithSchema, Deserializer, RawEnumValue, StructReader, UnknownEnumPolicy},
+        eventstream::EventStream,
+    },
+};
+
+// ============================================================================
+// Projection
+// ============================================================================
+
+/// Set of structure member names to deserialize.
+#[derive(Clone, Debug, Default)]
+pub struct Projection {
+    members: FxIndexSet<String>,
+}
+
+impl Projection {
+    /// Create a new projection containing the provided member names.
+    pub fn new<I, S>(members: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            members: members.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Add a member name to the projection.
+    #[must_use]
+    pub fn include<S: Into<String>>(mut self, member_name: S) -> Self {
+        self.members.insert(member_name.into());
+        self
+    }
+
+    /// Returns `true` if the member schema is included in this projection.
+    ///
+    /// Schemas that are not members are never included.
+    #[must_use]
+    pub fn contains(&self, member_schema: &Schema) -> bool {
+        member_schema
+            .as_member()
+            .is_some_and(|member| self.members.contains(member.name()))
+    }
+}
+
+// ============================================================================
+// Projected Deserializer
+// ============================================================================
+
+/// [`Deserializer`] wrapper that only reads the members of a [`Projection`]
+/// from the top-level structure.
+///
+/// All other reads are delegated directly to the wrapped deserializer.
+pub struct ProjectedDeserializer<'p, D> {
+    inner: D,
+    projection: &'p Projection,
+}
+
+impl<'p, D> ProjectedDeserializer<'p, D> {
+    /// Wrap a deserializer with a projection.
+    pub const fn new(inner: D, projection: &'p Projection) -> Self {
+        Self { inner, projection }
+    }
+}
+
+impl<'de, 'p, D: Deserializer<'de>> Deserializer<'de> for ProjectedDeserializer<'p, D> {
+    type Error = D::Error;
+    type StructReader = ProjectedStructReader<'p, D::StructReader>;
+    type ListReader = D::ListReader;
+    type MapReader = D::MapReader;
+
+    #[inline]
+    fn read_bool(self, schema: &Schema) -> Result<bool, Self::Error> {
+        self.inner.read_bool(schema)
+    }
+
+    #[inline]
+    fn read_byte(self, schema: &Schema) -> Result<i8, Self::Error> {
+        self.inner.read_byte(schema)
+    }
+
+    #[inline]
+    fn read_short(self, schema: &Schema) -> Result<i16, Self::Error> {
+        self.inner.read_short(schema)
+    }
+
+    #[inline]
+    fn read_integer(self, schema: &Schema) -> Result<i32, Self::Error> {
+        self.inner.read_integer(schema)
+    }
+
+    #[inline]
+    fn read_long(self, schema: &Schema) -> Result<i64, Self::Error> {
+        self.inner.read_long(schema)
+    }
+
+    #[inline]
+    fn read_float(self, schema: &Schema) -> Result<f32, Self::Error> {
+        self.inner.read_float(schema)
+    }
+
+    #[inline]
+    fn read_double(self, schema: &Schema) -> Result<f64, Self::Error> {
+        self.inner.read_double(schema)
+    }
+
+    #[inline]
+    fn read_big_integer(self, schema: &Schema) -> Result<BigInt, Self::Error> {
+        self.inner.read_big_integer(schema)
+    }
+
+    #[inline]
+    fn read_big_decimal(self, schema: &Schema) -> Result<BigDecimal, Self::Error> {
+        self.inner.read_big_decimal(schema)
+    }
+
+    #[inline]
+    fn read_string(self, schema: &Schema) -> Result<String, Self::Error> {
+        self.inner.read_string(schema)
+    }
+
+    #[inline]
+    fn read_str(self, schema: &Schema) -> Result<Cow<'de, str>, Self::Error> {
+        self.inner.read_str(schema)
+    }
+
+    #[inline]
+    fn read_enum(self, schema: &Schema) -> Result<RawEnumValue<'de>, Self::Error> {
+        self.inner.read_enum(schema)
+    }
+
+    fn unknown_enum_policy(&self) -> UnknownEnumPolicy {
+        self.inner.unknown_enum_policy()
+    }
+
+    #[inline]
+    fn read_blob(self, schema: &Schema) -> Result<Blob, Self::Error> {
+        self.inner.read_blob(schema)
+    }
+
+    #[inline]
+    fn read_streaming_blob(self, schema: &Schema) -> Result<StreamingBlob, Self::Error> {
+        self.inner.read_streaming_blob(schema)
+    }
+
+    #[inline]
+    fn read_event_stream<T>(self, schema: &Schema) -> Result<EventStream<T>, Self::Error> {
+        self.inner.read_event_stream(schema)
+    }
+
+    #[inline]
+    fn read_timestamp(self, schema: &Schema) -> Result<Instant, Self::Error> {
+        self.inner.read_timestamp(schema)
+    }
+
+    #[inline]
+    fn read_document(self, schema: &Schema) -> Result<Box<dyn Document>, Self::Error> {
+        self.inner.read_document(schema)
+    }
+
+    #[inline]
+    fn read_struct(self, schema: &Schema) -> Result<Self::StructReader, Self::Error> {
+        Ok(ProjectedStructReader {
+            inner: self.inner.read_struct(schema)?,
+            projection: self.projection,
+        })
+    }
+
+    #[inline]
+    fn read_list(self, schema: &Schema) -> Result<Self::ListReader, Self::Error> {
+        self.inner.read_list(schema)
+    }
+
+    #[inline]
+    fn read_map(self, schema: &Schema) -> Result<Self::MapReader, Self::Error> {
+        self.inner.read_map(schema)
+    }
+
+    #[inline]
+    fn is_null(&mut self) -> bool {
+        self.inner.is_null()
+    }
+
+    #[inline]
+    fn read_null(self) -> Result<(), Self::Error> {
+        self.inner.read_null()
+    }
+}
+
+/// [`StructReader`] that skips any members outside of a [`Projection`].
+pub struct ProjectedStructReader<'p, R> {
+    inner: R,
+    projection: &'p Projection,
+}
+
+impl<'de, R: StructReader<'de>> StructReader<'de> for ProjectedStructReader<'_, R> {
+    type Error = R::Error;
+
+    fn read_member<'a>(&mut self, schema: &'a Schema) -> Result<Option<&'a Schema>, Self::Error> {
+        while let Some(member_schema) = self.inner.read_member(schema)? {
+            if self.projection.contains(member_schema) {
+                return Ok(Some(member_schema));
+            }
+            self.inner.skip_value()?;
+        }
+        Ok(None)
+    }
+
+    #[inline]
+    fn read_value<T: DeserializeWithSchema<'de>>(
+        &mut self,
+        schema: &Schema,
+    ) -> Result<T, Self::Error> {
+        self.inner.read_value(schema)
+    }
+
+    #[inline]
+    fn skip_value(&mut self) -> Result<(), Self::Error> {
+        self.inner.skip_value()
+    }
+}
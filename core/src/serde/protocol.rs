@@ -0,0 +1,328 @@
+//! # Protocols
+//!
+//! A [`Protocol`] ties a wire format ([`Codec`]) to the other settings a client or
+//! server needs to exchange shapes with a peer, such as the media type of messages
+//! and the [`DefaultValidator`] used to check deserialized shapes.
+//!
+//! Protocols are identified by the [`ShapeId`] of their protocol trait
+//! (for example `smithy.protocols#rpcv2Cbor`), which allows a [`ProtocolRegistry`]
+//! to resolve the protocol to use for a service from the traits applied to it:
+//!
+//! ```rust,ignore
+//! let registry = ProtocolRegistry::new()
+//!     .with(RpcV2Cbor::new())
+//!     .with(RestJson1::new());
+//!
+//! let protocol = registry.negotiate(&MY_SERVICE_SCHEMA).expect("No supported protocol");
+//! let input: GetItemInput = protocol.codec().deserialize_shape(body)?;
+//! ```
+
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+use crate::{
+    FxIndexMap,
+    schema::{Document, Schema, ShapeId, StaticSchemaShape, TryFromDocument},
+    serde::{
+        CodecError,
+        builders::{Buildable, ShapeBuilder},
+        serializers::SerializeWithSchema,
+        validation::DefaultValidator,
+    },
+};
+
+// ============================================================================
+// Codec
+// ============================================================================
+
+/// Object-safe serialization format used by a [`Protocol`].
+///
+/// Codecs read and write [`Document`]'s so that they can be resolved at runtime
+/// (for example, from a [`ProtocolRegistry`]). Typed shapes are converted to and
+/// from documents with the `serialize_shape` and `deserialize_shape` methods on `dyn Codec`.
+pub trait Codec: Send + Sync {
+    /// Serialize a document into a new buffer, guided by `schema`.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the document could not be serialized.
+    #[allow(clippy::borrowed_box)]
+    fn serialize_document(
+        &self,
+        schema: &Schema,
+        document: &Box<dyn Document>,
+    ) -> Result<Vec<u8>, CodecError>;
+
+    /// Serialize a document into an existing buffer, guided by `schema`.
+    ///
+    /// The buffer is cleared before use, allowing buffers (i.e. from a
+    /// [`BufferPool`](crate::serde::pool::BufferPool)) to be reused across requests.
+    /// By default, this copies the output of [`Codec::serialize_document`]. Codecs
+    /// should override this to write to `buf` directly.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the document could not be serialized.
+    #[allow(clippy::borrowed_box)]
+    fn serialize_document_into(
+        &self,
+        schema: &Schema,
+        document: &Box<dyn Document>,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), CodecError> {
+        let bytes = self.serialize_document(schema, document)?;
+        buf.clear();
+        buf.extend_from_slice(&bytes);
+        Ok(())
+    }
+
+    /// Deserialize a document from `data`, guided by `schema`.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the data could not be deserialized.
+    fn deserialize_document(
+        &self,
+        schema: &Schema,
+        data: &[u8],
+    ) -> Result<Box<dyn Document>, CodecError>;
+}
+
+impl dyn Codec + '_ {
+    /// Serialize a shape with its pre-defined schema.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the shape could not be serialized.
+    pub fn serialize_shape<T>(&self, shape: T) -> Result<Vec<u8>, CodecError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema,
+    {
+        self.serialize_document(T::schema(), &shape.into())
+    }
+
+    /// Deserialize and build a shape with its pre-defined schema.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the data could not be deserialized or if
+    /// the built shape is invalid.
+    pub fn deserialize_shape<S, B>(&self, data: &[u8]) -> Result<S, CodecError>
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: TryFromDocument + for<'de> ShapeBuilder<'de, S>,
+    {
+        Ok(self
+            .deserialize_document(S::schema(), data)?
+            .deserialize_into::<S, B>()?)
+    }
+}
+
+// ============================================================================
+// Protocol
+// ============================================================================
+
+/// A protocol used to exchange shapes between clients and servers.
+pub trait Protocol: Send + Sync {
+    /// Get the [`ShapeId`] of the protocol trait that identifies this protocol.
+    fn id(&self) -> &ShapeId;
+
+    /// Get the media type of messages sent with this protocol (for example `application/json`).
+    fn content_type(&self) -> &str;
+
+    /// Get the codec used to serialize and deserialize message bodies.
+    fn codec(&self) -> &dyn Codec;
+
+    /// Create the validator used to check shapes deserialized with this protocol.
+    ///
+    /// Defaults to a [`DefaultValidator`] with default limits.
+    /// Server protocols may override this to return a validator created with
+    /// [`DefaultValidator::with_strict_enums`] to reject unknown enum values.
+    fn validator(&self) -> DefaultValidator {
+        DefaultValidator::new()
+    }
+}
+
+// ============================================================================
+// Registry
+// ============================================================================
+
+/// Registry of supported [`Protocol`]'s, keyed by protocol [`ShapeId`].
+///
+/// Protocols are kept in registration order, which is used as the order of
+/// preference when negotiating a protocol for a service.
+#[derive(Default, Clone)]
+pub struct ProtocolRegistry {
+    protocols: FxIndexMap<ShapeId, Arc<dyn Protocol>>,
+}
+
+impl ProtocolRegistry {
+    /// Create a new, empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        ProtocolRegistry {
+            protocols: FxIndexMap::default(),
+        }
+    }
+
+    /// Register a protocol under its [`ShapeId`].
+    ///
+    /// Registering a protocol with the same ID more than once replaces the
+    /// previous entry while keeping its original position.
+    pub fn register(&mut self, protocol: impl Protocol + 'static) -> &mut Self {
+        self.protocols
+            .insert(protocol.id().clone(), Arc::new(protocol));
+        self
+    }
+
+    /// Builder-style version of [`ProtocolRegistry::register`].
+    #[must_use]
+    pub fn with(mut self, protocol: impl Protocol + 'static) -> Self {
+        self.register(protocol);
+        self
+    }
+
+    /// Returns `true` if a protocol is registered for the given [`ShapeId`].
+    #[must_use]
+    pub fn contains(&self, id: &ShapeId) -> bool {
+        self.protocols.contains_key(id)
+    }
+
+    /// Get the protocol registered for the given [`ShapeId`].
+    #[must_use]
+    pub fn get(&self, id: &ShapeId) -> Option<&dyn Protocol> {
+        self.protocols.get(id).map(|protocol| &**protocol)
+    }
+
+    /// Get the first registered protocol with the given content type.
+    #[must_use]
+    pub fn for_content_type(&self, content_type: &str) -> Option<&dyn Protocol> {
+        self.protocols()
+            .find(|protocol| protocol.content_type() == content_type)
+    }
+
+    /// Get the most preferred registered protocol that is supported by a service.
+    ///
+    /// A service supports a protocol if the protocol trait is applied to the service schema.
+    #[must_use]
+    pub fn negotiate(&self, service: &Schema) -> Option<&dyn Protocol> {
+        self.protocols()
+            .find(|protocol| service.contains_trait(protocol.id()))
+    }
+
+    /// Iterate over registered protocols in order of preference.
+    pub fn protocols(&self) -> impl Iterator<Item = &dyn Protocol> {
+        self.protocols.values().map(|protocol| &**protocol)
+    }
+
+    /// Get the number of registered protocols.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.protocols.len()
+    }
+
+    /// Returns `true` if no protocols are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.protocols.is_empty()
+    }
+}
+
+impl Debug for ProtocolRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.protocols.keys()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{schema::DynamicTrait, smithy};
+
+    struct NoopCodec;
+    impl Codec for NoopCodec {
+        #[allow(clippy::borrowed_box)]
+        fn serialize_document(
+            &self,
+            _schema: &Schema,
+            _document: &Box<dyn Document>,
+        ) -> Result<Vec<u8>, CodecError> {
+            Err(CodecError::Unsupported("serialize".to_string()))
+        }
+
+        fn deserialize_document(
+            &self,
+            _schema: &Schema,
+            _data: &[u8],
+        ) -> Result<Box<dyn Document>, CodecError> {
+            Err(CodecError::Unsupported("deserialize".to_string()))
+        }
+    }
+
+    struct TestProtocol {
+        id: ShapeId,
+        content_type: &'static str,
+    }
+    impl TestProtocol {
+        fn new(id: &str, content_type: &'static str) -> Self {
+            TestProtocol {
+                id: ShapeId::from(id),
+                content_type,
+            }
+        }
+    }
+    impl Protocol for TestProtocol {
+        fn id(&self) -> &ShapeId {
+            &self.id
+        }
+
+        fn content_type(&self) -> &str {
+            self.content_type
+        }
+
+        fn codec(&self) -> &dyn Codec {
+            &NoopCodec
+        }
+    }
+
+    smithy!("com.example#JsonService": {
+        @DynamicTrait::from("com.example#json", true);
+        service JSON_SERVICE
+    });
+
+    fn registry() -> ProtocolRegistry {
+        ProtocolRegistry::new()
+            .with(TestProtocol::new("com.example#cbor", "application/cbor"))
+            .with(TestProtocol::new("com.example#json", "application/json"))
+    }
+
+    #[test]
+    fn resolves_protocols_by_id() {
+        let registry = registry();
+        assert_eq!(registry.len(), 2);
+        assert!(registry.contains(&ShapeId::from("com.example#json")));
+        let protocol = registry.get(&ShapeId::from("com.example#json")).unwrap();
+        assert_eq!(protocol.content_type(), "application/json");
+        assert!(registry.get(&ShapeId::from("com.example#xml")).is_none());
+    }
+
+    #[test]
+    fn resolves_protocols_by_content_type() {
+        let registry = registry();
+        let protocol = registry.for_content_type("application/cbor").unwrap();
+        assert_eq!(protocol.id(), &ShapeId::from("com.example#cbor"));
+    }
+
+    #[test]
+    fn negotiates_protocol_from_service_traits() {
+        let registry = registry();
+        let protocol = registry.negotiate(&JSON_SERVICE).unwrap();
+        assert_eq!(protocol.id(), &ShapeId::from("com.example#json"));
+    }
+
+    #[test]
+    fn re_registering_keeps_preference_order() {
+        let registry =
+            registry().with(TestProtocol::new("com.example#cbor", "application/cbor+v2"));
+        assert_eq!(registry.len(), 2);
+        let first = registry.protocols().next().unwrap();
+        assert_eq!(first.content_type(), "application/cbor+v2");
+    }
+}
@@ -0,0 +1,228 @@
+//! # Shape Registry
+//!
+//! Enables over-the-wire polymorphism by mapping a [`ShapeId`] discriminator
+//! to the builder used to deserialize the identified shape.
+//!
+//! For example, an operation that can return one of several errors might
+//! deserialize the error response into a [`Document`], and then use the
+//! document's discriminator to dispatch to the correct error builder:
+//!
+//! ```rust,ignore
+//! let registry = ShapeRegistry::new()
+//!     .with(GetItemError::NotFound)
+//!     .with(GetItemError::Throttled);
+//!
+//! let document = Box::<dyn Document>::deserialize(json_deserializer)?;
+//! let error: GetItemError = document.deserialize_dynamic(&registry)?;
+//! ```
+
+use std::fmt::{Debug, Formatter};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    schema::{Document, DocumentError, ShapeId, TryFromDocument},
+    serde::builders::{Buildable, ShapeBuilder},
+};
+
+type ShapeFactory<T> = Box<dyn Fn(Box<dyn Document>) -> Result<T, DocumentError> + Send + Sync>;
+
+/// Registry mapping shape IDs to the builders used to deserialize them.
+///
+/// All registered shapes are converted into a common output type `T`
+/// (for example, an enum of all errors an operation can return).
+pub struct ShapeRegistry<T> {
+    factories: FxHashMap<ShapeId, ShapeFactory<T>>,
+}
+
+impl<T> ShapeRegistry<T> {
+    /// Create a new, empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        ShapeRegistry {
+            factories: FxHashMap::default(),
+        }
+    }
+
+    /// Register the shape `S` (built by builder `B`), using `into` to convert
+    /// the built shape into the registry output type.
+    ///
+    /// The shape is registered under the [`ShapeId`] of its schema.
+    /// Registering the same shape more than once replaces the previous entry.
+    pub fn register<S, B>(&mut self, into: impl Fn(S) -> T + Send + Sync + 'static) -> &mut Self
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: TryFromDocument + for<'de> ShapeBuilder<'de, S>,
+    {
+        let id = S::schema().id().clone();
+        self.factories.insert(
+            id,
+            Box::new(move |document| document.deserialize_into::<S, B>().map(&into)),
+        );
+        self
+    }
+
+    /// Builder-style version of [`ShapeRegistry::register`].
+    #[must_use]
+    pub fn with<S, B>(mut self, into: impl Fn(S) -> T + Send + Sync + 'static) -> Self
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: TryFromDocument + for<'de> ShapeBuilder<'de, S>,
+    {
+        self.register(into);
+        self
+    }
+
+    /// Returns `true` if a shape is registered for the given [`ShapeId`].
+    #[must_use]
+    pub fn contains(&self, id: &ShapeId) -> bool {
+        self.factories.contains_key(id)
+    }
+
+    /// Get the ID of a registered shape by its name, ignoring the namespace.
+    ///
+    /// This supports protocols that identify shapes on the wire by name alone.
+    #[must_use]
+    pub fn find_by_name(&self, name: &str) -> Option<&ShapeId> {
+        self.factories.keys().find(|id| id.name() == name)
+    }
+
+    /// Deserialize a document into the shape registered for `id`.
+    ///
+    /// # Errors
+    /// Returns [`DocumentError::UnknownDiscriminator`] if no shape is registered
+    /// for `id`, or any error encountered while deserializing or building the shape.
+    pub fn deserialize(
+        &self,
+        id: &ShapeId,
+        document: Box<dyn Document>,
+    ) -> Result<T, DocumentError> {
+        let factory = self
+            .factories
+            .get(id)
+            .ok_or_else(|| DocumentError::UnknownDiscriminator(id.id().to_string()))?;
+        factory(document)
+    }
+}
+
+impl<T> Default for ShapeRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for ShapeRegistry<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.factories.keys()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{derive::SmithyShape, prelude::*, smithy};
+
+    smithy!("com.example#NotFound": {
+        @ErrorTrait::Client;
+        structure NOT_FOUND_SCHEMA {
+            @RequiredTrait::builder().build();
+            MESSAGE: STRING = "message"
+        }
+    });
+    smithy!("com.example#Throttled": {
+        @ErrorTrait::Server;
+        structure THROTTLED_SCHEMA {
+            RETRY_AFTER: INTEGER = "retryAfter"
+        }
+    });
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(NOT_FOUND_SCHEMA)]
+    pub struct NotFound {
+        #[smithy_schema(MESSAGE)]
+        message: String,
+    }
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(THROTTLED_SCHEMA)]
+    pub struct Throttled {
+        #[smithy_schema(RETRY_AFTER)]
+        retry_after: Option<i32>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum OperationError {
+        NotFound(NotFound),
+        Throttled(Throttled),
+    }
+
+    fn registry() -> ShapeRegistry<OperationError> {
+        ShapeRegistry::new()
+            .with(OperationError::NotFound)
+            .with(OperationError::Throttled)
+    }
+
+    #[test]
+    fn dispatches_on_discriminator() {
+        let registry = registry();
+        assert!(registry.contains(NOT_FOUND_SCHEMA.id()));
+        assert!(registry.contains(THROTTLED_SCHEMA.id()));
+
+        let not_found = NotFound {
+            message: "missing".to_string(),
+        };
+        let document: Box<dyn Document> = not_found.clone().into();
+        assert_eq!(
+            document.deserialize_dynamic(&registry).unwrap(),
+            OperationError::NotFound(not_found)
+        );
+
+        let throttled = Throttled {
+            retry_after: Some(5),
+        };
+        let document: Box<dyn Document> = throttled.clone().into();
+        assert_eq!(
+            document.deserialize_dynamic(&registry).unwrap(),
+            OperationError::Throttled(throttled)
+        );
+    }
+
+    #[test]
+    fn finds_shapes_by_name() {
+        let registry = registry();
+        assert_eq!(
+            registry.find_by_name("NotFound"),
+            Some(NOT_FOUND_SCHEMA.id())
+        );
+        assert!(registry.find_by_name("Missing").is_none());
+    }
+
+    #[test]
+    fn deserializes_into_concrete_shape() {
+        let not_found = NotFound {
+            message: "missing".to_string(),
+        };
+        let document: Box<dyn Document> = not_found.clone().into();
+        let result: NotFound = document.deserialize_into().unwrap();
+        assert_eq!(result, not_found);
+    }
+
+    #[test]
+    fn rejects_unknown_discriminator() {
+        let registry = ShapeRegistry::new().with(OperationError::NotFound);
+        let document: Box<dyn Document> = Throttled { retry_after: None }.into();
+        assert!(matches!(
+            document.deserialize_dynamic(&registry),
+            Err(DocumentError::UnknownDiscriminator(id)) if id == "com.example#Throttled"
+        ));
+    }
+
+    #[test]
+    fn rejects_missing_discriminator() {
+        let document: Box<dyn Document> = "not a shape".into();
+        assert!(matches!(
+            document.deserialize_dynamic(&registry()),
+            Err(DocumentError::MissingDiscriminator)
+        ));
+    }
+}
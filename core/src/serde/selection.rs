@@ -0,0 +1,686 @@
+//! Field selection during serialization
+//!
+//! A [`Selection`] describes a subset of the members of a shape, similar to
+//! a GraphQL selection set. When a value is serialized with a [`SelectedSerializer`],
+//! only the selected members of each structure are written. Because the selection is
+//! applied by wrapping a [`Serializer`], it can be used with any codec.
+//!
+//! Selections are parsed from a comma-separated list of member names. A member
+//! may be followed by a nested selection in braces to select members of the
+//! structure it targets. Members without a nested selection are written in full.
+//!
+//! Selections apply to each element of a list and each value of a map, so a
+//! selection on a list of structures selects members of every structure in the
+//! list. Documents are always written in full.
+//!
+//! ## Example
+//! ```rust,ignore
+//! let selection: Selection = "name,contact{email,address{city}}".parse()?;
+//! let mut ser = JsonSerializer::new(&mut output);
+//! user.serialize_with_schema(User::schema(), SelectedSerializer::new(&mut ser, &selection))?;
+//! ```
+//!
+use std::{iter::Peekable, str::CharIndices, str::FromStr};
+
+use thiserror::Error;
+
+use crate::{
+    BigDecimal, BigInt, FxIndexMap, Instant,
+    schema::{Blob, Document, Schema, ShapeId, StreamingBlob},
+    serde::{
+        eventstream::EventStream,
+        se::{ListWriter, MapWriter, SerializeWithSchema, Serializer, StructWriter},
+    },
+};
+
+// ============================================================================
+// Selection
+// ============================================================================
+
+/// Set of structure members to serialize, with nested selections for each member.
+///
+/// An empty selection selects all members.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Selection {
+    fields: FxIndexMap<String, Selection>,
+}
+
+impl Selection {
+    /// Create a new, empty selection that selects all members.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a member to the selection, selecting all of its value.
+    #[must_use]
+    pub fn field<S: Into<String>>(self, member_name: S) -> Self {
+        self.nested(member_name, Selection::new())
+    }
+
+    /// Add a member to the selection with a nested selection for its value.
+    #[must_use]
+    pub fn nested<S: Into<String>>(mut self, member_name: S, selection: Selection) -> Self {
+        self.fields.insert(member_name.into(), selection);
+        self
+    }
+
+    /// Returns `true` if the selection selects all members.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Get the nested selection for a member schema, or `None` if the member is not selected.
+    ///
+    /// Schemas that are not members are never selected, unless the selection is empty.
+    #[must_use]
+    pub fn get(&self, member_schema: &Schema) -> Option<&Selection> {
+        if self.is_empty() {
+            return Some(self);
+        }
+        member_schema
+            .as_member()
+            .and_then(|member| self.fields.get(member.name()))
+    }
+
+    /// Parse a selection from a string such as `name,contact{email,address{city}}`.
+    ///
+    /// # Errors
+    /// Returns a [`SelectionError`] if the selection is empty, malformed, or
+    /// contains the same member more than once.
+    pub fn parse(selection: &str) -> Result<Self, SelectionError> {
+        let mut parser = SelectionParser {
+            source: selection,
+            chars: selection.char_indices().peekable(),
+        };
+        let result = parser.selection()?;
+        match parser.next_token() {
+            None => Ok(result),
+            Some((offset, char)) => Err(SelectionError::new(
+                offset,
+                format!("Unexpected character `{char}`"),
+            )),
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = SelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Error raised when a [`Selection`] could not be parsed.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("Invalid selection at offset {offset}: {message}")]
+pub struct SelectionError {
+    /// Byte offset of the error in the selection
+    pub offset: usize,
+    /// Description of the error
+    pub message: String,
+}
+
+impl SelectionError {
+    fn new(offset: usize, message: impl Into<String>) -> Self {
+        Self {
+            offset,
+            message: message.into(),
+        }
+    }
+}
+
+/// Recursive descent parser for selection strings.
+struct SelectionParser<'a> {
+    source: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> SelectionParser<'a> {
+    /// Parse a comma-separated list of members and their nested selections.
+    fn selection(&mut self) -> Result<Selection, SelectionError> {
+        let mut fields = FxIndexMap::default();
+        loop {
+            let (offset, name) = self.name()?;
+            let nested = if self.peek_token() == Some('{') {
+                self.chars.next();
+                let nested = self.selection()?;
+                match self.next_token() {
+                    Some((_, '}')) => nested,
+                    Some((offset, char)) => {
+                        return Err(SelectionError::new(
+                            offset,
+                            format!("Expected `}}` but found `{char}`"),
+                        ));
+                    }
+                    None => return Err(SelectionError::new(self.source.len(), "Unclosed `{`")),
+                }
+            } else {
+                Selection::new()
+            };
+            if fields.insert(name.to_string(), nested).is_some() {
+                return Err(SelectionError::new(
+                    offset,
+                    format!("Duplicate member `{name}`"),
+                ));
+            }
+            if self.peek_token() != Some(',') {
+                return Ok(Selection { fields });
+            }
+            self.chars.next();
+        }
+    }
+
+    /// Parse a member name, returning its offset in the source.
+    fn name(&mut self) -> Result<(usize, &'a str), SelectionError> {
+        self.skip_whitespace();
+        let start = self
+            .chars
+            .peek()
+            .map_or(self.source.len(), |(offset, _)| *offset);
+        let mut end = start;
+        while let Some((offset, char)) = self.chars.peek() {
+            if !(char.is_alphanumeric() || *char == '_') {
+                break;
+            }
+            end = offset + char.len_utf8();
+            self.chars.next();
+        }
+        if start == end {
+            return Err(SelectionError::new(start, "Expected a member name"));
+        }
+        Ok((start, &self.source[start..end]))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .chars
+            .next_if(|(_, char)| char.is_whitespace())
+            .is_some()
+        {}
+    }
+
+    fn peek_token(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.peek().map(|(_, char)| *char)
+    }
+
+    fn next_token(&mut self) -> Option<(usize, char)> {
+        self.skip_whitespace();
+        self.chars.next()
+    }
+}
+
+/// Value that is serialized with a [`Selection`] applied.
+struct Selected<'a, T: ?Sized> {
+    value: &'a T,
+    selection: &'a Selection,
+}
+
+impl<T: SerializeWithSchema + ?Sized> SerializeWithSchema for Selected<'_, T> {
+    #[inline]
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if self.selection.is_empty() {
+            return self.value.serialize_with_schema(schema, serializer);
+        }
+        self.value
+            .serialize_with_schema(schema, SelectedSerializer::new(serializer, self.selection))
+    }
+}
+
+// ============================================================================
+// Selected Serializer
+// ============================================================================
+
+/// [`Serializer`] wrapper that only writes the structure members of a [`Selection`].
+///
+/// All other writes are delegated to the wrapped serializer.
+pub struct SelectedSerializer<'s, S> {
+    inner: S,
+    selection: &'s Selection,
+}
+
+impl<'s, S> SelectedSerializer<'s, S> {
+    /// Wrap a serializer with a selection.
+    pub const fn new(inner: S, selection: &'s Selection) -> Self {
+        Self { inner, selection }
+    }
+}
+
+impl<'s, S: Serializer> Serializer for SelectedSerializer<'s, S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+    type ListWriter = SelectedWriter<'s, S::ListWriter>;
+    type MapWriter = SelectedWriter<'s, S::MapWriter>;
+    type StructWriter = SelectedWriter<'s, S::StructWriter>;
+
+    #[inline]
+    fn write_struct(self, schema: &Schema, len: usize) -> Result<Self::StructWriter, Self::Error> {
+        Ok(SelectedWriter {
+            inner: self.inner.write_struct(schema, len)?,
+            selection: self.selection,
+        })
+    }
+
+    #[inline]
+    fn write_map(self, schema: &Schema, len: usize) -> Result<Self::MapWriter, Self::Error> {
+        Ok(SelectedWriter {
+            inner: self.inner.write_map(schema, len)?,
+            selection: self.selection,
+        })
+    }
+
+    #[inline]
+    fn write_list(self, schema: &Schema, len: usize) -> Result<Self::ListWriter, Self::Error> {
+        Ok(SelectedWriter {
+            inner: self.inner.write_list(schema, len)?,
+            selection: self.selection,
+        })
+    }
+
+    #[inline]
+    fn write_boolean(self, schema: &Schema, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_boolean(schema, value)
+    }
+
+    #[inline]
+    fn write_byte(self, schema: &Schema, value: i8) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_byte(schema, value)
+    }
+
+    #[inline]
+    fn write_short(self, schema: &Schema, value: i16) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_short(schema, value)
+    }
+
+    #[inline]
+    fn write_integer(self, schema: &Schema, value: i32) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_integer(schema, value)
+    }
+
+    #[inline]
+    fn write_long(self, schema: &Schema, value: i64) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_long(schema, value)
+    }
+
+    #[inline]
+    fn write_float(self, schema: &Schema, value: f32) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_float(schema, value)
+    }
+
+    #[inline]
+    fn write_double(self, schema: &Schema, value: f64) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_double(schema, value)
+    }
+
+    #[inline]
+    fn write_big_integer(self, schema: &Schema, value: &BigInt) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_big_integer(schema, value)
+    }
+
+    #[inline]
+    fn write_big_decimal(
+        self,
+        schema: &Schema,
+        value: &BigDecimal,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_big_decimal(schema, value)
+    }
+
+    #[inline]
+    fn write_string(self, schema: &Schema, value: &str) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_string(schema, value)
+    }
+
+    #[inline]
+    fn write_blob(self, schema: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_blob(schema, value)
+    }
+
+    #[inline]
+    fn write_streaming_blob(
+        self,
+        schema: &Schema,
+        value: &StreamingBlob,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_streaming_blob(schema, value)
+    }
+
+    #[inline]
+    fn write_event_stream<T>(
+        self,
+        schema: &Schema,
+        value: &EventStream<T>,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_event_stream(schema, value)
+    }
+
+    #[inline]
+    fn write_timestamp(self, schema: &Schema, value: &Instant) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_timestamp(schema, value)
+    }
+
+    #[inline]
+    fn write_document(
+        self,
+        schema: &Schema,
+        value: &Box<dyn Document>,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_document(schema, value)
+    }
+
+    #[inline]
+    fn write_null(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_null(schema)
+    }
+
+    #[inline]
+    fn write_missing(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.write_missing(schema)
+    }
+
+    #[inline]
+    fn skip(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.skip(schema)
+    }
+
+    #[inline]
+    fn flush(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.flush()
+    }
+}
+
+/// Struct, list, and map writer that applies a [`Selection`] to each value written.
+///
+/// Structure members outside the selection are not written.
+pub struct SelectedWriter<'s, W> {
+    inner: W,
+    selection: &'s Selection,
+}
+
+impl<W: StructWriter> StructWriter for SelectedWriter<'_, W> {
+    type Error = W::Error;
+    type Ok = W::Ok;
+
+    #[inline]
+    fn write_discriminator(&mut self, discriminator: &ShapeId) -> Result<(), Self::Error> {
+        self.inner.write_discriminator(discriminator)
+    }
+
+    #[inline]
+    fn write_member<T>(&mut self, member_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let Some(selection) = self.selection.get(member_schema) else {
+            return Ok(());
+        };
+        self.inner
+            .write_member(member_schema, &Selected { value, selection })
+    }
+
+    #[inline]
+    fn write_member_named<T>(
+        &mut self,
+        member_name: &str,
+        member_schema: &Schema,
+        value: &T,
+    ) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let Some(selection) = self.selection.get(member_schema) else {
+            return Ok(());
+        };
+        self.inner
+            .write_member_named(member_name, member_schema, &Selected { value, selection })
+    }
+
+    #[inline]
+    fn skip_member(&mut self, schema: &Schema) -> Result<(), Self::Error> {
+        self.inner.skip_member(schema)
+    }
+
+    #[inline]
+    fn write_unknown(&mut self, schema: &Schema, name: &String) -> Result<(), Self::Error> {
+        self.inner.write_unknown(schema, name)
+    }
+
+    #[inline]
+    fn write_unknown_member(
+        &mut self,
+        member_name: &str,
+        value: &Box<dyn Document>,
+    ) -> Result<(), Self::Error> {
+        self.inner.write_unknown_member(member_name, value)
+    }
+
+    #[inline]
+    fn end(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.end(schema)
+    }
+}
+
+impl<W: ListWriter> ListWriter for SelectedWriter<'_, W> {
+    type Error = W::Error;
+    type Ok = W::Ok;
+
+    #[inline]
+    fn write_element<T>(&mut self, element_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let selection = self.selection;
+        self.inner
+            .write_element(element_schema, &Selected { value, selection })
+    }
+
+    #[inline]
+    fn end(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.end(schema)
+    }
+}
+
+impl<W: MapWriter> MapWriter for SelectedWriter<'_, W> {
+    type Error = W::Error;
+    type Ok = W::Ok;
+
+    #[inline]
+    fn write_entry<K, V>(
+        &mut self,
+        key_schema: &Schema,
+        value_schema: &Schema,
+        key: &K,
+        value: &V,
+    ) -> Result<(), Self::Error>
+    where
+        K: SerializeWithSchema,
+        V: SerializeWithSchema,
+    {
+        let selection = self.selection;
+        self.inner.write_entry(
+            key_schema,
+            value_schema,
+            key,
+            &Selected { value, selection },
+        )
+    }
+
+    #[inline]
+    fn end(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.inner.end(schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{derive::SmithyShape, prelude::STRING, serde::documents::DocumentParser, smithy};
+
+    smithy!("com.example#Address": {
+        structure ADDRESS_SCHEMA {
+            CITY: STRING = "city"
+            STREET: STRING = "street"
+        }
+    });
+    smithy!("com.example#Contact": {
+        structure CONTACT_SCHEMA {
+            EMAIL: STRING = "email"
+            PHONE: STRING = "phone"
+            ADDRESS: ADDRESS_SCHEMA = "address"
+        }
+    });
+    smithy!("com.example#Contacts": {
+        list CONTACTS_SCHEMA {
+            member: CONTACT_SCHEMA
+        }
+    });
+    smithy!("com.example#User": {
+        structure USER_SCHEMA {
+            NAME: STRING = "name"
+            CONTACT: CONTACT_SCHEMA = "contact"
+            FRIENDS: CONTACTS_SCHEMA = "friends"
+        }
+    });
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(ADDRESS_SCHEMA)]
+    pub struct Address {
+        #[smithy_schema(CITY)]
+        city: String,
+        #[smithy_schema(STREET)]
+        street: String,
+    }
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(CONTACT_SCHEMA)]
+    pub struct Contact {
+        #[smithy_schema(EMAIL)]
+        email: String,
+        #[smithy_schema(PHONE)]
+        phone: Option<String>,
+        #[smithy_schema(ADDRESS)]
+        address: Address,
+    }
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(USER_SCHEMA)]
+    pub struct User {
+        #[smithy_schema(NAME)]
+        name: String,
+        #[smithy_schema(CONTACT)]
+        contact: Contact,
+        #[smithy_schema(FRIENDS)]
+        friends: Vec<Contact>,
+    }
+
+    fn contact(email: &str) -> Contact {
+        Contact {
+            email: email.to_string(),
+            phone: Some("555-0100".to_string()),
+            address: Address {
+                city: "Seattle".to_string(),
+                street: "Pike St".to_string(),
+            },
+        }
+    }
+
+    fn user() -> User {
+        User {
+            name: "Alice".to_string(),
+            contact: contact("alice@example.com"),
+            friends: vec![contact("bob@example.com")],
+        }
+    }
+
+    #[allow(clippy::borrowed_box)]
+    fn keys(document: &Box<dyn Document>) -> Vec<&str> {
+        document
+            .as_map()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect()
+    }
+
+    #[test]
+    fn parses_selection() {
+        let selection = Selection::parse(" name , contact { email, address{city} } ").unwrap();
+        assert_eq!(
+            selection,
+            Selection::new().field("name").nested(
+                "contact",
+                Selection::new()
+                    .field("email")
+                    .nested("address", Selection::new().field("city"))
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_selections() {
+        for (selection, offset) in [
+            ("", 0),
+            ("name,", 5),
+            ("contact{}", 8),
+            ("contact{email", 13),
+            ("name}", 4),
+            ("name,name", 5),
+            ("name email", 5),
+        ] {
+            let error = Selection::parse(selection).unwrap_err();
+            assert_eq!(error.offset, offset, "{selection}: {error}");
+        }
+    }
+
+    #[test]
+    fn serializes_selected_members() {
+        let selection: Selection = "contact{email,address{city}}".parse().unwrap();
+        let document = user()
+            .serialize_with_schema(
+                &USER_SCHEMA,
+                SelectedSerializer::new(DocumentParser, &selection),
+            )
+            .unwrap();
+        assert_eq!(keys(&document), vec!["contact"]);
+        let contact = document.as_map().unwrap().get("contact").unwrap();
+        assert_eq!(keys(contact), vec!["email", "address"]);
+        let address = contact.as_map().unwrap().get("address").unwrap();
+        assert_eq!(keys(address), vec!["city"]);
+    }
+
+    #[test]
+    fn serializes_unnested_members_in_full() {
+        let selection = Selection::new().field("name").field("contact");
+        let document = user()
+            .serialize_with_schema(
+                &USER_SCHEMA,
+                SelectedSerializer::new(DocumentParser, &selection),
+            )
+            .unwrap();
+        assert_eq!(keys(&document), vec!["name", "contact"]);
+        let contact = document.as_map().unwrap().get("contact").unwrap();
+        assert_eq!(keys(contact), vec!["email", "phone", "address"]);
+    }
+
+    #[test]
+    fn applies_selection_to_list_elements() {
+        let selection: Selection = "friends{email}".parse().unwrap();
+        let document = user()
+            .serialize_with_schema(
+                &USER_SCHEMA,
+                SelectedSerializer::new(DocumentParser, &selection),
+            )
+            .unwrap();
+        let friends = document.as_map().unwrap().get("friends").unwrap();
+        let friends = friends.as_list().unwrap();
+        assert_eq!(friends.len(), 1);
+        assert_eq!(keys(&friends[0]), vec!["email"]);
+    }
+}
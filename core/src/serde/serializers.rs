@@ -14,11 +14,13 @@
 //!
 //! This design (inspired by `serde`) separates iteration from value reading,
 //! allowing callers to control the serialization flow.
-use std::{error::Error as StdError, fmt::Display};
+use std::{borrow::Cow, error::Error as StdError, fmt::Display, sync::Arc};
 
 use crate::{
     BigDecimal, BigInt, ByteBuffer, IndexMap, Instant,
-    schema::{Document, Schema, SchemaShape, ShapeId},
+    prelude::DOCUMENT,
+    schema::{Blob, Document, Schema, SchemaShape, ShapeId, ShapeType, StreamingBlob},
+    serde::eventstream::EventStream,
 };
 
 // ============================================================================
@@ -305,6 +307,23 @@ pub trait StructWriter {
         )))
     }
 
+    /// Serialize a member that is not in the schema, such as the unknown variant of a union.
+    ///
+    /// By default, the value is written as a document with the provided member name.
+    ///
+    /// # Errors
+    /// Returns an [`Error`] matching the parent serializer if
+    /// the member could not be serialized.
+    #[inline]
+    #[allow(clippy::borrowed_box)]
+    fn write_unknown_member(
+        &mut self,
+        member_name: &str,
+        value: &Box<dyn Document>,
+    ) -> Result<(), Self::Error> {
+        self.write_member_named(member_name, &DOCUMENT, value)
+    }
+
     /// Finish serializing a structure
     ///
     /// # Errors
@@ -322,6 +341,10 @@ pub trait StructWriter {
 ///
 /// The serializer is stateful and methods take `self`. Implementations should,
 /// consider implement `Serializer` for `&mut` variants.
+///
+/// Methods for types that not every format can represent (i.e. `bigDecimal` or
+/// `timestamp`) return an error by default, so codecs only need to implement the
+/// types they support.
 pub trait Serializer: Sized {
     /// Error type emitted on failed serialization.
     ///
@@ -385,13 +408,21 @@ pub trait Serializer: Sized {
     ///
     /// # Errors
     /// `Self::Error` if the value could not be serialized as a `byte`.
-    fn write_byte(self, schema: &Schema, value: i8) -> Result<Self::Ok, Self::Error>;
+    fn write_byte(self, _schema: &Schema, _value: i8) -> Result<Self::Ok, Self::Error> {
+        Err(Error::custom(
+            "write_byte is not supported by this serializer",
+        ))
+    }
 
     /// Serialize a short (`i16`)
     ///
     /// # Errors
     /// `Self::Error` if the value could not be serialized as a `short`.
-    fn write_short(self, schema: &Schema, value: i16) -> Result<Self::Ok, Self::Error>;
+    fn write_short(self, _schema: &Schema, _value: i16) -> Result<Self::Ok, Self::Error> {
+        Err(Error::custom(
+            "write_short is not supported by this serializer",
+        ))
+    }
 
     /// Serialize an integer (`i32`)
     ///
@@ -409,7 +440,11 @@ pub trait Serializer: Sized {
     ///
     /// # Errors
     /// `Self::Error` if the value could not be serialized as a `float`.
-    fn write_float(self, schema: &Schema, value: f32) -> Result<Self::Ok, Self::Error>;
+    fn write_float(self, _schema: &Schema, _value: f32) -> Result<Self::Ok, Self::Error> {
+        Err(Error::custom(
+            "write_float is not supported by this serializer",
+        ))
+    }
 
     /// Serialize a double (`f64`)
     ///
@@ -421,7 +456,11 @@ pub trait Serializer: Sized {
     ///
     /// # Errors
     /// `Self::Error` if the value could not be serialized as a `bigInteger`.
-    fn write_big_integer(self, schema: &Schema, value: &BigInt) -> Result<Self::Ok, Self::Error>;
+    fn write_big_integer(self, _schema: &Schema, _value: &BigInt) -> Result<Self::Ok, Self::Error> {
+        Err(Error::custom(
+            "write_big_integer is not supported by this serializer",
+        ))
+    }
 
     /// Serialize a [`BigDecimal`]
     ///
@@ -429,9 +468,13 @@ pub trait Serializer: Sized {
     /// `Self::Error` if the value could not be serialized as a `bigDecimal`.
     fn write_big_decimal(
         self,
-        schema: &Schema,
-        value: &BigDecimal,
-    ) -> Result<Self::Ok, Self::Error>;
+        _schema: &Schema,
+        _value: &BigDecimal,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(Error::custom(
+            "write_big_decimal is not supported by this serializer",
+        ))
+    }
 
     /// Serialize a string (`&str`)
     ///
@@ -439,17 +482,64 @@ pub trait Serializer: Sized {
     /// `Self::Error` if the value could not be serialized as a `string`.
     fn write_string(self, schema: &Schema, value: &str) -> Result<Self::Ok, Self::Error>;
 
-    /// Serialize a blob (i.e. a buffer)
+    /// Serialize a [`Blob`]
     ///
     /// # Errors
     /// `Self::Error` if the value could not be serialized as a `blob`.
-    fn write_blob(self, schema: &Schema, value: &ByteBuffer) -> Result<Self::Ok, Self::Error>;
+    fn write_blob(self, _schema: &Schema, _value: &Blob) -> Result<Self::Ok, Self::Error> {
+        Err(Error::custom(
+            "write_blob is not supported by this serializer",
+        ))
+    }
+
+    /// Serialize a [`@streaming`](https://smithy.io/2.0/spec/streaming.html#streaming-trait) blob.
+    ///
+    /// Serializers that can stream data should override this method and take the
+    /// stream using [`StreamingBlob::take_stream`]. By default, in-memory data is
+    /// serialized as a regular `blob` and streams are not supported.
+    ///
+    /// # Errors
+    /// `Self::Error` if the value could not be serialized as a streaming `blob`.
+    fn write_streaming_blob(
+        self,
+        schema: &Schema,
+        value: &StreamingBlob,
+    ) -> Result<Self::Ok, Self::Error> {
+        let Some(bytes) = value.as_bytes() else {
+            return Err(Error::custom(
+                "Streaming blobs are not supported by this serializer",
+            ));
+        };
+        self.write_blob(schema, &Blob::from(bytes))
+    }
+
+    /// Serialize a member targeting a [`@streaming`](https://smithy.io/2.0/spec/streaming.html#event-streams) union.
+    ///
+    /// Event streams are not part of the message body. Protocols that support
+    /// event streams should override this method and send the events as separate
+    /// messages (see [`eventstream`](crate::serde::eventstream)). By default, event streams are not supported.
+    ///
+    /// # Errors
+    /// `Self::Error` if the value could not be serialized as an event stream.
+    fn write_event_stream<T>(
+        self,
+        _schema: &Schema,
+        _value: &EventStream<T>,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(Error::custom(
+            "Event streams are not supported by this serializer",
+        ))
+    }
 
     /// Serialize a timestamp
     ///
     /// # Errors
     /// `Self::Error` if the value could not be serialized as a `timestamp`.
-    fn write_timestamp(self, schema: &Schema, value: &Instant) -> Result<Self::Ok, Self::Error>;
+    fn write_timestamp(self, _schema: &Schema, _value: &Instant) -> Result<Self::Ok, Self::Error> {
+        Err(Error::custom(
+            "write_timestamp is not supported by this serializer",
+        ))
+    }
 
     /// Serialize an untyped [`Document`]
     ///
@@ -461,8 +551,15 @@ pub trait Serializer: Sized {
         schema: &Schema,
         value: &Box<dyn Document>,
     ) -> Result<Self::Ok, Self::Error> {
-        // Delegate to the document itself for serialization
-        value.serialize_with_schema(schema, self)
+        if value.get_type().is_none() {
+            return self.write_null(schema);
+        }
+        // Delegate to the document itself for serialization, using its own schema
+        // as a document-typed schema would write the document again.
+        if value.schema().shape_type() == &ShapeType::Document {
+            return Err(Self::Error::custom("Document value has no concrete schema"));
+        }
+        value.serialize_with_schema(value.schema(), self)
     }
 
     /// Serialize a `null` value
@@ -647,7 +744,7 @@ impl SerializeWithSchema for ByteBuffer {
         schema: &Schema,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        serializer.write_blob(schema, self)
+        serializer.write_blob(schema, &Blob::copy_from_slice(self.as_bytes()))
     }
 }
 
@@ -673,6 +770,17 @@ impl SerializeWithSchema for String {
     }
 }
 
+impl SerializeWithSchema for Cow<'_, str> {
+    #[inline]
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.write_string(schema, self)
+    }
+}
+
 // === Wrapper-type implementations ===
 
 impl<T: SerializeWithSchema> SerializeWithSchema for Option<T> {
@@ -682,10 +790,12 @@ impl<T: SerializeWithSchema> SerializeWithSchema for Option<T> {
         schema: &Schema,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
+        // `None` values can only be written as elements or values of `@sparse` collections,
+        // as optional structure members are skipped by `StructWriter::write_optional_member`.
         if let Some(value) = self.as_ref() {
             value.serialize_with_schema(schema, serializer)
         } else {
-            serializer.skip(schema)
+            serializer.write_null(schema)
         }
     }
 }
@@ -700,3 +810,36 @@ impl<T: SerializeWithSchema> SerializeWithSchema for Box<T> {
         (**self).serialize_with_schema(schema, serializer)
     }
 }
+
+impl<T: SerializeWithSchema + ?Sized> SerializeWithSchema for &T {
+    #[inline]
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        (**self).serialize_with_schema(schema, serializer)
+    }
+}
+
+impl<T: SerializeWithSchema + ?Sized> SerializeWithSchema for Arc<T> {
+    #[inline]
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        (**self).serialize_with_schema(schema, serializer)
+    }
+}
+
+impl<T: SerializeWithSchema + Clone> SerializeWithSchema for Cow<'_, T> {
+    #[inline]
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        (**self).serialize_with_schema(schema, serializer)
+    }
+}
@@ -0,0 +1,123 @@
+//! # Strict Members
+//!
+//! Deserializers skip members that are not in a structure's schema by default, so
+//! that older readers accept data written against newer models. Internal APIs that
+//! want typos and stale fields caught early can instead opt in to strict mode,
+//! either for all shapes read by a deserializer or per shape with the
+//! [`StrictMembersTrait`]. Strict reads fail with an
+//! [`Error::unknown_members`] error that lists every unknown member of the struct.
+
+use crate::{
+    derive::{SmithyShape, SmithyTraitImpl},
+    schema::Schema,
+    serde::de::Error,
+    smithy,
+};
+
+smithy!("smithy4rs.api#strictMembers": {
+    /// Schema for [`StrictMembersTrait`]
+    structure STRICT_MEMBERS_SCHEMA {
+    }
+});
+
+/// Marks a structure or union as strict.
+///
+/// Deserializers raise an [`Error::unknown_members`] error for input that contains
+/// members that are not in the schema of a strict shape, rather than skipping them.
+#[derive(SmithyShape, SmithyTraitImpl, PartialEq, Clone)]
+#[smithy_schema(STRICT_MEMBERS_SCHEMA)]
+pub struct StrictMembersTrait {}
+
+/// Collects the names of unknown members of a struct in strict mode.
+///
+/// A struct is read in strict mode if the deserializer is configured to reject
+/// all unknown members, or if its schema has the [`StrictMembersTrait`]. Readers
+/// record each unknown member they skip and call [`UnknownMembers::finish`] once
+/// the struct has been read:
+///
+/// ```ignore
+/// let mut unknown = UnknownMembers::new(schema, self.strict);
+/// while let Some(key) = next_key()? {
+///     if let Some(member) = schema.get_member(key) {
+///         return Ok(Some(member));
+///     }
+///     unknown.insert(key);
+///     skip_value()?;
+/// }
+/// unknown.finish(schema)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UnknownMembers {
+    strict: bool,
+    names: Vec<String>,
+}
+
+impl UnknownMembers {
+    /// Create a new collector for a struct with `schema`.
+    #[must_use]
+    pub fn new(schema: &Schema, strict: bool) -> Self {
+        UnknownMembers {
+            strict: strict || schema.contains_type::<StrictMembersTrait>(),
+            names: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if unknown members are rejected.
+    #[must_use]
+    pub const fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Record an unknown member.
+    ///
+    /// Names are only retained in strict mode.
+    pub fn insert(&mut self, name: &str) {
+        if self.strict {
+            self.names.push(name.to_string());
+        }
+    }
+
+    /// Finish reading a struct.
+    ///
+    /// # Errors
+    /// Returns [`Error::unknown_members`] with all recorded names, if any unknown
+    /// members were found in strict mode.
+    pub fn finish<E: Error>(&mut self, schema: &Schema) -> Result<(), E> {
+        if self.names.is_empty() {
+            return Ok(());
+        }
+        Err(E::unknown_members(schema, std::mem::take(&mut self.names)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::STRING, schema::ShapeId, serde::CodecError, traits};
+
+    #[test]
+    fn unknown_members_only_rejected_in_strict_mode() {
+        let lenient = Schema::structure_builder(ShapeId::from("com.example#Lenient"), traits![])
+            .put_member("a", &STRING, traits![])
+            .build();
+        let mut unknown = UnknownMembers::new(&lenient, false);
+        unknown.insert("b");
+        assert!(unknown.finish::<CodecError>(&lenient).is_ok());
+
+        let mut unknown = UnknownMembers::new(&lenient, true);
+        unknown.insert("b");
+        unknown.insert("c");
+        assert!(matches!(
+            unknown.finish::<CodecError>(&lenient),
+            Err(CodecError::UnknownMembers { shape, members })
+                if shape == "com.example#Lenient" && members == ["b", "c"]
+        ));
+
+        let strict = Schema::structure_builder(
+            ShapeId::from("com.example#Strict"),
+            traits![StrictMembersTrait::builder().build()],
+        )
+        .build();
+        assert!(UnknownMembers::new(&strict, false).is_strict());
+    }
+}
@@ -0,0 +1,147 @@
+//! # String Policies
+//!
+//! Controls how strictly codecs validate string values read from untrusted input.
+//!
+//! By default, codecs reject strings that are not valid UTF-8 and accept any
+//! embedded characters. A [`StringPolicy`] can relax UTF-8 handling to replace
+//! invalid sequences with `U+FFFD REPLACEMENT CHARACTER`, or tighten it to reject
+//! strings with embedded NUL characters (for example, when values are passed on to
+//! C FFI layers that treat NUL as a terminator):
+//!
+//! ```rust
+//! use smithy4rs_core::serde::strings::{InvalidUtf8, StringPolicy};
+//!
+//! let policy = StringPolicy::new()
+//!     .invalid_utf8(InvalidUtf8::Replace)
+//!     .reject_nul(true);
+//! assert_eq!(policy.decode(b"a\xFFb").unwrap(), "a\u{FFFD}b");
+//! assert!(policy.decode(b"a\0b").is_err());
+//! ```
+
+use std::borrow::Cow;
+
+use thiserror::Error;
+
+/// Handling of byte sequences that are not valid UTF-8.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum InvalidUtf8 {
+    /// Reject the string with an error.
+    #[default]
+    Reject,
+    /// Replace invalid sequences with `U+FFFD REPLACEMENT CHARACTER`.
+    Replace,
+}
+
+/// Strictness settings applied by codecs when reading string values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StringPolicy {
+    invalid_utf8: InvalidUtf8,
+    reject_nul: bool,
+}
+
+impl StringPolicy {
+    /// Create the default policy, which rejects invalid UTF-8 and allows NUL characters.
+    #[must_use]
+    pub const fn new() -> Self {
+        StringPolicy {
+            invalid_utf8: InvalidUtf8::Reject,
+            reject_nul: false,
+        }
+    }
+
+    /// Set how byte sequences that are not valid UTF-8 are handled.
+    #[must_use]
+    pub const fn invalid_utf8(mut self, handling: InvalidUtf8) -> Self {
+        self.invalid_utf8 = handling;
+        self
+    }
+
+    /// Set whether strings containing NUL (`U+0000`) characters are rejected.
+    #[must_use]
+    pub const fn reject_nul(mut self, reject: bool) -> Self {
+        self.reject_nul = reject;
+        self
+    }
+
+    /// Decode raw bytes into a string according to this policy.
+    ///
+    /// Valid input is borrowed without copying.
+    ///
+    /// # Errors
+    /// Returns [`StringError::InvalidUtf8`] if the bytes are not valid UTF-8 and the
+    /// policy rejects invalid UTF-8, or [`StringError::Nul`] if the decoded string
+    /// contains a NUL character and the policy rejects them.
+    pub fn decode<'a>(&self, bytes: &'a [u8]) -> Result<Cow<'a, str>, StringError> {
+        let value = match self.invalid_utf8 {
+            InvalidUtf8::Reject => Cow::Borrowed(
+                std::str::from_utf8(bytes)
+                    .map_err(|e| StringError::InvalidUtf8(e.valid_up_to()))?,
+            ),
+            InvalidUtf8::Replace => String::from_utf8_lossy(bytes),
+        };
+        self.check(&value)?;
+        Ok(value)
+    }
+
+    /// Check that an already-decoded string satisfies this policy.
+    ///
+    /// # Errors
+    /// Returns [`StringError::Nul`] if the string contains a NUL character and the
+    /// policy rejects them.
+    pub fn check(&self, value: &str) -> Result<(), StringError> {
+        if self.reject_nul
+            && let Some(position) = value.find('\0')
+        {
+            return Err(StringError::Nul(position));
+        }
+        Ok(())
+    }
+}
+
+/// Errors raised when a string does not satisfy a [`StringPolicy`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StringError {
+    /// String is not valid UTF-8. Contains the offset of the first invalid byte.
+    #[error("Invalid UTF-8 at byte {0}")]
+    InvalidUtf8(usize),
+    /// String contains a NUL character. Contains the byte offset of the character.
+    #[error("Embedded NUL character at byte {0}")]
+    Nul(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_policy_borrows_valid_strings() {
+        let policy = StringPolicy::default();
+        assert!(matches!(
+            policy.decode(b"hello"),
+            Ok(Cow::Borrowed("hello"))
+        ));
+        assert_eq!(policy.decode(b"a\0b").unwrap(), "a\0b");
+    }
+
+    #[test]
+    fn rejects_invalid_utf8() {
+        let policy = StringPolicy::new();
+        assert_eq!(policy.decode(b"ab\xFF"), Err(StringError::InvalidUtf8(2)));
+    }
+
+    #[test]
+    fn replaces_invalid_utf8() {
+        let policy = StringPolicy::new().invalid_utf8(InvalidUtf8::Replace);
+        assert_eq!(policy.decode(b"a\xFF\xFEb").unwrap(), "a\u{FFFD}\u{FFFD}b");
+        assert!(matches!(policy.decode(b"ok"), Ok(Cow::Borrowed("ok"))));
+    }
+
+    #[test]
+    fn rejects_nul() {
+        let policy = StringPolicy::new().reject_nul(true);
+        assert_eq!(policy.decode(b"ab\0"), Err(StringError::Nul(2)));
+        assert_eq!(policy.check("a\0"), Err(StringError::Nul(1)));
+        assert!(policy.check("abc").is_ok());
+    }
+}
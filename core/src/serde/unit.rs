@@ -1,6 +1,8 @@
 use crate::{
     schema::{Schema, Unit},
     serde::{
+        builders::{Buildable, ShapeBuilder},
+        correction::ErrorCorrection,
         de::{DeserializeWithSchema, Deserializer, Error, StructReader},
         se::{SerializeWithSchema, Serializer, StructWriter},
     },
@@ -36,3 +38,23 @@ impl<'de> DeserializeWithSchema<'de> for Unit {
         Ok(Unit)
     }
 }
+
+// Unit has no members to accumulate, so it acts as its own builder. This allows
+// operations with no input or output to be deserialized like any other shape.
+impl ErrorCorrection for Unit {
+    type Value = Unit;
+
+    #[inline]
+    fn correct(self) -> Self::Value {
+        self
+    }
+}
+
+impl ShapeBuilder<'_, Unit> for Unit {
+    #[inline]
+    fn new() -> Self {
+        Unit
+    }
+}
+
+impl<'de> Buildable<'de, Unit> for Unit {}
@@ -5,12 +5,11 @@
 use std::marker::PhantomData;
 
 use bigdecimal::BigDecimal;
-use bytebuffer::ByteBuffer;
 use num_bigint::BigInt;
 use temporal_rs::Instant;
 
 use crate::{
-    schema::{Document, Schema},
+    schema::{Blob, Document, Schema},
     serde::se::{Error, ListWriter, MapWriter, SerializeWithSchema, Serializer, StructWriter},
 };
 
@@ -104,7 +103,7 @@ impl<E: Error> Serializer for &mut KeySerializer<E> {
     }
 
     #[cold]
-    fn write_blob(self, schema: &Schema, _value: &ByteBuffer) -> Result<Self::Ok, Self::Error> {
+    fn write_blob(self, schema: &Schema, _value: &Blob) -> Result<Self::Ok, Self::Error> {
         Err(invalid_key_error(schema))
     }
 
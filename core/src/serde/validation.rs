@@ -34,6 +34,11 @@
 //! In addition to checking these constraint traits, the default validator also checks that the type in
 //! the schema is compatible with the data type present in the shape.
 //!
+//! Smithy enums are open, so by default values that are not a known variant of an `enum` or `intEnum`
+//! (i.e. values deserialized into an `Unknown` variant) are accepted. Servers that need to reject
+//! unknown values can opt in with [`DefaultValidator::with_strict_enums`], or mark individual enums
+//! as closed with the `@smithy4rs.api#knownVariants` trait ([`KnownVariantsTrait`]).
+//!
 //! For more info on built-in Smithy constraints see: [Smithy Documentation](<https://smithy.io/2.0/spec/constraint-traits.html>)
 //!
 //! ### Custom Constraints
@@ -51,37 +56,34 @@
 //! Some protocols may have additional constraints that they need to check in addition to the basic
 //! Smithy constraints.
 //!
-//! To support protocol-specific validation, Protocol implementations provide a [`Validator`]
-//! implementation (defaulting to the [`DefaultValidator`]) that is used to validate all shapes
-//! deserialized with that protocol.
+//! To support protocol-specific validation, [`Protocol`](crate::serde::Protocol) implementations
+//! provide a validator (see [`Protocol::validator`](crate::serde::Protocol::validator), defaulting
+//! to the [`DefaultValidator`]) that is used to validate all shapes deserialized with that protocol.
 //!
-use std::{
-    collections::BTreeSet,
-    convert::Into,
-    error::Error,
-    fmt::Display,
-    hash::{Hash, Hasher},
-};
+use std::{borrow::Cow, convert::Into, error::Error, fmt::Display};
 
 use bigdecimal::ToPrimitive;
-use bytebuffer::ByteBuffer;
 use num_bigint::BigInt;
-use rustc_hash::FxHasher;
+use rustc_hash::FxHashMap;
 use stack_array::{Array, ArrayBuf};
 use thiserror::Error;
 
 use crate::{
     BigDecimal, FxIndexSet, Instant,
+    derive::{SmithyShape, SmithyTraitImpl},
     schema::{
-        Document, Schema, ShapeType, TraitRef,
-        prelude::{LengthTrait, PatternTrait, RangeTrait, UniqueItemsTrait},
+        Blob, Document, MemberSchema, Schema, ShapeType, StaticSchemaShape, TraitRef,
+        prelude::{LengthTrait, PatternTrait, RangeTrait, STRING, SparseTrait, UniqueItemsTrait},
     },
     serde::{
+        CodecError,
+        deadline::{Deadline, DeadlineExceeded},
         se::{SerializeWithSchema, Serializer},
         serializers,
         serializers::{ListWriter, MapWriter, StructWriter},
         utils::KeySerializer,
     },
+    smithy,
 };
 
 // ============================================================================
@@ -112,7 +114,7 @@ use crate::{
 ///
 /// For example:
 /// ```rust,ignore
-/// let my_builder = MyShape::builder().a("string".to_string());
+/// let my_builder = MyShape::builder().a("string");
 /// let built = my_builder::build_with_validator(MyValidator)
 ///     .expect("shape invalid");
 /// ```
@@ -129,6 +131,24 @@ pub trait Validator: Serializer<Ok = (), Error = ValidationErrors> {
     ) -> Result<(), ValidationErrors>;
 }
 
+// ============================================================================
+// Known Variants Trait
+// ============================================================================
+
+smithy!("smithy4rs.api#knownVariants": {
+    /// Schema for [`KnownVariantsTrait`]
+    structure KNOWN_VARIANTS_SCHEMA {
+    }
+});
+
+/// Marks an `enum` or `intEnum` as closed.
+///
+/// The [`DefaultValidator`] rejects values of enums with this trait that are not
+/// one of the enum's known variants.
+#[derive(SmithyShape, SmithyTraitImpl, PartialEq, Clone)]
+#[smithy_schema(KNOWN_VARIANTS_SCHEMA)]
+pub struct KnownVariantsTrait {}
+
 // ============================================================================
 // Default Implementation
 // ============================================================================
@@ -157,9 +177,69 @@ pub trait Validator: Serializer<Ok = (), Error = ValidationErrors> {
 ///
 /// * `D` - Maximum validation depth (Default: 10).
 /// * `E` - Maximum number of errors the validator can track (Default: 20).
+///
+/// Limits can also be set at runtime (for example, from a configuration file)
+/// with [`DefaultValidator::with_config`]. Validators created with [`DefaultValidator::new`]
+/// track the validation path on the stack, while configured validators only fall back to
+/// a heap-allocated path if the configured depth exceeds `D`.
+///
+/// The number of elements tracked per `@uniqueItems` list can be bounded with
+/// [`DefaultValidator::with_max_tracked_unique_items`].
+///
+/// Unknown `enum` and `intEnum` values are only rejected for enums with the
+/// [`KnownVariantsTrait`] or if the validator is created with [`DefaultValidator::with_strict_enums`].
+///
+/// Validation of very large shapes can be bounded in time with [`DefaultValidator::with_deadline`].
 pub struct DefaultValidator<const D: usize = 10, const E: usize = 20> {
     errors: Option<ValidationErrors>,
-    path_stack: ArrayBuf<PathElement, D>,
+    path_stack: PathStack<D>,
+    max_depth: usize,
+    max_errors: usize,
+    max_collection_size: usize,
+    max_tracked_unique_items: usize,
+    /// Set while validating an element or value of a collection that is not `@sparse`
+    dense_element: bool,
+    /// Set while validating a schema with no constraints other than `@required`
+    unconstrained: bool,
+    /// Reject unknown values for all enums, not just those with the `KnownVariantsTrait`
+    strict_enums: bool,
+    /// Check string values against their `@pattern` trait
+    check_patterns: bool,
+    deadline: Deadline,
+}
+
+/// Runtime limits for a [`DefaultValidator`].
+///
+/// ```rust, ignore
+/// let config = ValidatorConfig {
+///     max_depth: 32,
+///     ..ValidatorConfig::default()
+/// };
+/// let result = DefaultValidator::with_config(config).validate(schema, &shape);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidatorConfig {
+    /// Maximum validation depth (Default: 10).
+    pub max_depth: usize,
+    /// Maximum number of errors the validator will collect (Default: 20).
+    pub max_errors: usize,
+    /// Maximum number of elements or entries in a single list or map (Default: unbounded).
+    pub max_collection_size: usize,
+    /// Check string values against their `@pattern` trait (Default: `true`).
+    ///
+    /// Skipping pattern checks can be useful for trusted (e.g. internal) callers,
+    /// as matching large strings against complex patterns can be expensive.
+    pub check_patterns: bool,
+}
+impl Default for ValidatorConfig {
+    fn default() -> Self {
+        ValidatorConfig {
+            max_depth: 10,
+            max_errors: 20,
+            max_collection_size: usize::MAX,
+            check_patterns: true,
+        }
+    }
 }
 
 impl<const D: usize, const ERR: usize> DefaultValidator<D, ERR> {
@@ -168,10 +248,83 @@ impl<const D: usize, const ERR: usize> DefaultValidator<D, ERR> {
     pub const fn new() -> Self {
         DefaultValidator {
             errors: None,
-            path_stack: ArrayBuf::new(),
+            path_stack: PathStack::Inline(ArrayBuf::new()),
+            max_depth: D,
+            max_errors: ERR,
+            max_collection_size: usize::MAX,
+            max_tracked_unique_items: usize::MAX,
+            dense_element: false,
+            unconstrained: false,
+            strict_enums: false,
+            check_patterns: true,
+            deadline: Deadline::none(),
+        }
+    }
+
+    /// Create a new [`Validator`] instance with limits set at runtime.
+    ///
+    /// The const generic limits are ignored in favor of the limits in `config`.
+    #[must_use]
+    pub fn with_config(config: ValidatorConfig) -> Self {
+        let path_stack = if config.max_depth <= D {
+            PathStack::Inline(ArrayBuf::new())
+        } else {
+            PathStack::Heap(Vec::with_capacity(config.max_depth))
+        };
+        DefaultValidator {
+            errors: None,
+            path_stack,
+            max_depth: config.max_depth,
+            max_errors: config.max_errors,
+            max_collection_size: config.max_collection_size,
+            max_tracked_unique_items: usize::MAX,
+            dense_element: false,
+            unconstrained: false,
+            strict_enums: false,
+            check_patterns: config.check_patterns,
+            deadline: Deadline::none(),
         }
     }
 
+    /// Bound the number of elements tracked when checking a `@uniqueItems` list.
+    ///
+    /// Once `max` elements of a list have been tracked, any further elements are
+    /// still compared against the tracked elements but are not tracked themselves.
+    /// This caps the memory used to validate very large lists at the cost of
+    /// missing duplicates that occur entirely past the limit.
+    ///
+    /// By default, all elements are tracked.
+    #[must_use]
+    pub const fn with_max_tracked_unique_items(mut self, max: usize) -> Self {
+        self.max_tracked_unique_items = max;
+        self
+    }
+
+    /// Reject `enum` and `intEnum` values that are not a known variant of the enum.
+    ///
+    /// By default, only enums with the [`KnownVariantsTrait`] are checked against their
+    /// known variants. Servers will typically want to enable this to reject unknown values.
+    #[must_use]
+    pub const fn with_strict_enums(mut self) -> Self {
+        self.strict_enums = true;
+        self
+    }
+
+    /// Abort validation once `deadline` expires.
+    ///
+    /// The deadline is checked as each member, element, and entry is validated.
+    /// An expired deadline short-circuits validation with a `deadlineExceeded` error.
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Deadline) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Returns `true` if values of the enum `target` must be one of its known variants.
+    fn checks_variants(&self, target: &Schema) -> bool {
+        self.strict_enums || target.contains_type::<KnownVariantsTrait>()
+    }
+
     /// Emit an error for accumulation
     ///
     /// This method _only_ returns an error response when the maximum number
@@ -181,14 +334,23 @@ impl<const D: usize, const ERR: usize> DefaultValidator<D, ERR> {
         let errors = self.errors.get_or_insert(ValidationErrors::new());
 
         // Short circuit if the maximum number of
-        if errors.len() >= ERR {
-            errors.add(&self.path_stack, ValidationFailure::MaxErrorsReached(ERR));
+        if errors.len() >= self.max_errors {
+            errors.add(
+                self.path_stack.as_slice(),
+                ValidationFailure::MaxErrorsReached(self.max_errors),
+            );
             return Err(self.errors.take().unwrap());
         }
-        errors.add(&self.path_stack, err);
+        errors.add(self.path_stack.as_slice(), err);
         Ok(())
     }
 
+    /// Number of validation errors collected so far.
+    #[inline]
+    fn error_count(&self) -> usize {
+        self.errors.as_ref().map_or(0, ValidationErrors::len)
+    }
+
     /// Return all collected validation errors
     ///
     /// This returns a `Result` type to allow `?` raising.
@@ -204,8 +366,11 @@ impl<const D: usize, const ERR: usize> DefaultValidator<D, ERR> {
 
     /// Add a path segment to the path stack
     fn push_path(&mut self, path: impl Into<PathElement>) -> Result<(), ValidationErrors> {
-        if self.path_stack.len() + 1 > D {
-            return self.short_circuit(ValidationFailure::MaximumDepthExceeded(D));
+        if self.path_stack.len() + 1 > self.max_depth {
+            return self.short_circuit(ValidationFailure::MaximumDepthExceeded(self.max_depth));
+        }
+        if let Err(e) = self.deadline.check() {
+            return self.short_circuit(ValidationFailure::DeadlineExceeded(e));
         }
         self.path_stack.push(path.into());
         Ok(())
@@ -221,17 +386,73 @@ impl<const D: usize, const ERR: usize> DefaultValidator<D, ERR> {
         Err(self.errors.take().unwrap())
     }
 
-    /// Pop a path segment from the path stack
+    /// Restore the path stack to the `depth` of an enclosing container,
+    /// discarding any path segments pushed since it was entered.
     ///
-    /// Returns an error if the call would pop from an empty stack.
-    fn pop_path(&mut self) -> Result<(), ValidationErrors> {
-        if self.path_stack.pop().is_none() {
+    /// Returns an error if segments owned by the enclosing container (or its parents)
+    /// have already been popped.
+    fn pop_path_to(&mut self, depth: usize) -> Result<(), ValidationErrors> {
+        if self.path_stack.len() < depth {
             return self.short_circuit(ValidationFailure::PopFromEmptyValidator);
         }
+        self.path_stack.truncate(depth);
+        Ok(())
+    }
+
+    /// Short circuit validation if a list or map exceeds the maximum collection size
+    fn check_collection_size(&mut self, len: usize) -> Result<(), ValidationErrors> {
+        if len > self.max_collection_size {
+            return self.short_circuit(ValidationFailure::MaxCollectionSizeExceeded(
+                len,
+                self.max_collection_size,
+            ));
+        }
         Ok(())
     }
 }
 
+/// Path of the value currently being validated.
+///
+/// Paths are kept on the stack unless a runtime-configured depth exceeds the
+/// const capacity of the validator.
+enum PathStack<const D: usize> {
+    Inline(ArrayBuf<PathElement, D>),
+    Heap(Vec<PathElement>),
+}
+impl<const D: usize> PathStack<D> {
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            PathStack::Inline(stack) => stack.len(),
+            PathStack::Heap(stack) => stack.len(),
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, element: PathElement) {
+        match self {
+            PathStack::Inline(stack) => stack.push(element),
+            PathStack::Heap(stack) => stack.push(element),
+        }
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        match self {
+            PathStack::Inline(stack) => stack.truncate(len),
+            PathStack::Heap(stack) => stack.truncate(len),
+        }
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[PathElement] {
+        match self {
+            PathStack::Inline(stack) => stack,
+            PathStack::Heap(stack) => stack,
+        }
+    }
+}
+
 impl Validator for &mut DefaultValidator {
     #[inline]
     fn validate<V: SerializeWithSchema>(
@@ -239,7 +460,14 @@ impl Validator for &mut DefaultValidator {
         schema: &Schema,
         value: &V,
     ) -> Result<(), ValidationErrors> {
-        value.serialize_with_schema(schema, &mut *self)?;
+        // Fast path: if nothing in the schema can fail other than `@required`,
+        // only check for missing members.
+        self.unconstrained = !schema.has_constraints();
+        // Discard any path left behind by a previous validation that returned early
+        self.path_stack.truncate(0);
+        let result = value.serialize_with_schema(schema, &mut *self);
+        self.unconstrained = false;
+        result?;
         self.results()
     }
 }
@@ -249,6 +477,13 @@ impl<const D: usize, const ERR: usize> Default for DefaultValidator<D, ERR> {
     }
 }
 
+macro_rules! skip_unconstrained {
+    ($self:ident) => {
+        if $self.unconstrained {
+            return Ok(());
+        }
+    };
+}
 macro_rules! shape_type {
     ($self:ident, $schema:ident, $ty:expr) => {
         if !$schema.shape_type().eq(&$ty) {
@@ -318,6 +553,7 @@ impl<'a> Serializer for &'a mut DefaultValidator {
     type StructWriter = DefaultStructValidator<'a>;
 
     fn write_struct(self, schema: &Schema, _len: usize) -> Result<Self::StructWriter, Self::Error> {
+        self.dense_element = false;
         if !schema.shape_type().eq(&ShapeType::Structure)
             && !schema.shape_type().eq(&ShapeType::Union)
         {
@@ -326,52 +562,76 @@ impl<'a> Serializer for &'a mut DefaultValidator {
                 ShapeType::Structure,
             ))?;
         }
-        Ok(DefaultStructValidator { root: self })
+        let depth = self.path_stack.len();
+        Ok(DefaultStructValidator { root: self, depth })
     }
 
     fn write_map(self, schema: &Schema, len: usize) -> Result<Self::MapWriter, Self::Error> {
-        shape_type!(self, schema, ShapeType::Map);
-        length!(self, schema, len);
-        Ok(DefaultMapValidator { root: self })
+        self.dense_element = false;
+        if !self.unconstrained {
+            shape_type!(self, schema, ShapeType::Map);
+            length!(self, schema, len);
+        }
+        self.check_collection_size(len)?;
+        let depth = self.path_stack.len();
+        Ok(DefaultMapValidator {
+            root: self,
+            depth,
+            sparse: schema.contains_type::<SparseTrait>(),
+        })
     }
 
     fn write_list(self, schema: &Schema, len: usize) -> Result<Self::ListWriter, Self::Error> {
-        shape_type!(self, schema, ShapeType::List);
-        length!(self, schema, len);
+        self.dense_element = false;
+        if !self.unconstrained {
+            shape_type!(self, schema, ShapeType::List);
+            length!(self, schema, len);
+        }
+        self.check_collection_size(len)?;
+        let lookup = UniquenessTracker::new(self.max_tracked_unique_items);
+        let unique = !self.unconstrained && schema.contains_type::<UniqueItemsTrait>();
+        let depth = self.path_stack.len();
         Ok(DefaultListValidator {
             root: self,
-            unique: schema.contains_type::<UniqueItemsTrait>(),
-            lookup: UniquenessTracker::new(),
+            depth,
+            sparse: schema.contains_type::<SparseTrait>(),
+            unique,
+            lookup,
             index: 0,
         })
     }
 
     fn write_boolean(self, schema: &Schema, _value: bool) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         shape_type!(self, schema, ShapeType::Boolean);
         Ok(())
     }
 
     fn write_byte(self, schema: &Schema, value: i8) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         shape_type!(self, schema, ShapeType::Byte);
         range!(self, schema, value, to_i8);
         Ok(())
     }
 
     fn write_short(self, schema: &Schema, value: i16) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         shape_type!(self, schema, ShapeType::Short);
         range!(self, schema, value, to_i16);
         Ok(())
     }
 
     fn write_integer(self, schema: &Schema, value: i32) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         // IntEnums are treated as Integers
         if schema.shape_type().eq(&ShapeType::Integer) {
             range!(self, schema, value, to_i32);
         } else if schema.shape_type().eq(&ShapeType::IntEnum) {
-            let Some(enum_schema) = schema.as_int_enum() else {
+            let target = schema.as_member().map_or(schema, MemberSchema::target);
+            let Some(enum_schema) = target.as_int_enum() else {
                 unreachable!("Only intEnum schemas can be constructed with an enum type");
             };
-            if !enum_schema.values().contains(&value) {
+            if self.checks_variants(target) && !enum_schema.values().contains(&value) {
                 self.emit_error(SmithyConstraints::IntEnumValue(
                     value,
                     enum_schema.values().clone(),
@@ -387,24 +647,28 @@ impl<'a> Serializer for &'a mut DefaultValidator {
     }
 
     fn write_long(self, schema: &Schema, value: i64) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         shape_type!(self, schema, ShapeType::Long);
         range!(self, schema, value, to_i64);
         Ok(())
     }
 
     fn write_float(self, schema: &Schema, value: f32) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         shape_type!(self, schema, ShapeType::Float);
         range!(self, schema, value, to_f32);
         Ok(())
     }
 
     fn write_double(self, schema: &Schema, value: f64) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         shape_type!(self, schema, ShapeType::Double);
         range!(self, schema, value, to_f64);
         Ok(())
     }
 
     fn write_big_integer(self, schema: &Schema, value: &BigInt) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         shape_type!(self, schema, ShapeType::BigInteger);
         if let Some(range) = schema.get_trait_as::<RangeTrait>() {
             let big_value: BigDecimal = BigDecimal::from_bigint(value.clone(), 0);
@@ -436,6 +700,7 @@ impl<'a> Serializer for &'a mut DefaultValidator {
         schema: &Schema,
         value: &BigDecimal,
     ) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         shape_type!(self, schema, ShapeType::BigDecimal);
         if let Some(range) = schema.get_trait_as::<RangeTrait>() {
             let mut over_min: bool = false;
@@ -462,45 +727,51 @@ impl<'a> Serializer for &'a mut DefaultValidator {
     }
 
     fn write_string(self, schema: &Schema, value: &str) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         // Enums are treated as strings for the purpose of validation
-        if schema.shape_type().eq(&ShapeType::String) {
-            let len = value.len();
-            length!(self, schema, len);
+        let shape_type = schema.shape_type();
+        if !matches!(shape_type, ShapeType::String | ShapeType::Enum) {
+            self.emit_error(SmithyConstraints::ShapeType(*shape_type, ShapeType::String))?;
+            return Ok(());
+        }
+        let len = value.len();
+        length!(self, schema, len);
 
-            // Check @pattern trait matches provided.
-            if let Some(pattern) = schema.get_trait_as::<PatternTrait>()
-                && pattern.0.find(value).is_none()
-            {
-                self.emit_error(SmithyConstraints::Pattern(
-                    value.to_string(),
-                    pattern.to_string(),
-                ))?;
-            }
-        } else if schema.shape_type().eq(&ShapeType::Enum) {
-            let Some(enum_schema) = schema.as_enum() else {
+        // Check @pattern trait matches provided.
+        if self.check_patterns
+            && let Some(pattern) = schema.get_trait_as::<PatternTrait>()
+            && pattern.0.find(value).is_none()
+        {
+            self.emit_error(SmithyConstraints::Pattern(
+                value.to_string(),
+                pattern.to_string(),
+            ))?;
+        }
+
+        if shape_type.eq(&ShapeType::Enum) {
+            // Enum members are validated against the values of their target
+            let target = schema.as_member().map_or(schema, MemberSchema::target);
+            let Some(enum_schema) = target.as_enum() else {
                 unreachable!("Only enum schemas can be constructed with an enum type");
             };
-            if !enum_schema.values().contains(value) {
+            if self.checks_variants(target) && !enum_schema.values().contains(value) {
                 self.emit_error(SmithyConstraints::EnumValue(
                     value.to_owned(),
                     enum_schema.values().clone(),
                 ))?;
             }
-        } else {
-            self.emit_error(SmithyConstraints::ShapeType(
-                *schema.shape_type(),
-                ShapeType::String,
-            ))?;
         }
         Ok(())
     }
 
-    fn write_blob(self, schema: &Schema, _value: &ByteBuffer) -> Result<Self::Ok, Self::Error> {
+    fn write_blob(self, schema: &Schema, _value: &Blob) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         shape_type!(self, schema, ShapeType::Blob);
         Ok(())
     }
 
     fn write_timestamp(self, schema: &Schema, _value: &Instant) -> Result<Self::Ok, Self::Error> {
+        skip_unconstrained!(self);
         shape_type!(self, schema, ShapeType::Timestamp);
         Ok(())
     }
@@ -519,8 +790,10 @@ impl<'a> Serializer for &'a mut DefaultValidator {
     }
 
     fn write_null(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
-        /* Skip null value validation */
-        // TODO(sparse lists): Decide how sparseness be handled in validator
+        // Only elements and values of `@sparse` collections may be null
+        if std::mem::take(&mut self.dense_element) {
+            self.emit_error(SmithyConstraints::Sparse)?;
+        }
         Ok(())
     }
 
@@ -539,6 +812,9 @@ impl<'a> Serializer for &'a mut DefaultValidator {
 #[doc(hidden)]
 pub struct DefaultListValidator<'a> {
     root: &'a mut DefaultValidator,
+    /// Depth of the path stack when the list was entered
+    depth: usize,
+    sparse: bool,
     unique: bool,
     lookup: UniquenessTracker,
     index: usize,
@@ -554,23 +830,24 @@ impl ListWriter for DefaultListValidator<'_> {
     {
         self.root.push_path(PathElement::Index(self.index))?;
         if self.unique {
-            match self.lookup.add(element_schema, value) {
-                Ok(true) => self.root.emit_error(SmithyConstraints::UniqueItems),
+            match self.lookup.add(element_schema, value, self.index) {
+                Ok(Some(first)) => self.root.emit_error(SmithyConstraints::UniqueItems(first)),
                 // Return early on this error. Something is wrong with the schema.
                 Err(err) => return self.root.short_circuit(err),
                 _ => Ok(()),
             }?;
         }
+        self.root.dense_element = !self.sparse;
         value.serialize_with_schema(element_schema, &mut *self.root)?;
-        self.root.pop_path()?;
+        self.root.dense_element = false;
+        self.root.pop_path_to(self.depth)?;
         self.index += 1;
         Ok(())
     }
 
     #[inline]
     fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
-        //self.root.pop_path()
-        Ok(())
+        self.root.pop_path_to(self.depth)
     }
 }
 
@@ -578,73 +855,223 @@ impl ListWriter for DefaultListValidator<'_> {
 // @Unique Support
 // ============================================================================
 
-/// Tracker for unique items using a hash lookup directly
+/// Tracker for unique items keyed by their canonical encoding.
+///
+/// Items are bucketed by the hash of their encoding and then compared byte-for-byte,
+/// so hash collisions can never cause distinct items to be reported as duplicates.
+/// Each tracked item maps to the index of the first element with that encoding,
+/// so duplicates can be reported against the element they repeat.
 struct UniquenessTracker {
-    // A b-tree is used here as it should be faster for
-    // search for a relatively small number of numeric
-    // values than a hashmap
-    lookup: BTreeSet<u64>,
+    lookup: FxHashMap<Box<[u8]>, usize>,
+    max_tracked: usize,
 }
 impl UniquenessTracker {
-    fn new() -> Self {
+    fn new(max_tracked: usize) -> Self {
         UniquenessTracker {
-            lookup: BTreeSet::new(),
+            lookup: FxHashMap::default(),
+            max_tracked,
         }
     }
 
-    /// Add an item to the set.
+    /// Add the item at `index` to the set.
     ///
-    /// Returns true if the item was already in the set.
+    /// Returns the index of the first matching item if the item was already in the set.
+    /// Items are not tracked once the maximum number of tracked items is reached.
     fn add<T: SerializeWithSchema>(
         &mut self,
         schema: &Schema,
         value: &T,
-    ) -> Result<bool, ValidationFailure> {
-        let mut serializer = HashingSerializer::new();
+        index: usize,
+    ) -> Result<Option<usize>, ValidationFailure> {
+        let mut serializer = CanonicalSerializer::new();
         value.serialize_with_schema(schema, &mut serializer)?;
-        Ok(!self.lookup.insert(serializer.result()))
+        let encoded = serializer.result();
+        if let Some(first) = self.lookup.get(encoded.as_slice()) {
+            return Ok(Some(*first));
+        }
+        if self.lookup.len() < self.max_tracked {
+            self.lookup.insert(encoded.into_boxed_slice(), index);
+        }
+        Ok(None)
     }
 }
 
-/// This type generates a unique hash for all items if possible.
+// Tags for each encoded value. Every encoding is prefix-free
+// (fixed width or length-prefixed), so concatenated values
+// can never be confused with one another.
+const TAG_BOOLEAN: u8 = 0x01;
+const TAG_BYTE: u8 = 0x02;
+const TAG_SHORT: u8 = 0x03;
+const TAG_INTEGER: u8 = 0x04;
+const TAG_LONG: u8 = 0x05;
+const TAG_BIG_INTEGER: u8 = 0x06;
+const TAG_BIG_DECIMAL: u8 = 0x07;
+const TAG_STRING: u8 = 0x08;
+const TAG_BLOB: u8 = 0x09;
+const TAG_TIMESTAMP: u8 = 0x0a;
+const TAG_NULL: u8 = 0x0b;
+const TAG_LIST: u8 = 0x10;
+const TAG_MAP: u8 = 0x11;
+const TAG_STRUCT: u8 = 0x12;
+const TAG_MEMBER: u8 = 0x13;
+const TAG_DOCUMENT: u8 = 0x14;
+const TAG_NUMBER: u8 = 0x15;
+const TAG_NON_FINITE: u8 = 0x16;
+const TAG_END: u8 = 0x1f;
+
+/// This type generates a canonical byte encoding for all items if possible.
+///
+/// Two items have the same encoding if and only if they are equal in the
+/// Smithy data model. In particular, map entries are encoded independently
+/// of their iteration order.
 ///
 /// Errors are raised if unhashable types (i.e. `f32` and `f64`) are
 /// checked for uniqueness. Such a check is considered invalid in the
 /// Smithy data model.
-struct HashingSerializer {
-    hasher: FxHasher,
+struct CanonicalSerializer {
+    buf: Vec<u8>,
 }
-impl HashingSerializer {
-    /// Create a new [`HashingSerializer`]
-    fn new() -> Self {
-        HashingSerializer {
-            hasher: FxHasher::default(),
-        }
+impl CanonicalSerializer {
+    /// Create a new [`CanonicalSerializer`]
+    const fn new() -> Self {
+        CanonicalSerializer { buf: Vec::new() }
+    }
+
+    /// Write a tag followed by fixed-width bytes.
+    #[inline]
+    fn write_tagged(&mut self, tag: u8, bytes: &[u8]) {
+        self.buf.push(tag);
+        self.buf.extend_from_slice(bytes);
     }
 
-    /// Utility function to compute `Hash` for types when possible.
+    /// Write a tag followed by length-prefixed bytes.
     #[inline]
-    fn hash<T: Hash>(&mut self, value: T) {
-        value.hash(&mut self.hasher);
+    fn write_prefixed(&mut self, tag: u8, bytes: &[u8]) {
+        self.buf.push(tag);
+        self.buf
+            .extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Get the final encoding
+    fn result(self) -> Vec<u8> {
+        self.buf
     }
 
-    /// Get the final hash result
-    fn result(self) -> u64 {
-        self.hasher.finish()
+    /// Write the encoding of a document value.
+    ///
+    /// Documents are encoded by value so that their encodings are equal if and
+    /// only if the documents are equal (see the `PartialEq` implementation for
+    /// `dyn Document`). In particular, numbers of any type are encoded as their
+    /// exact decimal value and map entries are encoded independently of their order.
+    fn write_document_value(&mut self, document: &dyn Document) -> Result<(), ValidationFailure> {
+        self.buf.push(TAG_DOCUMENT);
+        match document.discriminator() {
+            Some(id) => self.write_prefixed(TAG_STRING, id.id().as_bytes()),
+            None => self.buf.push(TAG_NULL),
+        }
+        let Some(shape_type) = document.get_type() else {
+            self.buf.push(TAG_NULL);
+            return Ok(());
+        };
+        if let Some(number) = document_number(document, shape_type) {
+            match number {
+                Ok(value) => {
+                    let (mantissa, exponent) = value.normalized().into_bigint_and_exponent();
+                    self.write_prefixed(TAG_NUMBER, &mantissa.to_signed_bytes_le());
+                    self.buf.extend_from_slice(&exponent.to_le_bytes());
+                }
+                Err(value) => self.write_tagged(TAG_NON_FINITE, &value.to_le_bytes()),
+            }
+            return Ok(());
+        }
+        // Non-numeric documents are only equal if they have the same schema
+        self.write_prefixed(TAG_STRING, document.schema().id().id().as_bytes());
+        match shape_type {
+            ShapeType::Boolean => {
+                self.write_tagged(TAG_BOOLEAN, &[u8::from(document.as_bool() == Some(true))]);
+            }
+            ShapeType::String | ShapeType::Enum => {
+                self.write_prefixed(
+                    TAG_STRING,
+                    document.as_string().unwrap_or_default().as_bytes(),
+                );
+            }
+            ShapeType::Blob => self.write_prefixed(
+                TAG_BLOB,
+                document.as_blob().map(Blob::as_bytes).unwrap_or_default(),
+            ),
+            ShapeType::Timestamp => {
+                let nanos = document
+                    .as_timestamp()
+                    .map_or(0, |value| value.epoch_nanoseconds().0);
+                self.write_tagged(TAG_TIMESTAMP, &nanos.to_le_bytes());
+            }
+            ShapeType::List => {
+                self.buf.push(TAG_LIST);
+                for element in document.as_list().into_iter().flatten() {
+                    self.write_document_value(element.as_ref())?;
+                }
+                self.buf.push(TAG_END);
+            }
+            ShapeType::Map | ShapeType::Structure | ShapeType::Union => {
+                let mut entries = Vec::with_capacity(document.size());
+                for (key, value) in document.as_map().into_iter().flatten() {
+                    let mut entry = CanonicalSerializer::new();
+                    entry.write_prefixed(TAG_STRING, key.as_bytes());
+                    entry.write_document_value(value.as_ref())?;
+                    entries.push(entry.result());
+                }
+                entries.sort_unstable();
+                self.buf.push(TAG_MAP);
+                for entry in &entries {
+                    self.buf.extend_from_slice(entry);
+                }
+                self.buf.push(TAG_END);
+            }
+            _ => {
+                return Err(ValidationFailure::Custom(format!(
+                    "Cannot check uniqueness of `{shape_type}` document"
+                )));
+            }
+        }
+        Ok(())
     }
 }
-macro_rules! hash_impl {
-    ($self:ident, $value:ident) => {
-        $self.hash($value);
-        return Ok(());
+
+/// Get the exact value of a numeric document.
+///
+/// Returns `None` if the document is not a number and `Some(Err(..))` with the
+/// raw bits of non-finite floating point values, which have no decimal value.
+fn document_number(
+    document: &dyn Document,
+    shape_type: &ShapeType,
+) -> Option<Result<BigDecimal, u64>> {
+    let value = match shape_type {
+        ShapeType::Byte
+        | ShapeType::Short
+        | ShapeType::Integer
+        | ShapeType::IntEnum
+        | ShapeType::Long => document.as_long().map(BigDecimal::from),
+        ShapeType::BigInteger => document
+            .as_big_integer()
+            .map(|value| BigDecimal::from(value.into_owned())),
+        ShapeType::BigDecimal => document.as_big_decimal().map(Cow::into_owned),
+        ShapeType::Float | ShapeType::Double => {
+            let value = document.as_double()?;
+            return Some(BigDecimal::try_from(value).map_err(|_| value.to_bits()));
+        }
+        _ => return None,
     };
+    value.map(Ok)
 }
-impl<'a> Serializer for &'a mut HashingSerializer {
+
+impl<'a> Serializer for &'a mut CanonicalSerializer {
     type Error = ValidationFailure;
     type Ok = ();
-    type ListWriter = InnerHasher<'a>;
-    type MapWriter = InnerHasher<'a>;
-    type StructWriter = InnerHasher<'a>;
+    type ListWriter = InnerEncoder<'a>;
+    type MapWriter = MapEncoder<'a>;
+    type StructWriter = InnerEncoder<'a>;
 
     #[inline]
     fn write_struct(
@@ -652,42 +1079,52 @@ impl<'a> Serializer for &'a mut HashingSerializer {
         _schema: &Schema,
         _len: usize,
     ) -> Result<Self::StructWriter, Self::Error> {
-        Ok(InnerHasher { root: self })
+        self.buf.push(TAG_STRUCT);
+        Ok(InnerEncoder { root: self })
     }
 
     #[inline]
-    fn write_map(self, _schema: &Schema, _len: usize) -> Result<Self::MapWriter, Self::Error> {
-        Ok(InnerHasher { root: self })
+    fn write_map(self, _schema: &Schema, len: usize) -> Result<Self::MapWriter, Self::Error> {
+        Ok(MapEncoder {
+            root: self,
+            entries: Vec::with_capacity(len),
+        })
     }
 
     #[inline]
     fn write_list(self, _schema: &Schema, _len: usize) -> Result<Self::ListWriter, Self::Error> {
-        Ok(InnerHasher { root: self })
+        self.buf.push(TAG_LIST);
+        Ok(InnerEncoder { root: self })
     }
 
     #[inline]
     fn write_boolean(self, _schema: &Schema, value: bool) -> Result<Self::Ok, Self::Error> {
-        hash_impl!(self, value);
+        self.write_tagged(TAG_BOOLEAN, &[u8::from(value)]);
+        Ok(())
     }
 
     #[inline]
     fn write_byte(self, _schema: &Schema, value: i8) -> Result<Self::Ok, Self::Error> {
-        hash_impl!(self, value);
+        self.write_tagged(TAG_BYTE, &value.to_le_bytes());
+        Ok(())
     }
 
     #[inline]
     fn write_short(self, _schema: &Schema, value: i16) -> Result<Self::Ok, Self::Error> {
-        hash_impl!(self, value);
+        self.write_tagged(TAG_SHORT, &value.to_le_bytes());
+        Ok(())
     }
 
     #[inline]
     fn write_integer(self, _schema: &Schema, value: i32) -> Result<Self::Ok, Self::Error> {
-        hash_impl!(self, value);
+        self.write_tagged(TAG_INTEGER, &value.to_le_bytes());
+        Ok(())
     }
 
     #[inline]
     fn write_long(self, _schema: &Schema, value: i64) -> Result<Self::Ok, Self::Error> {
-        hash_impl!(self, value);
+        self.write_tagged(TAG_LONG, &value.to_le_bytes());
+        Ok(())
     }
 
     #[cold]
@@ -702,7 +1139,8 @@ impl<'a> Serializer for &'a mut HashingSerializer {
 
     #[inline]
     fn write_big_integer(self, _schema: &Schema, value: &BigInt) -> Result<Self::Ok, Self::Error> {
-        hash_impl!(self, value);
+        self.write_prefixed(TAG_BIG_INTEGER, &value.to_signed_bytes_le());
+        Ok(())
     }
 
     #[inline]
@@ -711,46 +1149,61 @@ impl<'a> Serializer for &'a mut HashingSerializer {
         _schema: &Schema,
         value: &BigDecimal,
     ) -> Result<Self::Ok, Self::Error> {
-        hash_impl!(self, value);
+        // Normalize so numerically equal values (i.e. `1.0` and `1.00`) encode identically
+        let (mantissa, exponent) = value.normalized().into_bigint_and_exponent();
+        self.write_prefixed(TAG_BIG_DECIMAL, &mantissa.to_signed_bytes_le());
+        self.buf.extend_from_slice(&exponent.to_le_bytes());
+        Ok(())
     }
 
     #[inline]
     fn write_string(self, _schema: &Schema, value: &str) -> Result<Self::Ok, Self::Error> {
-        hash_impl!(self, value);
+        self.write_prefixed(TAG_STRING, value.as_bytes());
+        Ok(())
     }
 
     #[inline]
-    fn write_blob(self, _schema: &Schema, value: &ByteBuffer) -> Result<Self::Ok, Self::Error> {
-        hash_impl!(self, value);
+    fn write_blob(self, _schema: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
+        self.write_prefixed(TAG_BLOB, value.as_bytes());
+        Ok(())
     }
 
     #[inline]
     fn write_timestamp(self, _schema: &Schema, value: &Instant) -> Result<Self::Ok, Self::Error> {
-        self.hash(value.epoch_nanoseconds().0);
+        self.write_tagged(TAG_TIMESTAMP, &value.epoch_nanoseconds().0.to_le_bytes());
         Ok(())
     }
 
+    #[inline]
+    fn write_document(
+        self,
+        _schema: &Schema,
+        value: &Box<dyn Document>,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.write_document_value(value.as_ref())
+    }
+
     #[inline]
     fn write_null(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
-        self.hash("null");
+        self.buf.push(TAG_NULL);
         Ok(())
     }
 
     #[inline]
     fn skip(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
-        /* Do not execute hash on skip */
+        /* Do not encode skipped values */
         Ok(())
     }
 }
 
 // ============================================================================
-// Hashing Support
+// Encoding Support
 // ============================================================================
 
-struct InnerHasher<'a> {
-    root: &'a mut HashingSerializer,
+struct InnerEncoder<'a> {
+    root: &'a mut CanonicalSerializer,
 }
-impl ListWriter for InnerHasher<'_> {
+impl ListWriter for InnerEncoder<'_> {
     type Error = ValidationFailure;
     type Ok = ();
 
@@ -765,10 +1218,21 @@ impl ListWriter for InnerHasher<'_> {
 
     #[inline]
     fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.root.buf.push(TAG_END);
         Ok(())
     }
 }
-impl MapWriter for InnerHasher<'_> {
+
+/// Encodes map entries independently of their iteration order.
+///
+/// Smithy maps are unordered, so two maps with the same entries
+/// must be considered duplicates regardless of insertion order.
+/// Entries are encoded separately and sorted before being written.
+struct MapEncoder<'a> {
+    root: &'a mut CanonicalSerializer,
+    entries: Vec<Vec<u8>>,
+}
+impl MapWriter for MapEncoder<'_> {
     type Error = ValidationFailure;
     type Ok = ();
 
@@ -784,17 +1248,25 @@ impl MapWriter for InnerHasher<'_> {
         K: SerializeWithSchema,
         V: SerializeWithSchema,
     {
-        key.serialize_with_schema(key_schema, &mut *self.root)?;
-        value.serialize_with_schema(value_schema, &mut *self.root)?;
+        let mut entry = CanonicalSerializer::new();
+        key.serialize_with_schema(key_schema, &mut entry)?;
+        value.serialize_with_schema(value_schema, &mut entry)?;
+        self.entries.push(entry.result());
         Ok(())
     }
 
     #[inline]
-    fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+    fn end(mut self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.entries.sort_unstable();
+        self.root.buf.push(TAG_MAP);
+        for entry in &self.entries {
+            self.root.buf.extend_from_slice(entry);
+        }
+        self.root.buf.push(TAG_END);
         Ok(())
     }
 }
-impl StructWriter for InnerHasher<'_> {
+impl StructWriter for InnerEncoder<'_> {
     type Error = ValidationFailure;
     type Ok = ();
 
@@ -821,13 +1293,14 @@ impl StructWriter for InnerHasher<'_> {
     where
         T: SerializeWithSchema,
     {
-        self.root.hash(member_name);
+        self.root.write_prefixed(TAG_MEMBER, member_name.as_bytes());
         value.serialize_with_schema(member_schema, &mut *self.root)?;
         Ok(())
     }
 
     #[inline]
     fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.root.buf.push(TAG_END);
         Ok(())
     }
 }
@@ -835,6 +1308,9 @@ impl StructWriter for InnerHasher<'_> {
 #[doc(hidden)]
 pub struct DefaultMapValidator<'a> {
     root: &'a mut DefaultValidator,
+    /// Depth of the path stack when the map was entered
+    depth: usize,
+    sparse: bool,
 }
 impl MapWriter for DefaultMapValidator<'_> {
     type Error = ValidationErrors;
@@ -851,19 +1327,58 @@ impl MapWriter for DefaultMapValidator<'_> {
         K: SerializeWithSchema,
         V: SerializeWithSchema,
     {
-        match key.serialize_with_schema(key_schema, &mut KeySerializer::<ValidationFailure>::new())
+        let first_error = self.root.error_count();
+        // Keys are only rendered once an error is emitted for the entry, so the path
+        // holds an empty (unallocated) placeholder while the entry is validated.
+        self.root.push_path(PathElement::Key(String::new()))?;
+        let mut result = self
+            .validate_entry(key_schema, value_schema, key, value)
+            .and_then(|()| self.root.pop_path_to(self.depth));
+        let emitted = match &result {
+            Ok(()) => self.root.error_count() > first_error,
+            Err(errors) => errors.len() > first_error,
+        };
+        if !emitted {
+            return result;
+        }
+        let key = match key
+            .serialize_with_schema(key_schema, &mut KeySerializer::<ValidationFailure>::new())
         {
-            Ok(val) => self.root.push_path(PathElement::Key(val))?,
+            Ok(key) => key,
             // Return early on this error. Something is wrong with the schema.
             Err(err) => return self.root.short_circuit(err),
+        };
+        let errors = match &mut result {
+            Ok(()) => self.root.errors.as_mut(),
+            Err(errors) => Some(errors),
+        };
+        if let Some(errors) = errors {
+            errors.set_key(first_error, self.depth, &key);
         }
-        key.serialize_with_schema(key_schema, &mut *self.root)?;
-        value.serialize_with_schema(value_schema, &mut *self.root)?;
-        self.root.pop_path()
+        result
     }
 
     #[inline]
     fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.root.pop_path_to(self.depth)
+    }
+}
+impl DefaultMapValidator<'_> {
+    fn validate_entry<K, V>(
+        &mut self,
+        key_schema: &Schema,
+        value_schema: &Schema,
+        key: &K,
+        value: &V,
+    ) -> Result<(), ValidationErrors>
+    where
+        K: SerializeWithSchema,
+        V: SerializeWithSchema,
+    {
+        key.serialize_with_schema(key_schema, &mut *self.root)?;
+        self.root.dense_element = !self.sparse;
+        value.serialize_with_schema(value_schema, &mut *self.root)?;
+        self.root.dense_element = false;
         Ok(())
     }
 }
@@ -871,6 +1386,8 @@ impl MapWriter for DefaultMapValidator<'_> {
 #[doc(hidden)]
 pub struct DefaultStructValidator<'a> {
     root: &'a mut DefaultValidator,
+    /// Depth of the path stack when the structure was entered
+    depth: usize,
 }
 impl StructWriter for DefaultStructValidator<'_> {
     type Error = ValidationErrors;
@@ -883,20 +1400,30 @@ impl StructWriter for DefaultStructValidator<'_> {
     {
         self.root.push_path(member_schema)?;
         value.serialize_with_schema(member_schema, &mut *self.root)?;
-        self.root.pop_path()
+        self.root.pop_path_to(self.depth)
     }
 
     // We use this method to identify missing Required values in documents
     fn write_unknown(&mut self, schema: &Schema, _name: &String) -> Result<(), Self::Error> {
         self.root.push_path(schema)?;
         self.root.write_missing(schema)?;
-        self.root.pop_path()
+        self.root.pop_path_to(self.depth)
     }
 
+    // Members that are not in the schema have no constraints to validate
     #[inline]
-    fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+    fn write_unknown_member(
+        &mut self,
+        _member_name: &str,
+        _value: &Box<dyn Document>,
+    ) -> Result<(), Self::Error> {
         Ok(())
     }
+
+    #[inline]
+    fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.root.pop_path_to(self.depth)
+    }
 }
 
 // ============================================================================
@@ -951,6 +1478,15 @@ impl ValidationErrors {
         self.errors.push(ValidationErrorField::new(path, error));
     }
 
+    /// Set the map key at `depth` in the paths of all errors added after the first `from` errors.
+    fn set_key(&mut self, from: usize, depth: usize, key: &str) {
+        for field in self.errors.iter_mut().skip(from) {
+            if let Some(PathElement::Key(placeholder)) = field.paths.get_mut(depth) {
+                key.clone_into(placeholder);
+            }
+        }
+    }
+
     /// Get the number of child-errors contained in this error.
     #[must_use]
     #[inline]
@@ -964,6 +1500,13 @@ impl ValidationErrors {
     pub fn is_empty(&self) -> bool {
         self.errors.is_empty()
     }
+
+    /// Get the child-errors contained in this error.
+    #[must_use]
+    #[inline]
+    pub fn errors(&self) -> &[ValidationErrorField] {
+        &self.errors
+    }
 }
 impl Default for ValidationErrors {
     fn default() -> Self {
@@ -977,15 +1520,60 @@ impl serializers::Error for ValidationErrors {
     }
 }
 
+/// Renders one error per line as `<json pointer>: <message> [<code>]`.
 impl Display for ValidationErrors {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{:#?}", self.errors)
+        for error in &self.errors {
+            writeln!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+smithy!("smithy4rs.validation#ValidationErrorField": {
+    /// Schema used to serialize a [`ValidationErrorField`]
+    structure VALIDATION_ERROR_FIELD {
+        PATH: STRING = "path"
+        CODE: STRING = "code"
+        MESSAGE: STRING = "message"
+    }
+});
+smithy!("smithy4rs.validation#ValidationErrorFieldList": {
+    /// Schema used to serialize a list of [`ValidationErrorField`]'s
+    list VALIDATION_ERROR_FIELD_LIST {
+        member: VALIDATION_ERROR_FIELD
+    }
+});
+smithy!("smithy4rs.validation#ValidationErrors": {
+    /// Schema used to serialize [`ValidationErrors`]
+    structure VALIDATION_ERRORS {
+        ERRORS: VALIDATION_ERROR_FIELD_LIST = "errors"
+    }
+});
+
+impl StaticSchemaShape for ValidationErrors {
+    #[inline]
+    fn schema() -> &'static Schema {
+        &VALIDATION_ERRORS
+    }
+}
+
+/// Serializes as `{ "errors": [{ "path": ..., "code": ..., "message": ... }] }` so
+/// services can return validation failures in API error bodies.
+impl SerializeWithSchema for ValidationErrors {
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut writer = serializer.write_struct(schema, 1)?;
+        writer.write_member(&_VALIDATION_ERRORS_MEMBER_ERRORS, &self.errors)?;
+        writer.end(schema)
     }
 }
 
 /// Describes one specific validation failure and it's location.
 #[derive(Debug)]
-#[allow(dead_code)]
 pub struct ValidationErrorField {
     paths: Vec<PathElement>,
     error: Box<dyn ValidationError>,
@@ -998,6 +1586,103 @@ impl ValidationErrorField {
             error: error.into(),
         }
     }
+
+    /// Get the path to the invalid value.
+    #[must_use]
+    pub fn paths(&self) -> &[PathElement] {
+        &self.paths
+    }
+
+    /// Get the underlying validation error.
+    #[must_use]
+    pub fn error(&self) -> &dyn ValidationError {
+        &*self.error
+    }
+
+    /// Get the machine-readable code of the underlying validation error.
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        self.error.code()
+    }
+
+    /// Render the path to the invalid value as an [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901)
+    /// JSON Pointer (for example `/field_a/1/field_b`).
+    ///
+    /// Member path elements are rendered using their member name. An empty path
+    /// renders as the empty string, which points at the root value.
+    #[must_use]
+    pub fn json_pointer(&self) -> String {
+        json_pointer(&self.paths)
+    }
+}
+impl Display for ValidationErrorField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} [{}]",
+            self.json_pointer(),
+            self.error,
+            self.code()
+        )
+    }
+}
+impl StaticSchemaShape for ValidationErrorField {
+    #[inline]
+    fn schema() -> &'static Schema {
+        &VALIDATION_ERROR_FIELD
+    }
+}
+impl SerializeWithSchema for ValidationErrorField {
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut writer = serializer.write_struct(schema, 3)?;
+        writer.write_member(&_VALIDATION_ERROR_FIELD_MEMBER_PATH, &self.json_pointer())?;
+        writer.write_member(
+            &_VALIDATION_ERROR_FIELD_MEMBER_CODE,
+            &Cow::Borrowed(self.code()),
+        )?;
+        writer.write_member(
+            &_VALIDATION_ERROR_FIELD_MEMBER_MESSAGE,
+            &self.error.to_string(),
+        )?;
+        writer.end(schema)
+    }
+}
+
+/// Render a path as an [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901) JSON Pointer.
+#[must_use]
+pub fn json_pointer(paths: &[PathElement]) -> String {
+    let mut pointer = String::new();
+    for element in paths {
+        pointer.push('/');
+        match element {
+            PathElement::Schema(schema) => {
+                let name = schema
+                    .as_member()
+                    .map_or_else(|| schema.id().name(), |member| member.name());
+                escape_pointer_token(&mut pointer, name);
+            }
+            PathElement::Index(index) => {
+                pointer.push_str(&index.to_string());
+            }
+            PathElement::Key(key) => escape_pointer_token(&mut pointer, key),
+        }
+    }
+    pointer
+}
+
+/// Escape a JSON Pointer reference token (`~` as `~0` and `/` as `~1`).
+fn escape_pointer_token(pointer: &mut String, token: &str) {
+    for c in token.chars() {
+        match c {
+            '~' => pointer.push_str("~0"),
+            '/' => pointer.push_str("~1"),
+            _ => pointer.push(c),
+        }
+    }
 }
 
 /// Represents a `JsonPointer` path element.
@@ -1032,7 +1717,17 @@ impl From<&Schema> for PathElement {
 }
 
 /// Marker trait for validation errors.
-pub trait ValidationError: Error {}
+///
+/// Validation errors must be `Send + Sync` so [`ValidationErrors`] can be
+/// returned across async tasks.
+pub trait ValidationError: Error + Send + Sync {
+    /// Machine-readable code identifying the kind of validation failure.
+    ///
+    /// Defaults to `"custom"`.
+    fn code(&self) -> &'static str {
+        "custom"
+    }
+}
 
 // Implement conversion for any Error enums implementing Validation error
 impl<T: ValidationError + 'static> From<T> for Box<dyn ValidationError> {
@@ -1059,10 +1754,14 @@ enum ValidationFailure {
     MaximumDepthExceeded(usize),
     #[error("Maximum Number of errors ({0}) reached")]
     MaxErrorsReached(usize),
-    #[error("Tried to pop from an empty path stack. This is a bug.")]
+    #[error("Collection size {0} exceeds maximum of {1}")]
+    MaxCollectionSizeExceeded(usize, usize),
+    #[error("Tried to pop path segments owned by an enclosing container. This is a bug.")]
     PopFromEmptyValidator,
     #[error("Attempted to perform `@uniqueItem` check on float. This is invalid")]
     UniqueItemOnFloat,
+    #[error(transparent)]
+    DeadlineExceeded(DeadlineExceeded),
     #[error("{0}")]
     Custom(String),
 }
@@ -1071,7 +1770,30 @@ impl serializers::Error for ValidationFailure {
         ValidationFailure::Custom(msg.to_string())
     }
 }
-impl ValidationError for ValidationFailure {}
+impl ValidationError for ValidationFailure {
+    fn code(&self) -> &'static str {
+        match self {
+            ValidationFailure::ExpectedMember(_) => "expectedMember",
+            ValidationFailure::MaximumDepthExceeded(_) => "maxDepthExceeded",
+            ValidationFailure::MaxErrorsReached(_) => "maxErrorsReached",
+            ValidationFailure::MaxCollectionSizeExceeded(..) => "maxCollectionSizeExceeded",
+            ValidationFailure::PopFromEmptyValidator => "internal",
+            ValidationFailure::UniqueItemOnFloat => "uniqueItemsOnFloat",
+            ValidationFailure::DeadlineExceeded(_) => "deadlineExceeded",
+            ValidationFailure::Custom(_) => "custom",
+        }
+    }
+}
+impl From<ValidationFailure> for CodecError {
+    fn from(value: ValidationFailure) -> Self {
+        match value {
+            ValidationFailure::ExpectedMember(_) => CodecError::type_mismatch("", value),
+            ValidationFailure::UniqueItemOnFloat => CodecError::Unsupported(value.to_string()),
+            ValidationFailure::DeadlineExceeded(e) => CodecError::DeadlineExceeded(e),
+            _ => CodecError::Custom(value.to_string()),
+        }
+    }
+}
 
 // ============================================================================
 // Base Smithy constraint errors
@@ -1093,26 +1815,48 @@ enum SmithyConstraints {
     #[error("Size: {0} does not conform to @range constraint. Expected between {1} and {2}.")]
     Range(String, BigDecimal, BigDecimal),
     /// [@uniqueItems](<https://smithy.io/2.0/spec/constraint-traits.html#uniqueitems-trait>)
-    #[error("Items in collection should be unique.")]
-    UniqueItems,
+    #[error("Items in collection should be unique. Duplicate of item at index {0}.")]
+    UniqueItems(usize),
     #[error("Shape type {0} does not match expected {1}.")]
     ShapeType(ShapeType, ShapeType),
     #[error("Enum value `{0}` invalid. Expected one of: {1:?}.")]
     EnumValue(String, FxIndexSet<&'static str>),
     #[error("Enum value `{0}` invalid. Expected one of: {1:?}.")]
     IntEnumValue(i32, FxIndexSet<i32>),
+    /// [@sparse](<https://smithy.io/2.0/spec/type-refinement-traits.html#sparse-trait>)
+    #[error("Collection is not @sparse and cannot contain null values.")]
+    Sparse,
+}
+impl ValidationError for SmithyConstraints {
+    fn code(&self) -> &'static str {
+        match self {
+            SmithyConstraints::Required => "required",
+            SmithyConstraints::Length(..) => "length",
+            SmithyConstraints::Pattern(..) => "pattern",
+            SmithyConstraints::Range(..) => "range",
+            SmithyConstraints::UniqueItems(_) => "uniqueItems",
+            SmithyConstraints::ShapeType(..) => "shapeType",
+            SmithyConstraints::EnumValue(..) => "enum",
+            SmithyConstraints::IntEnumValue(..) => "intEnum",
+            SmithyConstraints::Sparse => "sparse",
+        }
+    }
 }
-impl ValidationError for SmithyConstraints {}
 
 #[cfg(test)]
 #[allow(clippy::type_complexity)]
 mod tests {
+    use std::str::FromStr;
+
     use super::*;
     use crate::{
         IndexMap,
         derive::SmithyShape,
         prelude::RequiredTrait,
-        schema::prelude::{INTEGER, LengthTrait, PatternTrait, STRING, UniqueItemsTrait},
+        schema::prelude::{
+            BIG_DECIMAL, DOCUMENT, INTEGER, LengthTrait, PatternTrait, STRING, SparseTrait,
+            UniqueItemsTrait,
+        },
         smithy,
     };
 
@@ -1179,7 +1923,7 @@ mod tests {
     #[test]
     fn builds_if_no_errors() {
         let output = SimpleStructBuilder::new()
-            .field_a("fieldA".to_string())
+            .field_a("fieldA")
             .build()
             .expect("Failed to build SimpleStruct");
         assert_eq!(output.field_a, "fieldA".to_string());
@@ -1212,7 +1956,7 @@ mod tests {
         let inner_vec = vec!["too long of a string".to_string()];
         let Some(err) = builder
             .field_list(inner_vec)
-            .field_a("field-a".to_string())
+            .field_a("field-a")
             .build()
             .err()
         else {
@@ -1293,7 +2037,7 @@ mod tests {
         ];
         let Some(err) = builder
             .field_list(inner_vec)
-            .field_a("fieldA".to_string())
+            .field_a("fieldA")
             .build()
             .err()
         else {
@@ -1323,7 +2067,7 @@ mod tests {
         );
         assert_eq!(
             error_unique.error.to_string(),
-            "Items in collection should be unique.".to_string()
+            "Items in collection should be unique. Duplicate of item at index 0.".to_string()
         );
     }
 
@@ -1334,12 +2078,7 @@ mod tests {
         inner_map.insert("bad-key".to_string(), "a".to_string());
         inner_map.insert("a".to_string(), "value is too long!".to_string());
         inner_map.insert("b".to_string(), "a".to_string());
-        let Some(err) = builder
-            .field_map(inner_map)
-            .field_a("fieldA".to_string())
-            .build()
-            .err()
-        else {
+        let Some(err) = builder.field_map(inner_map).field_a("fieldA").build().err() else {
             panic!("Expected an error");
         };
         assert_eq!(err.errors.len(), 3);
@@ -1419,7 +2158,7 @@ mod tests {
 
     #[test]
     fn nested_struct_fields_build_if_no_errors() {
-        let builder_nested = NestedStructBuilder::new().c("field".to_string());
+        let builder_nested = NestedStructBuilder::new().c("field");
         let builder = StructWithNestedBuilder::new();
         let _value = builder
             .field_required_nested_builder(builder_nested)
@@ -1430,7 +2169,7 @@ mod tests {
     #[test]
     fn nested_struct_fields_build_with_pre_built_shape() {
         let built_nested = NestedStructBuilder::new()
-            .c("field".to_string())
+            .c("field")
             .build()
             .expect("Failed to build NestedStruct");
         let builder = StructWithNestedBuilder::new();
@@ -1440,9 +2179,41 @@ mod tests {
             .expect("Failed to build SimpleStruct");
     }
 
+    #[test]
+    fn nested_struct_setter_accepts_builder() {
+        let builder = StructWithNestedBuilder::new()
+            .field_nested(NestedStructBuilder::new().c("dataWithCaps"))
+            .field_required_nested(NestedStructBuilder::new().c("field"));
+        let Some(err) = builder.build().err() else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(
+            err.errors.first().unwrap().paths,
+            vec![
+                PathElement::Schema(_STRUCT_WITH_NESTED_SCHEMA_MEMBER_NESTED.clone()),
+                PathElement::Schema(_NESTED_SCHEMA_MEMBER_C.clone())
+            ]
+        );
+    }
+
+    #[test]
+    fn setters_update_builder_in_place() {
+        let mut builder = StructWithNestedBuilder::new();
+        for value in ["a", "b"] {
+            builder.set_field_required_nested_builder(NestedStructBuilder::new().c(value));
+        }
+        let mut nested = NestedStructBuilder::new();
+        nested.set_c("field");
+        builder.set_field_nested(nested);
+        let output = builder.build().expect("Failed to build StructWithNested");
+        assert_eq!(output.field_required_nested.c, "b");
+        assert_eq!(output.field_nested.unwrap().c, "field");
+    }
+
     #[test]
     fn nested_struct_fields_checked() {
-        let builder_nested = NestedStructBuilder::new().c("dataWithCaps".to_string());
+        let builder_nested = NestedStructBuilder::new().c("dataWithCaps");
         let builder = StructWithNestedBuilder::new();
         let Some(err) = builder
             .field_required_nested_builder(builder_nested)
@@ -1466,6 +2237,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn nested_errors_render_as_json_pointers() {
+        let builder = StructWithNestedBuilder::new()
+            .field_required_nested(NestedStructBuilder::new().c("dataWithCaps"));
+        let Err(err) = builder.build() else {
+            panic!("Expected an error");
+        };
+        let error = err.errors.first().unwrap();
+        assert_eq!(error.json_pointer(), "/required/c");
+        assert_eq!(error.code(), "pattern");
+        assert_eq!(
+            err.to_string(),
+            "/required/c: Value `dataWithCaps` did not conform to expected pattern `^[a-z]*$` [pattern]\n"
+        );
+    }
+
+    #[test]
+    fn json_pointer_escapes_keys() {
+        let error = ValidationErrorField::new(
+            &[
+                PathElement::Schema(_STRUCT_WITH_NESTED_SCHEMA_MEMBER_NESTED.clone()),
+                PathElement::Index(1),
+                PathElement::Key("a/b~c".to_string()),
+            ],
+            SmithyConstraints::Required,
+        );
+        assert_eq!(error.json_pointer(), "/nested/1/a~1b~0c");
+        assert_eq!(error.code(), "required");
+        assert_eq!(
+            ValidationErrorField::new(&[], SmithyConstraints::Required).json_pointer(),
+            ""
+        );
+    }
+
     // ==== Nested List Validations ====
     smithy!("com.example#ListOfNested": {
         @LengthTrait::builder().max(3).build();
@@ -1506,7 +2311,7 @@ mod tests {
 
     #[test]
     fn nested_struct_list_build_if_no_errors() {
-        let nested_list = vec![NestedStructBuilder::new().c("data".to_string())];
+        let nested_list = vec![NestedStructBuilder::new().c("data")];
         let builder = StructWithNestedListsBuilder::new();
         builder
             .field_required_nested_list_builder(nested_list)
@@ -1518,11 +2323,11 @@ mod tests {
     fn nested_struct_list_fields_build_with_pre_built_shapes() {
         let nested_list = vec![
             NestedStructBuilder::new()
-                .c("a".to_string())
+                .c("a")
                 .build()
                 .expect("Failed to build NestedStruct"),
             NestedStructBuilder::new()
-                .c("b".to_string())
+                .c("b")
                 .build()
                 .expect("Failed to build NestedStruct"),
         ];
@@ -1536,10 +2341,10 @@ mod tests {
     #[test]
     fn nested_struct_list_checked() {
         let nested_list = vec![
-            NestedStructBuilder::new().c("a".to_string()),
-            NestedStructBuilder::new().c("b".to_string()),
-            NestedStructBuilder::new().c("dataWithCaps".to_string()),
-            NestedStructBuilder::new().c("b".to_string()),
+            NestedStructBuilder::new().c("a"),
+            NestedStructBuilder::new().c("b"),
+            NestedStructBuilder::new().c("dataWithCaps"),
+            NestedStructBuilder::new().c("b"),
         ];
         let builder = StructWithNestedListsBuilder::new();
         let Some(err) = builder
@@ -1580,10 +2385,8 @@ mod tests {
 
     #[test]
     fn deeply_nested_struct_list_checks_validation_rules() {
-        let nested_list = vec![NestedStructBuilder::new().c("data".to_string())];
-        let deeply_nested_list = vec![vec![vec![
-            NestedStructBuilder::new().c("dataWithCaps".to_string()),
-        ]]];
+        let nested_list = vec![NestedStructBuilder::new().c("data")];
+        let deeply_nested_list = vec![vec![vec![NestedStructBuilder::new().c("dataWithCaps")]]];
         let builder = StructWithNestedListsBuilder::new();
         let Some(err) = builder
             .field_required_nested_list_builder(nested_list)
@@ -1673,9 +2476,9 @@ mod tests {
     #[test]
     fn detects_duplicates_in_sets() {
         let structs = vec![
-            NestedStructBuilder::new().c("a".to_string()),
-            NestedStructBuilder::new().c("b".to_string()),
-            NestedStructBuilder::new().c("b".to_string()),
+            NestedStructBuilder::new().c("a"),
+            NestedStructBuilder::new().c("b"),
+            NestedStructBuilder::new().c("b"),
         ];
         let simple = vec![
             "Stuff".to_string(),
@@ -1705,9 +2508,10 @@ mod tests {
 
         // Should _only_ be uniqueness errors
         for e in &err.errors {
-            assert_eq!(
-                e.error.to_string(),
-                "Items in collection should be unique.".to_string()
+            assert!(
+                e.error
+                    .to_string()
+                    .starts_with("Items in collection should be unique.")
             );
         }
 
@@ -1719,6 +2523,10 @@ mod tests {
                 PathElement::Index(2)
             ]
         );
+        assert_eq!(
+            error_unique_struct.error.to_string(),
+            "Items in collection should be unique. Duplicate of item at index 1.".to_string()
+        );
 
         let error_unique_simple = err.errors.get(1).unwrap();
         assert_eq!(
@@ -1746,6 +2554,245 @@ mod tests {
                 PathElement::Index(2)
             ]
         );
+        assert_eq!(
+            error_unique_map.error.to_string(),
+            "Items in collection should be unique. Duplicate of item at index 0.".to_string()
+        );
+    }
+
+    smithy!("com.example#SetOfInt": {
+        @UniqueItemsTrait::builder().build();
+        list SET_OF_INT {
+            member: INTEGER
+        }
+    });
+    smithy!("com.example#SetOfSet": {
+        @UniqueItemsTrait::builder().build();
+        list SET_OF_SET {
+            member: SET_OF_INT
+        }
+    });
+    smithy!("com.example#ListOfList": {
+        list LIST_OF_LIST {
+            member: LIST_OF_INT
+        }
+    });
+    smithy!("com.example#SetOfListOfList": {
+        @UniqueItemsTrait::builder().build();
+        list SET_OF_LIST_OF_LIST {
+            member: LIST_OF_LIST
+        }
+    });
+
+    #[test]
+    fn reports_inner_path_of_nested_set_duplicates() {
+        let value = vec![vec![1, 2], vec![3, 3], vec![1, 2]];
+        let Err(err) = DefaultValidator::new().validate(&SET_OF_SET, &value) else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 2);
+
+        let inner = err.errors.first().unwrap();
+        assert_eq!(
+            inner.paths,
+            vec![PathElement::Index(1), PathElement::Index(1)]
+        );
+        assert_eq!(
+            inner.error.to_string(),
+            "Items in collection should be unique. Duplicate of item at index 0.".to_string()
+        );
+
+        let outer = err.errors.get(1).unwrap();
+        assert_eq!(outer.paths, vec![PathElement::Index(2)]);
+        assert_eq!(
+            outer.error.to_string(),
+            "Items in collection should be unique. Duplicate of item at index 0.".to_string()
+        );
+    }
+
+    #[test]
+    fn nested_lists_with_same_flattened_items_are_unique() {
+        let value = vec![vec![vec![1, 2], vec![3]], vec![vec![1], vec![2, 3]]];
+        assert!(
+            DefaultValidator::new()
+                .validate(&SET_OF_LIST_OF_LIST, &value)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn maps_with_different_entry_order_are_duplicates() {
+        let mut map_a = IndexMap::new();
+        map_a.insert("a".to_string(), 1);
+        map_a.insert("b".to_string(), 2);
+        let mut map_b = IndexMap::new();
+        map_b.insert("b".to_string(), 2);
+        map_b.insert("a".to_string(), 1);
+        let mut map_c = IndexMap::new();
+        map_c.insert("a".to_string(), 2);
+        map_c.insert("b".to_string(), 1);
+        let value = vec![map_a, map_c, map_b];
+        let Err(err) = DefaultValidator::new().validate(&SET_OF_MAP, &value) else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 1);
+        let error = err.errors.first().unwrap();
+        assert_eq!(error.paths, vec![PathElement::Index(2)]);
+        assert_eq!(
+            error.error.to_string(),
+            "Items in collection should be unique. Duplicate of item at index 0.".to_string()
+        );
+    }
+
+    fn encode<T: SerializeWithSchema>(schema: &Schema, value: &T) -> Vec<u8> {
+        let mut serializer = CanonicalSerializer::new();
+        value
+            .serialize_with_schema(schema, &mut serializer)
+            .unwrap();
+        serializer.result()
+    }
+
+    #[test]
+    fn canonical_encoding_is_exact() {
+        // Adjacent values must never run together
+        assert_ne!(
+            encode(&SET_OF_STRING, &vec!["ab".to_string()]),
+            encode(&SET_OF_STRING, &vec!["a".to_string(), "b".to_string()])
+        );
+        assert_ne!(
+            encode(&SET_OF_LIST, &vec![vec![1, 2], vec![3]]),
+            encode(&SET_OF_LIST, &vec![vec![1], vec![2, 3]])
+        );
+        // Numerically equal decimals are the same item
+        assert_eq!(
+            encode(&BIG_DECIMAL, &BigDecimal::from_str("1.0").unwrap()),
+            encode(&BIG_DECIMAL, &BigDecimal::from_str("1.00").unwrap())
+        );
+    }
+
+    #[test]
+    fn uniqueness_tracker_compares_full_encoding() {
+        let mut tracker = UniquenessTracker::new(usize::MAX);
+        assert_eq!(tracker.add(&STRING, &"a".to_string(), 0).unwrap(), None);
+        assert_eq!(tracker.add(&STRING, &"b".to_string(), 1).unwrap(), None);
+        assert_eq!(tracker.add(&STRING, &"a".to_string(), 2).unwrap(), Some(0));
+    }
+
+    #[test]
+    fn max_tracked_unique_items_bounds_tracking() {
+        let value = vec![1, 2, 3, 1, 3];
+        let mut validator = DefaultValidator::new().with_max_tracked_unique_items(2);
+        let Err(err) = validator.validate(&SET_OF_INT, &value) else {
+            panic!("Expected an error");
+        };
+        // `3` is past the tracking limit, so only the duplicate of `1` is found
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(
+            err.errors.first().unwrap().paths,
+            vec![PathElement::Index(3)]
+        );
+    }
+
+    #[test]
+    fn configured_max_collection_size_short_circuits() {
+        let config = ValidatorConfig {
+            max_collection_size: 2,
+            ..ValidatorConfig::default()
+        };
+        let Err(err) = DefaultValidator::with_config(config).validate(&SET_OF_INT, &vec![1, 2, 3])
+        else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(err.errors[0].code(), "maxCollectionSizeExceeded");
+    }
+
+    #[test]
+    fn expired_deadline_short_circuits() {
+        let mut validator =
+            DefaultValidator::new().with_deadline(Deadline::at(std::time::Instant::now()));
+        let Err(err) = validator.validate(&SET_OF_INT, &vec![1, 2, 3]) else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(err.errors[0].code(), "deadlineExceeded");
+        assert_eq!(err.errors[0].json_pointer(), "");
+    }
+
+    #[test]
+    fn cancelled_validation_short_circuits() {
+        let token = crate::serde::deadline::CancellationToken::new();
+        let deadline = Deadline::none().with_token(token.clone());
+        assert!(
+            DefaultValidator::new()
+                .with_deadline(deadline.clone())
+                .validate(&SET_OF_INT, &vec![1, 2, 3])
+                .is_ok()
+        );
+        token.cancel();
+        let Err(err) = DefaultValidator::new()
+            .with_deadline(deadline)
+            .validate(&SET_OF_INT, &vec![1, 2, 3])
+        else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors[0].code(), "deadlineExceeded");
+        assert_eq!(err.errors[0].error().to_string(), "Cancelled");
+    }
+
+    #[test]
+    fn configured_max_errors_short_circuits() {
+        let config = ValidatorConfig {
+            max_errors: 2,
+            ..ValidatorConfig::default()
+        };
+        let Err(err) =
+            DefaultValidator::with_config(config).validate(&SET_OF_INT, &vec![1, 1, 1, 1, 1])
+        else {
+            panic!("Expected an error");
+        };
+        // Two duplicates plus the error marking the limit
+        assert_eq!(err.errors.len(), 3);
+        assert_eq!(err.errors[2].code(), "maxErrorsReached");
+    }
+
+    #[test]
+    fn configured_max_depth_is_enforced() {
+        let value = vec![vec![1], vec![2]];
+        let shallow = ValidatorConfig {
+            max_depth: 1,
+            ..ValidatorConfig::default()
+        };
+        let Err(err) = DefaultValidator::with_config(shallow).validate(&SET_OF_LIST, &value) else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors[0].code(), "maxDepthExceeded");
+
+        // Depths past the const capacity fall back to a heap-allocated path
+        let deep = ValidatorConfig {
+            max_depth: 64,
+            ..ValidatorConfig::default()
+        };
+        DefaultValidator::with_config(deep)
+            .validate(&SET_OF_LIST, &value)
+            .expect("Expected value to be valid");
+    }
+
+    #[test]
+    fn configured_pattern_checks_can_be_skipped() {
+        let value = "ABC".to_string();
+        assert!(
+            DefaultValidator::new()
+                .validate(&LOWERCASE, &value)
+                .is_err()
+        );
+        let config = ValidatorConfig {
+            check_patterns: false,
+            ..ValidatorConfig::default()
+        };
+        DefaultValidator::with_config(config)
+            .validate(&LOWERCASE, &value)
+            .expect("Expected pattern checks to be skipped");
     }
 
     // ==== Nested Map Validations ====
@@ -1792,10 +2839,7 @@ mod tests {
     #[test]
     fn nested_struct_map_build_if_no_errors() {
         let mut nested_map = IndexMap::new();
-        nested_map.insert(
-            "a".to_string(),
-            NestedStructBuilder::new().c("data".to_string()),
-        );
+        nested_map.insert("a".to_string(), NestedStructBuilder::new().c("data"));
         let builder = StructWithNestedMapsBuilder::new();
         builder
             .required_builder(nested_map)
@@ -1809,7 +2853,7 @@ mod tests {
         nested_map.insert(
             "a".to_string(),
             NestedStructBuilder::new()
-                .c("data".to_string())
+                .c("data")
                 .build()
                 .expect("Failed to build nested"),
         );
@@ -1823,18 +2867,12 @@ mod tests {
     #[test]
     fn nested_struct_map_checked() {
         let mut nested_map = IndexMap::new();
-        nested_map.insert(
-            "a".to_string(),
-            NestedStructBuilder::new().c("a".to_string()),
-        );
+        nested_map.insert("a".to_string(), NestedStructBuilder::new().c("a"));
         nested_map.insert(
             "b".to_string(),
-            NestedStructBuilder::new().c("dataWithCaps".to_string()),
-        );
-        nested_map.insert(
-            "c".to_string(),
-            NestedStructBuilder::new().c("c".to_string()),
+            NestedStructBuilder::new().c("dataWithCaps"),
         );
+        nested_map.insert("c".to_string(), NestedStructBuilder::new().c("c"));
         let builder = StructWithNestedMapsBuilder::new();
         let Some(err) = builder.required_builder(nested_map).build().err() else {
             panic!("Expected an error");
@@ -1872,17 +2910,14 @@ mod tests {
     #[test]
     fn deeply_nested_struct_map_build_if_no_errors() {
         let mut nested_map = IndexMap::new();
-        nested_map.insert(
-            "a".to_string(),
-            NestedStructBuilder::new().c("a".to_string()),
-        );
+        nested_map.insert("a".to_string(), NestedStructBuilder::new().c("a"));
 
         let mut deep_nesting = IndexMap::new();
         let mut mid_nesting = IndexMap::new();
         let mut low_nesting = IndexMap::new();
         low_nesting.insert(
             "a".to_string(),
-            NestedStructBuilder::new().c("dataWithCaps".to_string()),
+            NestedStructBuilder::new().c("dataWithCaps"),
         );
         mid_nesting.insert("a".to_string(), low_nesting);
         deep_nesting.insert("a".to_string(), mid_nesting);
@@ -1924,7 +2959,7 @@ mod tests {
 
     #[test]
     fn checks_string_against_enum_value() {
-        let mut validator = DefaultValidator::new();
+        let mut validator = DefaultValidator::new().with_strict_enums();
         let Err(err) = validator.validate(&TEST_ENUM, &"lies!".to_string()) else {
             panic!("Expected an error");
         };
@@ -1945,7 +2980,7 @@ mod tests {
 
     #[test]
     fn checks_int_against_int_enum_value() {
-        let mut validator = DefaultValidator::new();
+        let mut validator = DefaultValidator::new().with_strict_enums();
         let Err(err) = validator.validate(&TEST_INT_ENUM, &4) else {
             panic!("Expected an error");
         };
@@ -1957,6 +2992,131 @@ mod tests {
         );
     }
 
+    #[test]
+    fn accepts_unknown_enum_values_by_default() {
+        let mut validator = DefaultValidator::new();
+        assert!(validator.validate(&TEST_ENUM, &"lies!".to_string()).is_ok());
+        let mut validator = DefaultValidator::new();
+        assert!(validator.validate(&TEST_INT_ENUM, &4).is_ok());
+    }
+
+    smithy!("test#ClosedEnum": {
+        @KnownVariantsTrait::builder().build();
+        enum CLOSED_ENUM {
+            A = "a"
+        }
+    });
+
+    smithy!("test#StructWithClosedEnum": {
+        structure STRUCT_WITH_CLOSED_ENUM {
+            VALUE: CLOSED_ENUM = "value"
+        }
+    });
+
+    #[test]
+    fn checks_enums_with_known_variants_trait() {
+        let mut value = IndexMap::new();
+        value.insert("value".to_string(), Box::<dyn Document>::from("b"));
+        let document: Box<dyn Document> = value.into();
+
+        let mut validator = DefaultValidator::new();
+        let Err(err) = validator.validate(&STRUCT_WITH_CLOSED_ENUM, &document) else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(
+            err.errors.first().unwrap().error.to_string(),
+            "Enum value `b` invalid. Expected one of: {\"a\"}.".to_string()
+        );
+    }
+
+    smithy!("test#ConstrainedEnum": {
+        @PatternTrait::new("^[a-z]+$");
+        @LengthTrait::builder().max(3).build();
+        enum CONSTRAINED_ENUM {
+            A = "a"
+            B = "b"
+        }
+    });
+
+    smithy!("test#MapWithEnumKeys": {
+        map MAP_WITH_ENUM_KEYS {
+            key: CONSTRAINED_ENUM
+            value: INTEGER
+        }
+    });
+
+    #[test]
+    fn checks_constraints_on_enum_values() {
+        let mut validator = DefaultValidator::new();
+        assert!(
+            validator
+                .validate(&CONSTRAINED_ENUM, &"b".to_string())
+                .is_ok()
+        );
+
+        let mut validator = DefaultValidator::new();
+        let Err(err) = validator.validate(&CONSTRAINED_ENUM, &"UNKNOWN".to_string()) else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 2);
+        assert_eq!(
+            err.errors.first().unwrap().error.to_string(),
+            "Size: 7 does not conform to @length constraint. Expected between 0 and 3.".to_string()
+        );
+        assert_eq!(
+            err.errors.get(1).unwrap().error.to_string(),
+            "Value `UNKNOWN` did not conform to expected pattern `^[a-z]+$`".to_string()
+        );
+    }
+
+    #[test]
+    fn checks_constraints_on_enum_map_keys() {
+        let mut map = IndexMap::new();
+        map.insert("a".to_string(), 1);
+        map.insert("Bad".to_string(), 2);
+
+        let mut validator = DefaultValidator::new();
+        let Err(err) = validator.validate(&MAP_WITH_ENUM_KEYS, &map) else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 1);
+        let error = err.errors.first().unwrap();
+        assert_eq!(error.paths, vec![PathElement::Key("Bad".to_string())]);
+        assert_eq!(
+            error.error.to_string(),
+            "Value `Bad` did not conform to expected pattern `^[a-z]+$`".to_string()
+        );
+    }
+
+    #[test]
+    fn sets_map_keys_of_short_circuited_entries() {
+        let mut map = IndexMap::new();
+        map.insert("Bad".to_string(), 1);
+        map.insert("Worse".to_string(), 2);
+
+        let mut validator: DefaultValidator = DefaultValidator::with_config(ValidatorConfig {
+            max_errors: 1,
+            ..ValidatorConfig::default()
+        });
+        let Err(err) = validator.validate(&MAP_WITH_ENUM_KEYS, &map) else {
+            panic!("Expected an error");
+        };
+        let paths = err
+            .errors()
+            .iter()
+            .map(ValidationErrorField::paths)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                [PathElement::Key("Bad".to_string())],
+                [PathElement::Key("Worse".to_string())]
+            ]
+        );
+        assert_eq!(err.errors().last().unwrap().code(), "maxErrorsReached");
+    }
+
     // ==== `@uniqueItem` Validations ====
     smithy!("com.example#Lowercase": {
         @PatternTrait::new("^[a-z]*$");
@@ -2035,4 +3195,342 @@ mod tests {
             "Value `dataWithCaps` did not conform to expected pattern `^[a-z]*$`".to_string()
         );
     }
+
+    smithy!("com.example#SetOfDocument": {
+        @UniqueItemsTrait::builder().build();
+        list SET_OF_DOCUMENT {
+            member: DOCUMENT
+        }
+    });
+
+    #[test]
+    fn unique_documents_checked() {
+        let mut first: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+        first.insert("a".into(), 1i32.into());
+        first.insert("b".into(), "two".into());
+        let mut second: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+        second.insert("b".into(), "two".into());
+        second.insert("a".into(), 1i64.into());
+        let documents: Vec<Box<dyn Document>> = vec![first.into(), "a".into(), second.into()];
+
+        let Err(err) = DefaultValidator::new().validate(&SET_OF_DOCUMENT, &documents) else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(err.errors[0].paths, vec![PathElement::Index(2)]);
+        assert_eq!(
+            err.errors[0].error.to_string(),
+            "Items in collection should be unique. Duplicate of item at index 0.".to_string()
+        );
+    }
+
+    #[test]
+    fn distinct_documents_are_unique() {
+        let documents: Vec<Box<dyn Document>> = vec![
+            1i32.into(),
+            "1".into(),
+            1.5f64.into(),
+            vec![1i32].into(),
+            Option::<i32>::None.into(),
+        ];
+        DefaultValidator::new()
+            .validate(&SET_OF_DOCUMENT, &documents)
+            .expect("Documents should be unique");
+    }
+
+    #[test]
+    fn document_encoding_matches_equality() {
+        let pairs: Vec<(Box<dyn Document>, Box<dyn Document>)> = vec![
+            (1i8.into(), 1i64.into()),
+            (0.5f32.into(), BigDecimal::from_str("0.50").unwrap().into()),
+            (BigInt::from(7).into(), 7i32.into()),
+            (vec![1i32, 2].into(), vec![1i64, 2].into()),
+            (0.1f32.into(), 0.1f64.into()),
+            ("a".into(), vec!["a"].into()),
+            (true.into(), false.into()),
+        ];
+        for (left, right) in &pairs {
+            assert_eq!(
+                **left == **right,
+                encode(&DOCUMENT, left) == encode(&DOCUMENT, right),
+            );
+        }
+    }
+
+    // ==== Collection Builder Validations ====
+    #[derive(SmithyShape)]
+    #[smithy_schema(LIST_SCHEMA)]
+    pub struct ValidatedList(Vec<String>);
+
+    #[derive(SmithyShape)]
+    #[smithy_schema(MAP_SCHEMA)]
+    pub struct ValidatedMap(IndexMap<String, String>);
+
+    #[test]
+    fn list_builder_collects_and_validates() {
+        let list = ["a", "b"]
+            .into_iter()
+            .map(String::from)
+            .collect::<ValidatedListBuilder>()
+            .build()
+            .expect("Failed to build list");
+        assert_eq!(list.0, vec!["a".to_string(), "b".to_string()]);
+
+        let mut builder = ValidatedList::builder();
+        builder.extend(["a", "b", "c", "d"].into_iter().map(String::from));
+        let Err(err) = builder.build() else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(
+            err.errors[0].error.to_string(),
+            "Size: 4 does not conform to @length constraint. Expected between 0 and 3.".to_string()
+        );
+    }
+
+    #[test]
+    fn map_builder_collects_and_validates() {
+        let map = [("a", "b")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<ValidatedMapBuilder>()
+            .build()
+            .expect("Failed to build map");
+        assert_eq!(map.0.get("a"), Some(&"b".to_string()));
+
+        let mut builder = ValidatedMap::builder();
+        builder.extend([("a".to_string(), "too long".to_string())]);
+        let Err(err) = builder.build() else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 1);
+    }
+
+    // ==== Sparse Collection Validations ====
+    smithy!("com.example#SparseList": {
+        @SparseTrait::builder().build();
+        list SPARSE_LIST {
+            member: STRING
+        }
+    });
+    smithy!("com.example#DenseList": {
+        list DENSE_LIST {
+            member: STRING
+        }
+    });
+    smithy!("com.example#SparseMap": {
+        @SparseTrait::builder().build();
+        map SPARSE_MAP {
+            key: STRING
+            value: STRING
+        }
+    });
+    smithy!("com.example#DenseMap": {
+        map DENSE_MAP {
+            key: STRING
+            value: STRING
+        }
+    });
+
+    #[test]
+    fn allows_nulls_in_sparse_collections() {
+        let list = vec![Some("a".to_string()), None];
+        DefaultValidator::new()
+            .validate(&SPARSE_LIST, &list)
+            .expect("Sparse list should be valid");
+
+        let mut map = IndexMap::new();
+        map.insert("a".to_string(), None::<String>);
+        DefaultValidator::new()
+            .validate(&SPARSE_MAP, &map)
+            .expect("Sparse map should be valid");
+    }
+
+    #[test]
+    fn rejects_nulls_in_dense_collections() {
+        let list = vec![None, Some("a".to_string()), None];
+        let Err(err) = DefaultValidator::new().validate(&DENSE_LIST, &list) else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 2);
+        assert_eq!(err.errors[0].json_pointer(), "/0");
+        assert_eq!(err.errors[0].code(), "sparse");
+        assert_eq!(err.errors[1].json_pointer(), "/2");
+
+        let mut map = IndexMap::new();
+        map.insert("a".to_string(), None::<String>);
+        let Err(err) = DefaultValidator::new().validate(&DENSE_MAP, &map) else {
+            panic!("Expected an error");
+        };
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(err.errors[0].json_pointer(), "/a");
+        assert_eq!(
+            err.errors[0].error.to_string(),
+            "Collection is not @sparse and cannot contain null values."
+        );
+    }
+
+    // ==== Path Lifecycle ====
+    smithy!("com.test#PathNode": {
+        structure PATH_NODE {
+            @PatternTrait::new("^[a-z]*$");
+            CHILD: LOWERCASE = "child"
+        }
+    });
+
+    /// Arbitrary nesting of containers, validated against the `LOWERCASE` string schema
+    /// so that every container and every upper-case leaf emits exactly one error.
+    enum PathTree {
+        Leaf(&'static str),
+        List(Vec<PathTree>),
+        Map(Vec<(String, PathTree)>),
+        Struct(Vec<PathTree>),
+    }
+
+    impl PathTree {
+        fn random(rng: &mut XorShift, depth: usize) -> Self {
+            let children = |rng: &mut XorShift| {
+                (0..rng.below(4))
+                    .map(|_| PathTree::random(rng, depth + 1))
+                    .collect::<Vec<_>>()
+            };
+            match if depth >= 4 { 0 } else { rng.below(5) } {
+                0 | 1 => PathTree::Leaf(if rng.below(2) == 0 { "ok" } else { "BAD" }),
+                2 => PathTree::List(children(rng)),
+                3 => PathTree::Map(
+                    children(rng)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, child)| (format!("k{i}"), child))
+                        .collect(),
+                ),
+                _ => PathTree::Struct(children(rng)),
+            }
+        }
+
+        /// Collect the JSON pointers of errors the validator should emit, in order.
+        fn expected_errors(&self, pointer: &str, errors: &mut Vec<String>) {
+            match self {
+                PathTree::Leaf(value) => {
+                    if *value != "ok" {
+                        errors.push(pointer.to_string());
+                    }
+                }
+                PathTree::List(children) => {
+                    errors.push(pointer.to_string());
+                    for (i, child) in children.iter().enumerate() {
+                        child.expected_errors(&format!("{pointer}/{i}"), errors);
+                    }
+                }
+                PathTree::Map(entries) => {
+                    errors.push(pointer.to_string());
+                    for (key, child) in entries {
+                        child.expected_errors(&format!("{pointer}/{key}"), errors);
+                    }
+                }
+                PathTree::Struct(children) => {
+                    errors.push(pointer.to_string());
+                    for child in children {
+                        child.expected_errors(&format!("{pointer}/child"), errors);
+                    }
+                }
+            }
+        }
+    }
+
+    impl SerializeWithSchema for PathTree {
+        fn serialize_with_schema<S: Serializer>(
+            &self,
+            schema: &Schema,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match self {
+                PathTree::Leaf(value) => serializer.write_string(schema, value),
+                PathTree::List(children) => {
+                    let mut list = serializer.write_list(schema, children.len())?;
+                    for child in children {
+                        list.write_element(&LOWERCASE, child)?;
+                    }
+                    list.end(schema)
+                }
+                PathTree::Map(entries) => {
+                    let mut map = serializer.write_map(schema, entries.len())?;
+                    for (key, child) in entries {
+                        map.write_entry(&STRING, &LOWERCASE, key, child)?;
+                    }
+                    map.end(schema)
+                }
+                PathTree::Struct(children) => {
+                    let member = PATH_NODE.expect_member("child");
+                    let mut structure = serializer.write_struct(schema, children.len())?;
+                    for child in children {
+                        structure.write_member(member, child)?;
+                    }
+                    structure.end(schema)
+                }
+            }
+        }
+    }
+
+    /// Small, seeded PRNG so randomized tests are reproducible.
+    struct XorShift(u64);
+    impl XorShift {
+        fn below(&mut self, bound: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % bound
+        }
+    }
+
+    fn error_pointers(result: Result<(), ValidationErrors>) -> Vec<String> {
+        result.err().map_or_else(Vec::new, |err| {
+            err.errors()
+                .iter()
+                .map(ValidationErrorField::json_pointer)
+                .collect()
+        })
+    }
+
+    #[test]
+    fn restores_paths_when_containers_end() {
+        let mut rng = XorShift(0x5EED_CAFE);
+        let mut validator: DefaultValidator = DefaultValidator::with_config(ValidatorConfig {
+            max_errors: usize::MAX,
+            ..ValidatorConfig::default()
+        });
+        for case in 0..500 {
+            let tree = PathTree::random(&mut rng, 0);
+            let mut expected = Vec::new();
+            tree.expected_errors("", &mut expected);
+            let result = validator.validate(&LOWERCASE, &tree);
+            assert_eq!(error_pointers(result), expected, "case {case}");
+            assert_eq!(validator.path_stack.len(), 0, "case {case}");
+        }
+    }
+
+    #[test]
+    fn resets_paths_after_short_circuit() {
+        let tree = PathTree::List(vec![PathTree::Map(vec![(
+            "k".to_string(),
+            PathTree::List(vec![PathTree::Leaf("BAD")]),
+        )])]);
+        let mut validator: DefaultValidator = DefaultValidator::with_config(ValidatorConfig {
+            max_errors: 2,
+            ..ValidatorConfig::default()
+        });
+        assert_eq!(
+            error_pointers(validator.validate(&LOWERCASE, &tree)),
+            vec!["", "/0", "/0/k"]
+        );
+        assert_eq!(
+            error_pointers(validator.validate(&LOWERCASE, &PathTree::Leaf("BAD"))),
+            vec![""]
+        );
+        let list = PathTree::List(vec![PathTree::Leaf("ok"), PathTree::Leaf("BAD")]);
+        assert_eq!(
+            error_pointers(validator.validate(&LOWERCASE, &list)),
+            vec!["", "/1"]
+        );
+    }
 }
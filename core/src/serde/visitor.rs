@@ -0,0 +1,748 @@
+//! Generic traversal of shapes
+//!
+//! Implementing [`Serializer`] requires three writer types and a method for
+//! every data type, which is a lot of boilerplate for code that only needs to
+//! inspect a value (for example, to scan for sensitive data or estimate the
+//! size of a payload). A [`ShapeVisitor`] instead receives a callback for each
+//! value in a shape along with its schema and its path from the root shape.
+//! Every callback has a default no-op implementation, so visitors only need to
+//! implement the callbacks they care about.
+//!
+//! Paths can be rendered as JSON Pointers with [`json_pointer`](crate::serde::validation::json_pointer).
+//! Values are visited in the order they would be serialized. Optional members
+//! that are not set are not visited, and untyped documents are visited as a
+//! single value.
+//!
+//! ## Example
+//! ```rust,ignore
+//! #[derive(Default)]
+//! struct EmailScanner(Vec<String>);
+//!
+//! impl ShapeVisitor for EmailScanner {
+//!     fn on_string(&mut self, path: &[PathElement], _: &Schema, value: &str) -> Result<(), VisitError> {
+//!         if value.contains('@') {
+//!             self.0.push(json_pointer(path));
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut scanner = EmailScanner::default();
+//! visit(&customer, &mut scanner)?;
+//! ```
+//!
+use std::fmt::Display;
+
+use thiserror::Error;
+
+use crate::{
+    BigDecimal, BigInt, Instant,
+    schema::{Blob, Document, Schema},
+    serde::{
+        CodecError,
+        se::{
+            ListWriter, MapWriter, SerializableShape, SerializeWithSchema, Serializer, StructWriter,
+        },
+        serializers,
+        utils::KeySerializer,
+        validation::PathElement,
+    },
+};
+
+// ============================================================================
+// Visitor
+// ============================================================================
+
+/// Callbacks invoked for each value in a shape by [`visit`].
+///
+/// Each callback receives the path from the root shape to the value (empty for
+/// the root shape itself) and the schema of the value. Returning an error from
+/// any callback stops the traversal.
+#[allow(unused_variables)]
+pub trait ShapeVisitor {
+    /// Called before the members of a structure or union are visited.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_struct_enter(&mut self, path: &[PathElement], schema: &Schema) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called after all members of a structure or union have been visited.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_struct_exit(&mut self, path: &[PathElement], schema: &Schema) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called before the `len` elements of a list are visited.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_list_enter(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        len: usize,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called after all elements of a list have been visited.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_list_exit(&mut self, path: &[PathElement], schema: &Schema) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called before the `len` values of a map are visited.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_map_enter(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        len: usize,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called after all values of a map have been visited.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_map_exit(&mut self, path: &[PathElement], schema: &Schema) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `boolean` value.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_boolean(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: bool,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `byte` value.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_byte(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: i8,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `short` value.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_short(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: i16,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `integer` value, including `intEnum` values.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_integer(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: i32,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `long` value.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_long(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: i64,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `float` value.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_float(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: f32,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `double` value.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_double(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: f64,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `bigInteger` value.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_big_integer(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: &BigInt,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `bigDecimal` value.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_big_decimal(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: &BigDecimal,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `string` value, including `enum` values.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_string(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: &str,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `blob` value.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_blob(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: &Blob,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `timestamp` value.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_timestamp(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: &Instant,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each untyped [`Document`] value. The contents of the
+    /// document are not visited.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    #[allow(clippy::borrowed_box)]
+    fn on_document(
+        &mut self,
+        path: &[PathElement],
+        schema: &Schema,
+        value: &Box<dyn Document>,
+    ) -> Result<(), VisitError> {
+        Ok(())
+    }
+
+    /// Called for each `null` element or value of a `@sparse` collection.
+    ///
+    /// # Errors
+    /// Returns a [`VisitError`] to stop the traversal.
+    fn on_null(&mut self, path: &[PathElement], schema: &Schema) -> Result<(), VisitError> {
+        Ok(())
+    }
+}
+
+/// Visit every value in a shape.
+///
+/// # Errors
+/// Returns a [`VisitError`] if a callback of the visitor returned an error, or
+/// if the shape could not be walked (for example, if a map key cannot be
+/// converted to a string).
+pub fn visit<T, V>(shape: &T, visitor: &mut V) -> Result<(), VisitError>
+where
+    T: SerializableShape,
+    V: ShapeVisitor + ?Sized,
+{
+    shape.serialize(&mut VisitSerializer {
+        visitor,
+        path: Vec::new(),
+    })
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Errors that can occur while visiting a shape.
+#[derive(Error, Debug)]
+#[error("Visit error: {0}")]
+pub struct VisitError(String);
+
+impl serializers::Error for VisitError {
+    fn custom<T: Display>(msg: T) -> Self {
+        VisitError(msg.to_string())
+    }
+}
+
+impl From<VisitError> for CodecError {
+    fn from(value: VisitError) -> Self {
+        CodecError::Custom(value.to_string())
+    }
+}
+
+// ============================================================================
+// Visit Serializer
+// ============================================================================
+
+/// [`Serializer`] that forwards every value written to it to a [`ShapeVisitor`].
+struct VisitSerializer<'v, V: ?Sized> {
+    visitor: &'v mut V,
+    path: Vec<PathElement>,
+}
+
+impl<V: ShapeVisitor + ?Sized> VisitSerializer<'_, V> {
+    fn visit<T: SerializeWithSchema>(
+        &mut self,
+        element: PathElement,
+        schema: &Schema,
+        value: &T,
+    ) -> Result<(), VisitError> {
+        self.path.push(element);
+        value.serialize_with_schema(schema, &mut *self)?;
+        self.path.pop();
+        Ok(())
+    }
+}
+
+impl<'a, 'v, V: ShapeVisitor + ?Sized> Serializer for &'a mut VisitSerializer<'v, V> {
+    type Error = VisitError;
+    type Ok = ();
+    type ListWriter = VisitListWriter<'a, 'v, V>;
+    type MapWriter = VisitWriter<'a, 'v, V>;
+    type StructWriter = VisitWriter<'a, 'v, V>;
+
+    #[inline]
+    fn write_struct(self, schema: &Schema, _len: usize) -> Result<Self::StructWriter, Self::Error> {
+        self.visitor.on_struct_enter(&self.path, schema)?;
+        Ok(VisitWriter { root: self })
+    }
+
+    #[inline]
+    fn write_map(self, schema: &Schema, len: usize) -> Result<Self::MapWriter, Self::Error> {
+        self.visitor.on_map_enter(&self.path, schema, len)?;
+        Ok(VisitWriter { root: self })
+    }
+
+    #[inline]
+    fn write_list(self, schema: &Schema, len: usize) -> Result<Self::ListWriter, Self::Error> {
+        self.visitor.on_list_enter(&self.path, schema, len)?;
+        Ok(VisitListWriter {
+            root: self,
+            index: 0,
+        })
+    }
+
+    #[inline]
+    fn write_boolean(self, schema: &Schema, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_boolean(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_byte(self, schema: &Schema, value: i8) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_byte(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_short(self, schema: &Schema, value: i16) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_short(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_integer(self, schema: &Schema, value: i32) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_integer(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_long(self, schema: &Schema, value: i64) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_long(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_float(self, schema: &Schema, value: f32) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_float(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_double(self, schema: &Schema, value: f64) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_double(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_big_integer(self, schema: &Schema, value: &BigInt) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_big_integer(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_big_decimal(
+        self,
+        schema: &Schema,
+        value: &BigDecimal,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_big_decimal(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_string(self, schema: &Schema, value: &str) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_string(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_blob(self, schema: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_blob(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_timestamp(self, schema: &Schema, value: &Instant) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_timestamp(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_document(
+        self,
+        schema: &Schema,
+        value: &Box<dyn Document>,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_document(&self.path, schema, value)
+    }
+
+    #[inline]
+    fn write_null(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.visitor.on_null(&self.path, schema)
+    }
+
+    #[inline]
+    fn skip(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+pub struct VisitListWriter<'a, 'v, V: ?Sized> {
+    root: &'a mut VisitSerializer<'v, V>,
+    index: usize,
+}
+
+impl<V: ShapeVisitor + ?Sized> ListWriter for VisitListWriter<'_, '_, V> {
+    type Error = VisitError;
+    type Ok = ();
+
+    #[inline]
+    fn write_element<T>(&mut self, element_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        self.root
+            .visit(PathElement::Index(self.index), element_schema, value)?;
+        self.index += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn end(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.root.visitor.on_list_exit(&self.root.path, schema)
+    }
+}
+
+#[doc(hidden)]
+pub struct VisitWriter<'a, 'v, V: ?Sized> {
+    root: &'a mut VisitSerializer<'v, V>,
+}
+
+impl<V: ShapeVisitor + ?Sized> MapWriter for VisitWriter<'_, '_, V> {
+    type Error = VisitError;
+    type Ok = ();
+
+    #[inline]
+    fn write_entry<K, W>(
+        &mut self,
+        key_schema: &Schema,
+        value_schema: &Schema,
+        key: &K,
+        value: &W,
+    ) -> Result<(), Self::Error>
+    where
+        K: SerializeWithSchema,
+        W: SerializeWithSchema,
+    {
+        let key = key.serialize_with_schema(key_schema, &mut KeySerializer::<VisitError>::new())?;
+        self.root.visit(PathElement::Key(key), value_schema, value)
+    }
+
+    #[inline]
+    fn end(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.root.visitor.on_map_exit(&self.root.path, schema)
+    }
+}
+
+impl<V: ShapeVisitor + ?Sized> StructWriter for VisitWriter<'_, '_, V> {
+    type Error = VisitError;
+    type Ok = ();
+
+    #[inline]
+    fn write_member<T>(&mut self, member_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        self.root.visit(member_schema.into(), member_schema, value)
+    }
+
+    #[inline]
+    fn end(self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.root.visitor.on_struct_exit(&self.root.path, schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use super::*;
+    use crate::{
+        derive::SmithyShape,
+        prelude::{INTEGER, STRING},
+        serde::validation::json_pointer,
+        smithy,
+    };
+
+    smithy!("com.example#Labels": {
+        map LABELS {
+            key: STRING
+            value: STRING
+        }
+    });
+
+    smithy!("com.example#Contacts": {
+        list CONTACTS {
+            member: STRING
+        }
+    });
+
+    smithy!("com.example#Account": {
+        structure ACCOUNT {
+            ID: INTEGER = "id"
+            OWNER: STRING = "owner"
+            NICKNAME: STRING = "nickname"
+            CONTACTS_MEMBER: CONTACTS = "contacts"
+            LABELS_MEMBER: LABELS = "labels"
+        }
+    });
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(ACCOUNT)]
+    pub struct Account {
+        #[smithy_schema(ID)]
+        pub id: i32,
+        #[smithy_schema(OWNER)]
+        pub owner: String,
+        #[smithy_schema(NICKNAME)]
+        pub nickname: Option<String>,
+        #[smithy_schema(CONTACTS_MEMBER)]
+        pub contacts: Vec<String>,
+        #[smithy_schema(LABELS_MEMBER)]
+        pub labels: IndexMap<String, String>,
+    }
+
+    fn account() -> Account {
+        let mut labels = IndexMap::new();
+        labels.insert("team".to_string(), "ops@example.com".to_string());
+        Account {
+            id: 7,
+            owner: "Jane".to_string(),
+            nickname: None,
+            contacts: vec!["a@example.com".to_string(), "555-0100".to_string()],
+            labels,
+        }
+    }
+
+    /// Records the path of every string that looks like an email address.
+    #[derive(Default)]
+    struct EmailScanner(Vec<String>);
+
+    impl ShapeVisitor for EmailScanner {
+        fn on_string(
+            &mut self,
+            path: &[PathElement],
+            _schema: &Schema,
+            value: &str,
+        ) -> Result<(), VisitError> {
+            if value.contains('@') {
+                self.0.push(json_pointer(path));
+            }
+            Ok(())
+        }
+    }
+
+    /// Records every callback as a line of text.
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl Recorder {
+        fn record(&mut self, event: &str, path: &[PathElement]) {
+            self.0.push(format!("{event} {}", json_pointer(path)));
+        }
+    }
+
+    impl ShapeVisitor for Recorder {
+        fn on_struct_enter(&mut self, path: &[PathElement], _: &Schema) -> Result<(), VisitError> {
+            self.record("struct", path);
+            Ok(())
+        }
+
+        fn on_struct_exit(&mut self, path: &[PathElement], _: &Schema) -> Result<(), VisitError> {
+            self.record("end struct", path);
+            Ok(())
+        }
+
+        fn on_list_enter(
+            &mut self,
+            path: &[PathElement],
+            _: &Schema,
+            len: usize,
+        ) -> Result<(), VisitError> {
+            self.record(&format!("list({len})"), path);
+            Ok(())
+        }
+
+        fn on_list_exit(&mut self, path: &[PathElement], _: &Schema) -> Result<(), VisitError> {
+            self.record("end list", path);
+            Ok(())
+        }
+
+        fn on_map_enter(
+            &mut self,
+            path: &[PathElement],
+            _: &Schema,
+            len: usize,
+        ) -> Result<(), VisitError> {
+            self.record(&format!("map({len})"), path);
+            Ok(())
+        }
+
+        fn on_map_exit(&mut self, path: &[PathElement], _: &Schema) -> Result<(), VisitError> {
+            self.record("end map", path);
+            Ok(())
+        }
+
+        fn on_integer(
+            &mut self,
+            path: &[PathElement],
+            _: &Schema,
+            value: i32,
+        ) -> Result<(), VisitError> {
+            self.record(&format!("integer({value})"), path);
+            Ok(())
+        }
+
+        fn on_string(
+            &mut self,
+            path: &[PathElement],
+            _: &Schema,
+            value: &str,
+        ) -> Result<(), VisitError> {
+            self.record(&format!("string({value})"), path);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn visits_values_in_order() {
+        let mut recorder = Recorder::default();
+        visit(&account(), &mut recorder).unwrap();
+        assert_eq!(
+            recorder.0,
+            vec![
+                "struct ",
+                "integer(7) /id",
+                "string(Jane) /owner",
+                "list(2) /contacts",
+                "string(a@example.com) /contacts/0",
+                "string(555-0100) /contacts/1",
+                "end list /contacts",
+                "map(1) /labels",
+                "string(ops@example.com) /labels/team",
+                "end map /labels",
+                "end struct ",
+            ]
+        );
+    }
+
+    #[test]
+    fn visitors_only_implement_needed_callbacks() {
+        let mut scanner = EmailScanner::default();
+        visit(&account(), &mut scanner).unwrap();
+        assert_eq!(scanner.0, vec!["/contacts/0", "/labels/team"]);
+    }
+
+    #[test]
+    fn callback_errors_stop_traversal() {
+        struct FailOnInteger;
+        impl ShapeVisitor for FailOnInteger {
+            fn on_integer(
+                &mut self,
+                _: &[PathElement],
+                _: &Schema,
+                _: i32,
+            ) -> Result<(), VisitError> {
+                Err(serializers::Error::custom("integers are not allowed"))
+            }
+        }
+        let error = visit(&account(), &mut FailOnInteger).unwrap_err();
+        assert_eq!(error.to_string(), "Visit error: integers are not allowed");
+    }
+}
@@ -0,0 +1,1353 @@
+//! # Waiters
+//!
+//! [Waiters](https://smithy.io/2.0/additional-specs/waiters.html) poll an operation
+//! until a resource reaches a desired state. Waiters are modeled with the
+//! `@smithy.waiters#waitable` trait on an operation:
+//!
+//! ```smithy
+//! @waitable(
+//!     ItemExists: {
+//!         acceptors: [
+//!             { state: "success", matcher: { output: { path: "status", expected: "ACTIVE", comparator: "stringEquals" } } }
+//!             { state: "retry", matcher: { errorType: "NotFound" } }
+//!         ]
+//!     }
+//! )
+//! operation GetItem { ... }
+//! ```
+//!
+//! Each attempt, the acceptors of the waiter are checked in order and the first one that
+//! matches decides whether the waiter succeeds, fails, or retries. Output matchers select values
+//! from the operation input and output with a subset of [JMESPath](https://jmespath.org/):
+//! - field access (`item.status`) and list indexes (`items[0]`, `items[-1]`)
+//! - list and object projections (`items[].status`, `items[*].status`)
+//! - the `length()` function
+//! - comparisons against a literal (`` length(items) > `0` ``)
+//!
+//! Between attempts, the waiter sleeps using the exponential backoff defined by the
+//! `minDelay` and `maxDelay` of the waiter.
+//!
+//! Path expressions are parsed once, when the `@waitable` trait of an operation is
+//! first read, so invalid expressions are reported before any request is sent.
+//!
+//! ```rust,ignore
+//! let item = GET_ITEM
+//!     .wait_until("ItemExists", &input, Duration::from_secs(300), |input| client.get_item(input), sleep)
+//!     .await?;
+//! ```
+//!
+//! Code generation also emits a `wait_until_<waiter>` function for each waiter of an
+//! operation, such as `wait_until_item_exists(&input, max_wait, call, sleep)`.
+
+use std::{
+    fmt::{Debug, Display},
+    future::Future,
+    ops::Deref,
+    time::Duration,
+};
+
+use thiserror::Error;
+
+use crate::{
+    IndexMap,
+    derive::{SmithyShape, smithy_enum, smithy_union},
+    doc_map,
+    prelude::{BOOLEAN, INTEGER, STRING},
+    retry::random_fraction,
+    schema::{
+        Document, DocumentError, DocumentPath, Operation, Schema, ShapeId, StaticSchemaShape,
+        StaticTraitId, TryFromDocument, parse_prefix,
+    },
+    serde::{DocumentParser, se::SerializeWithSchema},
+    smithy,
+};
+
+// ============================================================================
+// Waitable Trait
+// ============================================================================
+
+smithy!("smithy.waiters#PathComparator": {
+    /// Schema for [`PathComparator`]
+    enum PATH_COMPARATOR {
+        STRING_EQUALS = "stringEquals"
+        BOOLEAN_EQUALS = "booleanEquals"
+        ALL_STRING_EQUALS = "allStringEquals"
+        ANY_STRING_EQUALS = "anyStringEquals"
+    }
+});
+
+/// Comparison applied to the result of a [`PathMatcher`] path.
+#[smithy_enum]
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(PATH_COMPARATOR)]
+pub enum PathComparator {
+    /// The result is a string equal to the expected value.
+    StringEquals = "stringEquals",
+    /// The result is a boolean equal to the expected value (`"true"` or `"false"`).
+    BooleanEquals = "booleanEquals",
+    /// The result is a non-empty list whose elements are all strings equal to the expected value.
+    AllStringEquals = "allStringEquals",
+    /// The result is a list with at least one string equal to the expected value.
+    AnyStringEquals = "anyStringEquals",
+}
+
+smithy!("smithy.waiters#PathMatcher": {
+    /// Schema for [`PathMatcher`]
+    structure PATH_MATCHER {
+        PATH: STRING = "path"
+        EXPECTED: STRING = "expected"
+        COMPARATOR: PATH_COMPARATOR = "comparator"
+    }
+});
+
+/// Matches the value selected by a path expression against an expected value.
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(PATH_MATCHER)]
+pub struct PathMatcher {
+    /// Path expression selecting the value to compare.
+    #[smithy_schema(PATH)]
+    pub path: String,
+    /// Expected value of the selected value.
+    #[smithy_schema(EXPECTED)]
+    pub expected: String,
+    /// Comparison used to check the selected value.
+    #[smithy_schema(COMPARATOR)]
+    #[no_builder]
+    pub comparator: PathComparator,
+}
+
+smithy!("smithy.waiters#Matcher": {
+    /// Schema for [`Matcher`]
+    union MATCHER {
+        OUTPUT: PATH_MATCHER = "output"
+        INPUT_OUTPUT: PATH_MATCHER = "inputOutput"
+        SUCCESS: BOOLEAN = "success"
+        ERROR_TYPE: STRING = "errorType"
+    }
+});
+
+/// Condition checked against the result of a single attempt.
+#[smithy_union]
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(MATCHER)]
+pub enum Matcher {
+    /// Matches the operation output.
+    #[smithy_schema(OUTPUT)]
+    Output(PathMatcher),
+    /// Matches an object with an `input` and `output` member holding the operation input and output.
+    #[smithy_schema(INPUT_OUTPUT)]
+    InputOutput(PathMatcher),
+    /// Matches a successful response when `true`, or an error when `false`.
+    #[smithy_schema(SUCCESS)]
+    Success(bool),
+    /// Matches a modeled error by shape name or absolute shape ID.
+    #[smithy_schema(ERROR_TYPE)]
+    ErrorType(String),
+}
+
+smithy!("smithy.waiters#AcceptorState": {
+    /// Schema for [`AcceptorState`]
+    enum ACCEPTOR_STATE {
+        SUCCESS = "success"
+        FAILURE = "failure"
+        RETRY = "retry"
+    }
+});
+
+/// State a waiter transitions to when an [`Acceptor`] matches.
+#[smithy_enum]
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(ACCEPTOR_STATE)]
+pub enum AcceptorState {
+    /// The waiter succeeded.
+    Success = "success",
+    /// The waiter failed.
+    Failure = "failure",
+    /// The waiter should try again.
+    Retry = "retry",
+}
+
+smithy!("smithy.waiters#Acceptor": {
+    /// Schema for [`Acceptor`]
+    structure ACCEPTOR {
+        STATE: ACCEPTOR_STATE = "state"
+        MATCHER: MATCHER = "matcher"
+    }
+});
+
+/// Transition of a waiter to a new state when its matcher matches.
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(ACCEPTOR)]
+pub struct Acceptor {
+    /// State to transition to.
+    #[smithy_schema(STATE)]
+    #[no_builder]
+    pub state: AcceptorState,
+    /// Condition to check.
+    #[smithy_schema(MATCHER)]
+    #[no_builder]
+    pub matcher: Matcher,
+}
+
+smithy!("smithy.waiters#Acceptors": {
+    /// Schema for a list of [`Acceptor`]s
+    list ACCEPTORS {
+        member: ACCEPTOR
+    }
+});
+
+smithy!("smithy.waiters#NonEmptyStringList": {
+    /// Schema for a list of waiter tags
+    list WAITER_TAGS {
+        member: STRING
+    }
+});
+
+smithy!("smithy.waiters#Waiter": {
+    /// Schema for [`Waiter`]
+    structure WAITER {
+        DOCUMENTATION: STRING = "documentation"
+        ACCEPTORS: ACCEPTORS = "acceptors"
+        MIN_DELAY: INTEGER = "minDelay"
+        MAX_DELAY: INTEGER = "maxDelay"
+        DEPRECATED: BOOLEAN = "deprecated"
+        TAGS: WAITER_TAGS = "tags"
+    }
+});
+
+/// Definition of a single waiter.
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(WAITER)]
+pub struct Waiter {
+    /// Documentation of the waiter.
+    #[smithy_schema(DOCUMENTATION)]
+    pub documentation: Option<String>,
+    /// Acceptors checked in order after each attempt.
+    #[smithy_schema(ACCEPTORS)]
+    pub acceptors: Vec<Acceptor>,
+    /// Minimum delay between attempts, in seconds.
+    #[smithy_schema(MIN_DELAY)]
+    pub min_delay: Option<i32>,
+    /// Maximum delay between attempts, in seconds.
+    #[smithy_schema(MAX_DELAY)]
+    pub max_delay: Option<i32>,
+    /// Whether the waiter is deprecated.
+    #[smithy_schema(DEPRECATED)]
+    pub deprecated: Option<bool>,
+    /// Tags used to categorize the waiter.
+    #[smithy_schema(TAGS)]
+    pub tags: Option<Vec<String>>,
+}
+
+smithy!("smithy.waiters#waitable": {
+    /// Schema for [`WaitableTrait`]
+    map WAITABLE {
+        key: STRING
+        value: WAITER
+    }
+});
+
+/// Waiters of an operation, keyed by waiter name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaitableTrait {
+    waiters: IndexMap<String, Waiter>,
+    expressions: PathExpressions,
+}
+
+// NOTE: Implemented by hand, as wrapper shapes derive deserialization of their
+//       items directly, while structure items can only be deserialized with a builder.
+impl StaticTraitId for WaitableTrait {
+    fn trait_id() -> &'static ShapeId {
+        WAITABLE.id()
+    }
+
+    fn to_document(&self) -> Option<Box<dyn Document>> {
+        Some(self.waiters.clone().into())
+    }
+}
+
+impl TryFromDocument for WaitableTrait {
+    fn try_from(document: Box<dyn Document>) -> Result<Self, DocumentError> {
+        let waiters = document
+            .into_map()?
+            .into_iter()
+            .map(|(name, waiter)| Ok((name, waiter.try_into()?)))
+            .collect::<Result<IndexMap<String, Waiter>, DocumentError>>()?;
+        let mut expressions = PathExpressions::default();
+        for waiter in waiters.values() {
+            expressions.parse_all(waiter)?;
+        }
+        Ok(WaitableTrait {
+            waiters,
+            expressions,
+        })
+    }
+}
+
+impl Deref for WaitableTrait {
+    type Target = IndexMap<String, Waiter>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.waiters
+    }
+}
+
+// ============================================================================
+// Waiter Behavior
+// ============================================================================
+
+const DEFAULT_MIN_DELAY: u64 = 2;
+const DEFAULT_MAX_DELAY: u64 = 120;
+
+impl Waiter {
+    /// Minimum delay between attempts (defaults to 2 seconds).
+    #[must_use]
+    pub fn min_delay(&self) -> Duration {
+        delay_secs(self.min_delay, DEFAULT_MIN_DELAY)
+    }
+
+    /// Maximum delay between attempts (defaults to 120 seconds).
+    #[must_use]
+    pub fn max_delay(&self) -> Duration {
+        delay_secs(self.max_delay, DEFAULT_MAX_DELAY)
+    }
+
+    /// Returns `true` if the waiter is deprecated.
+    #[must_use]
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated == Some(true)
+    }
+
+    /// Delay before the attempt following `attempt` (starting from 1).
+    ///
+    /// The delay doubles with each attempt, starting from the minimum delay and
+    /// capped at the maximum delay, with full jitter between the minimum delay and that value.
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let min = self.min_delay();
+        let max = self.max_delay().max(min);
+        let backoff = min
+            .checked_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .map_or(max, |delay| delay.min(max));
+        min + (backoff - min).mul_f64(random_fraction())
+    }
+
+    /// Get the state the waiter transitions to for the result of an attempt.
+    ///
+    /// Returns `None` if no acceptor matched. Waiters retry successful responses that
+    /// no acceptor matched, and fail on unmatched errors.
+    ///
+    /// Path expressions are parsed on each call. [`Operation::wait_until`] instead uses
+    /// the expressions parsed when the operation's [`WaitableTrait`] was read.
+    ///
+    /// # Errors
+    /// Returns [`DocumentError`] if the input, output, or error could not be converted to a
+    /// document, or a path expression is invalid.
+    pub fn state<I, O, E, T>(
+        &self,
+        input: &I,
+        result: &Result<O, RequestError<E, T>>,
+    ) -> Result<Option<AcceptorState>, DocumentError>
+    where
+        I: StaticSchemaShape + SerializeWithSchema,
+        O: StaticSchemaShape + SerializeWithSchema,
+    {
+        let mut expressions = PathExpressions::default();
+        expressions.parse_all(self)?;
+        self.state_with(&expressions, input, result)
+    }
+
+    fn state_with<I, O, E, T>(
+        &self,
+        expressions: &PathExpressions,
+        input: &I,
+        result: &Result<O, RequestError<E, T>>,
+    ) -> Result<Option<AcceptorState>, DocumentError>
+    where
+        I: StaticSchemaShape + SerializeWithSchema,
+        O: StaticSchemaShape + SerializeWithSchema,
+    {
+        let mut attempt = Attempt::new(input, result);
+        for acceptor in &self.acceptors {
+            if attempt.matches(expressions, &acceptor.matcher)? {
+                return Ok(Some(acceptor.state.clone()));
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn delay_secs(value: Option<i32>, default: u64) -> Duration {
+    Duration::from_secs(
+        value
+            .and_then(|secs| u64::try_from(secs).ok())
+            .unwrap_or(default),
+    )
+}
+
+/// Result of a single attempt, converting shapes to documents as matchers need them.
+struct Attempt<'a, I, O, E, T> {
+    input: &'a I,
+    result: &'a Result<O, RequestError<E, T>>,
+    output: Option<Box<dyn Document>>,
+    input_output: Option<Box<dyn Document>>,
+}
+
+impl<'a, I, O, E, T> Attempt<'a, I, O, E, T>
+where
+    I: StaticSchemaShape + SerializeWithSchema,
+    O: StaticSchemaShape + SerializeWithSchema,
+{
+    const fn new(input: &'a I, result: &'a Result<O, RequestError<E, T>>) -> Self {
+        Attempt {
+            input,
+            result,
+            output: None,
+            input_output: None,
+        }
+    }
+
+    fn matches(
+        &mut self,
+        expressions: &PathExpressions,
+        matcher: &Matcher,
+    ) -> Result<bool, DocumentError> {
+        match (matcher, self.result) {
+            (Matcher::Success(success), result) => Ok(*success == result.is_ok()),
+            (Matcher::Output(path), Ok(output)) => {
+                if self.output.is_none() {
+                    self.output = Some(output.serialize_with_schema(O::schema(), DocumentParser)?);
+                }
+                let Some(document) = &self.output else {
+                    return Ok(false);
+                };
+                Ok(path.compare(expressions.get(&path.path)?, &**document))
+            }
+            (Matcher::InputOutput(path), Ok(output)) => {
+                if self.input_output.is_none() {
+                    let input = self
+                        .input
+                        .serialize_with_schema(I::schema(), DocumentParser)?;
+                    let output = output.serialize_with_schema(O::schema(), DocumentParser)?;
+                    self.input_output = Some(doc_map!["input" => input, "output" => output].into());
+                }
+                let Some(document) = &self.input_output else {
+                    return Ok(false);
+                };
+                Ok(path.compare(expressions.get(&path.path)?, &**document))
+            }
+            (Matcher::ErrorType(expected), Err(RequestError::Modeled { id, .. })) => {
+                Ok(id.id() == expected.as_str() || id.name() == expected.as_str())
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+impl PathMatcher {
+    /// Check the value selected by the path against the expected value.
+    ///
+    /// # Errors
+    /// Returns [`DocumentError`] if the path expression is invalid.
+    pub fn matches(&self, document: &dyn Document) -> Result<bool, DocumentError> {
+        Ok(self.compare(&PathExpression::parse(&self.path)?, document))
+    }
+
+    /// Check the value selected by the parsed path against the expected value.
+    fn compare(&self, expression: &PathExpression, document: &dyn Document) -> bool {
+        let value = expression.evaluate(document);
+        match &self.comparator {
+            PathComparator::StringEquals => value.as_str() == Some(self.expected.as_str()),
+            PathComparator::BooleanEquals => value
+                .as_bool()
+                .is_some_and(|b| b.to_string() == self.expected),
+            PathComparator::AllStringEquals => value.as_list().is_some_and(|items| {
+                !items.is_empty()
+                    && items
+                        .iter()
+                        .all(|item| item.as_string() == Some(self.expected.as_str()))
+            }),
+            PathComparator::AnyStringEquals => value.as_list().is_some_and(|items| {
+                items
+                    .iter()
+                    .any(|item| item.as_string() == Some(self.expected.as_str()))
+            }),
+            _ => false,
+        }
+    }
+}
+
+// ============================================================================
+// Path Expressions
+// ============================================================================
+
+/// Parsed path expressions of the [`PathMatcher`]s of one or more waiters, keyed by
+/// expression.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct PathExpressions(IndexMap<String, PathExpression>);
+
+impl PathExpressions {
+    /// Parse the path expressions of every path matcher of a waiter.
+    fn parse_all(&mut self, waiter: &Waiter) -> Result<(), DocumentError> {
+        for acceptor in &waiter.acceptors {
+            if let Matcher::Output(path) | Matcher::InputOutput(path) = &acceptor.matcher
+                && !self.0.contains_key(&path.path)
+            {
+                let expression = PathExpression::parse(&path.path)?;
+                self.0.insert(path.path.clone(), expression);
+            }
+        }
+        Ok(())
+    }
+
+    fn get(&self, path: &str) -> Result<&PathExpression, DocumentError> {
+        self.0.get(path).ok_or_else(|| {
+            DocumentError::CustomError(format!("Path expression `{path}` was not parsed"))
+        })
+    }
+}
+
+/// Parsed path expression of a [`PathMatcher`].
+#[derive(Debug, Clone, PartialEq)]
+struct PathExpression {
+    operand: Operand,
+    comparison: Option<(Comparison, Operand)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Path(DocumentPath),
+    Length(DocumentPath),
+    Literal(Literal),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Literal {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+/// Value selected by a path expression.
+enum PathValue<'d> {
+    Null,
+    Document(&'d dyn Document),
+    List(Vec<&'d dyn Document>),
+    String(&'d str),
+    Number(f64),
+    Bool(bool),
+}
+
+impl PathValue<'_> {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            PathValue::Document(document) => document.as_string(),
+            PathValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            PathValue::Document(document) => document.as_bool(),
+            PathValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            PathValue::Document(document) => number(*document),
+            PathValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_list(&self) -> Option<Vec<&dyn Document>> {
+        match self {
+            PathValue::Document(document) => document
+                .as_list()
+                .map(|items| items.iter().map(AsRef::as_ref).collect()),
+            PathValue::List(items) => Some(items.clone()),
+            _ => None,
+        }
+    }
+
+    fn length(&self) -> Option<usize> {
+        match self {
+            PathValue::Document(document) => document
+                .as_list()
+                .map(Vec::len)
+                .or_else(|| document.as_map().map(IndexMap::len))
+                .or_else(|| document.as_string().map(|s| s.chars().count())),
+            PathValue::List(items) => Some(items.len()),
+            _ => None,
+        }
+    }
+
+    fn equals(&self, other: &PathValue<'_>) -> bool {
+        if let (Some(left), Some(right)) = (self.as_str(), other.as_str()) {
+            left == right
+        } else if let (Some(left), Some(right)) = (self.as_number(), other.as_number()) {
+            #[allow(clippy::float_cmp)]
+            let equal = left == right;
+            equal
+        } else if let (Some(left), Some(right)) = (self.as_bool(), other.as_bool()) {
+            left == right
+        } else {
+            matches!((self, other), (PathValue::Null, PathValue::Null))
+        }
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn number(document: &dyn Document) -> Option<f64> {
+    document
+        .as_long()
+        .map(|value| value as f64)
+        .or_else(|| document.as_double())
+}
+
+impl PathExpression {
+    fn parse(expression: &str) -> Result<Self, DocumentError> {
+        let mut parser = Parser {
+            expression,
+            rest: expression.trim(),
+        };
+        let operand = parser.operand()?;
+        let comparison = if parser.rest.trim().is_empty() {
+            None
+        } else {
+            let comparison = parser.comparison()?;
+            Some((comparison, parser.operand()?))
+        };
+        if !parser.rest.trim().is_empty() {
+            return Err(parser.error("unexpected trailing characters"));
+        }
+        Ok(PathExpression {
+            operand,
+            comparison,
+        })
+    }
+
+    fn evaluate<'d>(&'d self, document: &'d dyn Document) -> PathValue<'d> {
+        let left = self.operand.evaluate(document);
+        let Some((comparison, right)) = &self.comparison else {
+            return left;
+        };
+        let right = right.evaluate(document);
+        let result = match comparison {
+            Comparison::Eq => left.equals(&right),
+            Comparison::Ne => !left.equals(&right),
+            ordering => {
+                let (Some(left), Some(right)) = (left.as_number(), right.as_number()) else {
+                    return PathValue::Null;
+                };
+                match ordering {
+                    Comparison::Lt => left < right,
+                    Comparison::Le => left <= right,
+                    Comparison::Gt => left > right,
+                    _ => left >= right,
+                }
+            }
+        };
+        PathValue::Bool(result)
+    }
+}
+
+impl Operand {
+    fn evaluate<'d>(&'d self, document: &'d dyn Document) -> PathValue<'d> {
+        match self {
+            Operand::Path(path) => select(document, path),
+            #[allow(clippy::cast_precision_loss)]
+            Operand::Length(path) => select(document, path)
+                .length()
+                .map_or(PathValue::Null, |len| PathValue::Number(len as f64)),
+            Operand::Literal(Literal::Null) => PathValue::Null,
+            Operand::Literal(Literal::Bool(value)) => PathValue::Bool(*value),
+            Operand::Literal(Literal::Number(value)) => PathValue::Number(*value),
+            Operand::Literal(Literal::String(value)) => PathValue::String(value),
+        }
+    }
+}
+
+/// Select the value at a path, collecting the values of any projections into a list.
+fn select<'d>(document: &'d dyn Document, path: &DocumentPath) -> PathValue<'d> {
+    if path.is_projection() {
+        PathValue::List(path.select(document))
+    } else {
+        path.get(document)
+            .map_or(PathValue::Null, PathValue::Document)
+    }
+}
+
+struct Parser<'e> {
+    expression: &'e str,
+    rest: &'e str,
+}
+
+impl Parser<'_> {
+    fn operand(&mut self) -> Result<Operand, DocumentError> {
+        self.rest = self.rest.trim_start();
+        if self.rest.starts_with(['`', '\'']) {
+            Ok(Operand::Literal(self.literal()?))
+        } else if let Some(rest) = self.rest.strip_prefix("length(") {
+            self.rest = rest.trim_start();
+            let path = self.path()?;
+            self.expect(")")?;
+            Ok(Operand::Length(path))
+        } else {
+            Ok(Operand::Path(self.path()?))
+        }
+    }
+
+    fn path(&mut self) -> Result<DocumentPath, DocumentError> {
+        parse_prefix(&mut self.rest).map_err(|message| self.error(message))
+    }
+
+    fn comparison(&mut self) -> Result<Comparison, DocumentError> {
+        self.rest = self.rest.trim_start();
+        for (token, comparison) in [
+            ("==", Comparison::Eq),
+            ("!=", Comparison::Ne),
+            ("<=", Comparison::Le),
+            (">=", Comparison::Ge),
+            ("<", Comparison::Lt),
+            (">", Comparison::Gt),
+        ] {
+            if let Some(rest) = self.rest.strip_prefix(token) {
+                self.rest = rest.trim_start();
+                return Ok(comparison);
+            }
+        }
+        Err(self.error("expected comparison"))
+    }
+
+    fn literal(&mut self) -> Result<Literal, DocumentError> {
+        if let Some(rest) = self.rest.strip_prefix('\'') {
+            let end = rest.find('\'').ok_or_else(|| self.error("unclosed `'`"))?;
+            self.rest = &rest[end + 1..];
+            return Ok(Literal::String(rest[..end].to_string()));
+        }
+        let rest = self
+            .rest
+            .strip_prefix('`')
+            .ok_or_else(|| self.error("expected literal"))?;
+        let end = rest.find('`').ok_or_else(|| self.error("unclosed '`'"))?;
+        let literal = rest[..end].trim();
+        self.rest = &rest[end + 1..];
+        Ok(match literal {
+            "null" => Literal::Null,
+            "true" => Literal::Bool(true),
+            "false" => Literal::Bool(false),
+            string if string.len() >= 2 && string.starts_with('"') && string.ends_with('"') => {
+                Literal::String(string[1..string.len() - 1].to_string())
+            }
+            number => Literal::Number(
+                number
+                    .parse()
+                    .map_err(|_| self.error(format!("invalid literal `{number}`")))?,
+            ),
+        })
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), DocumentError> {
+        self.rest = self
+            .rest
+            .trim_start()
+            .strip_prefix(token)
+            .ok_or_else(|| self.error(format!("expected `{token}`")))?;
+        Ok(())
+    }
+
+    fn error(&self, message: impl Display) -> DocumentError {
+        DocumentError::CustomError(format!(
+            "Invalid waiter path `{}`: {message}",
+            self.expression
+        ))
+    }
+}
+
+// ============================================================================
+// Waiting
+// ============================================================================
+
+/// Error returned by a single request made by a waiter.
+#[derive(Debug)]
+pub enum RequestError<E, T> {
+    /// The operation returned a modeled error.
+    Modeled {
+        /// Shape ID of the error, used to match `errorType` acceptors
+        id: ShapeId,
+        /// The deserialized error
+        error: E,
+    },
+    /// The request failed for any other reason (transport, serialization, etc.).
+    Other(T),
+}
+
+/// Error returned when a waiter does not reach a success state.
+#[derive(Error, Debug)]
+pub enum WaiterError<E, T> {
+    /// The operation has no waiter with the given name.
+    #[error("Operation has no waiter named `{0}`")]
+    UnknownWaiter(String),
+    /// A `failure` acceptor matched.
+    #[error("Waiter `{0}` reached a failure state")]
+    Failure(String),
+    /// A request failed with an error that no acceptor matched.
+    #[error("Waiter `{waiter}` encountered an unexpected error")]
+    Unexpected {
+        /// Name of the waiter
+        waiter: String,
+        /// Error returned by the request
+        error: RequestError<E, T>,
+    },
+    /// The maximum wait time elapsed before the waiter reached a success or failure state.
+    #[error("Waiter `{waiter}` timed out after {attempts} attempts")]
+    Timeout {
+        /// Name of the waiter
+        waiter: String,
+        /// Number of requests made
+        attempts: u32,
+    },
+    /// The waiter definition could not be evaluated.
+    #[error(transparent)]
+    Document(#[from] DocumentError),
+}
+
+/// Progress of a waiter after an attempt.
+enum Step<O> {
+    Done(Option<O>),
+    Sleep(Duration),
+}
+
+/// Tracks the attempts and wait time of a waiter.
+struct WaitState<'w> {
+    name: &'w str,
+    waiter: &'w Waiter,
+    expressions: &'w PathExpressions,
+    max_wait: Duration,
+    waited: Duration,
+    attempts: u32,
+}
+
+impl<'w> WaitState<'w> {
+    fn new<E, T>(
+        schema: &'w Schema,
+        name: &'w str,
+        max_wait: Duration,
+    ) -> Result<Self, WaiterError<E, T>> {
+        let waitable = schema.get_trait_as::<WaitableTrait>();
+        let (waiter, expressions) = waitable
+            .and_then(|waitable| Some((waitable.get(name)?, &waitable.expressions)))
+            .ok_or_else(|| WaiterError::UnknownWaiter(name.to_string()))?;
+        Ok(WaitState {
+            name,
+            waiter,
+            expressions,
+            max_wait,
+            waited: Duration::ZERO,
+            attempts: 0,
+        })
+    }
+
+    fn step<I, O, E, T>(
+        &mut self,
+        input: &I,
+        result: Result<O, RequestError<E, T>>,
+    ) -> Result<Step<O>, WaiterError<E, T>>
+    where
+        I: StaticSchemaShape + SerializeWithSchema,
+        O: StaticSchemaShape + SerializeWithSchema,
+    {
+        self.attempts += 1;
+        match (
+            self.waiter.state_with(self.expressions, input, &result)?,
+            result,
+        ) {
+            (Some(AcceptorState::Success), result) => return Ok(Step::Done(result.ok())),
+            (Some(AcceptorState::Retry), _) | (None, Ok(_)) => {}
+            (None, Err(error)) => {
+                return Err(WaiterError::Unexpected {
+                    waiter: self.name.to_string(),
+                    error,
+                });
+            }
+            (Some(_), _) => return Err(WaiterError::Failure(self.name.to_string())),
+        }
+
+        // Never sleep past the maximum wait time, leaving room for a final attempt
+        let min_delay = self.waiter.min_delay();
+        let remaining = self.max_wait.saturating_sub(self.waited);
+        if remaining <= min_delay {
+            return Err(WaiterError::Timeout {
+                waiter: self.name.to_string(),
+                attempts: self.attempts,
+            });
+        }
+        let delay = self
+            .waiter
+            .delay(self.attempts)
+            .min(remaining - min_delay)
+            .max(min_delay);
+        self.waited += delay;
+        Ok(Step::Sleep(delay))
+    }
+}
+
+impl<I, O, E> Operation<I, O, E>
+where
+    I: StaticSchemaShape + SerializeWithSchema + Clone,
+    O: StaticSchemaShape + SerializeWithSchema,
+{
+    /// Get a waiter of the operation by name.
+    #[must_use]
+    pub fn waiter(&self, name: &str) -> Option<&'static Waiter> {
+        self.schema()
+            .get_trait_as::<WaitableTrait>()
+            .and_then(|waitable| waitable.get(name))
+    }
+
+    /// Poll the operation until the named waiter reaches a success or failure state.
+    ///
+    /// `call` sends a single request with a copy of `input`, and `sleep` waits for the given
+    /// duration between attempts (for example `tokio::time::sleep`). Waiting stops once the
+    /// total time slept would exceed `max_wait`. Time spent in requests is not counted.
+    ///
+    /// Returns the output of the final request, or `None` if the waiter succeeded
+    /// by matching an error.
+    ///
+    /// # Errors
+    /// Returns [`WaiterError`] if the waiter does not exist, fails, or times out.
+    pub async fn wait_until<F, Fut, T, S, SFut>(
+        &self,
+        name: &str,
+        input: &I,
+        max_wait: Duration,
+        mut call: F,
+        mut sleep: S,
+    ) -> Result<Option<O>, WaiterError<E, T>>
+    where
+        F: FnMut(I) -> Fut,
+        Fut: Future<Output = Result<O, RequestError<E, T>>>,
+        S: FnMut(Duration) -> SFut,
+        SFut: Future<Output = ()>,
+    {
+        let mut state = WaitState::new(self.schema(), name, max_wait)?;
+        loop {
+            let result = call(input.clone()).await;
+            match state.step(input, result)? {
+                Step::Done(output) => return Ok(output),
+                Step::Sleep(delay) => sleep(delay).await,
+            }
+        }
+    }
+
+    /// Blocking version of [`Operation::wait_until`].
+    ///
+    /// # Errors
+    /// Returns [`WaiterError`] if the waiter does not exist, fails, or times out.
+    pub fn wait_until_blocking<F, T, S>(
+        &self,
+        name: &str,
+        input: &I,
+        max_wait: Duration,
+        mut call: F,
+        mut sleep: S,
+    ) -> Result<Option<O>, WaiterError<E, T>>
+    where
+        F: FnMut(I) -> Result<O, RequestError<E, T>>,
+        S: FnMut(Duration),
+    {
+        let mut state = WaitState::new(self.schema(), name, max_wait)?;
+        loop {
+            match state.step(input, call(input.clone()))? {
+                Step::Done(output) => return Ok(output),
+                Step::Sleep(delay) => sleep(delay),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use smithy4rs_test_utils::ready;
+
+    use super::*;
+    use crate::{
+        prelude::INTEGER,
+        schema::{DynamicTrait, Unit},
+    };
+
+    smithy!("com.example#GetItemInput": {
+        structure GET_ITEM_INPUT_SCHEMA {
+            ID: STRING = "id"
+        }
+    });
+    smithy!("com.example#Tags": {
+        list TAGS_SCHEMA {
+            member: STRING
+        }
+    });
+    smithy!("com.example#GetItemOutput": {
+        structure GET_ITEM_OUTPUT_SCHEMA {
+            ID: STRING = "id"
+            STATUS: STRING = "status"
+            TAGS: TAGS_SCHEMA = "tags"
+            SIZE: INTEGER = "size"
+        }
+    });
+
+    fn acceptor(state: &str, matcher: Box<dyn Document>) -> Box<dyn Document> {
+        doc_map!["state" => state, "matcher" => matcher].into()
+    }
+
+    fn path(path: &str, expected: &str, comparator: &str) -> Box<dyn Document> {
+        doc_map!["path" => path, "expected" => expected, "comparator" => comparator].into()
+    }
+
+    smithy!("com.example#GetItem": {
+        @DynamicTrait::from("smithy.waiters#waitable", doc_map![
+            "ItemActive" => doc_map![
+                "acceptors" => vec![
+                    acceptor("success", doc_map!["output" => path("status", "ACTIVE", "stringEquals")].into()),
+                    acceptor("failure", doc_map!["output" => path("tags", "broken", "anyStringEquals")].into()),
+                    acceptor("retry", doc_map!["errorType" => "NotFound"].into()),
+                ],
+                "minDelay" => 1,
+                "maxDelay" => 4,
+            ],
+            "ItemDeleted" => doc_map![
+                "documentation" => "Wait until the item is deleted",
+                "acceptors" => vec![
+                    acceptor("success", doc_map!["errorType" => "com.example#NotFound"].into()),
+                    acceptor("retry", doc_map!["inputOutput" => path("input.id == output.id", "true", "booleanEquals")].into()),
+                ],
+            ],
+        ]);
+        operation GET_ITEM_SCHEMA
+    });
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(GET_ITEM_INPUT_SCHEMA)]
+    pub struct GetItemInput {
+        #[smithy_schema(ID)]
+        id: String,
+    }
+
+    #[derive(SmithyShape, Clone, PartialEq)]
+    #[smithy_schema(GET_ITEM_OUTPUT_SCHEMA)]
+    pub struct GetItemOutput {
+        #[smithy_schema(ID)]
+        id: String,
+        #[smithy_schema(STATUS)]
+        status: String,
+        #[smithy_schema(TAGS)]
+        tags: Vec<String>,
+        #[smithy_schema(SIZE)]
+        size: Option<i32>,
+    }
+
+    static GET_ITEM: Operation<GetItemInput, GetItemOutput, Unit> =
+        Operation::new(&GET_ITEM_SCHEMA);
+
+    type Response = Result<GetItemOutput, RequestError<Unit, String>>;
+    type WaitResult = Result<Option<GetItemOutput>, WaiterError<Unit, String>>;
+
+    fn input() -> GetItemInput {
+        GetItemInput {
+            id: "item".to_string(),
+        }
+    }
+
+    fn output(status: &str, tags: &[&str]) -> GetItemOutput {
+        GetItemOutput {
+            id: "item".to_string(),
+            status: status.to_string(),
+            tags: tags.iter().map(|t| (*t).to_string()).collect(),
+            size: Some(3),
+        }
+    }
+
+    fn not_found() -> Response {
+        Err(RequestError::Modeled {
+            id: ShapeId::from("com.example#NotFound"),
+            error: Unit,
+        })
+    }
+
+    /// Wait with responses returned in order, recording each delay.
+    fn wait(
+        name: &str,
+        max_wait: Duration,
+        responses: Vec<Response>,
+    ) -> (WaitResult, Vec<Duration>) {
+        let mut responses = responses.into_iter();
+        let mut delays = Vec::new();
+        let result = GET_ITEM.wait_until_blocking(
+            name,
+            &input(),
+            max_wait,
+            |_| responses.next().expect("no more responses"),
+            |delay| delays.push(delay),
+        );
+        (result, delays)
+    }
+
+    fn evaluate(expression: &str, document: &dyn Document) -> Option<String> {
+        let expression = PathExpression::parse(expression).unwrap();
+        match expression.evaluate(document) {
+            PathValue::Null => None,
+            PathValue::String(value) => Some(format!("{:?}", Some(value))),
+            PathValue::Document(document) => Some(format!("{:?}", document.as_string())),
+            PathValue::List(items) => Some(format!(
+                "{:?}",
+                items.iter().map(|i| i.as_string()).collect::<Vec<_>>()
+            )),
+            PathValue::Number(number) => Some(number.to_string()),
+            PathValue::Bool(value) => Some(value.to_string()),
+        }
+    }
+
+    #[test]
+    fn reads_waiters_from_dynamic_trait() {
+        let waiter = GET_ITEM.waiter("ItemActive").unwrap();
+        assert_eq!(waiter.acceptors.len(), 3);
+        assert_eq!(waiter.min_delay(), Duration::from_secs(1));
+        assert_eq!(waiter.max_delay(), Duration::from_secs(4));
+        assert_eq!(waiter.acceptors[0].state, AcceptorState::Success);
+        assert!(matches!(
+            &waiter.acceptors[2].matcher,
+            Matcher::ErrorType(error) if error == "NotFound"
+        ));
+
+        let deleted = GET_ITEM.waiter("ItemDeleted").unwrap();
+        assert_eq!(
+            deleted.documentation.as_deref(),
+            Some("Wait until the item is deleted")
+        );
+        assert_eq!(deleted.min_delay(), Duration::from_secs(2));
+        assert_eq!(deleted.max_delay(), Duration::from_secs(120));
+        assert!(GET_ITEM.waiter("Missing").is_none());
+    }
+
+    #[test]
+    fn evaluates_path_expressions() {
+        let document: Box<dyn Document> = doc_map![
+            "name" => "a",
+            "items" => vec![
+                Box::<dyn Document>::from(doc_map!["state" => "on", "tags" => vec!["x", "y"]]),
+                doc_map!["state" => "off", "tags" => vec!["z"]].into(),
+                doc_map!["other" => 1].into(),
+            ],
+            "nested" => doc_map!["count" => 2],
+        ]
+        .into();
+
+        assert_eq!(evaluate("name", &*document), Some("Some(\"a\")".into()));
+        assert_eq!(evaluate("missing.field", &*document), None);
+        assert_eq!(
+            evaluate("items[0].state", &*document),
+            Some("Some(\"on\")".into())
+        );
+        assert_eq!(
+            evaluate("items[-2].state", &*document),
+            Some("Some(\"off\")".into())
+        );
+        assert_eq!(evaluate("items[5].state", &*document), None);
+        assert_eq!(
+            evaluate("items[].state", &*document),
+            Some("[Some(\"on\"), Some(\"off\")]".into())
+        );
+        assert_eq!(
+            evaluate("items[*].tags[]", &*document),
+            Some("[Some(\"x\"), Some(\"y\"), Some(\"z\")]".into())
+        );
+        assert_eq!(evaluate("length(items)", &*document), Some("3".into()));
+        assert_eq!(
+            evaluate("length(items[].state) > `1`", &*document),
+            Some("true".into())
+        );
+        assert_eq!(
+            evaluate("nested.count == `2`", &*document),
+            Some("true".into())
+        );
+        assert_eq!(evaluate("name != 'a'", &*document), Some("false".into()));
+        assert_eq!(evaluate("name == `\"a\"`", &*document), Some("true".into()));
+    }
+
+    #[test]
+    fn rejects_invalid_path_expressions() {
+        for expression in [
+            "", "a.", "a[", "a[x]", "length(a", "a ~ `1`", "a == `x`", "a b",
+        ] {
+            assert!(
+                PathExpression::parse(expression).is_err(),
+                "expected `{expression}` to be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_path_expressions_with_trait() {
+        let waitable = GET_ITEM_SCHEMA.get_trait_as::<WaitableTrait>().unwrap();
+        let paths = waitable.expressions.0.keys().collect::<Vec<_>>();
+        assert_eq!(paths, ["status", "tags", "input.id == output.id"]);
+
+        let invalid: Box<dyn Document> = doc_map![
+            "Broken" => doc_map![
+                "acceptors" => vec![acceptor("success", doc_map!["output" => path("a[", "x", "stringEquals")].into())],
+            ],
+        ]
+        .into();
+        assert!(<WaitableTrait as TryFromDocument>::try_from(invalid).is_err());
+    }
+
+    #[test]
+    fn compares_path_results() {
+        let document: Box<dyn Document> =
+            doc_map!["flag" => true, "states" => vec!["ok", "ok"], "empty" => Vec::<String>::new()]
+                .into();
+        let matches = |path: &str, expected: &str, comparator| {
+            PathMatcher {
+                path: path.to_string(),
+                expected: expected.to_string(),
+                comparator,
+            }
+            .matches(&*document)
+            .unwrap()
+        };
+
+        assert!(matches("flag", "true", PathComparator::BooleanEquals));
+        assert!(!matches("flag", "false", PathComparator::BooleanEquals));
+        assert!(matches("states", "ok", PathComparator::AllStringEquals));
+        assert!(!matches("empty", "ok", PathComparator::AllStringEquals));
+        assert!(matches("states[]", "ok", PathComparator::AnyStringEquals));
+        assert!(!matches("states", "bad", PathComparator::AnyStringEquals));
+        assert!(matches("states[0]", "ok", PathComparator::StringEquals));
+    }
+
+    #[test]
+    fn delay_stays_within_bounds() {
+        let waiter = GET_ITEM.waiter("ItemActive").unwrap();
+        for attempt in 1..10 {
+            let delay = waiter.delay(attempt);
+            assert!(delay >= Duration::from_secs(1), "{delay:?}");
+            assert!(delay <= Duration::from_secs(4), "{delay:?}");
+        }
+        assert_eq!(waiter.delay(1), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn waits_until_success() {
+        let (result, delays) = wait(
+            "ItemActive",
+            Duration::from_secs(60),
+            vec![
+                not_found(),
+                Ok(output("CREATING", &[])),
+                Ok(output("ACTIVE", &[])),
+            ],
+        );
+        assert!(matches!(result, Ok(Some(output)) if output.status == "ACTIVE"));
+        assert_eq!(delays.len(), 2);
+    }
+
+    #[test]
+    fn stops_on_failure_acceptor() {
+        let (result, _) = wait(
+            "ItemActive",
+            Duration::from_secs(60),
+            vec![Ok(output("CREATING", &["broken"]))],
+        );
+        assert!(matches!(result, Err(WaiterError::Failure(name)) if name == "ItemActive"));
+    }
+
+    #[test]
+    fn stops_on_unmatched_error() {
+        let (result, _) = wait(
+            "ItemActive",
+            Duration::from_secs(60),
+            vec![Err(RequestError::Other("connection reset".to_string()))],
+        );
+        assert!(matches!(
+            result,
+            Err(WaiterError::Unexpected {
+                error: RequestError::Other(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn succeeds_on_matched_error() {
+        let (result, delays) = wait(
+            "ItemDeleted",
+            Duration::from_secs(60),
+            vec![Ok(output("ACTIVE", &[])), not_found()],
+        );
+        assert!(matches!(result, Ok(None)));
+        assert_eq!(delays.len(), 1);
+    }
+
+    #[test]
+    fn times_out() {
+        let (result, delays) = wait(
+            "ItemActive",
+            Duration::from_secs(5),
+            (0..10).map(|_| Ok(output("CREATING", &[]))).collect(),
+        );
+        assert!(matches!(result, Err(WaiterError::Timeout { .. })));
+        assert!(delays.iter().sum::<Duration>() <= Duration::from_secs(5));
+    }
+
+    #[test]
+    fn rejects_unknown_waiter() {
+        let (result, _) = wait("Missing", Duration::from_secs(5), vec![]);
+        assert!(matches!(result, Err(WaiterError::UnknownWaiter(name)) if name == "Missing"));
+    }
+
+    #[test]
+    fn waits_asynchronously() {
+        let calls = Cell::new(0);
+        let sleeps = Cell::new(0);
+        let input = input();
+        let future = GET_ITEM.wait_until(
+            "ItemActive",
+            &input,
+            Duration::from_secs(60),
+            |_| {
+                calls.set(calls.get() + 1);
+                let status = if calls.get() < 3 {
+                    "CREATING"
+                } else {
+                    "ACTIVE"
+                };
+                let response: Response = Ok(output(status, &[]));
+                async move { response }
+            },
+            |_| {
+                sleeps.set(sleeps.get() + 1);
+                async {}
+            },
+        );
+        let result = ready(future);
+        assert!(matches!(result, Ok(Some(output)) if output.status == "ACTIVE"));
+        assert_eq!(calls.get(), 3);
+        assert_eq!(sleeps.get(), 2);
+    }
+}
@@ -0,0 +1,227 @@
+//! # Writing a third-party codec
+//!
+//! This crate implements a minimal serializer that flattens a shape into
+//! `path=value` lines using only the stable codec extension points exported by
+//! `smithy4rs-core` (see the [`smithy4rs_core::serde`] module documentation):
+//!
+//! - [`Serializer`] writes primitives and opens compound values.
+//! - [`StructWriter`], [`ListWriter`] and [`MapWriter`] write the contents of compound values.
+//! - [`Error`](SerializerError) lets shared code (for example generated shapes) create codec errors.
+//!
+//! Only the data types this format can represent are implemented. All other
+//! `write_*` methods fall back to their default implementations, which return
+//! an error. Converting the codec error into the shared [`CodecError`] lets
+//! callers handle errors from any codec in the same way.
+//!
+//! ```text
+//! name=Ferris
+//! tags.0=crab
+//! address.city=Seattle
+//! ```
+use std::fmt::Display;
+
+use smithy4rs_core::{
+    schema::Schema,
+    serde::{
+        CodecError,
+        se::{
+            Error as SerializerError, ListWriter, MapWriter, SerializeWithSchema, Serializer,
+            StructWriter,
+        },
+    },
+};
+use thiserror::Error;
+
+/// Error returned by the [`KeyValueSerializer`].
+#[derive(Error, Debug)]
+#[error("Failed to serialize: {0}")]
+pub struct KeyValueError(String);
+
+impl SerializerError for KeyValueError {
+    fn custom<T: Display>(msg: T) -> Self {
+        KeyValueError(msg.to_string())
+    }
+}
+
+impl From<KeyValueError> for CodecError {
+    fn from(value: KeyValueError) -> Self {
+        CodecError::Custom(value.0)
+    }
+}
+
+/// Serializer that writes each value in a shape as a `path=value` line.
+///
+/// Struct members are written using their member name, list elements using
+/// their index, and map entries as `<index>.key` and `<index>.value` pairs.
+pub struct KeyValueSerializer<'a> {
+    output: &'a mut String,
+    path: String,
+}
+
+impl<'a> KeyValueSerializer<'a> {
+    /// Create a new serializer that appends lines to `output`.
+    pub const fn new(output: &'a mut String) -> Self {
+        KeyValueSerializer {
+            output,
+            path: String::new(),
+        }
+    }
+
+    fn write_line(self, value: impl Display) {
+        self.output.push_str(&self.path);
+        self.output.push('=');
+        self.output.push_str(&value.to_string());
+        self.output.push('\n');
+    }
+
+    fn writer(self) -> KeyValueWriter<'a> {
+        KeyValueWriter {
+            output: self.output,
+            path: self.path,
+            index: 0,
+        }
+    }
+}
+
+impl<'a> Serializer for KeyValueSerializer<'a> {
+    type Error = KeyValueError;
+    type Ok = ();
+    type ListWriter = KeyValueWriter<'a>;
+    type MapWriter = KeyValueWriter<'a>;
+    type StructWriter = KeyValueWriter<'a>;
+
+    fn write_struct(
+        self,
+        _schema: &Schema,
+        _len: usize,
+    ) -> Result<Self::StructWriter, Self::Error> {
+        Ok(self.writer())
+    }
+
+    fn write_map(self, _schema: &Schema, _len: usize) -> Result<Self::MapWriter, Self::Error> {
+        Ok(self.writer())
+    }
+
+    fn write_list(self, _schema: &Schema, _len: usize) -> Result<Self::ListWriter, Self::Error> {
+        Ok(self.writer())
+    }
+
+    fn write_boolean(self, _schema: &Schema, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.write_line(value);
+        Ok(())
+    }
+
+    fn write_integer(self, _schema: &Schema, value: i32) -> Result<Self::Ok, Self::Error> {
+        self.write_line(value);
+        Ok(())
+    }
+
+    fn write_long(self, _schema: &Schema, value: i64) -> Result<Self::Ok, Self::Error> {
+        self.write_line(value);
+        Ok(())
+    }
+
+    fn write_double(self, _schema: &Schema, value: f64) -> Result<Self::Ok, Self::Error> {
+        self.write_line(value);
+        Ok(())
+    }
+
+    fn write_string(self, _schema: &Schema, value: &str) -> Result<Self::Ok, Self::Error> {
+        self.write_line(value);
+        Ok(())
+    }
+
+    fn write_null(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        self.write_line("null");
+        Ok(())
+    }
+
+    fn skip(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+/// Writer for the contents of structs, lists and maps.
+pub struct KeyValueWriter<'a> {
+    output: &'a mut String,
+    path: String,
+    index: usize,
+}
+
+impl KeyValueWriter<'_> {
+    fn child(&mut self, segment: impl Display) -> KeyValueSerializer<'_> {
+        let path = if self.path.is_empty() {
+            segment.to_string()
+        } else {
+            format!("{}.{segment}", self.path)
+        };
+        KeyValueSerializer {
+            output: self.output,
+            path,
+        }
+    }
+}
+
+impl StructWriter for KeyValueWriter<'_> {
+    type Error = KeyValueError;
+    type Ok = ();
+
+    fn write_member<T>(&mut self, member_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let Some(member) = member_schema.as_member() else {
+            return Err(KeyValueError::custom("Expected member schema"));
+        };
+        let name = member.name().to_string();
+        value.serialize_with_schema(member_schema, self.child(name))
+    }
+
+    fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+impl ListWriter for KeyValueWriter<'_> {
+    type Error = KeyValueError;
+    type Ok = ();
+
+    fn write_element<T>(&mut self, element_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let index = self.index;
+        self.index += 1;
+        value.serialize_with_schema(element_schema, self.child(index))
+    }
+
+    fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+impl MapWriter for KeyValueWriter<'_> {
+    type Error = KeyValueError;
+    type Ok = ();
+
+    fn write_entry<K, V>(
+        &mut self,
+        key_schema: &Schema,
+        value_schema: &Schema,
+        key: &K,
+        value: &V,
+    ) -> Result<(), Self::Error>
+    where
+        K: SerializeWithSchema,
+        V: SerializeWithSchema,
+    {
+        let index = self.index;
+        self.index += 1;
+        key.serialize_with_schema(key_schema, self.child(format!("{index}.key")))?;
+        value.serialize_with_schema(value_schema, self.child(format!("{index}.value")))
+    }
+
+    fn end(self, _schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use smithy4rs_codec_author_example::{KeyValueError, KeyValueSerializer};
+use smithy4rs_core::{
+    IndexMap, Instant,
+    derive::SmithyShape,
+    schema::prelude::{INTEGER, STRING, TIMESTAMP},
+    serde::{CodecError, se::SerializableShape},
+    smithy,
+};
+
+smithy!("test#Tags": {
+    list TAGS {
+        member: STRING
+    }
+});
+
+smithy!("test#Counts": {
+    map COUNTS {
+        key: STRING
+        value: INTEGER
+    }
+});
+
+smithy!("test#Address": {
+    structure ADDRESS_SCHEMA {
+        CITY: STRING = "city"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(ADDRESS_SCHEMA)]
+pub struct Address {
+    #[smithy_schema(CITY)]
+    city: String,
+}
+
+smithy!("test#Person": {
+    structure PERSON_SCHEMA {
+        NAME: STRING = "name"
+        AGE: INTEGER = "age"
+        TAGS: TAGS = "tags"
+        COUNTS: COUNTS = "counts"
+        ADDRESS: ADDRESS_SCHEMA = "address"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(PERSON_SCHEMA)]
+pub struct Person {
+    #[smithy_schema(NAME)]
+    name: String,
+    #[smithy_schema(AGE)]
+    age: Option<i32>,
+    #[smithy_schema(TAGS)]
+    tags: Vec<String>,
+    #[smithy_schema(COUNTS)]
+    counts: IndexMap<String, i32>,
+    #[smithy_schema(ADDRESS)]
+    address: Address,
+}
+
+smithy!("test#Event": {
+    structure EVENT_SCHEMA {
+        AT: TIMESTAMP = "at"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(EVENT_SCHEMA)]
+pub struct Event {
+    #[smithy_schema(AT)]
+    at: Instant,
+}
+
+#[test]
+fn flattens_shape_into_lines() {
+    let mut counts = IndexMap::new();
+    counts.insert("claws".to_string(), 2);
+    let person = Person {
+        name: "Ferris".to_string(),
+        age: None,
+        tags: vec!["crab".to_string(), "rust".to_string()],
+        counts,
+        address: Address {
+            city: "Seattle".to_string(),
+        },
+    };
+
+    let mut output = String::new();
+    person
+        .serialize(KeyValueSerializer::new(&mut output))
+        .unwrap();
+    assert_eq!(
+        output,
+        "name=Ferris\n\
+         tags.0=crab\n\
+         tags.1=rust\n\
+         counts.0.key=claws\n\
+         counts.0.value=2\n\
+         address.city=Seattle\n"
+    );
+}
+
+#[test]
+fn unsupported_types_use_default_error() {
+    let event = Event {
+        at: Instant::from_epoch_milliseconds(0).unwrap(),
+    };
+    let mut output = String::new();
+    let err: KeyValueError = event
+        .serialize(KeyValueSerializer::new(&mut output))
+        .unwrap_err();
+    assert_eq!(
+        CodecError::from(err).to_string(),
+        "write_timestamp is not supported by this serializer"
+    );
+}
@@ -0,0 +1,637 @@
+//! # Authentication
+//!
+//! Smithy services declare the [auth schemes](https://smithy.io/2.0/spec/authentication-traits.html)
+//! they support by applying auth definition traits (such as `@httpBearerAuth`) to the service
+//! shape. An [`AuthScheme`] implements one of those traits and is identified by the trait's
+//! [`ShapeId`]. Each scheme pairs:
+//! - An [`IdentityResolver`] that provides the [`Identity`] (token, API key, or credentials) to use.
+//! - A [`Signer`] that applies the identity to a serialized HTTP request.
+//!
+//! An [`AuthSchemeRegistry`] selects the scheme to use for an operation from the `@auth` and
+//! `@optionalAuth` traits and signs requests with it:
+//!
+//! ```rust,ignore
+//! let auth = AuthSchemeRegistry::new()
+//!     .with(DefaultAuthScheme::bearer(Identity::Token(token)));
+//!
+//! let mut request = protocol.serialize_request(http, &input)?;
+//! auth.sign(&SERVICE_SCHEMA, &GET_CITY_SCHEMA, &mut request)?;
+//! ```
+//!
+//! `httpBearerAuth` and `httpApiKeyAuth` signers are provided by this crate, along with an
+//! `aws.auth#sigv4` signer when the `sigv4` feature is enabled (see [`sigv4`](crate::sigv4)).
+//! Other schemes are supported by registering a [`Signer`] for their ID:
+//!
+//! ```rust,ignore
+//! let auth = AuthSchemeRegistry::new()
+//!     .with(DefaultAuthScheme::new(HTTP_BASIC_AUTH, credentials_provider, BasicSigner));
+//! ```
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+use http::{
+    HeaderValue, Request, Uri,
+    header::{AUTHORIZATION, HeaderName, InvalidHeaderName, InvalidHeaderValue},
+    uri::InvalidUri,
+};
+use percent_encoding::utf8_percent_encode;
+use smithy4rs_core::{
+    IndexMap,
+    derive::{SmithyShape, SmithyTraitImpl},
+    prelude::{
+        AuthTrait, HttpApiKeyLocations, HttpBearerAuthTrait, OptionalAuthTrait, RequiredTrait,
+        STRING,
+    },
+    schema::{Schema, ShapeId, StaticTraitId},
+    smithy,
+};
+use thiserror::Error;
+
+use crate::message::ENCODE_SET;
+
+/// Shape ID of the [AWS Signature Version 4](https://smithy.io/2.0/aws/aws-auth.html#aws-auth-sigv4-trait) auth trait.
+pub const SIGV4: &str = "aws.auth#sigv4";
+/// Shape ID of the `@httpBearerAuth` trait.
+pub const HTTP_BEARER_AUTH: &str = "smithy.api#httpBearerAuth";
+/// Shape ID of the `@httpApiKeyAuth` trait.
+pub const HTTP_API_KEY_AUTH: &str = "smithy.api#httpApiKeyAuth";
+/// Shape ID of the `@httpBasicAuth` trait.
+pub const HTTP_BASIC_AUTH: &str = "smithy.api#httpBasicAuth";
+/// Shape ID of the `@httpDigestAuth` trait.
+pub const HTTP_DIGEST_AUTH: &str = "smithy.api#httpDigestAuth";
+
+/// Auth traits that are checked for on a service even if no scheme is registered for them.
+const KNOWN_AUTH_TRAITS: [&str; 5] = [
+    HTTP_API_KEY_AUTH,
+    HTTP_BASIC_AUTH,
+    HTTP_BEARER_AUTH,
+    HTTP_DIGEST_AUTH,
+    SIGV4,
+];
+
+// ============================================================================
+// API Key Trait
+// ============================================================================
+
+smithy!("smithy.api#httpApiKeyAuth": {
+    /// Schema for [`HttpApiKeyAuthTrait`]
+    structure HTTP_API_KEY_AUTH_SCHEMA {
+        @RequiredTrait::builder().build();
+        NAME: STRING = "name"
+        @RequiredTrait::builder().build();
+        IN: STRING = "in"
+        SCHEME: STRING = "scheme"
+    }
+});
+
+/// Configures [HTTP API key authentication](https://smithy.io/2.0/spec/authentication-traits.html#httpapikeyauth-trait).
+#[derive(SmithyShape, SmithyTraitImpl, PartialEq, Clone)]
+#[smithy_schema(HTTP_API_KEY_AUTH_SCHEMA)]
+pub struct HttpApiKeyAuthTrait {
+    /// Name of the header or query parameter that contains the API key
+    #[smithy_schema(NAME)]
+    pub name: String,
+    /// Where the API key is sent (`header` or `query`)
+    #[smithy_schema(IN)]
+    pub location: String,
+    /// Scheme prefix of the `Authorization` header value, if the key is sent in that header
+    #[smithy_schema(SCHEME)]
+    pub scheme: Option<String>,
+}
+
+impl HttpApiKeyAuthTrait {
+    /// Get where the API key is sent.
+    ///
+    /// Returns `None` if the location is not a known [`HttpApiKeyLocations`] value.
+    #[must_use]
+    pub fn location(&self) -> Option<HttpApiKeyLocations> {
+        match self.location.as_str() {
+            "header" => Some(HttpApiKeyLocations::Header),
+            "query" => Some(HttpApiKeyLocations::Query),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Identities
+// ============================================================================
+
+/// Identity used to sign a request.
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Identity {
+    /// Bearer token
+    Token(String),
+    /// API key
+    ApiKey(String),
+    /// Access key credentials, as used by `aws.auth#sigv4`
+    Credentials {
+        /// Access key ID
+        access_key_id: String,
+        /// Secret access key
+        secret_access_key: String,
+        /// Session token for temporary credentials
+        session_token: Option<String>,
+    },
+}
+
+// Secrets are never written to logs
+impl Debug for Identity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Identity::Token(_) => f.write_str("Token(**REDACTED**)"),
+            Identity::ApiKey(_) => f.write_str("ApiKey(**REDACTED**)"),
+            Identity::Credentials { access_key_id, .. } => f
+                .debug_struct("Credentials")
+                .field("access_key_id", access_key_id)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Provides the [`Identity`] used to sign requests.
+///
+/// Resolvers are called once per request, so implementations that load
+/// identities from an external source should cache them.
+pub trait IdentityResolver: Send + Sync {
+    /// Resolve the identity to sign the next request with.
+    ///
+    /// # Errors
+    /// Returns [`AuthError::Identity`] if no identity could be resolved.
+    fn resolve_identity(&self) -> Result<Identity, AuthError>;
+}
+
+/// A fixed identity resolves to itself.
+impl IdentityResolver for Identity {
+    fn resolve_identity(&self) -> Result<Identity, AuthError> {
+        Ok(self.clone())
+    }
+}
+
+// ============================================================================
+// Signers
+// ============================================================================
+
+/// Applies an [`Identity`] to a serialized HTTP request.
+pub trait Signer: Send + Sync {
+    /// Sign `request` for an operation of `service` with `identity`.
+    ///
+    /// # Errors
+    /// Returns an [`AuthError`] if the identity is not supported by this
+    /// signer or the request could not be signed.
+    fn sign(
+        &self,
+        request: &mut Request<Vec<u8>>,
+        identity: &Identity,
+        service: &Schema,
+    ) -> Result<(), AuthError>;
+}
+
+/// Signer for `@httpBearerAuth` that sets an `Authorization: Bearer <token>` header.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BearerSigner;
+
+impl Signer for BearerSigner {
+    fn sign(
+        &self,
+        request: &mut Request<Vec<u8>>,
+        identity: &Identity,
+        _service: &Schema,
+    ) -> Result<(), AuthError> {
+        let Identity::Token(token) = identity else {
+            return Err(AuthError::UnsupportedIdentity(HTTP_BEARER_AUTH));
+        };
+        let mut value = HeaderValue::try_from(format!("Bearer {token}"))?;
+        value.set_sensitive(true);
+        request.headers_mut().insert(AUTHORIZATION, value);
+        Ok(())
+    }
+}
+
+/// Signer for `@httpApiKeyAuth` that writes the API key to the header or
+/// query parameter configured by the service's [`HttpApiKeyAuthTrait`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApiKeySigner;
+
+impl Signer for ApiKeySigner {
+    fn sign(
+        &self,
+        request: &mut Request<Vec<u8>>,
+        identity: &Identity,
+        service: &Schema,
+    ) -> Result<(), AuthError> {
+        let Identity::ApiKey(key) = identity else {
+            return Err(AuthError::UnsupportedIdentity(HTTP_API_KEY_AUTH));
+        };
+        let config = service
+            .get_trait_as::<HttpApiKeyAuthTrait>()
+            .ok_or(AuthError::MissingTrait(HTTP_API_KEY_AUTH))?;
+        match config.location() {
+            Some(HttpApiKeyLocations::Header) => {
+                let name = HeaderName::try_from(config.name.as_str())?;
+                let mut value = match &config.scheme {
+                    Some(scheme) => HeaderValue::try_from(format!("{scheme} {key}"))?,
+                    None => HeaderValue::try_from(key.as_str())?,
+                };
+                value.set_sensitive(true);
+                request.headers_mut().insert(name, value);
+            }
+            Some(HttpApiKeyLocations::Query) => {
+                let uri = request.uri().to_string();
+                let separator = if request.uri().query().is_some() {
+                    '&'
+                } else {
+                    '?'
+                };
+                *request.uri_mut() = Uri::try_from(format!(
+                    "{uri}{separator}{}={}",
+                    utf8_percent_encode(&config.name, ENCODE_SET),
+                    utf8_percent_encode(key, ENCODE_SET)
+                ))?;
+            }
+            _ => {
+                return Err(AuthError::Signing(format!(
+                    "Unsupported API key location `{}`",
+                    config.location
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Auth Schemes
+// ============================================================================
+
+/// An auth scheme supported by a client, identified by the [`ShapeId`] of its auth trait.
+pub trait AuthScheme: Send + Sync {
+    /// Get the [`ShapeId`] of the auth trait that identifies this scheme.
+    fn id(&self) -> &ShapeId;
+
+    /// Get the resolver for identities used by this scheme.
+    fn identity_resolver(&self) -> &dyn IdentityResolver;
+
+    /// Get the signer used to apply identities to requests.
+    fn signer(&self) -> &dyn Signer;
+}
+
+/// [`AuthScheme`] composed from an [`IdentityResolver`] and a [`Signer`].
+#[derive(Clone)]
+pub struct DefaultAuthScheme {
+    id: ShapeId,
+    resolver: Arc<dyn IdentityResolver>,
+    signer: Arc<dyn Signer>,
+}
+
+impl DefaultAuthScheme {
+    /// Create a new auth scheme for the auth trait with the given ID.
+    pub fn new(
+        id: impl Into<ShapeId>,
+        resolver: impl IdentityResolver + 'static,
+        signer: impl Signer + 'static,
+    ) -> Self {
+        DefaultAuthScheme {
+            id: id.into(),
+            resolver: Arc::new(resolver),
+            signer: Arc::new(signer),
+        }
+    }
+
+    /// Create an `@httpBearerAuth` scheme.
+    pub fn bearer(resolver: impl IdentityResolver + 'static) -> Self {
+        Self::new(
+            HttpBearerAuthTrait::trait_id().clone(),
+            resolver,
+            BearerSigner,
+        )
+    }
+
+    /// Create an `@httpApiKeyAuth` scheme.
+    pub fn api_key(resolver: impl IdentityResolver + 'static) -> Self {
+        Self::new(
+            HttpApiKeyAuthTrait::trait_id().clone(),
+            resolver,
+            ApiKeySigner,
+        )
+    }
+}
+
+impl AuthScheme for DefaultAuthScheme {
+    fn id(&self) -> &ShapeId {
+        &self.id
+    }
+
+    fn identity_resolver(&self) -> &dyn IdentityResolver {
+        &*self.resolver
+    }
+
+    fn signer(&self) -> &dyn Signer {
+        &*self.signer
+    }
+}
+
+impl Debug for DefaultAuthScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DefaultAuthScheme").field(&self.id).finish()
+    }
+}
+
+// ============================================================================
+// Registry
+// ============================================================================
+
+/// Registry of the [`AuthScheme`]'s supported by a client, keyed by auth trait [`ShapeId`].
+#[derive(Default, Clone)]
+pub struct AuthSchemeRegistry {
+    schemes: IndexMap<ShapeId, Arc<dyn AuthScheme>>,
+}
+
+impl AuthSchemeRegistry {
+    /// Create a new, empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        AuthSchemeRegistry {
+            schemes: IndexMap::new(),
+        }
+    }
+
+    /// Register an auth scheme under its [`ShapeId`].
+    ///
+    /// Registering a scheme with the same ID more than once replaces the previous entry.
+    pub fn register(&mut self, scheme: impl AuthScheme + 'static) -> &mut Self {
+        self.schemes.insert(scheme.id().clone(), Arc::new(scheme));
+        self
+    }
+
+    /// Builder-style version of [`AuthSchemeRegistry::register`].
+    #[must_use]
+    pub fn with(mut self, scheme: impl AuthScheme + 'static) -> Self {
+        self.register(scheme);
+        self
+    }
+
+    /// Get the scheme registered for the given [`ShapeId`].
+    #[must_use]
+    pub fn get(&self, id: &ShapeId) -> Option<&dyn AuthScheme> {
+        self.schemes.get(id).map(|scheme| &**scheme)
+    }
+
+    /// Select the scheme used to sign requests for an operation of a service.
+    ///
+    /// Schemes are tried in the order of the `@auth` trait on the operation, then the
+    /// `@auth` trait on the service. If neither is present, every auth trait applied to
+    /// the service (either a built-in Smithy auth trait or the trait of a registered scheme)
+    /// is tried in alphabetical order of shape ID. The first registered scheme whose auth
+    /// trait is applied to the service is selected.
+    ///
+    /// Returns `None` if the operation may be called without authentication, either
+    /// because it has the `@optionalAuth` trait or because it supports no auth schemes.
+    ///
+    /// # Errors
+    /// Returns [`AuthError::NoSupportedScheme`] if none of the operation's auth schemes
+    /// are registered.
+    pub fn resolve(
+        &self,
+        service: &Schema,
+        operation: &Schema,
+    ) -> Result<Option<&dyn AuthScheme>, AuthError> {
+        let candidates: Vec<ShapeId> = operation
+            .get_trait_as::<AuthTrait>()
+            .or_else(|| service.get_trait_as::<AuthTrait>())
+            .map_or_else(
+                || self.service_auth_traits(service),
+                |auth| auth.iter().map(|id| ShapeId::from(id.as_str())).collect(),
+            );
+        let selected = candidates
+            .iter()
+            .filter(|id| service.contains_trait(id))
+            .find_map(|id| self.get(id));
+        match selected {
+            Some(scheme) => Ok(Some(scheme)),
+            None if candidates.is_empty() || operation.contains_type::<OptionalAuthTrait>() => {
+                Ok(None)
+            }
+            None => Err(AuthError::NoSupportedScheme(
+                operation.id().id().to_string(),
+            )),
+        }
+    }
+
+    /// Get the IDs of all auth traits applied to a service, in alphabetical order.
+    fn service_auth_traits(&self, service: &Schema) -> Vec<ShapeId> {
+        let mut ids: Vec<ShapeId> = KNOWN_AUTH_TRAITS
+            .into_iter()
+            .map(ShapeId::from)
+            .chain(self.schemes.keys().cloned())
+            .filter(|id| service.contains_trait(id))
+            .collect();
+        ids.sort_by(|a, b| a.id().cmp(b.id()));
+        ids.dedup();
+        ids
+    }
+
+    /// Sign a serialized request for an operation of a service.
+    ///
+    /// The scheme is selected with [`AuthSchemeRegistry::resolve`]. Requests for
+    /// operations that do not require authentication are left unchanged.
+    ///
+    /// # Errors
+    /// Returns an [`AuthError`] if no scheme could be selected, no identity could
+    /// be resolved, or the request could not be signed.
+    pub fn sign(
+        &self,
+        service: &Schema,
+        operation: &Schema,
+        request: &mut Request<Vec<u8>>,
+    ) -> Result<(), AuthError> {
+        let Some(scheme) = self.resolve(service, operation)? else {
+            return Ok(());
+        };
+        let identity = scheme.identity_resolver().resolve_identity()?;
+        scheme.signer().sign(request, &identity, service)
+    }
+
+    /// Get the number of registered schemes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.schemes.len()
+    }
+
+    /// Returns `true` if no schemes are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.schemes.is_empty()
+    }
+}
+
+impl Debug for AuthSchemeRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.schemes.keys()).finish()
+    }
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Errors raised while selecting an auth scheme or signing a request.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum AuthError {
+    /// None of the auth schemes supported by the operation are registered.
+    #[error("No registered auth scheme is supported by operation `{0}`")]
+    NoSupportedScheme(String),
+    /// The resolved identity cannot be used by the auth scheme.
+    #[error("Identity is not supported by auth scheme `{0}`")]
+    UnsupportedIdentity(&'static str),
+    /// The service is missing the trait that configures the auth scheme.
+    #[error("Service has no `{0}` trait")]
+    MissingTrait(&'static str),
+    /// An identity could not be resolved.
+    #[error("Failed to resolve identity: {0}")]
+    Identity(String),
+    /// The request could not be signed.
+    #[error("Failed to sign request: {0}")]
+    Signing(String),
+    /// A header name was invalid.
+    #[error(transparent)]
+    InvalidHeaderName(#[from] InvalidHeaderName),
+    /// A header value was invalid.
+    #[error(transparent)]
+    InvalidHeaderValue(#[from] InvalidHeaderValue),
+    /// The signed URI was invalid.
+    #[error(transparent)]
+    InvalidUri(#[from] InvalidUri),
+}
+
+#[cfg(test)]
+mod tests {
+    use smithy4rs_core::{doc_map, schema::DynamicTrait};
+
+    use super::*;
+
+    smithy!("com.example#BearerService": {
+        @DynamicTrait::from(HTTP_BEARER_AUTH, doc_map![]);
+        @DynamicTrait::from(HTTP_API_KEY_AUTH, doc_map!["name" => "x-api-key", "in" => "header"]);
+        @DynamicTrait::from("smithy.api#auth", vec![HTTP_BEARER_AUTH, HTTP_API_KEY_AUTH]);
+        service BEARER_SERVICE
+    });
+
+    smithy!("com.example#QueryKeyService": {
+        @DynamicTrait::from(HTTP_API_KEY_AUTH, doc_map!["name" => "api key", "in" => "query"]);
+        service QUERY_KEY_SERVICE
+    });
+
+    smithy!("com.example#GetCity": {
+        operation GET_CITY
+    });
+
+    smithy!("com.example#ListCities": {
+        @DynamicTrait::from("smithy.api#auth", vec![HTTP_API_KEY_AUTH]);
+        operation LIST_CITIES
+    });
+
+    smithy!("com.example#Ping": {
+        @DynamicTrait::from(OptionalAuthTrait::trait_id().clone(), doc_map![]);
+        operation PING
+    });
+
+    fn request(uri: &str) -> Request<Vec<u8>> {
+        Request::builder().uri(uri).body(Vec::new()).unwrap()
+    }
+
+    fn registry() -> AuthSchemeRegistry {
+        AuthSchemeRegistry::new()
+            .with(DefaultAuthScheme::api_key(Identity::ApiKey("key".into())))
+            .with(DefaultAuthScheme::bearer(Identity::Token("token".into())))
+    }
+
+    #[test]
+    fn selects_scheme_by_service_auth_priority() {
+        let registry = registry();
+        let scheme = registry
+            .resolve(&BEARER_SERVICE, &GET_CITY)
+            .unwrap()
+            .unwrap();
+        assert_eq!(scheme.id(), &ShapeId::from(HTTP_BEARER_AUTH));
+
+        let mut request = request("/cities");
+        registry
+            .sign(&BEARER_SERVICE, &GET_CITY, &mut request)
+            .unwrap();
+        assert_eq!(request.headers()[AUTHORIZATION], "Bearer token");
+        assert!(request.headers()[AUTHORIZATION].is_sensitive());
+    }
+
+    #[test]
+    fn operation_auth_overrides_service() {
+        let mut request = request("/cities");
+        registry()
+            .sign(&BEARER_SERVICE, &LIST_CITIES, &mut request)
+            .unwrap();
+        assert_eq!(request.headers()["x-api-key"], "key");
+        assert!(request.headers().get(AUTHORIZATION).is_none());
+    }
+
+    #[test]
+    fn api_key_in_query() {
+        let mut request = request("/cities?page=2");
+        registry()
+            .sign(&QUERY_KEY_SERVICE, &GET_CITY, &mut request)
+            .unwrap();
+        assert_eq!(request.uri(), "/cities?page=2&api%20key=key");
+    }
+
+    #[test]
+    fn unregistered_scheme_is_an_error() {
+        let registry = AuthSchemeRegistry::new()
+            .with(DefaultAuthScheme::bearer(Identity::Token("token".into())));
+        let err = registry
+            .sign(&QUERY_KEY_SERVICE, &GET_CITY, &mut request("/"))
+            .unwrap_err();
+        assert!(matches!(err, AuthError::NoSupportedScheme(id) if id == "com.example#GetCity"));
+    }
+
+    #[test]
+    fn optional_auth_skips_signing() {
+        let mut request = request("/ping");
+        AuthSchemeRegistry::new()
+            .sign(&BEARER_SERVICE, &PING, &mut request)
+            .unwrap();
+        assert!(request.headers().is_empty());
+    }
+
+    #[test]
+    fn rejects_unsupported_identity() {
+        let registry = AuthSchemeRegistry::new()
+            .with(DefaultAuthScheme::bearer(Identity::ApiKey("key".into())));
+        let err = registry
+            .sign(&BEARER_SERVICE, &GET_CITY, &mut request("/"))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AuthError::UnsupportedIdentity(HTTP_BEARER_AUTH)
+        ));
+    }
+
+    #[test]
+    fn identity_debug_redacts_secrets() {
+        let identity = Identity::Credentials {
+            access_key_id: "AKID".into(),
+            secret_access_key: "secret".into(),
+            session_token: None,
+        };
+        assert_eq!(
+            format!("{identity:?}"),
+            "Credentials { access_key_id: \"AKID\", .. }"
+        );
+        assert_eq!(
+            format!("{:?}", Identity::Token("t".into())),
+            "Token(**REDACTED**)"
+        );
+    }
+}
@@ -0,0 +1,704 @@
+//! # Request Compression
+//!
+//! Operations with the [`@requestCompression`](https://smithy.io/2.0/spec/behavior-traits.html#requestcompression-trait)
+//! trait accept request bodies compressed with one of the encodings listed by the trait.
+//! A [`CompressionAlgorithm`] implements one encoding (such as `gzip`) and is identified
+//! by its `Content-Encoding` value.
+//!
+//! A [`CompressionRegistry`] negotiates the encoding to use for an operation and applies it:
+//! - Clients compress serialized requests with [`CompressionRegistry::compress_request`],
+//!   which picks the first encoding listed by the trait that is registered. Operations
+//!   without the trait are never compressed.
+//! - Servers (and clients reading responses) decode bodies with
+//!   [`CompressionRegistry::decompress`], which undoes every encoding listed in the
+//!   `Content-Encoding` header.
+//!
+//! A small compressed body can expand to many gigabytes, so decoding is bounded:
+//! [`CompressionRegistry::decompress`] fails with
+//! [`CompressionError::TooLarge`] once a body expands past [`DEFAULT_MAX_DECOMPRESSED_SIZE`]
+//! (see [`CompressionRegistry::with_max_decompressed_size`]), and rejects bodies with more
+//! than [`DEFAULT_MAX_ENCODINGS`] stacked encodings.
+//!
+//! ```rust,ignore
+//! let compression = CompressionRegistry::new()
+//!     .with(Gzip::default())
+//!     .with(Zstd::default());
+//!
+//! let mut request = protocol.serialize_request(http, &input)?;
+//! compression.compress_request(&PUT_CITY_SCHEMA, &mut request)?;
+//! ```
+//!
+//! `gzip` and `zstd` are provided by this crate. Other encodings are supported by
+//! registering a [`CompressionAlgorithm`] for their encoding name.
+use std::{
+    fmt::{Debug, Formatter},
+    io::{Read, Write, copy},
+    sync::Arc,
+};
+
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use http::{
+    HeaderMap, HeaderValue, Request,
+    header::{CONTENT_ENCODING, CONTENT_LENGTH, InvalidHeaderValue},
+};
+use smithy4rs_core::{IndexMap, prelude::RequestCompressionTrait, schema::Schema};
+use thiserror::Error;
+
+/// Default minimum size of a request body, in bytes, before it is compressed.
+///
+/// Compressing small bodies often makes them larger, so bodies smaller than this
+/// are sent uncompressed.
+pub const DEFAULT_MIN_COMPRESSION_SIZE: usize = 10_240;
+
+/// Default maximum size of a decompressed body, in bytes (16 MiB).
+pub const DEFAULT_MAX_DECOMPRESSED_SIZE: usize = 16 * 1024 * 1024;
+
+/// Default maximum number of encodings that can be listed in a `Content-Encoding` header.
+pub const DEFAULT_MAX_ENCODINGS: usize = 2;
+
+/// Encoding name that represents an uncompressed body.
+const IDENTITY: &str = "identity";
+
+// ============================================================================
+// Algorithms
+// ============================================================================
+
+/// A compression algorithm, identified by its `Content-Encoding` value.
+///
+/// Algorithms operate on streams, so bodies can be compressed as they are written
+/// and decompressed as they are read. [`compress`](CompressionAlgorithm::compress) and
+/// [`decompress`](CompressionAlgorithm::decompress) are provided for in-memory bodies.
+pub trait CompressionAlgorithm: Send + Sync {
+    /// Get the `Content-Encoding` value that identifies this algorithm (for example `gzip`).
+    fn encoding(&self) -> &str;
+
+    /// Compress everything read from `input` into `output`.
+    ///
+    /// # Errors
+    /// Returns a [`CompressionError`] if the input could not be read or compressed,
+    /// or the output could not be written.
+    fn compress_stream(
+        &self,
+        input: &mut dyn Read,
+        output: &mut dyn Write,
+    ) -> Result<(), CompressionError>;
+
+    /// Wrap a reader of data compressed with this algorithm in a reader of the
+    /// decompressed data.
+    ///
+    /// Errors in the compressed data are raised as the returned reader is read.
+    ///
+    /// # Errors
+    /// Returns a [`CompressionError`] if the decoder could not be created.
+    fn decoder<'a>(
+        &self,
+        input: Box<dyn Read + 'a>,
+    ) -> Result<Box<dyn Read + 'a>, CompressionError>;
+
+    /// Compress a message body.
+    ///
+    /// # Errors
+    /// Returns a [`CompressionError`] if the body could not be compressed.
+    fn compress(&self, mut body: &[u8]) -> Result<Vec<u8>, CompressionError> {
+        let mut compressed = Vec::with_capacity(body.len() / 2);
+        self.compress_stream(&mut body, &mut compressed)?;
+        Ok(compressed)
+    }
+
+    /// Decompress a message body compressed with this algorithm, failing if it
+    /// expands to more than `max_size` bytes.
+    ///
+    /// # Errors
+    /// Returns [`CompressionError::TooLarge`] if the decompressed body is larger than
+    /// `max_size`, or another [`CompressionError`] if the body is not valid for this algorithm.
+    fn decompress(&self, body: &[u8], max_size: usize) -> Result<Vec<u8>, CompressionError> {
+        read_limited(self.decoder(Box::new(body))?, max_size)
+    }
+}
+
+/// Read a decompressed stream to the end, failing once more than `max_size` bytes are read.
+fn read_limited(reader: impl Read, max_size: usize) -> Result<Vec<u8>, CompressionError> {
+    let limit = u64::try_from(max_size)
+        .unwrap_or(u64::MAX)
+        .saturating_add(1);
+    let mut decompressed = Vec::new();
+    reader.take(limit).read_to_end(&mut decompressed)?;
+    if decompressed.len() > max_size {
+        return Err(CompressionError::TooLarge(max_size));
+    }
+    Ok(decompressed)
+}
+
+/// `gzip` [`CompressionAlgorithm`].
+#[derive(Debug, Clone, Copy)]
+pub struct Gzip {
+    level: u32,
+}
+
+impl Gzip {
+    /// Create a `gzip` algorithm that compresses with the given level, from
+    /// `0` (no compression) to `9` (best compression).
+    #[must_use]
+    pub fn new(level: u32) -> Self {
+        Gzip {
+            level: level.min(9),
+        }
+    }
+}
+
+impl Default for Gzip {
+    fn default() -> Self {
+        Gzip {
+            level: Compression::default().level(),
+        }
+    }
+}
+
+impl CompressionAlgorithm for Gzip {
+    fn encoding(&self) -> &str {
+        "gzip"
+    }
+
+    fn compress_stream(
+        &self,
+        input: &mut dyn Read,
+        output: &mut dyn Write,
+    ) -> Result<(), CompressionError> {
+        let mut encoder = GzEncoder::new(output, Compression::new(self.level));
+        copy(input, &mut encoder)?;
+        encoder.finish()?;
+        Ok(())
+    }
+
+    fn decoder<'a>(
+        &self,
+        input: Box<dyn Read + 'a>,
+    ) -> Result<Box<dyn Read + 'a>, CompressionError> {
+        Ok(Box::new(GzDecoder::new(input)))
+    }
+}
+
+/// `zstd` [`CompressionAlgorithm`].
+#[derive(Debug, Clone, Copy)]
+pub struct Zstd {
+    level: i32,
+}
+
+impl Zstd {
+    /// Create a `zstd` algorithm that compresses with the given level, from `1`
+    /// (fastest) to `22` (best compression). Negative levels trade compression
+    /// ratio for even faster compression.
+    #[must_use]
+    pub fn new(level: i32) -> Self {
+        Zstd {
+            level: level.clamp(*zstd::compression_level_range().start(), 22),
+        }
+    }
+}
+
+impl Default for Zstd {
+    fn default() -> Self {
+        Zstd {
+            level: zstd::DEFAULT_COMPRESSION_LEVEL,
+        }
+    }
+}
+
+impl CompressionAlgorithm for Zstd {
+    fn encoding(&self) -> &str {
+        "zstd"
+    }
+
+    fn compress_stream(
+        &self,
+        input: &mut dyn Read,
+        output: &mut dyn Write,
+    ) -> Result<(), CompressionError> {
+        zstd::stream::copy_encode(input, output, self.level)?;
+        Ok(())
+    }
+
+    fn decoder<'a>(
+        &self,
+        input: Box<dyn Read + 'a>,
+    ) -> Result<Box<dyn Read + 'a>, CompressionError> {
+        Ok(Box::new(zstd::stream::read::Decoder::new(input)?))
+    }
+}
+
+// ============================================================================
+// Registry
+// ============================================================================
+
+/// Registry of the [`CompressionAlgorithm`]'s supported by a client or server,
+/// keyed by encoding name.
+#[derive(Clone)]
+pub struct CompressionRegistry {
+    algorithms: IndexMap<String, Arc<dyn CompressionAlgorithm>>,
+    min_size: usize,
+    max_decompressed_size: usize,
+    max_encodings: usize,
+}
+
+impl Default for CompressionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompressionRegistry {
+    /// Create a new, empty registry that compresses bodies of at least
+    /// [`DEFAULT_MIN_COMPRESSION_SIZE`] bytes, and decompresses bodies of at most
+    /// [`DEFAULT_MAX_DECOMPRESSED_SIZE`] bytes with at most [`DEFAULT_MAX_ENCODINGS`] encodings.
+    #[must_use]
+    pub fn new() -> Self {
+        CompressionRegistry {
+            algorithms: IndexMap::new(),
+            min_size: DEFAULT_MIN_COMPRESSION_SIZE,
+            max_decompressed_size: DEFAULT_MAX_DECOMPRESSED_SIZE,
+            max_encodings: DEFAULT_MAX_ENCODINGS,
+        }
+    }
+
+    /// Register an algorithm under its encoding name.
+    ///
+    /// Registering an algorithm with the same encoding more than once replaces the previous entry.
+    pub fn register(&mut self, algorithm: impl CompressionAlgorithm + 'static) -> &mut Self {
+        self.algorithms
+            .insert(algorithm.encoding().to_string(), Arc::new(algorithm));
+        self
+    }
+
+    /// Builder-style version of [`CompressionRegistry::register`].
+    #[must_use]
+    pub fn with(mut self, algorithm: impl CompressionAlgorithm + 'static) -> Self {
+        self.register(algorithm);
+        self
+    }
+
+    /// Set the minimum size of a request body, in bytes, before it is compressed.
+    #[must_use]
+    pub const fn with_min_size(mut self, min_size: usize) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Set the maximum size of a decompressed body, in bytes.
+    #[must_use]
+    pub const fn with_max_decompressed_size(mut self, max_size: usize) -> Self {
+        self.max_decompressed_size = max_size;
+        self
+    }
+
+    /// Set the maximum number of encodings that can be applied to a body.
+    ///
+    /// `identity` encodings are not counted.
+    #[must_use]
+    pub const fn with_max_encodings(mut self, max_encodings: usize) -> Self {
+        self.max_encodings = max_encodings;
+        self
+    }
+
+    /// Get the algorithm registered for the given encoding.
+    #[must_use]
+    pub fn get(&self, encoding: &str) -> Option<&dyn CompressionAlgorithm> {
+        self.algorithms
+            .get(encoding)
+            .or_else(|| self.algorithms.get(&encoding.to_ascii_lowercase()))
+            .map(|algorithm| &**algorithm)
+    }
+
+    /// Select the algorithm used to compress requests for an operation.
+    ///
+    /// Encodings are tried in the order they are listed by the `@requestCompression`
+    /// trait of the operation, and the first registered encoding is selected.
+    ///
+    /// Returns `None` if the operation does not have the `@requestCompression` trait
+    /// or none of its encodings are registered.
+    #[must_use]
+    pub fn resolve(&self, operation: &Schema) -> Option<&dyn CompressionAlgorithm> {
+        operation
+            .get_trait_as::<RequestCompressionTrait>()?
+            .encodings
+            .iter()
+            .find_map(|encoding| self.get(encoding))
+    }
+
+    /// Compress a serialized request for an operation.
+    ///
+    /// The algorithm is selected with [`CompressionRegistry::resolve`], and its
+    /// encoding is appended to the `Content-Encoding` header. Requests are left
+    /// unchanged if no algorithm is selected or the body is smaller than the
+    /// minimum compression size.
+    ///
+    /// # Errors
+    /// Returns a [`CompressionError`] if the body could not be compressed.
+    pub fn compress_request(
+        &self,
+        operation: &Schema,
+        request: &mut Request<Vec<u8>>,
+    ) -> Result<(), CompressionError> {
+        if request.body().len() < self.min_size {
+            return Ok(());
+        }
+        let Some(algorithm) = self.resolve(operation) else {
+            return Ok(());
+        };
+        let compressed = algorithm.compress(request.body())?;
+        let headers = request.headers_mut();
+        let encoding = match headers.get(CONTENT_ENCODING) {
+            Some(existing) => HeaderValue::from_str(&format!(
+                "{}, {}",
+                existing.to_str().map_err(|_| {
+                    CompressionError::UnsupportedEncoding(
+                        String::from_utf8_lossy(existing.as_bytes()).into_owned(),
+                    )
+                })?,
+                algorithm.encoding()
+            ))?,
+            None => HeaderValue::from_str(algorithm.encoding())?,
+        };
+        headers.insert(CONTENT_ENCODING, encoding);
+        if headers.contains_key(CONTENT_LENGTH) {
+            headers.insert(CONTENT_LENGTH, HeaderValue::from(compressed.len()));
+        }
+        *request.body_mut() = compressed;
+        Ok(())
+    }
+
+    /// Decompress the body of a request or response with the encodings listed in
+    /// its `Content-Encoding` header.
+    ///
+    /// Encodings are removed in the reverse of the order they were applied, and
+    /// the `Content-Encoding` header is removed once the body is decoded. Bodies
+    /// without a `Content-Encoding` header are left unchanged.
+    ///
+    /// Stacked encodings are decoded as a single stream, so intermediate bodies are
+    /// never buffered.
+    ///
+    /// # Errors
+    /// Returns [`CompressionError::UnsupportedEncoding`] if an encoding is not registered,
+    /// [`CompressionError::TooManyEncodings`] if more than the maximum number of encodings
+    /// are listed, [`CompressionError::TooLarge`] if the body decompresses to more than the
+    /// maximum size, or another [`CompressionError`] if the body could not be decompressed.
+    pub fn decompress(
+        &self,
+        headers: &mut HeaderMap,
+        body: &mut Vec<u8>,
+    ) -> Result<(), CompressionError> {
+        let Some(header) = headers.get(CONTENT_ENCODING) else {
+            return Ok(());
+        };
+        let encodings = header.to_str().map_err(|_| {
+            CompressionError::UnsupportedEncoding(
+                String::from_utf8_lossy(header.as_bytes()).into_owned(),
+            )
+        })?;
+        // Resolve every encoding before decoding so unsupported bodies are rejected cheaply
+        let algorithms = encodings
+            .split(',')
+            .map(str::trim)
+            .filter(|encoding| !encoding.is_empty() && !encoding.eq_ignore_ascii_case(IDENTITY))
+            .map(|encoding| {
+                self.get(encoding)
+                    .ok_or_else(|| CompressionError::UnsupportedEncoding(encoding.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if algorithms.len() > self.max_encodings {
+            return Err(CompressionError::TooManyEncodings(self.max_encodings));
+        }
+        let mut reader: Box<dyn Read + '_> = Box::new(body.as_slice());
+        for algorithm in algorithms.iter().rev() {
+            reader = algorithm.decoder(reader)?;
+        }
+        *body = read_limited(reader, self.max_decompressed_size)?;
+        headers.remove(CONTENT_ENCODING);
+        if headers.contains_key(CONTENT_LENGTH) {
+            headers.insert(CONTENT_LENGTH, HeaderValue::from(body.len()));
+        }
+        Ok(())
+    }
+
+    /// Get the number of registered algorithms.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.algorithms.len()
+    }
+
+    /// Returns `true` if no algorithms are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.algorithms.is_empty()
+    }
+}
+
+impl Debug for CompressionRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompressionRegistry")
+            .field("algorithms", &self.algorithms.keys())
+            .field("min_size", &self.min_size)
+            .field("max_decompressed_size", &self.max_decompressed_size)
+            .field("max_encodings", &self.max_encodings)
+            .finish()
+    }
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Errors raised while compressing or decompressing a message body.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum CompressionError {
+    /// The message uses an encoding that is not registered.
+    #[error("Unsupported content encoding `{0}`")]
+    UnsupportedEncoding(String),
+    /// The body could not be compressed or decompressed.
+    #[error("Failed to process compressed body: {0}")]
+    Io(#[from] std::io::Error),
+    /// The decompressed body is larger than the maximum size, in bytes.
+    #[error("Decompressed body exceeds the maximum size of {0} bytes")]
+    TooLarge(usize),
+    /// The body lists more encodings than the maximum.
+    #[error("Body has more than the maximum of {0} content encodings")]
+    TooManyEncodings(usize),
+    /// The `Content-Encoding` header value was invalid.
+    #[error(transparent)]
+    InvalidHeaderValue(#[from] InvalidHeaderValue),
+}
+
+#[cfg(test)]
+mod tests {
+    use smithy4rs_core::{doc_map, schema::DynamicTrait, smithy};
+
+    use super::*;
+
+    smithy!("com.example#PutCity": {
+        @DynamicTrait::from("smithy.api#requestCompression", doc_map!["encodings" => vec!["zstd", "gzip"]]);
+        operation PUT_CITY
+    });
+
+    smithy!("com.example#GetCity": {
+        operation GET_CITY
+    });
+
+    fn request(body: &[u8]) -> Request<Vec<u8>> {
+        Request::builder()
+            .method("PUT")
+            .uri("/cities/Seattle")
+            .body(body.to_vec())
+            .unwrap()
+    }
+
+    fn city_body() -> Vec<u8> {
+        "Seattle ".repeat(64).into_bytes()
+    }
+
+    #[test]
+    fn gzip_roundtrips() {
+        let compressed = Gzip::default().compress(&city_body()).unwrap();
+        assert!(compressed.len() < city_body().len());
+        assert_eq!(
+            Gzip::new(9).decompress(&compressed, usize::MAX).unwrap(),
+            city_body()
+        );
+    }
+
+    #[test]
+    fn zstd_roundtrips() {
+        let compressed = Zstd::default().compress(&city_body()).unwrap();
+        assert!(compressed.len() < city_body().len());
+        assert_eq!(
+            Zstd::new(19).decompress(&compressed, usize::MAX).unwrap(),
+            city_body()
+        );
+    }
+
+    #[test]
+    fn streams_bodies() {
+        let mut compressed = Vec::new();
+        Zstd::default()
+            .compress_stream(&mut city_body().as_slice(), &mut compressed)
+            .unwrap();
+        let mut decoder = Zstd::default()
+            .decoder(Box::new(compressed.as_slice()))
+            .unwrap();
+        let mut chunk = [0; 8];
+        decoder.read_exact(&mut chunk).unwrap();
+        assert_eq!(&chunk, b"Seattle ");
+    }
+
+    #[test]
+    fn limits_decompressed_size() {
+        let compressed = Gzip::default().compress(&city_body()).unwrap();
+        let size = city_body().len();
+        assert_eq!(
+            Gzip::default().decompress(&compressed, size).unwrap(),
+            city_body()
+        );
+        assert!(matches!(
+            Gzip::default().decompress(&compressed, size - 1),
+            Err(CompressionError::TooLarge(max)) if max == size - 1
+        ));
+    }
+
+    #[test]
+    fn resolves_first_registered_encoding() {
+        let registry = CompressionRegistry::new().with(Gzip::default());
+        assert_eq!(registry.resolve(&PUT_CITY).unwrap().encoding(), "gzip");
+        assert!(registry.resolve(&GET_CITY).is_none());
+        assert!(CompressionRegistry::new().resolve(&PUT_CITY).is_none());
+    }
+
+    #[test]
+    fn compresses_requests() {
+        let registry = CompressionRegistry::new()
+            .with(Gzip::default())
+            .with_min_size(0);
+        let mut request = request(&city_body());
+        registry.compress_request(&PUT_CITY, &mut request).unwrap();
+        assert_eq!(request.headers()[CONTENT_ENCODING], "gzip");
+        assert_eq!(
+            Gzip::default()
+                .decompress(request.body(), usize::MAX)
+                .unwrap(),
+            city_body()
+        );
+    }
+
+    #[test]
+    fn appends_to_existing_encoding() {
+        let registry = CompressionRegistry::new()
+            .with(Gzip::default())
+            .with_min_size(0);
+        let mut request = request(&city_body());
+        request
+            .headers_mut()
+            .insert(CONTENT_ENCODING, HeaderValue::from_static("custom"));
+        registry.compress_request(&PUT_CITY, &mut request).unwrap();
+        assert_eq!(request.headers()[CONTENT_ENCODING], "custom, gzip");
+    }
+
+    #[test]
+    fn skips_small_and_unsupported_requests() {
+        let registry = CompressionRegistry::new().with(Gzip::default());
+        let mut small = request(&city_body());
+        registry.compress_request(&PUT_CITY, &mut small).unwrap();
+        assert!(small.headers().get(CONTENT_ENCODING).is_none());
+        assert_eq!(small.body(), &city_body());
+
+        let registry = registry.with_min_size(0);
+        let mut uncompressed = request(&city_body());
+        registry
+            .compress_request(&GET_CITY, &mut uncompressed)
+            .unwrap();
+        assert!(uncompressed.headers().get(CONTENT_ENCODING).is_none());
+    }
+
+    #[test]
+    fn decompresses_bodies() {
+        let registry = CompressionRegistry::new()
+            .with(Gzip::default())
+            .with_min_size(0);
+        let mut request = request(&city_body());
+        registry.compress_request(&PUT_CITY, &mut request).unwrap();
+        let (mut parts, mut body) = request.into_parts();
+        registry.decompress(&mut parts.headers, &mut body).unwrap();
+        assert_eq!(body, city_body());
+        assert!(parts.headers.get(CONTENT_ENCODING).is_none());
+    }
+
+    #[test]
+    fn decompresses_multiple_encodings() {
+        let registry = CompressionRegistry::new().with(Gzip::default());
+        let mut body = Gzip::default()
+            .compress(&Gzip::default().compress(&city_body()).unwrap())
+            .unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_ENCODING,
+            HeaderValue::from_static("gzip, identity, GZIP"),
+        );
+        registry.decompress(&mut headers, &mut body).unwrap();
+        assert_eq!(body, city_body());
+    }
+
+    #[test]
+    fn decompresses_mixed_encodings() {
+        let registry = CompressionRegistry::new()
+            .with(Gzip::default())
+            .with(Zstd::default());
+        let mut body = Zstd::default()
+            .compress(&Gzip::default().compress(&city_body()).unwrap())
+            .unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_ENCODING, HeaderValue::from_static("gzip, zstd"));
+        registry.decompress(&mut headers, &mut body).unwrap();
+        assert_eq!(body, city_body());
+    }
+
+    #[test]
+    fn rejects_decompression_bombs() {
+        // 64 MiB of zeros compresses to a few kilobytes
+        let bomb = Gzip::default().compress(&vec![0; 64 << 20]).unwrap();
+        let registry = CompressionRegistry::new().with(Gzip::default());
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+        let mut body = bomb.clone();
+        let error = registry.decompress(&mut headers, &mut body).unwrap_err();
+        assert!(
+            matches!(error, CompressionError::TooLarge(max) if max == DEFAULT_MAX_DECOMPRESSED_SIZE)
+        );
+        assert_eq!(body, bomb);
+
+        let registry = registry.with_max_decompressed_size(city_body().len() - 1);
+        let mut body = Gzip::default().compress(&city_body()).unwrap();
+        let error = registry.decompress(&mut headers, &mut body).unwrap_err();
+        assert!(matches!(error, CompressionError::TooLarge(_)));
+    }
+
+    #[test]
+    fn rejects_too_many_encodings() {
+        let registry = CompressionRegistry::new().with(Gzip::default());
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_ENCODING,
+            HeaderValue::from_static("gzip, gzip, identity, gzip"),
+        );
+        let error = registry
+            .decompress(&mut headers, &mut b"data".to_vec())
+            .unwrap_err();
+        assert!(matches!(error, CompressionError::TooManyEncodings(2)));
+
+        let registry = registry.with_max_encodings(3);
+        let mut body = city_body();
+        for _ in 0..3 {
+            body = Gzip::default().compress(&body).unwrap();
+        }
+        registry.decompress(&mut headers, &mut body).unwrap();
+        assert_eq!(body, city_body());
+    }
+
+    #[test]
+    fn rejects_unsupported_encodings() {
+        let registry = CompressionRegistry::new().with(Gzip::default());
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_ENCODING, HeaderValue::from_static("br"));
+        let mut body = b"data".to_vec();
+        let error = registry.decompress(&mut headers, &mut body).unwrap_err();
+        assert!(matches!(error, CompressionError::UnsupportedEncoding(ref e) if e == "br"));
+        assert_eq!(body, b"data");
+    }
+
+    #[test]
+    fn rejects_corrupt_bodies() {
+        let registry = CompressionRegistry::new().with(Gzip::default());
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+        let error = registry
+            .decompress(&mut headers, &mut b"not gzip".to_vec())
+            .unwrap_err();
+        assert!(matches!(error, CompressionError::Io(_)));
+    }
+}
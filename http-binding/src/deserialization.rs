@@ -0,0 +1,618 @@
+use std::{borrow::Cow, marker::PhantomData, str::FromStr};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use smithy4rs_core::{
+    BigDecimal, BigInt, Instant,
+    prelude::{HttpHeaderTrait, HttpPrefixHeadersTrait, HttpQueryTrait, TimestampFormatTrait},
+    schema::{Blob, Schema, ShapeType, Timestamp},
+    serde::{
+        de::{DeserializeWithSchema, Deserializer, Error, ListReader, MapReader, StructReader},
+        http::HttpBinding,
+    },
+};
+
+use crate::{message::HttpMessage, serialization::Unsupported};
+
+// ============================================================================
+// Binding Deserializer
+// ============================================================================
+
+/// [`Deserializer`] that reads the members of a structure from an HTTP message.
+///
+/// Members with an HTTP binding trait are read from the URI labels, query string,
+/// headers, or status code of an [`HttpMessage`]. All other members are read from
+/// the message body using the wrapped body deserializer `D`. A member bound with
+/// `@httpPayload` is read from the raw message body if it targets a `blob`,
+/// `string`, or `enum`, and with the body deserializer otherwise.
+///
+/// ```rust,ignore
+/// let message = HttpMessage::from_request(&request, &http_trait)?;
+/// let mut body = JsonDeserializer::new(request.body());
+/// let input = GetCityInputBuilder::deserialize_with_schema(
+///     &GET_CITY_INPUT_SCHEMA,
+///     HttpBindingDeserializer::new(&message, request.body(), &mut body),
+/// )?
+/// .build()?;
+/// ```
+pub struct HttpBindingDeserializer<'m, 'de, D> {
+    message: &'m HttpMessage,
+    body: &'de [u8],
+    codec: D,
+}
+
+impl<'m, 'de, D> HttpBindingDeserializer<'m, 'de, D> {
+    /// Create a new binding deserializer.
+    ///
+    /// `body` is the raw message body, and `codec` is a deserializer over the same body.
+    pub const fn new(message: &'m HttpMessage, body: &'de [u8], codec: D) -> Self {
+        HttpBindingDeserializer {
+            message,
+            body,
+            codec,
+        }
+    }
+}
+
+impl<'m, 'de, D: Deserializer<'de>> Deserializer<'de> for HttpBindingDeserializer<'m, 'de, D> {
+    type Error = D::Error;
+    type StructReader = HttpBindingStructReader<'m, 'de, D>;
+    type ListReader = Unsupported<(), D::Error>;
+    type MapReader = Unsupported<(), D::Error>;
+
+    fn read_struct(self, _schema: &Schema) -> Result<Self::StructReader, Self::Error> {
+        Ok(HttpBindingStructReader {
+            message: self.message,
+            body: self.body,
+            codec: Codec::Pending(self.codec),
+            index: 0,
+            current: None,
+        })
+    }
+}
+
+enum Codec<'de, D: Deserializer<'de>> {
+    Pending(D),
+    Struct(D::StructReader),
+    Done,
+}
+
+enum Current {
+    Bound(HttpBinding, Vec<(String, String)>),
+    RawPayload,
+    Payload,
+    Body,
+}
+
+/// [`StructReader`] for [`HttpBindingDeserializer`].
+///
+/// Bound members are read first, in schema order, followed by members read
+/// from the message body.
+pub struct HttpBindingStructReader<'m, 'de, D: Deserializer<'de>> {
+    message: &'m HttpMessage,
+    body: &'de [u8],
+    codec: Codec<'de, D>,
+    index: usize,
+    current: Option<Current>,
+}
+
+impl<'de, D: Deserializer<'de>> HttpBindingStructReader<'_, 'de, D> {
+    fn bound_values(
+        &self,
+        binding: HttpBinding,
+        member: &Schema,
+    ) -> Result<Vec<(String, String)>, D::Error> {
+        let message = self.message;
+        let values = match binding {
+            HttpBinding::Header => {
+                let name = member
+                    .get_trait_as::<HttpHeaderTrait>()
+                    .map_or("", |name| name.as_str());
+                let http_date = member.get_list_member().is_some_and(|element| {
+                    element.shape_type() == &ShapeType::Timestamp
+                        && matches!(
+                            binding.timestamp_format(element, &TimestampFormatTrait::EpochSeconds),
+                            TimestampFormatTrait::HttpDate
+                        )
+                });
+                let mut values = Vec::new();
+                for value in message.headers.get_all(name) {
+                    let value = value.to_str().map_err(|e| {
+                        Error::custom(format!("Invalid value for header `{name}`: {e}"))
+                    })?;
+                    if member.shape_type() == &ShapeType::List {
+                        values.extend(
+                            split_header_list(value, http_date)
+                                .into_iter()
+                                .map(|v| (name.to_string(), v)),
+                        );
+                    } else {
+                        values.push((name.to_string(), value.to_string()));
+                    }
+                }
+                values
+            }
+            HttpBinding::PrefixHeaders => {
+                let prefix = member
+                    .get_trait_as::<HttpPrefixHeadersTrait>()
+                    .map_or(String::new(), |prefix| prefix.to_ascii_lowercase());
+                let mut values = Vec::new();
+                for (name, value) in &message.headers {
+                    let Some(suffix) = name.as_str().strip_prefix(prefix.as_str()) else {
+                        continue;
+                    };
+                    let value = value.to_str().map_err(|e| {
+                        Error::custom(format!("Invalid value for header `{name}`: {e}"))
+                    })?;
+                    values.push((suffix.to_string(), value.to_string()));
+                }
+                values
+            }
+            HttpBinding::Query => {
+                let name = member
+                    .get_trait_as::<HttpQueryTrait>()
+                    .map_or("", |name| name.as_str());
+                filter_key(&message.query, name)
+            }
+            HttpBinding::QueryParams => message.query.clone(),
+            HttpBinding::Label => {
+                let name = member.as_member().map_or("", |member| member.name());
+                filter_key(&message.labels, name)
+            }
+            HttpBinding::ResponseCode => message
+                .status
+                .map(|status| (String::new(), status.as_u16().to_string()))
+                .into_iter()
+                .collect(),
+            HttpBinding::Payload | HttpBinding::Body => Vec::new(),
+        };
+        Ok(values)
+    }
+}
+
+impl<'de, D: Deserializer<'de>> StructReader<'de> for HttpBindingStructReader<'_, 'de, D> {
+    type Error = D::Error;
+
+    fn read_member<'a>(&mut self, schema: &'a Schema) -> Result<Option<&'a Schema>, Self::Error> {
+        let members = schema.members();
+        // Read members bound outside the body first
+        while let Some((_, member)) = members.get_index(self.index) {
+            self.index += 1;
+            let current = match HttpBinding::of(member) {
+                HttpBinding::Body => continue,
+                HttpBinding::Payload if self.body.is_empty() => continue,
+                HttpBinding::Payload => {
+                    if matches!(
+                        member.shape_type(),
+                        ShapeType::Blob | ShapeType::String | ShapeType::Enum
+                    ) {
+                        Current::RawPayload
+                    } else {
+                        Current::Payload
+                    }
+                }
+                binding => {
+                    let values = self.bound_values(binding, member)?;
+                    if values.is_empty() {
+                        continue;
+                    }
+                    Current::Bound(binding, values)
+                }
+            };
+            self.current = Some(current);
+            return Ok(Some(member));
+        }
+
+        // Then read the remaining members from the body
+        loop {
+            match std::mem::replace(&mut self.codec, Codec::Done) {
+                Codec::Pending(codec) => {
+                    let has_body = members
+                        .values()
+                        .any(|member| HttpBinding::of(member) == HttpBinding::Body);
+                    if !has_body || self.body.is_empty() {
+                        return Ok(None);
+                    }
+                    self.codec = Codec::Struct(codec.read_struct(schema)?);
+                }
+                Codec::Struct(mut reader) => {
+                    let member = reader.read_member(schema)?;
+                    match member {
+                        None => return Ok(None),
+                        // Bound members are never read from the body
+                        Some(member) if HttpBinding::of(member) != HttpBinding::Body => {
+                            reader.skip_value()?;
+                            self.codec = Codec::Struct(reader);
+                        }
+                        Some(member) => {
+                            self.codec = Codec::Struct(reader);
+                            self.current = Some(Current::Body);
+                            return Ok(Some(member));
+                        }
+                    }
+                }
+                Codec::Done => return Ok(None),
+            }
+        }
+    }
+
+    fn read_value<T: DeserializeWithSchema<'de>>(
+        &mut self,
+        schema: &Schema,
+    ) -> Result<T, Self::Error> {
+        match self.current.take() {
+            Some(Current::Bound(binding, values)) => {
+                T::deserialize_with_schema(schema, ValueDeserializer::new(binding, values))
+            }
+            Some(Current::RawPayload) => {
+                T::deserialize_with_schema(schema, PayloadDeserializer::new(self.body))
+            }
+            Some(Current::Payload) => match std::mem::replace(&mut self.codec, Codec::Done) {
+                Codec::Pending(codec) => T::deserialize_with_schema(schema, codec),
+                _ => Err(Error::custom("HTTP payload has already been read")),
+            },
+            Some(Current::Body) => match &mut self.codec {
+                Codec::Struct(reader) => reader.read_value(schema),
+                _ => Err(Error::custom("HTTP body is not being read")),
+            },
+            None => Err(Error::custom("No member to read")),
+        }
+    }
+
+    fn skip_value(&mut self) -> Result<(), Self::Error> {
+        match self.current.take() {
+            Some(Current::Body) => match &mut self.codec {
+                Codec::Struct(reader) => reader.skip_value(),
+                _ => Err(Error::custom("HTTP body is not being read")),
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+fn filter_key(pairs: &[(String, String)], key: &str) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .filter(|(name, _)| name == key)
+        .cloned()
+        .collect()
+}
+
+/// Split a comma-separated header list, removing quotes from quoted elements.
+///
+/// `http-date` timestamps contain a comma and are never quoted, so every
+/// second comma separates list elements.
+fn split_header_list(value: &str, http_date: bool) -> Vec<String> {
+    let mut elements = Vec::new();
+    let mut current = String::new();
+    let mut chars = value.chars();
+    let mut quoted = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => quoted = !quoted,
+            '\\' if quoted => current.extend(chars.next()),
+            ',' if !quoted => elements.push(std::mem::take(&mut current).trim().to_string()),
+            c => current.push(c),
+        }
+    }
+    elements.push(current.trim().to_string());
+    if http_date {
+        return elements.chunks(2).map(|pair| pair.join(", ")).collect();
+    }
+    elements
+}
+
+// ============================================================================
+// Bound Value Deserializer
+// ============================================================================
+
+/// Deserializes bound values from `(key, value)` string pairs.
+struct ValueDeserializer<E> {
+    binding: HttpBinding,
+    values: Vec<(String, String)>,
+    _error: PhantomData<E>,
+}
+
+impl<E: Error> ValueDeserializer<E> {
+    const fn new(binding: HttpBinding, values: Vec<(String, String)>) -> Self {
+        ValueDeserializer {
+            binding,
+            values,
+            _error: PhantomData,
+        }
+    }
+
+    fn single(mut self) -> Result<String, E> {
+        // Repeated query parameters collected into a map with scalar values use the first value
+        if self.values.len() > 1 && self.binding != HttpBinding::QueryParams {
+            return Err(Error::custom("Expected a single bound value"));
+        }
+        if self.values.is_empty() {
+            return Err(Error::custom("Expected a bound value"));
+        }
+        Ok(self.values.swap_remove(0).1)
+    }
+
+    fn parse<T: FromStr>(self, type_name: &str) -> Result<T, E>
+    where
+        T::Err: std::fmt::Display,
+    {
+        let value = self.single()?;
+        value
+            .parse()
+            .map_err(|e| Error::custom(format!("Invalid {type_name} `{value}`: {e}")))
+    }
+}
+
+impl<'de, E: Error> Deserializer<'de> for ValueDeserializer<E> {
+    type Error = E;
+    type StructReader = Unsupported<(), E>;
+    type ListReader = ValueListReader<E>;
+    type MapReader = ValueMapReader<E>;
+
+    fn read_bool(self, _: &Schema) -> Result<bool, Self::Error> {
+        self.parse("boolean")
+    }
+
+    fn read_byte(self, _: &Schema) -> Result<i8, Self::Error> {
+        self.parse("byte")
+    }
+
+    fn read_short(self, _: &Schema) -> Result<i16, Self::Error> {
+        self.parse("short")
+    }
+
+    fn read_integer(self, _: &Schema) -> Result<i32, Self::Error> {
+        self.parse("integer")
+    }
+
+    fn read_long(self, _: &Schema) -> Result<i64, Self::Error> {
+        self.parse("long")
+    }
+
+    fn read_float(self, _: &Schema) -> Result<f32, Self::Error> {
+        self.parse("float")
+    }
+
+    fn read_double(self, _: &Schema) -> Result<f64, Self::Error> {
+        self.parse("double")
+    }
+
+    fn read_big_integer(self, _: &Schema) -> Result<BigInt, Self::Error> {
+        self.parse("big integer")
+    }
+
+    fn read_big_decimal(self, _: &Schema) -> Result<BigDecimal, Self::Error> {
+        self.parse("big decimal")
+    }
+
+    fn read_string(self, _: &Schema) -> Result<String, Self::Error> {
+        self.single()
+    }
+
+    fn read_blob(self, _: &Schema) -> Result<Blob, Self::Error> {
+        let value = self.single()?;
+        STANDARD
+            .decode(&value)
+            .map(Blob::from)
+            .map_err(|e| Error::custom(format!("Invalid base64 value `{value}`: {e}")))
+    }
+
+    fn read_timestamp(self, schema: &Schema) -> Result<Instant, Self::Error> {
+        let format = self
+            .binding
+            .timestamp_format(schema, &TimestampFormatTrait::EpochSeconds);
+        let value = self.single()?;
+        Timestamp::parse(&value, &format)
+            .map(Instant::from)
+            .map_err(Error::custom)
+    }
+
+    fn read_list(self, _: &Schema) -> Result<Self::ListReader, Self::Error> {
+        Ok(ValueListReader {
+            binding: self.binding,
+            values: self.values.into_iter(),
+            _error: PhantomData,
+        })
+    }
+
+    fn read_map(self, _: &Schema) -> Result<Self::MapReader, Self::Error> {
+        // Group repeated keys so that map values can be lists
+        let mut entries: Vec<(String, Vec<(String, String)>)> = Vec::new();
+        for (key, value) in self.values {
+            match entries.iter_mut().find(|(existing, _)| *existing == key) {
+                Some((_, values)) => values.push((key, value)),
+                None => entries.push((key.clone(), vec![(key, value)])),
+            }
+        }
+        Ok(ValueMapReader {
+            binding: self.binding,
+            entries: entries.into_iter(),
+            current: None,
+            _error: PhantomData,
+        })
+    }
+}
+
+/// [`ListReader`] for bound list values.
+struct ValueListReader<E> {
+    binding: HttpBinding,
+    values: std::vec::IntoIter<(String, String)>,
+    _error: PhantomData<E>,
+}
+
+impl<'de, E: Error> ListReader<'de> for ValueListReader<E> {
+    type Error = E;
+
+    fn read_element<T: DeserializeWithSchema<'de>>(
+        &mut self,
+        schema: &Schema,
+    ) -> Result<Option<T>, Self::Error> {
+        self.values
+            .next()
+            .map(|value| {
+                T::deserialize_with_schema(
+                    schema,
+                    ValueDeserializer::new(self.binding, vec![value]),
+                )
+            })
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+/// [`MapReader`] for maps bound using `@httpPrefixHeaders` or `@httpQueryParams`.
+struct ValueMapReader<E> {
+    binding: HttpBinding,
+    entries: std::vec::IntoIter<(String, Vec<(String, String)>)>,
+    current: Option<Vec<(String, String)>>,
+    _error: PhantomData<E>,
+}
+
+impl<'de, E: Error> MapReader<'de> for ValueMapReader<E> {
+    type Error = E;
+
+    fn read_key(&mut self) -> Result<Option<String>, Self::Error> {
+        Ok(self.entries.next().map(|(key, values)| {
+            self.current = Some(values);
+            key
+        }))
+    }
+
+    fn read_value<V: DeserializeWithSchema<'de>>(
+        &mut self,
+        schema: &Schema,
+    ) -> Result<V, Self::Error> {
+        let values = self
+            .current
+            .take()
+            .ok_or_else(|| Error::custom("No map entry to read"))?;
+        V::deserialize_with_schema(schema, ValueDeserializer::new(self.binding, values))
+    }
+
+    fn skip_value(&mut self) -> Result<(), Self::Error> {
+        self.current = None;
+        Ok(())
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+// ============================================================================
+// Payload Deserializer
+// ============================================================================
+
+/// Deserializes a `blob`, `string`, or `enum` bound with `@httpPayload` from raw bytes.
+struct PayloadDeserializer<'de, E> {
+    body: &'de [u8],
+    _error: PhantomData<E>,
+}
+
+impl<'de, E> PayloadDeserializer<'de, E> {
+    const fn new(body: &'de [u8]) -> Self {
+        PayloadDeserializer {
+            body,
+            _error: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: Error> Deserializer<'de> for PayloadDeserializer<'de, E> {
+    type Error = E;
+    type StructReader = Unsupported<(), E>;
+    type ListReader = Unsupported<(), E>;
+    type MapReader = Unsupported<(), E>;
+
+    fn read_string(self, schema: &Schema) -> Result<String, Self::Error> {
+        self.read_str(schema).map(Cow::into_owned)
+    }
+
+    fn read_str(self, _: &Schema) -> Result<Cow<'de, str>, Self::Error> {
+        std::str::from_utf8(self.body)
+            .map(Cow::Borrowed)
+            .map_err(|e| Error::custom(format!("Invalid UTF-8 in HTTP payload: {e}")))
+    }
+
+    fn read_blob(self, _: &Schema) -> Result<Blob, Self::Error> {
+        Ok(Blob::copy_from_slice(self.body))
+    }
+}
+
+// ============================================================================
+// Unsupported Reader
+// ============================================================================
+
+impl<'de, O, E: Error> StructReader<'de> for Unsupported<O, E> {
+    type Error = E;
+
+    fn read_member<'a>(&mut self, _: &'a Schema) -> Result<Option<&'a Schema>, Self::Error> {
+        match self.never {}
+    }
+
+    fn read_value<T: DeserializeWithSchema<'de>>(&mut self, _: &Schema) -> Result<T, Self::Error> {
+        match self.never {}
+    }
+
+    fn skip_value(&mut self) -> Result<(), Self::Error> {
+        match self.never {}
+    }
+}
+
+impl<'de, O, E: Error> ListReader<'de> for Unsupported<O, E> {
+    type Error = E;
+
+    fn read_element<T: DeserializeWithSchema<'de>>(
+        &mut self,
+        _: &Schema,
+    ) -> Result<Option<T>, Self::Error> {
+        match self.never {}
+    }
+}
+
+impl<'de, O, E: Error> MapReader<'de> for Unsupported<O, E> {
+    type Error = E;
+
+    fn read_key(&mut self) -> Result<Option<String>, Self::Error> {
+        match self.never {}
+    }
+
+    fn read_value<V: DeserializeWithSchema<'de>>(&mut self, _: &Schema) -> Result<V, Self::Error> {
+        match self.never {}
+    }
+
+    fn skip_value(&mut self) -> Result<(), Self::Error> {
+        match self.never {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_header_lists() {
+        assert_eq!(split_header_list("a, b,c", false), vec!["a", "b", "c"]);
+        assert_eq!(
+            split_header_list(r#""a,b", "say \"hi\"", c"#, false),
+            vec!["a,b", "say \"hi\"", "c"]
+        );
+    }
+
+    #[test]
+    fn splits_http_date_lists() {
+        assert_eq!(
+            split_header_list(
+                "Mon, 16 Dec 2019 23:48:18 GMT, Tue, 17 Dec 2019 23:48:18 GMT",
+                true
+            ),
+            vec![
+                "Mon, 16 Dec 2019 23:48:18 GMT",
+                "Tue, 17 Dec 2019 23:48:18 GMT"
+            ]
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use smithy4rs_core::serde::CodecError;
+use thiserror::Error;
+
+/// Errors raised when converting between bound values and HTTP messages.
+#[derive(Error, Debug)]
+pub enum HttpBindingError {
+    /// URI pattern of the `@http` trait is malformed.
+    #[error("Invalid URI pattern `{0}`")]
+    InvalidPattern(String),
+    /// Request path does not match the URI pattern of the `@http` trait.
+    #[error("Path `{path}` does not match URI pattern `{pattern}`")]
+    PathMismatch {
+        /// Path of the request
+        path: String,
+        /// URI pattern of the operation
+        pattern: String,
+    },
+    /// Operation has no `@http` trait to bind it to a method and URI.
+    #[error("Operation `{0}` has no `@http` trait")]
+    MissingHttpTrait(String),
+    /// No handler is registered for the operation.
+    #[error("No handler registered for operation `{0}`")]
+    UnregisteredOperation(String),
+    /// A label in the URI pattern was not bound to a value.
+    #[error("Missing value for URI label `{0}`")]
+    MissingLabel(String),
+    /// Error from the underlying `http` crate.
+    #[error(transparent)]
+    Http(#[from] http::Error),
+}
+
+impl From<HttpBindingError> for CodecError {
+    fn from(value: HttpBindingError) -> Self {
+        CodecError::Custom(value.to_string())
+    }
+}
@@ -0,0 +1,48 @@
+//! [HTTP binding](https://smithy.io/2.0/spec/http-bindings.html) support for `smithy4rs` protocols.
+//!
+//! HTTP binding traits bind the members of a structure to parts of an HTTP message:
+//! - `@httpLabel` members are substituted into the URI pattern of the `@http` trait.
+//! - `@httpQuery` and `@httpQueryParams` members are written to the query string.
+//! - `@httpHeader` and `@httpPrefixHeaders` members are written to headers.
+//! - `@httpResponseCode` members set the status code of a response.
+//! - An `@httpPayload` member is written as the entire message body.
+//!
+//! All remaining members are written to the message body using an existing codec,
+//! so protocols such as `restJson1` can be composed from a body codec and the
+//! [`HttpBindingSerializer`] and [`HttpBindingDeserializer`] types in this crate.
+//!
+//! The same bindings can be published as an OpenAPI document with the [`OpenApiBuilder`].
+//!
+//! Serialized requests are signed for the service's auth schemes with the [`auth`] module,
+//! and compressed for operations with the `@requestCompression` trait with the
+//! [`compression`] module.
+//!
+//! ## Features
+//! - `sigv4`: Signer for the `aws.auth#sigv4` auth scheme. See [`sigv4::SigV4Signer`].
+pub mod auth;
+pub mod compression;
+mod deserialization;
+mod errors;
+mod message;
+mod openapi;
+mod serialization;
+#[cfg(feature = "sigv4")]
+pub mod sigv4;
+
+pub use deserialization::{HttpBindingDeserializer, HttpBindingStructReader};
+pub use errors::HttpBindingError;
+pub use message::{HttpMessage, UriPattern};
+pub use openapi::OpenApiBuilder;
+pub use serialization::{HttpBindingSerializer, HttpBindingStructWriter, Unsupported};
+
+smithy4rs_core::assert_send_sync!(
+    HttpBindingError,
+    HttpMessage,
+    auth::AuthSchemeRegistry,
+    auth::AuthError,
+    auth::Identity,
+    compression::CompressionRegistry,
+    compression::CompressionError,
+);
+#[cfg(feature = "sigv4")]
+smithy4rs_core::assert_send_sync!(sigv4::SigV4Signer);
@@ -0,0 +1,600 @@
+use http::{HeaderMap, Method, Request, Response, StatusCode};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use smithy4rs_core::prelude::HttpTrait;
+
+use crate::errors::HttpBindingError;
+
+/// Characters that must be percent-encoded in URI labels and query strings.
+///
+/// Only unreserved characters ([RFC 3986 §2.3](https://datatracker.ietf.org/doc/html/rfc3986#section-2.3))
+/// are left as-is.
+pub(crate) const ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+// ============================================================================
+// HTTP Message
+// ============================================================================
+
+/// Values bound to the parts of an HTTP message outside the message body.
+///
+/// An `HttpMessage` is produced by [`HttpBindingSerializer`](crate::HttpBindingSerializer)
+/// and converted into an [`http::Request`] or [`http::Response`] along with the
+/// serialized body. When deserializing, an `HttpMessage` is extracted from an
+/// incoming request or response and passed to an
+/// [`HttpBindingDeserializer`](crate::HttpBindingDeserializer).
+#[derive(Debug, Clone, Default)]
+pub struct HttpMessage {
+    pub(crate) labels: Vec<(String, String)>,
+    pub(crate) query: Vec<(String, String)>,
+    pub(crate) headers: HeaderMap,
+    pub(crate) status: Option<StatusCode>,
+    pub(crate) payload: Option<Vec<u8>>,
+}
+
+impl HttpMessage {
+    /// Values bound to URI labels, keyed by label name.
+    #[must_use]
+    pub fn labels(&self) -> &[(String, String)] {
+        &self.labels
+    }
+
+    /// Query string parameters, in order.
+    #[must_use]
+    pub fn query(&self) -> &[(String, String)] {
+        &self.query
+    }
+
+    /// Headers of the message.
+    #[must_use]
+    pub const fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Status code bound using `@httpResponseCode`, if any.
+    #[must_use]
+    pub const fn status(&self) -> Option<StatusCode> {
+        self.status
+    }
+
+    /// Build an HTTP request for an operation with the provided `@http` trait.
+    ///
+    /// `body` contains the members serialized by the body codec. It is replaced
+    /// by the raw payload if a `blob` or `string` member is bound with `@httpPayload`.
+    ///
+    /// # Errors
+    /// Returns [`HttpBindingError`] if the URI pattern is invalid, a label has no
+    /// value, or the method or URI are not valid.
+    pub fn into_request(
+        self,
+        http: &HttpTrait,
+        body: Vec<u8>,
+    ) -> Result<Request<Vec<u8>>, HttpBindingError> {
+        let pattern = UriPattern::parse(&http.uri)?;
+        let mut uri = pattern.expand(&self.labels)?;
+        let query = pattern
+            .query
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.query.iter().map(|(key, value)| {
+                format!(
+                    "{}={}",
+                    utf8_percent_encode(key, ENCODE_SET),
+                    utf8_percent_encode(value, ENCODE_SET)
+                )
+            }))
+            .collect::<Vec<_>>();
+        if !query.is_empty() {
+            uri.push('?');
+            uri.push_str(&query.join("&"));
+        }
+        let mut request = Request::builder()
+            .method(Method::from_bytes(http.method.as_bytes()).map_err(http::Error::from)?)
+            .uri(uri)
+            .body(self.payload.unwrap_or(body))?;
+        *request.headers_mut() = self.headers;
+        Ok(request)
+    }
+
+    /// Build an HTTP response.
+    ///
+    /// The status code bound using `@httpResponseCode` takes precedence over
+    /// `default_code` (typically the `code` of the operation's `@http` trait).
+    ///
+    /// # Errors
+    /// Returns [`HttpBindingError`] if `default_code` is not a valid status code.
+    pub fn into_response(
+        self,
+        default_code: u16,
+        body: Vec<u8>,
+    ) -> Result<Response<Vec<u8>>, HttpBindingError> {
+        let status = match self.status {
+            Some(status) => status,
+            None => StatusCode::from_u16(default_code).map_err(http::Error::from)?,
+        };
+        let mut response = Response::builder()
+            .status(status)
+            .body(self.payload.unwrap_or(body))?;
+        *response.headers_mut() = self.headers;
+        Ok(response)
+    }
+
+    /// Extract the bound values of a request for an operation with the provided `@http` trait.
+    ///
+    /// # Errors
+    /// Returns [`HttpBindingError`] if the URI pattern is invalid or the request
+    /// path does not match it.
+    pub fn from_request<B>(
+        request: &Request<B>,
+        http: &HttpTrait,
+    ) -> Result<Self, HttpBindingError> {
+        let pattern = UriPattern::parse(&http.uri)?;
+        let path = request.uri().path();
+        let labels = pattern
+            .match_path(path)
+            .ok_or_else(|| HttpBindingError::PathMismatch {
+                path: path.to_string(),
+                pattern: http.uri.clone(),
+            })?;
+        let query = request.uri().query().map(parse_query).unwrap_or_default();
+        Ok(HttpMessage {
+            labels,
+            query,
+            headers: request.headers().clone(),
+            status: None,
+            payload: None,
+        })
+    }
+
+    /// Returns `true` if a request matches the method and URI pattern of an `@http` trait.
+    ///
+    /// Literal query string parameters in the URI pattern (for example, `list` in
+    /// `/items?list`) must also be present in the request.
+    ///
+    /// # Errors
+    /// Returns [`HttpBindingError`] if the URI pattern is invalid.
+    pub fn matches<B>(request: &Request<B>, http: &HttpTrait) -> Result<bool, HttpBindingError> {
+        let pattern = UriPattern::parse(&http.uri)?;
+        Ok(request.method().as_str() == http.method
+            && pattern.match_path(request.uri().path()).is_some()
+            && pattern.match_query(request.uri().query()))
+    }
+
+    /// Extract the bound values of a response.
+    #[must_use]
+    pub fn from_response<B>(response: &Response<B>) -> Self {
+        HttpMessage {
+            headers: response.headers().clone(),
+            status: Some(response.status()),
+            ..HttpMessage::default()
+        }
+    }
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode(key), decode(value))
+        })
+        .collect()
+}
+
+fn decode(value: &str) -> String {
+    percent_decode_str(value).decode_utf8_lossy().into_owned()
+}
+
+fn is_identifier(value: &str) -> bool {
+    value.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// ============================================================================
+// URI Pattern
+// ============================================================================
+
+/// Parsed URI pattern of an [`@http`](https://smithy.io/2.0/spec/http-bindings.html#http-trait) trait.
+///
+/// Patterns are validated against the [Smithy HTTP binding rules](https://smithy.io/2.0/spec/http-bindings.html#uri):
+/// - The pattern starts with `/` and has no empty path segments.
+/// - Labels (`{name}`) span an entire path segment and are named with a valid identifier.
+/// - Label names are unique, and at most one label is greedy (`{name+}`).
+/// - The query string only contains unique, literal parameters (for example, `?list&kind=town`).
+///
+/// ```rust
+/// use smithy4rs_http_binding::UriPattern;
+///
+/// let pattern = UriPattern::parse("/cities/{city}/files/{path+}").unwrap();
+/// assert_eq!(
+///     pattern.match_path("/cities/New%20York/files/a/b.txt"),
+///     Some(vec![
+///         ("city".to_string(), "New York".to_string()),
+///         ("path".to_string(), "a/b.txt".to_string()),
+///     ])
+/// );
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct UriPattern<'p> {
+    segments: Vec<Segment<'p>>,
+    query: Option<&'p str>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Segment<'p> {
+    Literal(&'p str),
+    Label { name: &'p str, greedy: bool },
+}
+
+impl<'p> UriPattern<'p> {
+    /// Parse a URI pattern.
+    ///
+    /// # Errors
+    /// Returns [`HttpBindingError::InvalidPattern`] if the pattern does not follow
+    /// the Smithy URI pattern rules.
+    pub fn parse(pattern: &'p str) -> Result<Self, HttpBindingError> {
+        let invalid = || HttpBindingError::InvalidPattern(pattern.to_string());
+        if pattern.contains('#') {
+            return Err(invalid());
+        }
+        let (path, query) = match pattern.split_once('?') {
+            Some((path, query)) => (path, Some(query).filter(|q| !q.is_empty())),
+            None => (pattern, None),
+        };
+        let path = path.strip_prefix('/').ok_or_else(invalid)?;
+        let mut segments = Vec::new();
+        // A single trailing slash is allowed, but other empty segments are not.
+        for segment in path.split_terminator('/') {
+            let Some(label) = segment.strip_prefix('{') else {
+                if segment.is_empty() || segment.contains(['{', '}']) {
+                    return Err(invalid());
+                }
+                segments.push(Segment::Literal(segment));
+                continue;
+            };
+            let label = label.strip_suffix('}').ok_or_else(invalid)?;
+            let (name, greedy) = label
+                .strip_suffix('+')
+                .map_or((label, false), |name| (name, true));
+            if !is_identifier(name) {
+                return Err(invalid());
+            }
+            segments.push(Segment::Label { name, greedy });
+        }
+        let labels = segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Label { name, greedy } => Some((*name, *greedy)),
+                Segment::Literal(_) => None,
+            })
+            .collect::<Vec<_>>();
+        let duplicate_label = labels
+            .iter()
+            .enumerate()
+            .any(|(index, (name, _))| labels[..index].iter().any(|(other, _)| other == name));
+        if duplicate_label || labels.iter().filter(|(_, greedy)| *greedy).count() > 1 {
+            return Err(invalid());
+        }
+        if let Some(query) = query {
+            let keys = query
+                .split('&')
+                .map(|pair| pair.split_once('=').map_or(pair, |(key, _)| key))
+                .collect::<Vec<_>>();
+            let duplicate_key = keys
+                .iter()
+                .enumerate()
+                .any(|(index, key)| keys[..index].contains(key));
+            if duplicate_key || keys.contains(&"") || query.contains(['{', '}']) {
+                return Err(invalid());
+            }
+        }
+        Ok(UriPattern { segments, query })
+    }
+
+    /// Get the path portion of the pattern, with greedy label markers removed.
+    ///
+    /// Literal query string parameters are not included.
+    #[must_use]
+    pub fn template(&self) -> String {
+        let mut path = String::new();
+        for segment in &self.segments {
+            path.push('/');
+            match segment {
+                Segment::Literal(literal) => path.push_str(literal),
+                Segment::Label { name, .. } => {
+                    path.push('{');
+                    path.push_str(name);
+                    path.push('}');
+                }
+            }
+        }
+        if path.is_empty() {
+            path.push('/');
+        }
+        path
+    }
+
+    /// Build a path by substituting label values into the pattern.
+    fn expand(&self, labels: &[(String, String)]) -> Result<String, HttpBindingError> {
+        let mut path = String::new();
+        for segment in &self.segments {
+            path.push('/');
+            match segment {
+                Segment::Literal(literal) => path.push_str(literal),
+                Segment::Label { name, greedy } => {
+                    let value = labels
+                        .iter()
+                        .find(|(label, _)| label == name)
+                        .map(|(_, value)| value)
+                        .filter(|value| !value.is_empty())
+                        .ok_or_else(|| HttpBindingError::MissingLabel((*name).to_string()))?;
+                    if *greedy {
+                        let parts = value
+                            .split('/')
+                            .map(|part| utf8_percent_encode(part, ENCODE_SET).to_string())
+                            .collect::<Vec<_>>();
+                        path.push_str(&parts.join("/"));
+                    } else {
+                        path.extend(utf8_percent_encode(value, ENCODE_SET));
+                    }
+                }
+            }
+        }
+        if path.is_empty() {
+            path.push('/');
+        }
+        Ok(path)
+    }
+
+    /// Match a request path against the pattern, returning the decoded label values.
+    ///
+    /// Path segments are percent-decoded before they are compared with literal segments.
+    /// A greedy label matches one or more segments, and its value keeps the `/` separators
+    /// between them (including empty segments, so `a//b` is matched as-is).
+    ///
+    /// Empty segments are otherwise significant: a non-greedy label never matches an
+    /// empty segment, so `/cities//files` does not match `/cities/{city}/files`.
+    /// A single trailing `/` is ignored.
+    ///
+    /// Returns `None` if the path does not match.
+    #[must_use]
+    pub fn match_path(&self, path: &str) -> Option<Vec<(String, String)>> {
+        let path = path.strip_prefix('/').unwrap_or(path);
+        let path = path.strip_suffix('/').unwrap_or(path);
+        let parts = if path.is_empty() {
+            Vec::new()
+        } else {
+            path.split('/').collect::<Vec<_>>()
+        };
+        let mut labels = Vec::new();
+        let mut index = 0;
+        for (position, segment) in self.segments.iter().enumerate() {
+            match segment {
+                Segment::Literal(literal) => {
+                    if decode(parts.get(index)?) != *literal {
+                        return None;
+                    }
+                    index += 1;
+                }
+                Segment::Label {
+                    name,
+                    greedy: false,
+                } => {
+                    let part = parts.get(index).filter(|part| !part.is_empty())?;
+                    labels.push(((*name).to_string(), decode(part)));
+                    index += 1;
+                }
+                Segment::Label { name, greedy: true } => {
+                    // Greedy labels consume every segment not needed by the rest of the pattern
+                    let remaining = self.segments.len() - position - 1;
+                    let end = parts.len().checked_sub(remaining)?;
+                    if end <= index {
+                        return None;
+                    }
+                    let value = parts[index..end]
+                        .iter()
+                        .map(|part| decode(part))
+                        .collect::<Vec<_>>()
+                        .join("/");
+                    if value.is_empty() {
+                        return None;
+                    }
+                    labels.push(((*name).to_string(), value));
+                    index = end;
+                }
+            }
+        }
+        (index == parts.len()).then_some(labels)
+    }
+
+    /// Returns `true` if a request query string contains every literal query
+    /// parameter of the pattern (for example, `list` in `/items?list`).
+    ///
+    /// Parameters with a value in the pattern (`?kind=town`) must have the same
+    /// value in the request.
+    #[must_use]
+    pub fn match_query(&self, query: Option<&str>) -> bool {
+        let Some(expected) = self.query else {
+            return true;
+        };
+        let query = query.map(parse_query).unwrap_or_default();
+        parse_query(expected).iter().all(|(key, value)| {
+            query
+                .iter()
+                .any(|(k, v)| k == key && (value.is_empty() || v == value))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(values: &[(&str, &str)]) -> Vec<(String, String)> {
+        values
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_pattern() {
+        let pattern = UriPattern::parse("/cities/{city}/files/{path+}?list").unwrap();
+        assert_eq!(
+            pattern.segments,
+            vec![
+                Segment::Literal("cities"),
+                Segment::Label {
+                    name: "city",
+                    greedy: false
+                },
+                Segment::Literal("files"),
+                Segment::Label {
+                    name: "path",
+                    greedy: true
+                },
+            ]
+        );
+        assert_eq!(pattern.query, Some("list"));
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        assert!(UriPattern::parse("cities").is_err());
+        assert!(UriPattern::parse("/cities/{city").is_err());
+        assert!(UriPattern::parse("/cities/{}").is_err());
+    }
+
+    #[test]
+    fn rejects_patterns_breaking_uri_rules() {
+        for pattern in [
+            "/cities//{city}",
+            "/cities/{city}/{city}",
+            "/{a+}/{b+}",
+            "/cities/prefix{city}",
+            "/cities/{city}suffix",
+            "/cities/{city-name}",
+            "/cities/{+}",
+            "/cities#fragment",
+            "/cities?list&list",
+            "/cities?kind={kind}",
+            "/cities?&list",
+        ] {
+            assert!(
+                UriPattern::parse(pattern).is_err(),
+                "{pattern} should be invalid"
+            );
+        }
+        assert!(UriPattern::parse("/cities/{city}/").is_ok());
+        assert!(UriPattern::parse("/{_city}/{path+}?list&kind=town").is_ok());
+    }
+
+    #[test]
+    fn expands_labels() {
+        let pattern = UriPattern::parse("/cities/{city}/files/{path+}").unwrap();
+        let path = pattern
+            .expand(&labels(&[("city", "New York"), ("path", "a b/c.txt")]))
+            .unwrap();
+        assert_eq!(path, "/cities/New%20York/files/a%20b/c.txt");
+        assert!(matches!(
+            pattern.expand(&labels(&[("city", "Seattle")])),
+            Err(HttpBindingError::MissingLabel(name)) if name == "path"
+        ));
+    }
+
+    #[test]
+    fn matches_paths() {
+        let pattern = UriPattern::parse("/cities/{city}/files/{path+}/meta").unwrap();
+        assert_eq!(
+            pattern.match_path("/cities/New%20York/files/a/b.txt/meta"),
+            Some(labels(&[("city", "New York"), ("path", "a/b.txt")]))
+        );
+        assert_eq!(pattern.match_path("/cities/Seattle/files/meta"), None);
+        assert_eq!(pattern.match_path("/towns/Seattle/files/a/meta"), None);
+    }
+
+    #[test]
+    fn matches_empty_segments() {
+        let pattern = UriPattern::parse("/cities/{city}/files/{path+}").unwrap();
+        assert_eq!(pattern.match_path("/cities//files/a"), None);
+        assert_eq!(pattern.match_path("/cities/Seattle/files//"), None);
+        assert_eq!(
+            pattern.match_path("/cities/Seattle/files/a//b"),
+            Some(labels(&[("city", "Seattle"), ("path", "a//b")]))
+        );
+        assert_eq!(
+            pattern.match_path("/cities/Seattle/files/a/"),
+            Some(labels(&[("city", "Seattle"), ("path", "a")]))
+        );
+        let pattern = UriPattern::parse("/cities/{city}").unwrap();
+        assert_eq!(pattern.match_path("/cities/"), None);
+        assert_eq!(pattern.match_path("//cities/Seattle"), None);
+    }
+
+    #[test]
+    fn matches_encoded_paths() {
+        let pattern = UriPattern::parse("/caf\u{e9}/{name}/{path+}").unwrap();
+        assert_eq!(
+            pattern.match_path("/caf%C3%A9/a%2Fb/c/%7Bd%7D"),
+            Some(labels(&[("name", "a/b"), ("path", "c/{d}")]))
+        );
+        assert_eq!(pattern.match_path("/cafe/a/b"), None);
+    }
+
+    #[test]
+    fn renders_template() {
+        let pattern = UriPattern::parse("/cities/{city}/files/{path+}?list").unwrap();
+        assert_eq!(pattern.template(), "/cities/{city}/files/{path}");
+        assert_eq!(UriPattern::parse("/").unwrap().template(), "/");
+    }
+
+    #[test]
+    fn matches_root() {
+        let pattern = UriPattern::parse("/").unwrap();
+        assert_eq!(pattern.expand(&[]).unwrap(), "/");
+        assert_eq!(pattern.match_path("/"), Some(Vec::new()));
+        assert_eq!(pattern.match_path("/a"), None);
+    }
+
+    #[test]
+    fn matches_requests() {
+        let http = HttpTrait::builder()
+            .method("GET".to_string())
+            .uri("/cities/{city}?list&kind=town".to_string())
+            .build()
+            .unwrap();
+        let request =
+            |method: &str, uri: &str| Request::builder().method(method).uri(uri).body(()).unwrap();
+        assert!(
+            HttpMessage::matches(&request("GET", "/cities/Seattle?kind=town&list"), &http).unwrap()
+        );
+        assert!(
+            !HttpMessage::matches(&request("PUT", "/cities/Seattle?list&kind=town"), &http)
+                .unwrap()
+        );
+        assert!(!HttpMessage::matches(&request("GET", "/cities/Seattle?list"), &http).unwrap());
+        assert!(
+            !HttpMessage::matches(&request("GET", "/towns/Seattle?list&kind=town"), &http).unwrap()
+        );
+    }
+
+    #[test]
+    fn matches_query() {
+        let pattern = UriPattern::parse("/cities?list&kind=town").unwrap();
+        assert!(pattern.match_query(Some("kind=town&list=1")));
+        assert!(!pattern.match_query(Some("kind=city&list")));
+        assert!(!pattern.match_query(None));
+        assert!(UriPattern::parse("/cities").unwrap().match_query(None));
+    }
+
+    #[test]
+    fn parses_query() {
+        assert_eq!(
+            parse_query("a=1&b=hello%20world&flag&&a=2"),
+            labels(&[("a", "1"), ("b", "hello world"), ("flag", ""), ("a", "2")])
+        );
+    }
+}
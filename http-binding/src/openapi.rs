@@ -0,0 +1,520 @@
+//! # OpenAPI
+//!
+//! Generates an [OpenAPI 3.1](https://spec.openapis.org/oas/v3.1.0) document from
+//! operation schemas and their [HTTP binding traits](https://smithy.io/2.0/spec/http-bindings.html).
+//!
+//! Each operation is added as a path item using the method and URI of its `@http`
+//! trait. Input members bound with `@httpLabel`, `@httpQuery`, `@httpQueryParams`,
+//! and `@httpHeader` become parameters, and the remaining members become the request
+//! body. Structures, unions, and enums are written as JSON Schemas to
+//! `components.schemas` and referenced by name.
+//!
+//! The generated document is returned as a [`Document`] so that it can be written
+//! with any codec:
+//!
+//! ```rust,ignore
+//! let mut builder = OpenApiBuilder::new("Item Service", "2024-01-01");
+//! builder.operation(&GET_ITEM)?.operation(&PUT_ITEM)?;
+//! let document = builder.build();
+//! let json = JsonCodec.serialize_document(document.schema(), &document)?;
+//! ```
+//!
+//! <div class="note">
+//! **NOTE**: `@httpPrefixHeaders` members cannot be described by OpenAPI parameters
+//! and are omitted. Shapes are keyed by name only, so shapes with the same name in
+//! different namespaces will collide.
+//! </div>
+
+use smithy4rs_core::{
+    IndexMap,
+    prelude::{
+        DeprecatedTrait, DocumentationTrait, HttpHeaderTrait, HttpQueryTrait, HttpTrait,
+        LengthTrait, PatternTrait, RangeTrait, RequiredTrait, TimestampFormatTrait,
+        UniqueItemsTrait,
+    },
+    schema::{Document, MemberSchema, Operation, Schema, ShapeType, StaticSchemaShape},
+    serde::http::{HttpBinding, error_code},
+};
+
+use crate::{errors::HttpBindingError, message::UriPattern};
+
+type Object = IndexMap<String, Box<dyn Document>>;
+
+/// Version of the OpenAPI specification the generated document conforms to.
+const OPENAPI_VERSION: &str = "3.1.0";
+
+/// Builds an OpenAPI document from operation descriptors.
+pub struct OpenApiBuilder {
+    title: String,
+    version: String,
+    content_type: String,
+    paths: IndexMap<String, Object>,
+    schemas: Object,
+}
+
+impl OpenApiBuilder {
+    /// Create a new builder for an API with the given title and version.
+    ///
+    /// Request and response bodies default to the `application/json` media type.
+    #[must_use]
+    pub fn new(title: impl Into<String>, version: impl Into<String>) -> Self {
+        OpenApiBuilder {
+            title: title.into(),
+            version: version.into(),
+            content_type: "application/json".to_string(),
+            paths: IndexMap::new(),
+            schemas: IndexMap::new(),
+        }
+    }
+
+    /// Set the media type of structured request and response bodies.
+    #[must_use]
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = content_type.into();
+        self
+    }
+
+    /// Add an operation to the document.
+    ///
+    /// # Errors
+    /// Returns [`HttpBindingError`] if the operation has no `@http` trait or
+    /// its URI pattern is invalid.
+    pub fn operation<I, O, E>(
+        &mut self,
+        operation: &Operation<I, O, E>,
+    ) -> Result<&mut Self, HttpBindingError>
+    where
+        I: StaticSchemaShape,
+        O: StaticSchemaShape,
+        E: StaticSchemaShape,
+    {
+        let schema = operation.schema();
+        let http = schema
+            .get_trait_as::<HttpTrait>()
+            .ok_or_else(|| HttpBindingError::MissingHttpTrait(schema.id().id().to_string()))?;
+        let path = UriPattern::parse(&http.uri)?.template();
+
+        let mut item = Object::new();
+        item.insert("operationId".to_string(), schema.id().name().into());
+        describe(schema, &mut item);
+        self.request(operation.input_schema(), &mut item);
+        let mut responses = Object::new();
+        responses.insert(
+            http.code.to_string(),
+            self.response(operation.output_schema()),
+        );
+        self.errors(operation.error_schema(), &mut responses);
+        item.insert("responses".to_string(), responses.into());
+
+        self.paths
+            .entry(path)
+            .or_default()
+            .insert(http.method.to_lowercase(), item.into());
+        Ok(self)
+    }
+
+    /// Build the OpenAPI document.
+    #[must_use]
+    pub fn build(self) -> Box<dyn Document> {
+        let mut info = Object::new();
+        info.insert("title".to_string(), self.title.into());
+        info.insert("version".to_string(), self.version.into());
+
+        let mut document = Object::new();
+        document.insert("openapi".to_string(), OPENAPI_VERSION.into());
+        document.insert("info".to_string(), info.into());
+        document.insert("paths".to_string(), self.paths.into());
+        if !self.schemas.is_empty() {
+            let mut components = Object::new();
+            components.insert("schemas".to_string(), self.schemas.into());
+            document.insert("components".to_string(), components.into());
+        }
+        document.into()
+    }
+
+    // ======== Operations =======
+
+    /// Add parameters and a request body for the members of an operation input.
+    fn request(&mut self, input: &Schema, item: &mut Object) {
+        let mut parameters = Vec::new();
+        let mut payload = None;
+        let mut body = Vec::new();
+        for member in input.members().values() {
+            let binding = HttpBinding::of(member);
+            let (name, location) = match binding {
+                HttpBinding::Label => (member_name(member), "path"),
+                HttpBinding::Query => (
+                    member
+                        .get_trait_as::<HttpQueryTrait>()
+                        .map_or("", |name| name.as_str()),
+                    "query",
+                ),
+                HttpBinding::QueryParams => (member_name(member), "query"),
+                HttpBinding::Header => (
+                    member
+                        .get_trait_as::<HttpHeaderTrait>()
+                        .map_or("", |name| name.as_str()),
+                    "header",
+                ),
+                HttpBinding::Payload => {
+                    payload = Some(member);
+                    continue;
+                }
+                HttpBinding::Body => {
+                    body.push(member);
+                    continue;
+                }
+                HttpBinding::PrefixHeaders | HttpBinding::ResponseCode => continue,
+            };
+            let mut parameter = Object::new();
+            parameter.insert("name".to_string(), name.into());
+            parameter.insert("in".to_string(), location.into());
+            if binding == HttpBinding::Label || member.contains_type::<RequiredTrait>() {
+                parameter.insert("required".to_string(), true.into());
+            }
+            if binding == HttpBinding::QueryParams {
+                parameter.insert("style".to_string(), "form".into());
+                parameter.insert("explode".to_string(), true.into());
+            }
+            describe(member, &mut parameter);
+            parameter.insert("schema".to_string(), self.member(member, binding));
+            parameters.push(parameter);
+        }
+        if !parameters.is_empty() {
+            item.insert("parameters".to_string(), parameters.into());
+        }
+
+        let mut request = Object::new();
+        if let Some(payload) = payload {
+            if payload.contains_type::<RequiredTrait>() {
+                request.insert("required".to_string(), true.into());
+            }
+            request.insert("content".to_string(), self.payload(payload));
+        } else if !body.is_empty() {
+            request.insert("required".to_string(), true.into());
+            request.insert("content".to_string(), self.body(input, &body));
+        } else {
+            return;
+        }
+        item.insert("requestBody".to_string(), request.into());
+    }
+
+    /// Create a response for the members of an operation output.
+    fn response(&mut self, output: &Schema) -> Box<dyn Document> {
+        let mut headers = Object::new();
+        let mut payload = None;
+        let mut body = Vec::new();
+        for member in output.members().values() {
+            match HttpBinding::of(member) {
+                HttpBinding::Header => {
+                    let name = member
+                        .get_trait_as::<HttpHeaderTrait>()
+                        .map_or("", |name| name.as_str());
+                    let mut header = Object::new();
+                    describe(member, &mut header);
+                    header.insert(
+                        "schema".to_string(),
+                        self.member(member, HttpBinding::Header),
+                    );
+                    headers.insert(name.to_string(), header.into());
+                }
+                HttpBinding::Payload => payload = Some(member),
+                HttpBinding::Body => body.push(member),
+                _ => {}
+            }
+        }
+
+        let mut response = Object::new();
+        response.insert("description".to_string(), output.id().name().into());
+        if !headers.is_empty() {
+            response.insert("headers".to_string(), headers.into());
+        }
+        if let Some(payload) = payload {
+            response.insert("content".to_string(), self.payload(payload));
+        } else if !body.is_empty() {
+            response.insert("content".to_string(), self.body(output, &body));
+        }
+        response.into()
+    }
+
+    /// Add a response for each status code returned by the members of an error union.
+    ///
+    /// Errors that share a status code are combined with `oneOf`.
+    fn errors(&mut self, errors: &Schema, responses: &mut Object) {
+        let mut by_code: IndexMap<String, Vec<&Schema>> = IndexMap::new();
+        for member in errors.members().values() {
+            by_code
+                .entry(error_code(member).to_string())
+                .or_default()
+                .push(member);
+        }
+        for (code, members) in by_code {
+            let description = members
+                .iter()
+                .map(|member| target(member).id().name())
+                .collect::<Vec<_>>()
+                .join(" | ");
+            let mut schemas = members
+                .into_iter()
+                .map(|member| self.member(member, HttpBinding::Body))
+                .collect::<Vec<_>>();
+            let schema = if schemas.len() == 1 {
+                schemas.remove(0)
+            } else {
+                let mut one_of = Object::new();
+                one_of.insert("oneOf".to_string(), schemas.into());
+                one_of.into()
+            };
+            let mut response = Object::new();
+            response.insert("description".to_string(), description.into());
+            response.insert(
+                "content".to_string(),
+                self.media(&self.content_type, schema),
+            );
+            responses.insert(code, response.into());
+        }
+    }
+
+    /// Create the content of a message body made up of the given members.
+    ///
+    /// Shapes with only body members are referenced directly.
+    fn body(&mut self, shape: &Schema, members: &[&Schema]) -> Box<dyn Document> {
+        let schema = if members.len() == shape.members().len() {
+            self.reference(shape)
+        } else {
+            self.structure(members.iter().copied()).into()
+        };
+        self.media(&self.content_type, schema)
+    }
+
+    /// Create the content of a message body bound to an `@httpPayload` member.
+    fn payload(&mut self, member: &Schema) -> Box<dyn Document> {
+        let content_type = match member.shape_type() {
+            ShapeType::Blob => "application/octet-stream",
+            ShapeType::String => "text/plain",
+            _ => &self.content_type,
+        }
+        .to_string();
+        let schema = self.member(member, HttpBinding::Payload);
+        self.media(&content_type, schema)
+    }
+
+    fn media(&self, content_type: &str, schema: Box<dyn Document>) -> Box<dyn Document> {
+        let mut media = Object::new();
+        media.insert("schema".to_string(), schema);
+        let mut content = Object::new();
+        content.insert(content_type.to_string(), media.into());
+        content.into()
+    }
+
+    // ======== JSON Schema =======
+
+    /// Create a JSON Schema for a member or list, map, or scalar shape.
+    ///
+    /// Aggregate and enum shapes are added to the components of the document
+    /// and referenced by name.
+    fn member(&mut self, schema: &Schema, binding: HttpBinding) -> Box<dyn Document> {
+        match schema.shape_type() {
+            ShapeType::Structure | ShapeType::Union | ShapeType::Enum | ShapeType::IntEnum => {
+                self.reference(target(schema))
+            }
+            _ => self.inline(schema, binding).into(),
+        }
+    }
+
+    /// Get a reference to a named shape, adding it to the components of the document
+    /// if it has not been seen yet.
+    fn reference(&mut self, schema: &Schema) -> Box<dyn Document> {
+        let name = schema.id().name();
+        if !self.schemas.contains_key(name) {
+            // Insert a placeholder so recursive references resolve to this entry
+            self.schemas.insert(name.to_string(), Object::new().into());
+            let mut definition = match schema.shape_type() {
+                ShapeType::Structure => self.structure(schema.members().values()),
+                ShapeType::Union => self.union(schema),
+                _ => self.inline(schema, HttpBinding::Body),
+            };
+            describe(schema, &mut definition);
+            self.schemas.insert(name.to_string(), definition.into());
+        }
+        let mut reference = Object::new();
+        reference.insert(
+            "$ref".to_string(),
+            format!("#/components/schemas/{name}").into(),
+        );
+        reference.into()
+    }
+
+    fn structure<'s>(&mut self, members: impl Iterator<Item = &'s Schema>) -> Object {
+        let mut properties = Object::new();
+        let mut required = Vec::new();
+        for member in members {
+            let name = member_name(member);
+            if member.contains_type::<RequiredTrait>() {
+                required.push(name);
+            }
+            properties.insert(name.to_string(), self.member(member, HttpBinding::Body));
+        }
+        let mut object = Object::new();
+        object.insert("type".to_string(), "object".into());
+        object.insert("properties".to_string(), properties.into());
+        if !required.is_empty() {
+            object.insert("required".to_string(), required.into());
+        }
+        object
+    }
+
+    fn union(&mut self, schema: &Schema) -> Object {
+        let variants = schema
+            .members()
+            .values()
+            .map(|member| {
+                let name = member_name(member);
+                let mut properties = Object::new();
+                properties.insert(name.to_string(), self.member(member, HttpBinding::Body));
+                let mut variant = Object::new();
+                variant.insert("type".to_string(), "object".into());
+                variant.insert("properties".to_string(), properties.into());
+                variant.insert("required".to_string(), vec![name].into());
+                variant.into()
+            })
+            .collect::<Vec<Box<dyn Document>>>();
+        let mut object = Object::new();
+        object.insert("oneOf".to_string(), variants.into());
+        object
+    }
+
+    fn inline(&mut self, schema: &Schema, binding: HttpBinding) -> Object {
+        let mut object = Object::new();
+        let mut set = |key: &str, value: Box<dyn Document>| {
+            object.insert(key.to_string(), value);
+        };
+        match schema.shape_type() {
+            ShapeType::Boolean => set("type", "boolean".into()),
+            ShapeType::Byte | ShapeType::Short | ShapeType::Integer => {
+                set("type", "integer".into());
+                set("format", "int32".into());
+            }
+            ShapeType::Long => {
+                set("type", "integer".into());
+                set("format", "int64".into());
+            }
+            ShapeType::BigInteger => set("type", "integer".into()),
+            ShapeType::Float => {
+                set("type", "number".into());
+                set("format", "float".into());
+            }
+            ShapeType::Double => {
+                set("type", "number".into());
+                set("format", "double".into());
+            }
+            ShapeType::BigDecimal => set("type", "number".into()),
+            ShapeType::String => set("type", "string".into()),
+            ShapeType::Blob => {
+                set("type", "string".into());
+                if binding == HttpBinding::Payload {
+                    set("format", "binary".into());
+                } else {
+                    set("contentEncoding", "base64".into());
+                }
+            }
+            ShapeType::Timestamp => {
+                match binding.timestamp_format(schema, &TimestampFormatTrait::EpochSeconds) {
+                    TimestampFormatTrait::EpochSeconds => set("type", "number".into()),
+                    TimestampFormatTrait::DateTime => {
+                        set("type", "string".into());
+                        set("format", "date-time".into());
+                    }
+                    _ => set("type", "string".into()),
+                }
+            }
+            ShapeType::Enum => {
+                set("type", "string".into());
+                if let Some(values) = target(schema).as_enum() {
+                    set(
+                        "enum",
+                        values.values().iter().copied().collect::<Vec<_>>().into(),
+                    );
+                }
+            }
+            ShapeType::IntEnum => {
+                set("type", "integer".into());
+                if let Some(values) = target(schema).as_int_enum() {
+                    set(
+                        "enum",
+                        values.values().iter().copied().collect::<Vec<_>>().into(),
+                    );
+                }
+            }
+            ShapeType::List => {
+                set("type", "array".into());
+                if let Some(member) = schema.get_list_member() {
+                    set("items", self.member(member, binding));
+                }
+            }
+            ShapeType::Map => {
+                set("type", "object".into());
+                if let Some((_, value)) = schema.get_key_value() {
+                    set("additionalProperties", self.member(value, binding));
+                }
+            }
+            // Documents may hold any value
+            _ => {}
+        }
+        constraints(schema, &mut object);
+        object
+    }
+}
+
+/// Get the shape targeted by a member, or the shape itself if it is not a member.
+fn target(schema: &Schema) -> &Schema {
+    schema.as_member().map_or(schema, MemberSchema::target)
+}
+
+fn member_name(schema: &Schema) -> &str {
+    schema
+        .as_member()
+        .map_or_else(|| schema.id().name(), MemberSchema::name)
+}
+
+/// Add `description` and `deprecated` properties for documentation traits.
+fn describe(schema: &Schema, object: &mut Object) {
+    if let Some(docs) = schema.get_trait_as::<DocumentationTrait>() {
+        object.insert("description".to_string(), docs.as_str().into());
+    }
+    if schema.contains_type::<DeprecatedTrait>() {
+        object.insert("deprecated".to_string(), true.into());
+    }
+}
+
+/// Add JSON Schema validation keywords for constraint traits.
+fn constraints(schema: &Schema, object: &mut Object) {
+    let length_keys = match schema.shape_type() {
+        ShapeType::String => Some(("minLength", "maxLength")),
+        ShapeType::List => Some(("minItems", "maxItems")),
+        ShapeType::Map => Some(("minProperties", "maxProperties")),
+        _ => None,
+    };
+    if let (Some(length), Some((min, max))) = (schema.get_trait_as::<LengthTrait>(), length_keys) {
+        if let Some(value) = length.min {
+            object.insert(min.to_string(), value.into());
+        }
+        if let Some(value) = length.max {
+            object.insert(max.to_string(), value.into());
+        }
+    }
+    if let Some(pattern) = schema.get_trait_as::<PatternTrait>() {
+        object.insert("pattern".to_string(), pattern.as_str().into());
+    }
+    if let Some(range) = schema.get_trait_as::<RangeTrait>() {
+        if let Some(value) = &range.min {
+            object.insert("minimum".to_string(), value.clone().into());
+        }
+        if let Some(value) = &range.max {
+            object.insert("maximum".to_string(), value.clone().into());
+        }
+    }
+    if schema.contains_type::<UniqueItemsTrait>() {
+        object.insert("uniqueItems".to_string(), true.into());
+    }
+}
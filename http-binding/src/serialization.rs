@@ -0,0 +1,651 @@
+use std::{convert::Infallible, marker::PhantomData};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use http::{HeaderName, HeaderValue, StatusCode};
+use smithy4rs_core::{
+    BigDecimal, BigInt, Instant,
+    prelude::{HttpHeaderTrait, HttpPrefixHeadersTrait, HttpQueryTrait, TimestampFormatTrait},
+    schema::{Blob, Schema, ShapeType, StreamingBlob, Timestamp},
+    serde::{
+        http::HttpBinding,
+        se::{Error, ListWriter, MapWriter, SerializeWithSchema, Serializer, StructWriter},
+    },
+};
+
+use crate::message::HttpMessage;
+
+/// Generate serializer methods that reject values that cannot be bound.
+macro_rules! unsupported {
+    ($msg:expr; $($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, _: &Schema, _: $ty) -> Result<Self::Ok, Self::Error> {
+                Err(Error::custom($msg))
+            }
+        )*
+    };
+}
+
+// ============================================================================
+// Binding Serializer
+// ============================================================================
+
+/// [`Serializer`] that binds the members of a structure to an HTTP message.
+///
+/// Members with an HTTP binding trait are written to the URI, query string,
+/// headers, or status code of the resulting [`HttpMessage`]. All other members
+/// are written to the wrapped body serializer `S`. A member bound with
+/// `@httpPayload` is written as the raw message body if it targets a `blob`,
+/// `string`, or `enum`, and with the body serializer otherwise.
+///
+/// ```rust,ignore
+/// let mut body = Vec::new();
+/// let message = input.serialize_with_schema(
+///     &GET_CITY_INPUT_SCHEMA,
+///     HttpBindingSerializer::new(JsonSerializer::new(&mut body)),
+/// )?;
+/// let request = message.into_request(&http_trait, body)?;
+/// ```
+pub struct HttpBindingSerializer<S> {
+    body: S,
+}
+
+impl<S> HttpBindingSerializer<S> {
+    /// Create a new binding serializer that writes unbound members to `body`.
+    pub const fn new(body: S) -> Self {
+        HttpBindingSerializer { body }
+    }
+}
+
+impl<S: Serializer> Serializer for HttpBindingSerializer<S> {
+    type Error = S::Error;
+    type Ok = HttpMessage;
+    type ListWriter = Unsupported<HttpMessage, S::Error>;
+    type MapWriter = Unsupported<HttpMessage, S::Error>;
+    type StructWriter = HttpBindingStructWriter<S>;
+
+    fn write_struct(self, schema: &Schema, _len: usize) -> Result<Self::StructWriter, Self::Error> {
+        let body_len = schema
+            .members()
+            .values()
+            .filter(|member| HttpBinding::of(member) == HttpBinding::Body)
+            .count();
+        // Structures with body members always have a body, even if every member is unset.
+        let body = if body_len > 0 {
+            Body::Struct(self.body.write_struct(schema, body_len)?)
+        } else {
+            Body::Pending(self.body)
+        };
+        Ok(HttpBindingStructWriter {
+            message: HttpMessage::default(),
+            query_params: Vec::new(),
+            body,
+        })
+    }
+
+    fn write_map(self, _: &Schema, _: usize) -> Result<Self::MapWriter, Self::Error> {
+        Err(Error::custom(NOT_A_STRUCTURE))
+    }
+
+    fn write_list(self, _: &Schema, _: usize) -> Result<Self::ListWriter, Self::Error> {
+        Err(Error::custom(NOT_A_STRUCTURE))
+    }
+
+    unsupported!(
+        NOT_A_STRUCTURE;
+        write_boolean(bool),
+        write_byte(i8),
+        write_short(i16),
+        write_integer(i32),
+        write_long(i64),
+        write_float(f32),
+        write_double(f64),
+        write_big_integer(&BigInt),
+        write_big_decimal(&BigDecimal),
+        write_string(&str),
+        write_blob(&Blob),
+        write_timestamp(&Instant),
+    );
+
+    fn write_null(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        Err(Error::custom(NOT_A_STRUCTURE))
+    }
+
+    fn skip(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        Err(Error::custom(NOT_A_STRUCTURE))
+    }
+}
+
+const NOT_A_STRUCTURE: &str = "HTTP bindings can only be applied to structures";
+
+enum Body<S: Serializer> {
+    Pending(S),
+    Struct(S::StructWriter),
+    Done,
+}
+
+/// [`StructWriter`] for [`HttpBindingSerializer`].
+pub struct HttpBindingStructWriter<S: Serializer> {
+    message: HttpMessage,
+    query_params: Vec<(String, String)>,
+    body: Body<S>,
+}
+
+impl<S: Serializer> HttpBindingStructWriter<S> {
+    fn write_payload<T: SerializeWithSchema>(
+        &mut self,
+        member_schema: &Schema,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        if matches!(
+            member_schema.shape_type(),
+            ShapeType::Blob | ShapeType::String | ShapeType::Enum
+        ) {
+            let mut payload = Vec::new();
+            value.serialize_with_schema(member_schema, PayloadSerializer::new(&mut payload))?;
+            self.message.payload = Some(payload);
+            return Ok(());
+        }
+        match std::mem::replace(&mut self.body, Body::Done) {
+            Body::Pending(body) => value.serialize_with_schema(member_schema, body).map(|_| ()),
+            _ => Err(Error::custom("HTTP payload has already been written")),
+        }
+    }
+}
+
+impl<S: Serializer> StructWriter for HttpBindingStructWriter<S> {
+    type Error = S::Error;
+    type Ok = HttpMessage;
+
+    fn write_member<T>(&mut self, member_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let binding = HttpBinding::of(member_schema);
+        match binding {
+            HttpBinding::Body => match &mut self.body {
+                Body::Struct(writer) => writer.write_member(member_schema, value),
+                _ => Err(Error::custom("HTTP body has already been written")),
+            },
+            HttpBinding::Payload => self.write_payload(member_schema, value),
+            HttpBinding::Header => {
+                let name = member_schema
+                    .get_trait_as::<HttpHeaderTrait>()
+                    .map_or("", |name| name.as_str());
+                let values = bound_values(binding, name, member_schema, value)?;
+                if !values.is_empty() {
+                    let value = values
+                        .into_iter()
+                        .map(|(_, value)| value)
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    append_header(&mut self.message, name, &value)?;
+                }
+                Ok(())
+            }
+            HttpBinding::PrefixHeaders => {
+                let prefix = member_schema
+                    .get_trait_as::<HttpPrefixHeadersTrait>()
+                    .map_or("", |prefix| prefix.as_str());
+                for (suffix, value) in bound_values(binding, "", member_schema, value)? {
+                    append_header(&mut self.message, &format!("{prefix}{suffix}"), &value)?;
+                }
+                Ok(())
+            }
+            HttpBinding::Query => {
+                let name = member_schema
+                    .get_trait_as::<HttpQueryTrait>()
+                    .map_or("", |name| name.as_str());
+                let values = bound_values(binding, name, member_schema, value)?;
+                self.message.query.extend(values);
+                Ok(())
+            }
+            HttpBinding::QueryParams => {
+                let values = bound_values(binding, "", member_schema, value)?;
+                self.query_params.extend(values);
+                Ok(())
+            }
+            HttpBinding::Label => {
+                let name = member_name(member_schema);
+                let values = bound_values(binding, name, member_schema, value)?;
+                self.message.labels.extend(values);
+                Ok(())
+            }
+            HttpBinding::ResponseCode => {
+                let values = bound_values(binding, "", member_schema, value)?;
+                if let Some((_, code)) = values.first() {
+                    let status = code
+                        .parse::<u16>()
+                        .ok()
+                        .and_then(|code| StatusCode::from_u16(code).ok())
+                        .ok_or_else(|| Error::custom(format!("Invalid status code: {code}")))?;
+                    self.message.status = Some(status);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn end(mut self, schema: &Schema) -> Result<Self::Ok, Self::Error> {
+        // Explicit `@httpQuery` bindings take precedence over `@httpQueryParams`
+        for (key, value) in self.query_params {
+            if !self.message.query.iter().any(|(bound, _)| *bound == key) {
+                self.message.query.push((key, value));
+            }
+        }
+        if let Body::Struct(writer) = self.body {
+            writer.end(schema)?;
+        }
+        Ok(self.message)
+    }
+}
+
+fn member_name(member_schema: &Schema) -> &str {
+    member_schema.as_member().map_or("", |member| member.name())
+}
+
+fn bound_values<T: SerializeWithSchema, E: Error>(
+    binding: HttpBinding,
+    key: &str,
+    member_schema: &Schema,
+    value: &T,
+) -> Result<Vec<(String, String)>, E> {
+    let mut values = Vec::new();
+    value.serialize_with_schema(
+        member_schema,
+        ValueSerializer::new(binding, key, &mut values),
+    )?;
+    Ok(values)
+}
+
+fn append_header<E: Error>(message: &mut HttpMessage, name: &str, value: &str) -> Result<(), E> {
+    let name = HeaderName::from_bytes(name.as_bytes())
+        .map_err(|e| Error::custom(format!("Invalid header name `{name}`: {e}")))?;
+    let value = HeaderValue::from_str(value)
+        .map_err(|e| Error::custom(format!("Invalid value for header `{name}`: {e}")))?;
+    message.headers.append(name, value);
+    Ok(())
+}
+
+// ============================================================================
+// Bound Value Serializer
+// ============================================================================
+
+/// Serializes bound values into `(key, value)` string pairs.
+///
+/// Scalars produce a single pair using the provided `key`, list elements produce
+/// one pair each, and map entries produce pairs keyed by the entry key.
+struct ValueSerializer<'a, E> {
+    binding: HttpBinding,
+    key: &'a str,
+    in_list: bool,
+    out: &'a mut Vec<(String, String)>,
+    _error: PhantomData<E>,
+}
+
+impl<'a, E> ValueSerializer<'a, E> {
+    const fn new(binding: HttpBinding, key: &'a str, out: &'a mut Vec<(String, String)>) -> Self {
+        ValueSerializer {
+            binding,
+            key,
+            in_list: false,
+            out,
+            _error: PhantomData,
+        }
+    }
+
+    fn push(self, value: String) {
+        self.out.push((self.key.to_string(), value));
+    }
+}
+
+impl<'a, E: Error> Serializer for ValueSerializer<'a, E> {
+    type Error = E;
+    type Ok = ();
+    type ListWriter = ValueListWriter<'a, E>;
+    type MapWriter = ValueMapWriter<'a, E>;
+    type StructWriter = Unsupported<(), E>;
+
+    fn write_struct(self, _: &Schema, _: usize) -> Result<Self::StructWriter, Self::Error> {
+        Err(Error::custom(
+            "Structures cannot be bound to an HTTP message outside the body",
+        ))
+    }
+
+    fn write_map(self, _: &Schema, _: usize) -> Result<Self::MapWriter, Self::Error> {
+        if !matches!(
+            self.binding,
+            HttpBinding::PrefixHeaders | HttpBinding::QueryParams
+        ) || self.in_list
+        {
+            return Err(Error::custom(
+                "Maps can only be bound using `@httpPrefixHeaders` or `@httpQueryParams`",
+            ));
+        }
+        Ok(ValueMapWriter {
+            binding: self.binding,
+            out: self.out,
+            _error: PhantomData,
+        })
+    }
+
+    fn write_list(self, _: &Schema, _: usize) -> Result<Self::ListWriter, Self::Error> {
+        if self.in_list {
+            return Err(Error::custom("Nested lists cannot be bound"));
+        }
+        Ok(ValueListWriter {
+            binding: self.binding,
+            key: self.key,
+            out: self.out,
+            _error: PhantomData,
+        })
+    }
+
+    fn write_boolean(self, _: &Schema, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.push(value.to_string());
+        Ok(())
+    }
+
+    fn write_byte(self, _: &Schema, value: i8) -> Result<Self::Ok, Self::Error> {
+        self.push(value.to_string());
+        Ok(())
+    }
+
+    fn write_short(self, _: &Schema, value: i16) -> Result<Self::Ok, Self::Error> {
+        self.push(value.to_string());
+        Ok(())
+    }
+
+    fn write_integer(self, _: &Schema, value: i32) -> Result<Self::Ok, Self::Error> {
+        self.push(value.to_string());
+        Ok(())
+    }
+
+    fn write_long(self, _: &Schema, value: i64) -> Result<Self::Ok, Self::Error> {
+        self.push(value.to_string());
+        Ok(())
+    }
+
+    fn write_float(self, _: &Schema, value: f32) -> Result<Self::Ok, Self::Error> {
+        self.push(format_float(f64::from(value)));
+        Ok(())
+    }
+
+    fn write_double(self, _: &Schema, value: f64) -> Result<Self::Ok, Self::Error> {
+        self.push(format_float(value));
+        Ok(())
+    }
+
+    fn write_big_integer(self, _: &Schema, value: &BigInt) -> Result<Self::Ok, Self::Error> {
+        self.push(value.to_string());
+        Ok(())
+    }
+
+    fn write_big_decimal(self, _: &Schema, value: &BigDecimal) -> Result<Self::Ok, Self::Error> {
+        self.push(value.to_string());
+        Ok(())
+    }
+
+    fn write_string(self, _: &Schema, value: &str) -> Result<Self::Ok, Self::Error> {
+        // List values in headers are comma-separated, so elements containing
+        // commas or quotes must be quoted.
+        if self.in_list && self.binding == HttpBinding::Header && value.contains([',', '"']) {
+            let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+            self.push(format!("\"{escaped}\""));
+            return Ok(());
+        }
+        self.push(value.to_string());
+        Ok(())
+    }
+
+    fn write_blob(self, _: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
+        self.push(STANDARD.encode(value.as_bytes()));
+        Ok(())
+    }
+
+    fn write_timestamp(self, schema: &Schema, value: &Instant) -> Result<Self::Ok, Self::Error> {
+        let format = self
+            .binding
+            .timestamp_format(schema, &TimestampFormatTrait::EpochSeconds);
+        let value = Timestamp::from(*value)
+            .format(&format)
+            .map_err(Error::custom)?;
+        self.push(value);
+        Ok(())
+    }
+
+    fn write_null(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    fn skip(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+fn format_float(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value.is_sign_positive() {
+            "Infinity".to_string()
+        } else {
+            "-Infinity".to_string()
+        }
+    } else {
+        value.to_string()
+    }
+}
+
+/// [`ListWriter`] for bound list values.
+struct ValueListWriter<'a, E> {
+    binding: HttpBinding,
+    key: &'a str,
+    out: &'a mut Vec<(String, String)>,
+    _error: PhantomData<E>,
+}
+
+impl<E: Error> ListWriter for ValueListWriter<'_, E> {
+    type Error = E;
+    type Ok = ();
+
+    fn write_element<T>(&mut self, element_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        let mut serializer = ValueSerializer::new(self.binding, self.key, self.out);
+        serializer.in_list = true;
+        value.serialize_with_schema(element_schema, serializer)
+    }
+
+    fn end(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+/// [`MapWriter`] for maps bound using `@httpPrefixHeaders` or `@httpQueryParams`.
+struct ValueMapWriter<'a, E> {
+    binding: HttpBinding,
+    out: &'a mut Vec<(String, String)>,
+    _error: PhantomData<E>,
+}
+
+impl<E: Error> MapWriter for ValueMapWriter<'_, E> {
+    type Error = E;
+    type Ok = ();
+
+    fn write_entry<K, V>(
+        &mut self,
+        key_schema: &Schema,
+        value_schema: &Schema,
+        key: &K,
+        value: &V,
+    ) -> Result<(), Self::Error>
+    where
+        K: SerializeWithSchema,
+        V: SerializeWithSchema,
+    {
+        let mut keys = Vec::new();
+        key.serialize_with_schema(
+            key_schema,
+            ValueSerializer::new(self.binding, "", &mut keys),
+        )?;
+        let Some((_, key)) = keys.pop() else {
+            return Err(Error::custom("Map keys must be strings"));
+        };
+        value.serialize_with_schema(
+            value_schema,
+            ValueSerializer::new(self.binding, &key, self.out),
+        )
+    }
+
+    fn end(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Payload Serializer
+// ============================================================================
+
+/// Serializes a `blob`, `string`, or `enum` bound with `@httpPayload` as raw bytes.
+struct PayloadSerializer<'a, E> {
+    out: &'a mut Vec<u8>,
+    _error: PhantomData<E>,
+}
+
+impl<'a, E> PayloadSerializer<'a, E> {
+    const fn new(out: &'a mut Vec<u8>) -> Self {
+        PayloadSerializer {
+            out,
+            _error: PhantomData,
+        }
+    }
+}
+
+impl<E: Error> Serializer for PayloadSerializer<'_, E> {
+    type Error = E;
+    type Ok = ();
+    type ListWriter = Unsupported<(), E>;
+    type MapWriter = Unsupported<(), E>;
+    type StructWriter = Unsupported<(), E>;
+
+    fn write_struct(self, _: &Schema, _: usize) -> Result<Self::StructWriter, Self::Error> {
+        Err(Error::custom(NOT_A_RAW_PAYLOAD))
+    }
+
+    fn write_map(self, _: &Schema, _: usize) -> Result<Self::MapWriter, Self::Error> {
+        Err(Error::custom(NOT_A_RAW_PAYLOAD))
+    }
+
+    fn write_list(self, _: &Schema, _: usize) -> Result<Self::ListWriter, Self::Error> {
+        Err(Error::custom(NOT_A_RAW_PAYLOAD))
+    }
+
+    unsupported!(
+        NOT_A_RAW_PAYLOAD;
+        write_boolean(bool),
+        write_byte(i8),
+        write_short(i16),
+        write_integer(i32),
+        write_long(i64),
+        write_float(f32),
+        write_double(f64),
+        write_big_integer(&BigInt),
+        write_big_decimal(&BigDecimal),
+        write_timestamp(&Instant),
+    );
+
+    fn write_string(self, _: &Schema, value: &str) -> Result<Self::Ok, Self::Error> {
+        self.out.extend_from_slice(value.as_bytes());
+        Ok(())
+    }
+
+    fn write_blob(self, _: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
+        self.out.extend_from_slice(value.as_bytes());
+        Ok(())
+    }
+
+    fn write_streaming_blob(
+        self,
+        _: &Schema,
+        value: &StreamingBlob,
+    ) -> Result<Self::Ok, Self::Error> {
+        let bytes = value.as_bytes().ok_or_else(|| {
+            Error::custom("Streaming payloads must be collected before serialization")
+        })?;
+        self.out.extend_from_slice(&bytes);
+        Ok(())
+    }
+
+    fn write_null(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    fn skip(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+const NOT_A_RAW_PAYLOAD: &str = "Only blobs and strings can be serialized as a raw HTTP payload";
+
+// ============================================================================
+// Unsupported Writer
+// ============================================================================
+
+/// Writer for values that cannot be bound to an HTTP message.
+///
+/// This type cannot be constructed. It is only used to satisfy the writer types
+/// of serializers that reject lists, maps, or structures.
+pub struct Unsupported<O, E> {
+    pub(crate) never: Infallible,
+    _marker: PhantomData<(O, E)>,
+}
+
+impl<O, E: Error> ListWriter for Unsupported<O, E> {
+    type Error = E;
+    type Ok = O;
+
+    fn write_element<T>(&mut self, _: &Schema, _: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        match self.never {}
+    }
+
+    fn end(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        match self.never {}
+    }
+}
+
+impl<O, E: Error> MapWriter for Unsupported<O, E> {
+    type Error = E;
+    type Ok = O;
+
+    fn write_entry<K, V>(&mut self, _: &Schema, _: &Schema, _: &K, _: &V) -> Result<(), Self::Error>
+    where
+        K: SerializeWithSchema,
+        V: SerializeWithSchema,
+    {
+        match self.never {}
+    }
+
+    fn end(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        match self.never {}
+    }
+}
+
+impl<O, E: Error> StructWriter for Unsupported<O, E> {
+    type Error = E;
+    type Ok = O;
+
+    fn write_member<T>(&mut self, _: &Schema, _: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        match self.never {}
+    }
+
+    fn end(self, _: &Schema) -> Result<Self::Ok, Self::Error> {
+        match self.never {}
+    }
+}
@@ -0,0 +1,411 @@
+//! # AWS Signature Version 4
+//!
+//! [`SigV4Signer`] signs requests for services with the [`aws.auth#sigv4`](https://smithy.io/2.0/aws/aws-auth.html#aws-auth-sigv4-trait)
+//! auth trait using [Signature Version 4](https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_sigv.html).
+//! The signing name of the service is read from the `name` property of the trait, and
+//! requests are signed with [`Identity::Credentials`]:
+//!
+//! ```rust,ignore
+//! let auth = AuthSchemeRegistry::new()
+//!     .with(DefaultAuthScheme::sigv4(credentials_provider, "us-east-1"));
+//! ```
+//!
+//! Requests are signed with an `Authorization` header. The host is read from the
+//! `Host` header of the request if present, and otherwise from the authority of its
+//! URI, so requests must be sent to their endpoint before they are signed.
+use std::fmt::Write;
+
+use hmac::{Hmac, Mac};
+use http::{
+    HeaderName, HeaderValue, Request,
+    header::{AUTHORIZATION, HOST},
+};
+use percent_encoding::{percent_decode_str, utf8_percent_encode};
+use sha2::{Digest, Sha256};
+use smithy4rs_core::{
+    derive::{SmithyShape, SmithyTraitImpl},
+    prelude::{RequiredTrait, STRING, TimestampFormatTrait},
+    schema::{Schema, StaticTraitId, Timestamp},
+    smithy,
+};
+
+use crate::{
+    auth::{AuthError, DefaultAuthScheme, Identity, IdentityResolver, SIGV4, Signer},
+    message::ENCODE_SET,
+};
+
+/// Name of the signing algorithm written to the `Authorization` header.
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+/// Header containing the time a request was signed.
+const X_AMZ_DATE: &str = "x-amz-date";
+/// Header containing the session token of temporary credentials.
+const X_AMZ_SECURITY_TOKEN: &str = "x-amz-security-token";
+
+/// Headers that may be changed after a request is signed, and so are never signed.
+const UNSIGNED_HEADERS: [&str; 6] = [
+    "authorization",
+    "connection",
+    "expect",
+    "transfer-encoding",
+    "user-agent",
+    "x-amzn-trace-id",
+];
+
+// ============================================================================
+// SigV4 Trait
+// ============================================================================
+
+smithy!("aws.auth#sigv4": {
+    /// Schema for [`SigV4Trait`]
+    structure SIGV4_SCHEMA {
+        @RequiredTrait::builder().build();
+        NAME: STRING = "name"
+    }
+});
+
+/// Configures [AWS Signature Version 4](https://smithy.io/2.0/aws/aws-auth.html#aws-auth-sigv4-trait)
+/// authentication for a service.
+#[derive(SmithyShape, SmithyTraitImpl, PartialEq, Clone)]
+#[smithy_schema(SIGV4_SCHEMA)]
+pub struct SigV4Trait {
+    /// Signing name of the service
+    #[smithy_schema(NAME)]
+    pub name: String,
+}
+
+// ============================================================================
+// Signer
+// ============================================================================
+
+/// [`Signer`] for `aws.auth#sigv4` that signs requests for a single region.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigV4Signer {
+    region: String,
+}
+
+impl SigV4Signer {
+    /// Create a signer for requests sent to `region`.
+    pub fn new(region: impl Into<String>) -> Self {
+        SigV4Signer {
+            region: region.into(),
+        }
+    }
+
+    /// Get the region requests are signed for.
+    #[must_use]
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// Sign `request` as if it were sent at `time`.
+    ///
+    /// # Errors
+    /// Returns an [`AuthError`] if the identity is not [`Identity::Credentials`], the
+    /// service has no `aws.auth#sigv4` trait, or the request has no host.
+    pub fn sign_at(
+        &self,
+        request: &mut Request<Vec<u8>>,
+        identity: &Identity,
+        service: &Schema,
+        time: Timestamp,
+    ) -> Result<(), AuthError> {
+        let Identity::Credentials {
+            access_key_id,
+            secret_access_key,
+            session_token,
+        } = identity
+        else {
+            return Err(AuthError::UnsupportedIdentity(SIGV4));
+        };
+        let signing_name = &service
+            .get_trait_as::<SigV4Trait>()
+            .ok_or(AuthError::MissingTrait(SIGV4))?
+            .name;
+
+        // Signatures have a precision of seconds
+        let time = Timestamp::from_epoch_millis(time.epoch_millis().div_euclid(1000) * 1000)
+            .map_err(|e| AuthError::Signing(e.to_string()))?;
+        let date_time: String = time
+            .format(&TimestampFormatTrait::DateTime)
+            .map_err(|e| AuthError::Signing(e.to_string()))?
+            .chars()
+            .filter(|c| *c != '-' && *c != ':')
+            .collect();
+        let date = &date_time[..8];
+
+        let headers = request.headers_mut();
+        headers.insert(X_AMZ_DATE, HeaderValue::try_from(date_time.as_str())?);
+        if let Some(token) = session_token {
+            let mut value = HeaderValue::try_from(token.as_str())?;
+            value.set_sensitive(true);
+            headers.insert(X_AMZ_SECURITY_TOKEN, value);
+        }
+
+        let (canonical_request, signed_headers) = canonical_request(request)?;
+        let scope = format!("{date}/{}/{signing_name}/aws4_request", self.region);
+        let string_to_sign = format!(
+            "{ALGORITHM}\n{date_time}\n{scope}\n{}",
+            hex(&Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let key = [date, &self.region, signing_name, "aws4_request"]
+            .into_iter()
+            .fold(
+                format!("AWS4{secret_access_key}").into_bytes(),
+                |key, part| hmac(&key, part.as_bytes()),
+            );
+        let signature = hex(&hmac(&key, string_to_sign.as_bytes()));
+
+        let mut authorization = HeaderValue::try_from(format!(
+            "{ALGORITHM} Credential={access_key_id}/{scope}, SignedHeaders={signed_headers}, Signature={signature}"
+        ))?;
+        authorization.set_sensitive(true);
+        request.headers_mut().insert(AUTHORIZATION, authorization);
+        Ok(())
+    }
+}
+
+impl Signer for SigV4Signer {
+    fn sign(
+        &self,
+        request: &mut Request<Vec<u8>>,
+        identity: &Identity,
+        service: &Schema,
+    ) -> Result<(), AuthError> {
+        self.sign_at(request, identity, service, Timestamp::now())
+    }
+}
+
+impl DefaultAuthScheme {
+    /// Create an `aws.auth#sigv4` scheme that signs requests for `region`.
+    pub fn sigv4(resolver: impl IdentityResolver + 'static, region: impl Into<String>) -> Self {
+        Self::new(
+            SigV4Trait::trait_id().clone(),
+            resolver,
+            SigV4Signer::new(region),
+        )
+    }
+}
+
+// ============================================================================
+// Canonical Request
+// ============================================================================
+
+/// Build the canonical form of a request, returning it along with the list of signed headers.
+fn canonical_request(request: &Request<Vec<u8>>) -> Result<(String, String), AuthError> {
+    let uri = request.uri();
+
+    // Each path segment is encoded again, so existing escapes are double-encoded
+    let path = uri
+        .path()
+        .split('/')
+        .map(|segment| utf8_percent_encode(segment, ENCODE_SET).to_string())
+        .collect::<Vec<_>>()
+        .join("/");
+    let path = if path.is_empty() { "/" } else { &path };
+
+    let mut query: Vec<(String, String)> = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (encode_query(name), encode_query(value))
+        })
+        .collect();
+    query.sort();
+    let query = query
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let mut headers: Vec<(&str, String)> = Vec::new();
+    if !request.headers().contains_key(HOST) {
+        let host = uri
+            .authority()
+            .ok_or_else(|| AuthError::Signing("Request has no host".to_string()))?;
+        headers.push((HOST.as_str(), host.to_string()));
+    }
+    for name in request.headers().keys() {
+        if UNSIGNED_HEADERS.contains(&name.as_str()) {
+            continue;
+        }
+        headers.push((name.as_str(), header_value(request, name)?));
+    }
+    headers.sort();
+
+    let mut canonical_headers = String::new();
+    for (name, value) in &headers {
+        let _ = writeln!(canonical_headers, "{name}:{value}");
+    }
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";");
+    let payload = hex(&Sha256::digest(request.body()));
+
+    Ok((
+        format!(
+            "{}\n{path}\n{query}\n{canonical_headers}\n{signed_headers}\n{payload}",
+            request.method()
+        ),
+        signed_headers,
+    ))
+}
+
+/// Join all values of a header with commas, collapsing runs of whitespace.
+fn header_value(request: &Request<Vec<u8>>, name: &HeaderName) -> Result<String, AuthError> {
+    let values = request
+        .headers()
+        .get_all(name)
+        .iter()
+        .map(|value| {
+            value
+                .to_str()
+                .map(|value| value.split_whitespace().collect::<Vec<_>>().join(" "))
+                .map_err(|_| AuthError::Signing(format!("Header `{name}` is not valid ASCII")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(values.join(","))
+}
+
+fn encode_query(value: &str) -> String {
+    let decoded = percent_decode_str(value).decode_utf8_lossy();
+    utf8_percent_encode(&decoded, ENCODE_SET).to_string()
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut out, byte| {
+        let _ = write!(out, "{byte:02x}");
+        out
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use smithy4rs_core::{doc_map, schema::DynamicTrait};
+
+    use super::*;
+    use crate::auth::AuthSchemeRegistry;
+
+    smithy!("com.example#Service": {
+        @DynamicTrait::from(SIGV4, doc_map!["name" => "service"]);
+        service SERVICE
+    });
+
+    smithy!("com.example#GetCity": {
+        operation GET_CITY
+    });
+
+    /// Credentials used by the AWS Signature Version 4 test suite
+    fn credentials(session_token: Option<&str>) -> Identity {
+        Identity::Credentials {
+            access_key_id: "AKIDEXAMPLE".into(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".into(),
+            session_token: session_token.map(str::to_string),
+        }
+    }
+
+    /// `2015-08-30T12:36:00Z`
+    fn time() -> Timestamp {
+        Timestamp::from_epoch_millis(1_440_938_160_000).unwrap()
+    }
+
+    fn sign(uri: &str, identity: &Identity) -> Request<Vec<u8>> {
+        let mut request = Request::builder().uri(uri).body(Vec::new()).unwrap();
+        SigV4Signer::new("us-east-1")
+            .sign_at(&mut request, identity, &SERVICE, time())
+            .unwrap();
+        request
+    }
+
+    #[test]
+    fn signs_vanilla_get() {
+        let request = sign("https://example.amazonaws.com/", &credentials(None));
+        assert_eq!(request.headers()[X_AMZ_DATE], "20150830T123600Z");
+        assert_eq!(
+            request.headers()[AUTHORIZATION],
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+        assert!(request.headers()[AUTHORIZATION].is_sensitive());
+    }
+
+    #[test]
+    fn canonicalizes_query_and_path() {
+        let mut request = Request::builder()
+            .uri("https://example.amazonaws.com/a%20b/c?b=2&a=2&a=1&flag")
+            .header("X-Custom", "  spaced   out  ")
+            .header("User-Agent", "ignored")
+            .body(b"body".to_vec())
+            .unwrap();
+        request
+            .headers_mut()
+            .insert(X_AMZ_DATE, HeaderValue::from_static("20150830T123600Z"));
+        let (canonical, signed) = canonical_request(&request).unwrap();
+        assert_eq!(signed, "host;x-amz-date;x-custom");
+        assert_eq!(
+            canonical,
+            "GET\n/a%2520b/c\na=1&a=2&b=2&flag=\n\
+             host:example.amazonaws.com\nx-amz-date:20150830T123600Z\nx-custom:spaced out\n\n\
+             host;x-amz-date;x-custom\n\
+             230d8358dc8e8890b4c58deeb62912ee2f20357ae92a5cc861b98e68fe31acb5"
+        );
+    }
+
+    #[test]
+    fn signs_session_token() {
+        let request = sign(
+            "https://example.amazonaws.com/",
+            &credentials(Some("token")),
+        );
+        assert_eq!(request.headers()[X_AMZ_SECURITY_TOKEN], "token");
+        assert!(
+            request.headers()[AUTHORIZATION]
+                .to_str()
+                .unwrap()
+                .contains("SignedHeaders=host;x-amz-date;x-amz-security-token,")
+        );
+    }
+
+    #[test]
+    fn requires_host_and_credentials() {
+        let signer = SigV4Signer::new("us-east-1");
+        let mut request = Request::builder().uri("/").body(Vec::new()).unwrap();
+        assert!(matches!(
+            signer.sign_at(&mut request, &credentials(None), &SERVICE, time()),
+            Err(AuthError::Signing(_))
+        ));
+        assert!(matches!(
+            signer.sign_at(&mut request, &Identity::Token("t".into()), &SERVICE, time()),
+            Err(AuthError::UnsupportedIdentity(SIGV4))
+        ));
+    }
+
+    #[test]
+    fn registers_as_auth_scheme() {
+        let registry = AuthSchemeRegistry::new()
+            .with(DefaultAuthScheme::sigv4(credentials(None), "us-west-2"));
+        let mut request = Request::builder()
+            .uri("https://example.amazonaws.com/")
+            .body(Vec::new())
+            .unwrap();
+        registry.sign(&SERVICE, &GET_CITY, &mut request).unwrap();
+        assert!(
+            request.headers()[AUTHORIZATION]
+                .to_str()
+                .unwrap()
+                .contains("/us-west-2/service/aws4_request")
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use smithy4rs_core::{
+    IndexMap, Instant,
+    prelude::HttpTrait,
+    schema::{Blob, Timestamp},
+    serde::{de::DeserializeWithSchema, se::SerializeWithSchema},
+};
+use smithy4rs_http_binding::{
+    HttpBindingDeserializer, HttpBindingError, HttpBindingSerializer, HttpMessage,
+};
+use smithy4rs_json_codec::{JsonDeserializer, JsonSerializer};
+use smithy4rs_test_utils::*;
+
+fn http_trait(method: &str, uri: &str) -> HttpTrait {
+    HttpTrait::builder()
+        .method(method.to_string())
+        .uri(uri.to_string())
+        .build()
+        .unwrap()
+}
+
+fn input() -> HttpBindingsInput {
+    let mut params = IndexMap::new();
+    params.insert("extra".to_string(), "1".to_string());
+    params.insert("pageSize".to_string(), "99".to_string());
+    let mut meta = IndexMap::new();
+    meta.insert("color".to_string(), "red".to_string());
+    HttpBindingsInput {
+        city: "New York".to_string(),
+        path: "a b/c.txt".to_string(),
+        page_size: Some(10),
+        tags: Some(vec!["a".to_string(), "b".to_string()]),
+        params: Some(params),
+        since: Some(Instant::from(
+            Timestamp::from_epoch_seconds(1_576_540_098.0).unwrap(),
+        )),
+        aliases: Some(vec!["NYC".to_string(), "Big Apple, The".to_string()]),
+        meta: Some(meta),
+        name: "New York".to_string(),
+        population: Some(8_000_000),
+    }
+}
+
+fn serialize<T: SerializeWithSchema>(
+    schema: &smithy4rs_core::schema::Schema,
+    value: &T,
+) -> (HttpMessage, Vec<u8>) {
+    let mut body = Vec::new();
+    let message = value
+        .serialize_with_schema(
+            schema,
+            HttpBindingSerializer::new(JsonSerializer::new(&mut body)),
+        )
+        .unwrap();
+    (message, body)
+}
+
+#[test]
+fn test_serializes_request() {
+    let http = http_trait("PUT", "/cities/{city}/files/{path+}");
+    let (message, body) = serialize(&HTTP_BINDINGS_INPUT_SCHEMA, &input());
+    let request = message.into_request(&http, body).unwrap();
+
+    assert_eq!(request.method(), "PUT");
+    assert_eq!(
+        request.uri(),
+        "/cities/New%20York/files/a%20b/c.txt?pageSize=10&tag=a&tag=b&extra=1"
+    );
+    assert_eq!(
+        request.headers()["x-since"],
+        "Mon, 16 Dec 2019 23:48:18 GMT"
+    );
+    assert_eq!(request.headers()["x-aliases"], r#"NYC, "Big Apple, The""#);
+    assert_eq!(request.headers()["x-meta-color"], "red");
+    assert_eq!(
+        std::str::from_utf8(request.body()).unwrap(),
+        r#"{"name":"New York","population":8000000}"#
+    );
+}
+
+#[test]
+fn test_request_round_trip() {
+    let http = http_trait("PUT", "/cities/{city}/files/{path+}");
+    let original = input();
+    let (message, body) = serialize(&HTTP_BINDINGS_INPUT_SCHEMA, &original);
+    let request = message.into_request(&http, body).unwrap();
+
+    let message = HttpMessage::from_request(&request, &http).unwrap();
+    let mut de = JsonDeserializer::new(request.body());
+    let result = HttpBindingsInputBuilder::deserialize_with_schema(
+        &HTTP_BINDINGS_INPUT_SCHEMA,
+        HttpBindingDeserializer::new(&message, request.body(), &mut de),
+    )
+    .unwrap()
+    .build()
+    .unwrap();
+
+    assert_eq!(result.city, original.city);
+    assert_eq!(result.path, original.path);
+    assert_eq!(result.page_size, original.page_size);
+    assert_eq!(result.tags, original.tags);
+    assert_eq!(result.since, original.since);
+    assert_eq!(result.aliases, original.aliases);
+    assert_eq!(result.meta, original.meta);
+    assert_eq!(result.name, original.name);
+    assert_eq!(result.population, original.population);
+    // Query params include all query string parameters
+    let params = result.params.unwrap();
+    assert_eq!(params["extra"], "1");
+    assert_eq!(params["pageSize"], "10");
+}
+
+#[test]
+fn test_missing_label() {
+    let http = http_trait("GET", "/cities/{city}/files/{path+}");
+    let mut value = input();
+    value.path = String::new();
+    let (message, body) = serialize(&HTTP_BINDINGS_INPUT_SCHEMA, &value);
+    assert!(matches!(
+        message.into_request(&http, body),
+        Err(HttpBindingError::MissingLabel(name)) if name == "path"
+    ));
+}
+
+#[test]
+fn test_path_mismatch() {
+    let http = http_trait("GET", "/cities/{city}/files/{path+}");
+    let request = http::Request::get("/towns/Seattle")
+        .body(Vec::<u8>::new())
+        .unwrap();
+    assert!(matches!(
+        HttpMessage::from_request(&request, &http),
+        Err(HttpBindingError::PathMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_blob_payload_response_round_trip() {
+    let output = HttpBlobPayloadOutput {
+        status: Some(201),
+        request_id: Some("abc-123".to_string()),
+        data: Some(Blob::from_static(b"raw \x00 bytes")),
+    };
+    let (message, body) = serialize(&HTTP_BLOB_PAYLOAD_OUTPUT_SCHEMA, &output);
+    let response = message.into_response(200, body).unwrap();
+    assert_eq!(response.status(), 201);
+    assert_eq!(response.headers()["x-request-id"], "abc-123");
+    assert_eq!(response.body(), b"raw \x00 bytes");
+
+    let message = HttpMessage::from_response(&response);
+    let mut de = JsonDeserializer::new(response.body());
+    let result = HttpBlobPayloadOutputBuilder::deserialize_with_schema(
+        &HTTP_BLOB_PAYLOAD_OUTPUT_SCHEMA,
+        HttpBindingDeserializer::new(&message, response.body(), &mut de),
+    )
+    .unwrap()
+    .build()
+    .unwrap();
+    assert!(result == output);
+}
+
+#[test]
+fn test_default_response_code() {
+    let output = HttpBlobPayloadOutput {
+        status: None,
+        request_id: None,
+        data: None,
+    };
+    let (message, body) = serialize(&HTTP_BLOB_PAYLOAD_OUTPUT_SCHEMA, &output);
+    let response = message.into_response(204, body).unwrap();
+    assert_eq!(response.status(), 204);
+    assert!(response.body().is_empty());
+}
+
+#[test]
+fn test_struct_payload_round_trip() {
+    let output = HttpStructPayloadOutput {
+        request_id: Some("abc-123".to_string()),
+        details: Some(SimpleStruct {
+            field_a: "a".to_string(),
+            field_b: 2,
+        }),
+    };
+    let (message, body) = serialize(&HTTP_STRUCT_PAYLOAD_OUTPUT_SCHEMA, &output);
+    let response = message.into_response(200, body).unwrap();
+    assert_eq!(
+        std::str::from_utf8(response.body()).unwrap(),
+        r#"{"field_a":"a","field_b":2}"#
+    );
+
+    let message = HttpMessage::from_response(&response);
+    let mut de = JsonDeserializer::new(response.body());
+    let result = HttpStructPayloadOutputBuilder::deserialize_with_schema(
+        &HTTP_STRUCT_PAYLOAD_OUTPUT_SCHEMA,
+        HttpBindingDeserializer::new(&message, response.body(), &mut de),
+    )
+    .unwrap()
+    .build()
+    .unwrap();
+    assert!(result == output);
+}
@@ -0,0 +1,360 @@
+use smithy4rs_core::{
+    derive::{SchemaShape, SmithyShape},
+    prelude::{
+        DocumentationTrait, ErrorTrait, HttpErrorTrait, HttpHeaderTrait, HttpLabelTrait,
+        HttpQueryTrait, HttpTrait, INTEGER, LengthTrait, RequiredTrait, STRING, TIMESTAMP,
+    },
+    schema::{Document, Operation, Unit},
+    serde::Codec,
+    smithy,
+};
+use smithy4rs_http_binding::{HttpBindingError, OpenApiBuilder};
+use smithy4rs_json_codec::JsonCodec;
+use smithy4rs_test_utils::*;
+
+smithy!("com.example#GetCityInput": {
+    structure GET_CITY_INPUT_SCHEMA {
+        @HttpLabelTrait::builder().build();
+        @RequiredTrait::builder().build();
+        CITY: STRING = "city"
+        @HttpQueryTrait::new("since");
+        SINCE: TIMESTAMP = "since"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_CITY_INPUT_SCHEMA)]
+pub struct GetCityInput {
+    #[smithy_schema(CITY)]
+    pub city: String,
+    #[smithy_schema(SINCE)]
+    pub since: Option<smithy4rs_core::Instant>,
+}
+
+smithy!("com.example#GetCityOutput": {
+    structure GET_CITY_OUTPUT_SCHEMA {
+        @HttpHeaderTrait::new("X-Request-Id");
+        REQUEST_ID: STRING = "request_id"
+        @RequiredTrait::builder().build();
+        @LengthTrait::builder().min(1i64).build();
+        NAME: STRING = "name"
+        POPULATION: INTEGER = "population"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_CITY_OUTPUT_SCHEMA)]
+pub struct GetCityOutput {
+    #[smithy_schema(REQUEST_ID)]
+    pub request_id: Option<String>,
+    #[smithy_schema(NAME)]
+    pub name: String,
+    #[smithy_schema(POPULATION)]
+    pub population: Option<i32>,
+}
+
+smithy!("com.example#NoSuchCity": {
+    @ErrorTrait::Client;
+    @HttpErrorTrait::new(404);
+    structure NO_SUCH_CITY_SCHEMA {
+        MESSAGE: STRING = "message"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(NO_SUCH_CITY_SCHEMA)]
+pub struct NoSuchCity {
+    #[smithy_schema(MESSAGE)]
+    pub message: Option<String>,
+}
+
+smithy!("com.example#ServiceFault": {
+    @ErrorTrait::Server;
+    structure SERVICE_FAULT_SCHEMA {
+        MESSAGE: STRING = "message"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(SERVICE_FAULT_SCHEMA)]
+pub struct ServiceFault {
+    #[smithy_schema(MESSAGE)]
+    pub message: Option<String>,
+}
+
+smithy!("smithy.synthetic#GetCityError": {
+    union GET_CITY_ERROR_SCHEMA {
+        NO_SUCH_CITY: NO_SUCH_CITY_SCHEMA = "NoSuchCity"
+        SERVICE_FAULT: SERVICE_FAULT_SCHEMA = "ServiceFault"
+    }
+});
+
+#[allow(dead_code)]
+#[derive(SchemaShape)]
+#[smithy_schema(GET_CITY_ERROR_SCHEMA)]
+pub enum GetCityError {
+    #[smithy_schema(NO_SUCH_CITY)]
+    NoSuchCity(NoSuchCity),
+    #[smithy_schema(SERVICE_FAULT)]
+    ServiceFault(ServiceFault),
+}
+
+smithy!("com.example#GetCity": {
+    @DocumentationTrait::new("Get a city by name.");
+    @HttpTrait::builder().method("GET".to_string()).uri("/cities/{city}".to_string()).build().unwrap();
+    operation GET_CITY_SCHEMA
+});
+
+smithy!("com.example#PutFile": {
+    @HttpTrait::builder().method("PUT".to_string()).uri("/cities/{city}/files/{path+}".to_string()).code(201).build().unwrap();
+    operation PUT_FILE_SCHEMA
+});
+
+smithy!("com.example#GetLog": {
+    @HttpTrait::builder().method("GET".to_string()).uri("/log?format=text".to_string()).build().unwrap();
+    operation GET_LOG_SCHEMA
+});
+
+smithy!("com.example#Ping": {
+    operation PING_SCHEMA
+});
+
+static GET_CITY: Operation<GetCityInput, GetCityOutput, GetCityError> =
+    Operation::new(&GET_CITY_SCHEMA);
+static PUT_FILE: Operation<HttpBindingsInput, Unit, Unit> = Operation::new(&PUT_FILE_SCHEMA);
+static GET_LOG: Operation<Unit, HttpStructPayloadOutput, Unit> = Operation::new(&GET_LOG_SCHEMA);
+static PING: Operation<Unit, Unit, Unit> = Operation::new(&PING_SCHEMA);
+
+fn openapi() -> Box<dyn Document> {
+    let mut builder = OpenApiBuilder::new("Cities", "2024-01-01");
+    builder
+        .operation(&GET_CITY)
+        .unwrap()
+        .operation(&PUT_FILE)
+        .unwrap()
+        .operation(&GET_LOG)
+        .unwrap();
+    builder.build()
+}
+
+#[allow(clippy::borrowed_box)]
+fn get<'d>(document: &'d Box<dyn Document>, path: &[&str]) -> &'d Box<dyn Document> {
+    path.iter().fold(document, |document, key| {
+        document
+            .as_map()
+            .and_then(|map| map.get(*key))
+            .unwrap_or_else(|| panic!("Missing `{key}` in {path:?}"))
+    })
+}
+
+#[allow(clippy::borrowed_box)]
+fn string<'d>(document: &'d Box<dyn Document>, path: &[&str]) -> &'d str {
+    get(document, path).as_string().unwrap()
+}
+
+#[test]
+fn test_writes_info_and_paths() {
+    let document = openapi();
+    assert_eq!(string(&document, &["openapi"]), "3.1.0");
+    assert_eq!(string(&document, &["info", "title"]), "Cities");
+    assert_eq!(string(&document, &["info", "version"]), "2024-01-01");
+
+    let paths = get(&document, &["paths"]).as_map().unwrap();
+    assert_eq!(
+        paths.keys().collect::<Vec<_>>(),
+        vec!["/cities/{city}", "/cities/{city}/files/{path}", "/log"]
+    );
+    let operation = get(&document, &["paths", "/cities/{city}", "get"]);
+    assert_eq!(string(operation, &["operationId"]), "GetCity");
+    assert_eq!(string(operation, &["description"]), "Get a city by name.");
+    assert!(
+        get(
+            &document,
+            &["paths", "/cities/{city}/files/{path}", "put", "responses"]
+        )
+        .as_map()
+        .unwrap()
+        .contains_key("201")
+    );
+}
+
+#[test]
+fn test_writes_parameters() {
+    let document = openapi();
+    let parameters = get(&document, &["paths", "/cities/{city}", "get", "parameters"])
+        .as_list()
+        .unwrap();
+    assert_eq!(parameters.len(), 2);
+    assert_eq!(string(&parameters[0], &["name"]), "city");
+    assert_eq!(string(&parameters[0], &["in"]), "path");
+    assert_eq!(get(&parameters[0], &["required"]).as_bool(), Some(true));
+    assert_eq!(string(&parameters[1], &["name"]), "since");
+    assert_eq!(string(&parameters[1], &["in"]), "query");
+    assert!(parameters[1].as_map().unwrap().get("required").is_none());
+    // Timestamps bound to query strings default to `date-time`
+    assert_eq!(string(&parameters[1], &["schema", "format"]), "date-time");
+
+    let parameters = get(
+        &document,
+        &["paths", "/cities/{city}/files/{path}", "put", "parameters"],
+    )
+    .as_list()
+    .unwrap();
+    let names = parameters
+        .iter()
+        .map(|parameter| {
+            (
+                string(parameter, &["name"]).to_string(),
+                string(parameter, &["in"]).to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            ("city".to_string(), "path".to_string()),
+            ("path".to_string(), "path".to_string()),
+            ("pageSize".to_string(), "query".to_string()),
+            ("tag".to_string(), "query".to_string()),
+            ("params".to_string(), "query".to_string()),
+            ("X-Since".to_string(), "header".to_string()),
+            ("X-Aliases".to_string(), "header".to_string()),
+        ]
+    );
+    assert_eq!(string(&parameters[3], &["schema", "type"]), "array");
+    assert_eq!(string(&parameters[5], &["schema", "type"]), "string");
+}
+
+#[test]
+fn test_writes_request_body_from_unbound_members() {
+    let document = openapi();
+    let schema = get(
+        &document,
+        &[
+            "paths",
+            "/cities/{city}/files/{path}",
+            "put",
+            "requestBody",
+            "content",
+            "application/json",
+            "schema",
+        ],
+    );
+    let properties = get(schema, &["properties"]).as_map().unwrap();
+    assert_eq!(
+        properties.keys().collect::<Vec<_>>(),
+        vec!["name", "population"]
+    );
+    assert_eq!(
+        string(schema, &["properties", "population", "format"]),
+        "int64"
+    );
+    assert!(
+        get(&document, &["paths", "/cities/{city}", "get"])
+            .as_map()
+            .unwrap()
+            .get("requestBody")
+            .is_none()
+    );
+}
+
+#[test]
+fn test_writes_responses() {
+    let document = openapi();
+    let responses = get(&document, &["paths", "/cities/{city}", "get", "responses"]);
+    assert_eq!(
+        responses.as_map().unwrap().keys().collect::<Vec<_>>(),
+        vec!["200", "404", "500"]
+    );
+    assert_eq!(
+        string(
+            responses,
+            &["200", "headers", "X-Request-Id", "schema", "type"]
+        ),
+        "string"
+    );
+    let output = get(
+        responses,
+        &["200", "content", "application/json", "schema", "properties"],
+    );
+    assert_eq!(
+        output.as_map().unwrap().keys().collect::<Vec<_>>(),
+        vec!["name", "population"]
+    );
+    assert_eq!(
+        string(
+            responses,
+            &["404", "content", "application/json", "schema", "$ref"]
+        ),
+        "#/components/schemas/NoSuchCity"
+    );
+    assert_eq!(string(responses, &["500", "description"]), "ServiceFault");
+
+    let payload = get(
+        &document,
+        &["paths", "/log", "get", "responses", "200", "content"],
+    );
+    assert_eq!(
+        string(payload, &["application/json", "schema", "$ref"]),
+        "#/components/schemas/SimpleStruct"
+    );
+}
+
+#[test]
+fn test_writes_component_schemas() {
+    let document = openapi();
+    let schemas = get(&document, &["components", "schemas"]);
+    let error = get(schemas, &["NoSuchCity"]);
+    assert_eq!(string(error, &["type"]), "object");
+    assert_eq!(string(error, &["properties", "message", "type"]), "string");
+    assert!(schemas.as_map().unwrap().contains_key("SimpleStruct"));
+}
+
+#[test]
+fn test_writes_constraints() {
+    let mut builder = OpenApiBuilder::new("Cities", "1");
+    builder.operation(&GET_CITY).unwrap();
+    let document = builder.build();
+    let schema = get(
+        &document,
+        &[
+            "paths",
+            "/cities/{city}",
+            "get",
+            "responses",
+            "200",
+            "content",
+            "application/json",
+            "schema",
+        ],
+    );
+    let required = get(schema, &["required"]).as_list().unwrap();
+    assert_eq!(required.len(), 1);
+    assert_eq!(required[0].as_string(), Some("name"));
+    assert_eq!(
+        get(schema, &["properties", "name", "minLength"]).as_long(),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_serializes_to_json() {
+    let mut builder = OpenApiBuilder::new("Cities", "1");
+    builder.operation(&GET_LOG).unwrap();
+    let document = builder.build();
+    let json = JsonCodec
+        .serialize_document(document.schema(), &document)
+        .unwrap();
+    let json = String::from_utf8(json).unwrap();
+    assert!(json.starts_with(r#"{"openapi":"3.1.0","info":{"title":"Cities","version":"1"}"#));
+    assert!(json.contains(r#""/log":{"get":{"operationId":"GetLog""#));
+}
+
+#[test]
+fn test_rejects_operations_without_http_trait() {
+    let mut builder = OpenApiBuilder::new("Cities", "1");
+    assert!(matches!(
+        builder.operation(&PING),
+        Err(HttpBindingError::MissingHttpTrait(id)) if id == "com.example#Ping"
+    ));
+}
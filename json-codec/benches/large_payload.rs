@@ -0,0 +1,116 @@
+//! Benchmarks of JSON deserialization for large payloads
+//!
+//! Run with `--features simd` to compare against the SIMD scanner.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use smithy4rs_core::{
+    schema::Document,
+    serde::{Codec, de::DeserializeWithSchema},
+};
+use smithy4rs_json_codec::{JsonCodec, JsonDeserializer};
+use smithy4rs_test_utils::{NESTED_COLLECTIONS_STRUCT_SCHEMA, NestedCollectionsStructBuilder};
+
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+/// Build a payload with `size` entries in each nested collection.
+fn payload(size: usize) -> Vec<u8> {
+    let inner = |i: usize| {
+        format!(
+            r#"{{"field_a": "value-{i}", "field_b": "escaped \"quote\" {i}", "field_c": "unicode é {i}"}}"#
+        )
+    };
+    let list = (0..size).map(inner).collect::<Vec<_>>().join(",");
+    let map = (0..size)
+        .map(|i| format!(r#""key-{i}": {}"#, inner(i)))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(
+        r#"{{"name": "large", "count": {size}, "single_nested": {}, "list_nested": [{list}], "map_nested": {{{map}}}}}"#,
+        inner(0)
+    )
+    .into_bytes()
+}
+
+pub fn deserialize_shape(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Deserialize large shape");
+    for size in SIZES {
+        let data = payload(size);
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &data, |b, data| {
+            b.iter(|| {
+                let mut de = JsonDeserializer::new(black_box(data));
+                let shape = NestedCollectionsStructBuilder::deserialize_with_schema(
+                    &NESTED_COLLECTIONS_STRUCT_SCHEMA,
+                    &mut de,
+                )
+                .unwrap()
+                .build()
+                .unwrap();
+                black_box(shape);
+            });
+        });
+    }
+    group.finish();
+}
+
+pub fn deserialize_document(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Deserialize large document");
+    for size in SIZES {
+        let data = payload(size);
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &data, |b, data| {
+            b.iter(|| {
+                let document: Box<dyn Document> = JsonCodec
+                    .deserialize_document(&NESTED_COLLECTIONS_STRUCT_SCHEMA, black_box(data))
+                    .unwrap();
+                black_box(document);
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Deserialize shapes with the SIMD scanner.
+///
+/// Each iteration copies the payload, as scanning overwrites its input.
+#[cfg(feature = "simd")]
+pub fn deserialize_shape_simd(c: &mut Criterion) {
+    use smithy4rs_json_codec::SimdJsonDeserializer;
+
+    let mut group = c.benchmark_group("Deserialize large shape (simd)");
+    for size in SIZES {
+        let data = payload(size);
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &data, |b, data| {
+            b.iter_batched_ref(
+                || data.clone(),
+                |data| {
+                    let mut de = SimdJsonDeserializer::new(black_box(data)).unwrap();
+                    let shape = NestedCollectionsStructBuilder::deserialize_with_schema(
+                        &NESTED_COLLECTIONS_STRUCT_SCHEMA,
+                        &mut de,
+                    )
+                    .unwrap()
+                    .build()
+                    .unwrap();
+                    black_box(shape);
+                },
+                criterion::BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+#[cfg(not(feature = "simd"))]
+criterion_group!(benches, deserialize_shape, deserialize_document);
+#[cfg(feature = "simd")]
+criterion_group!(
+    benches,
+    deserialize_shape,
+    deserialize_shape_simd,
+    deserialize_document
+);
+criterion_main!(benches);
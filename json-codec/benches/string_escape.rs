@@ -0,0 +1,42 @@
+//! Microbenchmarks of JSON string serialization
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use smithy4rs_core::{prelude::STRING, serde::se::SerializeWithSchema};
+use smithy4rs_json_codec::JsonSerializer;
+
+const SIZES: [usize; 3] = [16, 256, 4_096];
+
+/// Build a string of `size` bytes by repeating `pattern`.
+fn repeat(pattern: &str, size: usize) -> String {
+    pattern.chars().cycle().take(size).collect()
+}
+
+pub fn serialize_strings(c: &mut Criterion) {
+    let inputs = [
+        ("ascii", "The quick brown fox jumps over the lazy dog. "),
+        ("escaped", "line \"one\"\n\tline \\two\\\n"),
+        ("unicode", "héllo wörld ✓ 日本語 "),
+    ];
+    for (name, pattern) in inputs {
+        let mut group = c.benchmark_group(format!("Serialize {name} string"));
+        for size in SIZES {
+            let value = repeat(pattern, size);
+            group.throughput(Throughput::Bytes(value.len() as u64));
+            group.bench_with_input(BenchmarkId::from_parameter(size), &value, |b, value| {
+                let mut buf = Vec::with_capacity(size * 2);
+                b.iter(|| {
+                    black_box(value)
+                        .serialize_with_schema(&STRING, JsonSerializer::new(&mut buf))
+                        .unwrap();
+                    black_box(&buf);
+                });
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, serialize_strings);
+criterion_main!(benches);
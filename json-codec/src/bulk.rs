@@ -0,0 +1,257 @@
+use std::fmt::Display;
+
+use rayon::prelude::*;
+use smithy4rs_core::serde::{Buildable, CodecError, ShapeBuilder};
+use thiserror::Error;
+
+use crate::JsonDeserializer;
+
+/// Deserialize a JSON array of independent records in parallel.
+///
+/// The array is first split into its elements with a lightweight scan of the
+/// raw input, and each element is then deserialized and built on the [`rayon`]
+/// thread pool. Records that fail to deserialize or validate do not stop the rest
+/// of the batch. Their errors are collected along with the index and byte offset
+/// of the failed element.
+///
+/// ```rust,ignore
+/// let batch = deserialize_bulk::<Record, RecordBuilder>(body)?;
+/// for error in batch.errors() {
+///     log::warn!("{error}");
+/// }
+/// ingest(batch.into_records());
+/// ```
+///
+/// # Errors
+/// Returns a [`CodecError::Syntax`] if the input is not a well-formed JSON array.
+/// Errors within an element are returned as a [`RecordError`] in the [`BulkRecords`].
+pub fn deserialize_bulk<S, B>(data: &[u8]) -> Result<BulkRecords<S>, CodecError>
+where
+    S: for<'de> Buildable<'de, B> + Send,
+    B: for<'de> ShapeBuilder<'de, S>,
+{
+    let elements = split_array(data)?;
+    let results: Vec<Result<S, CodecError>> = elements
+        .par_iter()
+        .map(|&(_, element)| {
+            let mut deserializer = JsonDeserializer::new(element);
+            Ok(B::deserialize_with_schema(S::schema(), &mut deserializer)?.build()?)
+        })
+        .collect();
+
+    let mut records = Vec::with_capacity(results.len());
+    let mut errors = Vec::new();
+    for (index, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(record) => records.push(record),
+            Err(error) => errors.push(RecordError {
+                index,
+                offset: elements[index].0,
+                error,
+            }),
+        }
+    }
+    Ok(BulkRecords { records, errors })
+}
+
+/// Records read by [`deserialize_bulk`].
+#[derive(Debug)]
+pub struct BulkRecords<S> {
+    records: Vec<S>,
+    errors: Vec<RecordError>,
+}
+
+impl<S> BulkRecords<S> {
+    /// Successfully deserialized records, in input order.
+    #[must_use]
+    pub fn records(&self) -> &[S] {
+        &self.records
+    }
+
+    /// Errors of records that failed to deserialize, in input order.
+    #[must_use]
+    pub fn errors(&self) -> &[RecordError] {
+        &self.errors
+    }
+
+    /// Returns `true` if every record was deserialized.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Consume the batch, discarding any errors.
+    #[must_use]
+    pub fn into_records(self) -> Vec<S> {
+        self.records
+    }
+
+    /// Consume the batch, returning the records and errors.
+    #[must_use]
+    pub fn into_parts(self) -> (Vec<S>, Vec<RecordError>) {
+        (self.records, self.errors)
+    }
+}
+
+/// Error deserializing a single record of a bulk payload.
+#[derive(Error, Debug)]
+#[error("Record {index} at offset {offset}: {error}")]
+pub struct RecordError {
+    index: usize,
+    offset: usize,
+    #[source]
+    error: CodecError,
+}
+
+impl RecordError {
+    /// Index of the failed record in the input array.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Byte offset of the failed record in the input.
+    ///
+    /// Positions reported by [`RecordError::error`] are relative to the start of the
+    /// record, so add this offset to find them in the input.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Error encountered deserializing the record.
+    #[must_use]
+    pub const fn error(&self) -> &CodecError {
+        &self.error
+    }
+}
+
+// ============================================================================
+// Element Scanning
+// ============================================================================
+
+/// Split a JSON array into the byte offset and raw bytes of each element.
+///
+/// Only strings and nesting are tracked, so malformed scalars are left for the element
+/// deserializer to report.
+fn split_array(data: &[u8]) -> Result<Vec<(usize, &[u8])>, CodecError> {
+    let mut pos = skip_whitespace(data, 0);
+    if data.get(pos) != Some(&b'[') {
+        return Err(syntax(pos, "expected `[` at start of bulk payload"));
+    }
+    pos += 1;
+
+    let mut elements = Vec::new();
+    if data.get(skip_whitespace(data, pos)) == Some(&b']') {
+        return finish(data, skip_whitespace(data, pos) + 1, elements);
+    }
+    loop {
+        let start = skip_whitespace(data, pos);
+        let end = scan_value(data, start)?;
+        if start == end {
+            return Err(syntax(start, "expected array element"));
+        }
+        elements.push((start, &data[start..end]));
+        pos = skip_whitespace(data, end);
+        match data.get(pos) {
+            Some(b',') => pos += 1,
+            Some(b']') => return finish(data, pos + 1, elements),
+            Some(_) => return Err(syntax(pos, "expected `,` or `]` after array element")),
+            None => return Err(syntax(pos, "unterminated array")),
+        }
+    }
+}
+
+/// Find the end of the value starting at `start`.
+fn scan_value(data: &[u8], start: usize) -> Result<usize, CodecError> {
+    let mut depth = 0usize;
+    let mut pos = start;
+    while let Some(&byte) = data.get(pos) {
+        match byte {
+            b'"' => pos = scan_string(data, pos)?,
+            b'[' | b'{' => depth += 1,
+            b']' | b'}' if depth == 0 => return Ok(trim_end(data, start, pos)),
+            b']' | b'}' => depth -= 1,
+            b',' if depth == 0 => return Ok(trim_end(data, start, pos)),
+            _ => {}
+        }
+        pos += 1;
+    }
+    Err(syntax(pos, "unterminated array"))
+}
+
+/// Find the closing quote of the string starting at `start`.
+fn scan_string(data: &[u8], start: usize) -> Result<usize, CodecError> {
+    let mut pos = start + 1;
+    while let Some(&byte) = data.get(pos) {
+        match byte {
+            b'\\' => pos += 2,
+            b'"' => return Ok(pos),
+            _ => pos += 1,
+        }
+    }
+    Err(syntax(start, "unterminated string"))
+}
+
+fn finish<'a>(
+    data: &[u8],
+    pos: usize,
+    elements: Vec<(usize, &'a [u8])>,
+) -> Result<Vec<(usize, &'a [u8])>, CodecError> {
+    let end = skip_whitespace(data, pos);
+    if end < data.len() {
+        return Err(syntax(end, "trailing characters after bulk payload"));
+    }
+    Ok(elements)
+}
+
+fn skip_whitespace(data: &[u8], mut pos: usize) -> usize {
+    while data.get(pos).is_some_and(u8::is_ascii_whitespace) {
+        pos += 1;
+    }
+    pos
+}
+
+fn trim_end(data: &[u8], start: usize, mut end: usize) -> usize {
+    while end > start && data[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    end
+}
+
+fn syntax(offset: usize, message: impl Display) -> CodecError {
+    CodecError::syntax(Some(offset), message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(data: &str) -> Vec<&str> {
+        split_array(data.as_bytes())
+            .unwrap()
+            .into_iter()
+            .map(|(_, element)| std::str::from_utf8(element).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn splits_elements() {
+        assert_eq!(
+            split(r#" [ {"a": [1, 2]}, "x,]" , 3,[{}] ] "#),
+            vec![r#"{"a": [1, 2]}"#, r#""x,]""#, "3", "[{}]"]
+        );
+        assert_eq!(split(r#"["\"]", "\\"]"#), vec![r#""\"]""#, r#""\\""#]);
+        assert!(split(" [ ] ").is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_arrays() {
+        for data in ["{}", "[1, 2", "[1,, 2]", "[1, 2,]", r#"["abc]"#, "[1] x"] {
+            assert!(
+                matches!(split_array(data.as_bytes()), Err(CodecError::Syntax { .. })),
+                "expected syntax error for {data}"
+            );
+        }
+    }
+}
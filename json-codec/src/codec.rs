@@ -0,0 +1,46 @@
+use smithy4rs_core::{
+    schema::{Document, Schema},
+    serde::{Codec, CodecError, de::DeserializeWithSchema, se::SerializeWithSchema},
+};
+
+use crate::{JsonDeserializer, JsonSerializer};
+
+/// [`Codec`] that reads and writes JSON documents.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonCodec;
+
+impl Codec for JsonCodec {
+    #[allow(clippy::borrowed_box)]
+    fn serialize_document(
+        &self,
+        schema: &Schema,
+        document: &Box<dyn Document>,
+    ) -> Result<Vec<u8>, CodecError> {
+        let mut buf = Vec::new();
+        self.serialize_document_into(schema, document, &mut buf)?;
+        Ok(buf)
+    }
+
+    #[allow(clippy::borrowed_box)]
+    fn serialize_document_into(
+        &self,
+        schema: &Schema,
+        document: &Box<dyn Document>,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), CodecError> {
+        document.serialize_with_schema(schema, JsonSerializer::new(buf))?;
+        Ok(())
+    }
+
+    fn deserialize_document(
+        &self,
+        schema: &Schema,
+        data: &[u8],
+    ) -> Result<Box<dyn Document>, CodecError> {
+        let mut deserializer = JsonDeserializer::new(data);
+        Ok(Box::<dyn Document>::deserialize_with_schema(
+            schema,
+            &mut deserializer,
+        )?)
+    }
+}
@@ -1,31 +1,235 @@
+use std::borrow::Cow;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
 use smithy4rs_core::{
-    BigDecimal, BigInt, ByteBuffer, Instant,
-    schema::Schema,
-    serde::deserializers::{
-        DeserializeWithSchema, Deserializer, ListReader, MapReader, StructReader,
+    BigDecimal, BigInt, IndexMap, Instant,
+    prelude::TimestampFormatTrait,
+    schema::{Blob, Document, NULL, Schema, Timestamp},
+    serde::{
+        deadline::Deadline,
+        deserializers::{
+            DeserializeWithSchema, Deserializer, ListReader, MapReader, MemberKey, StructReader,
+            UnknownEnumPolicy,
+        },
+        pool::{BufferPool, PooledBuffer},
+        strict::UnknownMembers,
+        strings::StringPolicy,
     },
 };
 
-use crate::errors::JsonSerdeError;
+use crate::{
+    documents::{JsonNumberLiteral, JsonString},
+    errors::JsonSerdeError,
+};
 
 /// A JSON deserializer that uses jiter.
 pub struct JsonDeserializer<'de> {
+    data: &'de [u8],
     parser: jiter::Jiter<'de>,
+    strings: StringPolicy,
+    preserve_number_literals: bool,
+    pool: Option<BufferPool>,
+    deadline: Deadline,
+    strict_members: bool,
+    unknown_enums: UnknownEnumPolicy,
 }
 
 impl<'de> JsonDeserializer<'de> {
     /// Create a new JSON deserializer from a byte slice.
     pub fn new(data: &'de [u8]) -> Self {
         Self {
+            data,
             parser: jiter::Jiter::new(data),
+            strings: StringPolicy::default(),
+            preserve_number_literals: false,
+            pool: None,
+            deadline: Deadline::none(),
+            strict_members: false,
+            unknown_enums: UnknownEnumPolicy::MapToUnknown,
+        }
+    }
+
+    /// Set the [`StringPolicy`] used to validate string values.
+    ///
+    /// Map keys must always be valid UTF-8, but are checked for NUL characters.
+    #[must_use]
+    pub fn with_string_policy(mut self, policy: StringPolicy) -> Self {
+        self.strings = policy;
+        self
+    }
+
+    /// Retain the original text of numbers deserialized into documents.
+    ///
+    /// When enabled, numeric document values keep the exact literal they were
+    /// read from (i.e. `-0`, `1E+2`, or `1.500`) and [`JsonSerializer`](crate::JsonSerializer)
+    /// writes that literal back out unchanged. This allows proxies to forward
+    /// document payloads without perturbing them.
+    #[must_use]
+    pub fn with_preserved_number_literals(mut self, preserve: bool) -> Self {
+        self.preserve_number_literals = preserve;
+        self
+    }
+
+    /// Draw blob buffers and string unescaping scratch space from a [`BufferPool`].
+    #[must_use]
+    pub fn with_buffer_pool(mut self, pool: BufferPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Abort deserialization once `deadline` expires.
+    ///
+    /// The deadline is checked before each struct member, list element, and map
+    /// entry (including those of documents) is read.
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Deadline) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Reject unknown members of all structures and unions.
+    ///
+    /// Deserialization fails with [`JsonSerdeError::UnknownMembers`] listing every
+    /// unknown member of a struct, instead of skipping them. Individual shapes can
+    /// also opt in with the [`StrictMembersTrait`](smithy4rs_core::serde::strict::StrictMembersTrait).
+    #[must_use]
+    pub fn with_strict_members(mut self, strict: bool) -> Self {
+        self.strict_members = strict;
+        self
+    }
+
+    /// Set the [`UnknownEnumPolicy`] for enum values that do not match a known variant.
+    ///
+    /// Unknown values are deserialized into the `Unknown` variant of an enum by
+    /// default. Use [`UnknownEnumPolicy::Error`] to reject them instead.
+    #[must_use]
+    pub fn with_unknown_enum_policy(mut self, policy: UnknownEnumPolicy) -> Self {
+        self.unknown_enums = policy;
+        self
+    }
+
+    #[inline]
+    fn check_deadline(&mut self) -> Result<(), JsonSerdeError> {
+        Ok(self.deadline.check()?)
+    }
+
+    fn take_buffer(&self, capacity: usize) -> PooledBuffer {
+        PooledBuffer::take(self.pool.as_ref(), capacity)
+    }
+
+    /// Read the next JSON value as an untyped [`Document`].
+    fn next_document(&mut self) -> Result<Box<dyn Document>, JsonSerdeError> {
+        let err =
+            |e| JsonSerdeError::DeserializationError(format!("Failed to read document: {}", e));
+        let peek = self.parser.peek().map_err(err)?;
+        match peek {
+            jiter::Peek::Null => {
+                self.parser.next_null().map_err(err)?;
+                Ok(NULL.clone())
+            }
+            jiter::Peek::True | jiter::Peek::False => {
+                Ok(self.parser.next_bool().map_err(err)?.into())
+            }
+            jiter::Peek::String => Ok(Box::new(JsonString::new(self.next_string()?.into_owned()))),
+            jiter::Peek::Array => {
+                let mut elements: Vec<Box<dyn Document>> = Vec::new();
+                let mut has_element = self.parser.next_array().map_err(err)?.is_some();
+                while has_element {
+                    self.check_deadline()?;
+                    elements.push(self.next_document()?);
+                    has_element = self.parser.array_step().map_err(err)?.is_some();
+                }
+                Ok(elements.into())
+            }
+            jiter::Peek::Object => {
+                let mut entries: IndexMap<String, Box<dyn Document>> = IndexMap::new();
+                let mut key = self.parser.next_object().map_err(err)?.map(str::to_string);
+                while let Some(k) = key {
+                    self.strings.check(&k).map_err(|e| {
+                        JsonSerdeError::DeserializationError(format!("Invalid map key: {}", e))
+                    })?;
+                    self.check_deadline()?;
+                    let value = self.next_document()?;
+                    entries.insert(k, value);
+                    key = self.parser.next_key().map_err(err)?.map(str::to_string);
+                }
+                Ok(entries.into())
+            }
+            peek if peek.is_num() => {
+                let bytes = self.parser.next_number_bytes().map_err(err)?;
+                let literal = std::str::from_utf8(bytes).map_err(|e| {
+                    JsonSerdeError::DeserializationError(format!("Invalid UTF-8 in number: {}", e))
+                })?;
+                let value = parse_number_document(literal)?;
+                if self.preserve_number_literals {
+                    Ok(Box::new(JsonNumberLiteral::new(value, literal.to_string())))
+                } else {
+                    Ok(value)
+                }
+            }
+            _ => Err(JsonSerdeError::DeserializationError(
+                "Unexpected token while reading document".to_string(),
+            )),
         }
     }
+
+    /// Read the text of the next big number value.
+    ///
+    /// Big numbers are written as JSON numbers, but are also accepted as strings
+    /// for compatibility with services that send them in string form.
+    fn next_big_number(&mut self, kind: &str) -> Result<Cow<'de, str>, JsonSerdeError> {
+        let err = |e| JsonSerdeError::DeserializationError(format!("Failed to read {kind}: {e}"));
+        if self.parser.peek().map_err(err)? == jiter::Peek::String {
+            return self.next_string();
+        }
+        let start = self.parser.current_index();
+        let len = self.parser.next_number_bytes().map_err(err)?.len();
+        let data = self.data;
+        std::str::from_utf8(&data[start..start + len])
+            .map(Cow::Borrowed)
+            .map_err(|e| {
+                JsonSerdeError::DeserializationError(format!("Invalid UTF-8 in number: {}", e))
+            })
+    }
+
+    /// Read the next string value, applying the configured [`StringPolicy`].
+    ///
+    /// Strings without escapes are borrowed directly from the input buffer.
+    fn next_string(&mut self) -> Result<Cow<'de, str>, JsonSerdeError> {
+        let err = |e| JsonSerdeError::DeserializationError(format!("Failed to read string: {}", e));
+        self.parser.peek().map_err(err)?;
+        // Skip the opening quote
+        let start = self.parser.current_index() + 1;
+        let len = self.parser.next_bytes().map_err(err)?.len();
+        let data = self.data;
+        let raw = &data[start..start + len];
+        let policy_err = |e| JsonSerdeError::DeserializationError(format!("Invalid string: {}", e));
+        if !raw.contains(&b'\\') {
+            return self.strings.decode(raw).map_err(policy_err);
+        }
+        // Escape sequences are decoded by re-parsing the (now valid UTF-8) string.
+        // Raw control characters are rejected by the parser, so a NUL can only
+        // be introduced by an escape and is checked after decoding.
+        let decoded = self.strings.decode(raw).map_err(policy_err)?;
+        let mut quoted = self.take_buffer(decoded.len() + 2);
+        quoted.push(b'"');
+        quoted.extend_from_slice(decoded.as_bytes());
+        quoted.push(b'"');
+        // The scratch buffer is returned to the pool when dropped, even on error
+        let value = jiter::Jiter::new(&quoted)
+            .next_str()
+            .map_err(err)?
+            .to_string();
+        self.strings.check(&value).map_err(policy_err)?;
+        Ok(Cow::Owned(value))
+    }
 }
 
 /// Reader for JSON struct members.
 pub struct JsonStructReader<'de, 'a> {
     de: &'a mut JsonDeserializer<'de>,
     started: bool,
+    unknown: UnknownMembers,
 }
 
 /// Reader for JSON list elements.
@@ -50,6 +254,11 @@ impl<'de, 'a> Deserializer<'de> for &'a mut JsonDeserializer<'de> {
     type ListReader = JsonListReader<'de, 'a>;
     type MapReader = JsonMapReader<'de, 'a>;
 
+    #[inline]
+    fn unknown_enum_policy(&self) -> UnknownEnumPolicy {
+        self.unknown_enums
+    }
+
     fn read_bool(self, _schema: &Schema) -> Result<bool, Self::Error> {
         self.parser.next_bool().map_err(|e| {
             JsonSerdeError::DeserializationError(format!("Failed to read bool: {}", e))
@@ -142,65 +351,75 @@ impl<'de, 'a> Deserializer<'de> for &'a mut JsonDeserializer<'de> {
     }
 
     fn read_big_integer(self, _schema: &Schema) -> Result<BigInt, Self::Error> {
-        let s = self.parser.next_str().map_err(|e| {
-            JsonSerdeError::DeserializationError(format!(
-                "Failed to read big integer string: {}",
-                e
-            ))
-        })?;
-
-        s.parse::<BigInt>().map_err(|e| {
-            JsonSerdeError::DeserializationError(format!("Failed to parse big integer: {}", e))
-        })
+        self.next_big_number("big integer")?
+            .parse::<BigInt>()
+            .map_err(|e| {
+                JsonSerdeError::DeserializationError(format!("Failed to parse big integer: {}", e))
+            })
     }
 
     fn read_big_decimal(self, _schema: &Schema) -> Result<BigDecimal, Self::Error> {
-        let s = self.parser.next_str().map_err(|e| {
-            JsonSerdeError::DeserializationError(format!(
-                "Failed to read big decimal string: {}",
-                e
-            ))
-        })?;
-
-        s.parse::<BigDecimal>().map_err(|e| {
-            JsonSerdeError::DeserializationError(format!("Failed to parse big decimal: {}", e))
-        })
+        self.next_big_number("big decimal")?
+            .parse::<BigDecimal>()
+            .map_err(|e| {
+                JsonSerdeError::DeserializationError(format!("Failed to parse big decimal: {}", e))
+            })
     }
 
     fn read_string(self, _schema: &Schema) -> Result<String, Self::Error> {
-        self.parser
-            .next_str()
-            .map_err(|e| {
-                JsonSerdeError::DeserializationError(format!("Failed to read string: {}", e))
-            })
-            .map(|s| s.to_string())
+        self.next_string().map(Cow::into_owned)
     }
 
-    fn read_blob(self, _schema: &Schema) -> Result<ByteBuffer, Self::Error> {
+    fn read_str(self, _schema: &Schema) -> Result<Cow<'de, str>, Self::Error> {
+        self.next_string()
+    }
+
+    fn read_blob(self, _schema: &Schema) -> Result<Blob, Self::Error> {
         let s = self.parser.next_str().map_err(|e| {
             JsonSerdeError::DeserializationError(format!("Failed to read blob string: {}", e))
         })?;
 
-        // TODO: Add base64 decoding
-        Ok(ByteBuffer::from(s.as_bytes()))
-    }
-
-    fn read_timestamp(self, _schema: &Schema) -> Result<Instant, Self::Error> {
-        let s = self.parser.next_str().map_err(|e| {
-            JsonSerdeError::DeserializationError(format!("Failed to read timestamp string: {}", e))
+        // JSON protocols write blobs as base64 encoded strings
+        let capacity = base64::decoded_len_estimate(s.len());
+        let mut buffer = PooledBuffer::take(self.pool.as_ref(), capacity);
+        STANDARD.decode_vec(s, &mut buffer).map_err(|e| {
+            JsonSerdeError::DeserializationError(format!("Invalid base64 blob: {}", e))
         })?;
+        Ok(Blob::from(buffer.into_inner()))
+    }
 
-        // TODO: timestampFormat handling
-        Instant::from_utf8(s.as_bytes()).map_err(|e| {
+    fn read_timestamp(self, schema: &Schema) -> Result<Instant, Self::Error> {
+        // JSON protocols default to `epoch-seconds` if no format is specified
+        let format = schema
+            .get_trait_as::<TimestampFormatTrait>()
+            .unwrap_or(&TimestampFormatTrait::EpochSeconds);
+        let timestamp = if matches!(format, TimestampFormatTrait::EpochSeconds) {
+            let bytes = self.parser.next_number_bytes().map_err(|e| {
+                JsonSerdeError::DeserializationError(format!(
+                    "Failed to read timestamp number: {}",
+                    e
+                ))
+            })?;
+            let s = std::str::from_utf8(bytes).map_err(|e| {
+                JsonSerdeError::DeserializationError(format!("Invalid UTF-8 in number: {}", e))
+            })?;
+            Timestamp::parse(s, format)
+        } else {
+            let s = self.parser.next_str().map_err(|e| {
+                JsonSerdeError::DeserializationError(format!(
+                    "Failed to read timestamp string: {}",
+                    e
+                ))
+            })?;
+            Timestamp::parse(s, format)
+        };
+        timestamp.map(Instant::from).map_err(|e| {
             JsonSerdeError::DeserializationError(format!("Failed to parse timestamp: {}", e))
         })
     }
 
-    fn read_document(
-        self,
-        _schema: &Schema,
-    ) -> Result<Box<dyn smithy4rs_core::schema::Document>, Self::Error> {
-        todo!("Support deserialization of documents")
+    fn read_document(self, _schema: &Schema) -> Result<Box<dyn Document>, Self::Error> {
+        self.next_document()
     }
 
     fn read_null(self) -> Result<(), Self::Error> {
@@ -209,10 +428,12 @@ impl<'de, 'a> Deserializer<'de> for &'a mut JsonDeserializer<'de> {
             .map_err(|e| JsonSerdeError::DeserializationError(format!("Expected null: {}", e)))
     }
 
-    fn read_struct(self, _schema: &Schema) -> Result<Self::StructReader, Self::Error> {
+    fn read_struct(self, schema: &Schema) -> Result<Self::StructReader, Self::Error> {
+        let unknown = UnknownMembers::new(schema, self.strict_members);
         Ok(JsonStructReader {
             de: self,
             started: false,
+            unknown,
         })
     }
 
@@ -235,44 +456,100 @@ impl<'de, 'a> Deserializer<'de> for &'a mut JsonDeserializer<'de> {
     }
 }
 
+/// Convert a JSON number literal into the narrowest lossless numeric document.
+///
+/// Integers become `long` (or `bigInteger` if they overflow), and all other
+/// numbers become `double` (or `bigDecimal` if they overflow).
+pub(crate) fn parse_number_document(literal: &str) -> Result<Box<dyn Document>, JsonSerdeError> {
+    let err = |e: &dyn std::fmt::Display| {
+        JsonSerdeError::DeserializationError(format!("Failed to parse number {literal}: {e}"))
+    };
+    if !literal.contains(['.', 'e', 'E']) {
+        if let Ok(value) = literal.parse::<i64>() {
+            return Ok(value.into());
+        }
+        return literal
+            .parse::<BigInt>()
+            .map(Into::into)
+            .map_err(|e| err(&e));
+    }
+    match literal.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value.into()),
+        _ => literal
+            .parse::<BigDecimal>()
+            .map(Into::into)
+            .map_err(|e| err(&e)),
+    }
+}
+
 // ============================================================================
 // StructReader Implementation
 // ============================================================================
 
+impl<'de> JsonStructReader<'de, '_> {
+    /// Read the next object key, or `None` if the object is exhausted.
+    fn next_key<'k>(
+        de: &'k mut JsonDeserializer<'de>,
+        started: &mut bool,
+    ) -> Result<Option<&'k str>, JsonSerdeError> {
+        de.check_deadline()?;
+        if !*started {
+            *started = true;
+            de.parser.next_object().map_err(|e| {
+                JsonSerdeError::DeserializationError(format!("Expected object start: {}", e))
+            })
+        } else {
+            de.parser.next_key().map_err(|e| {
+                JsonSerdeError::DeserializationError(format!("Failed to read object key: {}", e))
+            })
+        }
+    }
+
+    /// Skip the value of an unknown key.
+    fn skip_unknown(&mut self) -> Result<(), JsonSerdeError> {
+        self.de.parser.next_skip().map_err(|e| {
+            JsonSerdeError::DeserializationError(format!("Failed to skip value: {}", e))
+        })
+    }
+}
+
 impl<'de> StructReader<'de> for JsonStructReader<'de, '_> {
     type Error = JsonSerdeError;
 
     fn read_member<'a>(&mut self, schema: &'a Schema) -> Result<Option<&'a Schema>, Self::Error> {
         loop {
-            let maybe_key = if !self.started {
-                self.started = true;
-                self.de.parser.next_object().map_err(|e| {
-                    JsonSerdeError::DeserializationError(format!("Expected object start: {}", e))
-                })?
-            } else {
-                self.de.parser.next_key().map_err(|e| {
-                    JsonSerdeError::DeserializationError(format!(
-                        "Failed to read object key: {}",
-                        e
-                    ))
-                })?
-            };
-
-            match maybe_key {
+            match Self::next_key(self.de, &mut self.started)? {
                 Some(key) => {
-                    if let Some(member_schema) = schema.get_member(key) {
+                    if let Some(member_schema) = schema.get_member_or_alias(key) {
                         return Ok(Some(member_schema));
                     }
                     // Unknown key — skip the value
-                    self.de.parser.next_skip().map_err(|e| {
-                        JsonSerdeError::DeserializationError(format!("Failed to skip value: {}", e))
-                    })?;
+                    self.unknown.insert(key);
+                    self.skip_unknown()?;
+                }
+                None => {
+                    self.unknown.finish::<Self::Error>(schema)?;
+                    return Ok(None);
                 }
-                None => return Ok(None),
             }
         }
     }
 
+    fn read_member_or_unknown<'a>(
+        &mut self,
+        schema: &'a Schema,
+    ) -> Result<Option<MemberKey<'a>>, Self::Error> {
+        // Unknown members are rejected rather than retained in strict mode
+        if self.unknown.is_strict() {
+            return Ok(self.read_member(schema)?.map(MemberKey::Known));
+        }
+        Ok(Self::next_key(self.de, &mut self.started)?.map(|key| {
+            schema
+                .get_member_or_alias(key)
+                .map_or_else(|| MemberKey::Unknown(key.to_string()), MemberKey::Known)
+        }))
+    }
+
     fn read_value<T: DeserializeWithSchema<'de>>(
         &mut self,
         schema: &Schema,
@@ -298,6 +575,7 @@ impl<'de> ListReader<'de> for JsonListReader<'de, '_> {
         &mut self,
         schema: &Schema,
     ) -> Result<Option<T>, Self::Error> {
+        self.de.check_deadline()?;
         let has_element = if !self.started {
             self.started = true;
             self.de
@@ -333,6 +611,7 @@ impl<'de> MapReader<'de> for JsonMapReader<'de, '_> {
     type Error = JsonSerdeError;
 
     fn read_key(&mut self) -> Result<Option<String>, Self::Error> {
+        self.de.check_deadline()?;
         let maybe_key = if !self.started {
             self.started = true;
             self.de.parser.next_object().map_err(|e| {
@@ -344,6 +623,11 @@ impl<'de> MapReader<'de> for JsonMapReader<'de, '_> {
             })?
         };
 
+        if let Some(key) = maybe_key {
+            self.de.strings.check(key).map_err(|e| {
+                JsonSerdeError::DeserializationError(format!("Invalid map key: {}", e))
+            })?;
+        }
         Ok(maybe_key.map(|s| s.to_string()))
     }
 
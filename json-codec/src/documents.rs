@@ -0,0 +1,343 @@
+use std::{borrow::Cow, sync::OnceLock};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use smithy4rs_core::{
+    BigDecimal, BigInt, IndexMap, Instant,
+    schema::{Blob, Document, DocumentError, Schema, SchemaShape, ShapeId, ShapeType},
+};
+
+/// A numeric [`Document`] that retains the literal text it was parsed from.
+///
+/// All accessors delegate to the parsed numeric value, while
+/// [`Document::as_number_literal`] returns the original text so that
+/// [`JsonSerializer`](crate::JsonSerializer) can write it back unchanged.
+#[derive(Clone)]
+pub(crate) struct JsonNumberLiteral {
+    value: Box<dyn Document>,
+    literal: String,
+}
+
+impl JsonNumberLiteral {
+    pub(crate) fn new(value: Box<dyn Document>, literal: String) -> Self {
+        Self { value, literal }
+    }
+}
+
+impl SchemaShape for JsonNumberLiteral {
+    fn schema(&self) -> &Schema {
+        self.value.schema()
+    }
+}
+
+impl Document for JsonNumberLiteral {
+    fn discriminator(&self) -> Option<&ShapeId> {
+        self.value.discriminator()
+    }
+
+    fn get_type(&self) -> Option<&ShapeType> {
+        self.value.get_type()
+    }
+
+    fn size(&self) -> usize {
+        self.value.size()
+    }
+
+    fn as_blob(&self) -> Option<&Blob> {
+        self.value.as_blob()
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        self.value.as_bool()
+    }
+
+    fn as_string(&self) -> Option<&str> {
+        self.value.as_string()
+    }
+
+    fn as_timestamp(&self) -> Option<&Instant> {
+        self.value.as_timestamp()
+    }
+
+    fn as_byte(&self) -> Option<i8> {
+        self.value.as_byte()
+    }
+
+    fn as_short(&self) -> Option<i16> {
+        self.value.as_short()
+    }
+
+    fn as_integer(&self) -> Option<i32> {
+        self.value.as_integer()
+    }
+
+    fn as_long(&self) -> Option<i64> {
+        self.value.as_long()
+    }
+
+    fn as_float(&self) -> Option<f32> {
+        self.value.as_float()
+    }
+
+    fn as_double(&self) -> Option<f64> {
+        self.value.as_double()
+    }
+
+    fn as_big_integer(&self) -> Option<Cow<'_, BigInt>> {
+        self.value.as_big_integer()
+    }
+
+    fn as_big_decimal(&self) -> Option<Cow<'_, BigDecimal>> {
+        self.value.as_big_decimal()
+    }
+
+    fn as_number_literal(&self) -> Option<&str> {
+        Some(&self.literal)
+    }
+
+    fn as_list(&self) -> Option<&Vec<Box<dyn Document>>> {
+        self.value.as_list()
+    }
+
+    fn as_map(&self) -> Option<&IndexMap<String, Box<dyn Document>>> {
+        self.value.as_map()
+    }
+
+    fn is_null(&self) -> bool {
+        self.value.is_null()
+    }
+
+    fn into_blob(self: Box<Self>) -> Result<Blob, DocumentError> {
+        self.value.into_blob()
+    }
+
+    fn into_bool(self: Box<Self>) -> Result<bool, DocumentError> {
+        self.value.into_bool()
+    }
+
+    fn into_string(self: Box<Self>) -> Result<String, DocumentError> {
+        self.value.into_string()
+    }
+
+    fn into_timestamp(self: Box<Self>) -> Result<Instant, DocumentError> {
+        self.value.into_timestamp()
+    }
+
+    fn into_byte(self: Box<Self>) -> Result<i8, DocumentError> {
+        self.value.into_byte()
+    }
+
+    fn into_short(self: Box<Self>) -> Result<i16, DocumentError> {
+        self.value.into_short()
+    }
+
+    fn into_integer(self: Box<Self>) -> Result<i32, DocumentError> {
+        self.value.into_integer()
+    }
+
+    fn into_long(self: Box<Self>) -> Result<i64, DocumentError> {
+        self.value.into_long()
+    }
+
+    fn into_float(self: Box<Self>) -> Result<f32, DocumentError> {
+        self.value.into_float()
+    }
+
+    fn into_double(self: Box<Self>) -> Result<f64, DocumentError> {
+        self.value.into_double()
+    }
+
+    fn into_big_integer(self: Box<Self>) -> Result<BigInt, DocumentError> {
+        self.value.into_big_integer()
+    }
+
+    fn into_big_decimal(self: Box<Self>) -> Result<BigDecimal, DocumentError> {
+        self.value.into_big_decimal()
+    }
+
+    fn into_list(self: Box<Self>) -> Result<Vec<Box<dyn Document>>, DocumentError> {
+        self.value.into_list()
+    }
+
+    fn into_map(self: Box<Self>) -> Result<IndexMap<String, Box<dyn Document>>, DocumentError> {
+        self.value.into_map()
+    }
+
+    fn box_clone(&self) -> Box<dyn Document> {
+        Box::new(self.clone())
+    }
+}
+
+/// A string [`Document`] that can also be read as a base64 encoded blob.
+///
+/// JSON has no binary type, so JSON protocols write blobs as base64 encoded
+/// strings. Because untyped documents cannot tell the two apart when parsing,
+/// string values are kept as strings and decoded on the first request for
+/// their blob value (for example, when the document is converted into a shape
+/// with a blob member).
+#[derive(Clone)]
+pub(crate) struct JsonString {
+    value: Box<dyn Document>,
+    blob: OnceLock<Option<Blob>>,
+}
+
+impl JsonString {
+    pub(crate) fn new(value: String) -> Self {
+        Self {
+            value: value.into(),
+            blob: OnceLock::new(),
+        }
+    }
+
+    fn decode(&self) -> Result<Blob, DocumentError> {
+        let value = self.value.as_string().ok_or_else(|| {
+            DocumentError::DocumentConversion("Expected string document".to_string())
+        })?;
+        STANDARD
+            .decode(value)
+            .map(Blob::from)
+            .map_err(|e| DocumentError::DocumentConversion(format!("Invalid base64 blob: {e}")))
+    }
+}
+
+impl SchemaShape for JsonString {
+    fn schema(&self) -> &Schema {
+        self.value.schema()
+    }
+}
+
+impl Document for JsonString {
+    fn discriminator(&self) -> Option<&ShapeId> {
+        self.value.discriminator()
+    }
+
+    fn get_type(&self) -> Option<&ShapeType> {
+        self.value.get_type()
+    }
+
+    fn size(&self) -> usize {
+        self.value.size()
+    }
+
+    fn as_blob(&self) -> Option<&Blob> {
+        self.blob.get_or_init(|| self.decode().ok()).as_ref()
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        self.value.as_bool()
+    }
+
+    fn as_string(&self) -> Option<&str> {
+        self.value.as_string()
+    }
+
+    fn as_timestamp(&self) -> Option<&Instant> {
+        self.value.as_timestamp()
+    }
+
+    fn as_byte(&self) -> Option<i8> {
+        self.value.as_byte()
+    }
+
+    fn as_short(&self) -> Option<i16> {
+        self.value.as_short()
+    }
+
+    fn as_integer(&self) -> Option<i32> {
+        self.value.as_integer()
+    }
+
+    fn as_long(&self) -> Option<i64> {
+        self.value.as_long()
+    }
+
+    fn as_float(&self) -> Option<f32> {
+        self.value.as_float()
+    }
+
+    fn as_double(&self) -> Option<f64> {
+        self.value.as_double()
+    }
+
+    fn as_big_integer(&self) -> Option<Cow<'_, BigInt>> {
+        self.value.as_big_integer()
+    }
+
+    fn as_big_decimal(&self) -> Option<Cow<'_, BigDecimal>> {
+        self.value.as_big_decimal()
+    }
+
+    fn as_list(&self) -> Option<&Vec<Box<dyn Document>>> {
+        self.value.as_list()
+    }
+
+    fn as_map(&self) -> Option<&IndexMap<String, Box<dyn Document>>> {
+        self.value.as_map()
+    }
+
+    fn is_null(&self) -> bool {
+        self.value.is_null()
+    }
+
+    fn into_blob(mut self: Box<Self>) -> Result<Blob, DocumentError> {
+        match self.blob.take() {
+            Some(Some(blob)) => Ok(blob),
+            _ => self.decode(),
+        }
+    }
+
+    fn into_bool(self: Box<Self>) -> Result<bool, DocumentError> {
+        self.value.into_bool()
+    }
+
+    fn into_string(self: Box<Self>) -> Result<String, DocumentError> {
+        self.value.into_string()
+    }
+
+    fn into_timestamp(self: Box<Self>) -> Result<Instant, DocumentError> {
+        self.value.into_timestamp()
+    }
+
+    fn into_byte(self: Box<Self>) -> Result<i8, DocumentError> {
+        self.value.into_byte()
+    }
+
+    fn into_short(self: Box<Self>) -> Result<i16, DocumentError> {
+        self.value.into_short()
+    }
+
+    fn into_integer(self: Box<Self>) -> Result<i32, DocumentError> {
+        self.value.into_integer()
+    }
+
+    fn into_long(self: Box<Self>) -> Result<i64, DocumentError> {
+        self.value.into_long()
+    }
+
+    fn into_float(self: Box<Self>) -> Result<f32, DocumentError> {
+        self.value.into_float()
+    }
+
+    fn into_double(self: Box<Self>) -> Result<f64, DocumentError> {
+        self.value.into_double()
+    }
+
+    fn into_big_integer(self: Box<Self>) -> Result<BigInt, DocumentError> {
+        self.value.into_big_integer()
+    }
+
+    fn into_big_decimal(self: Box<Self>) -> Result<BigDecimal, DocumentError> {
+        self.value.into_big_decimal()
+    }
+
+    fn into_list(self: Box<Self>) -> Result<Vec<Box<dyn Document>>, DocumentError> {
+        self.value.into_list()
+    }
+
+    fn into_map(self: Box<Self>) -> Result<IndexMap<String, Box<dyn Document>>, DocumentError> {
+        self.value.into_map()
+    }
+
+    fn box_clone(&self) -> Box<dyn Document> {
+        Box::new(self.clone())
+    }
+}
@@ -1,7 +1,11 @@
 use std::fmt::Display;
 
-use smithy4rs_core::serde::{
-    deserializers::Error as DeserializerError, serializers::Error as SerializerError,
+use smithy4rs_core::{
+    schema::Schema,
+    serde::{
+        CodecError, deadline::DeadlineExceeded, deserializers::Error as DeserializerError,
+        serializers::Error as SerializerError,
+    },
 };
 use thiserror::Error;
 
@@ -13,10 +17,16 @@ pub enum JsonSerdeError {
     // TODO(errors): I don't *love* that we're using strings, it also makes tests rely on matching specific error messages...
     #[error("Failed to deserialize: {0}")]
     DeserializationError(String),
+    #[error("Failed to deserialize: duplicate member `{0}`")]
+    DuplicateMember(String),
+    #[error("Failed to deserialize: unknown members of `{shape}`: {}", members.join(", "))]
+    UnknownMembers { shape: String, members: Vec<String> },
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
     #[error("Format error: {0}")]
     FmtError(#[from] std::fmt::Error),
+    #[error("Failed to deserialize: {0}")]
+    DeadlineExceeded(#[from] DeadlineExceeded),
 }
 
 impl SerializerError for JsonSerdeError {
@@ -29,4 +39,32 @@ impl DeserializerError for JsonSerdeError {
     fn custom<T: Display>(msg: T) -> Self {
         JsonSerdeError::DeserializationError(msg.to_string())
     }
+
+    fn duplicate_member(member: &Schema) -> Self {
+        JsonSerdeError::DuplicateMember(member.id().id().to_string())
+    }
+
+    fn unknown_members(schema: &Schema, members: Vec<String>) -> Self {
+        JsonSerdeError::UnknownMembers {
+            shape: schema.id().id().to_string(),
+            members,
+        }
+    }
+}
+
+impl From<JsonSerdeError> for CodecError {
+    fn from(value: JsonSerdeError) -> Self {
+        match value {
+            JsonSerdeError::IoError(e) => CodecError::Io(e),
+            JsonSerdeError::DuplicateMember(member) => CodecError::DuplicateMember(member),
+            JsonSerdeError::UnknownMembers { shape, members } => {
+                CodecError::UnknownMembers { shape, members }
+            }
+            JsonSerdeError::SerializationError(msg) | JsonSerdeError::DeserializationError(msg) => {
+                CodecError::Custom(msg)
+            }
+            JsonSerdeError::FmtError(_) => CodecError::Custom(value.to_string()),
+            JsonSerdeError::DeadlineExceeded(e) => CodecError::DeadlineExceeded(e),
+        }
+    }
 }
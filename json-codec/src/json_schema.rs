@@ -0,0 +1,254 @@
+use std::str::FromStr;
+
+use serde_json::{Map, Number, Value};
+use smithy4rs_core::{
+    prelude::{
+        DeprecatedTrait, DocumentationTrait, LengthTrait, PatternTrait, RangeTrait, RequiredTrait,
+        TimestampFormatTrait, UniqueItemsTrait,
+    },
+    schema::{MemberSchema, Schema, ShapeType},
+};
+
+/// URI of the JSON Schema dialect used by generated schemas.
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Convert a shape schema into a [JSON Schema (2020-12)](https://json-schema.org/draft/2020-12)
+/// document describing the JSON written by [`JsonCodec`](crate::JsonCodec).
+///
+/// Constraint traits are converted into the equivalent validation keywords so that
+/// clients (for example, frontend forms) can share the validation rules of the model:
+///
+/// | Trait            | Keywords                                                              |
+/// |------------------|-----------------------------------------------------------------------|
+/// | `@length`        | `minLength`/`maxLength`, `minItems`/`maxItems`, `minProperties`/`maxProperties` |
+/// | `@pattern`       | `pattern`                                                             |
+/// | `@range`         | `minimum`/`maximum`                                                   |
+/// | `@required`      | `required`                                                            |
+/// | `@uniqueItems`   | `uniqueItems`                                                         |
+/// | `enum`/`intEnum` | `enum`                                                                |
+///
+/// Structures, unions, and enums other than the root shape are written to `$defs`
+/// and referenced by name, so recursive shapes can be described.
+///
+/// <div class="note">
+/// **NOTE**: Shapes are keyed by name only, so shapes with the same name in
+/// different namespaces will collide.
+/// </div>
+#[must_use]
+pub fn schema_to_json_schema(schema: &Schema) -> Value {
+    let root = target(schema);
+    let mut converter = Converter {
+        root: root.id().name(),
+        defs: Map::new(),
+    };
+    let mut object = Map::new();
+    object.insert("$schema".to_string(), DIALECT.into());
+    object.insert("title".to_string(), root.id().name().into());
+    object.extend(converter.definition(root));
+    if !converter.defs.is_empty() {
+        object.insert("$defs".to_string(), converter.defs.into());
+    }
+    object.into()
+}
+
+struct Converter<'s> {
+    root: &'s str,
+    defs: Map<String, Value>,
+}
+
+impl Converter<'_> {
+    /// Create a JSON Schema for a member or list, map, or scalar shape.
+    ///
+    /// Aggregate and enum shapes are added to `$defs` and referenced by name.
+    fn member(&mut self, schema: &Schema) -> Value {
+        match schema.shape_type() {
+            ShapeType::Structure | ShapeType::Union | ShapeType::Enum | ShapeType::IntEnum => {
+                let mut object = self.reference(target(schema));
+                describe(schema, &mut object);
+                object.into()
+            }
+            _ => self.inline(schema).into(),
+        }
+    }
+
+    /// Get a reference to a named shape, adding it to `$defs` if it has not been seen yet.
+    fn reference(&mut self, schema: &Schema) -> Map<String, Value> {
+        let name = schema.id().name();
+        let mut reference = Map::new();
+        if name == self.root {
+            reference.insert("$ref".to_string(), "#".into());
+            return reference;
+        }
+        if !self.defs.contains_key(name) {
+            // Insert a placeholder so recursive references resolve to this entry
+            self.defs.insert(name.to_string(), Map::new().into());
+            let definition = self.definition(schema);
+            self.defs.insert(name.to_string(), definition.into());
+        }
+        reference.insert("$ref".to_string(), format!("#/$defs/{name}").into());
+        reference
+    }
+
+    fn definition(&mut self, schema: &Schema) -> Map<String, Value> {
+        let mut definition = match schema.shape_type() {
+            ShapeType::Structure => self.structure(schema),
+            ShapeType::Union => self.union(schema),
+            _ => self.inline(schema),
+        };
+        describe(schema, &mut definition);
+        definition
+    }
+
+    fn structure(&mut self, schema: &Schema) -> Map<String, Value> {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for member in schema.members().values() {
+            let name = member_name(member);
+            if member.contains_type::<RequiredTrait>() {
+                required.push(Value::from(name));
+            }
+            properties.insert(name.to_string(), self.member(member));
+        }
+        let mut object = Map::new();
+        object.insert("type".to_string(), "object".into());
+        object.insert("properties".to_string(), properties.into());
+        if !required.is_empty() {
+            object.insert("required".to_string(), required.into());
+        }
+        object
+    }
+
+    fn union(&mut self, schema: &Schema) -> Map<String, Value> {
+        let variants = schema
+            .members()
+            .values()
+            .map(|member| {
+                let name = member_name(member);
+                let mut properties = Map::new();
+                properties.insert(name.to_string(), self.member(member));
+                let mut variant = Map::new();
+                variant.insert("type".to_string(), "object".into());
+                variant.insert("properties".to_string(), properties.into());
+                variant.insert("required".to_string(), vec![name].into());
+                variant.into()
+            })
+            .collect::<Vec<Value>>();
+        let mut object = Map::new();
+        object.insert("oneOf".to_string(), variants.into());
+        object
+    }
+
+    fn inline(&mut self, schema: &Schema) -> Map<String, Value> {
+        let mut object = Map::new();
+        let mut set = |key: &str, value: Value| {
+            object.insert(key.to_string(), value);
+        };
+        match schema.shape_type() {
+            ShapeType::Boolean => set("type", "boolean".into()),
+            ShapeType::Byte
+            | ShapeType::Short
+            | ShapeType::Integer
+            | ShapeType::Long
+            | ShapeType::BigInteger => set("type", "integer".into()),
+            ShapeType::Float | ShapeType::Double | ShapeType::BigDecimal => {
+                set("type", "number".into());
+            }
+            ShapeType::String => set("type", "string".into()),
+            ShapeType::Blob => {
+                set("type", "string".into());
+                set("contentEncoding", "base64".into());
+            }
+            ShapeType::Timestamp => match schema.get_trait_as::<TimestampFormatTrait>() {
+                Some(TimestampFormatTrait::DateTime) => {
+                    set("type", "string".into());
+                    set("format", "date-time".into());
+                }
+                Some(TimestampFormatTrait::HttpDate) => set("type", "string".into()),
+                _ => set("type", "number".into()),
+            },
+            ShapeType::Enum => {
+                set("type", "string".into());
+                if let Some(values) = target(schema).as_enum() {
+                    set("enum", values.values().iter().copied().collect());
+                }
+            }
+            ShapeType::IntEnum => {
+                set("type", "integer".into());
+                if let Some(values) = target(schema).as_int_enum() {
+                    set("enum", values.values().iter().copied().collect());
+                }
+            }
+            ShapeType::List => {
+                set("type", "array".into());
+                if let Some(member) = schema.get_list_member() {
+                    set("items", self.member(member));
+                }
+            }
+            ShapeType::Map => {
+                set("type", "object".into());
+                if let Some((_, value)) = schema.get_key_value() {
+                    set("additionalProperties", self.member(value));
+                }
+            }
+            // Documents may hold any value
+            _ => {}
+        }
+        describe(schema, &mut object);
+        constraints(schema, &mut object);
+        object
+    }
+}
+
+/// Get the shape targeted by a member, or the shape itself if it is not a member.
+fn target(schema: &Schema) -> &Schema {
+    schema.as_member().map_or(schema, MemberSchema::target)
+}
+
+fn member_name(schema: &Schema) -> &str {
+    schema
+        .as_member()
+        .map_or_else(|| schema.id().name(), MemberSchema::name)
+}
+
+/// Add `description` and `deprecated` keywords for documentation traits.
+fn describe(schema: &Schema, object: &mut Map<String, Value>) {
+    if let Some(docs) = schema.get_trait_as::<DocumentationTrait>() {
+        object.insert("description".to_string(), docs.as_str().into());
+    }
+    if schema.contains_type::<DeprecatedTrait>() {
+        object.insert("deprecated".to_string(), true.into());
+    }
+}
+
+/// Add JSON Schema validation keywords for constraint traits.
+fn constraints(schema: &Schema, object: &mut Map<String, Value>) {
+    let length_keys = match schema.shape_type() {
+        ShapeType::String => Some(("minLength", "maxLength")),
+        ShapeType::List => Some(("minItems", "maxItems")),
+        ShapeType::Map => Some(("minProperties", "maxProperties")),
+        _ => None,
+    };
+    if let (Some(length), Some((min, max))) = (schema.get_trait_as::<LengthTrait>(), length_keys) {
+        if let Some(value) = length.min {
+            object.insert(min.to_string(), value.into());
+        }
+        if let Some(value) = length.max {
+            object.insert(max.to_string(), value.into());
+        }
+    }
+    if let Some(pattern) = schema.get_trait_as::<PatternTrait>() {
+        object.insert("pattern".to_string(), pattern.as_str().into());
+    }
+    if let Some(range) = schema.get_trait_as::<RangeTrait>() {
+        let number = |value: &smithy4rs_core::BigDecimal| Number::from_str(&value.to_string()).ok();
+        if let Some(value) = range.min.as_ref().and_then(number) {
+            object.insert("minimum".to_string(), value.into());
+        }
+        if let Some(value) = range.max.as_ref().and_then(number) {
+            object.insert("maximum".to_string(), value.into());
+        }
+    }
+    if schema.contains_type::<UniqueItemsTrait>() {
+        object.insert("uniqueItems".to_string(), true.into());
+    }
+}
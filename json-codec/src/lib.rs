@@ -1,7 +1,24 @@
+#[cfg(feature = "parallel")]
+mod bulk;
+mod codec;
 mod deserialization;
+mod documents;
 mod errors;
+mod json_schema;
 mod serialization;
+#[cfg(feature = "simd")]
+pub mod simd;
 
+#[cfg(feature = "parallel")]
+pub use bulk::{BulkRecords, RecordError, deserialize_bulk};
+pub use codec::JsonCodec;
 pub use deserialization::JsonDeserializer;
 pub use errors::JsonSerdeError;
+pub use json_schema::schema_to_json_schema;
 pub use serialization::JsonSerializer;
+#[cfg(feature = "simd")]
+pub use simd::SimdJsonDeserializer;
+
+smithy4rs_core::assert_send_sync!(JsonCodec, JsonSerdeError, JsonDeserializer<'static>);
+#[cfg(feature = "simd")]
+smithy4rs_core::assert_send_sync!(SimdJsonDeserializer<'static>);
@@ -1,38 +1,48 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
 use smithy4rs_core::{
-    BigDecimal, BigInt, ByteBuffer, Instant,
-    schema::Schema,
+    BigDecimal, BigInt, Instant,
+    prelude::TimestampFormatTrait,
+    schema::{Blob, Document, Schema, ShapeType, Timestamp},
     serde::serializers::{ListWriter, MapWriter, SerializeWithSchema, Serializer, StructWriter},
 };
 
 use crate::errors::JsonSerdeError;
 
-// Pre-computed escape lookup table for fast string escaping
+// Pre-computed escape lookup table for fast string escaping.
+//
+// Each entry is `0` for bytes written as-is, the character following the backslash
+// for short escape sequences, or `u` for bytes written as `\u00XX`.
 static ESCAPE: [u8; 256] = {
-    const NONE: u8 = 0;
-    const ESCAPE: u8 = 1;
-    let mut table = [NONE; 256];
-
-    table[b'"' as usize] = ESCAPE;
-    table[b'\\' as usize] = ESCAPE;
-    table[b'\n' as usize] = ESCAPE;
-    table[b'\r' as usize] = ESCAPE;
-    table[b'\t' as usize] = ESCAPE;
+    let mut table = [0; 256];
 
     // Control characters (0x00-0x1F)
     let mut i = 0;
     while i < 0x20 {
-        table[i] = ESCAPE;
+        table[i] = b'u';
         i += 1;
     }
 
+    table[b'"' as usize] = b'"';
+    table[b'\\' as usize] = b'\\';
+    table[b'\n' as usize] = b'n';
+    table[b'\r' as usize] = b'r';
+    table[b'\t' as usize] = b't';
+    table[0x08] = b'b';
+    table[0x0C] = b'f';
+
     table
 };
 
 // TODO: Add pretty printing, will need some refactoring of the writing code to add some formatter/writer abstraction
 /// JSON serializer that writes directly to a `Vec<u8>`.
+///
+/// Output never contains insignificant whitespace. Use [`JsonSerializer::canonical`]
+/// to additionally produce deterministic, [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)
+/// (JCS) canonical output.
 #[repr(C, align(64))]
 pub struct JsonSerializer<'a> {
     buf: &'a mut Vec<u8>,
+    canonical: bool,
 }
 
 impl<'a> JsonSerializer<'a> {
@@ -42,7 +52,34 @@ impl<'a> JsonSerializer<'a> {
     #[inline]
     pub fn new(buf: &'a mut Vec<u8>) -> Self {
         buf.clear();
-        Self { buf }
+        Self {
+            buf,
+            canonical: false,
+        }
+    }
+
+    /// Create a new JSON serializer that writes canonical JSON to the given buffer.
+    ///
+    /// Canonical output follows the JSON Canonicalization Scheme ([RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)),
+    /// making it suitable for signing payloads and for snapshot tests:
+    /// - Object members (structure members and map entries) are sorted by their
+    ///   UTF-16 code units.
+    /// - Floating point numbers use the shortest round-trip ECMAScript representation
+    ///   (i.e. `1.0` is written as `1` and `1e21` as `1e+21`).
+    /// - Strings only escape the characters JCS requires to be escaped.
+    ///
+    /// Non-finite floating point values (`NaN`, `Infinity`) cannot be represented in
+    /// canonical JSON and will result in a serialization error. Integers and
+    /// big numbers are written exactly rather than being rounded to IEEE-754 doubles.
+    ///
+    /// The buffer will be cleared before use.
+    #[inline]
+    pub fn canonical(buf: &'a mut Vec<u8>) -> Self {
+        buf.clear();
+        Self {
+            buf,
+            canonical: true,
+        }
     }
 
     /// Create a new JSON serializer with a capacity hint.
@@ -52,7 +89,57 @@ impl<'a> JsonSerializer<'a> {
     pub fn with_capacity(buf: &'a mut Vec<u8>, capacity: usize) -> Self {
         buf.clear();
         buf.reserve(capacity);
-        Self { buf }
+        Self {
+            buf,
+            canonical: false,
+        }
+    }
+
+    /// Create a new JSON serializer that appends to the given buffer.
+    ///
+    /// Unlike [`JsonSerializer::new`], the buffer is _not_ cleared, so existing
+    /// contents (i.e. a framing header) are preserved and its allocation can be
+    /// reused across many serializations.
+    #[inline]
+    pub fn with_buffer(buf: &'a mut Vec<u8>) -> Self {
+        Self {
+            buf,
+            canonical: false,
+        }
+    }
+
+    /// Clear the output buffer, retaining its allocated capacity.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.buf.clear();
+    }
+
+    /// Borrow this serializer to serialize a value without consuming it.
+    ///
+    /// The returned serializer writes to the same buffer with the same settings,
+    /// so a single serializer (and buffer) can be reused for many values:
+    ///
+    /// ```rust,ignore
+    /// let mut buf = Vec::new();
+    /// let mut serializer = JsonSerializer::new(&mut buf);
+    /// for shape in shapes {
+    ///     serializer.reset();
+    ///     shape.serialize_with_schema(schema, serializer.by_ref())?;
+    ///     send(serializer.as_bytes());
+    /// }
+    /// ```
+    #[inline]
+    pub fn by_ref(&mut self) -> JsonSerializer<'_> {
+        JsonSerializer {
+            buf: self.buf,
+            canonical: self.canonical,
+        }
+    }
+
+    /// Returns `true` if this serializer produces canonical JSON.
+    #[inline]
+    pub fn is_canonical(&self) -> bool {
+        self.canonical
     }
 
     /// Get the serialized JSON as a string slice.
@@ -92,6 +179,7 @@ impl<'a> Serializer for JsonSerializer<'a> {
         Ok(JsonStructSerializer {
             buf: self.buf,
             first: true,
+            entries: self.canonical.then(Vec::new),
         })
     }
 
@@ -101,6 +189,7 @@ impl<'a> Serializer for JsonSerializer<'a> {
         Ok(JsonMapSerializer {
             buf: self.buf,
             first: true,
+            entries: self.canonical.then(Vec::new),
         })
     }
 
@@ -110,6 +199,7 @@ impl<'a> Serializer for JsonSerializer<'a> {
         Ok(JsonListSerializer {
             buf: self.buf,
             first: true,
+            canonical: self.canonical,
         })
     }
 
@@ -146,12 +236,18 @@ impl<'a> Serializer for JsonSerializer<'a> {
 
     #[inline]
     fn write_float(self, _schema: &Schema, value: f32) -> Result<Self::Ok, Self::Error> {
+        if self.canonical {
+            return write_canonical_number(self.buf, value);
+        }
         write_json_float(self.buf, value);
         Ok(())
     }
 
     #[inline]
     fn write_double(self, _schema: &Schema, value: f64) -> Result<Self::Ok, Self::Error> {
+        if self.canonical {
+            return write_canonical_number(self.buf, value);
+        }
         write_json_double(self.buf, value);
         Ok(())
     }
@@ -183,13 +279,67 @@ impl<'a> Serializer for JsonSerializer<'a> {
     }
 
     #[inline]
-    fn write_blob(self, _schema: &Schema, _value: &ByteBuffer) -> Result<Self::Ok, Self::Error> {
-        todo!("Blob serialization (base64 encoding) not yet implemented")
+    fn write_blob(self, _schema: &Schema, value: &Blob) -> Result<Self::Ok, Self::Error> {
+        // JSON protocols write blobs as base64 encoded strings. The base64 alphabet
+        // never needs escaping, so encode directly into the output buffer.
+        let bytes = value.as_bytes();
+        let encoded_len = base64::encoded_len(bytes.len(), true).ok_or_else(|| {
+            JsonSerdeError::SerializationError("Blob is too large to encode".to_string())
+        })?;
+        self.buf.push(b'"');
+        let start = self.buf.len();
+        self.buf.resize(start + encoded_len, 0);
+        STANDARD
+            .encode_slice(bytes, &mut self.buf[start..])
+            .map_err(|e| {
+                JsonSerdeError::SerializationError(format!("Failed to encode blob: {e}"))
+            })?;
+        self.buf.push(b'"');
+        Ok(())
     }
 
     #[inline]
-    fn write_timestamp(self, _schema: &Schema, _value: &Instant) -> Result<Self::Ok, Self::Error> {
-        todo!("Timestamp serialization not yet implemented")
+    fn write_timestamp(self, schema: &Schema, value: &Instant) -> Result<Self::Ok, Self::Error> {
+        // JSON protocols default to `epoch-seconds` if no format is specified
+        let format = schema
+            .get_trait_as::<TimestampFormatTrait>()
+            .unwrap_or(&TimestampFormatTrait::EpochSeconds);
+        let formatted = Timestamp::from(*value).format(format).map_err(|e| {
+            JsonSerdeError::SerializationError(format!("Failed to format timestamp: {e}"))
+        })?;
+        if matches!(format, TimestampFormatTrait::EpochSeconds) {
+            self.buf.extend_from_slice(formatted.as_bytes());
+        } else {
+            write_json_string(self.buf, &formatted);
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::borrowed_box)]
+    fn write_document(
+        mut self,
+        schema: &Schema,
+        value: &Box<dyn Document>,
+    ) -> Result<Self::Ok, Self::Error> {
+        // Numbers read with preserved literals are written back unchanged,
+        // unless canonical formatting is required.
+        if !self.canonical
+            && let Some(literal) = value.as_number_literal()
+        {
+            self.push_bytes(literal.as_bytes());
+            return Ok(());
+        }
+        if value.get_type().is_none() {
+            return self.write_null(schema);
+        }
+        if value.schema().shape_type() == &ShapeType::Document {
+            return Err(JsonSerdeError::SerializationError(
+                "Document value has no concrete schema".to_string(),
+            ));
+        }
+        // Serialize using the document's own schema so nested documents are
+        // written with any traits they were created with.
+        value.serialize_with_schema(value.schema(), self)
     }
 
     #[inline]
@@ -208,6 +358,7 @@ impl<'a> Serializer for JsonSerializer<'a> {
 pub struct JsonListSerializer<'a> {
     buf: &'a mut Vec<u8>,
     first: bool,
+    canonical: bool,
 }
 
 impl<'a> ListWriter for JsonListSerializer<'a> {
@@ -224,7 +375,10 @@ impl<'a> ListWriter for JsonListSerializer<'a> {
         }
         self.first = false;
 
-        let serializer = JsonSerializer { buf: self.buf };
+        let serializer = JsonSerializer {
+            buf: self.buf,
+            canonical: self.canonical,
+        };
         value.serialize_with_schema(element_schema, serializer)?;
 
         Ok(())
@@ -240,6 +394,8 @@ impl<'a> ListWriter for JsonListSerializer<'a> {
 pub struct JsonMapSerializer<'a> {
     buf: &'a mut Vec<u8>,
     first: bool,
+    /// Buffered entries, only present in canonical mode.
+    entries: Option<Vec<CanonicalEntry>>,
 }
 
 impl<'a> MapWriter for JsonMapSerializer<'a> {
@@ -258,17 +414,36 @@ impl<'a> MapWriter for JsonMapSerializer<'a> {
         K: SerializeWithSchema,
         V: SerializeWithSchema,
     {
+        if let Some(entries) = &mut self.entries {
+            let mut key_buf = Vec::new();
+            key.serialize_with_schema(key_schema, JsonSerializer::canonical(&mut key_buf))?;
+            let key = unescape_canonical_key(&key_buf)?;
+            let mut value_buf = Vec::new();
+            value.serialize_with_schema(value_schema, JsonSerializer::canonical(&mut value_buf))?;
+            entries.push(CanonicalEntry {
+                key,
+                value: value_buf,
+            });
+            return Ok(());
+        }
+
         if !self.first {
             write_json_comma(self.buf);
         }
         self.first = false;
 
-        let key_serializer = JsonSerializer { buf: self.buf };
+        let key_serializer = JsonSerializer {
+            buf: self.buf,
+            canonical: false,
+        };
         key.serialize_with_schema(key_schema, key_serializer)?;
 
         write_json_colon(self.buf);
 
-        let value_serializer = JsonSerializer { buf: self.buf };
+        let value_serializer = JsonSerializer {
+            buf: self.buf,
+            canonical: false,
+        };
         value.serialize_with_schema(value_schema, value_serializer)?;
 
         Ok(())
@@ -276,6 +451,9 @@ impl<'a> MapWriter for JsonMapSerializer<'a> {
 
     #[inline]
     fn end(self, _schema: &Schema) -> Result<(), Self::Error> {
+        if let Some(entries) = self.entries {
+            write_canonical_entries(self.buf, entries);
+        }
         end_json_object(self.buf);
         Ok(())
     }
@@ -284,35 +462,66 @@ impl<'a> MapWriter for JsonMapSerializer<'a> {
 pub struct JsonStructSerializer<'a> {
     buf: &'a mut Vec<u8>,
     first: bool,
+    /// Buffered members, only present in canonical mode.
+    entries: Option<Vec<CanonicalEntry>>,
 }
 
-impl<'a> StructWriter for JsonStructSerializer<'a> {
-    type Error = JsonSerdeError;
-    type Ok = ();
-
+impl JsonStructSerializer<'_> {
     #[inline]
-    fn write_member<T>(&mut self, member_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    fn write_named<T>(
+        &mut self,
+        member_name: &str,
+        member_schema: &Schema,
+        value: &T,
+    ) -> Result<(), JsonSerdeError>
     where
         T: SerializeWithSchema,
     {
+        if let Some(entries) = &mut self.entries {
+            let mut value_buf = Vec::new();
+            value
+                .serialize_with_schema(member_schema, JsonSerializer::canonical(&mut value_buf))?;
+            entries.push(CanonicalEntry {
+                key: member_name.to_string(),
+                value: value_buf,
+            });
+            return Ok(());
+        }
+
         if !self.first {
             write_json_comma(self.buf);
         }
         self.first = false;
 
-        // Get the member name from the schema
-        let member = member_schema.as_member().ok_or_else(|| {
-            JsonSerdeError::SerializationError("Expected member schema".to_string())
-        })?;
-
-        write_json_string(self.buf, member.name());
+        write_json_string(self.buf, member_name);
         write_json_colon(self.buf);
 
-        let value_serializer = JsonSerializer { buf: self.buf };
+        let value_serializer = JsonSerializer {
+            buf: self.buf,
+            canonical: false,
+        };
         value.serialize_with_schema(member_schema, value_serializer)?;
 
         Ok(())
     }
+}
+
+impl<'a> StructWriter for JsonStructSerializer<'a> {
+    type Error = JsonSerdeError;
+    type Ok = ();
+
+    #[inline]
+    fn write_member<T>(&mut self, member_schema: &Schema, value: &T) -> Result<(), Self::Error>
+    where
+        T: SerializeWithSchema,
+    {
+        // Get the member name from the schema
+        let member = member_schema.as_member().ok_or_else(|| {
+            JsonSerdeError::SerializationError("Expected member schema".to_string())
+        })?;
+
+        self.write_named(member.name(), member_schema, value)
+    }
 
     #[inline]
     fn write_member_named<T>(
@@ -324,22 +533,14 @@ impl<'a> StructWriter for JsonStructSerializer<'a> {
     where
         T: SerializeWithSchema,
     {
-        if !self.first {
-            write_json_comma(self.buf);
-        }
-        self.first = false;
-
-        write_json_string(self.buf, member_name);
-        write_json_colon(self.buf);
-
-        let value_serializer = JsonSerializer { buf: self.buf };
-        value.serialize_with_schema(member_schema, value_serializer)?;
-
-        Ok(())
+        self.write_named(member_name, member_schema, value)
     }
 
     #[inline]
     fn end(self, _schema: &Schema) -> Result<(), Self::Error> {
+        if let Some(entries) = self.entries {
+            write_canonical_entries(self.buf, entries);
+        }
         end_json_object(self.buf);
         Ok(())
     }
@@ -387,58 +588,73 @@ fn write_json_integer<T: itoa::Integer>(buf: &mut Vec<u8>, value: T) {
     buf.extend_from_slice(itoa::Buffer::new().format(value).as_bytes());
 }
 
-/// Optimized JSON string escaping using lookup table.
+/// Optimized JSON string escaping.
 ///
-/// This is significantly faster than character-by-character iteration
-/// for ASCII-heavy strings (which is the common case).
+/// Runs of bytes that need no escaping (the common case) are located with
+/// [`find_escape`] and copied with a single call. Bytes that must be escaped
+/// are written using the [`ESCAPE`] lookup table.
 #[inline]
 fn write_json_string(buf: &mut Vec<u8>, s: &str) {
+    let bytes = s.as_bytes();
+    buf.reserve(bytes.len() + 2);
     buf.push(b'"');
 
-    let bytes = s.as_bytes();
     let mut start = 0;
-
-    // Fast path: scan for characters that need escaping
-    for (i, &byte) in bytes.iter().enumerate() {
-        if byte < 128 && ESCAPE[byte as usize] == 0 {
-            // Common case: no escape needed
-            continue;
-        }
-
-        // Found a character that needs escaping
-        // Write everything up to this point
-        if start < i {
-            buf.extend_from_slice(&bytes[start..i]);
+    while let Some(offset) = find_escape(&bytes[start..]) {
+        let index = start + offset;
+        buf.extend_from_slice(&bytes[start..index]);
+        let byte = bytes[index];
+        match ESCAPE[byte as usize] {
+            b'u' => buf.extend_from_slice(&[
+                b'\\',
+                b'u',
+                b'0',
+                b'0',
+                HEX_DIGITS[(byte >> 4) as usize],
+                HEX_DIGITS[(byte & 0x0F) as usize],
+            ]),
+            escape => buf.extend_from_slice(&[b'\\', escape]),
         }
+        start = index + 1;
+    }
+    buf.extend_from_slice(&bytes[start..]);
 
-        // Write the escape sequence
-        match byte {
-            b'"' => buf.extend_from_slice(b"\\\""),
-            b'\\' => buf.extend_from_slice(b"\\\\"),
-            b'\n' => buf.extend_from_slice(b"\\n"),
-            b'\r' => buf.extend_from_slice(b"\\r"),
-            b'\t' => buf.extend_from_slice(b"\\t"),
-            b if b < 0x20 => {
-                // Control character
-                buf.extend_from_slice(b"\\u00");
-                buf.push(HEX_DIGITS[(b >> 4) as usize]);
-                buf.push(HEX_DIGITS[(b & 0x0F) as usize]);
-            }
-            _ => {
-                // Non-ASCII - write as-is (already valid UTF-8)
-                buf.push(byte);
-            }
-        }
+    buf.push(b'"');
+}
 
-        start = i + 1;
-    }
+const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
 
-    // Write remaining unescaped portion
-    if start < bytes.len() {
-        buf.extend_from_slice(&bytes[start..]);
+/// Find the index of the first byte in `bytes` that must be escaped.
+///
+/// Bytes are checked eight at a time so that long runs of plain text (including
+/// non-ASCII UTF-8) are skipped without a table lookup per byte.
+#[inline]
+fn find_escape(bytes: &[u8]) -> Option<usize> {
+    let mut offset = 0;
+    for chunk in bytes.chunks_exact(8) {
+        let word = u64::from_ne_bytes(chunk.try_into().expect("chunk has 8 bytes"));
+        if needs_escape(word) {
+            break;
+        }
+        offset += 8;
     }
+    bytes[offset..]
+        .iter()
+        .position(|&byte| ESCAPE[byte as usize] != 0)
+        .map(|index| offset + index)
+}
 
-    buf.push(b'"');
+/// Returns `true` if any byte of `word` is a control character, `"`, or `\`.
+#[inline]
+const fn needs_escape(word: u64) -> bool {
+    // See "Determine if a word has a byte less than n" from Bit Twiddling Hacks
+    const fn has_byte_less_than(word: u64, n: u8) -> bool {
+        word.wrapping_sub(LOW_BITS * n as u64) & !word & HIGH_BITS != 0
+    }
+    has_byte_less_than(word, 0x20)
+        || has_byte_less_than(word ^ (LOW_BITS * b'"' as u64), 1)
+        || has_byte_less_than(word ^ (LOW_BITS * b'\\' as u64), 1)
 }
 
 const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
@@ -471,6 +687,169 @@ fn write_json_double(buf: &mut Vec<u8>, value: f64) {
     }
 }
 
+/// A buffered object member used to sort output in canonical mode.
+struct CanonicalEntry {
+    key: String,
+    value: Vec<u8>,
+}
+
+/// Write buffered object members sorted by the UTF-16 code units of their keys,
+/// as required by RFC 8785 section 3.2.3.
+fn write_canonical_entries(buf: &mut Vec<u8>, mut entries: Vec<CanonicalEntry>) {
+    entries.sort_by(|a, b| a.key.encode_utf16().cmp(b.key.encode_utf16()));
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 {
+            write_json_comma(buf);
+        }
+        write_json_string(buf, &entry.key);
+        write_json_colon(buf);
+        buf.extend_from_slice(&entry.value);
+    }
+}
+
+/// Recover the raw string for a map key serialized by [`write_json_string`].
+///
+/// Keys are needed unescaped so that they can be sorted by code unit.
+fn unescape_canonical_key(bytes: &[u8]) -> Result<String, JsonSerdeError> {
+    let inner = bytes
+        .strip_prefix(b"\"")
+        .and_then(|b| b.strip_suffix(b"\""))
+        .ok_or_else(|| {
+            JsonSerdeError::SerializationError("Canonical map keys must be strings".to_string())
+        })?;
+    let mut out = Vec::with_capacity(inner.len());
+    let mut iter = inner.iter();
+    while let Some(&byte) = iter.next() {
+        if byte != b'\\' {
+            out.push(byte);
+            continue;
+        }
+        match iter.next() {
+            Some(b'"') => out.push(b'"'),
+            Some(b'\\') => out.push(b'\\'),
+            Some(b'n') => out.push(b'\n'),
+            Some(b'r') => out.push(b'\r'),
+            Some(b't') => out.push(b'\t'),
+            Some(b'b') => out.push(0x08),
+            Some(b'f') => out.push(0x0C),
+            Some(b'u') => {
+                // Only control characters are written as `\u00XX`
+                let hex: Vec<u8> = iter.by_ref().take(4).copied().collect();
+                let code = std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|h| u8::from_str_radix(h, 16).ok())
+                    .ok_or_else(|| {
+                        JsonSerdeError::SerializationError("Invalid escape in map key".to_string())
+                    })?;
+                out.push(code);
+            }
+            _ => {
+                return Err(JsonSerdeError::SerializationError(
+                    "Invalid escape in map key".to_string(),
+                ));
+            }
+        }
+    }
+    String::from_utf8(out)
+        .map_err(|e| JsonSerdeError::SerializationError(format!("Invalid map key: {e}")))
+}
+
+/// Write a floating point number using the ECMAScript `Number.prototype.toString`
+/// algorithm required by RFC 8785 section 3.2.2.3.
+fn write_canonical_number<F: ryu::Float + CanonicalFloat>(
+    buf: &mut Vec<u8>,
+    value: F,
+) -> Result<(), JsonSerdeError> {
+    if !value.is_finite() {
+        return Err(JsonSerdeError::SerializationError(
+            "Non-finite numbers cannot be represented in canonical JSON".to_string(),
+        ));
+    }
+    if value.is_zero() {
+        // Negative zero is also written as `0`
+        buf.push(b'0');
+        return Ok(());
+    }
+
+    let mut ryu_buf = ryu::Buffer::new();
+    let shortest = ryu_buf.format_finite(value);
+    let (negative, shortest) = shortest
+        .strip_prefix('-')
+        .map_or((false, shortest), |rest| (true, rest));
+    let (mantissa, exponent) = match shortest.split_once('e') {
+        Some((m, e)) => (m, e.parse::<i32>().expect("ryu writes valid exponents")),
+        None => (shortest, 0),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    // Decompose into significant digits `d` and exponent `n` such that the
+    // value is `0.d * 10^n`
+    let all_digits = format!("{int_part}{frac_part}");
+    let leading_trimmed = all_digits.trim_start_matches('0');
+    let digits = leading_trimmed.trim_end_matches('0');
+    let trailing_zeros = (leading_trimmed.len() - digits.len()) as i32;
+    let k = digits.len() as i32;
+    let n = exponent - frac_part.len() as i32 + trailing_zeros + k;
+
+    if negative {
+        buf.push(b'-');
+    }
+    if k <= n && n <= 21 {
+        buf.extend_from_slice(digits.as_bytes());
+        buf.resize(buf.len() + (n - k) as usize, b'0');
+    } else if 0 < n && n <= 21 {
+        let (whole, fraction) = digits.split_at(n as usize);
+        buf.extend_from_slice(whole.as_bytes());
+        buf.push(b'.');
+        buf.extend_from_slice(fraction.as_bytes());
+    } else if -6 < n && n <= 0 {
+        buf.extend_from_slice(b"0.");
+        buf.resize(buf.len() + (-n) as usize, b'0');
+        buf.extend_from_slice(digits.as_bytes());
+    } else {
+        let (first, rest) = digits.split_at(1);
+        buf.extend_from_slice(first.as_bytes());
+        if !rest.is_empty() {
+            buf.push(b'.');
+            buf.extend_from_slice(rest.as_bytes());
+        }
+        buf.push(b'e');
+        buf.push(if n - 1 < 0 { b'-' } else { b'+' });
+        write_json_integer(buf, (n - 1).unsigned_abs());
+    }
+    Ok(())
+}
+
+/// Floating point operations needed to write canonical numbers.
+trait CanonicalFloat: Copy {
+    fn is_finite(self) -> bool;
+    fn is_zero(self) -> bool;
+}
+
+impl CanonicalFloat for f32 {
+    #[inline]
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+
+    #[inline]
+    fn is_zero(self) -> bool {
+        self == 0.0
+    }
+}
+
+impl CanonicalFloat for f64 {
+    #[inline]
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+
+    #[inline]
+    fn is_zero(self) -> bool {
+        self == 0.0
+    }
+}
+
 /// Helper struct for writing Display types to Vec<u8>.
 struct StringWriter<'a>(&'a mut Vec<u8>);
 
@@ -485,4 +864,54 @@ impl std::fmt::Write for StringWriter<'_> {
 #[cfg(test)]
 mod tests {
     // TODO(test): Add comprehensive suite here
+    use super::*;
+
+    fn escaped(value: &str) -> String {
+        let mut buf = Vec::new();
+        write_json_string(&mut buf, value);
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn writes_strings_without_escapes() {
+        assert_eq!(escaped(""), r#""""#);
+        assert_eq!(escaped("plain ascii text"), r#""plain ascii text""#);
+        assert_eq!(escaped("héllo wörld ✓"), r#""héllo wörld ✓""#);
+    }
+
+    #[test]
+    fn writes_escape_sequences() {
+        assert_eq!(
+            escaped("\"\\\n\r\t\u{8}\u{c}\u{0}\u{1f}\u{7f}"),
+            "\"\\\"\\\\\\n\\r\\t\\b\\f\\u0000\\u001f\u{7f}\""
+        );
+    }
+
+    #[test]
+    fn matches_serde_json_at_every_position() {
+        // Place each character at every offset across several 8-byte words
+        for special in ['"', '\\', '\n', '\u{1}', 'é'] {
+            for len in 0..24 {
+                for position in 0..=len {
+                    let mut value = "a".repeat(len);
+                    value.insert(position, special);
+                    assert_eq!(escaped(&value), serde_json::to_string(&value).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn detects_escapes_in_words() {
+        assert!(!needs_escape(u64::from_ne_bytes(*b"abcdefgh")));
+        assert!(!needs_escape(u64::from_ne_bytes([
+            0xC3, 0xA9, b' ', 0x7F, b'~', b'!', b'#', b'['
+        ])));
+        assert!(needs_escape(u64::from_ne_bytes(*b"abc\"efgh")));
+        assert!(needs_escape(u64::from_ne_bytes(*b"abcdefg\\")));
+        assert!(needs_escape(u64::from_ne_bytes(*b"\nbcdefgh")));
+        assert!(needs_escape(u64::from_ne_bytes([
+            0xFF, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF
+        ])));
+    }
 }
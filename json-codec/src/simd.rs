@@ -0,0 +1,704 @@
+//! SIMD-accelerated JSON deserialization.
+//!
+//! [`SimdJsonDeserializer`] scans its whole input into a [simd-json] tape up front
+//! and then walks that tape with the same schema-guided readers as
+//! [`JsonDeserializer`](crate::JsonDeserializer). Scanning with SIMD instructions
+//! is considerably faster than the incremental parser for large payloads, at the
+//! cost of a few restrictions:
+//!
+//! - The input buffer is mutable, as strings are unescaped in place.
+//! - The entire input must be valid UTF-8, so [`InvalidUtf8::Replace`](smithy4rs_core::serde::strings::InvalidUtf8::Replace)
+//!   has no effect.
+//! - Numbers are parsed into 64-bit values while scanning. Integers outside the
+//!   range of `i64`/`u64` are rejected, and `bigDecimal` values are limited to the
+//!   precision of an `f64` unless they are sent as strings.
+//!
+//! [simd-json]: https://docs.rs/simd-json
+
+use std::borrow::Cow;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use simd_json::{
+    StaticNode,
+    prelude::{TypedScalarValue, ValueAsScalar},
+    tape::Node,
+};
+use smithy4rs_core::{
+    BigDecimal, BigInt, IndexMap, Instant,
+    prelude::TimestampFormatTrait,
+    schema::{Blob, Document, NULL, Schema, Timestamp},
+    serde::{
+        deadline::Deadline,
+        deserializers::{
+            DeserializeWithSchema, Deserializer, ListReader, MapReader, MemberKey, StructReader,
+            UnknownEnumPolicy,
+        },
+        pool::{BufferPool, PooledBuffer},
+        strict::UnknownMembers,
+        strings::StringPolicy,
+    },
+};
+
+use crate::{
+    deserialization::parse_number_document, documents::JsonString, errors::JsonSerdeError,
+};
+
+/// A JSON deserializer backed by a [simd-json](https://docs.rs/simd-json) tape.
+///
+/// See the [module documentation](self) for how it differs from [`JsonDeserializer`](crate::JsonDeserializer).
+pub struct SimdJsonDeserializer<'de> {
+    nodes: Vec<Node<'de>>,
+    index: usize,
+    strings: StringPolicy,
+    pool: Option<BufferPool>,
+    deadline: Deadline,
+    strict_members: bool,
+    unknown_enums: UnknownEnumPolicy,
+}
+
+impl<'de> SimdJsonDeserializer<'de> {
+    /// Scan a JSON document from a mutable byte slice.
+    ///
+    /// The contents of `data` are overwritten while scanning.
+    ///
+    /// # Errors
+    /// Returns [`JsonSerdeError::DeserializationError`] if `data` is not a single valid JSON value.
+    pub fn new(data: &'de mut [u8]) -> Result<Self, JsonSerdeError> {
+        let tape = simd_json::to_tape(data).map_err(|e| {
+            JsonSerdeError::DeserializationError(format!("Failed to scan JSON: {}", e))
+        })?;
+        Ok(Self {
+            nodes: tape.0,
+            index: 0,
+            strings: StringPolicy::default(),
+            pool: None,
+            deadline: Deadline::none(),
+            strict_members: false,
+            unknown_enums: UnknownEnumPolicy::MapToUnknown,
+        })
+    }
+
+    /// Set the [`StringPolicy`] used to validate string values.
+    ///
+    /// Map keys are also checked for NUL characters.
+    #[must_use]
+    pub fn with_string_policy(mut self, policy: StringPolicy) -> Self {
+        self.strings = policy;
+        self
+    }
+
+    /// Draw blob buffers from a [`BufferPool`].
+    #[must_use]
+    pub fn with_buffer_pool(mut self, pool: BufferPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Abort deserialization once `deadline` expires.
+    ///
+    /// The deadline is checked before each struct member, list element, and map
+    /// entry (including those of documents) is read.
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Deadline) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Reject unknown members of all structures and unions.
+    ///
+    /// See [`JsonDeserializer::with_strict_members`](crate::JsonDeserializer::with_strict_members).
+    #[must_use]
+    pub fn with_strict_members(mut self, strict: bool) -> Self {
+        self.strict_members = strict;
+        self
+    }
+
+    /// Set the [`UnknownEnumPolicy`] for enum values that do not match a known variant.
+    #[must_use]
+    pub fn with_unknown_enum_policy(mut self, policy: UnknownEnumPolicy) -> Self {
+        self.unknown_enums = policy;
+        self
+    }
+
+    #[inline]
+    fn check_deadline(&mut self) -> Result<(), JsonSerdeError> {
+        Ok(self.deadline.check()?)
+    }
+
+    fn peek(&self) -> Option<&Node<'de>> {
+        self.nodes.get(self.index)
+    }
+
+    /// Take the next node of the tape.
+    fn next(&mut self, kind: &str) -> Result<Node<'de>, JsonSerdeError> {
+        let node = self.peek().copied().ok_or_else(|| {
+            JsonSerdeError::DeserializationError(format!(
+                "Failed to read {kind}: unexpected end of input"
+            ))
+        })?;
+        self.index += 1;
+        Ok(node)
+    }
+
+    /// Skip the next value, including all nested values.
+    fn skip(&mut self) -> Result<(), JsonSerdeError> {
+        match self.next("value")? {
+            Node::Array { count, .. } | Node::Object { count, .. } => self.index += count,
+            Node::String(_) | Node::Static(_) => {}
+        }
+        Ok(())
+    }
+
+    fn next_static(&mut self, kind: &str) -> Result<StaticNode, JsonSerdeError> {
+        match self.next(kind)? {
+            Node::Static(node) => Ok(node),
+            node => Err(unexpected(kind, &node)),
+        }
+    }
+
+    fn next_int(&mut self, kind: &str) -> Result<i64, JsonSerdeError> {
+        let node = self.next_static(kind)?;
+        node.as_i64().ok_or_else(|| {
+            JsonSerdeError::DeserializationError(format!(
+                "Failed to read {kind}: expected an integer, found {node}"
+            ))
+        })
+    }
+
+    fn next_float(&mut self, kind: &str) -> Result<f64, JsonSerdeError> {
+        let node = self.next_static(kind)?;
+        node.cast_f64().ok_or_else(|| {
+            JsonSerdeError::DeserializationError(format!(
+                "Failed to read {kind}: expected a number, found {node}"
+            ))
+        })
+    }
+
+    /// Read the text of the next number (or string) value.
+    fn next_number_text(&mut self, kind: &str) -> Result<Cow<'de, str>, JsonSerdeError> {
+        match self.next(kind)? {
+            Node::String(value) => Ok(Cow::Borrowed(value)),
+            Node::Static(node) if node.as_bool().is_none() && !node.is_null() => {
+                Ok(Cow::Owned(node.to_string()))
+            }
+            node => Err(unexpected(kind, &node)),
+        }
+    }
+
+    /// Read the next string value, applying the configured [`StringPolicy`].
+    fn next_string(&mut self, kind: &str) -> Result<&'de str, JsonSerdeError> {
+        match self.next(kind)? {
+            Node::String(value) => {
+                self.strings.check(value).map_err(|e| {
+                    JsonSerdeError::DeserializationError(format!("Invalid string: {}", e))
+                })?;
+                Ok(value)
+            }
+            node => Err(unexpected(kind, &node)),
+        }
+    }
+
+    /// Read the next object key, checking it against the configured [`StringPolicy`].
+    fn next_key(&mut self) -> Result<&'de str, JsonSerdeError> {
+        match self.next("object key")? {
+            Node::String(key) => {
+                self.strings.check(key).map_err(|e| {
+                    JsonSerdeError::DeserializationError(format!("Invalid map key: {}", e))
+                })?;
+                Ok(key)
+            }
+            node => Err(unexpected("object key", &node)),
+        }
+    }
+
+    /// Start reading an object, returning its number of entries.
+    fn next_object(&mut self) -> Result<usize, JsonSerdeError> {
+        match self.next("object")? {
+            Node::Object { len, .. } => Ok(len),
+            node => Err(unexpected("object", &node)),
+        }
+    }
+
+    /// Start reading an array, returning its number of elements.
+    fn next_array(&mut self) -> Result<usize, JsonSerdeError> {
+        match self.next("array")? {
+            Node::Array { len, .. } => Ok(len),
+            node => Err(unexpected("array", &node)),
+        }
+    }
+
+    /// Read the next JSON value as an untyped [`Document`].
+    fn next_document(&mut self) -> Result<Box<dyn Document>, JsonSerdeError> {
+        match self.next("document")? {
+            Node::String(value) => {
+                self.strings.check(value).map_err(|e| {
+                    JsonSerdeError::DeserializationError(format!("Invalid string: {}", e))
+                })?;
+                Ok(Box::new(JsonString::new(value.to_string())))
+            }
+            Node::Array { len, .. } => {
+                let mut elements: Vec<Box<dyn Document>> = Vec::with_capacity(len);
+                for _ in 0..len {
+                    self.check_deadline()?;
+                    elements.push(self.next_document()?);
+                }
+                Ok(elements.into())
+            }
+            Node::Object { len, .. } => {
+                let mut entries: IndexMap<String, Box<dyn Document>> = IndexMap::with_capacity(len);
+                for _ in 0..len {
+                    let key = self.next_key()?.to_string();
+                    self.check_deadline()?;
+                    let value = self.next_document()?;
+                    entries.insert(key, value);
+                }
+                Ok(entries.into())
+            }
+            Node::Static(node) => {
+                if node.is_null() {
+                    Ok(NULL.clone())
+                } else if let Some(value) = node.as_bool() {
+                    Ok(value.into())
+                } else if let Some(value) = node.as_f64() {
+                    Ok(value.into())
+                } else {
+                    parse_number_document(&node.to_string())
+                }
+            }
+        }
+    }
+}
+
+fn unexpected(kind: &str, node: &Node<'_>) -> JsonSerdeError {
+    JsonSerdeError::DeserializationError(format!(
+        "Failed to read {kind}: unexpected {:?}",
+        node.value_type()
+    ))
+}
+
+fn out_of_range<T>(kind: &str) -> impl FnOnce(T) -> JsonSerdeError {
+    move |_| JsonSerdeError::DeserializationError(format!("Value out of range for {kind}"))
+}
+
+/// Reader for JSON struct members.
+pub struct SimdStructReader<'de, 'a> {
+    de: &'a mut SimdJsonDeserializer<'de>,
+    remaining: Option<usize>,
+    unknown: UnknownMembers,
+}
+
+/// Reader for JSON list elements.
+pub struct SimdListReader<'de, 'a> {
+    de: &'a mut SimdJsonDeserializer<'de>,
+    remaining: Option<usize>,
+}
+
+/// Reader for JSON map entries.
+pub struct SimdMapReader<'de, 'a> {
+    de: &'a mut SimdJsonDeserializer<'de>,
+    remaining: Option<usize>,
+}
+
+/// Take one entry from the `remaining` count of an object, starting the object if needed.
+///
+/// Returns `false` once all entries are read.
+fn next_entry(
+    de: &mut SimdJsonDeserializer<'_>,
+    remaining: &mut Option<usize>,
+) -> Result<bool, JsonSerdeError> {
+    de.check_deadline()?;
+    let left = match remaining {
+        Some(left) => left,
+        None => remaining.insert(de.next_object()?),
+    };
+    if *left == 0 {
+        return Ok(false);
+    }
+    *left -= 1;
+    Ok(true)
+}
+
+// ============================================================================
+// Deserializer Implementation
+// ============================================================================
+
+impl<'de, 'a> Deserializer<'de> for &'a mut SimdJsonDeserializer<'de> {
+    type Error = JsonSerdeError;
+    type StructReader = SimdStructReader<'de, 'a>;
+    type ListReader = SimdListReader<'de, 'a>;
+    type MapReader = SimdMapReader<'de, 'a>;
+
+    #[inline]
+    fn unknown_enum_policy(&self) -> UnknownEnumPolicy {
+        self.unknown_enums
+    }
+
+    fn read_bool(self, _schema: &Schema) -> Result<bool, Self::Error> {
+        let node = self.next_static("bool")?;
+        node.as_bool().ok_or_else(|| {
+            JsonSerdeError::DeserializationError(format!("Failed to read bool: found {node}"))
+        })
+    }
+
+    fn read_byte(self, _schema: &Schema) -> Result<i8, Self::Error> {
+        i8::try_from(self.next_int("byte")?).map_err(out_of_range("i8"))
+    }
+
+    fn read_short(self, _schema: &Schema) -> Result<i16, Self::Error> {
+        i16::try_from(self.next_int("short")?).map_err(out_of_range("i16"))
+    }
+
+    fn read_integer(self, _schema: &Schema) -> Result<i32, Self::Error> {
+        i32::try_from(self.next_int("integer")?).map_err(out_of_range("i32"))
+    }
+
+    fn read_long(self, _schema: &Schema) -> Result<i64, Self::Error> {
+        self.next_int("long")
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn read_float(self, _schema: &Schema) -> Result<f32, Self::Error> {
+        self.next_float("float").map(|v| v as f32)
+    }
+
+    fn read_double(self, _schema: &Schema) -> Result<f64, Self::Error> {
+        self.next_float("double")
+    }
+
+    fn read_big_integer(self, _schema: &Schema) -> Result<BigInt, Self::Error> {
+        self.next_number_text("big integer")?
+            .parse::<BigInt>()
+            .map_err(|e| {
+                JsonSerdeError::DeserializationError(format!("Failed to parse big integer: {}", e))
+            })
+    }
+
+    fn read_big_decimal(self, _schema: &Schema) -> Result<BigDecimal, Self::Error> {
+        self.next_number_text("big decimal")?
+            .parse::<BigDecimal>()
+            .map_err(|e| {
+                JsonSerdeError::DeserializationError(format!("Failed to parse big decimal: {}", e))
+            })
+    }
+
+    fn read_string(self, _schema: &Schema) -> Result<String, Self::Error> {
+        self.next_string("string").map(str::to_string)
+    }
+
+    fn read_str(self, _schema: &Schema) -> Result<Cow<'de, str>, Self::Error> {
+        self.next_string("string").map(Cow::Borrowed)
+    }
+
+    fn read_blob(self, _schema: &Schema) -> Result<Blob, Self::Error> {
+        let Node::String(s) = self.next("blob string")? else {
+            return Err(JsonSerdeError::DeserializationError(
+                "Failed to read blob string: expected a string".to_string(),
+            ));
+        };
+
+        // JSON protocols write blobs as base64 encoded strings
+        let capacity = base64::decoded_len_estimate(s.len());
+        let mut buffer = PooledBuffer::take(self.pool.as_ref(), capacity);
+        STANDARD.decode_vec(s, &mut buffer).map_err(|e| {
+            JsonSerdeError::DeserializationError(format!("Invalid base64 blob: {}", e))
+        })?;
+        Ok(Blob::from(buffer.into_inner()))
+    }
+
+    fn read_timestamp(self, schema: &Schema) -> Result<Instant, Self::Error> {
+        // JSON protocols default to `epoch-seconds` if no format is specified
+        let format = schema
+            .get_trait_as::<TimestampFormatTrait>()
+            .unwrap_or(&TimestampFormatTrait::EpochSeconds);
+        let value = match (format, self.next("timestamp")?) {
+            (TimestampFormatTrait::EpochSeconds, Node::Static(node))
+                if node.as_bool().is_none() && !node.is_null() =>
+            {
+                Cow::Owned(node.to_string())
+            }
+            (TimestampFormatTrait::EpochSeconds, node) => {
+                return Err(unexpected("timestamp number", &node));
+            }
+            (_, Node::String(value)) => Cow::Borrowed(value),
+            (_, node) => return Err(unexpected("timestamp string", &node)),
+        };
+        Timestamp::parse(&value, format)
+            .map(Instant::from)
+            .map_err(|e| {
+                JsonSerdeError::DeserializationError(format!("Failed to parse timestamp: {}", e))
+            })
+    }
+
+    fn read_document(self, _schema: &Schema) -> Result<Box<dyn Document>, Self::Error> {
+        self.next_document()
+    }
+
+    fn read_null(self) -> Result<(), Self::Error> {
+        match self.next_static("null")? {
+            node if node.is_null() => Ok(()),
+            node => Err(JsonSerdeError::DeserializationError(format!(
+                "Expected null: found {node}"
+            ))),
+        }
+    }
+
+    fn read_struct(self, schema: &Schema) -> Result<Self::StructReader, Self::Error> {
+        let unknown = UnknownMembers::new(schema, self.strict_members);
+        Ok(SimdStructReader {
+            de: self,
+            remaining: None,
+            unknown,
+        })
+    }
+
+    fn read_list(self, _schema: &Schema) -> Result<Self::ListReader, Self::Error> {
+        Ok(SimdListReader {
+            de: self,
+            remaining: None,
+        })
+    }
+
+    fn read_map(self, _schema: &Schema) -> Result<Self::MapReader, Self::Error> {
+        Ok(SimdMapReader {
+            de: self,
+            remaining: None,
+        })
+    }
+
+    fn is_null(&mut self) -> bool {
+        matches!(self.peek(), Some(Node::Static(node)) if node.is_null())
+    }
+}
+
+// ============================================================================
+// StructReader Implementation
+// ============================================================================
+
+impl<'de> SimdStructReader<'de, '_> {
+    /// Read the next object key, or `None` if the object is exhausted.
+    fn next_key(&mut self) -> Result<Option<&'de str>, JsonSerdeError> {
+        if !next_entry(self.de, &mut self.remaining)? {
+            return Ok(None);
+        }
+        match self.de.next("object key")? {
+            Node::String(key) => Ok(Some(key)),
+            node => Err(unexpected("object key", &node)),
+        }
+    }
+}
+
+impl<'de> StructReader<'de> for SimdStructReader<'de, '_> {
+    type Error = JsonSerdeError;
+
+    fn read_member<'a>(&mut self, schema: &'a Schema) -> Result<Option<&'a Schema>, Self::Error> {
+        loop {
+            match self.next_key()? {
+                Some(key) => {
+                    if let Some(member_schema) = schema.get_member_or_alias(key) {
+                        return Ok(Some(member_schema));
+                    }
+                    // Unknown key — skip the value
+                    self.unknown.insert(key);
+                    self.de.skip()?;
+                }
+                None => {
+                    self.unknown.finish::<Self::Error>(schema)?;
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    fn read_member_or_unknown<'a>(
+        &mut self,
+        schema: &'a Schema,
+    ) -> Result<Option<MemberKey<'a>>, Self::Error> {
+        // Unknown members are rejected rather than retained in strict mode
+        if self.unknown.is_strict() {
+            return Ok(self.read_member(schema)?.map(MemberKey::Known));
+        }
+        Ok(self.next_key()?.map(|key| {
+            schema
+                .get_member_or_alias(key)
+                .map_or_else(|| MemberKey::Unknown(key.to_string()), MemberKey::Known)
+        }))
+    }
+
+    fn read_value<T: DeserializeWithSchema<'de>>(
+        &mut self,
+        schema: &Schema,
+    ) -> Result<T, Self::Error> {
+        T::deserialize_with_schema(schema, &mut *self.de)
+    }
+
+    fn skip_value(&mut self) -> Result<(), Self::Error> {
+        self.de.skip()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining
+    }
+}
+
+// ============================================================================
+// ListReader Implementation
+// ============================================================================
+
+impl<'de> ListReader<'de> for SimdListReader<'de, '_> {
+    type Error = JsonSerdeError;
+
+    fn read_element<T: DeserializeWithSchema<'de>>(
+        &mut self,
+        schema: &Schema,
+    ) -> Result<Option<T>, Self::Error> {
+        self.de.check_deadline()?;
+        let left = match &mut self.remaining {
+            Some(left) => left,
+            None => self.remaining.insert(self.de.next_array()?),
+        };
+        if *left == 0 {
+            return Ok(None);
+        }
+        *left -= 1;
+        T::deserialize_with_schema(schema, &mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining
+    }
+}
+
+// ============================================================================
+// MapReader Implementation
+// ============================================================================
+
+impl<'de> MapReader<'de> for SimdMapReader<'de, '_> {
+    type Error = JsonSerdeError;
+
+    fn read_key(&mut self) -> Result<Option<String>, Self::Error> {
+        if !next_entry(self.de, &mut self.remaining)? {
+            return Ok(None);
+        }
+        self.de.next_key().map(|key| Some(key.to_string()))
+    }
+
+    fn read_value<V: DeserializeWithSchema<'de>>(
+        &mut self,
+        schema: &Schema,
+    ) -> Result<V, Self::Error> {
+        V::deserialize_with_schema(schema, &mut *self.de)
+    }
+
+    fn skip_value(&mut self) -> Result<(), Self::Error> {
+        self.de.skip()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smithy4rs_core::prelude::*;
+
+    use super::*;
+
+    fn deserializer(data: &mut [u8]) -> SimdJsonDeserializer<'_> {
+        SimdJsonDeserializer::new(data).unwrap()
+    }
+
+    #[test]
+    fn test_read_primitives() {
+        assert!(
+            (&mut deserializer(&mut b"true".to_vec()))
+                .read_bool(&BOOLEAN)
+                .unwrap()
+        );
+        assert_eq!(
+            (&mut deserializer(&mut b"42".to_vec()))
+                .read_integer(&INTEGER)
+                .unwrap(),
+            42
+        );
+        assert!(
+            ((&mut deserializer(&mut b"1.234".to_vec()))
+                .read_float(&FLOAT)
+                .unwrap()
+                - 1.234)
+                .abs()
+                < 0.001
+        );
+        assert_eq!(
+            (&mut deserializer(&mut br#""he\"llo""#.to_vec()))
+                .read_string(&STRING)
+                .unwrap(),
+            "he\"llo"
+        );
+        assert!(
+            (&mut deserializer(&mut b"300".to_vec()))
+                .read_byte(&BYTE)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_is_null() {
+        assert!((&mut deserializer(&mut b"null".to_vec())).is_null());
+        assert!(!(&mut deserializer(&mut b"42".to_vec())).is_null());
+    }
+
+    #[test]
+    fn test_rejects_invalid_json() {
+        assert!(SimdJsonDeserializer::new(&mut b"{\"a\": ".to_vec()).is_err());
+        assert!(SimdJsonDeserializer::new(&mut b"[1, 2] 3".to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_big_numbers() {
+        let mut data = br#"[18446744073709551615, "123456789012345678901234567890", 1.5]"#.to_vec();
+        let mut de = deserializer(&mut data);
+        let mut list = (&mut de).read_list(&DOCUMENT).unwrap();
+        assert_eq!(
+            list.read_element::<BigInt>(&BIG_INTEGER)
+                .unwrap()
+                .unwrap()
+                .to_string(),
+            "18446744073709551615"
+        );
+        assert_eq!(
+            list.read_element::<BigInt>(&BIG_INTEGER)
+                .unwrap()
+                .unwrap()
+                .to_string(),
+            "123456789012345678901234567890"
+        );
+        assert_eq!(list.size_hint(), Some(1));
+        assert_eq!(
+            list.read_element::<BigDecimal>(&BIG_DECIMAL)
+                .unwrap()
+                .unwrap()
+                .to_string(),
+            "1.5"
+        );
+        assert!(
+            list.read_element::<BigDecimal>(&BIG_DECIMAL)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_skips_nested_values() {
+        let mut data = br#"{"a": {"b": [1, {"c": 2}], "d": "e"}, "f": [[]], "g": 3}"#.to_vec();
+        let mut de = deserializer(&mut data);
+        let mut map = (&mut de).read_map(&DOCUMENT).unwrap();
+        assert_eq!(map.read_key().unwrap().as_deref(), Some("a"));
+        map.skip_value().unwrap();
+        assert_eq!(map.read_key().unwrap().as_deref(), Some("f"));
+        map.skip_value().unwrap();
+        assert_eq!(map.read_key().unwrap().as_deref(), Some("g"));
+        assert_eq!(map.read_value::<i64>(&LONG).unwrap(), 3);
+        assert!(map.read_key().unwrap().is_none());
+    }
+}
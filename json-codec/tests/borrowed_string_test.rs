@@ -0,0 +1,47 @@
+use std::borrow::Cow;
+
+use smithy4rs_core::{schema::prelude::STRING, serde::de::DeserializeWithSchema};
+use smithy4rs_json_codec::JsonDeserializer;
+use smithy4rs_test_utils::*;
+
+#[test]
+fn test_borrows_unescaped_string() {
+    let json = br#"  "hello world""#;
+    let mut de = JsonDeserializer::new(json);
+    let result = Cow::<str>::deserialize_with_schema(&STRING, &mut de).unwrap();
+    assert!(matches!(result, Cow::Borrowed("hello world")));
+}
+
+#[test]
+fn test_copies_escaped_string() {
+    let json = br#""line\nbreak \"quoted\"""#;
+    let mut de = JsonDeserializer::new(json);
+    let result = Cow::<str>::deserialize_with_schema(&STRING, &mut de).unwrap();
+    assert!(matches!(result, Cow::Owned(ref s) if s == "line\nbreak \"quoted\""));
+}
+
+#[test]
+fn test_borrows_unicode_string() {
+    let json = "\"héllo wörld\"".as_bytes();
+    let mut de = JsonDeserializer::new(json);
+    let result = Cow::<str>::deserialize_with_schema(&STRING, &mut de).unwrap();
+    assert!(matches!(result, Cow::Borrowed("héllo wörld")));
+}
+
+#[test]
+fn test_borrows_list_elements() {
+    let json = br#"["a", "bc", "d"]"#;
+    let mut de = JsonDeserializer::new(json);
+    let result =
+        Vec::<Cow<'_, str>>::deserialize_with_schema(&STRING_LIST_SCHEMA, &mut de).unwrap();
+    assert!(matches!(result[0], Cow::Borrowed("a")));
+    assert!(matches!(result[1], Cow::Borrowed("bc")));
+    assert!(matches!(result[2], Cow::Borrowed("d")));
+}
+
+#[test]
+fn test_rejects_non_string() {
+    let json = b"42";
+    let mut de = JsonDeserializer::new(json);
+    assert!(Cow::<str>::deserialize_with_schema(&STRING, &mut de).is_err());
+}
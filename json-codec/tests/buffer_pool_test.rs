@@ -0,0 +1,100 @@
+use smithy4rs_core::{
+    schema::{
+        Blob, Document,
+        prelude::{BLOB, STRING},
+    },
+    serde::{Codec, de::DeserializeWithSchema, pool::BufferPool, se::SerializeWithSchema},
+};
+use smithy4rs_json_codec::{JsonCodec, JsonDeserializer, JsonSerializer};
+
+#[test]
+fn test_blob_reuses_recycled_buffer() {
+    let pool = BufferPool::new();
+    let recycled = pool.take(64);
+    let ptr = recycled.as_ptr();
+    pool.give(recycled);
+
+    let mut de = JsonDeserializer::new(br#""YmxvYiBkYXRh""#).with_buffer_pool(pool.clone());
+    let blob = Blob::deserialize_with_schema(&BLOB, &mut de).unwrap();
+    assert_eq!(blob.as_bytes(), b"blob data");
+    assert_eq!(blob.as_bytes().as_ptr(), ptr);
+    assert!(pool.is_empty());
+
+    pool.recycle(blob);
+    assert_eq!(pool.len(), 1);
+}
+
+#[test]
+fn test_escaped_string_returns_scratch_buffer() {
+    let pool = BufferPool::new();
+    let mut de = JsonDeserializer::new(br#""a\nb""#).with_buffer_pool(pool.clone());
+    let result = String::deserialize_with_schema(&STRING, &mut de).unwrap();
+    assert_eq!(result, "a\nb");
+    assert_eq!(pool.len(), 1);
+
+    let mut de = JsonDeserializer::new(br#""c\td""#).with_buffer_pool(pool.clone());
+    let result = String::deserialize_with_schema(&STRING, &mut de).unwrap();
+    assert_eq!(result, "c\td");
+    assert_eq!(pool.len(), 1);
+}
+
+#[test]
+fn test_invalid_blob_returns_buffer() {
+    let pool = BufferPool::new();
+    pool.give(Vec::with_capacity(64));
+
+    let mut de = JsonDeserializer::new(br#""not base64!""#).with_buffer_pool(pool.clone());
+    assert!(Blob::deserialize_with_schema(&BLOB, &mut de).is_err());
+    assert_eq!(pool.len(), 1);
+}
+
+#[test]
+fn test_unescaped_string_does_not_use_pool() {
+    let pool = BufferPool::new();
+    let mut de = JsonDeserializer::new(br#""plain""#).with_buffer_pool(pool.clone());
+    let result = String::deserialize_with_schema(&STRING, &mut de).unwrap();
+    assert_eq!(result, "plain");
+    assert!(pool.is_empty());
+}
+
+#[test]
+fn test_serializer_reuses_buffer() {
+    let mut buf = Vec::with_capacity(64);
+    let ptr = buf.as_ptr();
+    let mut serializer = JsonSerializer::new(&mut buf);
+    for value in ["first", "second"] {
+        serializer.reset();
+        value
+            .to_string()
+            .serialize_with_schema(&STRING, serializer.by_ref())
+            .unwrap();
+        assert_eq!(serializer.as_str(), format!("\"{value}\""));
+    }
+    assert_eq!(buf.as_ptr(), ptr);
+}
+
+#[test]
+fn test_serializer_appends_to_buffer() {
+    let mut buf = b"data: ".to_vec();
+    "value"
+        .to_string()
+        .serialize_with_schema(&STRING, JsonSerializer::with_buffer(&mut buf))
+        .unwrap();
+    assert_eq!(buf, br#"data: "value""#);
+}
+
+#[test]
+fn test_codec_serializes_into_pooled_buffer() {
+    let pool = BufferPool::new();
+    let document: Box<dyn Document> = "pooled".into();
+    let mut buf = pool.take(64);
+    buf.extend_from_slice(b"stale");
+    let ptr = buf.as_ptr();
+    JsonCodec
+        .serialize_document_into(&STRING, &document, &mut buf)
+        .unwrap();
+    assert_eq!(buf, br#""pooled""#);
+    assert_eq!(buf.as_ptr(), ptr);
+    pool.give(buf);
+    assert_eq!(pool.len(), 1);
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "parallel")]
+
+use smithy4rs_core::serde::CodecError;
+use smithy4rs_json_codec::deserialize_bulk;
+use smithy4rs_test_utils::*;
+
+#[test]
+fn test_bulk_records_in_order() {
+    let json = (0..1000)
+        .map(|i| format!(r#"{{"field_a": "record-{i}", "field_b": {i}}}"#))
+        .collect::<Vec<_>>()
+        .join(",\n");
+    let json = format!("[{json}]");
+
+    let batch = deserialize_bulk::<SimpleStruct, SimpleStructBuilder>(json.as_bytes()).unwrap();
+
+    assert!(batch.is_complete());
+    let records = batch.into_records();
+    assert_eq!(records.len(), 1000);
+    for (i, record) in records.iter().enumerate() {
+        assert_eq!(record.field_a, format!("record-{i}"));
+        assert_eq!(record.field_b, i as i32);
+    }
+}
+
+#[test]
+fn test_bulk_collects_record_errors() {
+    let json = br#"[
+        {"field_a": "first", "field_b": 1},
+        {"field_a": "wrong type", "field_b": "2"},
+        {"field_a": "second", "field_b": 3},
+        {"field_a": "missing field_b"},
+        {"field_a": "third", "field_b": 5}
+    ]"#;
+
+    let batch = deserialize_bulk::<SimpleStruct, SimpleStructBuilder>(json).unwrap();
+
+    assert!(!batch.is_complete());
+    let names: Vec<_> = batch.records().iter().map(|r| r.field_a.as_str()).collect();
+    assert_eq!(names, vec!["first", "second", "third"]);
+    let indices: Vec<_> = batch.errors().iter().map(|e| e.index()).collect();
+    assert_eq!(indices, vec![1, 3]);
+    assert!(matches!(
+        batch.errors()[1].error(),
+        CodecError::Validation(_)
+    ));
+}
+
+#[test]
+fn test_bulk_errors_include_record_offset() {
+    let json = br#"[{"field_a": "first", "field_b": 1}, {"field_a": "second", "field_b": tru}]"#;
+
+    let batch = deserialize_bulk::<SimpleStruct, SimpleStructBuilder>(json).unwrap();
+
+    let error = &batch.errors()[0];
+    assert_eq!(error.index(), 1);
+    assert_eq!(error.offset(), 37);
+    assert!(json[error.offset()..].starts_with(br#"{"field_a": "second""#));
+
+    // Positions in the record error are relative to the record
+    let message = error.error().to_string();
+    let position: usize = message.rsplit("at index ").next().unwrap().parse().unwrap();
+    assert!(json[error.offset() + position..].starts_with(b"tru"));
+    assert!(error.to_string().starts_with("Record 1 at offset 37: "));
+}
+
+#[test]
+fn test_bulk_empty_array() {
+    let batch = deserialize_bulk::<SimpleStruct, SimpleStructBuilder>(b" [ ] ").unwrap();
+    assert!(batch.is_complete());
+    assert!(batch.records().is_empty());
+}
+
+#[test]
+fn test_bulk_rejects_malformed_array() {
+    let result = deserialize_bulk::<SimpleStruct, SimpleStructBuilder>(
+        br#"[{"field_a": "a", "field_b": 1}"#,
+    );
+    assert!(matches!(result, Err(CodecError::Syntax { .. })));
+}
@@ -0,0 +1,142 @@
+use smithy4rs_core::{IndexMap, serde::serializers::SerializeWithSchema};
+use smithy4rs_json_codec::JsonSerializer;
+use smithy4rs_test_utils::*;
+
+fn canonical<T: SerializeWithSchema>(value: &T, schema: &smithy4rs_core::schema::Schema) -> String {
+    let mut buf = Vec::new();
+    value
+        .serialize_with_schema(schema, JsonSerializer::canonical(&mut buf))
+        .unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+fn numbers(float_val: f32, double_val: f64) -> NumericTypesStruct {
+    NumericTypesStruct {
+        byte_val: 1,
+        short_val: 2,
+        int_val: 3,
+        long_val: 4,
+        float_val,
+        double_val,
+    }
+}
+
+fn inner(value: &str) -> InnerStruct {
+    InnerStructBuilder::new()
+        .field_a(value.to_string())
+        .field_b(value.to_string())
+        .field_c(value.to_string())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_canonical_sorts_struct_members_and_map_keys() {
+    let mut map_nested = IndexMap::new();
+    map_nested.insert("zeta".to_string(), inner("z"));
+    map_nested.insert("alpha".to_string(), inner("a"));
+
+    let nested = NestedCollectionsStructBuilder::new()
+        .name("test".to_string())
+        .count(1)
+        .single_nested(inner("s"))
+        .list_nested(vec![inner("l2"), inner("l1")])
+        .map_nested(map_nested)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        canonical(&nested, &NESTED_COLLECTIONS_STRUCT_SCHEMA),
+        concat!(
+            r#"{"count":1,"#,
+            r#""list_nested":[{"field_a":"l2","field_b":"l2","field_c":"l2"},{"field_a":"l1","field_b":"l1","field_c":"l1"}],"#,
+            r#""map_nested":{"alpha":{"field_a":"a","field_b":"a","field_c":"a"},"zeta":{"field_a":"z","field_b":"z","field_c":"z"}},"#,
+            r#""name":"test","#,
+            r#""single_nested":{"field_a":"s","field_b":"s","field_c":"s"}}"#
+        )
+    );
+}
+
+#[test]
+fn test_canonical_number_formatting() {
+    let cases: &[(f64, &str)] = &[
+        (1.0, "1"),
+        (-0.0, "0"),
+        (0.5, "0.5"),
+        (123.456, "123.456"),
+        (1e21, "1e+21"),
+        (1e20, "100000000000000000000"),
+        (0.000001, "0.000001"),
+        (0.0000001, "1e-7"),
+        (-1.5e-10, "-1.5e-10"),
+        (4.5e300, "4.5e+300"),
+    ];
+    for (value, expected) in cases {
+        let json = canonical(&numbers(0.0, *value), &NUMERIC_TYPES_STRUCT_SCHEMA);
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"byte_val":1,"double_val":{expected},"float_val":0,"int_val":3,"long_val":4,"short_val":2}}"#
+            )
+        );
+    }
+}
+
+#[test]
+fn test_canonical_float_uses_shortest_representation() {
+    let json = canonical(&numbers(0.1, 2.0), &NUMERIC_TYPES_STRUCT_SCHEMA);
+    assert!(json.contains(r#""float_val":0.1"#), "{json}");
+    assert!(json.contains(r#""double_val":2"#), "{json}");
+}
+
+#[test]
+fn test_canonical_rejects_non_finite_numbers() {
+    let mut buf = Vec::new();
+    let result = numbers(0.0, f64::NAN).serialize_with_schema(
+        &NUMERIC_TYPES_STRUCT_SCHEMA,
+        JsonSerializer::canonical(&mut buf),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_canonical_string_escaping() {
+    let value = SimpleStruct {
+        field_a: "quote\" slash\\ \u{8}\u{c}\n\r\t \u{1f} é €".to_string(),
+        field_b: 0,
+    };
+    assert_eq!(
+        canonical(&value, &SIMPLE_STRUCT_SCHEMA),
+        "{\"field_a\":\"quote\\\" slash\\\\ \\b\\f\\n\\r\\t \\u001f é €\",\"field_b\":0}"
+    );
+}
+
+#[test]
+fn test_canonical_sorts_by_utf16_code_units() {
+    let mut map_nested = IndexMap::new();
+    // U+1F600 (surrogate pair 0xD83D...) sorts before U+FB33 in UTF-16 order,
+    // but after it when comparing code points.
+    map_nested.insert("\u{fb33}".to_string(), inner("b"));
+    map_nested.insert("\u{1f600}".to_string(), inner("a"));
+
+    let nested = NestedCollectionsStructBuilder::new()
+        .name("test".to_string())
+        .count(1)
+        .single_nested(inner("s"))
+        .list_nested(vec![])
+        .map_nested(map_nested)
+        .build()
+        .unwrap();
+
+    let json = canonical(&nested, &NESTED_COLLECTIONS_STRUCT_SCHEMA);
+    let emoji = json.find('\u{1f600}').unwrap();
+    let hebrew = json.find('\u{fb33}').unwrap();
+    assert!(emoji < hebrew, "{json}");
+}
+
+#[test]
+fn test_default_serializer_is_not_canonical() {
+    let mut buf = Vec::new();
+    assert!(!JsonSerializer::new(&mut buf).is_canonical());
+    assert!(JsonSerializer::canonical(&mut buf).is_canonical());
+}
@@ -0,0 +1,76 @@
+use std::time::{Duration, Instant};
+
+use smithy4rs_core::{
+    IndexMap,
+    schema::{Document, prelude::DOCUMENT},
+    serde::{
+        CodecError,
+        de::DeserializeWithSchema,
+        deadline::{CancellationToken, Deadline, DeadlineExceeded},
+    },
+};
+use smithy4rs_json_codec::{JsonDeserializer, JsonSerdeError};
+use smithy4rs_test_utils::*;
+
+fn large_list(len: usize) -> String {
+    let elements = (0..len).map(|i| i.to_string()).collect::<Vec<_>>();
+    format!("[{}]", elements.join(","))
+}
+
+#[test]
+fn test_deserializes_before_deadline() {
+    let json = large_list(1000);
+    let mut de = JsonDeserializer::new(json.as_bytes())
+        .with_deadline(Deadline::after(Duration::from_secs(3600)));
+    let values = Vec::<i32>::deserialize_with_schema(&INTEGER_LIST_SCHEMA, &mut de).unwrap();
+    assert_eq!(values.len(), 1000);
+}
+
+#[test]
+fn test_expired_deadline_aborts_list() {
+    let json = large_list(1000);
+    let mut de = JsonDeserializer::new(json.as_bytes()).with_deadline(Deadline::at(Instant::now()));
+    let Err(err) = Vec::<i32>::deserialize_with_schema(&INTEGER_LIST_SCHEMA, &mut de) else {
+        panic!("Expected deadline to be exceeded");
+    };
+    assert!(matches!(
+        err,
+        JsonSerdeError::DeadlineExceeded(DeadlineExceeded::TimedOut)
+    ));
+    assert!(matches!(
+        CodecError::from(err),
+        CodecError::DeadlineExceeded(DeadlineExceeded::TimedOut)
+    ));
+}
+
+#[test]
+fn test_expired_deadline_aborts_struct_and_map() {
+    let json = br#"{"field_a": "a", "field_b": 1}"#;
+    let mut de = JsonDeserializer::new(json).with_deadline(Deadline::at(Instant::now()));
+    assert!(matches!(
+        SimpleStructBuilder::deserialize_with_schema(&SIMPLE_STRUCT_SCHEMA, &mut de),
+        Err(JsonSerdeError::DeadlineExceeded(_))
+    ));
+
+    let json = br#"{"a": "b"}"#;
+    let mut de = JsonDeserializer::new(json).with_deadline(Deadline::at(Instant::now()));
+    assert!(matches!(
+        IndexMap::<String, String>::deserialize_with_schema(&STRING_MAP_SCHEMA, &mut de),
+        Err(JsonSerdeError::DeadlineExceeded(_))
+    ));
+}
+
+#[test]
+fn test_cancellation_aborts_document() {
+    let token = CancellationToken::new();
+    token.cancel();
+    let json = br#"{"a": [1, 2, {"b": null}]}"#;
+    let mut de = JsonDeserializer::new(json).with_deadline(Deadline::none().with_token(token));
+    let Err(err) = Box::<dyn Document>::deserialize_with_schema(&DOCUMENT, &mut de) else {
+        panic!("Expected deserialization to be cancelled");
+    };
+    assert!(matches!(
+        err,
+        JsonSerdeError::DeadlineExceeded(DeadlineExceeded::Cancelled)
+    ));
+}
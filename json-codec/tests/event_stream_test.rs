@@ -0,0 +1,140 @@
+use smithy4rs_core::{
+    derive::{SmithyShape, smithy_union},
+    prelude::{ErrorTrait, EventHeaderTrait, INTEGER, STRING, StreamingTrait},
+    serde::eventstream::{
+        CONTENT_TYPE_HEADER, EventMarshaller, EventStream, EventStreamError, HeaderValue, Message,
+    },
+    smithy,
+};
+use smithy4rs_json_codec::JsonCodec;
+
+smithy!("com.example#Greeting": {
+    structure GREETING_SCHEMA {
+        @EventHeaderTrait::builder().build();
+        LANGUAGE: STRING = "language"
+        TEXT: STRING = "text"
+        COUNT: INTEGER = "count"
+    }
+});
+smithy!("com.example#Rejected": {
+    @ErrorTrait::Client;
+    structure REJECTED_SCHEMA {
+        MESSAGE: STRING = "message"
+    }
+});
+smithy!("com.example#ChatEvents": {
+    @StreamingTrait::builder().build();
+    union CHAT_EVENTS_SCHEMA {
+        GREETING: GREETING_SCHEMA = "greeting"
+        REJECTED: REJECTED_SCHEMA = "rejected"
+    }
+});
+smithy!("com.example#ChatInput": {
+    structure CHAT_INPUT_SCHEMA {
+        ROOM: STRING = "room"
+        EVENTS: CHAT_EVENTS_SCHEMA = "events"
+    }
+});
+
+#[derive(SmithyShape, Clone, PartialEq)]
+#[smithy_schema(GREETING_SCHEMA)]
+pub struct Greeting {
+    #[smithy_schema(LANGUAGE)]
+    language: String,
+    #[smithy_schema(TEXT)]
+    text: String,
+    #[smithy_schema(COUNT)]
+    count: i32,
+}
+
+#[derive(SmithyShape, Clone, PartialEq)]
+#[smithy_schema(REJECTED_SCHEMA)]
+#[smithy_error]
+pub struct Rejected {
+    #[smithy_schema(MESSAGE)]
+    message: String,
+}
+
+#[smithy_union]
+#[derive(SmithyShape, Clone, PartialEq)]
+#[smithy_schema(CHAT_EVENTS_SCHEMA)]
+pub enum ChatEvents {
+    #[smithy_schema(GREETING)]
+    Greeting(Greeting),
+    #[smithy_schema(REJECTED)]
+    Rejected(Rejected),
+}
+
+#[derive(SmithyShape)]
+#[smithy_schema(CHAT_INPUT_SCHEMA)]
+pub struct ChatInput {
+    #[smithy_schema(ROOM)]
+    room: String,
+    #[no_builder]
+    #[smithy_schema(EVENTS)]
+    events: Option<EventStream<ChatEvents>>,
+}
+
+fn marshaller() -> EventMarshaller<'static> {
+    EventMarshaller::new(&JsonCodec, "application/json")
+}
+
+#[test]
+fn marshals_event_body_with_codec() {
+    let event = ChatEvents::Greeting(Greeting {
+        language: "en".to_string(),
+        text: "hello".to_string(),
+        count: 2,
+    });
+    let message = marshaller().marshal(&event).unwrap();
+    assert_eq!(message.event_type(), Some("greeting"));
+    assert_eq!(message.header("language"), Some(&HeaderValue::from("en")));
+    assert_eq!(
+        message.header(CONTENT_TYPE_HEADER),
+        Some(&HeaderValue::from("application/json"))
+    );
+    assert_eq!(message.payload(), r#"{"text":"hello","count":2}"#);
+
+    let output: ChatEvents = marshaller().unmarshal(&message).unwrap();
+    assert!(output == event);
+}
+
+#[test]
+fn marshals_modeled_errors_as_exceptions() {
+    let event = ChatEvents::Rejected(Rejected {
+        message: "room is full".to_string(),
+    });
+    let message = marshaller().marshal(&event).unwrap();
+    assert_eq!(message.message_type(), Some("exception"));
+    assert_eq!(message.exception_type(), Some("rejected"));
+    assert!(message.event_type().is_none());
+
+    let output: ChatEvents = marshaller().unmarshal(&message).unwrap();
+    assert!(output == event);
+}
+
+#[test]
+fn unmarshal_validates_events() {
+    let message = Message::new(r#"{"text":"hello"}"#)
+        .with_header(":message-type", "event")
+        .with_header(":event-type", "greeting")
+        .with_header("language", "en");
+    let result = marshaller().unmarshal::<ChatEvents>(&message);
+    assert!(matches!(result, Err(EventStreamError::Codec(_))));
+}
+
+#[test]
+fn initial_request_omits_event_stream() {
+    let input = ChatInput {
+        room: "lobby".to_string(),
+        events: Some(EventStream::from_events(Vec::new())),
+    };
+    let message = marshaller().initial_request(&input).unwrap();
+    assert_eq!(message.event_type(), Some("initial-request"));
+    assert_eq!(message.payload(), r#"{"room":"lobby"}"#);
+    assert!(!input.events.as_ref().unwrap().is_consumed());
+
+    let output: ChatInput = marshaller().read_initial(&message).unwrap();
+    assert_eq!(output.room, "lobby");
+    assert!(output.events.is_none());
+}
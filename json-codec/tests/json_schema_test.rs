@@ -0,0 +1,115 @@
+use serde_json::json;
+use smithy4rs_core::{
+    prelude::{
+        DocumentationTrait, INTEGER, LengthTrait, PatternTrait, RangeTrait, RequiredTrait, STRING,
+        UniqueItemsTrait,
+    },
+    smithy,
+};
+use smithy4rs_json_codec::schema_to_json_schema;
+use smithy4rs_test_utils::*;
+
+smithy!("com.example#Tags": {
+    @UniqueItemsTrait::builder().build();
+    @LengthTrait::builder().max(5i64).build();
+    list TAGS_SCHEMA {
+        member: STRING
+    }
+});
+
+smithy!("com.example#Priority": {
+    intEnum PRIORITY_SCHEMA {
+        LOW = 1
+        HIGH = 10
+    }
+});
+
+smithy!("com.example#CreateUser": {
+    @DocumentationTrait::new("A new user.");
+    structure CREATE_USER_SCHEMA {
+        @RequiredTrait::builder().build();
+        @LengthTrait::builder().min(1i64).max(32i64).build();
+        @PatternTrait::new("^[a-z]+$");
+        NAME: STRING = "name"
+        @RangeTrait::builder().min(0).max(150).build();
+        AGE: INTEGER = "age"
+        TAGS: TAGS_SCHEMA = "tags"
+        STATUS: SIMPLE_ENUM = "status"
+        PRIORITY: PRIORITY_SCHEMA = "priority"
+    }
+});
+
+#[test]
+fn converts_constraints() {
+    assert_eq!(
+        schema_to_json_schema(&CREATE_USER_SCHEMA),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "CreateUser",
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 32,
+                    "pattern": "^[a-z]+$"
+                },
+                "age": { "type": "integer", "minimum": 0, "maximum": 150 },
+                "tags": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "maxItems": 5,
+                    "uniqueItems": true
+                },
+                "status": { "$ref": "#/$defs/StringEnum" },
+                "priority": { "$ref": "#/$defs/Priority" }
+            },
+            "required": ["name"],
+            "description": "A new user.",
+            "$defs": {
+                "StringEnum": { "type": "string", "enum": ["a", "b", "c"] },
+                "Priority": { "type": "integer", "enum": [1, 10] }
+            }
+        })
+    );
+}
+
+#[test]
+fn converts_recursive_shapes() {
+    let schema = schema_to_json_schema(&RECURSIVE_SHAPES_STRUCT_SCHEMA);
+    assert_eq!(schema["title"], "RecursiveShapesStruct");
+    assert_eq!(schema["properties"]["next"], json!({ "$ref": "#" }));
+    assert_eq!(
+        schema["properties"]["map_field"],
+        json!({ "type": "object", "additionalProperties": { "type": "string" } })
+    );
+    assert!(schema.get("$defs").is_none());
+}
+
+#[test]
+fn converts_nested_structures() {
+    let schema = schema_to_json_schema(&NESTED_COLLECTIONS_STRUCT_SCHEMA);
+    let reference = json!({ "$ref": "#/$defs/InnerStruct" });
+    assert_eq!(schema["properties"]["single_nested"], reference);
+    assert_eq!(schema["properties"]["list_nested"]["items"], reference);
+    assert_eq!(
+        schema["properties"]["map_nested"]["additionalProperties"],
+        reference
+    );
+    assert_eq!(schema["$defs"]["InnerStruct"]["type"], "object");
+}
+
+#[test]
+fn converts_unions() {
+    let schema = schema_to_json_schema(&UNION);
+    let variants = schema["oneOf"].as_array().unwrap();
+    assert_eq!(variants.len(), 3);
+    assert_eq!(
+        variants[0],
+        json!({
+            "type": "object",
+            "properties": { "a": { "type": "string" } },
+            "required": ["a"]
+        })
+    );
+}
@@ -1,4 +1,4 @@
-use smithy4rs_core::serde::deserializers::DeserializeWithSchema;
+use smithy4rs_core::serde::{CodecError, deserializers::DeserializeWithSchema};
 use smithy4rs_json_codec::{JsonDeserializer, JsonSerdeError};
 use smithy4rs_test_utils::*;
 
@@ -55,3 +55,18 @@ fn test_trailing_comma() {
         Ok(_) => panic!("Expected error, got Ok"),
     }
 }
+
+#[test]
+fn test_converts_to_codec_error() {
+    let json = b"{\"field_a\": \"test\"";
+    let mut de = JsonDeserializer::new(json);
+    let result: Result<_, CodecError> =
+        SimpleStructBuilder::deserialize_with_schema(&SIMPLE_STRUCT_SCHEMA, &mut de)
+            .map_err(CodecError::from);
+
+    match result {
+        Err(CodecError::Custom(msg)) => assert!(msg.contains("EOF while parsing")),
+        Err(e) => panic!("Expected Custom error, got: {e:?}"),
+        Ok(_) => panic!("Expected error, got Ok"),
+    }
+}
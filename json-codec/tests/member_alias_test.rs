@@ -0,0 +1,56 @@
+use smithy4rs_core::{
+    derive::SmithyShape,
+    prelude::{INTEGER, STRING},
+    schema::AliasesTrait,
+    serde::{deserializers::DeserializeWithSchema, serializers::SerializeWithSchema},
+    smithy,
+};
+use smithy4rs_json_codec::{JsonDeserializer, JsonSerializer};
+
+smithy!("test#RenamedStruct": {
+    structure RENAMED_STRUCT_SCHEMA {
+        @AliasesTrait::new(vec!["cityName".to_string(), "city_name".to_string()]);
+        NAME: STRING = "name"
+        POPULATION: INTEGER = "population"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(RENAMED_STRUCT_SCHEMA)]
+pub struct RenamedStruct {
+    #[smithy_schema(NAME)]
+    pub name: String,
+    #[smithy_schema(POPULATION)]
+    pub population: Option<i32>,
+}
+
+fn deserialize(json: &[u8]) -> RenamedStruct {
+    let mut de = JsonDeserializer::new(json);
+    RenamedStructBuilder::deserialize_with_schema(&RENAMED_STRUCT_SCHEMA, &mut de)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_deserializes_current_name() {
+    let result = deserialize(br#"{"name": "Seattle", "population": 1}"#);
+    assert_eq!(result.name, "Seattle");
+    assert_eq!(result.population, Some(1));
+}
+
+#[test]
+fn test_deserializes_aliases() {
+    assert_eq!(deserialize(br#"{"cityName": "Seattle"}"#).name, "Seattle");
+    assert_eq!(deserialize(br#"{"city_name": "Seattle"}"#).name, "Seattle");
+}
+
+#[test]
+fn test_serializes_current_name() {
+    let value = deserialize(br#"{"cityName": "Seattle"}"#);
+    let mut output = Vec::new();
+    value
+        .serialize_with_schema(&RENAMED_STRUCT_SCHEMA, JsonSerializer::new(&mut output))
+        .unwrap();
+    assert_eq!(output, br#"{"name":"Seattle"}"#);
+}
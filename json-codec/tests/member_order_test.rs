@@ -0,0 +1,86 @@
+use smithy4rs_core::serde::{CodecError, deserializers::DeserializeWithSchema};
+use smithy4rs_json_codec::{JsonDeserializer, JsonSerdeError};
+use smithy4rs_test_utils::*;
+
+fn numeric_types(json: &[u8]) -> Result<NumericTypesStruct, JsonSerdeError> {
+    let mut de = JsonDeserializer::new(json);
+    Ok(
+        NumericTypesStructBuilder::deserialize_with_schema(&NUMERIC_TYPES_STRUCT_SCHEMA, &mut de)?
+            .build()
+            .unwrap(),
+    )
+}
+
+#[test]
+fn test_members_in_reverse_order() {
+    let json = br#"{
+        "double_val": 6.5,
+        "float_val": 5.5,
+        "long_val": 4,
+        "int_val": 3,
+        "short_val": 2,
+        "byte_val": 1
+    }"#;
+    let result = numeric_types(json).unwrap();
+
+    assert_eq!(result.byte_val, 1);
+    assert_eq!(result.short_val, 2);
+    assert_eq!(result.int_val, 3);
+    assert_eq!(result.long_val, 4);
+    assert_eq!(result.float_val, 5.5);
+    assert_eq!(result.double_val, 6.5);
+}
+
+#[test]
+fn test_members_in_shuffled_order() {
+    let json = br#"{
+        "long_val": 4,
+        "byte_val": 1,
+        "double_val": 6.5,
+        "short_val": 2,
+        "float_val": 5.5,
+        "int_val": 3
+    }"#;
+    let in_order = br#"{
+        "byte_val": 1,
+        "short_val": 2,
+        "int_val": 3,
+        "long_val": 4,
+        "float_val": 5.5,
+        "double_val": 6.5
+    }"#;
+
+    assert!(numeric_types(json).unwrap() == numeric_types(in_order).unwrap());
+}
+
+#[test]
+fn test_duplicate_member_rejected() {
+    let json = br#"{"field_a": "first", "field_b": 42, "field_a": "second"}"#;
+    let mut de = JsonDeserializer::new(json);
+    let Err(err) = SimpleStructBuilder::deserialize_with_schema(&SIMPLE_STRUCT_SCHEMA, &mut de)
+    else {
+        panic!("expected duplicate member error");
+    };
+
+    assert!(
+        matches!(&err, JsonSerdeError::DuplicateMember(member) if member == "test#SimpleStruct$field_a"),
+        "unexpected error: {err}"
+    );
+    assert!(matches!(
+        CodecError::from(err),
+        CodecError::DuplicateMember(member) if member == "test#SimpleStruct$field_a"
+    ));
+}
+
+#[test]
+fn test_duplicate_unknown_member_ignored() {
+    let json = br#"{"field_a": "test", "extra": 1, "field_b": 42, "extra": 2}"#;
+    let mut de = JsonDeserializer::new(json);
+    let result = SimpleStructBuilder::deserialize_with_schema(&SIMPLE_STRUCT_SCHEMA, &mut de)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(result.field_a, "test");
+    assert_eq!(result.field_b, 42);
+}
@@ -43,3 +43,30 @@ fn test_null_for_required_field() {
         Ok(_) => panic!("Expected error, got Ok"),
     }
 }
+
+#[test]
+fn test_missing_required_field_with_correction() {
+    let json = br#"{"field_a": "test"}"#;
+    let mut de = JsonDeserializer::new(json);
+    let builder = SimpleStructBuilder::deserialize_with_schema(&SIMPLE_STRUCT_SCHEMA, &mut de)
+        .expect("Deserialization should succeed");
+
+    // Missing required field is filled with its zero value
+    let built = builder
+        .build_with_correction()
+        .expect("Corrected build should succeed");
+    assert_eq!(built.field_a, "test");
+    assert_eq!(built.field_b, 0);
+}
+
+#[test]
+fn test_missing_required_field_unchecked_correction() {
+    let json = br#"{"field_b": 42}"#;
+    let mut de = JsonDeserializer::new(json);
+    let builder = SimpleStructBuilder::deserialize_with_schema(&SIMPLE_STRUCT_SCHEMA, &mut de)
+        .expect("Deserialization should succeed");
+
+    let built = builder.build_unchecked_corrected();
+    assert_eq!(built.field_a, "");
+    assert_eq!(built.field_b, 42);
+}
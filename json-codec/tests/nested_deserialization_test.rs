@@ -210,3 +210,44 @@ fn test_deeply_nested_without_recursion() {
     assert_eq!(map_val.field_b, "map_val_b");
     assert_eq!(map_val.field_c, "map_val_c");
 }
+
+#[test]
+fn test_mutually_recursive_deserialization() {
+    let json = br#"{
+        "value": 1,
+        "children": [{"value": 2, "children": [{"value": 3}]}, {"value": 4}],
+        "parent": {"value": 0}
+    }"#;
+    let mut de = JsonDeserializer::new(json);
+    let tree = TreeNodeBuilder::deserialize_with_schema(&TREE_NODE_SCHEMA, &mut de)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let children = tree.children.as_ref().unwrap();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[0].children.as_ref().unwrap()[0].value, 3);
+    assert_eq!(tree.parent.as_ref().unwrap().value, 0);
+}
+
+#[test]
+fn test_recursive_members_are_validated() {
+    let json = br#"{"value": 1, "children": [{"value": 2, "parent": {"value": -1}}]}"#;
+    let mut de = JsonDeserializer::new(json);
+    let result = TreeNodeBuilder::deserialize_with_schema(&TREE_NODE_SCHEMA, &mut de)
+        .unwrap()
+        .build();
+    let errors = result.unwrap_err();
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_recursive_union_deserialization() {
+    let json = br#"{"negate": {"negate": {"literal": 3}}}"#;
+    let mut de = JsonDeserializer::new(json);
+    let expr = Expr::deserialize_with_schema(&EXPR_SCHEMA, &mut de).unwrap();
+    assert_eq!(
+        expr,
+        Expr::Negate(Box::new(Expr::Negate(Box::new(Expr::Literal(3)))))
+    );
+}
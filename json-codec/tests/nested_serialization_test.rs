@@ -1,5 +1,10 @@
 // TODO(test): add some test utils.rs for validating the serialized json outputs, perhaps snapshots would suffice
-use smithy4rs_core::{IndexMap, serde::se::SerializeWithSchema};
+use std::{borrow::Cow, sync::Arc};
+
+use smithy4rs_core::{
+    IndexMap,
+    serde::se::{SerializableShape, SerializeWithSchema},
+};
 use smithy4rs_json_codec::JsonSerializer;
 use smithy4rs_test_utils::*;
 
@@ -201,3 +206,52 @@ fn test_deeply_nested_without_recursion() {
     assert!(json.contains("\"map_key\""));
     assert!(json.contains("\"map_val_a\""));
 }
+
+fn to_json<T: SerializableShape>(shape: T) -> String {
+    let mut buf = Vec::new();
+    shape.serialize(JsonSerializer::new(&mut buf)).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn test_borrowed_and_pointer_shapes_serialize() {
+    let inner = InnerStructBuilder::new()
+        .field_a("a")
+        .field_b("b")
+        .field_c("c")
+        .build()
+        .unwrap();
+    let expected = to_json(inner.clone());
+
+    assert_eq!(to_json(&inner), expected);
+    assert_eq!(to_json(Box::new(inner.clone())), expected);
+    assert_eq!(to_json(Arc::new(inner.clone())), expected);
+    assert_eq!(to_json(Cow::Borrowed(&inner)), expected);
+    assert_eq!(to_json(Cow::<InnerStruct>::Owned(inner)), expected);
+}
+
+#[test]
+fn test_boxed_members_set_without_boxing() {
+    let parent = TreeNodeBuilder::new().value(0).build().unwrap();
+    let tree = TreeNodeBuilder::new()
+        .value(1)
+        .children(vec![TreeNodeBuilder::new().value(2).build().unwrap()])
+        .parent(parent)
+        .build()
+        .unwrap();
+    let from_builder = TreeNodeBuilder::new()
+        .value(1)
+        .children(vec![TreeNodeBuilder::new().value(2).build().unwrap()])
+        .parent_builder(TreeNodeBuilder::new().value(0))
+        .build()
+        .unwrap();
+    assert_eq!(tree, from_builder);
+
+    let mut buf = Vec::new();
+    tree.serialize_with_schema(&TREE_NODE_SCHEMA, JsonSerializer::new(&mut buf))
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"{"value":1,"children":[{"value":2}],"parent":{"value":0}}"#
+    );
+}
@@ -0,0 +1,67 @@
+use smithy4rs_core::{
+    prelude::DOCUMENT,
+    schema::Document,
+    serde::{de::DeserializeWithSchema, serializers::SerializeWithSchema},
+};
+use smithy4rs_json_codec::{JsonDeserializer, JsonSerializer};
+
+const PAYLOAD: &str = r#"{"zero":-0,"exp":1E+2,"precise":1.500,"list":[1,2.0e-3,-7],"big":12345678901234567890123,"nested":{"value":0.10}}"#;
+
+fn read(json: &str, preserve: bool) -> Box<dyn Document> {
+    let mut de = JsonDeserializer::new(json.as_bytes()).with_preserved_number_literals(preserve);
+    Box::<dyn Document>::deserialize_with_schema(&DOCUMENT, &mut de).unwrap()
+}
+
+#[allow(clippy::borrowed_box)]
+fn write(document: &Box<dyn Document>) -> String {
+    let mut buf = Vec::new();
+    document
+        .serialize_with_schema(&DOCUMENT, JsonSerializer::new(&mut buf))
+        .unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn test_preserved_literals_roundtrip_byte_identical() {
+    let document = read(PAYLOAD, true);
+    assert_eq!(write(&document), PAYLOAD);
+}
+
+#[test]
+fn test_preserved_literals_expose_numeric_values() {
+    let document = read(PAYLOAD, true);
+    let map = document.as_map().unwrap();
+    assert_eq!(map["zero"].as_number_literal(), Some("-0"));
+    assert_eq!(map["zero"].as_long(), Some(0));
+    assert_eq!(map["exp"].as_number_literal(), Some("1E+2"));
+    assert_eq!(map["exp"].as_double(), Some(100.0));
+    assert_eq!(map["precise"].as_double(), Some(1.5));
+    assert_eq!(map["list"].as_list().unwrap()[2].as_long(), Some(-7));
+}
+
+#[test]
+fn test_numbers_are_normalized_by_default() {
+    let document = read(PAYLOAD, false);
+    let map = document.as_map().unwrap();
+    assert_eq!(map["zero"].as_number_literal(), None);
+    assert_eq!(
+        write(&document),
+        r#"{"zero":0,"exp":100.0,"precise":1.5,"list":[1,0.002,-7],"big":12345678901234567890123,"nested":{"value":0.1}}"#
+    );
+}
+
+#[test]
+fn test_canonical_output_ignores_preserved_literals() {
+    let document = read(r#"{"b":1.500,"a":1E+2}"#, true);
+    let mut buf = Vec::new();
+    document
+        .serialize_with_schema(&DOCUMENT, JsonSerializer::canonical(&mut buf))
+        .unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), r#"{"a":100,"b":1.5}"#);
+}
+
+#[test]
+fn test_document_values_roundtrip() {
+    let json = r#"{"s":"text","t":true,"f":false,"n":null,"l":[]}"#;
+    assert_eq!(write(&read(json, false)), json);
+}
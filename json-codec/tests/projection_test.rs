@@ -0,0 +1,55 @@
+use smithy4rs_core::serde::{
+    de::DeserializeWithSchema,
+    projection::{ProjectedDeserializer, Projection},
+};
+use smithy4rs_json_codec::JsonDeserializer;
+use smithy4rs_test_utils::*;
+
+#[test]
+fn test_projection_skips_unprojected_members() {
+    let json = br#"{
+        "name": "routing-key",
+        "count": 42,
+        "single_nested": {"field_a": "a", "field_b": "b", "field_c": "c"},
+        "list_nested": [{"field_a": "a", "field_b": "b", "field_c": "c"}],
+        "map_nested": {"key": {"field_a": "a", "field_b": "b", "field_c": "c"}}
+    }"#;
+    let projection = Projection::new(["name"]);
+    let mut de = JsonDeserializer::new(json);
+    let builder = NestedCollectionsStructBuilder::deserialize_with_schema(
+        &NESTED_COLLECTIONS_STRUCT_SCHEMA,
+        ProjectedDeserializer::new(&mut de, &projection),
+    )
+    .expect("Deserialization should succeed");
+
+    // Skipped required members are unset, so `build` fails validation
+    assert!(builder.clone().build().is_err());
+
+    let result = builder.build_unchecked_corrected();
+    assert_eq!(result.name, "routing-key");
+    assert_eq!(result.count, 0);
+    assert!(result.list_nested.is_empty());
+    assert!(result.map_nested.is_empty());
+}
+
+#[test]
+fn test_projection_reads_nested_members_in_full() {
+    let json = br#"{
+        "name": "routing-key",
+        "single_nested": {"field_a": "a", "field_b": "b", "field_c": "c"},
+        "count": 42
+    }"#;
+    let projection = Projection::new(["single_nested"]).include("count");
+    let mut de = JsonDeserializer::new(json);
+    let result = NestedCollectionsStructBuilder::deserialize_with_schema(
+        &NESTED_COLLECTIONS_STRUCT_SCHEMA,
+        ProjectedDeserializer::new(&mut de, &projection),
+    )
+    .expect("Deserialization should succeed")
+    .build_unchecked_corrected();
+
+    assert_eq!(result.name, "");
+    assert_eq!(result.count, 42);
+    assert_eq!(result.single_nested.field_a, "a");
+    assert_eq!(result.single_nested.field_c, "c");
+}
@@ -0,0 +1,59 @@
+use smithy4rs_core::{
+    schema::ShapeId,
+    serde::{Codec, Protocol, ProtocolRegistry},
+};
+use smithy4rs_json_codec::JsonCodec;
+use smithy4rs_test_utils::*;
+
+struct TestJsonProtocol {
+    id: ShapeId,
+}
+
+impl Protocol for TestJsonProtocol {
+    fn id(&self) -> &ShapeId {
+        &self.id
+    }
+
+    fn content_type(&self) -> &str {
+        "application/json"
+    }
+
+    fn codec(&self) -> &dyn Codec {
+        &JsonCodec
+    }
+}
+
+fn registry() -> ProtocolRegistry {
+    ProtocolRegistry::new().with(TestJsonProtocol {
+        id: ShapeId::from("test#json"),
+    })
+}
+
+#[test]
+fn resolved_codec_roundtrips_shapes() {
+    let registry = registry();
+    let protocol = registry.for_content_type("application/json").unwrap();
+    let shape = SimpleStructBuilder::new()
+        .field_a("a")
+        .field_b(1)
+        .build()
+        .unwrap();
+
+    let bytes = protocol.codec().serialize_shape(shape.clone()).unwrap();
+    assert_eq!(
+        String::from_utf8(bytes.clone()).unwrap(),
+        r#"{"field_a":"a","field_b":1}"#
+    );
+    let output: SimpleStruct = protocol.codec().deserialize_shape(&bytes).unwrap();
+    assert_eq!(output, shape);
+}
+
+#[test]
+fn resolved_codec_reports_malformed_input() {
+    let registry = registry();
+    let protocol = registry.get(&ShapeId::from("test#json")).unwrap();
+    let result = protocol
+        .codec()
+        .deserialize_shape::<SimpleStruct, SimpleStructBuilder>(br#"{"field_a":"#);
+    assert!(result.is_err());
+}
@@ -1,5 +1,7 @@
 use smithy4rs_core::{
-    schema::Schema,
+    BigDecimal, BigInt, ByteBuffer, IndexMap,
+    prelude::{BIG_DECIMAL, BIG_INTEGER, BLOB, DOCUMENT},
+    schema::{Blob, Document, Schema},
     serde::{Buildable, ShapeBuilder, de::DeserializeWithSchema, serializers::SerializeWithSchema},
 };
 use smithy4rs_json_codec::{JsonDeserializer, JsonSerializer};
@@ -119,6 +121,88 @@ fn test_numbers_edge_cases() {
     assert!((numbers.double_val - result.double_val).abs() < f64::EPSILON);
 }
 
+#[test]
+fn test_big_numbers_roundtrip() {
+    let big_int = "-123456789012345678901234567890".parse::<BigInt>().unwrap();
+    let json = serialize_to_json(&big_int, &BIG_INTEGER);
+    assert_eq!(json, b"-123456789012345678901234567890");
+    let mut de = JsonDeserializer::new(&json);
+    assert_eq!(
+        BigInt::deserialize_with_schema(&BIG_INTEGER, &mut de).unwrap(),
+        big_int
+    );
+
+    let big_dec = "12345678901234567890.000123".parse::<BigDecimal>().unwrap();
+    let json = serialize_to_json(&big_dec, &BIG_DECIMAL);
+    assert_eq!(json, b"12345678901234567890.000123");
+    let mut de = JsonDeserializer::new(&json);
+    assert_eq!(
+        BigDecimal::deserialize_with_schema(&BIG_DECIMAL, &mut de).unwrap(),
+        big_dec
+    );
+}
+
+#[test]
+fn test_big_numbers_read_from_strings() {
+    let mut de = JsonDeserializer::new(br#""98765432109876543210""#);
+    assert_eq!(
+        BigInt::deserialize_with_schema(&BIG_INTEGER, &mut de).unwrap(),
+        "98765432109876543210".parse::<BigInt>().unwrap()
+    );
+    let mut de = JsonDeserializer::new(br#""1.5e3""#);
+    assert_eq!(
+        BigDecimal::deserialize_with_schema(&BIG_DECIMAL, &mut de).unwrap(),
+        BigDecimal::from(1500)
+    );
+}
+
+#[test]
+fn test_blobs_roundtrip_as_base64() {
+    let blob = Blob::from_static(b"hello\x00world");
+    let json = serialize_to_json(&blob, &BLOB);
+    assert_eq!(json, br#""aGVsbG8Ad29ybGQ=""#);
+
+    let mut de = JsonDeserializer::new(&json);
+    let result = Blob::deserialize_with_schema(&BLOB, &mut de).unwrap();
+    assert_eq!(result, blob);
+}
+
+#[test]
+fn test_byte_buffer_blobs_roundtrip() {
+    let buffer = ByteBuffer::from_vec(b"hello\x00world".to_vec());
+    let json = serialize_to_json(&buffer, &BLOB);
+    assert_eq!(json, br#""aGVsbG8Ad29ybGQ=""#);
+
+    let mut de = JsonDeserializer::new(&json);
+    let result = ByteBuffer::deserialize_with_schema(&BLOB, &mut de).unwrap();
+    assert_eq!(result, buffer);
+}
+
+#[test]
+fn test_invalid_base64_blob() {
+    let mut de = JsonDeserializer::new(br#""not base64!""#);
+    assert!(Blob::deserialize_with_schema(&BLOB, &mut de).is_err());
+}
+
+#[test]
+fn test_document_blobs_are_decoded() {
+    let data = HttpBlobPayloadOutput {
+        status: Some(200),
+        request_id: Some("id".to_string()),
+        data: Some(Blob::from_static(b"blob data")),
+    };
+    let json = serialize_to_json(&data, &HTTP_BLOB_PAYLOAD_OUTPUT_SCHEMA);
+
+    let mut de = JsonDeserializer::new(&json);
+    let document = Box::<dyn Document>::deserialize_with_schema(&DOCUMENT, &mut de).unwrap();
+    let blob = &document.as_map().unwrap()["data"];
+    assert_eq!(blob.as_string(), Some("YmxvYiBkYXRh"));
+    assert_eq!(blob.as_blob().map(Blob::as_bytes), Some(&b"blob data"[..]));
+
+    let result: HttpBlobPayloadOutput = document.try_into().unwrap();
+    assert_eq!(result, data);
+}
+
 #[test]
 fn test_special_characters_in_strings() {
     let data = SimpleStructBuilder::new()
@@ -164,3 +248,60 @@ fn test_union() {
     let result = TestUnion::deserialize_with_schema(&UNION, &mut de).unwrap();
     assert_eq!(data, result);
 }
+
+#[test]
+fn test_union_unknown_variant_roundtrip() {
+    let json = br#"{"d":{"nested":[1,"two",null]}}"#;
+    let mut de = JsonDeserializer::new(json);
+    let result = TestUnion::deserialize_with_schema(&UNION, &mut de).unwrap();
+    let TestUnion::Unknown(name, value) = &result else {
+        panic!("Expected unknown variant: {result:?}");
+    };
+    assert_eq!(name, "d");
+    assert!(value.as_map().unwrap().contains_key("nested"));
+
+    let output = serialize_to_json(&result, &UNION);
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        String::from_utf8_lossy(json)
+    );
+}
+
+#[test]
+fn test_union_null_members_are_unset() {
+    let deserialize = |json: &[u8]| {
+        let mut de = JsonDeserializer::new(json);
+        TestUnion::deserialize_with_schema(&UNION, &mut de)
+    };
+    assert_eq!(
+        deserialize(br#"{"a":null,"b":1}"#).unwrap(),
+        TestUnion::B(1)
+    );
+    assert_eq!(
+        deserialize(br#"{"b":2,"c":null,"d":null}"#).unwrap(),
+        TestUnion::B(2)
+    );
+    assert_eq!(deserialize(br#"{"c":{},"a":null}"#).unwrap(), TestUnion::C);
+    assert!(deserialize(br#"{"a":null}"#).is_err());
+    assert!(deserialize(br#"{"a":"x","b":1}"#).is_err());
+}
+
+#[test]
+fn test_sparse_collections() {
+    let mut sparse_map = IndexMap::new();
+    sparse_map.insert("a".to_string(), Some("value".to_string()));
+    sparse_map.insert("b".to_string(), None);
+    let data = SparseCollectionsStructBuilder::new()
+        .sparse_list(vec![Some("a".to_string()), None, Some("c".to_string())])
+        .sparse_map(sparse_map)
+        .build()
+        .unwrap();
+
+    let json = serialize_to_json(&data, &SPARSE_COLLECTIONS_STRUCT_SCHEMA);
+    assert_eq!(
+        String::from_utf8(json).unwrap(),
+        r#"{"sparse_list":["a",null,"c"],"sparse_map":{"a":"value","b":null}}"#
+    );
+    let result = roundtrip(&data, &SPARSE_COLLECTIONS_STRUCT_SCHEMA);
+    assert_eq!(data, result);
+}
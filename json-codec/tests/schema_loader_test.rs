@@ -0,0 +1,160 @@
+use smithy4rs_core::{
+    prelude::{DOCUMENT, JsonNameTrait, LengthTrait, RequiredTrait, STRING},
+    schema::{
+        Document, ShapeType,
+        loader::{ModelError, SchemaIndex},
+    },
+    serde::de::DeserializeWithSchema,
+};
+use smithy4rs_json_codec::JsonDeserializer;
+
+const MODEL: &str = r#"{
+    "smithy": "2.0",
+    "shapes": {
+        "com.example#Person": {
+            "type": "structure",
+            "members": {
+                "name": {
+                    "target": "smithy.api#String",
+                    "traits": {
+                        "smithy.api#required": {},
+                        "smithy.api#jsonName": "fullName"
+                    }
+                },
+                "nicknames": { "target": "com.example#Nicknames" },
+                "scores": { "target": "com.example#Scores" },
+                "color": { "target": "com.example#Color" },
+                "size": { "target": "com.example#Size" },
+                "friend": { "target": "com.example#Person" }
+            },
+            "traits": {
+                "com.example#custom": { "enabled": true }
+            }
+        },
+        "com.example#Nicknames": {
+            "type": "list",
+            "member": { "target": "com.example#Nickname" }
+        },
+        "com.example#Nickname": {
+            "type": "string",
+            "traits": {
+                "smithy.api#length": { "min": 1, "max": 10 }
+            }
+        },
+        "com.example#Scores": {
+            "type": "map",
+            "key": { "target": "smithy.api#String" },
+            "value": { "target": "smithy.api#Integer" }
+        },
+        "com.example#Color": {
+            "type": "enum",
+            "members": {
+                "RED": { "target": "smithy.api#Unit", "traits": { "smithy.api#enumValue": "red" } },
+                "GREEN": { "target": "smithy.api#Unit" }
+            }
+        },
+        "com.example#Size": {
+            "type": "intEnum",
+            "members": {
+                "SMALL": { "target": "smithy.api#Unit", "traits": { "smithy.api#enumValue": 1 } },
+                "LARGE": { "target": "smithy.api#Unit", "traits": { "smithy.api#enumValue": 2 } }
+            }
+        }
+    }
+}"#;
+
+fn load(json: &str) -> Result<SchemaIndex, ModelError> {
+    let mut de = JsonDeserializer::new(json.as_bytes());
+    let model = Box::<dyn Document>::deserialize_with_schema(&DOCUMENT, &mut de).unwrap();
+    SchemaIndex::load(&*model)
+}
+
+#[test]
+fn test_loads_shapes_keyed_by_id() {
+    let index = load(MODEL).unwrap();
+    assert_eq!(index.len(), 6);
+    let ids: Vec<_> = index.iter().map(|(id, _)| id.id().to_string()).collect();
+    assert_eq!(ids[0], "com.example#Person");
+    assert!(index.contains(&"com.example#Scores".into()));
+}
+
+#[test]
+fn test_loads_members_and_traits() {
+    let index = load(MODEL).unwrap();
+    let person = index.get(&"com.example#Person".into()).unwrap();
+    assert_eq!(person.shape_type(), &ShapeType::Structure);
+    assert_eq!(person.members().len(), 6);
+    assert!(person.contains_trait(&"com.example#custom".into()));
+
+    let name = person.get_member("name").unwrap();
+    assert_eq!(name.shape_type(), &ShapeType::String);
+    assert_eq!(name.as_member().unwrap().name(), "name");
+    assert!(name.contains_type::<RequiredTrait>());
+    assert_eq!(
+        name.get_trait_as::<JsonNameTrait>().unwrap().as_str(),
+        "fullName"
+    );
+
+    let nicknames = person.get_member("nicknames").unwrap();
+    let element = nicknames.get_list_member().unwrap();
+    let length = element.get_trait_as::<LengthTrait>().unwrap();
+    assert_eq!(length.min, Some(1));
+    assert_eq!(length.max, Some(10));
+
+    let (key, value) = person
+        .get_member("scores")
+        .unwrap()
+        .get_key_value()
+        .unwrap();
+    assert_eq!(key.shape_type(), &ShapeType::String);
+    assert_eq!(value.shape_type(), &ShapeType::Integer);
+}
+
+#[test]
+fn test_loads_enum_values() {
+    let index = load(MODEL).unwrap();
+    let color = index.get(&"com.example#Color".into()).unwrap();
+    let values: Vec<_> = color.as_enum().unwrap().values().iter().copied().collect();
+    assert_eq!(values, vec!["red", "GREEN"]);
+
+    let size = index.get(&"com.example#Size".into()).unwrap();
+    let values: Vec<_> = size
+        .as_int_enum()
+        .unwrap()
+        .values()
+        .iter()
+        .copied()
+        .collect();
+    assert_eq!(values, vec![1, 2]);
+}
+
+#[test]
+fn test_resolves_recursive_members() {
+    let index = load(MODEL).unwrap();
+    let person = index.get(&"com.example#Person".into()).unwrap();
+    let friend = person.get_member("friend").unwrap();
+    assert_eq!(friend.shape_type(), &ShapeType::Structure);
+    let name = friend.get_member("name").unwrap();
+    assert!(name.contains_type::<RequiredTrait>());
+    assert_eq!(name.shape_type(), STRING.shape_type());
+}
+
+#[test]
+fn test_reports_unresolved_targets() {
+    let model = r#"{
+        "smithy": "2.0",
+        "shapes": {
+            "com.example#Shape": {
+                "type": "structure",
+                "members": { "a": { "target": "com.example#Missing" } }
+            }
+        }
+    }"#;
+    assert_eq!(
+        load(model).unwrap_err(),
+        ModelError::UnresolvedTarget {
+            member: "com.example#Shape$a".to_string(),
+            target: "com.example#Missing".to_string(),
+        }
+    );
+}
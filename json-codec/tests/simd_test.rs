@@ -0,0 +1,100 @@
+#![cfg(feature = "simd")]
+
+use std::borrow::Cow;
+
+use smithy4rs_core::{
+    prelude::{DOCUMENT, STRING},
+    schema::Document,
+    serde::{
+        deserializers::DeserializeWithSchema, serializers::SerializeWithSchema,
+        strings::StringPolicy,
+    },
+};
+use smithy4rs_json_codec::{JsonDeserializer, JsonSerializer, SimdJsonDeserializer};
+use smithy4rs_test_utils::*;
+
+const NESTED: &str = r#"{
+    "name": "test_object",
+    "count": 42,
+    "unknown": {"skipped": [1, 2, {"deeply": null}]},
+    "single_nested": {"field_a": "alpha", "field_b": "be\"ta", "field_c": "gämma"},
+    "list_nested": [
+        {"field_a": "item1-a", "field_b": "item1-b", "field_c": "item1-c"},
+        {"field_a": "item2-a", "field_b": "item2-b", "field_c": "item2-c"}
+    ],
+    "map_nested": {
+        "key1": {"field_a": "value1-a", "field_b": "value1-b", "field_c": "value1-c"}
+    }
+}"#;
+
+#[test]
+fn test_matches_json_deserializer_for_shapes() {
+    let expected = NestedCollectionsStructBuilder::deserialize_with_schema(
+        &NESTED_COLLECTIONS_STRUCT_SCHEMA,
+        &mut JsonDeserializer::new(NESTED.as_bytes()),
+    )
+    .unwrap()
+    .build()
+    .unwrap();
+
+    let mut data = NESTED.as_bytes().to_vec();
+    let mut de = SimdJsonDeserializer::new(&mut data).unwrap();
+    let actual = NestedCollectionsStructBuilder::deserialize_with_schema(
+        &NESTED_COLLECTIONS_STRUCT_SCHEMA,
+        &mut de,
+    )
+    .unwrap()
+    .build()
+    .unwrap();
+
+    assert_eq!(format!("{actual:?}"), format!("{expected:?}"));
+    assert_eq!(actual.single_nested.field_b, "be\"ta");
+}
+
+#[test]
+fn test_matches_json_deserializer_for_documents() {
+    let json =
+        r#"{"a": [1, -2, 1.5, 18446744073709551615], "b": {"c": true, "d": null}, "e": "f"}"#;
+    let expected = Box::<dyn Document>::deserialize_with_schema(
+        &DOCUMENT,
+        &mut JsonDeserializer::new(json.as_bytes()),
+    )
+    .unwrap();
+
+    let mut data = json.as_bytes().to_vec();
+    let actual = Box::<dyn Document>::deserialize_with_schema(
+        &DOCUMENT,
+        &mut SimdJsonDeserializer::new(&mut data).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(write(&actual), write(&expected));
+}
+
+#[allow(clippy::borrowed_box)]
+fn write(document: &Box<dyn Document>) -> String {
+    let mut buf = Vec::new();
+    document
+        .serialize_with_schema(&DOCUMENT, JsonSerializer::new(&mut buf))
+        .unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn test_borrows_unescaped_strings() {
+    let mut data = br#"["plain", "esc\"aped"]"#.to_vec();
+    let mut de = SimdJsonDeserializer::new(&mut data).unwrap();
+    let result =
+        Vec::<Cow<'_, str>>::deserialize_with_schema(&STRING_LIST_SCHEMA, &mut de).unwrap();
+    assert_eq!(result, vec!["plain", "esc\"aped"]);
+    assert!(result.iter().all(|s| matches!(s, Cow::Borrowed(_))));
+}
+
+#[test]
+fn test_applies_string_policy() {
+    let mut data = br#""a\u0000b""#.to_vec();
+    let mut de = SimdJsonDeserializer::new(&mut data)
+        .unwrap()
+        .with_string_policy(StringPolicy::new().reject_nul(true));
+    assert!(String::deserialize_with_schema(&STRING, &mut de).is_err());
+}
@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+
+use smithy4rs_core::{
+    IndexMap,
+    schema::prelude::STRING,
+    serde::{
+        de::DeserializeWithSchema,
+        strings::{InvalidUtf8, StringPolicy},
+    },
+};
+use smithy4rs_json_codec::JsonDeserializer;
+use smithy4rs_test_utils::*;
+
+#[test]
+fn test_rejects_invalid_utf8_by_default() {
+    let json = b"\"ab\xFFcd\"";
+    let mut de = JsonDeserializer::new(json);
+    assert!(String::deserialize_with_schema(&STRING, &mut de).is_err());
+}
+
+#[test]
+fn test_replaces_invalid_utf8() {
+    let json = b"\"ab\xFFcd\"";
+    let mut de = JsonDeserializer::new(json)
+        .with_string_policy(StringPolicy::new().invalid_utf8(InvalidUtf8::Replace));
+    let result = String::deserialize_with_schema(&STRING, &mut de).unwrap();
+    assert_eq!(result, "ab\u{FFFD}cd");
+}
+
+#[test]
+fn test_replaces_invalid_utf8_in_escaped_string() {
+    let json = b"\"a\\n\xFF\"";
+    let mut de = JsonDeserializer::new(json)
+        .with_string_policy(StringPolicy::new().invalid_utf8(InvalidUtf8::Replace));
+    let result = String::deserialize_with_schema(&STRING, &mut de).unwrap();
+    assert_eq!(result, "a\n\u{FFFD}");
+}
+
+#[test]
+fn test_allows_nul_by_default() {
+    let json = br#""a\u0000b""#;
+    let mut de = JsonDeserializer::new(json);
+    let result = String::deserialize_with_schema(&STRING, &mut de).unwrap();
+    assert_eq!(result, "a\0b");
+}
+
+#[test]
+fn test_rejects_escaped_nul() {
+    let json = br#""a\u0000b""#;
+    let mut de =
+        JsonDeserializer::new(json).with_string_policy(StringPolicy::new().reject_nul(true));
+    let err = String::deserialize_with_schema(&STRING, &mut de).unwrap_err();
+    assert!(err.to_string().contains("NUL"));
+}
+
+#[test]
+fn test_rejects_nul_in_borrowed_string() {
+    let json = br#""a\u0000b""#;
+    let mut de =
+        JsonDeserializer::new(json).with_string_policy(StringPolicy::new().reject_nul(true));
+    assert!(Cow::<str>::deserialize_with_schema(&STRING, &mut de).is_err());
+}
+
+#[test]
+fn test_rejects_nul_in_map_key() {
+    let json = br#"{"a\u0000": "b"}"#;
+    let mut de =
+        JsonDeserializer::new(json).with_string_policy(StringPolicy::new().reject_nul(true));
+    assert!(
+        IndexMap::<String, String>::deserialize_with_schema(&STRING_MAP_SCHEMA, &mut de).is_err()
+    );
+}
+
+#[test]
+fn test_policy_still_borrows_valid_strings() {
+    let json = br#""hello""#;
+    let mut de = JsonDeserializer::new(json).with_string_policy(
+        StringPolicy::new()
+            .invalid_utf8(InvalidUtf8::Replace)
+            .reject_nul(true),
+    );
+    let result = Cow::<str>::deserialize_with_schema(&STRING, &mut de).unwrap();
+    assert!(matches!(result, Cow::Borrowed("hello")));
+}
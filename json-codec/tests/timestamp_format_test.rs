@@ -0,0 +1,102 @@
+use smithy4rs_core::{
+    schema::Timestamp,
+    serde::{de::DeserializeWithSchema, serializers::SerializeWithSchema},
+};
+use smithy4rs_json_codec::{JsonDeserializer, JsonSerializer};
+use smithy4rs_test_utils::*;
+
+fn timestamp(millis: i64) -> smithy4rs_core::Instant {
+    Timestamp::from_epoch_millis(millis).unwrap().into()
+}
+
+fn serialize(value: &TimestampFormatsStruct) -> String {
+    let mut buf = Vec::new();
+    value
+        .serialize_with_schema(
+            &TIMESTAMP_FORMATS_STRUCT_SCHEMA,
+            JsonSerializer::new(&mut buf),
+        )
+        .unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+fn deserialize(json: &str) -> TimestampFormatsStruct {
+    let mut de = JsonDeserializer::new(json.as_bytes());
+    TimestampFormatsStructBuilder::deserialize_with_schema(
+        &TIMESTAMP_FORMATS_STRUCT_SCHEMA,
+        &mut de,
+    )
+    .unwrap()
+    .build()
+    .unwrap()
+}
+
+#[test]
+fn test_serializes_each_timestamp_format() {
+    let value = TimestampFormatsStruct {
+        default: timestamp(1_398_796_238_000),
+        epoch_seconds: timestamp(1_398_796_238_123),
+        date_time: timestamp(1_398_796_238_123),
+        http_date: Some(timestamp(1_398_796_238_000)),
+    };
+    let json = serialize(&value);
+    assert!(json.contains(r#""default":1398796238"#), "{json}");
+    assert!(json.contains(r#""epoch_seconds":1398796238.123"#), "{json}");
+    assert!(
+        json.contains(r#""date_time":"2014-04-29T18:30:38.123Z""#),
+        "{json}"
+    );
+    assert!(
+        json.contains(r#""http_date":"Tue, 29 Apr 2014 18:30:38 GMT""#),
+        "{json}"
+    );
+}
+
+#[test]
+fn test_deserializes_each_timestamp_format() {
+    let json = r#"{
+        "default": 1398796238,
+        "epoch_seconds": 1398796238.123,
+        "date_time": "2014-04-29T18:30:38.123Z",
+        "http_date": "Tue, 29 Apr 2014 18:30:38 GMT"
+    }"#;
+    let result = deserialize(json);
+    assert_eq!(result.default, timestamp(1_398_796_238_000));
+    assert_eq!(result.epoch_seconds, timestamp(1_398_796_238_123));
+    assert_eq!(result.date_time, timestamp(1_398_796_238_123));
+    assert_eq!(result.http_date, Some(timestamp(1_398_796_238_000)));
+}
+
+#[test]
+fn test_roundtrips_pre_epoch_timestamps() {
+    let value = TimestampFormatsStruct {
+        default: timestamp(-1_500),
+        epoch_seconds: timestamp(-86_400_000),
+        date_time: timestamp(-1),
+        http_date: None,
+    };
+    let json = serialize(&value);
+    assert!(json.contains(r#""default":-1.5"#), "{json}");
+    assert!(
+        json.contains(r#""date_time":"1969-12-31T23:59:59.999Z""#),
+        "{json}"
+    );
+    assert!(deserialize(&json) == value);
+}
+
+#[test]
+fn test_rejects_mismatched_timestamp_format() {
+    let json = r#"{
+        "default": "2014-04-29T18:30:38Z",
+        "epoch_seconds": 0,
+        "date_time": "2014-04-29T18:30:38Z"
+    }"#;
+    let mut de = JsonDeserializer::new(json.as_bytes());
+    assert!(
+        TimestampFormatsStructBuilder::deserialize_with_schema(
+            &TIMESTAMP_FORMATS_STRUCT_SCHEMA,
+            &mut de
+        )
+        .is_err()
+    );
+}
@@ -1,5 +1,14 @@
-use smithy4rs_core::serde::deserializers::DeserializeWithSchema;
-use smithy4rs_json_codec::JsonDeserializer;
+use smithy4rs_core::{
+    derive::SmithyShape,
+    prelude::STRING,
+    serde::{
+        CodecError,
+        deserializers::{DeserializeWithSchema, UnknownEnumPolicy},
+        strict::StrictMembersTrait,
+    },
+    smithy,
+};
+use smithy4rs_json_codec::{JsonDeserializer, JsonSerdeError};
 use smithy4rs_test_utils::*;
 
 #[test]
@@ -151,3 +160,112 @@ fn test_unknown_fields_at_multiple_nesting_levels() {
     assert_eq!(result.map_nested.len(), 1);
     assert_eq!(result.map_nested.get("key1").unwrap().field_a, "map_a");
 }
+
+#[test]
+fn test_strict_mode_rejects_unknown_fields() {
+    let json = br#"{"field_a": "test", "extra1": 1, "field_b": 42, "extra2": {"a": []}}"#;
+    let mut de = JsonDeserializer::new(json).with_strict_members(true);
+    let Err(err) = SimpleStructBuilder::deserialize_with_schema(&SIMPLE_STRUCT_SCHEMA, &mut de)
+    else {
+        panic!("Expected unknown members to be rejected");
+    };
+    assert!(matches!(
+        &err,
+        JsonSerdeError::UnknownMembers { shape, members }
+            if shape == "test#SimpleStruct" && members == &["extra1", "extra2"]
+    ));
+    assert!(matches!(
+        CodecError::from(err),
+        CodecError::UnknownMembers { members, .. } if members.len() == 2
+    ));
+}
+
+#[test]
+fn test_strict_mode_rejects_unknown_nested_fields() {
+    let json = br#"{
+        "name": "outer",
+        "count": 1,
+        "single_nested": {"field_a": "a", "field_b": "b", "field_c": "c", "unknown_inner": 1},
+        "list_nested": [],
+        "map_nested": {}
+    }"#;
+    let mut de = JsonDeserializer::new(json).with_strict_members(true);
+    let result = NestedCollectionsStructBuilder::deserialize_with_schema(
+        &NESTED_COLLECTIONS_STRUCT_SCHEMA,
+        &mut de,
+    );
+    assert!(matches!(
+        result,
+        Err(JsonSerdeError::UnknownMembers { members, .. }) if members == ["unknown_inner"]
+    ));
+}
+
+#[test]
+fn test_strict_mode_accepts_known_fields() {
+    let json = br#"{"field_a": "test", "field_b": 42}"#;
+    let mut de = JsonDeserializer::new(json).with_strict_members(true);
+    let result = SimpleStructBuilder::deserialize_with_schema(&SIMPLE_STRUCT_SCHEMA, &mut de)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(result.field_a, "test");
+}
+
+#[test]
+fn test_strict_mode_rejects_unknown_union_members() {
+    let json = br#"{"unknown": "value"}"#;
+    let mut de = JsonDeserializer::new(json).with_strict_members(true);
+    assert!(matches!(
+        TestUnion::deserialize_with_schema(&UNION, &mut de),
+        Err(JsonSerdeError::UnknownMembers { .. })
+    ));
+}
+
+#[test]
+fn test_unknown_enum_values_mapped_to_unknown_by_default() {
+    let mut de = JsonDeserializer::new(br#""d""#);
+    let result = TestEnum::deserialize_with_schema(&SIMPLE_ENUM, &mut de).unwrap();
+    assert_eq!(result, TestEnum::Unknown("d".to_string()));
+}
+
+#[test]
+fn test_unknown_enum_policy_rejects_unknown_values() {
+    let mut de =
+        JsonDeserializer::new(br#""d""#).with_unknown_enum_policy(UnknownEnumPolicy::Error);
+    let err = TestEnum::deserialize_with_schema(&SIMPLE_ENUM, &mut de).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Failed to deserialize: Unknown value `d` for enum `test#StringEnum`"
+    );
+
+    let mut de = JsonDeserializer::new(b"7").with_unknown_enum_policy(UnknownEnumPolicy::Error);
+    assert!(TestIntEnum::deserialize_with_schema(&SIMPLE_INT_ENUM, &mut de).is_err());
+
+    let mut de = JsonDeserializer::new(b"2").with_unknown_enum_policy(UnknownEnumPolicy::Error);
+    let result = TestIntEnum::deserialize_with_schema(&SIMPLE_INT_ENUM, &mut de).unwrap();
+    assert_eq!(result, TestIntEnum::B);
+}
+
+smithy!("test#StrictStruct": {
+    @StrictMembersTrait::builder().build();
+    structure STRICT_STRUCT_SCHEMA {
+        FIELD: STRING = "field"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(STRICT_STRUCT_SCHEMA)]
+pub struct StrictStruct {
+    #[smithy_schema(FIELD)]
+    pub field: Option<String>,
+}
+
+#[test]
+fn test_strict_members_trait_rejects_unknown_fields() {
+    let json = br#"{"field": "a", "extra": 1}"#;
+    let mut de = JsonDeserializer::new(json);
+    assert!(matches!(
+        StrictStructBuilder::deserialize_with_schema(&STRICT_STRUCT_SCHEMA, &mut de),
+        Err(JsonSerdeError::UnknownMembers { members, .. }) if members == ["extra"]
+    ));
+}
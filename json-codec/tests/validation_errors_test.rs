@@ -0,0 +1,16 @@
+use smithy4rs_core::serde::{
+    se::{Error, SerializableShape},
+    validation::ValidationErrors,
+};
+use smithy4rs_json_codec::JsonSerializer;
+
+#[test]
+fn validation_errors_serialize_as_json_pointers() {
+    let errors = ValidationErrors::custom("something went wrong");
+    let mut buf = Vec::new();
+    errors.serialize(JsonSerializer::new(&mut buf)).unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"{"errors":[{"path":"","code":"custom","message":"something went wrong"}]}"#
+    );
+}
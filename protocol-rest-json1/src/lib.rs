@@ -0,0 +1,30 @@
+//! [AWS restJson1](https://smithy.io/2.0/aws/protocols/aws-restjson1-protocol.html) protocol support for `smithy4rs`.
+//!
+//! The `restJson1` protocol binds operation inputs and outputs to HTTP messages using the
+//! [HTTP binding traits](https://smithy.io/2.0/spec/http-bindings.html), and serializes all
+//! unbound members as a JSON object in the message body.
+//!
+//! Modeled errors are identified by the `X-Amzn-Errortype` header. If the header is absent,
+//! the error type is read from the `code` or `__type` member of the JSON body, matching the
+//! discriminator behavior described for [`Document`](smithy4rs_core::schema::Document)'s.
+//! Error types are resolved by shape name, so a [`ShapeRegistry`](smithy4rs_core::serde::ShapeRegistry)
+//! of the errors an operation can return is used to deserialize the error:
+//!
+//! ```rust,ignore
+//! let protocol = RestJson1::new();
+//! let http = GET_CITY_SCHEMA.get_trait_as::<HttpTrait>().unwrap();
+//! let request = protocol.serialize_request(http, &input)?;
+//!
+//! let response = send(request).await?;
+//! if response.status().is_success() {
+//!     let output: GetCityOutput = protocol.deserialize_response(&response)?;
+//! } else {
+//!     let registry = ShapeRegistry::new().with(GetCityError::NoSuchCity);
+//!     let error: GetCityError = protocol.deserialize_error(&response, &registry)?;
+//! }
+//! ```
+mod protocol;
+
+pub use protocol::{ERROR_TYPE_HEADER, RestJson1, error_type};
+
+smithy4rs_core::assert_send_sync!(RestJson1);
@@ -0,0 +1,301 @@
+use http::{
+    HeaderMap, HeaderValue, Request, Response,
+    header::{CONTENT_TYPE, InvalidHeaderValue},
+};
+use smithy4rs_core::{
+    IndexMap,
+    prelude::{DOCUMENT, HttpTrait},
+    schema::{Document, DocumentError, Schema, ShapeId, ShapeType, StaticSchemaShape},
+    serde::{
+        Buildable, Codec, CodecError, Protocol, ShapeBuilder, ShapeRegistry,
+        http::{HttpBinding, error_code},
+        se::SerializeWithSchema,
+        validation::DefaultValidator,
+    },
+};
+use smithy4rs_http_binding::{
+    HttpBindingDeserializer, HttpBindingError, HttpBindingSerializer, HttpMessage,
+};
+use smithy4rs_json_codec::{JsonCodec, JsonDeserializer, JsonSerializer};
+
+/// Header used to identify the modeled error returned in a response.
+pub const ERROR_TYPE_HEADER: &str = "x-amzn-errortype";
+
+/// Media type of JSON message bodies.
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// The [AWS restJson1](https://smithy.io/2.0/aws/protocols/aws-restjson1-protocol.html) protocol.
+///
+/// Serializes requests, responses, and errors using the HTTP binding traits
+/// with a JSON message body.
+#[derive(Debug, Clone)]
+pub struct RestJson1 {
+    id: ShapeId,
+}
+
+impl RestJson1 {
+    /// Create a new `restJson1` protocol.
+    #[must_use]
+    pub fn new() -> Self {
+        RestJson1 {
+            id: ShapeId::from("aws.protocols#restJson1"),
+        }
+    }
+
+    /// Serialize an operation input into an HTTP request for an operation with the provided `@http` trait.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the input could not be serialized or bound to the request.
+    pub fn serialize_request<T>(
+        &self,
+        http: &HttpTrait,
+        input: &T,
+    ) -> Result<Request<Vec<u8>>, CodecError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema,
+    {
+        let (message, body) = serialize(T::schema(), input)?;
+        let mut request = message.into_request(http, body)?;
+        let empty = request.body().is_empty();
+        set_content_type(T::schema(), request.headers_mut(), empty);
+        Ok(request)
+    }
+
+    /// Deserialize and build an operation input from an HTTP request for an operation
+    /// with the provided `@http` trait.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the request does not match the operation, could not be
+    /// deserialized, or if the built input is invalid.
+    pub fn deserialize_request<S, B, T>(
+        &self,
+        http: &HttpTrait,
+        request: &Request<T>,
+    ) -> Result<S, CodecError>
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: for<'de> ShapeBuilder<'de, S>,
+        T: AsRef<[u8]>,
+    {
+        self.deserialize_request_with_validator(http, request, &mut self.validator())
+    }
+
+    /// Deserialize and build an operation input from an HTTP request, validating
+    /// the built input with `validator` instead of the protocol's [`validator`](Protocol::validator).
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the request does not match the operation, could not be
+    /// deserialized, or if the built input is invalid.
+    pub fn deserialize_request_with_validator<S, B, T>(
+        &self,
+        http: &HttpTrait,
+        request: &Request<T>,
+        validator: &mut DefaultValidator,
+    ) -> Result<S, CodecError>
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: for<'de> ShapeBuilder<'de, S>,
+        T: AsRef<[u8]>,
+    {
+        let message = HttpMessage::from_request(request, http)?;
+        deserialize_message(&message, request.body().as_ref(), validator)
+    }
+
+    /// Serialize an operation output into an HTTP response for an operation with the
+    /// provided `@http` trait.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the output could not be serialized or bound to the response.
+    pub fn serialize_response<T>(
+        &self,
+        http: &HttpTrait,
+        output: &T,
+    ) -> Result<Response<Vec<u8>>, CodecError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema,
+    {
+        response(T::schema(), output, http.code)
+    }
+
+    /// Deserialize and build an operation output from an HTTP response.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the response could not be deserialized or if
+    /// the built output is invalid.
+    pub fn deserialize_response<S, B, T>(&self, response: &Response<T>) -> Result<S, CodecError>
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: for<'de> ShapeBuilder<'de, S>,
+        T: AsRef<[u8]>,
+    {
+        let message = HttpMessage::from_response(response);
+        deserialize_message(&message, response.body().as_ref(), &mut self.validator())
+    }
+
+    /// Serialize a modeled error into an HTTP response.
+    ///
+    /// The status code is resolved from the `@httpError` and `@error` traits of the
+    /// error shape, and the shape name is written to the [`ERROR_TYPE_HEADER`].
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the error could not be serialized or bound to the response.
+    pub fn serialize_error<T>(&self, error: &T) -> Result<Response<Vec<u8>>, CodecError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema,
+    {
+        let schema = T::schema();
+        let mut response = response(schema, error, error_code(schema))?;
+        let error_type = HeaderValue::from_str(schema.id().name()).map_err(invalid_header)?;
+        response.headers_mut().insert(ERROR_TYPE_HEADER, error_type);
+        Ok(response)
+    }
+
+    /// Deserialize a modeled error from an HTTP response, using a [`ShapeRegistry`]
+    /// of the errors that can be returned.
+    ///
+    /// See [`error_type`] for how the error shape is identified.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the response does not identify an error type, the error
+    /// type is not registered, or the error could not be deserialized.
+    pub fn deserialize_error<E, T>(
+        &self,
+        response: &Response<T>,
+        registry: &ShapeRegistry<E>,
+    ) -> Result<E, CodecError>
+    where
+        T: AsRef<[u8]>,
+    {
+        let body = response.body().as_ref();
+        let document = if body.is_empty() {
+            IndexMap::<String, Box<dyn Document>>::new().into()
+        } else {
+            JsonCodec.deserialize_document(&DOCUMENT, body)?
+        };
+        let name = error_type(response.headers(), &document)
+            .ok_or_else(|| CodecError::Custom("Response has no error type".to_string()))?;
+        let id = registry
+            .find_by_name(name)
+            .ok_or_else(|| DocumentError::UnknownDiscriminator(name.to_string()))?
+            .clone();
+        Ok(registry.deserialize(&id, document)?)
+    }
+}
+
+impl Default for RestJson1 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Protocol for RestJson1 {
+    fn id(&self) -> &ShapeId {
+        &self.id
+    }
+
+    fn content_type(&self) -> &str {
+        JSON_CONTENT_TYPE
+    }
+
+    fn codec(&self) -> &dyn Codec {
+        &JsonCodec
+    }
+}
+
+/// Get the name of the error shape identified by an error response.
+///
+/// The error type is read from the [`ERROR_TYPE_HEADER`] if present, and otherwise
+/// from the `code` or `__type` member of the response body. Any namespace prefix
+/// (`com.example#`) or trailing URI (`:http://...`) is removed from the value.
+#[allow(clippy::borrowed_box)]
+#[must_use]
+pub fn error_type<'a>(headers: &'a HeaderMap, body: &'a Box<dyn Document>) -> Option<&'a str> {
+    let value = headers
+        .get(ERROR_TYPE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .or_else(|| {
+            let members = body.as_map()?;
+            members
+                .get("code")
+                .or_else(|| members.get("__type"))
+                .and_then(|value| value.as_string())
+        })?;
+    let value = value.split_once(':').map_or(value, |(name, _)| name);
+    let value = value.rsplit_once('#').map_or(value, |(_, name)| name);
+    Some(value.trim()).filter(|name| !name.is_empty())
+}
+
+fn serialize<T: SerializeWithSchema>(
+    schema: &Schema,
+    value: &T,
+) -> Result<(HttpMessage, Vec<u8>), CodecError> {
+    let mut body = Vec::new();
+    let message = value.serialize_with_schema(
+        schema,
+        HttpBindingSerializer::new(JsonSerializer::new(&mut body)),
+    )?;
+    Ok((message, body))
+}
+
+fn deserialize_message<S, B>(
+    message: &HttpMessage,
+    body: &[u8],
+    validator: &mut DefaultValidator,
+) -> Result<S, CodecError>
+where
+    S: for<'de> Buildable<'de, B>,
+    B: for<'de> ShapeBuilder<'de, S>,
+{
+    // An empty body is equivalent to an empty JSON object unless it is bound to a payload
+    let body = if body.is_empty() && payload(S::schema()).is_none() {
+        b"{}"
+    } else {
+        body
+    };
+    let mut deserializer = JsonDeserializer::new(body);
+    let builder = B::deserialize_with_schema(
+        S::schema(),
+        HttpBindingDeserializer::new(message, body, &mut deserializer),
+    )?;
+    Ok(builder.build_with_validator(validator)?)
+}
+
+fn response<T: SerializeWithSchema>(
+    schema: &Schema,
+    value: &T,
+    code: i32,
+) -> Result<Response<Vec<u8>>, CodecError> {
+    let code = u16::try_from(code)
+        .map_err(|_| CodecError::Custom(format!("Invalid status code `{code}`")))?;
+    let (message, body) = serialize(schema, value)?;
+    let mut response = message.into_response(code, body)?;
+    let empty = response.body().is_empty();
+    set_content_type(schema, response.headers_mut(), empty);
+    Ok(response)
+}
+
+/// Get the member bound to the entire message body, if any.
+fn payload(schema: &Schema) -> Option<&Schema> {
+    schema
+        .members()
+        .values()
+        .find(|member| HttpBinding::of(member) == HttpBinding::Payload)
+}
+
+/// Set the `Content-Type` of a message with a body if it was not set by a binding.
+fn set_content_type(schema: &Schema, headers: &mut HeaderMap, empty_body: bool) {
+    if empty_body || headers.contains_key(CONTENT_TYPE) {
+        return;
+    }
+    let content_type =
+        payload(schema).map_or(JSON_CONTENT_TYPE, |payload| match payload.shape_type() {
+            ShapeType::Blob => "application/octet-stream",
+            ShapeType::String => "text/plain",
+            _ => JSON_CONTENT_TYPE,
+        });
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+}
+
+fn invalid_header(error: InvalidHeaderValue) -> CodecError {
+    HttpBindingError::Http(error.into()).into()
+}
@@ -0,0 +1,223 @@
+use http::{Response, header::CONTENT_TYPE};
+use smithy4rs_core::{
+    derive::SmithyShape,
+    prelude::{ErrorTrait, HttpErrorTrait, HttpTrait, INTEGER, RequiredTrait, STRING},
+    schema::{Blob, ShapeId},
+    serde::{Protocol, ShapeRegistry},
+    smithy,
+};
+use smithy4rs_protocol_rest_json1::{ERROR_TYPE_HEADER, RestJson1};
+use smithy4rs_test_utils::*;
+
+smithy!("com.example#NotFound": {
+    @ErrorTrait::Client;
+    @HttpErrorTrait::new(404);
+    structure NOT_FOUND_SCHEMA {
+        @RequiredTrait::builder().build();
+        MESSAGE: STRING = "message"
+    }
+});
+
+#[derive(SmithyShape, Clone, PartialEq)]
+#[smithy_schema(NOT_FOUND_SCHEMA)]
+pub struct NotFound {
+    #[smithy_schema(MESSAGE)]
+    message: String,
+}
+
+smithy!("com.example#Throttled": {
+    @ErrorTrait::Server;
+    structure THROTTLED_SCHEMA {
+        RETRY_AFTER: INTEGER = "retryAfter"
+    }
+});
+
+#[derive(SmithyShape, Clone, PartialEq)]
+#[smithy_schema(THROTTLED_SCHEMA)]
+pub struct Throttled {
+    #[smithy_schema(RETRY_AFTER)]
+    retry_after: Option<i32>,
+}
+
+#[derive(Debug, PartialEq)]
+enum GetCityError {
+    NotFound(NotFound),
+    Throttled(Throttled),
+}
+
+smithy!("com.example#GetCityOutput": {
+    structure GET_CITY_OUTPUT_SCHEMA {
+        NAME: STRING = "name"
+    }
+});
+
+#[derive(SmithyShape, Clone, PartialEq)]
+#[smithy_schema(GET_CITY_OUTPUT_SCHEMA)]
+pub struct GetCityOutput {
+    #[smithy_schema(NAME)]
+    name: Option<String>,
+}
+
+fn http_trait(method: &str, uri: &str) -> HttpTrait {
+    HttpTrait::builder()
+        .method(method.to_string())
+        .uri(uri.to_string())
+        .build()
+        .unwrap()
+}
+
+fn registry() -> ShapeRegistry<GetCityError> {
+    ShapeRegistry::new()
+        .with(GetCityError::NotFound)
+        .with(GetCityError::Throttled)
+}
+
+fn error_response(header: Option<&str>, body: &str) -> Response<Vec<u8>> {
+    let mut response = Response::builder().status(400);
+    if let Some(header) = header {
+        response = response.header(ERROR_TYPE_HEADER, header);
+    }
+    response.body(body.as_bytes().to_vec()).unwrap()
+}
+
+#[test]
+fn test_protocol_id() {
+    let protocol = RestJson1::new();
+    assert_eq!(protocol.id(), &ShapeId::from("aws.protocols#restJson1"));
+    assert_eq!(protocol.content_type(), "application/json");
+}
+
+#[test]
+fn test_request_round_trip() {
+    let protocol = RestJson1::new();
+    let http = http_trait("PUT", "/cities/{city}/files/{path+}");
+    let input = HttpBindingsInput {
+        city: "Seattle".to_string(),
+        path: "a/b.txt".to_string(),
+        page_size: Some(10),
+        tags: None,
+        params: None,
+        since: None,
+        aliases: None,
+        meta: None,
+        name: "Seattle".to_string(),
+        population: Some(750_000),
+    };
+    let request = protocol.serialize_request(&http, &input).unwrap();
+    assert_eq!(request.uri(), "/cities/Seattle/files/a/b.txt?pageSize=10");
+    assert_eq!(request.headers()[CONTENT_TYPE], "application/json");
+    assert_eq!(
+        std::str::from_utf8(request.body()).unwrap(),
+        r#"{"name":"Seattle","population":750000}"#
+    );
+
+    let result: HttpBindingsInput = protocol.deserialize_request(&http, &request).unwrap();
+    assert_eq!(result.city, input.city);
+    assert_eq!(result.path, input.path);
+    assert_eq!(result.page_size, input.page_size);
+    assert_eq!(result.name, input.name);
+    assert_eq!(result.population, input.population);
+}
+
+#[test]
+fn test_response_round_trip() {
+    let protocol = RestJson1::new();
+    let http = http_trait("GET", "/cities/{city}");
+    let output = GetCityOutput {
+        name: Some("Seattle".to_string()),
+    };
+    let response = protocol.serialize_response(&http, &output).unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/json");
+
+    let result: GetCityOutput = protocol.deserialize_response(&response).unwrap();
+    assert!(result == output);
+}
+
+#[test]
+fn test_empty_body_is_empty_object() {
+    let protocol = RestJson1::new();
+    let response = Response::builder().status(200).body(Vec::new()).unwrap();
+    let result: GetCityOutput = protocol.deserialize_response(&response).unwrap();
+    assert_eq!(result.name, None);
+}
+
+#[test]
+fn test_blob_payload_content_type() {
+    let protocol = RestJson1::new();
+    let http = http_trait("GET", "/data");
+    let output = HttpBlobPayloadOutput {
+        status: None,
+        request_id: None,
+        data: Some(Blob::from_static(b"raw")),
+    };
+    let response = protocol.serialize_response(&http, &output).unwrap();
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/octet-stream");
+    assert_eq!(response.body(), b"raw");
+}
+
+#[test]
+fn test_error_round_trip() {
+    let protocol = RestJson1::new();
+    let error = NotFound {
+        message: "No such city".to_string(),
+    };
+    let response = protocol.serialize_error(&error).unwrap();
+    assert_eq!(response.status(), 404);
+    assert_eq!(response.headers()[ERROR_TYPE_HEADER], "NotFound");
+
+    let result = protocol.deserialize_error(&response, &registry()).unwrap();
+    assert_eq!(result, GetCityError::NotFound(error));
+
+    let response = protocol
+        .serialize_error(&Throttled { retry_after: None })
+        .unwrap();
+    assert_eq!(response.status(), 500);
+}
+
+#[test]
+fn test_error_type_from_body() {
+    let protocol = RestJson1::new();
+    let response = error_response(
+        None,
+        r#"{"__type":"com.example#Throttled:http://internal.amazon.com/","retryAfter":5}"#,
+    );
+    assert_eq!(
+        protocol.deserialize_error(&response, &registry()).unwrap(),
+        GetCityError::Throttled(Throttled {
+            retry_after: Some(5)
+        })
+    );
+
+    let response = error_response(None, r#"{"code":"NotFound","message":"gone"}"#);
+    assert_eq!(
+        protocol.deserialize_error(&response, &registry()).unwrap(),
+        GetCityError::NotFound(NotFound {
+            message: "gone".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_error_header_takes_precedence() {
+    let protocol = RestJson1::new();
+    let response = error_response(
+        Some("NotFound:http://internal.amazon.com/"),
+        r#"{"__type":"Throttled","message":"gone"}"#,
+    );
+    assert_eq!(
+        protocol.deserialize_error(&response, &registry()).unwrap(),
+        GetCityError::NotFound(NotFound {
+            message: "gone".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_unknown_error_type() {
+    let protocol = RestJson1::new();
+    let response = error_response(Some("Unavailable"), "");
+    assert!(protocol.deserialize_error(&response, &registry()).is_err());
+    let response = error_response(None, r#"{"message":"gone"}"#);
+    assert!(protocol.deserialize_error(&response, &registry()).is_err());
+}
@@ -0,0 +1,132 @@
+//! Integration with the [`axum`] web framework.
+//!
+//! [`SmithyJson`] lets `axum` handlers take and return Smithy shapes directly,
+//! without routing requests through a [`Router`](crate::Router):
+//!
+//! ```rust,ignore
+//! async fn create_city(
+//!     SmithyJson(input): SmithyJson<CreateCityInput>,
+//! ) -> Result<SmithyJson<CreateCityOutput>, SmithyError<CityExists>> {
+//!     // ...
+//! }
+//!
+//! let app = axum::Router::new().route("/cities", post(create_city));
+//! ```
+//!
+//! Request bodies are deserialized as JSON and validated with the
+//! [`DefaultValidator`](smithy4rs_core::serde::validation::DefaultValidator).
+//! Invalid requests are rejected with the standard [`ValidationException`](crate::ValidationException).
+//!
+//! HTTP binding traits (`@httpLabel`, `@httpHeader`, ...) are not applied. The
+//! entire shape is read from and written to the body.
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{FromRequest, Request},
+    response::{IntoResponse, Response},
+};
+use http::{HeaderValue, header::CONTENT_TYPE};
+use smithy4rs_core::{
+    schema::StaticSchemaShape,
+    serde::{
+        CodecError, HasBuilder, Protocol, ShapeBuilder, de::DeserializeWithSchema,
+        se::SerializeWithSchema,
+    },
+};
+use smithy4rs_json_codec::{JsonDeserializer, JsonSerializer};
+use smithy4rs_protocol_rest_json1::RestJson1;
+
+use crate::{ErrorResponse, ServerError, ServerProtocol};
+
+/// JSON request body extractor and response for Smithy shapes.
+///
+/// As an extractor, deserializes and validates the request body into the shape
+/// `T`, rejecting the request with a [`SmithyRejection`] if it is invalid.
+///
+/// As a response, serializes the shape `T` into a `200 OK` JSON response.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SmithyJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for SmithyJson<T>
+where
+    T: HasBuilder,
+    S: Send + Sync,
+{
+    type Rejection = SmithyRejection;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let body = Bytes::from_request(request, state).await.map_err(|e| {
+            SmithyRejection(ServerError::Deserialization(CodecError::Custom(
+                e.body_text(),
+            )))
+        })?;
+        deserialize_body(&body)
+            .map(SmithyJson)
+            .map_err(|e| SmithyRejection(ServerError::Deserialization(e)))
+    }
+}
+
+fn deserialize_body<T: HasBuilder>(body: &[u8]) -> Result<T, CodecError> {
+    // An empty body is equivalent to an empty JSON object
+    let body = if body.is_empty() { b"{}" } else { body };
+    let mut deserializer = JsonDeserializer::new(body);
+    let builder = T::Builder::deserialize_with_schema(T::schema(), &mut deserializer)?;
+    Ok(builder.build_with_validator(&mut RestJson1::new().validator())?)
+}
+
+impl<T> IntoResponse for SmithyJson<T>
+where
+    T: StaticSchemaShape + SerializeWithSchema,
+{
+    fn into_response(self) -> Response {
+        let mut body = Vec::new();
+        if let Err(e) = self
+            .0
+            .serialize_with_schema(T::schema(), JsonSerializer::new(&mut body))
+        {
+            return SmithyRejection(ServerError::Serialization(e.into())).into_response();
+        }
+        let mut response = Response::new(Body::from(body));
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        response
+    }
+}
+
+/// Response for a modeled error returned by a handler.
+///
+/// The status code is resolved from the `@httpError` and `@error` traits of
+/// the error shape.
+#[derive(Debug, Clone, Copy)]
+pub struct SmithyError<E>(pub E);
+
+impl<E: ErrorResponse> From<E> for SmithyError<E> {
+    fn from(error: E) -> Self {
+        SmithyError(error)
+    }
+}
+
+impl<E: ErrorResponse> IntoResponse for SmithyError<E> {
+    fn into_response(self) -> Response {
+        match self.0.serialize_with(&RestJson1::new()) {
+            Ok(response) => response.map(Body::from),
+            Err(e) => SmithyRejection(ServerError::Serialization(e)).into_response(),
+        }
+    }
+}
+
+/// Rejection for requests that could not be extracted into a [`SmithyJson`].
+///
+/// Responds with a [`ValidationException`](crate::ValidationException) for
+/// inputs that fail validation, and a [`ServerError`] response otherwise.
+#[derive(Debug)]
+pub struct SmithyRejection(pub ServerError);
+
+impl IntoResponse for SmithyRejection {
+    fn into_response(self) -> Response {
+        RestJson1::new()
+            .serialize_server_error(&self.0)
+            .map(Body::from)
+    }
+}
@@ -0,0 +1,55 @@
+use http::StatusCode;
+use smithy4rs_core::serde::CodecError;
+use thiserror::Error;
+
+/// Errors raised by the server runtime while handling a request.
+///
+/// Server errors are not modeled by the service, and are written to a response
+/// with [`ServerProtocol::serialize_server_error`](crate::ServerProtocol::serialize_server_error).
+#[derive(Error, Debug)]
+pub enum ServerError {
+    /// No registered operation matches the method and path of the request.
+    #[error("No operation matches `{method} {path}`")]
+    UnknownOperation {
+        /// Method of the request
+        method: String,
+        /// Path of the request
+        path: String,
+    },
+    /// The request could not be deserialized into the operation input,
+    /// or the input failed validation.
+    #[error("Failed to deserialize request: {0}")]
+    Deserialization(CodecError),
+    /// The operation output or error could not be serialized into a response.
+    #[error("Failed to serialize response: {0}")]
+    Serialization(CodecError),
+}
+
+impl ServerError {
+    /// Get the status code of the response for this error.
+    #[must_use]
+    pub const fn status(&self) -> StatusCode {
+        match self {
+            ServerError::UnknownOperation { .. } => StatusCode::NOT_FOUND,
+            ServerError::Deserialization(CodecError::DeadlineExceeded(_)) => {
+                StatusCode::REQUEST_TIMEOUT
+            }
+            ServerError::Deserialization(_) => StatusCode::BAD_REQUEST,
+            ServerError::Serialization(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Get the name used to identify this error to clients.
+    #[must_use]
+    pub const fn error_type(&self) -> &'static str {
+        match self {
+            ServerError::UnknownOperation { .. } => "UnknownOperationException",
+            ServerError::Deserialization(CodecError::Validation(_)) => "ValidationException",
+            ServerError::Deserialization(CodecError::DeadlineExceeded(_)) => {
+                "RequestTimeoutException"
+            }
+            ServerError::Deserialization(_) => "SerializationException",
+            ServerError::Serialization(_) => "InternalFailure",
+        }
+    }
+}
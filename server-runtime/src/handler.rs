@@ -0,0 +1,71 @@
+use http::Response;
+use smithy4rs_core::{
+    schema::StaticSchemaShape,
+    serde::{CodecError, se::SerializeWithSchema},
+};
+
+use crate::protocol::ServerProtocol;
+
+/// Handles requests for an operation with input `I`, output `O`, and errors `E`.
+///
+/// Handlers are implemented for async closures and functions that take the
+/// operation input:
+///
+/// ```rust,ignore
+/// async fn get_city(input: GetCityInput) -> Result<GetCityOutput, GetCityError> {
+///     // ...
+/// }
+///
+/// let router = Router::new(RestJson1::new()).with(&GET_CITY, get_city)?;
+/// ```
+pub trait Handler<I, O, E>: Send + Sync + 'static {
+    /// Handle a deserialized and validated operation input.
+    fn handle(&self, input: I) -> impl Future<Output = Result<O, E>> + Send;
+}
+
+impl<I, O, E, F, Fut> Handler<I, O, E> for F
+where
+    F: Fn(I) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<O, E>> + Send,
+{
+    fn handle(&self, input: I) -> impl Future<Output = Result<O, E>> + Send {
+        self(input)
+    }
+}
+
+/// An error returned by a [`Handler`] that can be written to a response.
+///
+/// Implemented for all error shapes. Operation error enums implement this
+/// by serializing the error shape of the returned variant:
+///
+/// ```rust,ignore
+/// impl ErrorResponse for GetCityError {
+///     fn serialize_with<P: ServerProtocol>(
+///         &self,
+///         protocol: &P,
+///     ) -> Result<Response<Vec<u8>>, CodecError> {
+///         match self {
+///             GetCityError::NoSuchCity(error) => protocol.serialize_error(error),
+///         }
+///     }
+/// }
+/// ```
+pub trait ErrorResponse {
+    /// Serialize this error into a response using `protocol`.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the error could not be serialized.
+    fn serialize_with<P: ServerProtocol>(
+        &self,
+        protocol: &P,
+    ) -> Result<Response<Vec<u8>>, CodecError>;
+}
+
+impl<T: StaticSchemaShape + SerializeWithSchema> ErrorResponse for T {
+    fn serialize_with<P: ServerProtocol>(
+        &self,
+        protocol: &P,
+    ) -> Result<Response<Vec<u8>>, CodecError> {
+        protocol.serialize_error(self)
+    }
+}
@@ -0,0 +1,50 @@
+//! Server runtime for `smithy4rs` services.
+//!
+//! A [`Router`] dispatches incoming HTTP requests to the [`Handler`] registered for
+//! each operation. Requests are matched to operations by the method and URI
+//! pattern of their [`@http`](https://smithy.io/2.0/spec/http-bindings.html#http-trait)
+//! trait, and are then:
+//! 1. Deserialized into the operation input by a [`ServerProtocol`], which checks the
+//!    input with the protocol's [`DefaultValidator`](smithy4rs_core::serde::validation::DefaultValidator).
+//! 2. Passed to the operation's handler.
+//! 3. Serialized into a response from the handler's output or error.
+//!
+//! ```rust,ignore
+//! async fn get_city(input: GetCityInput) -> Result<GetCityOutput, NoSuchCity> {
+//!     // ...
+//! }
+//!
+//! let router = Router::new(RestJson1::new()).with(&GET_CITY, get_city)?;
+//! let response = router.call(&request).await;
+//! ```
+//!
+//! Requests that do not match an operation, or that fail to deserialize, are
+//! answered with a [`ServerError`] response. Inputs that fail validation are
+//! answered with the standard [`ValidationException`].
+//!
+//! ## Features
+//! - `axum`: Extractors and responses for using shapes in [`axum`](https://docs.rs/axum)
+//!   handlers without a [`Router`]. See [`axum::SmithyJson`].
+//! - `tower`: Adapters between operation handlers and [`tower`](https://docs.rs/tower)
+//!   services. See [`tower::OperationService`].
+#[cfg(feature = "axum")]
+pub mod axum;
+mod errors;
+mod handler;
+mod protocol;
+mod router;
+#[cfg(feature = "tower")]
+pub mod tower;
+mod validation;
+
+pub use errors::ServerError;
+pub use handler::{ErrorResponse, Handler};
+pub use protocol::ServerProtocol;
+pub use router::Router;
+pub use validation::{ValidationException, ValidationExceptionField};
+
+smithy4rs_core::assert_send_sync!(
+    ServerError,
+    ValidationException,
+    Router<smithy4rs_protocol_rest_json1::RestJson1>,
+);
@@ -0,0 +1,142 @@
+use http::{
+    HeaderValue, Request, Response,
+    header::{CONTENT_TYPE, HeaderName},
+};
+use smithy4rs_core::{
+    doc_map,
+    prelude::{DOCUMENT, HttpTrait},
+    schema::StaticSchemaShape,
+    serde::{
+        Buildable, Codec, CodecError, Protocol, ShapeBuilder, se::SerializeWithSchema,
+        validation::DefaultValidator,
+    },
+};
+use smithy4rs_json_codec::JsonCodec;
+use smithy4rs_protocol_rest_json1::{ERROR_TYPE_HEADER, RestJson1};
+
+use crate::{errors::ServerError, validation::ValidationException};
+
+/// A [`Protocol`] that can be used to serve operations over HTTP.
+///
+/// Server protocols read operation inputs from requests and write operation
+/// outputs and errors to responses. Deserialized inputs must be checked with
+/// the protocol's [`validator`](Protocol::validator) before they are returned.
+pub trait ServerProtocol: Protocol {
+    /// Deserialize, build, and validate an operation input from a request for an
+    /// operation with the provided `@http` trait.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the request could not be deserialized or if
+    /// the built input is invalid.
+    fn deserialize_input<S, B>(
+        &self,
+        http: &HttpTrait,
+        request: &Request<Vec<u8>>,
+    ) -> Result<S, CodecError>
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: for<'de> ShapeBuilder<'de, S>,
+    {
+        self.deserialize_input_with_validator(http, request, &mut self.validator())
+    }
+
+    /// Deserialize and build an operation input from a request, validating the
+    /// built input with `validator` instead of the protocol's validator.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the request could not be deserialized or if
+    /// the built input is invalid.
+    fn deserialize_input_with_validator<S, B>(
+        &self,
+        http: &HttpTrait,
+        request: &Request<Vec<u8>>,
+        validator: &mut DefaultValidator,
+    ) -> Result<S, CodecError>
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: for<'de> ShapeBuilder<'de, S>;
+
+    /// Serialize an operation output into a response for an operation with the
+    /// provided `@http` trait.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the output could not be serialized.
+    fn serialize_output<T>(
+        &self,
+        http: &HttpTrait,
+        output: &T,
+    ) -> Result<Response<Vec<u8>>, CodecError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema;
+
+    /// Serialize a modeled error into a response.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the error could not be serialized.
+    fn serialize_error<T>(&self, error: &T) -> Result<Response<Vec<u8>>, CodecError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema;
+
+    /// Serialize an error raised by the server runtime into a response.
+    ///
+    /// Inputs that fail validation should be answered with a
+    /// [`ValidationException`].
+    fn serialize_server_error(&self, error: &ServerError) -> Response<Vec<u8>>;
+}
+
+impl ServerProtocol for RestJson1 {
+    fn deserialize_input_with_validator<S, B>(
+        &self,
+        http: &HttpTrait,
+        request: &Request<Vec<u8>>,
+        validator: &mut DefaultValidator,
+    ) -> Result<S, CodecError>
+    where
+        S: for<'de> Buildable<'de, B>,
+        B: for<'de> ShapeBuilder<'de, S>,
+    {
+        self.deserialize_request_with_validator(http, request, validator)
+    }
+
+    fn serialize_output<T>(
+        &self,
+        http: &HttpTrait,
+        output: &T,
+    ) -> Result<Response<Vec<u8>>, CodecError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema,
+    {
+        self.serialize_response(http, output)
+    }
+
+    fn serialize_error<T>(&self, error: &T) -> Result<Response<Vec<u8>>, CodecError>
+    where
+        T: StaticSchemaShape + SerializeWithSchema,
+    {
+        RestJson1::serialize_error(self, error)
+    }
+
+    fn serialize_server_error(&self, error: &ServerError) -> Response<Vec<u8>> {
+        if let ServerError::Deserialization(CodecError::Validation(errors)) = error
+            && let Ok(response) = self.serialize_error(&ValidationException::from(errors))
+        {
+            return response;
+        }
+        // Serializing a single string member cannot fail, but an empty body is
+        // still a valid error response.
+        let body = JsonCodec
+            .serialize_document(&DOCUMENT, &doc_map!["message" => error.to_string()].into())
+            .unwrap_or_default();
+        let mut response = Response::new(body);
+        *response.status_mut() = error.status();
+        let headers = response.headers_mut();
+        if let Ok(content_type) = HeaderValue::from_str(self.content_type()) {
+            headers.insert(CONTENT_TYPE, content_type);
+        }
+        headers.insert(
+            HeaderName::from_static(ERROR_TYPE_HEADER),
+            HeaderValue::from_static(error.error_type()),
+        );
+        response
+    }
+}
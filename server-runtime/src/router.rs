@@ -0,0 +1,307 @@
+use std::{
+    cmp::Reverse,
+    fmt::{Debug, Formatter},
+    future::ready,
+    marker::PhantomData,
+    pin::Pin,
+};
+
+use http::{Request, Response};
+use smithy4rs_core::{
+    prelude::HttpTrait,
+    schema::{Operation, ShapeId, StaticSchemaShape},
+    serde::{
+        Buildable, ShapeBuilder,
+        se::SerializeWithSchema,
+        validation::{DefaultValidator, ValidatorConfig},
+    },
+};
+use smithy4rs_http_binding::{HttpBindingError, UriPattern};
+
+use crate::{
+    errors::ServerError,
+    handler::{ErrorResponse, Handler},
+    protocol::ServerProtocol,
+};
+
+type ResponseFuture<'a> = Pin<Box<dyn Future<Output = Response<Vec<u8>>> + Send + 'a>>;
+
+// ============================================================================
+// Router
+// ============================================================================
+
+/// Routes HTTP requests to the [`Handler`] registered for the matching operation.
+///
+/// Requests are matched to operations by the method and URI pattern of their
+/// `@http` trait. When more than one pattern matches a request, the pattern with
+/// the most literal segments is preferred, then patterns without a greedy label,
+/// then patterns with the most literal query parameters.
+///
+/// ```rust,ignore
+/// let router = Router::new(RestJson1::new())
+///     .with(&GET_CITY, get_city)?
+///     .with(&PUT_CITY, put_city)?;
+///
+/// let response = router.call(&request).await;
+/// ```
+///
+/// Inputs are validated with the protocol's [`validator`](smithy4rs_core::serde::Protocol::validator)
+/// unless a [`ValidatorConfig`] is set for their operation with [`Router::with_validator`].
+pub struct Router<P> {
+    protocol: P,
+    routes: Vec<Route<P>>,
+}
+
+struct Route<P> {
+    id: &'static ShapeId,
+    http: &'static HttpTrait,
+    pattern: UriPattern<'static>,
+    validator: Option<ValidatorConfig>,
+    endpoint: Box<dyn Endpoint<P>>,
+}
+
+impl<P: ServerProtocol> Router<P> {
+    /// Create a new router with no operations that serves requests using `protocol`.
+    #[must_use]
+    pub fn new(protocol: P) -> Self {
+        Router {
+            protocol,
+            routes: Vec::new(),
+        }
+    }
+
+    /// Register the handler for an operation.
+    ///
+    /// # Errors
+    /// Returns [`HttpBindingError`] if the operation has no `@http` trait or its
+    /// URI pattern is invalid.
+    pub fn register<I, B, O, E, H>(
+        &mut self,
+        operation: &Operation<I, O, E>,
+        handler: H,
+    ) -> Result<&mut Self, HttpBindingError>
+    where
+        I: for<'de> Buildable<'de, B> + Send + 'static,
+        B: for<'de> ShapeBuilder<'de, I> + 'static,
+        O: StaticSchemaShape + SerializeWithSchema + Send + 'static,
+        E: ErrorResponse + Send + 'static,
+        H: Handler<I, O, E>,
+    {
+        let http = operation
+            .schema()
+            .get_trait_as::<HttpTrait>()
+            .ok_or_else(|| HttpBindingError::MissingHttpTrait(operation.id().id().to_string()))?;
+        let pattern = UriPattern::parse(&http.uri)?;
+        self.routes.push(Route {
+            id: operation.id(),
+            http,
+            pattern,
+            validator: None,
+            endpoint: Box::new(OperationEndpoint {
+                handler,
+                _shapes: PhantomData,
+            }),
+        });
+        // Stable sort keeps registration order for equally specific patterns
+        self.routes.sort_by_key(|route| rank(&route.http.uri));
+        Ok(self)
+    }
+
+    /// Builder-style version of [`Router::register`].
+    ///
+    /// # Errors
+    /// Returns [`HttpBindingError`] if the operation has no `@http` trait or its
+    /// URI pattern is invalid.
+    pub fn with<I, B, O, E, H>(
+        mut self,
+        operation: &Operation<I, O, E>,
+        handler: H,
+    ) -> Result<Self, HttpBindingError>
+    where
+        I: for<'de> Buildable<'de, B> + Send + 'static,
+        B: for<'de> ShapeBuilder<'de, I> + 'static,
+        O: StaticSchemaShape + SerializeWithSchema + Send + 'static,
+        E: ErrorResponse + Send + 'static,
+        H: Handler<I, O, E>,
+    {
+        self.register(operation, handler)?;
+        Ok(self)
+    }
+
+    /// Validate the inputs of a registered operation with a validator created from
+    /// `config` instead of the protocol's validator.
+    ///
+    /// This can be used to relax or tighten validation for individual operations,
+    /// for example to skip `@pattern` checks for an operation that is only called
+    /// by trusted clients.
+    ///
+    /// # Errors
+    /// Returns [`HttpBindingError::UnregisteredOperation`] if no handler is registered
+    /// for the operation.
+    pub fn set_validator<I, O, E>(
+        &mut self,
+        operation: &Operation<I, O, E>,
+        config: ValidatorConfig,
+    ) -> Result<&mut Self, HttpBindingError> {
+        let route = self
+            .routes
+            .iter_mut()
+            .find(|route| route.id == operation.id())
+            .ok_or_else(|| {
+                HttpBindingError::UnregisteredOperation(operation.id().id().to_string())
+            })?;
+        route.validator = Some(config);
+        Ok(self)
+    }
+
+    /// Builder-style version of [`Router::set_validator`].
+    ///
+    /// # Errors
+    /// Returns [`HttpBindingError::UnregisteredOperation`] if no handler is registered
+    /// for the operation.
+    pub fn with_validator<I, O, E>(
+        mut self,
+        operation: &Operation<I, O, E>,
+        config: ValidatorConfig,
+    ) -> Result<Self, HttpBindingError> {
+        self.set_validator(operation, config)?;
+        Ok(self)
+    }
+
+    /// Get the protocol used to serve requests.
+    #[must_use]
+    pub const fn protocol(&self) -> &P {
+        &self.protocol
+    }
+
+    /// Get the [`ShapeId`] of the operation a request is routed to, if any.
+    #[must_use]
+    pub fn resolve<T>(&self, request: &Request<T>) -> Option<&'static ShapeId> {
+        self.route(request).map(|route| route.id)
+    }
+
+    /// Handle a request with the handler of the matching operation.
+    ///
+    /// The request is deserialized into the operation input and validated before
+    /// the handler is invoked. The handler's output or error is then serialized
+    /// into the returned response. Requests that do not match an operation, or
+    /// that fail to deserialize, are answered with a [`ServerError`] response.
+    pub async fn call(&self, request: &Request<Vec<u8>>) -> Response<Vec<u8>> {
+        match self.route(request) {
+            Some(route) => {
+                route
+                    .endpoint
+                    .call(
+                        &self.protocol,
+                        route.http,
+                        route.validator.as_ref(),
+                        request,
+                    )
+                    .await
+            }
+            None => self
+                .protocol
+                .serialize_server_error(&ServerError::UnknownOperation {
+                    method: request.method().to_string(),
+                    path: request.uri().path().to_string(),
+                }),
+        }
+    }
+
+    fn route<T>(&self, request: &Request<T>) -> Option<&Route<P>> {
+        let uri = request.uri();
+        self.routes.iter().find(|route| {
+            request.method().as_str() == route.http.method
+                && route.pattern.match_path(uri.path()).is_some()
+                && route.pattern.match_query(uri.query())
+        })
+    }
+}
+
+impl<P> Debug for Router<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.routes.iter().map(|route| route.id))
+            .finish()
+    }
+}
+
+/// Sort key that orders more specific URI patterns first.
+fn rank(uri: &str) -> (Reverse<usize>, bool, Reverse<usize>) {
+    let (path, query) = uri.split_once('?').unwrap_or((uri, ""));
+    let literals = path
+        .split('/')
+        .filter(|segment| !segment.is_empty() && !segment.starts_with('{'))
+        .count();
+    let greedy = path.contains("+}");
+    let query = query.split('&').filter(|param| !param.is_empty()).count();
+    (Reverse(literals), greedy, Reverse(query))
+}
+
+// ============================================================================
+// Endpoints
+// ============================================================================
+
+/// Type-erased handler for a single operation.
+trait Endpoint<P>: Send + Sync {
+    fn call<'a>(
+        &'a self,
+        protocol: &'a P,
+        http: &'a HttpTrait,
+        validator: Option<&ValidatorConfig>,
+        request: &Request<Vec<u8>>,
+    ) -> ResponseFuture<'a>;
+}
+
+struct OperationEndpoint<I, B, O, E, H> {
+    handler: H,
+    _shapes: PhantomData<EndpointShapes<I, B, O, E>>,
+}
+
+// `fn() -> T` keeps the endpoint `Send + Sync` regardless of `T`
+type EndpointShapes<I, B, O, E> = fn() -> (I, B, O, E);
+
+impl<P, I, B, O, E, H> Endpoint<P> for OperationEndpoint<I, B, O, E, H>
+where
+    P: ServerProtocol,
+    I: for<'de> Buildable<'de, B> + Send + 'static,
+    B: for<'de> ShapeBuilder<'de, I> + 'static,
+    O: StaticSchemaShape + SerializeWithSchema + Send + 'static,
+    E: ErrorResponse + Send + 'static,
+    H: Handler<I, O, E>,
+{
+    fn call<'a>(
+        &'a self,
+        protocol: &'a P,
+        http: &'a HttpTrait,
+        validator: Option<&ValidatorConfig>,
+        request: &Request<Vec<u8>>,
+    ) -> ResponseFuture<'a> {
+        let input = validator.map_or_else(
+            || protocol.deserialize_input::<I, B>(http, request),
+            |config| {
+                protocol.deserialize_input_with_validator::<I, B>(
+                    http,
+                    request,
+                    &mut DefaultValidator::with_config(*config),
+                )
+            },
+        );
+        let input = match input {
+            Ok(input) => input,
+            Err(error) => {
+                let error = ServerError::Deserialization(error);
+                return Box::pin(ready(protocol.serialize_server_error(&error)));
+            }
+        };
+        Box::pin(async move {
+            let result = match self.handler.handle(input).await {
+                Ok(output) => protocol.serialize_output(http, &output),
+                Err(error) => error.serialize_with(protocol),
+            };
+            result.unwrap_or_else(|error| {
+                protocol.serialize_server_error(&ServerError::Serialization(error))
+            })
+        })
+    }
+}
@@ -0,0 +1,142 @@
+//! Integration with [`tower`](https://docs.rs/tower) services.
+//!
+//! [`OperationService`] exposes the [`Handler`] of an operation as a
+//! [`Service`] from the operation input to its output, so that existing `tower`
+//! middleware (retries, buffering, rate limits, ...) can wrap it:
+//!
+//! ```rust,ignore
+//! let service = ServiceBuilder::new()
+//!     .concurrency_limit(64)
+//!     .service(OperationService::new(&GET_CITY, get_city));
+//! ```
+//!
+//! In the other direction, [`ServiceHandler`] lets a (possibly layered) service
+//! be registered as the handler of an operation in a [`Router`](crate::Router):
+//!
+//! ```rust,ignore
+//! let router = Router::new(RestJson1::new())
+//!     .with(&GET_CITY, ServiceHandler::new(service))?;
+//! ```
+
+use std::{
+    fmt::{Debug, Formatter},
+    future::poll_fn,
+    marker::PhantomData,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use smithy4rs_core::schema::Operation;
+use tower_service::Service;
+
+use crate::Handler;
+
+type HandlerFuture<O, E> = Pin<Box<dyn Future<Output = Result<O, E>> + Send>>;
+
+// ============================================================================
+// Handler -> Service
+// ============================================================================
+
+/// A [`Service`] that calls the [`Handler`] of an operation with input `I`,
+/// output `O`, and errors `E`.
+///
+/// The service is always ready, and cloning it shares the same handler.
+pub struct OperationService<H, I, O, E> {
+    handler: Arc<H>,
+    _shapes: PhantomData<fn(I) -> (O, E)>,
+}
+
+impl<H, I, O, E> OperationService<H, I, O, E>
+where
+    H: Handler<I, O, E>,
+{
+    /// Create a service that calls `handler` for `operation`.
+    #[must_use]
+    pub fn new(_operation: &Operation<I, O, E>, handler: H) -> Self {
+        OperationService {
+            handler: Arc::new(handler),
+            _shapes: PhantomData,
+        }
+    }
+
+    /// Get the handler called by this service.
+    #[must_use]
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+}
+
+impl<H, I, O, E> Clone for OperationService<H, I, O, E> {
+    fn clone(&self) -> Self {
+        OperationService {
+            handler: Arc::clone(&self.handler),
+            _shapes: PhantomData,
+        }
+    }
+}
+
+impl<H, I, O, E> Debug for OperationService<H, I, O, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OperationService").finish_non_exhaustive()
+    }
+}
+
+impl<H, I, O, E> Service<I> for OperationService<H, I, O, E>
+where
+    H: Handler<I, O, E>,
+    I: Send + 'static,
+{
+    type Response = O;
+    type Error = E;
+    type Future = HandlerFuture<O, E>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), E>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, input: I) -> Self::Future {
+        let handler = Arc::clone(&self.handler);
+        Box::pin(async move { handler.handle(input).await })
+    }
+}
+
+// ============================================================================
+// Service -> Handler
+// ============================================================================
+
+/// A [`Handler`] that calls a [`Service`] from the operation input to its output.
+///
+/// Each request is handled by a clone of the service, which is driven to
+/// readiness before it is called.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ServiceHandler<S>(pub S);
+
+impl<S> ServiceHandler<S> {
+    /// Create a handler that calls `service`.
+    #[must_use]
+    pub const fn new(service: S) -> Self {
+        ServiceHandler(service)
+    }
+
+    /// Get the wrapped service.
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<I, O, E, S> Handler<I, O, E> for ServiceHandler<S>
+where
+    S: Service<I, Response = O, Error = E> + Clone + Send + Sync + 'static,
+    S::Future: Send,
+    I: Send,
+{
+    fn handle(&self, input: I) -> impl Future<Output = Result<O, E>> + Send {
+        let mut service = self.0.clone();
+        async move {
+            poll_fn(|cx| service.poll_ready(cx)).await?;
+            service.call(input).await
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt::Write;
+
+use smithy4rs_core::{
+    derive::SmithyShape,
+    prelude::{ErrorTrait, RequiredTrait, STRING},
+    serde::validation::ValidationErrors,
+    smithy,
+};
+
+smithy!("smithy.framework#ValidationExceptionField": {
+    /// Schema of the standard `smithy.framework#ValidationExceptionField` shape
+    structure VALIDATION_EXCEPTION_FIELD_SCHEMA {
+        @RequiredTrait::builder().build();
+        PATH: STRING = "path"
+        @RequiredTrait::builder().build();
+        MESSAGE: STRING = "message"
+    }
+});
+
+smithy!("smithy.framework#ValidationExceptionFieldList": {
+    /// Schema of the standard `smithy.framework#ValidationExceptionFieldList` shape
+    list VALIDATION_EXCEPTION_FIELD_LIST_SCHEMA {
+        member: VALIDATION_EXCEPTION_FIELD_SCHEMA
+    }
+});
+
+smithy!("smithy.framework#ValidationException": {
+    /// Schema of the standard `smithy.framework#ValidationException` shape
+    @ErrorTrait::Client;
+    structure VALIDATION_EXCEPTION_SCHEMA {
+        @RequiredTrait::builder().build();
+        MESSAGE: STRING = "message"
+        FIELD_LIST: VALIDATION_EXCEPTION_FIELD_LIST_SCHEMA = "fieldList"
+    }
+});
+
+/// A single invalid value in a [`ValidationException`].
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(VALIDATION_EXCEPTION_FIELD_SCHEMA)]
+pub struct ValidationExceptionField {
+    /// JSON Pointer to the invalid value within the operation input.
+    #[smithy_schema(PATH)]
+    pub path: String,
+    /// Description of the constraint the value failed to satisfy.
+    #[smithy_schema(MESSAGE)]
+    pub message: String,
+}
+
+/// The standard [`smithy.framework#ValidationException`](https://smithy.io/2.0/additional-specs/constraint-traits.html)
+/// error returned when an operation input fails constraint validation.
+///
+/// A [`ValidationException`] can be created from the [`ValidationErrors`] raised
+/// while validating an input:
+///
+/// ```rust,ignore
+/// let exception = ValidationException::from(&errors);
+/// let response = protocol.serialize_error(&exception)?;
+/// ```
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(VALIDATION_EXCEPTION_SCHEMA)]
+pub struct ValidationException {
+    /// Summary of all validation failures.
+    #[smithy_schema(MESSAGE)]
+    pub message: String,
+    /// Each invalid value and the constraint it failed to satisfy.
+    #[smithy_schema(FIELD_LIST)]
+    pub field_list: Option<Vec<ValidationExceptionField>>,
+}
+
+impl From<&ValidationErrors> for ValidationException {
+    fn from(errors: &ValidationErrors) -> Self {
+        let field_list: Vec<_> = errors
+            .errors()
+            .iter()
+            .map(|error| {
+                let path = error.json_pointer();
+                let message = format!(
+                    "Value at '{path}' failed to satisfy constraint: {}",
+                    error.error()
+                );
+                ValidationExceptionField { path, message }
+            })
+            .collect();
+        let mut message = match field_list.len() {
+            1 => "1 validation error detected.".to_string(),
+            count => format!("{count} validation errors detected."),
+        };
+        for field in &field_list {
+            let _ = write!(message, " {}", field.message);
+        }
+        ValidationException {
+            message,
+            field_list: Some(field_list),
+        }
+    }
+}
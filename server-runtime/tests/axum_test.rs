@@ -0,0 +1,118 @@
+#![cfg(feature = "axum")]
+
+use axum::{
+    body::{Body, to_bytes},
+    extract::{FromRequest, Request},
+    response::{IntoResponse, Response},
+};
+use http::{StatusCode, header::CONTENT_TYPE};
+use smithy4rs_core::{
+    derive::SmithyShape,
+    prelude::{ErrorTrait, HttpErrorTrait, INTEGER, LengthTrait, RequiredTrait, STRING},
+    smithy,
+};
+use smithy4rs_protocol_rest_json1::ERROR_TYPE_HEADER;
+use smithy4rs_server_runtime::axum::{SmithyError, SmithyJson, SmithyRejection};
+use smithy4rs_test_utils::ready;
+
+smithy!("com.example#CreateCityInput": {
+    structure CREATE_CITY_INPUT_SCHEMA {
+        @RequiredTrait::builder().build();
+        @LengthTrait::builder().min(3i64).build();
+        NAME: STRING = "name"
+        POPULATION: INTEGER = "population"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(CREATE_CITY_INPUT_SCHEMA)]
+pub struct CreateCityInput {
+    #[smithy_schema(NAME)]
+    pub name: String,
+    #[smithy_schema(POPULATION)]
+    pub population: Option<i32>,
+}
+
+smithy!("com.example#CityExists": {
+    @ErrorTrait::Client;
+    @HttpErrorTrait::new(409);
+    structure CITY_EXISTS_SCHEMA {
+        MESSAGE: STRING = "message"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(CITY_EXISTS_SCHEMA)]
+pub struct CityExists {
+    #[smithy_schema(MESSAGE)]
+    pub message: Option<String>,
+}
+
+fn extract(body: &str) -> Result<SmithyJson<CreateCityInput>, SmithyRejection> {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/cities")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    ready(SmithyJson::<CreateCityInput>::from_request(request, &()))
+}
+
+fn body(response: Response) -> String {
+    let bytes = ready(to_bytes(response.into_body(), usize::MAX)).unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn test_extracts_valid_input() {
+    let SmithyJson(input) = extract(r#"{"name":"Seattle","population":750000}"#).unwrap();
+    assert_eq!(input.name, "Seattle");
+    assert_eq!(input.population, Some(750_000));
+}
+
+#[test]
+fn test_rejects_invalid_input() {
+    let response = extract(r#"{"name":"NY"}"#).unwrap_err().into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(response.headers()[ERROR_TYPE_HEADER], "ValidationException");
+    assert!(body(response).contains("/name"));
+}
+
+#[test]
+fn test_rejects_missing_required_member() {
+    let response = extract("").unwrap_err().into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(response.headers()[ERROR_TYPE_HEADER], "ValidationException");
+}
+
+#[test]
+fn test_rejects_malformed_json() {
+    let response = extract("{").unwrap_err().into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response.headers()[ERROR_TYPE_HEADER],
+        "SerializationException"
+    );
+}
+
+#[test]
+fn test_responds_with_shape() {
+    let response = SmithyJson(CreateCityInput {
+        name: "Seattle".to_string(),
+        population: None,
+    })
+    .into_response();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/json");
+    assert_eq!(body(response), r#"{"name":"Seattle"}"#);
+}
+
+#[test]
+fn test_responds_with_modeled_error() {
+    let response = SmithyError(CityExists {
+        message: Some("Seattle already exists".to_string()),
+    })
+    .into_response();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(response.headers()[ERROR_TYPE_HEADER], "CityExists");
+    assert_eq!(body(response), r#"{"message":"Seattle already exists"}"#);
+}
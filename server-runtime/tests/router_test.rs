@@ -0,0 +1,377 @@
+use http::{Request, Response, StatusCode, header::CONTENT_TYPE};
+use smithy4rs_core::{
+    derive::{SchemaShape, SmithyShape},
+    prelude::{
+        ErrorTrait, HttpErrorTrait, HttpLabelTrait, HttpTrait, INTEGER, LengthTrait, PatternTrait,
+        RequiredTrait, STRING,
+    },
+    schema::{Operation, ShapeId, Unit},
+    serde::{CodecError, Protocol, validation::ValidatorConfig},
+    smithy,
+};
+use smithy4rs_http_binding::HttpBindingError;
+use smithy4rs_protocol_rest_json1::{ERROR_TYPE_HEADER, RestJson1};
+use smithy4rs_server_runtime::{ErrorResponse, Router, ServerProtocol, ValidationException};
+use smithy4rs_test_utils::ready;
+
+smithy!("com.example#GetCityInput": {
+    structure GET_CITY_INPUT_SCHEMA {
+        @HttpLabelTrait::builder().build();
+        @RequiredTrait::builder().build();
+        @LengthTrait::builder().min(3i64).build();
+        CITY: STRING = "city"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_CITY_INPUT_SCHEMA)]
+pub struct GetCityInput {
+    #[smithy_schema(CITY)]
+    pub city: String,
+}
+
+smithy!("com.example#PutCityInput": {
+    structure PUT_CITY_INPUT_SCHEMA {
+        @HttpLabelTrait::builder().build();
+        @RequiredTrait::builder().build();
+        CITY: STRING = "city"
+        POPULATION: INTEGER = "population"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(PUT_CITY_INPUT_SCHEMA)]
+pub struct PutCityInput {
+    #[smithy_schema(CITY)]
+    pub city: String,
+    #[smithy_schema(POPULATION)]
+    pub population: Option<i32>,
+}
+
+smithy!("com.example#TagList": {
+    list TAG_LIST_SCHEMA {
+        @LengthTrait::builder().min(1i64).build();
+        member: STRING
+    }
+});
+
+smithy!("com.example#TagCityInput": {
+    structure TAG_CITY_INPUT_SCHEMA {
+        @HttpLabelTrait::builder().build();
+        @RequiredTrait::builder().build();
+        @PatternTrait::new("^[A-Z][a-z]*$");
+        CITY: STRING = "city"
+        TAGS: TAG_LIST_SCHEMA = "tags"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(TAG_CITY_INPUT_SCHEMA)]
+pub struct TagCityInput {
+    #[smithy_schema(CITY)]
+    pub city: String,
+    #[smithy_schema(TAGS)]
+    pub tags: Option<Vec<String>>,
+}
+
+smithy!("com.example#CityOutput": {
+    structure CITY_OUTPUT_SCHEMA {
+        @RequiredTrait::builder().build();
+        NAME: STRING = "name"
+        POPULATION: INTEGER = "population"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(CITY_OUTPUT_SCHEMA)]
+pub struct CityOutput {
+    #[smithy_schema(NAME)]
+    pub name: String,
+    #[smithy_schema(POPULATION)]
+    pub population: Option<i32>,
+}
+
+smithy!("com.example#NoSuchCity": {
+    @ErrorTrait::Client;
+    @HttpErrorTrait::new(404);
+    structure NO_SUCH_CITY_SCHEMA {
+        MESSAGE: STRING = "message"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(NO_SUCH_CITY_SCHEMA)]
+pub struct NoSuchCity {
+    #[smithy_schema(MESSAGE)]
+    pub message: Option<String>,
+}
+
+smithy!("smithy.synthetic#GetCityError": {
+    union GET_CITY_ERROR_SCHEMA {
+        NO_SUCH_CITY: NO_SUCH_CITY_SCHEMA = "NoSuchCity"
+    }
+});
+
+#[derive(SchemaShape)]
+#[smithy_schema(GET_CITY_ERROR_SCHEMA)]
+pub enum GetCityError {
+    #[smithy_schema(NO_SUCH_CITY)]
+    NoSuchCity(NoSuchCity),
+}
+
+impl ErrorResponse for GetCityError {
+    fn serialize_with<P: ServerProtocol>(
+        &self,
+        protocol: &P,
+    ) -> Result<Response<Vec<u8>>, CodecError> {
+        match self {
+            GetCityError::NoSuchCity(error) => protocol.serialize_error(error),
+        }
+    }
+}
+
+smithy!("com.example#GetCity": {
+    @HttpTrait::builder().method("GET".to_string()).uri("/cities/{city}".to_string()).build().unwrap();
+    operation GET_CITY_SCHEMA
+});
+
+smithy!("com.example#GetCapital": {
+    @HttpTrait::builder().method("GET".to_string()).uri("/cities/capital".to_string()).build().unwrap();
+    operation GET_CAPITAL_SCHEMA
+});
+
+smithy!("com.example#PutCity": {
+    @HttpTrait::builder().method("PUT".to_string()).uri("/cities/{city}".to_string()).code(201).build().unwrap();
+    operation PUT_CITY_SCHEMA
+});
+
+smithy!("com.example#TagCity": {
+    @HttpTrait::builder().method("POST".to_string()).uri("/cities/{city}/tags".to_string()).build().unwrap();
+    operation TAG_CITY_SCHEMA
+});
+
+smithy!("com.example#Ping": {
+    operation PING_SCHEMA
+});
+
+static GET_CITY: Operation<GetCityInput, CityOutput, GetCityError> =
+    Operation::new(&GET_CITY_SCHEMA);
+static GET_CAPITAL: Operation<Unit, CityOutput, Unit> = Operation::new(&GET_CAPITAL_SCHEMA);
+static PUT_CITY: Operation<PutCityInput, CityOutput, Unit> = Operation::new(&PUT_CITY_SCHEMA);
+static TAG_CITY: Operation<TagCityInput, CityOutput, Unit> = Operation::new(&TAG_CITY_SCHEMA);
+static PING: Operation<Unit, Unit, Unit> = Operation::new(&PING_SCHEMA);
+
+async fn get_city(input: GetCityInput) -> Result<CityOutput, GetCityError> {
+    if input.city == "Atlantis" {
+        return Err(GetCityError::NoSuchCity(NoSuchCity {
+            message: Some("Atlantis was not found".to_string()),
+        }));
+    }
+    Ok(CityOutput {
+        name: input.city,
+        population: None,
+    })
+}
+
+fn router() -> Router<RestJson1> {
+    Router::new(RestJson1::new())
+        .with(&GET_CITY, get_city)
+        .unwrap()
+        .with(&GET_CAPITAL, |_: Unit| async {
+            Ok::<_, Unit>(CityOutput {
+                name: "Olympia".to_string(),
+                population: Some(55_000),
+            })
+        })
+        .unwrap()
+        .with(&PUT_CITY, |input: PutCityInput| async move {
+            Ok::<_, Unit>(CityOutput {
+                name: input.city,
+                population: input.population,
+            })
+        })
+        .unwrap()
+}
+
+fn tag_router() -> Router<RestJson1> {
+    Router::new(RestJson1::new())
+        .with(&TAG_CITY, |input: TagCityInput| async move {
+            Ok::<_, Unit>(CityOutput {
+                name: input.city,
+                population: None,
+            })
+        })
+        .unwrap()
+}
+
+fn request(method: &str, uri: &str, body: &str) -> Request<Vec<u8>> {
+    Request::builder()
+        .method(method)
+        .uri(uri)
+        .body(body.as_bytes().to_vec())
+        .unwrap()
+}
+
+fn call(router: &Router<RestJson1>, request: &Request<Vec<u8>>) -> Response<Vec<u8>> {
+    ready(router.call(request))
+}
+
+fn body(response: &Response<Vec<u8>>) -> &str {
+    std::str::from_utf8(response.body()).unwrap()
+}
+
+fn error_type(response: &Response<Vec<u8>>) -> &str {
+    response.headers()[ERROR_TYPE_HEADER].to_str().unwrap()
+}
+
+#[test]
+fn test_routes_request_to_handler() {
+    let response = call(&router(), &request("GET", "/cities/Seattle", ""));
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/json");
+    assert_eq!(body(&response), r#"{"name":"Seattle"}"#);
+}
+
+#[test]
+fn test_routes_by_method() {
+    let response = call(
+        &router(),
+        &request("PUT", "/cities/Tacoma", r#"{"population":220000}"#),
+    );
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(body(&response), r#"{"name":"Tacoma","population":220000}"#);
+}
+
+#[test]
+fn test_prefers_literal_segments() {
+    let router = router();
+    let capital = request("GET", "/cities/capital", "");
+    assert_eq!(
+        router.resolve(&capital),
+        Some(&ShapeId::from("com.example#GetCapital"))
+    );
+    assert_eq!(
+        router.resolve(&request("GET", "/cities/Spokane", "")),
+        Some(&ShapeId::from("com.example#GetCity"))
+    );
+    assert_eq!(
+        body(&call(&router, &capital)),
+        r#"{"name":"Olympia","population":55000}"#
+    );
+}
+
+#[test]
+fn test_serializes_modeled_error() {
+    let response = call(&router(), &request("GET", "/cities/Atlantis", ""));
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(error_type(&response), "NoSuchCity");
+    assert_eq!(body(&response), r#"{"message":"Atlantis was not found"}"#);
+}
+
+#[test]
+fn test_rejects_unknown_operation() {
+    let router = router();
+    for request in [
+        request("GET", "/towns/Seattle", ""),
+        request("DELETE", "/cities/Seattle", ""),
+    ] {
+        assert_eq!(router.resolve(&request), None);
+        let response = call(&router, &request);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(error_type(&response), "UnknownOperationException");
+    }
+}
+
+#[test]
+fn test_rejects_invalid_input() {
+    let router = router();
+    let response = call(&router, &request("GET", "/cities/NY", ""));
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(error_type(&response), "ValidationException");
+    let exception: ValidationException = router
+        .protocol()
+        .codec()
+        .deserialize_shape(response.body())
+        .unwrap();
+    let fields = exception.field_list.unwrap();
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0].path, "/city");
+    assert!(
+        exception
+            .message
+            .starts_with("1 validation error detected.")
+    );
+    assert!(exception.message.contains(&fields[0].message));
+}
+
+#[test]
+fn test_rejects_malformed_body() {
+    let response = call(&router(), &request("PUT", "/cities/Tacoma", "{"));
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(error_type(&response), "SerializationException");
+}
+
+#[test]
+fn test_validator_override_skips_patterns() {
+    let request = request("POST", "/cities/seattle/tags", "");
+    let response = call(&tag_router(), &request);
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(error_type(&response), "ValidationException");
+
+    let router = tag_router()
+        .with_validator(
+            &TAG_CITY,
+            ValidatorConfig {
+                check_patterns: false,
+                ..ValidatorConfig::default()
+            },
+        )
+        .unwrap();
+    let response = call(&router, &request);
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body(&response), r#"{"name":"seattle"}"#);
+}
+
+#[test]
+fn test_validator_override_limits_depth() {
+    let request = request("POST", "/cities/Seattle/tags", r#"{"tags":["coffee"]}"#);
+    let response = call(&tag_router(), &request);
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let router = tag_router()
+        .with_validator(
+            &TAG_CITY,
+            ValidatorConfig {
+                max_depth: 1,
+                ..ValidatorConfig::default()
+            },
+        )
+        .unwrap();
+    let response = call(&router, &request);
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(error_type(&response), "ValidationException");
+    let exception: ValidationException = router
+        .protocol()
+        .codec()
+        .deserialize_shape(response.body())
+        .unwrap();
+    assert_eq!(exception.field_list.unwrap()[0].path, "/tags");
+}
+
+#[test]
+fn test_validator_override_requires_registered_operation() {
+    let result = tag_router().with_validator(&GET_CITY, ValidatorConfig::default());
+    assert!(matches!(
+        result,
+        Err(HttpBindingError::UnregisteredOperation(id)) if id == "com.example#GetCity"
+    ));
+}
+
+#[test]
+fn test_requires_http_trait() {
+    let result = Router::new(RestJson1::new()).with(&PING, |_: Unit| async { Ok::<_, Unit>(Unit) });
+    assert!(matches!(
+        result,
+        Err(HttpBindingError::MissingHttpTrait(id)) if id == "com.example#Ping"
+    ));
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "tower")]
+
+use std::{
+    pin::pin,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    task::{Context, Poll},
+};
+
+use http::{Request, StatusCode};
+use smithy4rs_core::{
+    derive::SmithyShape,
+    prelude::{ErrorTrait, HttpErrorTrait, HttpLabelTrait, HttpTrait, RequiredTrait, STRING},
+    schema::Operation,
+    smithy,
+};
+use smithy4rs_protocol_rest_json1::RestJson1;
+use smithy4rs_server_runtime::{
+    Router,
+    tower::{OperationService, ServiceHandler},
+};
+use smithy4rs_test_utils::{noop_context, ready};
+use tower_service::Service;
+
+smithy!("com.example#GetCityInput": {
+    structure GET_CITY_INPUT_SCHEMA {
+        @HttpLabelTrait::builder().build();
+        @RequiredTrait::builder().build();
+        CITY: STRING = "city"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_CITY_INPUT_SCHEMA)]
+pub struct GetCityInput {
+    #[smithy_schema(CITY)]
+    pub city: String,
+}
+
+smithy!("com.example#GetCityOutput": {
+    structure GET_CITY_OUTPUT_SCHEMA {
+        @RequiredTrait::builder().build();
+        NAME: STRING = "name"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(GET_CITY_OUTPUT_SCHEMA)]
+pub struct GetCityOutput {
+    #[smithy_schema(NAME)]
+    pub name: String,
+}
+
+smithy!("com.example#NoSuchCity": {
+    @ErrorTrait::Client;
+    @HttpErrorTrait::new(404);
+    structure NO_SUCH_CITY_SCHEMA {
+        MESSAGE: STRING = "message"
+    }
+});
+
+#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_schema(NO_SUCH_CITY_SCHEMA)]
+pub struct NoSuchCity {
+    #[smithy_schema(MESSAGE)]
+    pub message: Option<String>,
+}
+
+smithy!("com.example#GetCity": {
+    @HttpTrait::builder().method("GET".to_string()).uri("/cities/{city}".to_string()).build().unwrap();
+    operation GET_CITY_SCHEMA
+});
+
+static GET_CITY: Operation<GetCityInput, GetCityOutput, NoSuchCity> =
+    Operation::new(&GET_CITY_SCHEMA);
+
+async fn get_city(input: GetCityInput) -> Result<GetCityOutput, NoSuchCity> {
+    if input.city == "Atlantis" {
+        return Err(NoSuchCity {
+            message: Some("Atlantis was not found".to_string()),
+        });
+    }
+    Ok(GetCityOutput { name: input.city })
+}
+
+fn input(city: &str) -> GetCityInput {
+    GetCityInput {
+        city: city.to_string(),
+    }
+}
+
+/// Middleware that counts calls and is only ready on every other poll.
+#[derive(Clone)]
+struct Counting<S> {
+    inner: S,
+    polls: Arc<AtomicUsize>,
+    calls: Arc<AtomicUsize>,
+}
+
+impl<S: Service<GetCityInput>> Service<GetCityInput> for Counting<S> {
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.polls.fetch_add(1, Ordering::SeqCst).is_multiple_of(2) {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, input: GetCityInput) -> Self::Future {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.inner.call(input)
+    }
+}
+
+#[test]
+fn test_operation_service_calls_handler() {
+    let mut service = OperationService::new(&GET_CITY, get_city);
+    assert!(matches!(
+        service.poll_ready(&mut noop_context()),
+        Poll::Ready(Ok(()))
+    ));
+
+    let output = ready(service.call(input("Seattle"))).unwrap();
+    assert_eq!(output.name, "Seattle");
+
+    let error = ready(service.clone().call(input("Atlantis"))).unwrap_err();
+    assert_eq!(error.message.as_deref(), Some("Atlantis was not found"));
+}
+
+#[test]
+fn test_service_handler_drives_readiness() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let service = Counting {
+        inner: OperationService::new(&GET_CITY, get_city),
+        polls: Arc::new(AtomicUsize::new(0)),
+        calls: Arc::clone(&calls),
+    };
+    let router = Router::new(RestJson1::new())
+        .with(&GET_CITY, ServiceHandler::new(service))
+        .unwrap();
+
+    let request = Request::builder()
+        .method("GET")
+        .uri("/cities/Seattle")
+        .body(Vec::new())
+        .unwrap();
+    let mut cx = noop_context();
+    let mut call = pin!(router.call(&request));
+    assert!(call.as_mut().poll(&mut cx).is_pending());
+    let Poll::Ready(response) = call.poll(&mut cx) else {
+        panic!("expected service to be ready");
+    };
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.body(), br#"{"name":"Seattle"}"#);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_service_handler_returns_modeled_errors() {
+    let router = Router::new(RestJson1::new())
+        .with(
+            &GET_CITY,
+            ServiceHandler::new(OperationService::new(&GET_CITY, get_city)),
+        )
+        .unwrap();
+    let request = Request::builder()
+        .method("GET")
+        .uri("/cities/Atlantis")
+        .body(Vec::new())
+        .unwrap();
+    let response = ready(router.call(&request));
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -1,8 +1,10 @@
 use smithy4rs_core::{
-    ByteBuffer, Instant,
-    derive::SmithyShape,
-    schema::prelude::{
-        BLOB, BOOLEAN, BYTE, DOUBLE, FLOAT, INTEGER, LONG, SHORT, STRING, TIMESTAMP,
+    Instant,
+    derive::{SmithyShape, smithy_structure},
+    prelude::TimestampFormatTrait,
+    schema::{
+        Blob,
+        prelude::{BLOB, BOOLEAN, BYTE, DOUBLE, FLOAT, INTEGER, LONG, SHORT, STRING, TIMESTAMP},
     },
     smithy,
 };
@@ -28,7 +30,8 @@ smithy!("test#AllPrimitivesStruct": {
     }
 });
 
-#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_structure]
+#[derive(SmithyShape)]
 #[smithy_schema(ALL_PRIMITIVES_STRUCT_SCHEMA)]
 pub struct AllPrimitivesStruct {
     #[smithy_schema(STRING)]
@@ -48,7 +51,7 @@ pub struct AllPrimitivesStruct {
     #[smithy_schema(BOOLEAN)]
     pub boolean_field: bool,
     #[smithy_schema(BLOB)]
-    pub blob_field: ByteBuffer,
+    pub blob_field: Blob,
     #[smithy_schema(TIMESTAMP)]
     pub timestamp_field: Instant,
 }
@@ -60,7 +63,8 @@ smithy!("test#OptionalFieldsStruct": {
     }
 });
 
-#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_structure]
+#[derive(SmithyShape)]
 #[smithy_schema(OPTIONAL_FIELDS_STRUCT_SCHEMA)]
 pub struct OptionalFieldsStruct {
     #[smithy_schema(REQUIRED)]
@@ -80,7 +84,8 @@ smithy!("test#NumericTypesStruct": {
     }
 });
 
-#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_structure]
+#[derive(SmithyShape)]
 #[smithy_schema(NUMERIC_TYPES_STRUCT_SCHEMA)]
 pub struct NumericTypesStruct {
     #[smithy_schema(BYTE)]
@@ -104,7 +109,8 @@ smithy!("test#SimpleStruct": {
     }
 });
 
-#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_structure]
+#[derive(SmithyShape)]
 #[smithy_schema(SIMPLE_STRUCT_SCHEMA)]
 pub struct SimpleStruct {
     #[smithy_schema(A)]
@@ -112,3 +118,29 @@ pub struct SimpleStruct {
     #[smithy_schema(B)]
     pub field_b: i32,
 }
+
+smithy!("test#TimestampFormatsStruct": {
+    structure TIMESTAMP_FORMATS_STRUCT_SCHEMA {
+        DEFAULT: TIMESTAMP = "default"
+        @TimestampFormatTrait::EpochSeconds;
+        EPOCH_SECONDS: TIMESTAMP = "epoch_seconds"
+        @TimestampFormatTrait::DateTime;
+        DATE_TIME: TIMESTAMP = "date_time"
+        @TimestampFormatTrait::HttpDate;
+        HTTP_DATE: TIMESTAMP = "http_date"
+    }
+});
+
+#[smithy_structure]
+#[derive(SmithyShape)]
+#[smithy_schema(TIMESTAMP_FORMATS_STRUCT_SCHEMA)]
+pub struct TimestampFormatsStruct {
+    #[smithy_schema(DEFAULT)]
+    pub default: Instant,
+    #[smithy_schema(EPOCH_SECONDS)]
+    pub epoch_seconds: Instant,
+    #[smithy_schema(DATE_TIME)]
+    pub date_time: Instant,
+    #[smithy_schema(HTTP_DATE)]
+    pub http_date: Option<Instant>,
+}
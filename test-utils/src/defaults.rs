@@ -1,6 +1,12 @@
 #![allow(dead_code)]
 
-use smithy4rs_core::{ByteBuffer, Instant, derive::SmithyShape, prelude::*, smithy};
+use smithy4rs_core::{
+    Instant,
+    derive::{SmithyShape, smithy_structure},
+    prelude::*,
+    schema::Blob,
+    smithy,
+};
 
 smithy!("test#AllPrimitivesDefaults": {
     structure ALL_PRIMITIVES_DEFAULTS_SCHEMA {
@@ -17,7 +23,8 @@ smithy!("test#AllPrimitivesDefaults": {
     }
 });
 
-#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_structure]
+#[derive(SmithyShape)]
 #[smithy_schema(ALL_PRIMITIVES_DEFAULTS_SCHEMA)]
 pub struct AllPrimitivesDefaults {
     #[smithy_schema(STRING)]
@@ -45,8 +52,8 @@ pub struct AllPrimitivesDefaults {
     #[default(true)]
     pub boolean_field: bool,
     #[smithy_schema(BLOB)]
-    #[default(ByteBuffer::default())]
-    pub blob_field: ByteBuffer,
+    #[default(Blob::new())]
+    pub blob_field: Blob,
     #[smithy_schema(TIMESTAMP)]
     #[default(Instant::from_epoch_milliseconds(1000000).expect("Epoch milliseconds must be set"))]
     pub timestamp_field: Instant,
@@ -0,0 +1,41 @@
+use std::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use futures_core::Stream;
+
+/// Get a [`Context`] with a waker that does nothing, for polling futures and
+/// streams by hand.
+#[must_use]
+pub fn noop_context() -> Context<'static> {
+    Context::from_waker(Waker::noop())
+}
+
+/// Poll a future that is expected to complete without waiting.
+///
+/// # Panics
+/// Panics if the future is pending.
+pub fn ready<F: Future>(future: F) -> F::Output {
+    let Poll::Ready(output) = pin!(future).poll(&mut noop_context()) else {
+        panic!("Expected future to be ready");
+    };
+    output
+}
+
+/// Poll every item of a stream that is expected to complete without waiting.
+///
+/// # Panics
+/// Panics if the stream is pending before it ends.
+pub fn ready_items<S: Stream>(stream: S) -> Vec<S::Item> {
+    let mut stream = pin!(stream);
+    let mut cx = noop_context();
+    let mut items = Vec::new();
+    loop {
+        match stream.as_mut().poll_next(&mut cx) {
+            Poll::Ready(Some(item)) => items.push(item),
+            Poll::Ready(None) => return items,
+            Poll::Pending => panic!("Expected stream to be ready"),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use smithy4rs_core::{
+    IndexMap, Instant,
+    derive::{SmithyShape, smithy_structure},
+    prelude::{
+        HttpHeaderTrait, HttpLabelTrait, HttpPayloadTrait, HttpPrefixHeadersTrait,
+        HttpQueryParamsTrait, HttpQueryTrait, HttpResponseCodeTrait,
+    },
+    schema::{
+        Blob,
+        prelude::{BLOB, INTEGER, LONG, STRING, TIMESTAMP},
+    },
+    smithy,
+};
+
+use crate::{
+    SIMPLE_STRUCT_SCHEMA, STRING_LIST_SCHEMA, STRING_MAP_SCHEMA, SimpleStruct, SimpleStructBuilder,
+};
+
+smithy!("test#HttpBindingsInput": {
+    structure HTTP_BINDINGS_INPUT_SCHEMA {
+        @HttpLabelTrait::builder().build();
+        CITY: STRING = "city"
+        @HttpLabelTrait::builder().build();
+        PATH: STRING = "path"
+        @HttpQueryTrait::new("pageSize");
+        PAGE_SIZE: INTEGER = "page_size"
+        @HttpQueryTrait::new("tag");
+        TAGS: STRING_LIST_SCHEMA = "tags"
+        @HttpQueryParamsTrait::builder().build();
+        PARAMS: STRING_MAP_SCHEMA = "params"
+        @HttpHeaderTrait::new("X-Since");
+        SINCE: TIMESTAMP = "since"
+        @HttpHeaderTrait::new("X-Aliases");
+        ALIASES: STRING_LIST_SCHEMA = "aliases"
+        @HttpPrefixHeadersTrait::new("X-Meta-");
+        META: STRING_MAP_SCHEMA = "meta"
+        NAME: STRING = "name"
+        POPULATION: LONG = "population"
+    }
+});
+
+#[smithy_structure]
+#[derive(SmithyShape)]
+#[smithy_schema(HTTP_BINDINGS_INPUT_SCHEMA)]
+pub struct HttpBindingsInput {
+    #[smithy_schema(CITY)]
+    pub city: String,
+    #[smithy_schema(PATH)]
+    pub path: String,
+    #[smithy_schema(PAGE_SIZE)]
+    pub page_size: Option<i32>,
+    #[smithy_schema(TAGS)]
+    pub tags: Option<Vec<String>>,
+    #[smithy_schema(PARAMS)]
+    pub params: Option<IndexMap<String, String>>,
+    #[smithy_schema(SINCE)]
+    pub since: Option<Instant>,
+    #[smithy_schema(ALIASES)]
+    pub aliases: Option<Vec<String>>,
+    #[smithy_schema(META)]
+    pub meta: Option<IndexMap<String, String>>,
+    #[smithy_schema(NAME)]
+    pub name: String,
+    #[smithy_schema(POPULATION)]
+    pub population: Option<i64>,
+}
+
+smithy!("test#HttpBlobPayloadOutput": {
+    structure HTTP_BLOB_PAYLOAD_OUTPUT_SCHEMA {
+        @HttpResponseCodeTrait::builder().build();
+        STATUS: INTEGER = "status"
+        @HttpHeaderTrait::new("X-Request-Id");
+        REQUEST_ID: STRING = "request_id"
+        @HttpPayloadTrait::builder().build();
+        DATA: BLOB = "data"
+    }
+});
+
+#[smithy_structure]
+#[derive(SmithyShape)]
+#[smithy_schema(HTTP_BLOB_PAYLOAD_OUTPUT_SCHEMA)]
+pub struct HttpBlobPayloadOutput {
+    #[smithy_schema(STATUS)]
+    pub status: Option<i32>,
+    #[smithy_schema(REQUEST_ID)]
+    pub request_id: Option<String>,
+    #[smithy_schema(DATA)]
+    pub data: Option<Blob>,
+}
+
+smithy!("test#HttpStructPayloadOutput": {
+    structure HTTP_STRUCT_PAYLOAD_OUTPUT_SCHEMA {
+        @HttpHeaderTrait::new("X-Request-Id");
+        REQUEST_ID: STRING = "request_id"
+        @HttpPayloadTrait::builder().build();
+        DETAILS: SIMPLE_STRUCT_SCHEMA = "details"
+    }
+});
+
+#[smithy_structure]
+#[derive(SmithyShape)]
+#[smithy_schema(HTTP_STRUCT_PAYLOAD_OUTPUT_SCHEMA)]
+pub struct HttpStructPayloadOutput {
+    #[smithy_schema(REQUEST_ID)]
+    pub request_id: Option<String>,
+    #[smithy_schema(DETAILS)]
+    pub details: Option<SimpleStruct>,
+}
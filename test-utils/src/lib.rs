@@ -6,12 +6,34 @@
 mod basic_types;
 mod defaults;
 mod enums;
+mod futures;
+mod http;
 mod nested;
 mod recursive;
 mod unions;
 
 pub use basic_types::*;
 pub use enums::*;
+pub use futures::*;
+pub use http::*;
 pub use nested::*;
 pub use recursive::*;
 pub use unions::*;
+
+// Generated shapes and builders must be usable across async tasks
+smithy4rs_core::assert_send_sync!(
+    AllPrimitivesStruct,
+    AllPrimitivesStructBuilder,
+    NestedCollectionsStruct,
+    NestedCollectionsStructBuilder,
+    RecursiveShapesStruct,
+    RecursiveShapesStructBuilder,
+    TreeNode,
+    TreeNodeBuilder,
+    Expr,
+    HttpBlobPayloadOutput,
+    HttpBlobPayloadOutputBuilder,
+    TestEnum,
+    TestIntEnum,
+    TestUnion,
+);
@@ -1,7 +1,7 @@
 use smithy4rs_core::{
     IndexMap,
-    derive::SmithyShape,
-    schema::prelude::{INTEGER, STRING},
+    derive::{SmithyShape, smithy_structure},
+    schema::prelude::{INTEGER, STRING, SparseTrait},
     smithy,
 };
 
@@ -13,7 +13,8 @@ smithy!("test#InnerStruct": {
     }
 });
 
-#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_structure]
+#[derive(SmithyShape)]
 #[smithy_schema(INNER_STRUCT_SCHEMA)]
 pub struct InnerStruct {
     #[smithy_schema(A)]
@@ -46,7 +47,8 @@ smithy!("test#NestedCollectionsStruct": {
     }
 });
 
-#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_structure]
+#[derive(SmithyShape)]
 #[smithy_schema(NESTED_COLLECTIONS_STRUCT_SCHEMA)]
 pub struct NestedCollectionsStruct {
     #[smithy_schema(NAME)]
@@ -62,3 +64,33 @@ pub struct NestedCollectionsStruct {
     #[smithy_schema(MAP)]
     pub map_nested: IndexMap<String, InnerStruct>,
 }
+
+smithy!("test#SparseStringList": {
+    @SparseTrait::builder().build();
+    list SPARSE_STRING_LIST_SCHEMA {
+        member: STRING
+    }
+});
+smithy!("test#SparseStringMap": {
+    @SparseTrait::builder().build();
+    map SPARSE_STRING_MAP_SCHEMA {
+        key: STRING
+        value: STRING
+    }
+});
+smithy!("test#SparseCollectionsStruct": {
+    structure SPARSE_COLLECTIONS_STRUCT_SCHEMA {
+        LIST: SPARSE_STRING_LIST_SCHEMA = "sparse_list"
+        MAP: SPARSE_STRING_MAP_SCHEMA = "sparse_map"
+    }
+});
+
+#[smithy_structure]
+#[derive(SmithyShape)]
+#[smithy_schema(SPARSE_COLLECTIONS_STRUCT_SCHEMA)]
+pub struct SparseCollectionsStruct {
+    #[smithy_schema(LIST)]
+    pub sparse_list: Vec<Option<String>>,
+    #[smithy_schema(MAP)]
+    pub sparse_map: IndexMap<String, Option<String>>,
+}
@@ -1,6 +1,6 @@
 use smithy4rs_core::{
     IndexMap,
-    derive::SmithyShape,
+    derive::{SmithyShape, smithy_structure, smithy_union},
     schema::prelude::{INTEGER, STRING},
     smithy,
 };
@@ -29,7 +29,8 @@ smithy!("test#RecursiveShapesStruct": {
     }
 });
 
-#[derive(SmithyShape, PartialEq, Clone)]
+#[smithy_structure]
+#[derive(SmithyShape)]
 #[smithy_schema(RECURSIVE_SHAPES_STRUCT_SCHEMA)]
 pub struct RecursiveShapesStruct {
     #[smithy_schema(STRING)]
@@ -45,3 +46,48 @@ pub struct RecursiveShapesStruct {
     #[smithy_schema(NEXT)]
     pub next: Option<Box<RecursiveShapesStruct>>,
 }
+
+// Lists of a recursive shape must defer resolving their member target, as the
+// target is still being built when the list schema is resolved.
+smithy!("test#TreeNodes": {
+    list TREE_NODES_SCHEMA {
+        member: (@lazy TREE_NODE_SCHEMA)
+    }
+});
+
+smithy!("test#TreeNode": {
+    structure TREE_NODE_SCHEMA {
+        VALUE: INTEGER = "value" [range(min: 0)]
+        CHILDREN: TREE_NODES_SCHEMA = "children"
+        PARENT: (@self) = "parent"
+    }
+});
+
+#[smithy_structure]
+#[derive(SmithyShape)]
+#[smithy_schema(TREE_NODE_SCHEMA)]
+pub struct TreeNode {
+    #[smithy_schema(VALUE)]
+    pub value: i32,
+    #[smithy_schema(CHILDREN)]
+    pub children: Option<Vec<TreeNode>>,
+    #[smithy_schema(PARENT)]
+    pub parent: Option<Box<TreeNode>>,
+}
+
+smithy!("test#Expr": {
+    union EXPR_SCHEMA {
+        LITERAL: INTEGER = "literal"
+        NEGATE: (@self) = "negate"
+    }
+});
+
+#[smithy_union]
+#[derive(SmithyShape)]
+#[smithy_schema(EXPR_SCHEMA)]
+pub enum Expr {
+    #[smithy_schema(LITERAL)]
+    Literal(i32),
+    #[smithy_schema(NEGATE)]
+    Negate(Box<Expr>),
+}
@@ -18,7 +18,7 @@ smithy!("test#SimpleUnion": {
 });
 
 #[smithy_union]
-#[derive(SmithyShape, PartialEq)]
+#[derive(SmithyShape)]
 #[smithy_schema(UNION)]
 pub enum TestUnion {
     #[smithy_schema(A)]